@@ -137,7 +137,7 @@ _: {
             "ethermint"
             "tendermint-bls"
             "parlia"
-            # "movement"
+            "movement"
             "state-lens-ics23-mpt"
             # "state-lens-ics23-smt"
           ];
@@ -165,7 +165,7 @@ _: {
             "trusted-mpt"
             "ethermint"
             "tendermint-bls"
-            # "movement"
+            "movement"
             "state-lens-ics23-mpt"
             # "state-lens-ics23-smt"
           ];
@@ -448,21 +448,31 @@ _: {
           client-type = "tendermint";
           features = [ "bls" ];
         }
-        # {
-        #   name = "movement";
-        #   dir = "movement";
-        #   client-type = "movement";
-        # }
+        {
+          name = "movement";
+          dir = "movement";
+          client-type = "movement";
+        }
+        {
+          name = "linea";
+          dir = "linea";
+          client-type = "linea";
+        }
+        {
+          name = "scroll";
+          dir = "scroll";
+          client-type = "scroll";
+        }
         {
           name = "state-lens-ics23-mpt";
           dir = "state-lens-ics23-mpt";
           client-type = "state-lens/ics23/mpt";
         }
-        # {
-        #   name = "state-lens-ics23-smt";
-        #   dir = "state-lens-ics23-smt";
-        #   client-type = "state-lens/ics23/smt";
-        # }
+        {
+          name = "state-lens-ics23-smt";
+          dir = "state-lens-ics23-smt";
+          client-type = "state-lens/ics23/smt";
+        }
         {
           name = "state-lens-ics23-ics23";
           dir = "state-lens-ics23-ics23";
@@ -22,6 +22,8 @@ use crate::{msg::InitMsg, state::IbcHost};
 
 pub mod msg;
 pub mod state;
+#[cfg(any(feature = "test-utils", test))]
+pub mod test_utils;
 
 pub use ibc_union_spec as spec;
 
@@ -65,6 +67,16 @@ pub enum IbcClientError<T: IbcClient> {
     InvalidClientMessage(Vec<u8>),
     #[error("caller `{0}` is not a whitelisted relayer")]
     UnauthorizedCaller(String),
+    #[error(
+        "substitute client tracks chain `{substitute_chain_id}`, but the \
+        subject client tracks chain `{subject_chain_id}`"
+    )]
+    SubstituteChainIdMismatch {
+        subject_chain_id: String,
+        substitute_chain_id: String,
+    },
+    #[error("client upgrades are not supported by this client")]
+    UpgradeNotSupported,
 }
 
 impl<T: IbcClient + 'static> From<IbcClientError<T>> for StdError {
@@ -113,6 +125,16 @@ impl<'a, T: IbcClient> IbcClientCtx<'a, T> {
             .map_err(Into::into)
     }
 
+    pub fn maybe_read_self_storage<S: Store>(
+        &self,
+        key: S::Key,
+    ) -> Result<Option<S::Value>, IbcClientError<T>> {
+        self.deps
+            .querier
+            .maybe_read::<ClientStore<S>>(&self.ibc_host, &(self.client_id, key))
+            .map_err(Into::into)
+    }
+
     pub fn read_client_state<Client: IbcClient>(
         &self,
         client_id: ClientId,
@@ -165,6 +187,20 @@ impl<'a, T: IbcClient> IbcClientCtx<'a, T> {
 
         Ok(status)
     }
+
+    /// Get the status of the L1 client `l1_client_id` that this client is built on top of.
+    ///
+    /// Clients that verify their state against another client (L2 and state lens clients) must not
+    /// be usable if that client is not.
+    ///
+    /// [`IbcClient::status`] cannot fail, so any error when querying the status of the L1 client
+    /// (the client does not exist, or its contract fails the query) is reported as
+    /// [`Status::Frozen`]: the L1 client cannot be used for verification in that case, and unlike
+    /// [`Status::Expired`], this does not suggest that the client can be revived by an update.
+    pub fn l1_status<Client: IbcClient>(&self, l1_client_id: ClientId) -> Status {
+        self.status::<Client>(l1_client_id)
+            .unwrap_or(Status::Frozen)
+    }
 }
 
 fn client_impl<T: IbcClient>(
@@ -186,7 +222,9 @@ fn client_impl<T: IbcClient>(
 pub struct StateUpdate<T: IbcClient> {
     pub height: u64,
     pub client_state: Option<T::ClientState>,
-    pub consensus_state: T::ConsensusState,
+    /// The consensus state to save at `height`. If `None`, the client is not updated to a new
+    /// height (see [`StateUpdate::storage_only`]).
+    pub consensus_state: Option<T::ConsensusState>,
     pub storage_writes: StorageWrites,
 }
 
@@ -194,7 +232,19 @@ impl<T: IbcClient> StateUpdate<T> {
     pub fn new(height: u64, consensus_state: T::ConsensusState) -> Self {
         StateUpdate {
             height,
-            consensus_state,
+            consensus_state: Some(consensus_state),
+            client_state: None,
+            storage_writes: Default::default(),
+        }
+    }
+
+    /// A state update that does not save a new consensus state, for headers that are verified but
+    /// only tracked by the client (through [`StateUpdate::add_storage_write`]) instead of being
+    /// applied. `trusted_height` is the height the header was verified against.
+    pub fn storage_only(trusted_height: u64) -> Self {
+        StateUpdate {
+            height: trusted_height,
+            consensus_state: None,
             client_state: None,
             storage_writes: Default::default(),
         }
@@ -325,6 +375,61 @@ pub trait IbcClient: Sized + 'static {
         misbehaviour: Self::Misbehaviour,
         relayer: Addr,
     ) -> Result<Self::ClientState, IbcClientError<Self>>;
+
+    /// Verify that the client at `ctx.client_id` can be recovered with the state of the substitute
+    /// client, and return the state to overwrite it with.
+    ///
+    /// The host has already checked that both clients are of the same type, that the substitute is
+    /// active, and that its latest height is greater than the subject's. By default, this only
+    /// checks that both clients track the same chain, and returns the substitute's state as is.
+    /// Clients with additional invariants (i.e. a chain revision or a trusting period that must not
+    /// change) should override this.
+    fn verify_substitute(
+        ctx: IbcClientCtx<Self>,
+        client_state: Self::ClientState,
+        substitute_client_state: Self::ClientState,
+        substitute_consensus_state: Self::ConsensusState,
+    ) -> Result<StateUpdate<Self>, IbcClientError<Self>> {
+        let _ = ctx;
+
+        let subject_chain_id = Self::get_counterparty_chain_id(&client_state);
+        let substitute_chain_id = Self::get_counterparty_chain_id(&substitute_client_state);
+
+        if subject_chain_id != substitute_chain_id {
+            return Err(IbcClientError::SubstituteChainIdMismatch {
+                subject_chain_id,
+                substitute_chain_id,
+            });
+        }
+
+        Ok(StateUpdate::new(
+            Self::get_latest_height(&substitute_client_state),
+            substitute_consensus_state,
+        )
+        .overwrite_client_state(substitute_client_state))
+    }
+
+    /// Verify `new_client_state` and `new_consensus_state` against the upgrade path of the
+    /// counterparty chain at the latest trusted height, and return the upgraded state.
+    ///
+    /// Clients do not support upgrades by default.
+    fn verify_upgrade(
+        ctx: IbcClientCtx<Self>,
+        new_client_state: Self::ClientState,
+        new_consensus_state: Self::ConsensusState,
+        proof_upgrade_client: Self::StorageProof,
+        proof_upgrade_consensus_state: Self::StorageProof,
+    ) -> Result<StateUpdate<Self>, IbcClientError<Self>> {
+        let _ = (
+            ctx,
+            new_client_state,
+            new_consensus_state,
+            proof_upgrade_client,
+            proof_upgrade_consensus_state,
+        );
+
+        Err(IbcClientError::UpgradeNotSupported)
+    }
 }
 
 pub fn init<T: IbcClient>(
@@ -448,25 +553,14 @@ pub fn query<T: IbcClient>(
             let header =
                 T::Header::decode_as::<T::Encoding>(&message).map_err(DecodeError::Header)?;
 
-            let StateUpdate {
-                height,
-                client_state,
-                consensus_state,
-                storage_writes,
-            } = T::verify_header(
+            let state_update = T::verify_header(
                 IbcClientCtx::new(client_id, ibc_host, deps, env),
                 Addr::unchecked(caller),
                 header,
                 Addr::unchecked(relayer),
             )?;
 
-            to_json_binary(&UpdateStateResponse {
-                height,
-                consensus_state_bytes: consensus_state.encode().into(),
-                client_state_bytes: client_state.map(|cs| cs.encode_as::<T::Encoding>().into()),
-                storage_writes,
-            })
-            .map_err(Into::into)
+            to_json_binary(&state_update_response(state_update)).map_err(Into::into)
         }
         QueryMsg::Misbehaviour {
             caller,
@@ -490,6 +584,82 @@ pub fn query<T: IbcClient>(
             })
             .map_err(Into::into)
         }
+        QueryMsg::VerifySubstitute {
+            client_id,
+            substitute_client_id,
+        } => {
+            let ibc_host = deps.storage.read_item::<IbcHost>()?;
+
+            let client_state = read_client_state::<T>(&*deps.querier, &ibc_host, client_id)?;
+            let substitute_client_state =
+                read_client_state::<T>(&*deps.querier, &ibc_host, substitute_client_id)?;
+            let substitute_consensus_state = read_consensus_state::<T>(
+                &*deps.querier,
+                &ibc_host,
+                substitute_client_id,
+                T::get_latest_height(&substitute_client_state),
+            )?;
+
+            let state_update = T::verify_substitute(
+                IbcClientCtx::new(client_id, ibc_host, deps, env),
+                client_state,
+                substitute_client_state,
+                substitute_consensus_state,
+            )?;
+
+            to_json_binary(&state_update_response(state_update)).map_err(Into::into)
+        }
+        QueryMsg::VerifyUpgrade {
+            client_id,
+            new_client_state,
+            new_consensus_state,
+            proof_upgrade_client,
+            proof_upgrade_consensus_state,
+        } => {
+            let ibc_host = deps.storage.read_item::<IbcHost>()?;
+
+            let new_client_state = T::ClientState::decode_as::<T::Encoding>(&new_client_state)
+                .map_err(DecodeError::ClientState)?;
+            let new_consensus_state =
+                T::ConsensusState::decode(&new_consensus_state).map_err(|error| {
+                    DecodeError::ConsensusState {
+                        counterparty_height: T::get_latest_height(&new_client_state),
+                        error,
+                    }
+                })?;
+            let proof_upgrade_client =
+                T::StorageProof::decode_as::<T::Encoding>(&proof_upgrade_client)
+                    .map_err(DecodeError::StorageProof)?;
+            let proof_upgrade_consensus_state =
+                T::StorageProof::decode_as::<T::Encoding>(&proof_upgrade_consensus_state)
+                    .map_err(DecodeError::StorageProof)?;
+
+            let state_update = T::verify_upgrade(
+                IbcClientCtx::new(client_id, ibc_host, deps, env),
+                new_client_state,
+                new_consensus_state,
+                proof_upgrade_client,
+                proof_upgrade_consensus_state,
+            )?;
+
+            to_json_binary(&state_update_response(state_update)).map_err(Into::into)
+        }
+    }
+}
+
+fn state_update_response<T: IbcClient>(
+    StateUpdate {
+        height,
+        client_state,
+        consensus_state,
+        storage_writes,
+    }: StateUpdate<T>,
+) -> UpdateStateResponse {
+    UpdateStateResponse {
+        height,
+        consensus_state_bytes: consensus_state.map(|cs| cs.encode().into()),
+        client_state_bytes: client_state.map(|cs| cs.encode_as::<T::Encoding>().into()),
+        storage_writes,
     }
 }
 
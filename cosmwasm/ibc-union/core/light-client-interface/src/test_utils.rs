@@ -0,0 +1,212 @@
+use cosmwasm_std::{
+    from_json,
+    testing::{mock_dependencies, mock_env},
+    to_json_binary, Addr, Binary, ContractResult, Empty, QuerierResult, SystemError, SystemResult,
+    WasmQuery,
+};
+use depolama::{RawStore, ValueCodec};
+use ibc_union::state::{ClientConsensusStates, ClientImpls, ClientStates, ClientStore};
+use ibc_union_msg::lightclient::{QueryMsg, StorageWrites};
+use ibc_union_spec::{ClientId, Status};
+use unionlabs::{
+    encoding::{EncodeAs, EthAbi},
+    primitives::Bytes,
+};
+
+use crate::{IbcClient, IbcClientCtx};
+
+/// A mock ibc host, serving the reads and queries that a light client makes through
+/// [`IbcClientCtx`].
+///
+/// Use [`MockIbcHost::querier`] as the wasm query handler of a [`MockQuerier`].
+///
+/// [`MockQuerier`]: cosmwasm_std::testing::MockQuerier
+#[derive(Debug, Clone)]
+pub struct MockIbcHost {
+    address: Addr,
+    storage: StorageWrites,
+    clients: Vec<(ClientId, Addr, Option<Status>)>,
+}
+
+impl MockIbcHost {
+    pub fn new(address: Addr) -> Self {
+        Self {
+            address,
+            storage: StorageWrites::default(),
+            clients: vec![],
+        }
+    }
+
+    /// Register `client_id` as being implemented by the contract at `client_impl`.
+    ///
+    /// The contract reports `status` for the client, or fails the query if `status` is `None`.
+    #[must_use]
+    pub fn add_client(
+        mut self,
+        client_id: ClientId,
+        client_impl: Addr,
+        status: Option<Status>,
+    ) -> Self {
+        self.storage.insert(
+            depolama::raw_key::<ClientImpls>(&client_id),
+            ClientImpls::encode_value(&client_impl),
+        );
+        self.clients.push((client_id, client_impl, status));
+        self
+    }
+
+    #[must_use]
+    pub fn add_client_state<T: IbcClient>(
+        mut self,
+        client_id: ClientId,
+        client_state: T::ClientState,
+    ) -> Self {
+        self.storage.insert(
+            depolama::raw_key::<ClientStates>(&client_id),
+            client_state.encode_as::<T::Encoding>().into(),
+        );
+        self
+    }
+
+    #[must_use]
+    pub fn add_consensus_state<T: IbcClient>(
+        mut self,
+        client_id: ClientId,
+        height: u64,
+        consensus_state: T::ConsensusState,
+    ) -> Self {
+        self.storage.insert(
+            depolama::raw_key::<ClientConsensusStates>(&(client_id, height)),
+            consensus_state.encode_as::<EthAbi>().into(),
+        );
+        self
+    }
+
+    /// Add `storage_writes` (as returned by [`IbcClient::verify_creation`] and
+    /// [`IbcClient::verify_header`]) to the client store of `client_id`.
+    #[must_use]
+    pub fn add_storage_writes(
+        mut self,
+        client_id: ClientId,
+        storage_writes: StorageWrites,
+    ) -> Self {
+        self.storage
+            .extend(storage_writes.into_iter().map(|(key, value)| {
+                (
+                    depolama::raw_key::<ClientStore<RawStore>>(&(client_id, key)),
+                    value,
+                )
+            }));
+        self
+    }
+
+    pub fn querier(self) -> impl Fn(&WasmQuery) -> QuerierResult + 'static {
+        move |query| match query {
+            WasmQuery::Raw { contract_addr, key } if contract_addr == self.address.as_str() => {
+                SystemResult::Ok(ContractResult::Ok(
+                    self.storage
+                        .get(&Bytes::from(key.to_vec()))
+                        .map(|value| Binary::from(value.clone().into_vec()))
+                        .unwrap_or_default(),
+                ))
+            }
+            WasmQuery::Smart { contract_addr, msg } => match from_json::<QueryMsg>(msg) {
+                Ok(QueryMsg::GetStatus { client_id }) => self
+                    .clients
+                    .iter()
+                    .find(|(id, client_impl, _)| {
+                        *id == client_id && client_impl.as_str() == contract_addr
+                    })
+                    .map(|(_, _, status)| match status {
+                        Some(status) => SystemResult::Ok(ContractResult::Ok(
+                            to_json_binary(status).expect("serialization is infallible; qed;"),
+                        )),
+                        None => SystemResult::Ok(ContractResult::Err(format!(
+                            "unable to query the status of client {client_id}"
+                        ))),
+                    })
+                    .unwrap_or_else(|| {
+                        SystemResult::Err(SystemError::NoSuchContract {
+                            addr: contract_addr.clone(),
+                        })
+                    }),
+                _ => SystemResult::Err(SystemError::UnsupportedRequest {
+                    kind: format!("unsupported light client query: {msg}"),
+                }),
+            },
+            _ => SystemResult::Err(SystemError::UnsupportedRequest {
+                kind: format!("unsupported wasm query: {query:?}"),
+            }),
+        }
+    }
+}
+
+/// The state of the L1 client of a client, as seen by [`IbcClientCtx::l1_status`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum L1Client {
+    /// The L1 client is not registered in the ibc host.
+    Missing,
+    /// The status query to the L1 client fails.
+    Failing,
+    /// The L1 client reports the given status.
+    Status(Status),
+}
+
+/// Query the status of a client with `client_state` through [`IbcClient::status`], where its L1
+/// client `l1_client_id` is `l1_client`.
+pub fn client_status<T: IbcClient<CustomQuery = Empty>>(
+    client_state: &T::ClientState,
+    l1_client_id: ClientId,
+    l1_client: L1Client,
+) -> Status {
+    let ibc_host = MockIbcHost::new(Addr::unchecked("ibc_host"));
+
+    let ibc_host = match l1_client {
+        L1Client::Missing => ibc_host,
+        L1Client::Failing => ibc_host.add_client(l1_client_id, Addr::unchecked("l1_client"), None),
+        L1Client::Status(status) => {
+            ibc_host.add_client(l1_client_id, Addr::unchecked("l1_client"), Some(status))
+        }
+    };
+
+    let mut deps = mock_dependencies();
+    deps.querier.update_wasm(ibc_host.querier());
+
+    let client_id = l1_client_id.checked_add(1).expect("client id overflow");
+
+    T::status(
+        IbcClientCtx::new(
+            client_id,
+            Addr::unchecked("ibc_host"),
+            deps.as_ref(),
+            mock_env(),
+        ),
+        client_state,
+    )
+}
+
+/// Assert that the status of a client with the (unfrozen) `client_state` is the status of its L1
+/// client `l1_client_id`, and that the client is frozen if the status of the L1 client cannot be
+/// queried (see [`IbcClientCtx::l1_status`]).
+pub fn assert_status_follows_l1_client<T: IbcClient<CustomQuery = Empty>>(
+    client_state: &T::ClientState,
+    l1_client_id: ClientId,
+) {
+    for l1_status in [Status::Active, Status::Expired, Status::Frozen] {
+        assert_eq!(
+            client_status::<T>(
+                client_state,
+                l1_client_id,
+                L1Client::Status(l1_status.clone())
+            ),
+            l1_status
+        );
+    }
+
+    for l1_client in [L1Client::Missing, L1Client::Failing] {
+        assert_eq!(
+            client_status::<T>(client_state, l1_client_id, l1_client),
+            Status::Frozen
+        );
+    }
+}
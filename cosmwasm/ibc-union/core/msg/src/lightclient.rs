@@ -37,8 +37,9 @@ pub struct UpdateStateResponse {
     pub height: u64,
     /// The client state to overwrite the current one with if provided
     pub client_state_bytes: Option<Bytes>,
-    /// The consensus state to save at the `update_height`
-    pub consensus_state_bytes: Bytes,
+    /// The consensus state to save at the `update_height`. If not provided, the client is not
+    /// updated to a new height, and only the client state and the storage writes are saved
+    pub consensus_state_bytes: Option<Bytes>,
     /// The storage writes which will be written under the client's storage in the core module
     pub storage_writes: StorageWrites,
 }
@@ -88,4 +89,19 @@ pub enum QueryMsg {
         message: Bytes,
         relayer: String,
     },
+    /// Verify that `substitute_client_id` can be used to recover `client_id`. Returns an
+    /// [`UpdateStateResponse`] containing the state to overwrite `client_id` with.
+    VerifySubstitute {
+        client_id: ClientId,
+        substitute_client_id: ClientId,
+    },
+    /// Verify the proofs of an upgraded client and consensus state committed to by the
+    /// counterparty chain. Returns an [`UpdateStateResponse`] containing the upgraded state.
+    VerifyUpgrade {
+        client_id: ClientId,
+        new_client_state: Bytes,
+        new_consensus_state: Bytes,
+        proof_upgrade_client: Bytes,
+        proof_upgrade_consensus_state: Bytes,
+    },
 }
@@ -26,6 +26,9 @@ pub enum ExecuteMsg {
     CreateClient(MsgCreateClient),
     UpdateClient(MsgUpdateClient),
     ForceUpdateClient(MsgForceUpdateClient),
+    RecoverClient(MsgRecoverClient),
+    UpgradeClient(MsgUpgradeClient),
+    SubmitMisbehaviour(MsgSubmitMisbehaviour),
     ConnectionOpenInit(MsgConnectionOpenInit),
     ConnectionOpenTry(MsgConnectionOpenTry),
     ForceConnectionOpenTry(MsgConnectionOpenTry),
@@ -88,6 +91,41 @@ pub struct MsgForceUpdateClient {
     pub height: u64,
 }
 
+/// Replace the state of `subject_client_id` with the latest state of `substitute_client_id`.
+///
+/// This is used to recover a client that has expired or been frozen. Both clients must be of the
+/// same client type, and the substitute must be active. Only callable by the relayer admin.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MsgRecoverClient {
+    pub subject_client_id: ClientId,
+    pub substitute_client_id: ClientId,
+}
+
+/// Upgrade `client_id` to a new client and consensus state committed to by the counterparty chain.
+///
+/// The proofs are verified by the light client against its latest trusted height.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MsgUpgradeClient {
+    pub client_id: ClientId,
+    pub new_client_state: Bytes,
+    pub new_consensus_state: Bytes,
+    pub proof_upgrade_client: Bytes,
+    pub proof_upgrade_consensus_state: Bytes,
+}
+
+/// Submit evidence of misbehaviour of the chain tracked by `client_id`.
+///
+/// The misbehaviour is verified by the light client, which freezes the client if it is valid.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MsgSubmitMisbehaviour {
+    pub client_id: ClientId,
+    pub client_message: Bytes,
+    pub relayer: String,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct MsgConnectionOpenInit {
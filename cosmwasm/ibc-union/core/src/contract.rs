@@ -11,8 +11,8 @@ use depolama::{RawStore, StorageExt};
 use frissitheto::{UpgradeError, UpgradeMsg};
 use ibc_union_msg::{
     lightclient::{
-        QueryMsg as LightClientQuery, UpdateStateResponse, VerifyCreationResponse,
-        VerifyCreationResponseEvent,
+        MisbehaviourResponse, QueryMsg as LightClientQuery, UpdateStateResponse,
+        VerifyCreationResponse, VerifyCreationResponseEvent,
     },
     module::{ExecuteMsg as ModuleMsg, IbcUnionMsg},
     msg::{
@@ -21,7 +21,8 @@ use ibc_union_msg::{
         MsgChannelOpenTry, MsgConnectionOpenAck, MsgConnectionOpenConfirm, MsgConnectionOpenInit,
         MsgConnectionOpenTry, MsgCreateClient, MsgForceUpdateClient, MsgIntentPacketRecv,
         MsgMigrateState, MsgPacketAcknowledgement, MsgPacketRecv, MsgPacketTimeout,
-        MsgRegisterClient, MsgSendPacket, MsgUpdateClient, MsgWriteAcknowledgement,
+        MsgRecoverClient, MsgRegisterClient, MsgSendPacket, MsgSubmitMisbehaviour, MsgUpdateClient,
+        MsgUpgradeClient, MsgWriteAcknowledgement,
     },
     query::QueryMsg,
 };
@@ -55,6 +56,9 @@ pub mod events {
         pub const REGISTER: &str = "register_client";
         pub const CREATE: &str = "create_client";
         pub const UPDATE: &str = "update_client";
+        pub const RECOVER: &str = "recover_client";
+        pub const UPGRADE: &str = "upgrade_client";
+        pub const MISBEHAVIOUR: &str = "submit_misbehaviour";
     }
     pub mod connection {
         pub const OPEN_INIT: &str = "connection_open_init";
@@ -82,6 +86,7 @@ pub mod events {
     }
     pub mod attribute {
         pub const CLIENT_ID: &str = "client_id";
+        pub const SUBSTITUTE_CLIENT_ID: &str = "substitute_client_id";
         pub const CONNECTION_ID: &str = "connection_id";
         pub const CHANNEL_ID: &str = "channel_id";
         pub const COUNTERPARTY_CHANNEL_ID: &str = "counterparty_channel_id";
@@ -238,6 +243,38 @@ pub fn execute(
                     .add_attribute(events::attribute::COUNTERPARTY_HEIGHT, height.to_string()),
             ))
         }
+        ExecuteMsg::RecoverClient(MsgRecoverClient {
+            subject_client_id,
+            substitute_client_id,
+        }) => {
+            ensure_relayer_admin(deps.storage, &info.sender)?;
+            recover_client(deps.branch(), subject_client_id, substitute_client_id)
+        }
+        ExecuteMsg::UpgradeClient(MsgUpgradeClient {
+            client_id,
+            new_client_state,
+            new_consensus_state,
+            proof_upgrade_client,
+            proof_upgrade_consensus_state,
+        }) => {
+            ensure_relayer(deps.storage, &info.sender)?;
+            upgrade_client(
+                deps.branch(),
+                client_id,
+                new_client_state,
+                new_consensus_state,
+                proof_upgrade_client,
+                proof_upgrade_consensus_state,
+            )
+        }
+        ExecuteMsg::SubmitMisbehaviour(MsgSubmitMisbehaviour {
+            client_id,
+            client_message,
+            relayer,
+        }) => {
+            let relayer = deps.api.addr_validate(&relayer)?;
+            submit_misbehaviour(deps.branch(), info, client_id, client_message, relayer)
+        }
         ExecuteMsg::ConnectionOpenInit(MsgConnectionOpenInit {
             client_id,
             counterparty_client_id,
@@ -1076,6 +1113,209 @@ fn update_client(
         update
     };
 
+    // the header was only tracked by the client, it has not been updated to a new height
+    if update.consensus_state_bytes.is_none() {
+        write_state_update(deps.branch(), client_id, update);
+        return Ok(Response::new());
+    }
+
+    let height = update.height;
+
+    write_state_update(deps.branch(), client_id, update);
+
+    Ok(
+        Response::new().add_event(Event::new(events::client::UPDATE).add_attributes([
+            (events::attribute::CLIENT_ID, client_id.to_string()),
+            (events::attribute::COUNTERPARTY_HEIGHT, height.to_string()),
+        ])),
+    )
+}
+
+fn recover_client(
+    mut deps: DepsMut,
+    subject_client_id: ClientId,
+    substitute_client_id: ClientId,
+) -> Result<Response, ContractError> {
+    if subject_client_id == substitute_client_id {
+        return Err(ContractError::SubstituteIsSubject {
+            client_id: subject_client_id,
+        });
+    }
+
+    let subject_client_type = deps.storage.read::<ClientTypes>(&subject_client_id)?;
+    let substitute_client_type = deps.storage.read::<ClientTypes>(&substitute_client_id)?;
+    if subject_client_type != substitute_client_type {
+        return Err(ContractError::SubstituteClientTypeMismatch {
+            subject_client_type,
+            substitute_client_type,
+        });
+    }
+
+    let subject_client_impl = client_impl(deps.as_ref(), subject_client_id)?;
+    let substitute_client_impl = client_impl(deps.as_ref(), substitute_client_id)?;
+
+    let subject_status = query_light_client::<Status>(
+        deps.as_ref(),
+        subject_client_impl.clone(),
+        LightClientQuery::GetStatus {
+            client_id: subject_client_id,
+        },
+    )?;
+    if matches!(subject_status, Status::Active) {
+        return Err(ContractError::CannotRecoverActiveClient {
+            client_id: subject_client_id,
+        });
+    }
+
+    let substitute_status = query_light_client::<Status>(
+        deps.as_ref(),
+        substitute_client_impl.clone(),
+        LightClientQuery::GetStatus {
+            client_id: substitute_client_id,
+        },
+    )?;
+    if !matches!(substitute_status, Status::Active) {
+        return Err(ContractError::ClientNotActive {
+            client_id: substitute_client_id,
+            status: substitute_status,
+        });
+    }
+
+    let subject_height = query_light_client::<u64>(
+        deps.as_ref(),
+        subject_client_impl.clone(),
+        LightClientQuery::GetLatestHeight {
+            client_id: subject_client_id,
+        },
+    )?;
+    let substitute_height = query_light_client::<u64>(
+        deps.as_ref(),
+        substitute_client_impl,
+        LightClientQuery::GetLatestHeight {
+            client_id: substitute_client_id,
+        },
+    )?;
+    if substitute_height <= subject_height {
+        return Err(ContractError::SubstituteHeightNotGreater {
+            subject_height,
+            substitute_height,
+        });
+    }
+
+    let update = query_light_client::<UpdateStateResponse>(
+        deps.as_ref(),
+        subject_client_impl,
+        LightClientQuery::VerifySubstitute {
+            client_id: subject_client_id,
+            substitute_client_id,
+        },
+    )?;
+
+    let height = update.height;
+
+    write_state_update(deps.branch(), subject_client_id, update);
+
+    Ok(
+        Response::new().add_event(Event::new(events::client::RECOVER).add_attributes([
+            (events::attribute::CLIENT_ID, subject_client_id.to_string()),
+            (
+                events::attribute::SUBSTITUTE_CLIENT_ID,
+                substitute_client_id.to_string(),
+            ),
+            (events::attribute::COUNTERPARTY_HEIGHT, height.to_string()),
+        ])),
+    )
+}
+
+fn upgrade_client(
+    mut deps: DepsMut,
+    client_id: ClientId,
+    new_client_state: Bytes,
+    new_consensus_state: Bytes,
+    proof_upgrade_client: Bytes,
+    proof_upgrade_consensus_state: Bytes,
+) -> Result<Response, ContractError> {
+    let client_impl = client_impl(deps.as_ref(), client_id)?;
+
+    let status = query_light_client::<Status>(
+        deps.as_ref(),
+        client_impl.clone(),
+        LightClientQuery::GetStatus { client_id },
+    )?;
+    if !matches!(status, Status::Active) {
+        return Err(ContractError::ClientNotActive { client_id, status });
+    }
+
+    let update = query_light_client::<UpdateStateResponse>(
+        deps.as_ref(),
+        client_impl,
+        LightClientQuery::VerifyUpgrade {
+            client_id,
+            new_client_state,
+            new_consensus_state,
+            proof_upgrade_client,
+            proof_upgrade_consensus_state,
+        },
+    )?;
+
+    let height = update.height;
+
+    write_state_update(deps.branch(), client_id, update);
+
+    Ok(
+        Response::new().add_event(Event::new(events::client::UPGRADE).add_attributes([
+            (events::attribute::CLIENT_ID, client_id.to_string()),
+            (events::attribute::COUNTERPARTY_HEIGHT, height.to_string()),
+        ])),
+    )
+}
+
+fn submit_misbehaviour(
+    mut deps: DepsMut,
+    info: MessageInfo,
+    client_id: ClientId,
+    client_message: Bytes,
+    relayer: Addr,
+) -> Result<Response, ContractError> {
+    let client_impl = client_impl(deps.as_ref(), client_id)?;
+
+    let status = query_light_client::<Status>(
+        deps.as_ref(),
+        client_impl.clone(),
+        LightClientQuery::GetStatus { client_id },
+    )?;
+    if !matches!(status, Status::Active) {
+        return Err(ContractError::ClientNotActive { client_id, status });
+    }
+
+    let MisbehaviourResponse { client_state } = query_light_client::<MisbehaviourResponse>(
+        deps.as_ref(),
+        client_impl,
+        LightClientQuery::Misbehaviour {
+            caller: info.sender.into(),
+            client_id,
+            message: client_message,
+            relayer: relayer.into(),
+        },
+    )?;
+
+    store_commit(
+        deps.branch(),
+        &ClientStatePath { client_id }.key(),
+        &commit(&client_state),
+    );
+    deps.storage
+        .write::<ClientStates>(&client_id, &client_state.into_vec().into());
+
+    Ok(Response::new().add_event(
+        Event::new(events::client::MISBEHAVIOUR)
+            .add_attribute(events::attribute::CLIENT_ID, client_id.to_string()),
+    ))
+}
+
+/// Write the state returned by the light client for `client_id`, along with the corresponding
+/// commitments.
+fn write_state_update(mut deps: DepsMut, client_id: ClientId, update: UpdateStateResponse) {
     if let Some(client_state_bytes) = update.client_state_bytes {
         store_commit(
             deps.branch(),
@@ -1086,35 +1326,27 @@ fn update_client(
             .write::<ClientStates>(&client_id, &client_state_bytes.to_vec().into());
     }
 
-    store_commit(
-        deps.branch(),
-        &ConsensusStatePath {
-            client_id,
-            height: update.height,
-        }
-        .key(),
-        &commit(&update.consensus_state_bytes),
-    );
+    if let Some(consensus_state_bytes) = update.consensus_state_bytes {
+        store_commit(
+            deps.branch(),
+            &ConsensusStatePath {
+                client_id,
+                height: update.height,
+            }
+            .key(),
+            &commit(&consensus_state_bytes),
+        );
 
-    deps.storage.write::<ClientConsensusStates>(
-        &(client_id, update.height),
-        &update.consensus_state_bytes.into_vec().into(),
-    );
+        deps.storage.write::<ClientConsensusStates>(
+            &(client_id, update.height),
+            &consensus_state_bytes.into_vec().into(),
+        );
+    }
 
     for (k, v) in update.storage_writes {
         deps.storage
             .write::<ClientStore<RawStore>>(&(client_id, k), &v);
     }
-
-    Ok(
-        Response::new().add_event(Event::new(events::client::UPDATE).add_attributes([
-            (events::attribute::CLIENT_ID, client_id.to_string()),
-            (
-                events::attribute::COUNTERPARTY_HEIGHT,
-                update.height.to_string(),
-            ),
-        ])),
-    )
 }
 
 fn connection_open_init(
@@ -158,6 +158,32 @@ pub enum ContractError {
     OnlyWhitelistedRelayer,
     #[error("sender is not the relayer admin")]
     OnlyRelayerAdmin,
+    #[error(
+        "{} client {client_id} cannot be recovered with itself",
+        ContractErrorKind::from(self)
+    )]
+    SubstituteIsSubject { client_id: ClientId },
+    #[error(
+        "{} the substitute client type ({substitute_client_type}) must be the same as the subject client type ({subject_client_type})",
+        ContractErrorKind::from(self)
+    )]
+    SubstituteClientTypeMismatch {
+        subject_client_type: String,
+        substitute_client_type: String,
+    },
+    #[error(
+        "{} client {client_id} is active and cannot be recovered",
+        ContractErrorKind::from(self)
+    )]
+    CannotRecoverActiveClient { client_id: ClientId },
+    #[error(
+        "{} the substitute client height ({substitute_height}) must be greater than the subject client height ({subject_height})",
+        ContractErrorKind::from(self)
+    )]
+    SubstituteHeightNotGreater {
+        subject_height: u64,
+        substitute_height: u64,
+    },
 }
 
 impl ContractErrorKind {
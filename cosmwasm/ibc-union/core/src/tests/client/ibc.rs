@@ -4,8 +4,11 @@ use cosmwasm_std::{
 };
 use depolama::StorageExt;
 use ibc_union_msg::{
-    lightclient::{QueryMsg as LightClientQueryMsg, UpdateStateResponse, VerifyCreationResponse},
-    msg::{ExecuteMsg, InitMsg, MsgUpdateClient},
+    lightclient::{
+        MisbehaviourResponse, QueryMsg as LightClientQueryMsg, UpdateStateResponse,
+        VerifyCreationResponse,
+    },
+    msg::{ExecuteMsg, InitMsg, MsgRecoverClient, MsgSubmitMisbehaviour, MsgUpdateClient},
 };
 
 use super::*;
@@ -179,7 +182,7 @@ fn update_client_ok() {
             }),
             LightClientQueryMsg::UpdateState { .. } => to_json_binary(&UpdateStateResponse {
                 height: 2,
-                consensus_state_bytes: vec![3, 2, 1].into(),
+                consensus_state_bytes: Some(vec![3, 2, 1].into()),
                 client_state_bytes: Some(vec![3, 2, 1].into()),
                 storage_writes: Default::default(),
             }),
@@ -217,6 +220,66 @@ fn update_client_ok() {
     .is_ok())
 }
 
+#[test]
+fn update_client_storage_only() {
+    let mut deps = mock_dependencies();
+
+    init(
+        deps.as_mut(),
+        InitMsg {
+            relayers_admin: None,
+            relayers: vec![mock_addr(SENDER).to_string()],
+        },
+    )
+    .unwrap();
+    deps.querier
+        .update_wasm(wasm_query_handler(|msg| match msg {
+            LightClientQueryMsg::VerifyCreation { .. } => to_json_binary(&VerifyCreationResponse {
+                counterparty_chain_id: "testchain".to_owned(),
+                events: vec![],
+                storage_writes: Default::default(),
+                client_state_bytes: None,
+            }),
+            LightClientQueryMsg::UpdateState { .. } => to_json_binary(&UpdateStateResponse {
+                height: 2,
+                consensus_state_bytes: None,
+                client_state_bytes: Some(vec![3, 2, 1].into()),
+                storage_writes: Default::default(),
+            }),
+            LightClientQueryMsg::GetStatus { .. } => to_json_binary(&Status::Active),
+            LightClientQueryMsg::GetLatestHeight { .. } => to_json_binary(&1),
+            msg => panic!("should not be called: {:?}", msg),
+        }));
+
+    register_client(deps.as_mut()).expect("register client ok");
+    create_client(deps.as_mut()).expect("create client ok");
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&mock_addr(SENDER), &[]),
+        ExecuteMsg::UpdateClient(MsgUpdateClient {
+            client_id: ClientId!(1),
+            client_message: vec![3, 2, 1].into(),
+            relayer: mock_addr(RELAYER).into_string(),
+        }),
+    )
+    .expect("update client ok");
+
+    // the client is not updated to a new height, but its state is still saved
+    assert!(res.events.is_empty());
+    assert_eq!(
+        deps.storage
+            .maybe_read::<ClientConsensusStates>(&(ClientId!(1), 2))
+            .unwrap(),
+        None
+    );
+    assert_eq!(
+        deps.storage.read::<ClientStates>(&ClientId!(1)).unwrap(),
+        vec![3, 2, 1]
+    );
+}
+
 #[test]
 fn update_client_ko() {
     let mut deps = mock_dependencies();
@@ -294,7 +357,7 @@ fn update_client_commitments_saved() {
             }),
             LightClientQueryMsg::UpdateState { .. } => to_json_binary(&UpdateStateResponse {
                 height: 2,
-                consensus_state_bytes: vec![3, 2, 1].into(),
+                consensus_state_bytes: Some(vec![3, 2, 1].into()),
                 client_state_bytes: Some(vec![3, 2, 1].into()),
                 storage_writes: Default::default(),
             }),
@@ -342,3 +405,464 @@ fn update_client_commitments_saved() {
         vec![3, 2, 1]
     );
 }
+
+fn recover_client_query_handler(msg: LightClientQueryMsg) -> StdResult<Binary> {
+    match msg {
+        LightClientQueryMsg::VerifyCreation { .. } => to_json_binary(&VerifyCreationResponse {
+            counterparty_chain_id: "testchain".to_owned(),
+            events: vec![],
+            storage_writes: Default::default(),
+            client_state_bytes: None,
+        }),
+        LightClientQueryMsg::GetStatus { client_id } if client_id == ClientId!(1) => {
+            to_json_binary(&Status::Expired)
+        }
+        LightClientQueryMsg::GetStatus { .. } => to_json_binary(&Status::Active),
+        LightClientQueryMsg::VerifySubstitute {
+            client_id,
+            substitute_client_id,
+        } => {
+            assert_eq!(client_id, ClientId!(1));
+            assert_eq!(substitute_client_id, ClientId!(2));
+            to_json_binary(&UpdateStateResponse {
+                height: 5,
+                consensus_state_bytes: Some(vec![5, 5, 5].into()),
+                client_state_bytes: Some(vec![4, 4, 4].into()),
+                storage_writes: Default::default(),
+            })
+        }
+        LightClientQueryMsg::GetLatestHeight { client_id } if client_id == ClientId!(1) => {
+            to_json_binary(&1)
+        }
+        LightClientQueryMsg::GetLatestHeight { .. } => to_json_binary(&5),
+        msg => panic!("should not be called: {:?}", msg),
+    }
+}
+
+fn recover_client(
+    deps: DepsMut,
+    sender: &Addr,
+    subject_client_id: ClientId,
+    substitute_client_id: ClientId,
+) -> Result<Response, ContractError> {
+    execute(
+        deps,
+        mock_env(),
+        message_info(sender, &[]),
+        ExecuteMsg::RecoverClient(MsgRecoverClient {
+            subject_client_id,
+            substitute_client_id,
+        }),
+    )
+}
+
+#[test]
+fn recover_client_ok() {
+    let mut deps = mock_dependencies();
+
+    init(
+        deps.as_mut(),
+        InitMsg {
+            relayers_admin: Some(mock_addr(SENDER).to_string()),
+            relayers: vec![mock_addr(SENDER).to_string()],
+        },
+    )
+    .unwrap();
+    deps.querier
+        .update_wasm(wasm_query_handler(recover_client_query_handler));
+
+    register_client(deps.as_mut()).expect("register client ok");
+    create_client(deps.as_mut()).expect("create client ok");
+    create_client(deps.as_mut()).expect("create client ok");
+
+    let res = recover_client(
+        deps.as_mut(),
+        &mock_addr(SENDER),
+        ClientId!(1),
+        ClientId!(2),
+    )
+    .expect("recover client ok");
+
+    assert!(res.events.into_iter().any(|e| e
+        == Event::new(events::client::RECOVER)
+            .add_attribute(events::attribute::CLIENT_ID, "1")
+            .add_attribute(events::attribute::SUBSTITUTE_CLIENT_ID, "2")
+            .add_attribute(events::attribute::COUNTERPARTY_HEIGHT, "5")));
+
+    assert_eq!(
+        deps.storage.read::<ClientStates>(&ClientId!(1)).unwrap(),
+        vec![4, 4, 4]
+    );
+    assert_eq!(
+        deps.storage
+            .read::<ClientConsensusStates>(&(ClientId!(1), 5))
+            .unwrap(),
+        vec![5, 5, 5]
+    );
+    // the substitute is left untouched
+    assert_eq!(
+        deps.storage.read::<ClientStates>(&ClientId!(2)).unwrap(),
+        vec![1, 2, 3]
+    );
+}
+
+#[test]
+fn recover_client_fails_when_not_relayer_admin() {
+    let mut deps = mock_dependencies();
+
+    init(
+        deps.as_mut(),
+        InitMsg {
+            relayers_admin: Some(mock_addr(RELAYER).to_string()),
+            relayers: vec![mock_addr(SENDER).to_string()],
+        },
+    )
+    .unwrap();
+    deps.querier
+        .update_wasm(wasm_query_handler(recover_client_query_handler));
+
+    register_client(deps.as_mut()).expect("register client ok");
+    create_client(deps.as_mut()).expect("create client ok");
+    create_client(deps.as_mut()).expect("create client ok");
+
+    assert_eq!(
+        recover_client(
+            deps.as_mut(),
+            &mock_addr(SENDER),
+            ClientId!(1),
+            ClientId!(2)
+        ),
+        Err(ContractError::OnlyRelayerAdmin)
+    );
+}
+
+#[test]
+fn recover_client_fails_when_subject_active() {
+    let mut deps = mock_dependencies();
+
+    init(
+        deps.as_mut(),
+        InitMsg {
+            relayers_admin: Some(mock_addr(SENDER).to_string()),
+            relayers: vec![mock_addr(SENDER).to_string()],
+        },
+    )
+    .unwrap();
+    deps.querier
+        .update_wasm(wasm_query_handler(recover_client_query_handler));
+
+    register_client(deps.as_mut()).expect("register client ok");
+    create_client(deps.as_mut()).expect("create client ok");
+    create_client(deps.as_mut()).expect("create client ok");
+    create_client(deps.as_mut()).expect("create client ok");
+
+    assert_eq!(
+        recover_client(
+            deps.as_mut(),
+            &mock_addr(SENDER),
+            ClientId!(2),
+            ClientId!(3)
+        ),
+        Err(ContractError::CannotRecoverActiveClient {
+            client_id: ClientId!(2)
+        })
+    );
+}
+
+#[test]
+fn recover_client_fails_when_substitute_is_subject() {
+    let mut deps = mock_dependencies();
+
+    init(
+        deps.as_mut(),
+        InitMsg {
+            relayers_admin: Some(mock_addr(SENDER).to_string()),
+            relayers: vec![mock_addr(SENDER).to_string()],
+        },
+    )
+    .unwrap();
+    deps.querier
+        .update_wasm(wasm_query_handler(recover_client_query_handler));
+
+    register_client(deps.as_mut()).expect("register client ok");
+    create_client(deps.as_mut()).expect("create client ok");
+
+    assert_eq!(
+        recover_client(
+            deps.as_mut(),
+            &mock_addr(SENDER),
+            ClientId!(1),
+            ClientId!(1)
+        ),
+        Err(ContractError::SubstituteIsSubject {
+            client_id: ClientId!(1)
+        })
+    );
+}
+
+#[test]
+fn recover_client_fails_when_substitute_not_active() {
+    let mut deps = mock_dependencies();
+
+    init(
+        deps.as_mut(),
+        InitMsg {
+            relayers_admin: Some(mock_addr(SENDER).to_string()),
+            relayers: vec![mock_addr(SENDER).to_string()],
+        },
+    )
+    .unwrap();
+    deps.querier
+        .update_wasm(wasm_query_handler(|msg| match msg {
+            LightClientQueryMsg::GetStatus { .. } => to_json_binary(&Status::Frozen),
+            msg => recover_client_query_handler(msg),
+        }));
+
+    register_client(deps.as_mut()).expect("register client ok");
+    create_client(deps.as_mut()).expect("create client ok");
+    create_client(deps.as_mut()).expect("create client ok");
+
+    assert_eq!(
+        recover_client(
+            deps.as_mut(),
+            &mock_addr(SENDER),
+            ClientId!(1),
+            ClientId!(2)
+        ),
+        Err(ContractError::ClientNotActive {
+            client_id: ClientId!(2),
+            status: Status::Frozen
+        })
+    );
+}
+
+#[test]
+fn recover_client_fails_when_substitute_not_ahead() {
+    let mut deps = mock_dependencies();
+
+    init(
+        deps.as_mut(),
+        InitMsg {
+            relayers_admin: Some(mock_addr(SENDER).to_string()),
+            relayers: vec![mock_addr(SENDER).to_string()],
+        },
+    )
+    .unwrap();
+    deps.querier
+        .update_wasm(wasm_query_handler(|msg| match msg {
+            LightClientQueryMsg::GetLatestHeight { .. } => to_json_binary(&1),
+            msg => recover_client_query_handler(msg),
+        }));
+
+    register_client(deps.as_mut()).expect("register client ok");
+    create_client(deps.as_mut()).expect("create client ok");
+    create_client(deps.as_mut()).expect("create client ok");
+
+    assert_eq!(
+        recover_client(
+            deps.as_mut(),
+            &mock_addr(SENDER),
+            ClientId!(1),
+            ClientId!(2)
+        ),
+        Err(ContractError::SubstituteHeightNotGreater {
+            subject_height: 1,
+            substitute_height: 1
+        })
+    );
+}
+
+#[test]
+fn recover_client_fails_when_client_types_differ() {
+    let mut deps = mock_dependencies();
+
+    init(
+        deps.as_mut(),
+        InitMsg {
+            relayers_admin: Some(mock_addr(SENDER).to_string()),
+            relayers: vec![mock_addr(SENDER).to_string()],
+        },
+    )
+    .unwrap();
+    deps.querier
+        .update_wasm(wasm_query_handler(recover_client_query_handler));
+
+    register_client(deps.as_mut()).expect("register client ok");
+    create_client(deps.as_mut()).expect("create client ok");
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&mock_addr(SENDER), &[]),
+        ExecuteMsg::RegisterClient(MsgRegisterClient {
+            client_type: "other".to_owned(),
+            client_address: mock_addr("other_client").into_string(),
+        }),
+    )
+    .expect("register client ok");
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&mock_addr(SENDER), &[]),
+        ExecuteMsg::CreateClient(MsgCreateClient {
+            client_type: "other".to_owned(),
+            client_state_bytes: vec![1, 2, 3].into(),
+            consensus_state_bytes: vec![1, 2, 3].into(),
+            relayer: mock_addr(RELAYER).into_string(),
+        }),
+    )
+    .expect("create client ok");
+
+    assert_eq!(
+        recover_client(
+            deps.as_mut(),
+            &mock_addr(SENDER),
+            ClientId!(1),
+            ClientId!(2)
+        ),
+        Err(ContractError::SubstituteClientTypeMismatch {
+            subject_client_type: CLIENT_TYPE.to_owned(),
+            substitute_client_type: "other".to_owned(),
+        })
+    );
+}
+
+fn misbehaviour_query_handler(msg: LightClientQueryMsg) -> StdResult<Binary> {
+    match msg {
+        LightClientQueryMsg::VerifyCreation { .. } => to_json_binary(&VerifyCreationResponse {
+            counterparty_chain_id: "testchain".to_owned(),
+            events: vec![],
+            storage_writes: Default::default(),
+            client_state_bytes: None,
+        }),
+        LightClientQueryMsg::GetStatus { .. } => to_json_binary(&Status::Active),
+        LightClientQueryMsg::Misbehaviour {
+            client_id,
+            message,
+            relayer,
+            ..
+        } => {
+            assert_eq!(client_id, ClientId!(1));
+            assert_eq!(message, vec![6, 6, 6]);
+            assert_eq!(relayer, mock_addr(RELAYER).to_string());
+            to_json_binary(&MisbehaviourResponse {
+                client_state: vec![7, 7, 7].into(),
+            })
+        }
+        LightClientQueryMsg::GetLatestHeight { .. } => to_json_binary(&1),
+        msg => panic!("should not be called: {:?}", msg),
+    }
+}
+
+fn submit_misbehaviour(
+    deps: DepsMut,
+    sender: &Addr,
+    client_id: ClientId,
+) -> Result<Response, ContractError> {
+    execute(
+        deps,
+        mock_env(),
+        message_info(sender, &[]),
+        ExecuteMsg::SubmitMisbehaviour(MsgSubmitMisbehaviour {
+            client_id,
+            client_message: vec![6, 6, 6].into(),
+            relayer: mock_addr(RELAYER).to_string(),
+        }),
+    )
+}
+
+#[test]
+fn submit_misbehaviour_ok() {
+    let mut deps = mock_dependencies();
+
+    init(
+        deps.as_mut(),
+        InitMsg {
+            relayers_admin: None,
+            relayers: vec![mock_addr(SENDER).to_string()],
+        },
+    )
+    .unwrap();
+    deps.querier
+        .update_wasm(wasm_query_handler(misbehaviour_query_handler));
+
+    register_client(deps.as_mut()).expect("register client ok");
+    create_client(deps.as_mut()).expect("create client ok");
+
+    let res = submit_misbehaviour(deps.as_mut(), &mock_addr(SENDER), ClientId!(1))
+        .expect("submit misbehaviour ok");
+
+    assert!(res.events.into_iter().any(|e| e
+        == Event::new(events::client::MISBEHAVIOUR)
+            .add_attribute(events::attribute::CLIENT_ID, "1")));
+
+    assert_eq!(
+        deps.storage.read::<ClientStates>(&ClientId!(1)).unwrap(),
+        vec![7, 7, 7]
+    );
+    // the consensus states are left untouched
+    assert_eq!(
+        deps.storage
+            .read::<ClientConsensusStates>(&(ClientId!(1), 1))
+            .unwrap(),
+        vec![1, 2, 3]
+    );
+}
+
+// misbehaviour is verified by the light client, so anyone can submit it
+#[test]
+fn submit_misbehaviour_ok_when_not_relayer() {
+    let mut deps = mock_dependencies();
+
+    init(
+        deps.as_mut(),
+        InitMsg {
+            relayers_admin: None,
+            relayers: vec![mock_addr(SENDER).to_string()],
+        },
+    )
+    .unwrap();
+    deps.querier
+        .update_wasm(wasm_query_handler(misbehaviour_query_handler));
+
+    register_client(deps.as_mut()).expect("register client ok");
+    create_client(deps.as_mut()).expect("create client ok");
+
+    submit_misbehaviour(deps.as_mut(), &mock_addr(RELAYER), ClientId!(1))
+        .expect("submit misbehaviour ok");
+
+    assert_eq!(
+        deps.storage.read::<ClientStates>(&ClientId!(1)).unwrap(),
+        vec![7, 7, 7]
+    );
+}
+
+#[test]
+fn submit_misbehaviour_fails_when_not_active() {
+    let mut deps = mock_dependencies();
+
+    init(
+        deps.as_mut(),
+        InitMsg {
+            relayers_admin: None,
+            relayers: vec![mock_addr(SENDER).to_string()],
+        },
+    )
+    .unwrap();
+    deps.querier
+        .update_wasm(wasm_query_handler(|msg| match msg {
+            LightClientQueryMsg::GetStatus { .. } => to_json_binary(&Status::Frozen),
+            msg => misbehaviour_query_handler(msg),
+        }));
+
+    register_client(deps.as_mut()).expect("register client ok");
+    create_client(deps.as_mut()).expect("create client ok");
+
+    assert_eq!(
+        submit_misbehaviour(deps.as_mut(), &mock_addr(SENDER), ClientId!(1)),
+        Err(ContractError::ClientNotActive {
+            client_id: ClientId!(1),
+            status: Status::Frozen
+        })
+    );
+}
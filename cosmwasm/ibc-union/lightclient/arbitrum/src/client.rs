@@ -1,4 +1,4 @@
-use arbitrum_light_client_types::{ClientState, ConsensusState, Header};
+use arbitrum_light_client_types::{ClientState, ConsensusState, Header, Misbehaviour};
 use cosmwasm_std::{Addr, Empty};
 use ethereum_light_client::client::EthereumLightClient;
 use ethereum_light_client_types::StorageProof;
@@ -6,7 +6,7 @@ use ibc_union_light_client::{
     spec::{Status, Timestamp},
     ClientCreationResult, IbcClient, IbcClientCtx, IbcClientError, StateUpdate,
 };
-use unionlabs::encoding::Bincode;
+use unionlabs::{encoding::Bincode, ibc::core::client::height::Height};
 
 use crate::errors::Error;
 
@@ -19,7 +19,7 @@ impl IbcClient for ArbitrumLightClient {
 
     type Header = Header;
 
-    type Misbehaviour = Header;
+    type Misbehaviour = Misbehaviour;
 
     type ClientState = ClientState;
 
@@ -106,24 +106,56 @@ impl IbcClient for ArbitrumLightClient {
     }
 
     fn misbehaviour(
-        _ctx: IbcClientCtx<Self>,
+        ctx: IbcClientCtx<Self>,
         _caller: Addr,
-        _misbehaviour: Self::Misbehaviour,
+        misbehaviour: Self::Misbehaviour,
         _relayer: Addr,
     ) -> Result<Self::ClientState, IbcClientError<Self>> {
-        Err(Error::Unimplemented.into())
+        let ClientState::V1(mut client_state) = ctx.read_self_client_state()?;
+
+        let l1_consensus_state_a = ctx
+            .read_consensus_state::<EthereumLightClient>(
+                client_state.l1_client_id,
+                misbehaviour.header_a.l1_height.height(),
+            )
+            .map_err(Into::<Error>::into)?;
+
+        let l1_consensus_state_b = ctx
+            .read_consensus_state::<EthereumLightClient>(
+                client_state.l1_client_id,
+                misbehaviour.header_b.l1_height.height(),
+            )
+            .map_err(Into::<Error>::into)?;
+
+        arbitrum_verifier::verify_misbehaviour_v1(
+            &client_state,
+            &misbehaviour.header_a,
+            l1_consensus_state_a.state_root,
+            &misbehaviour.header_b,
+            l1_consensus_state_b.state_root,
+        )
+        .map_err(Error::HeaderVerify)?;
+
+        let frozen_height = misbehaviour
+            .header_a
+            .l2_header
+            .number
+            .try_into()
+            .map_err(|()| Error::L2HeightTooLarge(misbehaviour.header_a.l2_header.number))?;
+
+        client_state.frozen_height = Height::new(frozen_height);
+
+        Ok(ClientState::V1(client_state))
     }
 
     fn status(
         ctx: IbcClientCtx<Self>,
         ClientState::V1(client_state): &Self::ClientState,
     ) -> Status {
-        let _ = ctx;
-
         if client_state.frozen_height.height() != 0 {
             Status::Frozen
         } else {
-            Status::Active
+            ctx.l1_status::<EthereumLightClient>(client_state.l1_client_id)
         }
     }
 
@@ -148,3 +180,43 @@ impl IbcClient for ArbitrumLightClient {
         client_state.chain_id.to_string()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use arbitrum_light_client_types::ClientStateV1;
+    use ibc_union_light_client::{
+        spec::ClientId,
+        test_utils::{assert_status_follows_l1_client, client_status, L1Client},
+    };
+    use unionlabs::primitives::{H160, U256};
+
+    use super::*;
+
+    fn client_state(frozen_height: u64) -> ClientState {
+        ClientState::V1(ClientStateV1 {
+            chain_id: U256::from(42161_u64),
+            latest_height: 1,
+            l1_client_id: ClientId!(1),
+            l1_contract_address: H160::default(),
+            frozen_height: Height::new(frozen_height),
+            ibc_contract_address: H160::default(),
+        })
+    }
+
+    #[test]
+    fn status_follows_l1_client() {
+        assert_status_follows_l1_client::<ArbitrumLightClient>(&client_state(0), ClientId!(1));
+    }
+
+    #[test]
+    fn status_frozen_if_frozen() {
+        assert_eq!(
+            client_status::<ArbitrumLightClient>(
+                &client_state(1),
+                ClientId!(1),
+                L1Client::Status(Status::Active)
+            ),
+            Status::Frozen
+        );
+    }
+}
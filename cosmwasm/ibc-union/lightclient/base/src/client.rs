@@ -1,4 +1,4 @@
-use base_light_client_types::{ClientState, ConsensusState, Header};
+use base_light_client_types::{ClientState, ConsensusState, Header, Misbehaviour};
 use cosmwasm_std::{Addr, Empty};
 use ethereum_light_client::client::EthereumLightClient;
 use ethereum_light_client_types::StorageProof;
@@ -17,7 +17,7 @@ impl IbcClient for BaseLightClient {
 
     type Header = Header;
 
-    type Misbehaviour = ();
+    type Misbehaviour = Misbehaviour;
 
     type ClientState = ClientState;
 
@@ -75,13 +75,15 @@ impl IbcClient for BaseLightClient {
         client_state.chain_id.to_string()
     }
 
-    fn status(ctx: IbcClientCtx<Self>, client_state: &Self::ClientState) -> Status {
-        let _ = client_state;
-        let _ = ctx;
-        // FIXME: expose the ctx to this call to allow threading this call to L1
-        // client. generally, we want to thread if a client is an L2 so always
-        // provide the ctx?
-        Status::Active
+    fn status(
+        ctx: IbcClientCtx<Self>,
+        ClientState::V1(client_state): &Self::ClientState,
+    ) -> Status {
+        if client_state.frozen_height != 0 {
+            Status::Frozen
+        } else {
+            ctx.l1_status::<EthereumLightClient>(client_state.l1_client_id)
+        }
     }
 
     fn verify_creation(
@@ -111,7 +113,11 @@ impl IbcClient for BaseLightClient {
         base_verifier::verify_header(&client_state, &header, l1_consensus_state.state_root)
             .map_err(Into::<Error>::into)?;
 
-        let update_height = header.l2_header.number.try_into().expect("impossible");
+        let update_height = header
+            .l2_header
+            .number
+            .try_into()
+            .map_err(|()| Error::L2HeightTooLarge(header.l2_header.number))?;
 
         let consensus_state = ConsensusState {
             timestamp: Timestamp::from_secs(header.l2_header.timestamp),
@@ -130,11 +136,85 @@ impl IbcClient for BaseLightClient {
     }
 
     fn misbehaviour(
-        _ctx: IbcClientCtx<Self>,
+        ctx: IbcClientCtx<Self>,
         _caller: Addr,
-        _misbehaviour: Self::Misbehaviour,
+        misbehaviour: Self::Misbehaviour,
         _relayer: Addr,
     ) -> Result<Self::ClientState, IbcClientError<Self>> {
-        Err(Error::Unimplemented.into())
+        let ClientState::V1(mut client_state) = ctx.read_self_client_state()?;
+
+        let l1_consensus_state_a = ctx
+            .read_consensus_state::<EthereumLightClient>(
+                client_state.l1_client_id,
+                misbehaviour.header_a.l1_height,
+            )
+            .map_err(Into::<Error>::into)?;
+
+        let l1_consensus_state_b = ctx
+            .read_consensus_state::<EthereumLightClient>(
+                client_state.l1_client_id,
+                misbehaviour.header_b.l1_height,
+            )
+            .map_err(Into::<Error>::into)?;
+
+        base_verifier::verify_misbehaviour(
+            &client_state,
+            &misbehaviour.header_a,
+            l1_consensus_state_a.state_root,
+            &misbehaviour.header_b,
+            l1_consensus_state_b.state_root,
+        )
+        .map_err(Into::<Error>::into)?;
+
+        client_state.frozen_height = misbehaviour
+            .header_a
+            .l2_header
+            .number
+            .try_into()
+            .map_err(|()| Error::L2HeightTooLarge(misbehaviour.header_a.l2_header.number))?;
+
+        Ok(ClientState::V1(client_state))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base_light_client_types::ClientStateV1;
+    use ibc_union_light_client::{
+        spec::ClientId,
+        test_utils::{assert_status_follows_l1_client, client_status, L1Client},
+    };
+    use unionlabs::primitives::{H160, U256};
+
+    use super::*;
+
+    fn client_state(frozen_height: u64) -> ClientState {
+        ClientState::V1(ClientStateV1 {
+            chain_id: U256::from(8453_u64),
+            latest_height: 1,
+            l1_client_id: ClientId!(1),
+            dispute_game_factory_address: H160::default(),
+            dispute_game_factory_dispute_game_list_slot: U256::from(0_u64),
+            fault_dispute_game_code_root_claim_index: 0,
+            frozen_height,
+            ibc_contract_address: H160::default(),
+        })
+    }
+
+    #[test]
+    fn status_follows_l1_client() {
+        assert_status_follows_l1_client::<BaseLightClient>(&client_state(0), ClientId!(1));
+    }
+
+    #[test]
+    fn status_frozen_if_frozen() {
+        assert_eq!(
+            client_status::<BaseLightClient>(
+                &client_state(1),
+                ClientId!(1),
+                L1Client::Status(Status::Active)
+            ),
+            Status::Frozen
+        );
     }
 }
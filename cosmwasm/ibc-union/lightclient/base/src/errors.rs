@@ -1,13 +1,11 @@
 use ethereum_light_client::client::EthereumLightClient;
 use ibc_union_light_client::IbcClientError;
+use unionlabs::primitives::U256;
 
 use crate::client::BaseLightClient;
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
-    #[error("unimplemented")]
-    Unimplemented,
-
     #[error(transparent)]
     Verify(#[from] base_verifier::Error),
 
@@ -16,6 +14,9 @@ pub enum Error {
 
     #[error(transparent)]
     EvmIbcClient(#[from] IbcClientError<EthereumLightClient>),
+
+    #[error("the l2 height {0} is too large (> u64::MAX)")]
+    L2HeightTooLarge(U256),
 }
 
 // required for IbcClient trait
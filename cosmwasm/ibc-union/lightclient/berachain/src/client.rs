@@ -81,12 +81,7 @@ impl IbcClient for BerachainLightClient {
     }
 
     fn status(ctx: IbcClientCtx<Self>, client_state: &Self::ClientState) -> Status {
-        let _ = client_state;
-        let _ = ctx;
-        // FIXME: expose the ctx to this call to allow threading this call to L1
-        // client. generally, we want to thread if a client is an L2 so always
-        // provide the ctx?
-        Status::Active
+        ctx.l1_status::<TendermintLightClient>(client_state.l1_client_id)
     }
 
     fn verify_creation(
@@ -170,3 +165,25 @@ impl IbcClient for BerachainLightClient {
         Err(Error::Unimplemented.into())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ibc_union_light_client::{spec::ClientId, test_utils::assert_status_follows_l1_client};
+    use unionlabs::primitives::{H160, U256};
+
+    use super::*;
+
+    fn client_state() -> ClientState {
+        ClientState {
+            l1_client_id: ClientId!(1),
+            chain_id: U256::from(80094_u64),
+            latest_height: 1,
+            ibc_contract_address: H160::default(),
+        }
+    }
+
+    #[test]
+    fn status_follows_l1_client() {
+        assert_status_follows_l1_client::<BerachainLightClient>(&client_state(), ClientId!(1));
+    }
+}
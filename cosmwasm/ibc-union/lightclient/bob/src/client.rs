@@ -1,4 +1,4 @@
-use bob_light_client_types::{ClientState, ConsensusState, Header};
+use bob_light_client_types::{ClientState, ConsensusState, Header, Misbehaviour};
 use cosmwasm_std::{Addr, Empty};
 use ethereum_light_client::client::EthereumLightClient;
 use ethereum_light_client_types::StorageProof;
@@ -17,7 +17,7 @@ impl IbcClient for BobLightClient {
 
     type Header = Header;
 
-    type Misbehaviour = ();
+    type Misbehaviour = Misbehaviour;
 
     type ClientState = ClientState;
 
@@ -82,12 +82,16 @@ impl IbcClient for BobLightClient {
     }
 
     fn status(ctx: IbcClientCtx<Self>, client_state: &Self::ClientState) -> Status {
-        let _ = client_state;
-        let _ = ctx;
-        // FIXME: expose the ctx to this call to allow threading this call to L1
-        // client. generally, we want to thread if a client is an L2 so always
-        // provide the ctx?
-        Status::Active
+        let (frozen_height, l1_client_id) = match client_state {
+            ClientState::V1(v1) => (v1.frozen_height, v1.l1_client_id),
+            ClientState::V2(v2) => (v2.frozen_height, v2.l1_client_id),
+        };
+
+        if frozen_height != 0 {
+            Status::Frozen
+        } else {
+            ctx.l1_status::<EthereumLightClient>(l1_client_id)
+        }
     }
 
     fn verify_creation(
@@ -119,7 +123,11 @@ impl IbcClient for BobLightClient {
         bob_verifier::verify_header(&client_state, &header, l1_consensus_state.state_root)
             .map_err(Into::<Error>::into)?;
 
-        let update_height = header.l2_header.number.try_into().expect("impossible");
+        let update_height = header
+            .l2_header
+            .number
+            .try_into()
+            .map_err(|()| Error::L2HeightTooLarge(header.l2_header.number))?;
 
         let consensus_state = ConsensusState {
             timestamp: Timestamp::from_secs(header.l2_header.timestamp),
@@ -138,11 +146,103 @@ impl IbcClient for BobLightClient {
     }
 
     fn misbehaviour(
-        _ctx: IbcClientCtx<Self>,
+        ctx: IbcClientCtx<Self>,
         _caller: Addr,
-        _misbehaviour: Self::Misbehaviour,
+        misbehaviour: Self::Misbehaviour,
         _relayer: Addr,
     ) -> Result<Self::ClientState, IbcClientError<Self>> {
-        Err(Error::Unimplemented.into())
+        let ClientState::V2(mut client_state) = ctx.read_self_client_state()? else {
+            panic!("impossible")
+        };
+
+        let l1_consensus_state_a = ctx
+            .read_consensus_state::<EthereumLightClient>(
+                client_state.l1_client_id,
+                misbehaviour.header_a.l1_height,
+            )
+            .map_err(Into::<Error>::into)?;
+
+        let l1_consensus_state_b = ctx
+            .read_consensus_state::<EthereumLightClient>(
+                client_state.l1_client_id,
+                misbehaviour.header_b.l1_height,
+            )
+            .map_err(Into::<Error>::into)?;
+
+        bob_verifier::verify_misbehaviour(
+            &client_state,
+            &misbehaviour.header_a,
+            l1_consensus_state_a.state_root,
+            &misbehaviour.header_b,
+            l1_consensus_state_b.state_root,
+        )
+        .map_err(Into::<Error>::into)?;
+
+        client_state.frozen_height = misbehaviour
+            .header_a
+            .l2_header
+            .number
+            .try_into()
+            .map_err(|()| Error::L2HeightTooLarge(misbehaviour.header_a.l2_header.number))?;
+
+        Ok(ClientState::V2(client_state))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bob_light_client_types::{ClientStateV1, ClientStateV2};
+    use ibc_union_light_client::{
+        spec::ClientId,
+        test_utils::{assert_status_follows_l1_client, client_status, L1Client},
+    };
+    use unionlabs::primitives::{H160, U256};
+
+    use super::*;
+
+    fn client_state_v1(frozen_height: u64) -> ClientState {
+        ClientState::V1(ClientStateV1 {
+            chain_id: U256::from(60808_u64),
+            latest_height: 1,
+            l1_client_id: ClientId!(1),
+            l2_oracle_address: H160::default(),
+            l2_oracle_l2_outputs_slot: U256::from(0_u64),
+            frozen_height,
+            ibc_contract_address: H160::default(),
+        })
+    }
+
+    fn client_state_v2(frozen_height: u64) -> ClientState {
+        ClientState::V2(ClientStateV2 {
+            chain_id: U256::from(60808_u64),
+            latest_height: 1,
+            l1_client_id: ClientId!(1),
+            dispute_game_factory_address: H160::default(),
+            dispute_game_factory_dispute_game_list_slot: U256::from(0_u64),
+            fault_dispute_game_code_root_claim_index: 0,
+            frozen_height,
+            ibc_contract_address: H160::default(),
+        })
+    }
+
+    #[test]
+    fn status_follows_l1_client() {
+        for client_state in [client_state_v1(0), client_state_v2(0)] {
+            assert_status_follows_l1_client::<BobLightClient>(&client_state, ClientId!(1));
+        }
+    }
+
+    #[test]
+    fn status_frozen_if_frozen() {
+        for client_state in [client_state_v1(1), client_state_v2(1)] {
+            assert_eq!(
+                client_status::<BobLightClient>(
+                    &client_state,
+                    ClientId!(1),
+                    L1Client::Status(Status::Active)
+                ),
+                Status::Frozen
+            );
+        }
     }
 }
@@ -1,19 +1,20 @@
 use beacon_api_types::{
     chain_spec::{ChainSpec, Mainnet, Minimal, PresetBaseKind},
-    custom_types::Period,
+    custom_types::{Period, Slot},
 };
 use cosmwasm_std::{Addr, Empty, StdError, StdResult};
 use depolama::{KeyCodec, Prefix, Store, ValueCodec};
 use ethereum_light_client_types::{
-    ClientState, ClientStateV1, ConsensusState, Header, LightClientUpdate, Misbehaviour,
+    ClientState, ClientStateV2, ConsensusState, Header, LightClientUpdate, Misbehaviour,
     StorageProof,
 };
 use ethereum_sync_protocol::{
+    is_better_update, update_timeout,
     utils::{
         compute_slot_at_timestamp, compute_sync_committee_period_at_slot,
         validate_signature_supermajority,
     },
-    validate_light_client_update,
+    validate_light_client_optimistic_update, validate_light_client_update,
 };
 use evm_storage_verifier::{
     verify_account_storage_root, verify_storage_absence, verify_storage_proof,
@@ -90,12 +91,14 @@ impl IbcClient for EthereumLightClient {
     fn get_latest_height(client_state: &Self::ClientState) -> u64 {
         match client_state {
             ClientState::V1(cs) => cs.latest_height,
+            ClientState::V2(cs) => cs.latest_height,
         }
     }
 
     fn get_counterparty_chain_id(client_state: &Self::ClientState) -> String {
         match client_state {
             ClientState::V1(cs) => cs.chain_id.to_string(),
+            ClientState::V2(cs) => cs.chain_id.to_string(),
         }
     }
 
@@ -104,6 +107,7 @@ impl IbcClient for EthereumLightClient {
 
         if match client_state {
             ClientState::V1(cs) => cs.frozen_height,
+            ClientState::V2(cs) => cs.frozen_height,
         }
         .height()
             != 0
@@ -120,13 +124,12 @@ impl IbcClient for EthereumLightClient {
         consensus_state: &Self::ConsensusState,
         _relayer: Addr,
     ) -> Result<ClientCreationResult<Self>, IbcClientError<Self>> {
-        let ClientState::V1(client_state) = client_state;
-        let Some(initial_sync_committee) = client_state.initial_sync_committee.as_ref() else {
+        // V1 client states are always stored as V2, with optimistic and force updates disabled.
+        let mut client_state = ClientStateV2::from(client_state.clone());
+        // We only require this at the creation phase. The client then manages the committees in a separate storage.
+        let Some(initial_sync_committee) = client_state.initial_sync_committee.take() else {
             return Err(Error::NoInitialSyncCommittee.into());
         };
-        let mut client_state = client_state.clone();
-        // We only require this at the creation phase. The client then manages the committees in a separate storage.
-        client_state.initial_sync_committee = None;
 
         let current_sync_period = if client_state.chain_spec == PresetBaseKind::Minimal {
             compute_sync_committee_period_at_slot::<Minimal>(consensus_state.slot)
@@ -135,9 +138,11 @@ impl IbcClient for EthereumLightClient {
         };
 
         // Set the client state so that it overwrites the one that is passed.
-        // Also save the current and next sync committees with the corresponding epoch numbers.
+        // Also save the finalized slot, and the current and next sync committees with the
+        // corresponding epoch numbers.
         Ok(ClientCreationResult::new()
-            .overwrite_client_state(ClientState::V1(client_state))
+            .overwrite_client_state(ClientState::V2(client_state))
+            .add_storage_write::<FinalizedSlotStore>((), consensus_state.slot)
             .add_storage_write::<SyncCommitteeStore>(
                 current_sync_period,
                 InverseSyncCommittee::take_inverse(&initial_sync_committee.current_sync_committee),
@@ -154,35 +159,16 @@ impl IbcClient for EthereumLightClient {
         header: Header,
         _relayer: Addr,
     ) -> Result<StateUpdate<Self>, IbcClientError<Self>> {
-        let ClientState::V1(client_state) = ctx.read_self_client_state()?;
+        let client_state = ClientStateV2::from(ctx.read_self_client_state()?);
         let consensus_state = ctx.read_self_consensus_state(header.trusted_height.height())?;
 
-        let finalized_slot = header
-            .consensus_update
-            .update_data()
-            .finalized_header
-            .beacon
-            .slot;
-
         match client_state.chain_spec {
-            PresetBaseKind::Minimal => verify_header::<Minimal>(
-                &ctx,
-                client_state,
-                consensus_state,
-                ctx.read_self_storage::<SyncCommitteeStore>(
-                    compute_sync_committee_period_at_slot::<Minimal>(finalized_slot),
-                )?,
-                header,
-            ),
-            PresetBaseKind::Mainnet => verify_header::<Mainnet>(
-                &ctx,
-                client_state,
-                consensus_state,
-                ctx.read_self_storage::<SyncCommitteeStore>(
-                    compute_sync_committee_period_at_slot::<Mainnet>(finalized_slot),
-                )?,
-                header,
-            ),
+            PresetBaseKind::Minimal => {
+                verify_any_header::<Minimal>(&ctx, client_state, consensus_state, header)
+            }
+            PresetBaseKind::Mainnet => {
+                verify_any_header::<Mainnet>(&ctx, client_state, consensus_state, header)
+            }
         }
     }
 
@@ -195,7 +181,7 @@ impl IbcClient for EthereumLightClient {
         let consensus_state =
             ctx.read_self_consensus_state(misbehaviour.trusted_height.height())?;
 
-        let ClientState::V1(mut client_state) = ctx.read_self_client_state()?;
+        let mut client_state = ClientStateV2::from(ctx.read_self_client_state()?);
 
         match client_state.chain_spec {
             PresetBaseKind::Minimal => {
@@ -208,7 +194,7 @@ impl IbcClient for EthereumLightClient {
 
         client_state.frozen_height = Height::new(1);
 
-        Ok(ClientState::V1(client_state))
+        Ok(ClientState::V2(client_state))
     }
 }
 
@@ -276,28 +262,78 @@ pub fn check_commitment_key(path: H256, key: U256) -> Result<(), Error> {
     }
 }
 
+/// Verify either a finality or an optimistic header, reading the sync committee of the relevant
+/// period from the client's storage.
+fn verify_any_header<C: ChainSpec>(
+    ctx: &IbcClientCtx<EthereumLightClient>,
+    client_state: ClientStateV2,
+    consensus_state: ConsensusState,
+    header: Header,
+) -> Result<StateUpdate<EthereumLightClient>, IbcClientError<EthereumLightClient>> {
+    match header.consensus_update.update_data() {
+        Some(update_data) => {
+            let sync_committee = ctx.read_self_storage::<SyncCommitteeStore>(
+                compute_sync_committee_period_at_slot::<C>(
+                    update_data.finalized_header.beacon.slot,
+                ),
+            )?;
+
+            verify_header::<C>(ctx, client_state, consensus_state, sync_committee, header)
+        }
+        None => {
+            ensure(
+                client_state.allow_optimistic_updates,
+                Error::OptimisticUpdatesNotAllowed,
+            )?;
+
+            // optimistic updates don't carry a next sync committee, so the signature is always
+            // verified with the sync committee of the signature period
+            let sync_committee = ctx.read_self_storage::<SyncCommitteeStore>(
+                compute_sync_committee_period_at_slot::<C>(
+                    header.consensus_update.signature_slot(),
+                ),
+            )?;
+
+            verify_optimistic_header::<C>(
+                ctx,
+                client_state,
+                consensus_state,
+                sync_committee,
+                header,
+            )
+        }
+    }
+}
+
 pub fn verify_header<C: ChainSpec>(
     ctx: &IbcClientCtx<EthereumLightClient>,
-    client_state: ClientStateV1,
+    client_state: ClientStateV2,
     consensus_state: ConsensusState,
     sync_committee: InverseSyncCommittee,
     header: Header,
 ) -> Result<StateUpdate<EthereumLightClient>, IbcClientError<EthereumLightClient>> {
+    let sync_committee = sync_committee.as_sync_committee();
+    let (current_sync_committee, next_sync_committee) = match header.consensus_update {
+        LightClientUpdate::SyncCommitteePeriodChange(_) => (None, Some(&sync_committee)),
+        LightClientUpdate::WithinSyncCommitteePeriod(_) => (Some(&sync_committee), None),
+        LightClientUpdate::Optimistic(_) => return Err(Error::UnexpectedOptimisticUpdate.into()),
+    };
+
     // NOTE(aeryz): Ethereum consensus-spec says that we should use the slot
     // at the current timestamp.
     let current_slot =
         compute_slot_at_timestamp::<C>(client_state.genesis_time, ctx.env.block.time.seconds())
             .ok_or(Error::IntegerOverflow)?;
 
-    let sync_committee = sync_committee.as_sync_committee();
-    let (current_sync_committee, next_sync_committee) = match header.consensus_update {
-        LightClientUpdate::SyncCommitteePeriodChange(_) => (None, Some(&sync_committee)),
-        LightClientUpdate::WithinSyncCommitteePeriod(_) => (Some(&sync_committee), None),
-    };
+    let update = header
+        .consensus_update
+        .clone()
+        .into_light_client_update()
+        .ok_or(Error::UnexpectedOptimisticUpdate)?;
 
     validate_light_client_update::<C, _>(
         client_state.chain_id,
-        &header.consensus_update.clone().into_light_client_update(),
+        &update,
         current_sync_committee,
         next_sync_committee,
         current_slot,
@@ -307,34 +343,193 @@ pub fn verify_header<C: ChainSpec>(
     )
     .map_err(Error::ValidateLightClient)?;
 
-    let update_data = header.consensus_update.update_data();
-
     // check whether at least 2/3 of the sync committee signed
+    if !validate_signature_supermajority(&update.sync_aggregate.sync_committee_bits) {
+        return process_best_valid_update::<C>(
+            ctx,
+            client_state,
+            consensus_state,
+            current_slot,
+            header,
+            update,
+        );
+    }
+
+    verify_ibc_account_proof(
+        &client_state,
+        update.finalized_header.execution.state_root,
+        &header,
+    )?;
+
+    update_state::<C>(ctx, client_state, consensus_state, header)
+}
+
+/// Handle a valid finality update that does not have a sync committee supermajority, following
+/// the `best_valid_update` semantics of the consensus spec light client store. This is only
+/// enabled for clients that opted in with [`ClientStateV2::allow_force_updates`].
+///
+/// - The update replaces the best valid update tracked for the client's latest finalized slot if
+///   it is better (see [`is_better_update`]).
+/// - If no finalized update has been applied for [`update_timeout`] slots after the latest
+///   finalized slot, the stored best valid update is force-applied and cleared. Otherwise, the
+///   client is not updated.
+///
+/// [See in consensus-spec](https://github.com/ethereum/consensus-specs/blob/dev/specs/altair/light-client/sync-protocol.md#process_light_client_store_force_update)
+fn process_best_valid_update<C: ChainSpec>(
+    ctx: &IbcClientCtx<EthereumLightClient>,
+    client_state: ClientStateV2,
+    consensus_state: ConsensusState,
+    current_slot: Slot,
+    header: Header,
+    update: ethereum_sync_protocol_types::LightClientUpdate,
+) -> Result<StateUpdate<EthereumLightClient>, IbcClientError<EthereumLightClient>> {
+    ensure(client_state.allow_force_updates, Error::NotEnoughSignatures)?;
+
+    let finalized_slot = ctx.read_self_storage::<FinalizedSlotStore>(())?;
+
+    let stored_best_valid_update = ctx
+        .maybe_read_self_storage::<BestValidUpdateStore>(finalized_slot)?
+        .flatten();
+
+    let is_better = match &stored_best_valid_update {
+        Some(best_valid_update) => is_better_update::<C>(
+            &update,
+            &best_valid_update
+                .consensus_update
+                .clone()
+                .into_light_client_update()
+                .ok_or(Error::UnexpectedOptimisticUpdate)?,
+        )
+        .map_err(Error::ValidateLightClient)?,
+        None => true,
+    };
+
+    if current_slot <= finalized_slot + Slot::new(update_timeout::<C>()) {
+        // the update timeout has not passed yet, only track this update as the best valid update
+        ensure(
+            is_better,
+            Error::NotBetterThanBestValidUpdate(finalized_slot),
+        )?;
+
+        return Ok(StateUpdate::storage_only(header.trusted_height.height())
+            .add_storage_write::<BestValidUpdateStore>(finalized_slot, Some(header)));
+    }
+
+    // the update timeout has passed, force-apply the best valid update (which is this update if it
+    // is better than the stored one)
+    let (consensus_state, mut best_valid_update) = match stored_best_valid_update {
+        Some(best_valid_update) if !is_better => (
+            ctx.read_self_consensus_state(best_valid_update.trusted_height.height())?,
+            best_valid_update,
+        ),
+        _ => (consensus_state, header),
+    };
+
+    // the finalized header of the update may not move the client forward, in which case the
+    // attested header is applied instead
+    let update_data = best_valid_update
+        .consensus_update
+        .update_data_mut()
+        .ok_or(Error::UnexpectedOptimisticUpdate)?;
+    if update_data.finalized_header.beacon.slot <= finalized_slot {
+        update_data.finalized_header = update_data.attested_header.clone();
+    }
+    let execution_state_root = update_data.finalized_header.execution.state_root;
+
+    verify_ibc_account_proof(&client_state, execution_state_root, &best_valid_update)?;
+
+    Ok(
+        update_state::<C>(ctx, client_state, consensus_state, best_valid_update)?
+            .add_storage_write::<BestValidUpdateStore>(finalized_slot, None),
+    )
+}
+
+/// Verify an optimistic header, i.e. a header that only contains an attested (but not finalized)
+/// beacon block header. The client is updated to the attested execution block.
+pub fn verify_optimistic_header<C: ChainSpec>(
+    ctx: &IbcClientCtx<EthereumLightClient>,
+    mut client_state: ClientStateV2,
+    consensus_state: ConsensusState,
+    sync_committee: InverseSyncCommittee,
+    header: Header,
+) -> Result<StateUpdate<EthereumLightClient>, IbcClientError<EthereumLightClient>> {
+    let LightClientUpdate::Optimistic(update) = &header.consensus_update else {
+        return Err(Error::ExpectedOptimisticUpdate.into());
+    };
+
+    let current_slot =
+        compute_slot_at_timestamp::<C>(client_state.genesis_time, ctx.env.block.time.seconds())
+            .ok_or(Error::IntegerOverflow)?;
+
+    validate_light_client_optimistic_update::<C, _>(
+        client_state.chain_id,
+        update,
+        &sync_committee.as_sync_committee(),
+        current_slot,
+        consensus_state.slot,
+        client_state.genesis_validators_root,
+        VerificationContext { deps: ctx.deps },
+    )
+    .map_err(Error::ValidateLightClient)?;
+
+    // since the attested header is not finalized, a supermajority is always required
     ensure(
-        validate_signature_supermajority(&update_data.sync_aggregate.sync_committee_bits),
+        validate_signature_supermajority(&update.sync_aggregate.sync_committee_bits),
         Error::NotEnoughSignatures,
     )?;
 
+    verify_ibc_account_proof(
+        &client_state,
+        update.attested_header.execution.state_root,
+        &header,
+    )?;
+
+    let updated_height = update.attested_header.execution.block_number;
+
+    let consensus_state = ConsensusState {
+        slot: update.attested_header.beacon.slot,
+        state_root: update.attested_header.execution.state_root,
+        storage_root: header.ibc_account_proof.storage_root,
+        timestamp: Timestamp::from_secs(update.attested_header.execution.timestamp),
+    };
+
+    let state_update = StateUpdate::new(updated_height, consensus_state);
+
+    if client_state.latest_height < updated_height {
+        client_state.latest_height = updated_height;
+        Ok(state_update.overwrite_client_state(ClientState::V2(client_state)))
+    } else {
+        Ok(state_update)
+    }
+}
+
+fn verify_ibc_account_proof(
+    client_state: &ClientStateV2,
+    execution_state_root: H256,
+    header: &Header,
+) -> Result<(), Error> {
     let proof_data = &header.ibc_account_proof;
 
     verify_account_storage_root(
-        update_data.finalized_header.execution.state_root,
+        execution_state_root,
         &client_state.ibc_contract_address,
         &proof_data.proof,
         &proof_data.storage_root,
     )
-    .map_err(Error::VerifyAccountStorageRoot)?;
-
-    update_state::<C>(client_state, consensus_state, header)
+    .map_err(Error::VerifyAccountStorageRoot)
 }
 
 fn update_state<C: ChainSpec>(
-    mut client_state: ClientStateV1,
+    ctx: &IbcClientCtx<EthereumLightClient>,
+    mut client_state: ClientStateV2,
     mut consensus_state: ConsensusState,
     mut header: Header,
 ) -> Result<StateUpdate<EthereumLightClient>, IbcClientError<EthereumLightClient>> {
     let trusted_height = header.trusted_height;
-    let consensus_update = header.consensus_update.update_data();
+    let consensus_update = header
+        .consensus_update
+        .update_data()
+        .ok_or(Error::UnexpectedOptimisticUpdate)?;
 
     // TODO(aeryz): we should ditch this functionality as it complicates the light client and we don't use it
     // Some updates can be only for updating the sync committee, therefore the slot number can be
@@ -360,7 +555,17 @@ fn update_state<C: ChainSpec>(
 
     let mut state_update = StateUpdate::new(updated_height, consensus_state);
     if client_state.latest_height == consensus_update.finalized_header.execution.block_number {
-        state_update = state_update.overwrite_client_state(ClientState::V1(client_state));
+        state_update = state_update.overwrite_client_state(ClientState::V2(client_state));
+    }
+
+    // the finalized slot is tracked separately from the consensus states, since optimistic updates
+    // also produce consensus states
+    let finalized_slot = consensus_update.finalized_header.beacon.slot;
+    if ctx
+        .maybe_read_self_storage::<FinalizedSlotStore>(())?
+        .is_none_or(|latest_finalized_slot| latest_finalized_slot < finalized_slot)
+    {
+        state_update = state_update.add_storage_write::<FinalizedSlotStore>((), finalized_slot);
     }
 
     if let LightClientUpdate::SyncCommitteePeriodChange(update) = &mut header.consensus_update {
@@ -378,24 +583,23 @@ fn update_state<C: ChainSpec>(
 
 pub fn verify_misbehaviour<C: ChainSpec>(
     ctx: &IbcClientCtx<EthereumLightClient>,
-    client_state: &ClientStateV1,
+    client_state: &ClientStateV2,
     consensus_state: ConsensusState,
     misbehaviour: Misbehaviour,
 ) -> Result<(), IbcClientError<EthereumLightClient>> {
+    // Optimistic updates don't carry a finalized header, so they can't be used as evidence of
+    // conflicting finalized headers
+    let (Some(update_1), Some(update_2)) = (
+        misbehaviour.update_1.clone().into_light_client_update(),
+        misbehaviour.update_2.clone().into_light_client_update(),
+    ) else {
+        return Err(Error::UnexpectedOptimisticUpdate.into());
+    };
+
     // There is no point to check for misbehaviour when the headers are not for the same height
     let (slot_1, slot_2) = (
-        misbehaviour
-            .update_1
-            .update_data()
-            .finalized_header
-            .beacon
-            .slot,
-        misbehaviour
-            .update_2
-            .update_data()
-            .finalized_header
-            .beacon
-            .slot,
+        update_1.finalized_header.beacon.slot,
+        update_2.finalized_header.beacon.slot,
     );
     ensure(
         slot_1 == slot_2,
@@ -403,8 +607,7 @@ pub fn verify_misbehaviour<C: ChainSpec>(
     )?;
 
     ensure(
-        misbehaviour.update_1.update_data().finalized_header
-            != misbehaviour.update_2.update_data().finalized_header,
+        update_1.finalized_header != update_2.finalized_header,
         Error::IdenticalMisbehaviourHeaders,
     )?;
 
@@ -420,12 +623,13 @@ pub fn verify_misbehaviour<C: ChainSpec>(
     let (current_sync_committee, next_sync_committee) = match misbehaviour.update_1 {
         LightClientUpdate::SyncCommitteePeriodChange(_) => (None, Some(&sync_committee)),
         LightClientUpdate::WithinSyncCommitteePeriod(_) => (Some(&sync_committee), None),
+        LightClientUpdate::Optimistic(_) => unreachable!("checked above; qed;"),
     };
 
     // Make sure both headers would have been accepted by the light client
     validate_light_client_update::<C, VerificationContext>(
         client_state.chain_id,
-        &misbehaviour.update_1.clone().into_light_client_update(),
+        &update_1,
         current_sync_committee,
         next_sync_committee,
         current_slot,
@@ -437,19 +641,13 @@ pub fn verify_misbehaviour<C: ChainSpec>(
 
     // check whether at least 2/3 of the sync committee signed
     ensure(
-        validate_signature_supermajority(
-            &misbehaviour
-                .update_1
-                .update_data()
-                .sync_aggregate
-                .sync_committee_bits,
-        ),
+        validate_signature_supermajority(&update_1.sync_aggregate.sync_committee_bits),
         Error::NotEnoughSignatures,
     )?;
 
     validate_light_client_update::<C, VerificationContext>(
         client_state.chain_id,
-        &misbehaviour.update_1.into_light_client_update(),
+        &update_2,
         current_sync_committee,
         next_sync_committee,
         current_slot,
@@ -460,13 +658,7 @@ pub fn verify_misbehaviour<C: ChainSpec>(
     .map_err(Error::ValidateLightClient)?;
 
     ensure(
-        validate_signature_supermajority(
-            &misbehaviour
-                .update_2
-                .update_data()
-                .sync_aggregate
-                .sync_committee_bits,
-        ),
+        validate_signature_supermajority(&update_2.sync_aggregate.sync_committee_bits),
         Error::NotEnoughSignatures,
     )?;
 
@@ -517,21 +709,92 @@ impl ValueCodec<InverseSyncCommittee> for SyncCommitteeStore {
     }
 }
 
+/// The slot of the latest finalized beacon block header that has been applied to the client.
+pub enum FinalizedSlotStore {}
+impl Store for FinalizedSlotStore {
+    const PREFIX: Prefix = Prefix::new(b"finalized_slot");
+
+    type Key = ();
+    type Value = Slot;
+}
+impl ValueCodec<Slot> for FinalizedSlotStore {
+    fn encode_value(value: &Slot) -> Bytes {
+        value.get().to_be_bytes().into()
+    }
+
+    fn decode_value(raw: &Bytes) -> StdResult<Slot> {
+        raw.try_into()
+            .map_err(|_| {
+                StdError::generic_err(format!(
+                    "invalid value: expected {N} bytes, found {}: {raw}",
+                    raw.len(),
+                    N = u64::BITS / 8,
+                ))
+            })
+            .map(u64::from_be_bytes)
+            .map(Slot::new)
+    }
+}
+
+/// The best valid update without a sync committee supermajority that has been seen since the
+/// client's latest finalized slot, keyed by that slot. See [`process_best_valid_update`].
+///
+/// Entries are cleared (stored as `None`) once they are force-applied. Entries for older finalized
+/// slots are never read again.
+pub enum BestValidUpdateStore {}
+impl Store for BestValidUpdateStore {
+    const PREFIX: Prefix = Prefix::new(b"best_valid_update");
+
+    type Key = Slot;
+    type Value = Option<Header>;
+}
+impl KeyCodec<Slot> for BestValidUpdateStore {
+    fn encode_key(key: &Slot) -> Bytes {
+        key.get().to_be_bytes().into()
+    }
+
+    fn decode_key(raw: &Bytes) -> StdResult<Slot> {
+        raw.try_into()
+            .map_err(|_| {
+                StdError::generic_err(format!(
+                    "invalid key: expected {N} bytes, found {}: {raw}",
+                    raw.len(),
+                    N = u64::BITS / 8,
+                ))
+            })
+            .map(u64::from_be_bytes)
+            .map(Slot::new)
+    }
+}
+impl ValueCodec<Option<Header>> for BestValidUpdateStore {
+    fn encode_value(value: &Option<Header>) -> Bytes {
+        value.encode_as::<Bincode>().into()
+    }
+
+    fn decode_value(raw: &Bytes) -> StdResult<Option<Header>> {
+        Option::<Header>::decode_as::<Bincode>(raw)
+            .map_err(|e| StdError::generic_err(format!("unable to decode header: {e}")))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::sync::LazyLock;
 
     use beacon_api_types::{altair::SyncCommittee, electra};
     use cosmwasm_std::{
-        testing::{mock_dependencies, mock_env},
-        Addr,
+        testing::{mock_dependencies, mock_env, MockApi, MockQuerier, MockStorage},
+        Addr, Deps, OwnedDeps,
     };
     use ethereum_light_client_types::{
-        client_state::InitialSyncCommittee, AccountProof, LightClientUpdateData,
+        client_state::InitialSyncCommittee, AccountProof, ClientStateV1, LightClientUpdateData,
         SyncCommitteePeriodChangeUpdate, WithinSyncCommitteePeriodUpdate,
     };
     use ethereum_sync_protocol::utils::compute_timestamp_at_slot;
+    use ethereum_sync_protocol_types::LightClientOptimisticUpdate;
     use hex_literal::hex;
+    use ibc_union_light_client::test_utils::MockIbcHost;
+    use ibc_union_msg::lightclient::StorageWrites;
     use unionlabs::primitives::H160;
 
     use super::*;
@@ -573,8 +836,8 @@ mod tests {
         serde_json::from_str(include_str!("./test/account_proof_7168000.json")).unwrap()
     });
 
-    fn initial_client_state(latest_height: u64) -> ClientState {
-        ClientState::V1(ClientStateV1 {
+    fn initial_client_state(latest_height: u64) -> ClientStateV2 {
+        ClientStateV2 {
             chain_id: SEPOLIA_CHAIN_ID,
             chain_spec: PresetBaseKind::Mainnet,
             genesis_validators_root: SEPOLIA_GENESIS_VALIDATORS_ROOT,
@@ -583,17 +846,13 @@ mod tests {
             frozen_height: Height::default(),
             ibc_contract_address: IBC_CONTRACT_ADDRESS,
             initial_sync_committee: None,
-        })
+            allow_optimistic_updates: false,
+            allow_force_updates: false,
+        }
     }
 
-    #[test]
-    fn verify_creation_works() {
-        let ClientState::V1(mut client_state) = initial_client_state(100);
-        client_state.initial_sync_committee = Some(InitialSyncCommittee {
-            current_sync_committee: CURRENT_SYNC_COMMITTEE.clone(),
-            next_sync_committee: NEXT_SYNC_COMMITTEE.clone(),
-        });
-        let consensus_state = ConsensusState {
+    fn initial_consensus_state() -> ConsensusState {
+        ConsensusState {
             slot: INITIAL_HEADER.slot,
             state_root: INITIAL_HEADER.state_root,
             storage_root: INITIAL_STORAGE_HASH,
@@ -601,22 +860,71 @@ mod tests {
                 SEPOLIA_GENESIS_TIME,
                 INITIAL_HEADER.slot,
             )),
-        };
+        }
+    }
+
+    /// Mock the client store of client 1 in the ibc host `ibc_host`, after it has been created with
+    /// (or updated to) `finalized_slot`.
+    fn mock_deps(
+        ibc_host: &Addr,
+        finalized_slot: Slot,
+        consensus_states: Vec<(u64, ConsensusState)>,
+        storage_writes: StorageWrites,
+    ) -> OwnedDeps<MockStorage, MockApi, MockQuerier> {
+        let client_id = 1.try_into().unwrap();
+
+        let ibc_host = consensus_states.into_iter().fold(
+            MockIbcHost::new(ibc_host.clone()).add_storage_writes(
+                client_id,
+                StateUpdate::<EthereumLightClient>::new(0, initial_consensus_state())
+                    .add_storage_write::<FinalizedSlotStore>((), finalized_slot)
+                    .storage_writes
+                    .into_iter()
+                    .chain(storage_writes)
+                    .collect(),
+            ),
+            |ibc_host, (height, consensus_state)| {
+                ibc_host.add_consensus_state::<EthereumLightClient>(
+                    client_id,
+                    height,
+                    consensus_state,
+                )
+            },
+        );
+
+        let mut deps = mock_dependencies();
+        deps.querier.update_wasm(ibc_host.querier());
+        deps
+    }
+
+    #[test]
+    fn verify_creation_works() {
+        let mut client_state = initial_client_state(100);
+        client_state.initial_sync_committee = Some(InitialSyncCommittee {
+            current_sync_committee: CURRENT_SYNC_COMMITTEE.clone(),
+            next_sync_committee: NEXT_SYNC_COMMITTEE.clone(),
+        });
+        let consensus_state = initial_consensus_state();
 
         let res = EthereumLightClient::verify_creation(
             Addr::unchecked("hello"),
-            &ClientState::V1(client_state.clone()),
+            &ClientState::V2(client_state.clone()),
             &consensus_state,
             Addr::unchecked("hello"),
         )
         .unwrap();
 
         client_state.initial_sync_committee = None;
-        assert_eq!(res.client_state, Some(ClientState::V1(client_state)));
+        assert_eq!(res.client_state, Some(ClientState::V2(client_state)));
 
         assert!(res.events.is_empty());
 
-        assert_eq!(res.storage_writes.len(), 2);
+        assert_eq!(res.storage_writes.len(), 3);
+        assert_eq!(
+            res.storage_writes
+                .get(&depolama::raw_key::<FinalizedSlotStore>(&())),
+            Some(&FinalizedSlotStore::encode_value(&consensus_state.slot)),
+        );
         let current_period = compute_sync_committee_period_at_slot::<Mainnet>(consensus_state.slot);
         assert_eq!(
             res.storage_writes
@@ -642,21 +950,68 @@ mod tests {
     }
 
     #[test]
-    fn verify_within_period_update() {
-        let consensus_state = ConsensusState {
-            slot: INITIAL_HEADER.slot,
-            state_root: INITIAL_HEADER.state_root,
-            storage_root: INITIAL_STORAGE_HASH,
-            timestamp: Timestamp::from_secs(compute_timestamp_at_slot::<Mainnet>(
-                SEPOLIA_GENESIS_TIME,
-                INITIAL_HEADER.slot,
+    fn verify_creation_stores_v1_as_v2() {
+        let ClientStateV2 {
+            chain_id,
+            chain_spec,
+            genesis_validators_root,
+            genesis_time,
+            latest_height,
+            frozen_height,
+            ibc_contract_address,
+            ..
+        } = initial_client_state(100);
+
+        let res = EthereumLightClient::verify_creation(
+            Addr::unchecked("hello"),
+            &ClientState::V1(ClientStateV1 {
+                chain_id,
+                chain_spec,
+                genesis_validators_root,
+                genesis_time,
+                latest_height,
+                frozen_height,
+                ibc_contract_address,
+                initial_sync_committee: Some(InitialSyncCommittee {
+                    current_sync_committee: CURRENT_SYNC_COMMITTEE.clone(),
+                    next_sync_committee: NEXT_SYNC_COMMITTEE.clone(),
+                }),
+            }),
+            &initial_consensus_state(),
+            Addr::unchecked("hello"),
+        )
+        .unwrap();
+
+        assert_eq!(
+            res.client_state,
+            Some(ClientState::V2(initial_client_state(100)))
+        );
+    }
+
+    fn finality_update_header(trusted_height: u64) -> Header {
+        Header {
+            trusted_height: Height::new(trusted_height),
+            consensus_update: LightClientUpdate::WithinSyncCommitteePeriod(Box::new(
+                WithinSyncCommitteePeriodUpdate {
+                    update_data: FINALITY_UPDATE.clone(),
+                },
             )),
-        };
+            ibc_account_proof: AccountProof {
+                storage_root: FINALITY_UPDATE_ACCOUNT_STORAGE_ROOT,
+                proof: FINALITY_UPDATE_ACCOUNT_PROOF.clone(),
+            },
+        }
+    }
+
+    #[test]
+    fn verify_within_period_update() {
+        let consensus_state = initial_consensus_state();
 
-        let ClientState::V1(mut client_state) =
+        let mut client_state =
             initial_client_state(FINALITY_UPDATE.finalized_header.execution.block_number - 1);
 
-        let deps = mock_dependencies();
+        let ibc_host = Addr::unchecked("hey bro");
+        let deps = mock_deps(&ibc_host, consensus_state.slot, vec![], Default::default());
         let mut env = mock_env();
         env.block.time = cosmwasm_std::Timestamp::from_seconds(
             FINALITY_UPDATE.attested_header.execution.timestamp + 24,
@@ -664,25 +1019,14 @@ mod tests {
         let state_update = verify_header::<Mainnet>(
             &IbcClientCtx {
                 client_id: 1.try_into().unwrap(),
-                ibc_host: Addr::unchecked("hey bro"),
+                ibc_host,
                 deps: deps.as_ref(),
                 env,
             },
             client_state.clone(),
             consensus_state,
             InverseSyncCommittee::take_inverse(&CURRENT_SYNC_COMMITTEE),
-            Header {
-                trusted_height: Height::new(client_state.latest_height),
-                consensus_update: LightClientUpdate::WithinSyncCommitteePeriod(Box::new(
-                    WithinSyncCommitteePeriodUpdate {
-                        update_data: FINALITY_UPDATE.clone(),
-                    },
-                )),
-                ibc_account_proof: AccountProof {
-                    storage_root: FINALITY_UPDATE_ACCOUNT_STORAGE_ROOT,
-                    proof: FINALITY_UPDATE_ACCOUNT_PROOF.clone(),
-                },
-            },
+            finality_update_header(client_state.latest_height),
         )
         .unwrap();
 
@@ -697,10 +1041,10 @@ mod tests {
 
         assert_eq!(
             state_update.client_state,
-            Some(ClientState::V1(client_state))
+            Some(ClientState::V2(client_state))
         );
 
-        assert_eq!(state_update.consensus_state, consensus_state);
+        assert_eq!(state_update.consensus_state, Some(consensus_state));
 
         assert_eq!(
             state_update.height,
@@ -708,7 +1052,101 @@ mod tests {
         );
 
         // No sync committee write because this is within the sync committee period, so no new sync committee to be written
-        assert!(state_update.storage_writes.is_empty());
+        assert_eq!(state_update.storage_writes.len(), 1);
+        assert_eq!(
+            state_update
+                .storage_writes
+                .get(&depolama::raw_key::<FinalizedSlotStore>(&())),
+            Some(&FinalizedSlotStore::encode_value(
+                &FINALITY_UPDATE.finalized_header.beacon.slot
+            )),
+        );
+    }
+
+    fn optimistic_update_header(client_state: &ClientStateV2) -> Header {
+        Header {
+            trusted_height: Height::new(client_state.latest_height),
+            consensus_update: LightClientUpdate::Optimistic(Box::new(
+                LightClientOptimisticUpdate {
+                    attested_header: FINALITY_UPDATE.attested_header.clone(),
+                    sync_aggregate: FINALITY_UPDATE.sync_aggregate.clone(),
+                    signature_slot: FINALITY_UPDATE.signature_slot,
+                },
+            )),
+            ibc_account_proof: AccountProof {
+                storage_root: FINALITY_UPDATE_ACCOUNT_STORAGE_ROOT,
+                proof: FINALITY_UPDATE_ACCOUNT_PROOF.clone(),
+            },
+        }
+    }
+
+    #[test]
+    fn verify_optimistic_update_verifies_attested_header() {
+        let consensus_state = initial_consensus_state();
+
+        let mut client_state =
+            initial_client_state(FINALITY_UPDATE.finalized_header.execution.block_number - 1);
+        client_state.allow_optimistic_updates = true;
+
+        let deps = mock_dependencies();
+        let mut env = mock_env();
+        env.block.time = cosmwasm_std::Timestamp::from_seconds(
+            FINALITY_UPDATE.attested_header.execution.timestamp + 24,
+        );
+
+        let result = verify_optimistic_header::<Mainnet>(
+            &IbcClientCtx {
+                client_id: 1.try_into().unwrap(),
+                ibc_host: Addr::unchecked("hey bro"),
+                deps: deps.as_ref(),
+                env,
+            },
+            client_state.clone(),
+            consensus_state,
+            InverseSyncCommittee::take_inverse(&CURRENT_SYNC_COMMITTEE),
+            optimistic_update_header(&client_state),
+        );
+
+        // the sync committee signature over the attested header is valid, but the account proof is
+        // for the finalized execution block and not the attested one
+        assert!(matches!(
+            result,
+            Err(IbcClientError::ClientSpecific(
+                Error::VerifyAccountStorageRoot(_)
+            ))
+        ));
+    }
+
+    #[test]
+    fn verify_header_rejects_optimistic_update() {
+        let client_state =
+            initial_client_state(FINALITY_UPDATE.finalized_header.execution.block_number - 1);
+
+        let deps = mock_dependencies();
+        let result = verify_header::<Mainnet>(
+            &IbcClientCtx {
+                client_id: 1.try_into().unwrap(),
+                ibc_host: Addr::unchecked("hey bro"),
+                deps: deps.as_ref(),
+                env: mock_env(),
+            },
+            client_state.clone(),
+            ConsensusState {
+                slot: INITIAL_HEADER.slot,
+                state_root: INITIAL_HEADER.state_root,
+                storage_root: INITIAL_STORAGE_HASH,
+                timestamp: Timestamp::from_secs(0),
+            },
+            InverseSyncCommittee::take_inverse(&CURRENT_SYNC_COMMITTEE),
+            optimistic_update_header(&client_state),
+        );
+
+        assert!(matches!(
+            result,
+            Err(IbcClientError::ClientSpecific(
+                Error::UnexpectedOptimisticUpdate
+            ))
+        ));
     }
 
     fn construct_light_client_update_data(
@@ -739,10 +1177,11 @@ mod tests {
             )),
         };
 
-        let ClientState::V1(mut client_state) =
+        let mut client_state =
             initial_client_state(FINALITY_UPDATE.finalized_header.execution.block_number);
 
-        let deps = mock_dependencies();
+        let ibc_host = Addr::unchecked("hey bro");
+        let deps = mock_deps(&ibc_host, consensus_state.slot, vec![], Default::default());
         let mut env = mock_env();
         env.block.time = cosmwasm_std::Timestamp::from_seconds(
             PERIOD_CHANGING_UPDATE.attested_header.execution.timestamp + 24,
@@ -750,7 +1189,7 @@ mod tests {
         let state_update = verify_header::<Mainnet>(
             &IbcClientCtx {
                 client_id: 1.try_into().unwrap(),
-                ibc_host: Addr::unchecked("hey bro"),
+                ibc_host,
                 deps: deps.as_ref(),
                 env,
             },
@@ -794,10 +1233,10 @@ mod tests {
 
         assert_eq!(
             state_update.client_state,
-            Some(ClientState::V1(client_state))
+            Some(ClientState::V2(client_state))
         );
 
-        assert_eq!(state_update.consensus_state, consensus_state);
+        assert_eq!(state_update.consensus_state, Some(consensus_state));
 
         assert_eq!(
             state_update.height,
@@ -810,7 +1249,7 @@ mod tests {
         let next_sync_period = compute_sync_committee_period_at_slot::<Mainnet>(
             PERIOD_CHANGING_UPDATE.finalized_header.beacon.slot,
         ) + 1;
-        assert_eq!(state_update.storage_writes.len(), 1);
+        assert_eq!(state_update.storage_writes.len(), 2);
         assert_eq!(
             state_update
                 .storage_writes
@@ -823,6 +1262,191 @@ mod tests {
         );
     }
 
+    /// A header with the same update as [`finality_update_header`], but with less sync committee
+    /// participation and an invalid ibc account proof.
+    fn worse_finality_update_header(trusted_height: u64) -> Header {
+        let mut header = finality_update_header(trusted_height);
+        header
+            .consensus_update
+            .update_data_mut()
+            .unwrap()
+            .sync_aggregate
+            .sync_committee_bits = vec![0x0F; 64].into();
+        header.ibc_account_proof.proof = vec![];
+        header
+    }
+
+    fn process_best_valid_update_at(
+        deps: Deps,
+        client_state: ClientStateV2,
+        current_slot: Slot,
+        header: Header,
+    ) -> Result<StateUpdate<EthereumLightClient>, IbcClientError<EthereumLightClient>> {
+        let update = header
+            .consensus_update
+            .clone()
+            .into_light_client_update()
+            .unwrap();
+
+        process_best_valid_update::<Mainnet>(
+            &IbcClientCtx {
+                client_id: 1.try_into().unwrap(),
+                ibc_host: Addr::unchecked("hey bro"),
+                deps,
+                env: mock_env(),
+            },
+            client_state,
+            initial_consensus_state(),
+            current_slot,
+            header,
+            update,
+        )
+    }
+
+    #[test]
+    fn force_updates_require_opt_in() {
+        let trusted_height = FINALITY_UPDATE.finalized_header.execution.block_number - 1;
+
+        let result = process_best_valid_update_at(
+            mock_dependencies().as_ref(),
+            initial_client_state(trusted_height),
+            INITIAL_HEADER.slot + Slot::new(update_timeout::<Mainnet>() + 1),
+            finality_update_header(trusted_height),
+        );
+
+        assert!(matches!(
+            result,
+            Err(IbcClientError::ClientSpecific(Error::NotEnoughSignatures))
+        ));
+    }
+
+    #[test]
+    fn best_valid_update_is_tracked_until_timeout() {
+        let trusted_height = FINALITY_UPDATE.finalized_header.execution.block_number - 1;
+        let mut client_state = initial_client_state(trusted_height);
+        client_state.allow_force_updates = true;
+
+        // the trusted slot is older than the update timeout, but the latest finalized slot isn't
+        let finalized_slot = INITIAL_HEADER.slot + Slot::new(100);
+
+        let deps = mock_deps(
+            &Addr::unchecked("hey bro"),
+            finalized_slot,
+            vec![],
+            Default::default(),
+        );
+
+        let header = finality_update_header(trusted_height);
+        let state_update = process_best_valid_update_at(
+            deps.as_ref(),
+            client_state,
+            INITIAL_HEADER.slot + Slot::new(update_timeout::<Mainnet>() + 1),
+            header.clone(),
+        )
+        .unwrap();
+
+        // the client is not updated, the update is only stored
+        assert_eq!(state_update.client_state, None);
+        assert_eq!(state_update.consensus_state, None);
+        assert_eq!(
+            state_update.storage_writes,
+            StateUpdate::<EthereumLightClient>::storage_only(trusted_height)
+                .add_storage_write::<BestValidUpdateStore>(finalized_slot, Some(header))
+                .storage_writes
+        );
+    }
+
+    #[test]
+    fn update_worse_than_best_valid_update_is_rejected() {
+        let trusted_height = FINALITY_UPDATE.finalized_header.execution.block_number - 1;
+        let mut client_state = initial_client_state(trusted_height);
+        client_state.allow_force_updates = true;
+
+        let deps = mock_deps(
+            &Addr::unchecked("hey bro"),
+            INITIAL_HEADER.slot,
+            vec![],
+            StateUpdate::<EthereumLightClient>::new(0, initial_consensus_state())
+                .add_storage_write::<BestValidUpdateStore>(
+                    INITIAL_HEADER.slot,
+                    Some(finality_update_header(trusted_height)),
+                )
+                .storage_writes,
+        );
+
+        let result = process_best_valid_update_at(
+            deps.as_ref(),
+            client_state,
+            INITIAL_HEADER.slot + Slot::new(1),
+            worse_finality_update_header(trusted_height),
+        );
+
+        assert!(matches!(
+            result,
+            Err(IbcClientError::ClientSpecific(
+                Error::NotBetterThanBestValidUpdate(slot)
+            )) if slot == INITIAL_HEADER.slot
+        ));
+    }
+
+    #[test]
+    fn stored_best_valid_update_is_force_applied_after_timeout() {
+        let trusted_height = FINALITY_UPDATE.finalized_header.execution.block_number - 1;
+        let mut client_state = initial_client_state(trusted_height);
+        client_state.allow_force_updates = true;
+
+        let deps = mock_deps(
+            &Addr::unchecked("hey bro"),
+            INITIAL_HEADER.slot,
+            vec![(trusted_height, initial_consensus_state())],
+            StateUpdate::<EthereumLightClient>::new(0, initial_consensus_state())
+                .add_storage_write::<BestValidUpdateStore>(
+                    INITIAL_HEADER.slot,
+                    Some(finality_update_header(trusted_height)),
+                )
+                .storage_writes,
+        );
+
+        // the incoming update has an invalid ibc account proof, so this would fail if it were
+        // applied instead of the stored best valid update
+        let state_update = process_best_valid_update_at(
+            deps.as_ref(),
+            client_state.clone(),
+            INITIAL_HEADER.slot + Slot::new(update_timeout::<Mainnet>() + 1),
+            worse_finality_update_header(trusted_height),
+        )
+        .unwrap();
+
+        client_state.latest_height = FINALITY_UPDATE.finalized_header.execution.block_number;
+
+        assert_eq!(
+            state_update.client_state,
+            Some(ClientState::V2(client_state))
+        );
+        assert_eq!(
+            state_update.consensus_state,
+            Some(ConsensusState {
+                slot: FINALITY_UPDATE.finalized_header.beacon.slot,
+                state_root: FINALITY_UPDATE.finalized_header.execution.state_root,
+                storage_root: FINALITY_UPDATE_ACCOUNT_STORAGE_ROOT,
+                timestamp: Timestamp::from_secs(
+                    FINALITY_UPDATE.finalized_header.execution.timestamp
+                ),
+            })
+        );
+        // the best valid update is cleared, and the finalized slot is advanced
+        assert_eq!(
+            state_update.storage_writes,
+            StateUpdate::<EthereumLightClient>::new(0, initial_consensus_state())
+                .add_storage_write::<BestValidUpdateStore>(INITIAL_HEADER.slot, None)
+                .add_storage_write::<FinalizedSlotStore>(
+                    (),
+                    FINALITY_UPDATE.finalized_header.beacon.slot
+                )
+                .storage_writes
+        );
+    }
+
     #[test]
     fn please_work() {
         verify_account_storage_root(
@@ -31,6 +31,21 @@ pub enum Error {
     #[error("not enough signatures")]
     NotEnoughSignatures,
 
+    #[error("optimistic updates are not allowed by this client")]
+    OptimisticUpdatesNotAllowed,
+
+    #[error("expected a finality update, found an optimistic update")]
+    UnexpectedOptimisticUpdate,
+
+    #[error("expected an optimistic update, found a finality update")]
+    ExpectedOptimisticUpdate,
+
+    #[error(
+        "the update does not have a supermajority and is not better than \
+        the best valid update for the finalized slot {0}"
+    )]
+    NotBetterThanBestValidUpdate(Slot),
+
     #[error("integer arithmetic overflow")]
     IntegerOverflow,
 
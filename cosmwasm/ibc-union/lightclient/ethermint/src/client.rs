@@ -124,7 +124,12 @@ impl IbcClient for EthermintLightClient {
                     &SignatureVerifier::new(Ed25519Verifier::new(ctx.deps)),
                 )
                 .map_err(Error::from)?;
-                let state_update = StateUpdate::new(height, consensus_state);
+                let state_update = StateUpdate {
+                    height,
+                    client_state: None,
+                    consensus_state,
+                    storage_writes: Default::default(),
+                };
                 if let Some(tendermint_client_state) = tendermint_client_state {
                     Ok(state_update.overwrite_client_state(ClientState {
                         tendermint_client_state,
@@ -1,285 +1,338 @@
-use cosmwasm_std::{Deps, DepsMut, Env};
-use ethereum_light_client::client::{canonicalize_stored_value, check_commitment_key};
+use cosmwasm_std::{Addr, Empty};
+use ethereum_light_client::client::EthereumLightClient;
 use gnark_mimc::new_mimc_constants_bls12_377;
-use ics008_wasm_client::{
-    storage_utils::{
-        read_client_state, read_consensus_state, save_client_state, save_consensus_state,
-        update_client_state,
-    },
-    IbcClient, IbcClientError, Status, StorageState,
+use ibc_union_light_client::{
+    spec::{Status, Timestamp},
+    ClientCreationResult, IbcClient, IbcClientCtx, IbcClientError, StateUpdate,
 };
-use linea_light_client_types::{
-    client_state::ClientState, consensus_state::ConsensusState, header::Header,
+use linea_light_client_types::{ClientState, ConsensusState, Header, Misbehaviour, StorageProof};
+use linea_types::{
+    account::ZkAccount,
+    proof::{InclusionProof, NonInclusionProof},
 };
 use unionlabs::{
-    cosmwasm::wasm::union::custom_query::{query_consensus_state, UnionCustomQuery},
-    encoding::{DecodeAs, Proto},
-    ethereum::keccak256,
-    ibc::{
-        core::{
-            client::{genesis_metadata::GenesisMetadata, height::Height},
-            commitment::merkle_path::MerklePath,
-        },
-        lightclients::{ethereum, wasm},
-    },
-    linea::{
-        account::ZkAccount,
-        proof::{InclusionProof, NonInclusionProof},
-    },
+    encoding::Bincode,
+    ethereum::ibc_commitment_key,
     primitives::{H256, U256},
 };
 
 use crate::errors::Error;
 
-type WasmClientState = wasm::client_state::ClientState<ClientState>;
-type WasmConsensusState = wasm::consensus_state::ConsensusState<ConsensusState>;
-type WasmL1ConsensusState =
-    wasm::consensus_state::ConsensusState<ethereum::consensus_state::ConsensusState>;
-
 pub struct LineaLightClient;
 
 impl IbcClient for LineaLightClient {
     type Error = Error;
 
-    type CustomQuery = UnionCustomQuery;
-
     type Header = Header;
 
-    type Misbehaviour = Header;
+    type Misbehaviour = Misbehaviour;
 
     type ClientState = ClientState;
 
     type ConsensusState = ConsensusState;
 
-    type Encoding = Proto;
+    type Encoding = Bincode;
+
+    type CustomQuery = Empty;
+
+    type StorageProof = StorageProof;
 
     fn verify_membership(
-        deps: Deps<Self::CustomQuery>,
-        height: Height,
-        _delay_time_period: u64,
-        _delay_block_period: u64,
-        proof: Vec<u8>,
-        mut path: MerklePath,
-        value: ics008_wasm_client::StorageState,
+        ctx: IbcClientCtx<Self>,
+        height: u64,
+        key: Vec<u8>,
+        storage_proof: Self::StorageProof,
+        value: Vec<u8>,
     ) -> Result<(), IbcClientError<Self>> {
-        let consensus_state: WasmConsensusState =
-            read_consensus_state(deps, &height)?.ok_or(Error::ConsensusStateNotFound(height))?;
-        let client_state: WasmClientState = read_client_state(deps)?;
-
-        let path = path.key_path.pop().ok_or(Error::EmptyIbcPath)?;
-
-        // This storage root is verified during the header update, so we don't need to verify it again.
-        let storage_root = consensus_state.data.ibc_storage_root;
-
-        match value {
-            StorageState::Occupied(value) => {
-                let inclusion_proof = InclusionProof::decode_as::<Proto>(&proof)
-                    .map_err(Error::InclusionProofDecode)?;
-                do_verify_membership(path, storage_root, inclusion_proof, value)?
-            }
-            StorageState::Empty => {
-                let noninclusion_proof = NonInclusionProof::decode_as::<Proto>(&proof)
-                    .map_err(Error::InclusionProofDecode)?;
-                do_verify_non_membership(path, storage_root, noninclusion_proof)?
-            }
-        }
+        let consensus_state = ctx.read_self_consensus_state(height)?;
 
-        Ok(())
-    }
+        let StorageProof::Inclusion(inclusion_proof) = storage_proof else {
+            return Err(Error::ExpectedInclusionProof.into());
+        };
+
+        verify_membership(
+            key,
+            consensus_state.ibc_storage_root,
+            inclusion_proof,
+            value,
+        )?;
 
-    fn verify_header(
-        deps: Deps<Self::CustomQuery>,
-        env: Env,
-        header: Self::Header,
-    ) -> Result<(), IbcClientError<Self>> {
-        let client_state: WasmClientState = read_client_state(deps)?;
-        let l1_consensus_state = query_consensus_state::<WasmL1ConsensusState>(
-            deps,
-            &env,
-            client_state.data.l1_client_id.clone(),
-            header.l1_height,
-        )
-        .map_err(Error::CustomQuery)?;
-        linea_verifier::verify_header(
-            client_state.data,
-            header,
-            l1_consensus_state.data.state_root,
-        )
-        .map_err(Error::Verify)?;
         Ok(())
     }
 
-    fn verify_misbehaviour(
-        _deps: Deps<Self::CustomQuery>,
-        _env: Env,
-        _misbehaviour: Self::Misbehaviour,
+    fn verify_non_membership(
+        ctx: IbcClientCtx<Self>,
+        height: u64,
+        key: Vec<u8>,
+        storage_proof: Self::StorageProof,
     ) -> Result<(), IbcClientError<Self>> {
-        Err(Error::Unimplemented.into())
-    }
-
-    fn update_state(
-        mut deps: DepsMut<Self::CustomQuery>,
-        _env: Env,
-        header: Self::Header,
-    ) -> Result<Vec<Height>, IbcClientError<Self>> {
-        let mut client_state: WasmClientState = read_client_state(deps.as_ref())?;
+        let consensus_state = ctx.read_self_consensus_state(height)?;
 
-        let updated_height = Height {
-            revision_number: client_state.latest_height.revision_number,
-            revision_height: header.l1_height.revision_height,
+        let StorageProof::NonInclusion(noninclusion_proof) = storage_proof else {
+            return Err(Error::ExpectedNonInclusionProof.into());
         };
 
-        if client_state.latest_height < header.l1_height {
-            client_state.data.l1_latest_height = updated_height;
-            update_client_state::<Self>(
-                deps.branch(),
-                client_state,
-                updated_height.revision_height,
-            );
-        }
+        verify_non_membership(key, consensus_state.ibc_storage_root, noninclusion_proof)?;
 
-        // Guaranteed to succeed as we previously verified the header
-        let zk_account =
-            ZkAccount::decode(header.l2_ibc_contract_proof.proof.value).expect("impossible");
-
-        let consensus_state = WasmConsensusState {
-            data: ConsensusState {
-                ibc_storage_root: zk_account.storage_root,
-                // must be nanos
-                timestamp: 1_000_000_000
-                    * u64::try_from(header.l2_timestamp_proof.value).expect("impossible"),
-            },
-        };
-        save_consensus_state::<Self>(deps, consensus_state, &updated_height);
-        Ok(vec![updated_height])
+        Ok(())
     }
 
-    fn update_state_on_misbehaviour(
-        deps: DepsMut<Self::CustomQuery>,
-        env: Env,
-        _client_message: Vec<u8>,
-    ) -> Result<(), IbcClientError<Self>> {
-        let mut client_state: WasmClientState = read_client_state(deps.as_ref())?;
-        client_state.data.frozen_height = Height {
-            revision_number: client_state.latest_height.revision_number,
-            revision_height: env.block.height,
-        };
-        save_client_state::<Self>(deps, client_state);
-        Ok(())
+    fn get_timestamp(consensus_state: &Self::ConsensusState) -> Timestamp {
+        consensus_state.timestamp
     }
 
-    fn check_for_misbehaviour_on_header(
-        _deps: Deps<Self::CustomQuery>,
-        _header: Self::Header,
-    ) -> Result<bool, IbcClientError<Self>> {
-        Ok(false)
+    fn get_latest_height(ClientState::V1(client_state): &Self::ClientState) -> u64 {
+        client_state.latest_height
     }
 
-    fn check_for_misbehaviour_on_misbehaviour(
-        _deps: Deps<Self::CustomQuery>,
-        _misbehaviour: Self::Misbehaviour,
-    ) -> Result<bool, IbcClientError<Self>> {
-        Err(Error::Unimplemented.into())
+    fn get_counterparty_chain_id(ClientState::V1(client_state): &Self::ClientState) -> String {
+        client_state.chain_id.to_string()
     }
 
-    fn verify_upgrade_and_update_state(
-        _deps: DepsMut<Self::CustomQuery>,
-        _upgrade_client_state: Self::ClientState,
-        _upgrade_consensus_state: Self::ConsensusState,
-        _proof_upgrade_client: Vec<u8>,
-        _proof_upgrade_consensus_state: Vec<u8>,
-    ) -> Result<(), IbcClientError<Self>> {
-        Err(Error::Unimplemented.into())
+    fn status(
+        ctx: IbcClientCtx<Self>,
+        ClientState::V1(client_state): &Self::ClientState,
+    ) -> Status {
+        if client_state.frozen_height != 0 {
+            Status::Frozen
+        } else {
+            ctx.l1_status::<EthereumLightClient>(client_state.l1_client_id)
+        }
     }
 
-    fn migrate_client_store(_deps: DepsMut<Self::CustomQuery>) -> Result<(), IbcClientError<Self>> {
-        Err(Error::Unimplemented.into())
+    fn verify_creation(
+        _caller: Addr,
+        _client_state: &Self::ClientState,
+        _consensus_state: &Self::ConsensusState,
+        _relayer: Addr,
+    ) -> Result<ClientCreationResult<Self>, IbcClientError<Self>> {
+        Ok(ClientCreationResult::new())
     }
 
-    fn status(deps: Deps<Self::CustomQuery>, _env: &Env) -> Result<Status, IbcClientError<Self>> {
-        let client_state: WasmClientState = read_client_state(deps)?;
+    fn verify_header(
+        ctx: IbcClientCtx<Self>,
+        _caller: Addr,
+        header: Self::Header,
+        _relayer: Addr,
+    ) -> Result<StateUpdate<Self>, IbcClientError<Self>> {
+        let ClientState::V1(mut client_state) = ctx.read_self_client_state()?;
+
+        let l1_consensus_state = ctx
+            .read_consensus_state::<EthereumLightClient>(
+                client_state.l1_client_id,
+                header.l1_height,
+            )
+            .map_err(Into::<Error>::into)?;
+
+        linea_verifier::verify_header(&client_state, &header, l1_consensus_state.state_root)
+            .map_err(Into::<Error>::into)?;
+
+        let update_height = l2_block_number(&header)?;
+
+        // the account has been proven to be part of the l2 state root in verify_header, hence the
+        // value of the leaf is a valid encoded account
+        let ibc_contract_account = ZkAccount::decode(&header.l2_ibc_contract_proof.proof.value)
+            .map_err(|_| Error::InvalidL2IbcContractAccount)?;
+
+        let timestamp = header
+            .l2_timestamp_proof
+            .value
+            .try_into()
+            .map_err(|()| Error::L2TimestampOverflow(header.l2_timestamp_proof.value))?;
+
+        let consensus_state = ConsensusState {
+            state_root: header.l2_state_root_proof.value.to_be_bytes().into(),
+            ibc_storage_root: ibc_contract_account.storage_root,
+            timestamp: Timestamp::from_secs(timestamp),
+        };
+
+        let state_update = StateUpdate::new(update_height, consensus_state);
 
-        if client_state.data.frozen_height != Height::default() {
-            return Ok(Status::Frozen);
+        if client_state.latest_height < update_height {
+            client_state.latest_height = update_height;
+            Ok(state_update.overwrite_client_state(ClientState::V1(client_state)))
+        } else {
+            Ok(state_update)
         }
+    }
 
-        let Some(_) = read_consensus_state::<Self>(deps, &client_state.latest_height)? else {
-            return Ok(Status::Expired);
-        };
+    fn misbehaviour(
+        ctx: IbcClientCtx<Self>,
+        _caller: Addr,
+        misbehaviour: Self::Misbehaviour,
+        _relayer: Addr,
+    ) -> Result<Self::ClientState, IbcClientError<Self>> {
+        let ClientState::V1(mut client_state) = ctx.read_self_client_state()?;
+
+        let l1_consensus_state_a = ctx
+            .read_consensus_state::<EthereumLightClient>(
+                client_state.l1_client_id,
+                misbehaviour.header_a.l1_height,
+            )
+            .map_err(Into::<Error>::into)?;
+
+        let l1_consensus_state_b = ctx
+            .read_consensus_state::<EthereumLightClient>(
+                client_state.l1_client_id,
+                misbehaviour.header_b.l1_height,
+            )
+            .map_err(Into::<Error>::into)?;
+
+        linea_verifier::verify_misbehaviour(
+            &client_state,
+            &misbehaviour.header_a,
+            l1_consensus_state_a.state_root,
+            &misbehaviour.header_b,
+            l1_consensus_state_b.state_root,
+        )
+        .map_err(Into::<Error>::into)?;
 
-        Ok(Status::Active)
-    }
+        client_state.frozen_height = l2_block_number(&misbehaviour.header_a)?;
 
-    fn export_metadata(
-        _deps: Deps<Self::CustomQuery>,
-        _env: &Env,
-    ) -> Result<Vec<GenesisMetadata>, IbcClientError<Self>> {
-        Ok(Vec::new())
+        Ok(ClientState::V1(client_state))
     }
+}
 
-    fn timestamp_at_height(
-        deps: Deps<Self::CustomQuery>,
-        height: Height,
-    ) -> Result<u64, IbcClientError<Self>> {
-        Ok(read_consensus_state::<Self>(deps, &height)?
-            .ok_or(Error::ConsensusStateNotFound(height))?
-            .data
-            .timestamp)
-    }
+fn l2_block_number(header: &Header) -> Result<u64, Error> {
+    header
+        .l2_block_number_proof
+        .value
+        .try_into()
+        .map_err(|()| Error::L2BlockNumberOverflow(header.l2_block_number_proof.value))
 }
 
-fn do_verify_membership(
-    path: String,
+/// Verifies that `value` is committed at `key` in the IBC contract storage on the L2.
+pub fn verify_membership(
+    key: Vec<u8>,
     storage_root: H256,
-    storage_proof: InclusionProof,
-    raw_value: Vec<u8>,
+    inclusion_proof: InclusionProof,
+    value: Vec<u8>,
 ) -> Result<(), Error> {
-    // TODO: handle error
-    let key = U256::try_from_be_bytes(&storage_proof.key).unwrap();
+    let key = H256::try_from(&key).map_err(|_| Error::InvalidCommitmentKeyLength(key))?;
 
-    check_commitment_key(&path, key)?;
+    let value = H256::try_from(&value).map_err(|_| Error::InvalidCommitmentValueLength(value))?;
 
-    // we store the hash of the data, not the data itself to the commitments map
-    let expected_value_hash = keccak256(canonicalize_stored_value(path, raw_value)?);
+    let (_, proof_value) = linea_zktrie::verify::verify_inclusion_and_key::<U256>(
+        &new_mimc_constants_bls12_377(),
+        inclusion_proof.leaf_index,
+        &inclusion_proof.proof,
+        storage_root,
+        ibc_commitment_key(key),
+    )?;
 
-    // TODO: handle error
-    let proof_value = H256::new(storage_proof.proof.value.clone().try_into().unwrap());
+    let proof_value = H256::from(proof_value.to_be_bytes());
 
-    if expected_value_hash != proof_value {
+    if value != proof_value {
         return Err(Error::StoredValueMismatch {
-            expected: expected_value_hash,
+            expected: value,
             stored: proof_value,
         });
     }
 
-    linea_zktrie::verify::verify_inclusion_and_key::<U256>(
-        &new_mimc_constants_bls12_377(),
-        storage_proof.leaf_index,
-        &storage_proof.proof,
-        storage_root,
-        key,
-    )?;
-
     Ok(())
 }
 
-/// Verifies that no value is committed at `path` in the counterparty light client's storage.
-fn do_verify_non_membership(
-    path: String,
+/// Verifies that no value is committed at `key` in the IBC contract storage on the L2.
+pub fn verify_non_membership(
+    key: Vec<u8>,
     storage_root: H256,
     noninclusion_proof: NonInclusionProof,
 ) -> Result<(), Error> {
-    // TODO: handle error
-    let key = U256::try_from_be_bytes(&noninclusion_proof.key).unwrap();
-
-    check_commitment_key(&path, key)?;
+    let key = H256::try_from(&key).map_err(|_| Error::InvalidCommitmentKeyLength(key))?;
 
     linea_zktrie::verify::verify_noninclusion::<U256>(
         &new_mimc_constants_bls12_377(),
         &noninclusion_proof,
         storage_root,
-        key,
+        ibc_commitment_key(key),
     )?;
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use ibc_union_light_client::{
+        spec::ClientId,
+        test_utils::{assert_status_follows_l1_client, client_status, L1Client},
+    };
+    use linea_light_client_types::ClientStateV1;
+    use unionlabs::primitives::H160;
+
+    use super::*;
+
+    fn client_state(frozen_height: u64) -> ClientState {
+        ClientState::V1(ClientStateV1 {
+            chain_id: U256::from(59144_u64),
+            latest_height: 1,
+            l1_client_id: ClientId!(1),
+            l1_rollup_contract_address: H160::default(),
+            l1_rollup_current_l2_block_number_slot: U256::from(0x119_u64),
+            l1_rollup_current_l2_timestamp_slot: U256::from(0x118_u64),
+            l1_rollup_l2_state_root_hashes_slot: U256::from(0x11A_u64),
+            frozen_height,
+            ibc_contract_address: H160::default(),
+        })
+    }
+
+    #[test]
+    fn status_follows_l1_client() {
+        assert_status_follows_l1_client::<LineaLightClient>(&client_state(0), ClientId!(1));
+    }
+
+    #[test]
+    fn status_frozen_if_frozen() {
+        assert_eq!(
+            client_status::<LineaLightClient>(
+                &client_state(1),
+                ClientId!(1),
+                L1Client::Status(Status::Active)
+            ),
+            Status::Frozen
+        );
+    }
+
+    #[test]
+    fn membership_rejects_invalid_key_length() {
+        assert!(matches!(
+            verify_membership(
+                vec![0; 31],
+                H256::default(),
+                InclusionProof {
+                    key: Default::default(),
+                    leaf_index: 0,
+                    proof: linea_types::proof::MerklePath {
+                        value: Default::default(),
+                        proof_related_nodes: vec![],
+                    },
+                },
+                vec![0; 32],
+            ),
+            Err(Error::InvalidCommitmentKeyLength(_))
+        ));
+    }
+
+    #[test]
+    fn non_membership_rejects_invalid_key_length() {
+        assert!(matches!(
+            verify_non_membership(
+                vec![0; 31],
+                H256::default(),
+                NonInclusionProof {
+                    key: Default::default(),
+                    left_leaf_index: 0,
+                    left_proof: linea_types::proof::MerklePath {
+                        value: Default::default(),
+                        proof_related_nodes: vec![],
+                    },
+                    right_leaf_index: 0,
+                    right_proof: linea_types::proof::MerklePath {
+                        value: Default::default(),
+                        proof_related_nodes: vec![],
+                    },
+                },
+            ),
+            Err(Error::InvalidCommitmentKeyLength(_))
+        ));
+    }
+}
@@ -1,79 +1,49 @@
-use ethereum_light_client::errors::{CanonicalizeStoredValueError, InvalidCommitmentKey};
-use ics008_wasm_client::IbcClientError;
-use linea_light_client_types::{ClientState, ConsensusState};
-use unionlabs::{
-    encoding::{DecodeErrorOf, Proto},
-    ibc::core::client::height::Height,
-    ics24::PathParseError,
-    linea::proof::InclusionProof,
-    primitives::H256,
-};
+use ethereum_light_client::client::EthereumLightClient;
+use ibc_union_light_client::IbcClientError;
+use unionlabs::primitives::{H256, U256};
 
 use crate::client::LineaLightClient;
 
-#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+#[derive(Debug, thiserror::Error)]
 pub enum Error {
-    #[error("unable to decode storage proof")]
-    InclusionProofDecode(#[source] DecodeErrorOf<Proto, InclusionProof>),
-
-    // #[error("unable to decode counterparty's stored cometbls client state")]
-    // CometblsClientStateDecode(
-    //     #[source] DecodeErrorOf<Proto, Any<cometbls::client_state::ClientState>>,
-    // ),
-    // #[error("unable to decode counterparty's stored cometbls consensus state")]
-    // CometblsConsensusStateDecode(
-    //     #[source]
-    //     DecodeErrorOf<
-    //         Proto,
-    //         Any<wasm::consensus_state::ConsensusState<cometbls::consensus_state::ConsensusState>>,
-    //     >,
-    // ),
-    #[error("unable to decode client state")]
-    ClientStateDecode(#[source] DecodeErrorOf<Proto, ClientState>),
-    #[error("unable to decode consensus state")]
-    ConsensusStateDecode(#[source] DecodeErrorOf<Proto, ConsensusState>),
-
     #[error(transparent)]
-    CanonicalizeStoredValue(#[from] CanonicalizeStoredValueError),
-
-    // REVIEW: Move this variant to IbcClientError?
-    #[error("consensus state not found at height {0}")]
-    ConsensusStateNotFound(Height),
+    Verify(#[from] linea_verifier::Error),
 
-    #[error("IBC path is empty")]
-    EmptyIbcPath,
+    #[error(transparent)]
+    Evm(#[from] ethereum_light_client::errors::Error),
 
     #[error(transparent)]
-    InvalidCommitmentKey(#[from] InvalidCommitmentKey),
+    EvmIbcClient(#[from] IbcClientError<EthereumLightClient>),
 
-    #[error("proof is empty")]
-    EmptyProof,
+    #[error("commitment key must be 32 bytes but we got: {0:?}")]
+    InvalidCommitmentKeyLength(Vec<u8>),
 
-    #[error("batching proofs are not supported")]
-    BatchingProofsNotSupported,
+    #[error("commitment value must be 32 bytes but we got: {0:?}")]
+    InvalidCommitmentValueLength(Vec<u8>),
 
     #[error("expected value ({expected}) and stored value ({stored}) don't match")]
     StoredValueMismatch { expected: H256, stored: H256 },
 
-    #[error("unable to parse ics24 path")]
-    PathParse(#[from] PathParseError),
+    #[error("membership verification requires an inclusion proof")]
+    ExpectedInclusionProof,
 
-    #[error("failed to verify linea header: {0}")]
-    Verify(#[from] linea_verifier::Error),
+    #[error("non-membership verification requires a non-inclusion proof")]
+    ExpectedNonInclusionProof,
 
-    #[error("the operation has not been implemented yet")]
-    Unimplemented,
+    #[error("failed to verify linea storage proof")]
+    InvalidStorageProof(#[from] linea_zktrie::verify::Error),
 
-    #[error("error while calling custom query: {0}")]
-    CustomQuery(#[from] unionlabs::cosmwasm::wasm::union::custom_query::Error),
+    #[error("the l2 ibc contract account is invalid")]
+    InvalidL2IbcContractAccount,
 
-    #[error("L2 account proof must be an inclusion proof")]
-    InvalidL2AccountProof,
+    #[error("l2 block number ({0}) does not fit in a u64")]
+    L2BlockNumberOverflow(U256),
 
-    #[error("failed to verify linea membership proof {0}")]
-    InvalidMembershipProof(#[from] linea_zktrie::verify::Error),
+    #[error("l2 timestamp ({0}) does not fit in a u64")]
+    L2TimestampOverflow(U256),
 }
 
+// required for IbcClient trait
 impl From<Error> for IbcClientError<LineaLightClient> {
     fn from(value: Error) -> Self {
         IbcClientError::ClientSpecific(value)
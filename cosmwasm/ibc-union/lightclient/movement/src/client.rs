@@ -1,16 +1,18 @@
 use cosmwasm_std::{Addr, Empty};
+use ethereum_light_client::client::EthereumLightClient;
 use ibc_union_light_client::{
-    spec::Timestamp, ClientCreationResult, IbcClient, IbcClientCtx, IbcClientError, StateUpdate,
+    spec::{Status, Timestamp},
+    ClientCreationResult, IbcClient, IbcClientCtx, IbcClientError, StateUpdate,
 };
-use ibc_union_msg::lightclient::Status;
 use movement_light_client_types::{
     client_state::ClientState, consensus_state::ConsensusState, header::Header,
+    misbehaviour::Misbehaviour,
 };
 use unionlabs::{
-    aptos::{
-        account::AccountAddress, storage_proof::StorageProof, transaction_info::TransactionInfo,
-    },
+    aptos::{storage_proof::StorageProof, transaction_info::TransactionInfo},
     encoding::Bincode,
+    ethereum::slot::{MappingKey, Slot},
+    ibc::core::client::height::Height,
     primitives::{H256, U256},
 };
 
@@ -32,7 +34,7 @@ impl IbcClient for MovementLightClient {
 
     type Header = Header;
 
-    type Misbehaviour = Header;
+    type Misbehaviour = Misbehaviour;
 
     type ClientState = ClientState;
 
@@ -43,32 +45,43 @@ impl IbcClient for MovementLightClient {
     type Encoding = Bincode;
 
     fn verify_membership(
-        _ctx: IbcClientCtx<Self>,
-        _height: u64,
-        _key: Vec<u8>,
-        _storage_proof: Self::StorageProof,
-        _value: Vec<u8>,
+        ctx: IbcClientCtx<Self>,
+        height: u64,
+        key: Vec<u8>,
+        storage_proof: Self::StorageProof,
+        value: Vec<u8>,
     ) -> Result<(), IbcClientError<Self>> {
-        // let client_state = ctx.read_self_client_state()?;
-        // let consensus_state = ctx.read_self_consensus_state(height)?;
-        // verify_membership(
-        //     &key,
-        //     consensus_state.state_root,
-        //     client_state.table_handle,
-        //     storage_proof,
-        //     &value,
-        // )
-        // .map_err(Into::into)
+        let client_state = ctx.read_self_client_state()?;
+        let consensus_state = ctx.read_self_consensus_state(height)?;
+        aptos_verifier::verify_table_membership(
+            &key,
+            consensus_state.state_root,
+            client_state.table_handle,
+            storage_proof,
+            &value,
+        )
+        .map_err(Into::<Error>::into)?;
+
         Ok(())
     }
 
     fn verify_non_membership(
-        _ctx: IbcClientCtx<Self>,
-        _height: u64,
-        _key: Vec<u8>,
-        _storage_proof: Self::StorageProof,
+        ctx: IbcClientCtx<Self>,
+        height: u64,
+        key: Vec<u8>,
+        storage_proof: Self::StorageProof,
     ) -> Result<(), IbcClientError<Self>> {
-        unimplemented!()
+        let client_state = ctx.read_self_client_state()?;
+        let consensus_state = ctx.read_self_consensus_state(height)?;
+        aptos_verifier::verify_table_non_membership(
+            &key,
+            consensus_state.state_root,
+            client_state.table_handle,
+            storage_proof,
+        )
+        .map_err(Into::<Error>::into)?;
+
+        Ok(())
     }
 
     fn get_timestamp(consensus_state: &Self::ConsensusState) -> Timestamp {
@@ -84,12 +97,10 @@ impl IbcClient for MovementLightClient {
     }
 
     fn status(ctx: IbcClientCtx<Self>, client_state: &Self::ClientState) -> Status {
-        let _ = ctx;
-
         if client_state.frozen_height.height() != 0 {
             Status::Frozen
         } else {
-            Status::Active
+            ctx.l1_status::<EthereumLightClient>(client_state.l1_client_id)
         }
     }
 
@@ -125,74 +136,183 @@ impl IbcClient for MovementLightClient {
 
         #[cfg(feature = "union-movement")]
         {
-            aptos_verifier::verify_tx_state(
-                &header.tx_proof,
-                *header
-                    .state_proof
-                    .latest_ledger_info()
-                    .commit_info
-                    .executed_state_id
-                    .get(),
-                header.tx_index,
-            )
-            .map_err(Into::<Error>::into)?;
-
-            // TODO(aeryz): make sure the given state_proof_hash_proof.key matches the correct slot
-
-            let l1_consensus_state =
-                ctx.read_consensus_state(client_state.l1_client_id, header.l1_height)?;
-
-            let expected_commitment = BlockCommitment {
-                height: header.new_height.into(),
-                commitment: U256::from_be_bytes(header.state_proof.hash()),
-                // TODO(aeryz): check if hash here is big endian
-                block_id: U256::from_be_bytes(
-                    header
-                        .state_proof
-                        .latest_ledger_info()
-                        .commit_info
-                        .id
-                        .into(),
-                ),
-            };
-
-            evm_storage_verifier::verify_account_storage_root(
-                l1_consensus_state.state_root,
-                &client_state.l1_contract_address,
-                &header.settlement_contract_proof.proof,
-                &header.settlement_contract_proof.storage_root,
-            )
-            .unwrap();
-
-            evm_storage_verifier::verify_storage_proof(
-                header.settlement_contract_proof.storage_root,
-                header.state_proof_hash_proof.key,
-                &rlp::encode(&expected_commitment),
-                &header.state_proof_hash_proof.proof,
-            )
-            .unwrap();
+            let l1_consensus_state = ctx
+                .read_consensus_state::<EthereumLightClient>(
+                    client_state.l1_client_id,
+                    header.l1_height,
+                )
+                .map_err(Into::<Error>::into)?;
+
+            verify_header_proofs(&client_state, &header, l1_consensus_state.state_root)?;
         }
         update_state(client_state, header).map_err(Into::into)
     }
 
     fn misbehaviour(
-        _ctx: IbcClientCtx<Self>,
+        ctx: IbcClientCtx<Self>,
         _caller: Addr,
-        _misbehaviour: Self::Misbehaviour,
+        misbehaviour: Self::Misbehaviour,
         _relayer: Addr,
     ) -> Result<Self::ClientState, IbcClientError<Self>> {
-        unimplemented!()
+        let mut client_state = ctx.read_self_client_state()?;
+
+        let l1_consensus_state_a = ctx
+            .read_consensus_state::<EthereumLightClient>(
+                client_state.l1_client_id,
+                misbehaviour.header_a.l1_height,
+            )
+            .map_err(Into::<Error>::into)?;
+
+        let l1_consensus_state_b = ctx
+            .read_consensus_state::<EthereumLightClient>(
+                client_state.l1_client_id,
+                misbehaviour.header_b.l1_height,
+            )
+            .map_err(Into::<Error>::into)?;
+
+        verify_misbehaviour(
+            &client_state,
+            &misbehaviour.header_a,
+            l1_consensus_state_a.state_root,
+            &misbehaviour.header_b,
+            l1_consensus_state_b.state_root,
+        )?;
+
+        client_state.frozen_height = Height::new(misbehaviour.header_a.new_height);
+
+        Ok(client_state)
+    }
+}
+
+/// Verify the proofs of `header` against the L1 state root `l1_state_root`.
+///
+/// Header verification consists of 4 steps:
+///
+/// 1. Verify that the state proof hash proof is for the commitment of `header.new_height` in the
+///    block commitments mapping of the settlement contract.
+/// 2. Verify that the transaction info is part of the ledger at the executed state id of the state
+///    proof.
+/// 3. Verify the storage root of the settlement contract against the L1 state root.
+/// 4. Verify that the block commitment of the state proof is stored in the settlement contract.
+pub fn verify_header_proofs(
+    client_state: &ClientState,
+    header: &Header,
+    l1_state_root: H256,
+) -> Result<(), Error> {
+    // 1.
+    let expected_key =
+        block_commitments_key(client_state.l1_block_commitments_slot, header.new_height);
+
+    if header.state_proof_hash_proof.key != expected_key {
+        return Err(Error::InvalidStateProofKey {
+            expected: expected_key,
+            found: header.state_proof_hash_proof.key,
+        });
+    }
+
+    // 2.
+    aptos_verifier::verify_tx_state(
+        &header.tx_proof,
+        *header
+            .state_proof
+            .latest_ledger_info()
+            .commit_info
+            .executed_state_id
+            .get(),
+        header.tx_index,
+    )?;
+
+    let expected_commitment = BlockCommitment {
+        height: header.new_height.into(),
+        commitment: U256::from_be_bytes(header.state_proof.hash()),
+        // TODO(aeryz): check if hash here is big endian
+        block_id: U256::from_be_bytes(
+            header
+                .state_proof
+                .latest_ledger_info()
+                .commit_info
+                .id
+                .into(),
+        ),
+    };
+
+    // 3.
+    evm_storage_verifier::verify_account_storage_root(
+        l1_state_root,
+        &client_state.l1_contract_address,
+        &header.settlement_contract_proof.proof,
+        &header.settlement_contract_proof.storage_root,
+    )
+    .map_err(Error::InvalidContractAddressProof)?;
+
+    // 4.
+    evm_storage_verifier::verify_storage_proof(
+        header.settlement_contract_proof.storage_root,
+        expected_key,
+        &rlp::encode(&expected_commitment),
+        &header.state_proof_hash_proof.proof,
+    )
+    .map_err(Error::InvalidStateProof)?;
+
+    Ok(())
+}
+
+/// Verify that the two provided headers are a valid proof of misbehaviour.
+///
+/// Misbehaviour verification consists of 3 steps:
+///
+/// 1. Verify that both headers are for the same L2 block.
+/// 2. Verify that the state roots of the headers are different.
+/// 3. Verify both headers against their respective L1 state roots, as in [`verify_header_proofs`].
+pub fn verify_misbehaviour(
+    client_state: &ClientState,
+    header_a: &Header,
+    l1_state_root_a: H256,
+    header_b: &Header,
+    l1_state_root_b: H256,
+) -> Result<(), Error> {
+    // 1.
+    if header_a.new_height != header_b.new_height {
+        return Err(Error::MisbehaviourHeightMismatch {
+            a: header_a.new_height,
+            b: header_b.new_height,
+        });
     }
+
+    // 2.
+    let state_root_a = state_root(header_a)?;
+
+    if state_root_a == state_root(header_b)? {
+        return Err(Error::MisbehaviourStateRootsEqual(state_root_a));
+    }
+
+    // 3.
+    verify_header_proofs(client_state, header_a, l1_state_root_a)?;
+    verify_header_proofs(client_state, header_b, l1_state_root_b)?;
+
+    Ok(())
+}
+
+/// The slot of the commitment of the block at `height` in the block commitments mapping at `slot`.
+pub fn block_commitments_key(slot: U256, height: u64) -> U256 {
+    Slot::Mapping(&Slot::Offset(slot), MappingKey::Uint256(height.into())).slot()
+}
+
+fn state_root(header: &Header) -> Result<H256, Error> {
+    let TransactionInfo::V0(tx_info) = &header.tx_proof.transaction_info;
+
+    tx_info
+        .state_checkpoint_hash
+        .map(|state_checkpoint_hash| H256::new(*state_checkpoint_hash.get()))
+        .ok_or(Error::MissingStateCheckpointHash)
 }
 
 fn update_state(
     mut client_state: ClientState,
     header: Header,
 ) -> Result<StateUpdate<MovementLightClient>, Error> {
-    let TransactionInfo::V0(tx_info) = header.tx_proof.transaction_info;
-
     let consensus_state = ConsensusState {
-        state_root: H256::new(*tx_info.state_checkpoint_hash.unwrap().get()), // TODO(aeryz): we always need this, no need to make this not an option
+        state_root: state_root(&header)?,
         timestamp: Timestamp::from_nanos(
             header
                 .state_proof
@@ -214,79 +334,132 @@ fn update_state(
     }
 }
 
-// #[cfg(feature = "union-movement")]
-pub fn verify_membership(
-    path: &[u8],
-    state_root: H256,
-    table_handle: AccountAddress,
-    proof: StorageProof,
-    value: &[u8],
-) -> Result<(), Error> {
-    let Some(proof_value) = &proof.state_value else {
-        return Err(Error::MembershipProofWithoutValue);
+#[cfg(test)]
+mod tests {
+    use ethereum_light_client_types::{AccountProof, StorageProof};
+    use hex_literal::hex;
+    use ibc_union_light_client::{
+        spec::ClientId,
+        test_utils::{assert_status_follows_l1_client, client_status, L1Client},
+    };
+    use unionlabs::{
+        aptos::{
+            account::AccountAddress, state_proof::StateProof,
+            transaction_proof::TransactionInfoWithProof,
+        },
+        encoding::{DecodeAs, Proto},
+        ibc::core::channel::channel::Channel,
+        primitives::FixedBytes,
     };
 
-    // `aptos_std::table` stores the value as bcs encoded
-    let given_value = bcs::to_bytes(&value).expect("cannot fail");
-    if proof_value.data() != given_value {
-        return Err(Error::ProofValueMismatch(
-            proof_value.data().to_vec(),
-            given_value,
-        ));
+    use super::*;
+
+    fn client_state(frozen_height: u64) -> ClientState {
+        ClientState {
+            chain_id: "27".to_owned(),
+            l1_client_id: ClientId!(1),
+            l1_contract_address: Default::default(),
+            l1_block_commitments_slot: U256::from(3_u64),
+            l2_contract_address: AccountAddress(FixedBytes::default()),
+            table_handle: AccountAddress(FixedBytes::default()),
+            frozen_height: Height::new(frozen_height),
+            latest_block_num: 1,
+            whitelisted_relayers: vec![],
+        }
     }
 
-    let Some(proof_leaf) = proof.proof.leaf.as_ref() else {
-        return Err(Error::MembershipProofWithoutValue);
-    };
+    fn header(new_height: u64, state_root: [u8; 32]) -> Header {
+        let mut tx_proof = TransactionInfoWithProof::default();
+        let TransactionInfo::V0(tx_info) = &mut tx_proof.transaction_info;
+        tx_info.state_checkpoint_hash = Some(FixedBytes::new(state_root));
+
+        Header {
+            l1_height: 1,
+            trusted_height: Height::new(1),
+            state_proof: StateProof::default(),
+            tx_index: 0,
+            tx_proof,
+            state_proof_hash_proof: StorageProof {
+                key: block_commitments_key(U256::from(3_u64), new_height),
+                value: U256::ZERO,
+                proof: vec![],
+            },
+            settlement_contract_proof: AccountProof {
+                storage_root: H256::default(),
+                proof: vec![],
+            },
+            new_height,
+        }
+    }
 
-    if aptos_verifier::hash_state_value(proof_value) != *proof_leaf.value_hash.get() {
-        return Err(Error::ProofValueHashMismatch);
+    #[test]
+    fn header_proofs_reject_wrong_slot_key() {
+        let mut header = header(2, [1; 32]);
+        header.state_proof_hash_proof.key = block_commitments_key(U256::from(4_u64), 2);
+
+        assert!(matches!(
+            verify_header_proofs(&client_state(0), &header, H256::default()),
+            Err(Error::InvalidStateProofKey { expected, found })
+                if expected == block_commitments_key(U256::from(3_u64), 2)
+                    && found == header.state_proof_hash_proof.key
+        ));
     }
 
-    let key =
-        aptos_verifier::hash_table_key(&bcs::to_bytes(path).expect("cannot fail"), &table_handle);
+    #[test]
+    fn header_proofs_reject_other_height_key() {
+        let mut header = header(2, [1; 32]);
+        header.state_proof_hash_proof.key = block_commitments_key(U256::from(3_u64), 3);
 
-    if key != *proof_leaf.key.get() {
-        return Err(Error::ProofKeyMismatch);
+        assert!(matches!(
+            verify_header_proofs(&client_state(0), &header, H256::default()),
+            Err(Error::InvalidStateProofKey { .. })
+        ));
     }
 
-    Ok(aptos_verifier::verify_membership(
-        proof.proof,
-        state_root.into(),
-    )?)
-}
+    #[test]
+    fn misbehaviour_rejects_different_heights() {
+        assert!(matches!(
+            verify_misbehaviour(
+                &client_state(0),
+                &header(2, [1; 32]),
+                H256::default(),
+                &header(3, [2; 32]),
+                H256::default(),
+            ),
+            Err(Error::MisbehaviourHeightMismatch { a: 2, b: 3 })
+        ));
+    }
 
-#[derive(serde::Serialize, serde::Deserialize)]
-#[serde(rename = "StateValue")]
-enum PersistedStateValue {
-    V0(Vec<u8>),
-    WithMetadata {
-        data: Vec<u8>,
-        metadata: PersistedStateValueMetadata,
-    },
-}
+    #[test]
+    fn misbehaviour_rejects_equal_state_roots() {
+        assert!(matches!(
+            verify_misbehaviour(
+                &client_state(0),
+                &header(2, [1; 32]),
+                H256::default(),
+                &header(2, [1; 32]),
+                H256::default(),
+            ),
+            Err(Error::MisbehaviourStateRootsEqual(root)) if root == <H256>::new([1; 32])
+        ));
+    }
 
-#[derive(serde::Deserialize, serde::Serialize)]
-#[serde(rename = "StateValueMetadata")]
-pub enum PersistedStateValueMetadata {
-    V0 {
-        deposit: u64,
-        creation_time_usecs: u64,
-    },
-    V1 {
-        slot_deposit: u64,
-        bytes_deposit: u64,
-        creation_time_usecs: u64,
-    },
-}
+    #[test]
+    fn status_follows_l1_client() {
+        assert_status_follows_l1_client::<MovementLightClient>(&client_state(0), ClientId!(1));
+    }
 
-#[cfg(test)]
-mod tests {
-    use hex_literal::hex;
-    use unionlabs::{
-        encoding::{DecodeAs, Proto},
-        ibc::core::channel::channel::Channel,
-    };
+    #[test]
+    fn status_frozen_if_frozen() {
+        assert_eq!(
+            client_status::<MovementLightClient>(
+                &client_state(1),
+                ClientId!(1),
+                L1Client::Status(Status::Active)
+            ),
+            Status::Frozen
+        );
+    }
 
     #[test]
     fn test_proto() {
@@ -1,27 +1,37 @@
 use cosmwasm_std::StdError;
+use ethereum_light_client::client::EthereumLightClient;
 use ibc_union_light_client::IbcClientError;
-use unionlabs::ibc::core::client::height::Height;
+use unionlabs::{
+    ibc::core::client::height::Height,
+    primitives::{H256, U256},
+};
 
 use crate::client::MovementLightClient;
 
-#[derive(thiserror::Error, Debug, PartialEq)]
+#[derive(thiserror::Error, Debug)]
 pub enum Error {
     #[error("header verification failure ({0})")]
     HeaderVerification(#[from] aptos_verifier::Error),
+    #[error("unimplemented feature")]
+    Unimplemented,
+    #[error(transparent)]
+    EvmIbcClient(#[from] IbcClientError<EthereumLightClient>),
+    #[error("invalid settlement contract proof")]
+    InvalidContractAddressProof(#[source] evm_storage_verifier::error::Error),
     #[error("invalid state_proof storage proof")]
-    InvalidStateProof,
+    InvalidStateProof(#[source] evm_storage_verifier::error::Error),
+    #[error("invalid state_proof storage proof key: expected {expected}, found {found}")]
+    InvalidStateProofKey { expected: U256, found: U256 },
+    #[error("misbehaviour headers are for different heights ({a} != {b})")]
+    MisbehaviourHeightMismatch { a: u64, b: u64 },
+    #[error("misbehaviour headers commit to the same state root ({0})")]
+    MisbehaviourStateRootsEqual(H256),
+    #[error("transaction info is missing the state checkpoint hash")]
+    MissingStateCheckpointHash,
     #[error("empty ibc path")]
     EmptyIbcPath,
     #[error("consensus state not found ({0})")]
     ConsensusStateNotFound(Height),
-    #[error("membership proof with no value")]
-    MembershipProofWithoutValue,
-    #[error("proof value {proof_value} doesn't match the given value {given})", proof_value = serde_utils::to_hex(.0), given = serde_utils::to_hex(.1))]
-    ProofValueMismatch(Vec<u8>, Vec<u8>),
-    #[error("proof value hash doesn't match the calculated one")]
-    ProofValueHashMismatch,
-    #[error("proof key hash doesn't match the calculated one")]
-    ProofKeyMismatch,
     #[error("invalid ibc path {0}")]
     InvalidIbcPath(String),
     #[error(transparent)]
@@ -80,29 +80,27 @@ impl IbcClient for ParliaLightClient {
     }
 
     fn status(
-        _ctx: IbcClientCtx<Self>,
-        ClientState::V1(_client_state): &Self::ClientState,
+        ctx: IbcClientCtx<Self>,
+        ClientState::V1(client_state): &Self::ClientState,
     ) -> Status {
-        // TODO: Re-enable these checks before we go to mainnet
-        Status::Active
-        // if client_state.frozen_height == 0 {
-        //     let consensus_state = ctx
-        //         .read_self_consensus_state(client_state.latest_height)
-        //         .unwrap();
-
-        //     if consensus_state
-        //         .timestamp
-        //         .plus_duration(client_state.unbond_period)
-        //         .expect("should be ok")
-        //         < Timestamp::from_nanos(ctx.env.block.time.nanos())
-        //     {
-        //         Status::Expired
-        //     } else {
-        //         Status::Active
-        //     }
-        // } else {
-        //     Status::Frozen
-        // }
+        if client_state.frozen_height != 0 {
+            Status::Frozen
+        } else {
+            let Ok(consensus_state) = ctx.read_self_consensus_state(client_state.latest_height)
+            else {
+                return Status::Expired;
+            };
+
+            if consensus_state
+                .timestamp
+                .plus_duration(client_state.unbond_period)
+                .is_none_or(|expiry| expiry < Timestamp::from_nanos(ctx.env.block.time.nanos()))
+            {
+                Status::Expired
+            } else {
+                Status::Active
+            }
+        }
     }
 
     fn verify_creation(
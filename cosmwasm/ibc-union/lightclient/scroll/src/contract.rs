@@ -1,22 +1,34 @@
-use cosmwasm_std::{entry_point, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
+use cosmwasm_std::{entry_point, Binary, Deps, DepsMut, Env, Response, StdResult};
+use frissitheto::UpgradeMsg;
 use ibc_union_light_client::{
-    msg::{InstantiateMsg, QueryMsg},
+    msg::{InitMsg, QueryMsg},
     IbcClientError,
 };
+use serde::{Deserialize, Serialize};
 
 use crate::client::ScrollLightClient;
 
 #[entry_point]
-pub fn instantiate(
-    deps: DepsMut,
-    env: Env,
-    info: MessageInfo,
-    msg: InstantiateMsg,
-) -> Result<Response, IbcClientError<ScrollLightClient>> {
-    ibc_union_light_client::instantiate(deps, env, info, msg)
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    ibc_union_light_client::query::<ScrollLightClient>(deps, env, msg).map_err(Into::into)
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MigrateMsg {}
+
 #[entry_point]
-pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
-    ibc_union_light_client::query::<ScrollLightClient>(deps, env, msg).map_err(Into::into)
+pub fn migrate(
+    deps: DepsMut,
+    _env: Env,
+    msg: UpgradeMsg<InitMsg, MigrateMsg>,
+) -> Result<Response, IbcClientError<ScrollLightClient>> {
+    msg.run(
+        deps,
+        |deps, init_msg| {
+            let res = ibc_union_light_client::init(deps, init_msg)?;
+
+            Ok((res, None))
+        },
+        |_deps, _migrate_msg, _current_version| Ok((Response::default(), None)),
+    )
 }
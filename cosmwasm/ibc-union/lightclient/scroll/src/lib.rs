@@ -1,4 +1,3 @@
 pub mod client;
-#[cfg(any(test, not(feature = "library")))]
 pub mod contract;
 pub mod errors;
@@ -107,30 +107,7 @@ impl IbcClient for StateLensIcs23Ics23LightClient {
     }
 
     fn status(ctx: IbcClientCtx<Self>, client_state: &Self::ClientState) -> Status {
-        let _ = ctx;
-        let _ = client_state;
-
-        // FIXME: expose the ctx to this call to allow threading this call to L1
-        // client. generally, we want to thread if a client is an L2 so always
-        // provide the ctx?
-        // let client_state: WasmClientState = read_client_state(deps)?;
-        // let l1_client_state = query_client_state::<WasmL1ClientState>(
-        //     deps,
-        //     env,
-        //     client_state.data.l1_client_id.clone(),
-        // )
-        // .map_err(Error::CustomQuery)?;
-
-        // if l1_client_state.data.frozen_height != Height::default() {
-        //     return Ok(Status::Frozen);
-        // }
-
-        // let Some(_) = read_consensus_state::<Self>(deps, &client_state.latest_height)? else {
-        //     return Ok(Status::Expired);
-        // };
-
-        // Ok(Status::Active)
-        Status::Active
+        ctx.l1_status::<CometblsLightClient>(client_state.l1_client_id)
     }
 
     fn verify_creation(
@@ -297,6 +274,8 @@ pub fn verify_non_membership(
 #[cfg(test)]
 mod tests {
     use hex_literal::hex;
+    use ibc_union_light_client::{spec::ClientId, test_utils::assert_status_follows_l1_client};
+    use state_lens_ics23_ics23_light_client_types::client_state::ExtraV1;
 
     use super::*;
 
@@ -388,4 +367,25 @@ mod tests {
         )
         .unwrap();
     }
+
+    fn client_state() -> ClientState {
+        ClientState {
+            l2_chain_id: "union-1".to_owned(),
+            l1_client_id: ClientId!(1),
+            l2_client_id: ClientId!(1),
+            l2_latest_height: 1,
+            extra: Extra::V1(ExtraV1 {
+                store_key: b"wasm".into(),
+                key_prefix_storage: b"\x03".into(),
+            }),
+        }
+    }
+
+    #[test]
+    fn status_follows_l1_client() {
+        assert_status_follows_l1_client::<StateLensIcs23Ics23LightClient>(
+            &client_state(),
+            ClientId!(1),
+        );
+    }
 }
@@ -76,30 +76,7 @@ impl IbcClient for StateLensIcs23MptLightClient {
     }
 
     fn status(ctx: IbcClientCtx<Self>, client_state: &Self::ClientState) -> Status {
-        let _ = ctx;
-        let _ = client_state;
-
-        // FIXME: expose the ctx to this call to allow threading this call to L1
-        // client. generally, we want to thread if a client is an L2 so always
-        // provide the ctx?
-        // let client_state: WasmClientState = read_client_state(deps)?;
-        // let l1_client_state = query_client_state::<WasmL1ClientState>(
-        //     deps,
-        //     env,
-        //     client_state.data.l1_client_id.clone(),
-        // )
-        // .map_err(Error::CustomQuery)?;
-
-        // if l1_client_state.data.frozen_height != Height::default() {
-        //     return Ok(Status::Frozen);
-        // }
-
-        // let Some(_) = read_consensus_state::<Self>(deps, &client_state.latest_height)? else {
-        //     return Ok(Status::Expired);
-        // };
-
-        // Ok(Status::Active)
-        Status::Active
+        ctx.l1_status::<CometblsLightClient>(client_state.l1_client_id)
     }
 
     fn verify_creation(
@@ -274,3 +251,32 @@ pub fn verify_non_membership(
         Err(Error::CounterpartyStorageNotNil)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ibc_union_light_client::{spec::ClientId, test_utils::assert_status_follows_l1_client};
+
+    use super::*;
+
+    fn client_state() -> ClientState {
+        ClientState {
+            l2_chain_id: "1".to_owned(),
+            l1_client_id: ClientId!(1),
+            l2_client_id: ClientId!(1),
+            l2_latest_height: 1,
+            extra: Extra {
+                timestamp_offset: 0,
+                state_root_offset: 32,
+                storage_root_offset: 64,
+            },
+        }
+    }
+
+    #[test]
+    fn status_follows_l1_client() {
+        assert_status_follows_l1_client::<StateLensIcs23MptLightClient>(
+            &client_state(),
+            ClientId!(1),
+        );
+    }
+}
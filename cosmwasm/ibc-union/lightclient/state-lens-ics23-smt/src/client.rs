@@ -3,8 +3,8 @@ use cosmwasm_std::{Addr, Empty};
 use ibc_union_light_client::{
     ClientCreationResult, IbcClient, IbcClientCtx, IbcClientError, StateUpdate,
 };
-use ibc_union_msg::lightclient::{Status, VerifyCreationResponseEvent};
-use ibc_union_spec::{path::ConsensusStatePath, Timestamp};
+use ibc_union_msg::lightclient::VerifyCreationResponseEvent;
+use ibc_union_spec::{path::ConsensusStatePath, Status, Timestamp};
 use movement_light_client_types::ConsensusState as L2ConsensusState;
 use state_lens_ics23_smt_light_client_types::{ClientState, ConsensusState};
 use state_lens_light_client_types::Header;
@@ -46,23 +46,35 @@ impl IbcClient for StateLensIcs23SmtLightClient {
     ) -> Result<(), IbcClientError<Self>> {
         let client_state = ctx.read_self_client_state()?;
         let consensus_state = ctx.read_self_consensus_state(height)?;
-        verify_membership(
+        aptos_verifier::verify_table_membership(
             &key,
             consensus_state.state_root,
             AccountAddress(client_state.extra.table_handle),
             storage_proof,
             &value,
         )
-        .map_err(Into::into)
+        .map_err(Into::<Error>::into)?;
+
+        Ok(())
     }
 
     fn verify_non_membership(
-        _ctx: IbcClientCtx<Self>,
-        _height: u64,
-        _key: Vec<u8>,
-        _storage_proof: Self::StorageProof,
+        ctx: IbcClientCtx<Self>,
+        height: u64,
+        key: Vec<u8>,
+        storage_proof: Self::StorageProof,
     ) -> Result<(), IbcClientError<Self>> {
-        unimplemented!()
+        let client_state = ctx.read_self_client_state()?;
+        let consensus_state = ctx.read_self_consensus_state(height)?;
+        aptos_verifier::verify_table_non_membership(
+            &key,
+            consensus_state.state_root,
+            AccountAddress(client_state.extra.table_handle),
+            storage_proof,
+        )
+        .map_err(Into::<Error>::into)?;
+
+        Ok(())
     }
 
     fn get_timestamp(consensus_state: &Self::ConsensusState) -> Timestamp {
@@ -78,30 +90,7 @@ impl IbcClient for StateLensIcs23SmtLightClient {
     }
 
     fn status(ctx: IbcClientCtx<Self>, client_state: &Self::ClientState) -> Status {
-        let _ = ctx;
-        let _ = client_state;
-
-        // FIXME: expose the ctx to this call to allow threading this call to L1
-        // client. generally, we want to thread if a client is an L2 so always
-        // provide the ctx?
-        // let client_state: WasmClientState = read_client_state(deps)?;
-        // let l1_client_state = query_client_state::<WasmL1ClientState>(
-        //     deps,
-        //     env,
-        //     client_state.data.l1_client_id.clone(),
-        // )
-        // .map_err(Error::CustomQuery)?;
-
-        // if l1_client_state.data.frozen_height != Height::default() {
-        //     return Ok(Status::Frozen);
-        // }
-
-        // let Some(_) = read_consensus_state::<Self>(deps, &client_state.latest_height)? else {
-        //     return Ok(Status::Expired);
-        // };
-
-        // Ok(Status::Active)
-        Status::Active
+        ctx.l1_status::<CometblsLightClient>(client_state.l1_client_id)
     }
 
     fn verify_creation(
@@ -169,51 +158,14 @@ impl IbcClient for StateLensIcs23SmtLightClient {
         _misbehaviour: Self::Misbehaviour,
         _relayer: Addr,
     ) -> Result<Self::ClientState, IbcClientError<Self>> {
-        unimplemented!()
+        // the l2 consensus states are only ever read out of the l1 client's state root, so
+        // conflicting l2 headers can only be produced if the l1 client itself is fooled. that
+        // misbehaviour is submitted to the l1 client, and its frozen status is inherited through
+        // `status`.
+        Err(Error::Unimplemented.into())
     }
 }
 
-pub fn verify_membership(
-    path: &[u8],
-    state_root: H256,
-    table_handle: AccountAddress,
-    proof: StorageProof,
-    value: &[u8],
-) -> Result<(), Error> {
-    let Some(proof_value) = &proof.state_value else {
-        return Err(Error::MembershipProofWithoutValue);
-    };
-
-    // `aptos_std::table` stores the value as bcs encoded
-    let given_value = bcs::to_bytes(&value).expect("cannot fail");
-    if proof_value.data() != given_value {
-        return Err(Error::ProofValueMismatch(
-            proof_value.data().to_vec(),
-            given_value,
-        ));
-    }
-
-    let Some(proof_leaf) = proof.proof.leaf.as_ref() else {
-        return Err(Error::MembershipProofWithoutValue);
-    };
-
-    if aptos_verifier::hash_state_value(proof_value) != *proof_leaf.value_hash.get() {
-        return Err(Error::ProofValueHashMismatch);
-    }
-
-    let key =
-        aptos_verifier::hash_table_key(&bcs::to_bytes(path).expect("cannot fail"), &table_handle);
-
-    if key != *proof_leaf.key.get() {
-        return Err(Error::ProofKeyMismatch);
-    }
-
-    Ok(aptos_verifier::verify_membership(
-        proof.proof,
-        state_root.into(),
-    )?)
-}
-
 pub fn check_commitment_key(path: H256, key: U256) -> Result<(), Error> {
     let expected_commitment_key = ibc_commitment_key(path);
 
@@ -226,3 +178,31 @@ pub fn check_commitment_key(path: H256, key: U256) -> Result<(), Error> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ibc_union_light_client::{spec::ClientId, test_utils::assert_status_follows_l1_client};
+    use state_lens_ics23_smt_light_client_types::client_state::Extra;
+
+    use super::*;
+
+    fn client_state() -> ClientState {
+        ClientState {
+            l2_chain_id: "27".to_owned(),
+            l1_client_id: ClientId!(1),
+            l2_client_id: ClientId!(1),
+            l2_latest_height: 1,
+            extra: Extra {
+                table_handle: Default::default(),
+            },
+        }
+    }
+
+    #[test]
+    fn status_follows_l1_client() {
+        assert_status_follows_l1_client::<StateLensIcs23SmtLightClient>(
+            &client_state(),
+            ClientId!(1),
+        );
+    }
+}
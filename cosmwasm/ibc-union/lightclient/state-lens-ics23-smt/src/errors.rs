@@ -48,18 +48,6 @@ pub enum Error {
 
     #[error("could not decode the proof")]
     ProofDecode(bincode::error::DecodeError),
-
-    #[error("membership proof without a value")]
-    MembershipProofWithoutValue,
-
-    #[error("proof value {proof_value} doesn't match the given value {given})", proof_value = serde_utils::to_hex(.0), given = serde_utils::to_hex(.1))]
-    ProofValueMismatch(Vec<u8>, Vec<u8>),
-
-    #[error("proof value hash doesn't match the calculated one")]
-    ProofValueHashMismatch,
-
-    #[error("proof key hash doesn't match the calculated one")]
-    ProofKeyMismatch,
 }
 
 impl From<Error> for IbcClientError<StateLensIcs23SmtLightClient> {
@@ -6,7 +6,7 @@ use ibc_union_light_client::{
 };
 use sui_light_client_types::{
     client_state::ClientState, committee::Committee, consensus_state::ConsensusState,
-    header::Header, storage_proof::StorageProof, U64,
+    header::Header, misbehaviour::Misbehaviour, storage_proof::StorageProof, U64,
 };
 use unionlabs::encoding::{Bincode, DecodeAs, EncodeAs};
 
@@ -21,7 +21,7 @@ impl IbcClient for SuiLightClient {
 
     type Header = Header;
 
-    type Misbehaviour = Header;
+    type Misbehaviour = Misbehaviour;
 
     type ClientState = ClientState;
 
@@ -56,13 +56,19 @@ impl IbcClient for SuiLightClient {
         Ok(())
     }
 
+    /// Non-membership proofs are out of scope for this client and are always rejected.
+    ///
+    /// Sui checkpoints only commit to the effects of the transactions they contain, not to the
+    /// object set, so the absence of a commitment object cannot be proven against a checkpoint.
+    /// Membership of a commitment is proven through the effects of the transaction that last
+    /// wrote it instead (see [`sui_verifier::verify_membership`]).
     fn verify_non_membership(
         _ctx: ibc_union_light_client::IbcClientCtx<Self>,
         _height: u64,
         _key: Vec<u8>,
         _storage_proof: Self::StorageProof,
     ) -> Result<(), ibc_union_light_client::IbcClientError<Self>> {
-        Ok(())
+        Err(Error::NonMembershipNotSupported.into())
     }
 
     fn get_timestamp(consensus_state: &Self::ConsensusState) -> Timestamp {
@@ -160,12 +166,46 @@ impl IbcClient for SuiLightClient {
     }
 
     fn misbehaviour(
-        _ctx: ibc_union_light_client::IbcClientCtx<Self>,
+        ctx: ibc_union_light_client::IbcClientCtx<Self>,
         _caller: cosmwasm_std::Addr,
-        _misbehaviour: Self::Misbehaviour,
+        misbehaviour: Self::Misbehaviour,
         _relayer: cosmwasm_std::Addr,
     ) -> Result<Self::ClientState, ibc_union_light_client::IbcClientError<Self>> {
-        todo!()
+        let checkpoint_a = &misbehaviour.header_a.checkpoint_summary;
+        let checkpoint_b = &misbehaviour.header_b.checkpoint_summary;
+
+        if checkpoint_a.sequence_number != checkpoint_b.sequence_number {
+            return Err(Error::MisbehaviourSequenceNumberMismatch {
+                a: checkpoint_a.sequence_number,
+                b: checkpoint_b.sequence_number,
+            }
+            .into());
+        }
+
+        if checkpoint_a == checkpoint_b {
+            return Err(Error::MisbehaviourIdenticalCheckpoints.into());
+        }
+
+        let ClientState::V1(mut client_state) = ctx.read_self_client_state()?;
+
+        // both checkpoints must be certified by the committee of their epoch, otherwise anyone
+        // could freeze the client
+        for header in [&misbehaviour.header_a, &misbehaviour.header_b] {
+            let committee =
+                ctx.read_self_storage::<CommitteeStore>(header.checkpoint_summary.epoch)?;
+
+            sui_verifier::verify_checkpoint(
+                &committee,
+                &header.checkpoint_summary,
+                &header.sign_info,
+                &Verifier { deps: ctx.deps },
+            )
+            .map_err(Into::<Error>::into)?;
+        }
+
+        client_state.frozen_height = checkpoint_a.sequence_number;
+
+        Ok(ClientState::V1(client_state))
     }
 }
 
@@ -206,3 +246,237 @@ impl ValueCodec<Committee> for CommitteeStore {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{
+        testing::{mock_dependencies, mock_env, MockApi},
+        Addr, Api, HashFunction,
+    };
+    use ibc_union_light_client::{spec::ClientId, test_utils::MockIbcHost, IbcClientCtx};
+    use sui_light_client_types::{
+        checkpoint_summary::{
+            CheckpointContents, CheckpointContentsV1, CheckpointSummary, GasCostSummary,
+        },
+        client_state::ClientStateV1,
+        crypto::{AuthorityStrongQuorumSignInfo, CryptoBytes, SuiBitmap, BLS_DST},
+        digest::Digest,
+        object::{Data, MoveObject, MoveObjectType, ObjectInner},
+        transaction_effects::{ExecutionStatus, TransactionEffects, TransactionEffectsV1},
+        AppId, Intent, IntentMessage, IntentScope, IntentVersion, ObjectID, Owner,
+    };
+    use sui_verifier::SignatureVerification;
+    use unionlabs::primitives::FixedBytes;
+
+    use super::*;
+
+    fn gas_cost_summary() -> GasCostSummary {
+        GasCostSummary {
+            computation_cost: U64(0),
+            storage_cost: U64(0),
+            storage_rebate: U64(0),
+            non_refundable_storage_fee: U64(0),
+        }
+    }
+
+    fn header(sequence_number: u64, timestamp_ms: u64) -> Header {
+        Header {
+            trusted_height: 1,
+            checkpoint_summary: CheckpointSummary {
+                epoch: 1,
+                sequence_number,
+                network_total_transactions: 1,
+                content_digest: Digest(FixedBytes::new([1; 32])),
+                previous_digest: None,
+                epoch_rolling_gas_cost_summary: gas_cost_summary(),
+                timestamp_ms,
+                checkpoint_commitments: vec![],
+                end_of_epoch_data: None,
+                version_specific_data: vec![],
+            },
+            sign_info: AuthorityStrongQuorumSignInfo {
+                epoch: 1,
+                signature: CryptoBytes(FixedBytes::default()),
+                // an empty roaring bitmap
+                signers_map: SuiBitmap::deserialize_from_bytes(&[58, 48, 0, 0, 0, 0, 0, 0])
+                    .unwrap(),
+            },
+        }
+    }
+
+    /// The committee of epoch 1, whose only member has the secret key `1`. Its public key is the
+    /// generator of G2, so its signature of a message is the message hashed to G1.
+    fn committee() -> Committee {
+        Committee {
+            epoch: U64(1),
+            voting_rights: vec![(
+                CryptoBytes(FixedBytes::new(Verifier::BLS_GENERATOR)),
+                U64(1),
+            )],
+        }
+    }
+
+    /// Sign the checkpoint of `header` with the only member of [`committee`].
+    fn sign(mut header: Header) -> Header {
+        let mut intent_msg_bytes = bcs::to_bytes(&IntentMessage {
+            intent: Intent {
+                scope: IntentScope::CheckpointSummary,
+                version: IntentVersion::V0,
+                app_id: AppId::Sui,
+            },
+            value: header.checkpoint_summary.clone(),
+        })
+        .unwrap();
+        bcs::serialize_into(&mut intent_msg_bytes, &header.checkpoint_summary.epoch).unwrap();
+
+        let signature = MockApi::default()
+            .bls12_381_hash_to_g1(HashFunction::Sha256, &intent_msg_bytes, BLS_DST)
+            .unwrap();
+
+        header.sign_info.signature = CryptoBytes(FixedBytes::new(signature));
+        header.sign_info.signers_map.0.insert(0);
+
+        header
+    }
+
+    fn misbehaviour(
+        header_a: Header,
+        header_b: Header,
+    ) -> Result<ClientState, IbcClientError<SuiLightClient>> {
+        let creation = SuiLightClient::verify_creation(
+            Addr::unchecked("caller"),
+            &ClientState::V1(ClientStateV1 {
+                chain_id: "sui".to_owned(),
+                latest_checkpoint: 1,
+                frozen_height: 0,
+                ibc_commitments_object_id: ObjectID::new([1; 32]),
+                initial_committee: Some(committee()),
+            }),
+            &ConsensusState {
+                timestamp: 1_000_000,
+                content_digest: Digest(FixedBytes::new([1; 32])),
+            },
+            Addr::unchecked("relayer"),
+        )
+        .unwrap();
+
+        let mut deps = mock_dependencies();
+        deps.querier.update_wasm(
+            MockIbcHost::new(Addr::unchecked("ibc_host"))
+                .add_client_state::<SuiLightClient>(ClientId!(1), creation.client_state.unwrap())
+                .add_storage_writes(ClientId!(1), creation.storage_writes)
+                .querier(),
+        );
+
+        SuiLightClient::misbehaviour(
+            IbcClientCtx::new(
+                ClientId!(1),
+                Addr::unchecked("ibc_host"),
+                deps.as_ref(),
+                mock_env(),
+            ),
+            Addr::unchecked("caller"),
+            Misbehaviour { header_a, header_b },
+            Addr::unchecked("relayer"),
+        )
+    }
+
+    #[test]
+    fn misbehaviour_rejects_different_sequence_numbers() {
+        assert!(matches!(
+            misbehaviour(header(1, 1), header(2, 2)),
+            Err(IbcClientError::ClientSpecific(
+                Error::MisbehaviourSequenceNumberMismatch { a: 1, b: 2 }
+            ))
+        ));
+    }
+
+    #[test]
+    fn misbehaviour_rejects_identical_checkpoints() {
+        assert!(matches!(
+            misbehaviour(header(1, 1), header(1, 1)),
+            Err(IbcClientError::ClientSpecific(
+                Error::MisbehaviourIdenticalCheckpoints
+            ))
+        ));
+    }
+
+    #[test]
+    fn misbehaviour_rejects_invalid_signature() {
+        let mut header_b = sign(header(2, 2));
+        header_b.checkpoint_summary.timestamp_ms = 3;
+
+        assert!(matches!(
+            misbehaviour(sign(header(2, 1)), header_b),
+            Err(IbcClientError::ClientSpecific(Error::Verifier(_)))
+        ));
+    }
+
+    #[test]
+    fn misbehaviour_freezes_client() {
+        let ClientState::V1(client_state) =
+            misbehaviour(sign(header(2, 1)), sign(header(2, 2))).unwrap();
+
+        assert_eq!(client_state.frozen_height, 2);
+    }
+
+    #[test]
+    fn non_membership_not_supported() {
+        let deps = mock_dependencies();
+
+        let storage_proof = StorageProof {
+            checkpoint_contents: CheckpointContents::V1(CheckpointContentsV1 {
+                transactions: vec![],
+                user_signatures: vec![],
+            }),
+            transaction_effects: TransactionEffects::V1(Box::new(TransactionEffectsV1 {
+                status: ExecutionStatus::Success,
+                executed_epoch: 1,
+                gas_used: gas_cost_summary(),
+                modified_at_versions: vec![],
+                shared_objects: vec![],
+                transaction_digest: Digest(FixedBytes::new([1; 32])),
+                created: vec![],
+                mutated: vec![],
+                unwrapped: vec![],
+                deleted: vec![],
+                unwrapped_then_deleted: vec![],
+                wrapped: vec![],
+                gas_object: (
+                    (ObjectID::new([2; 32]), 1, Digest(FixedBytes::new([3; 32]))),
+                    Owner::AddressOwner(Default::default()),
+                ),
+                events_digest: None,
+                dependencies: vec![],
+            })),
+            object: ObjectInner {
+                data: Data::Move(MoveObject {
+                    type_: MoveObjectType::GasCoin,
+                    has_public_transfer: false,
+                    version: 1,
+                    contents: Default::default(),
+                }),
+                owner: Owner::AddressOwner(Default::default()),
+                previous_transaction: Digest(FixedBytes::new([1; 32])),
+                storage_rebate: 0,
+            },
+        };
+
+        assert!(matches!(
+            SuiLightClient::verify_non_membership(
+                IbcClientCtx::new(
+                    ClientId!(1),
+                    Addr::unchecked("ibc_host"),
+                    deps.as_ref(),
+                    mock_env()
+                ),
+                1,
+                b"key".to_vec(),
+                storage_proof,
+            ),
+            Err(IbcClientError::ClientSpecific(
+                Error::NonMembershipNotSupported
+            ))
+        ));
+    }
+}
@@ -18,6 +18,15 @@ pub enum Error {
 
     #[error("failed signature verification")]
     SignatureVerification,
+
+    #[error("non-membership proofs are not supported")]
+    NonMembershipNotSupported,
+
+    #[error("misbehaviour checkpoints have different sequence numbers ({a} != {b})")]
+    MisbehaviourSequenceNumberMismatch { a: u64, b: u64 },
+
+    #[error("misbehaviour checkpoints are identical")]
+    MisbehaviourIdenticalCheckpoints,
 }
 
 impl From<Error> for IbcClientError<SuiLightClient> {
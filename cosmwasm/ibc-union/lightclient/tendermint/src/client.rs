@@ -29,6 +29,12 @@ use crate::{
     verifier::Ed25519Verifier,
 };
 
+/// The key under the upgrade path that the upgraded client state is committed to.
+pub const UPGRADED_CLIENT_KEY: &str = "upgradedClient";
+
+/// The key under the upgrade path that the upgraded consensus state is committed to.
+pub const UPGRADED_CONSENSUS_STATE_KEY: &str = "upgradedConsState";
+
 pub struct TendermintLightClient;
 
 impl IbcClient for TendermintLightClient {
@@ -159,6 +165,26 @@ impl IbcClient for TendermintLightClient {
         client_state.chain_id.clone()
     }
 
+    fn verify_upgrade(
+        ctx: IbcClientCtx<Self>,
+        new_client_state: Self::ClientState,
+        new_consensus_state: Self::ConsensusState,
+        proof_upgrade_client: Self::StorageProof,
+        proof_upgrade_consensus_state: Self::StorageProof,
+    ) -> Result<StateUpdate<Self>, IbcClientError<Self>> {
+        let client_state = ctx.read_self_client_state()?;
+        let consensus_state = ctx.read_self_consensus_state(client_state.latest_height.height())?;
+
+        Ok(verify_upgrade(
+            client_state,
+            &consensus_state,
+            new_client_state,
+            new_consensus_state,
+            proof_upgrade_client,
+            proof_upgrade_consensus_state,
+        )?)
+    }
+
     fn verify_creation(
         _caller: Addr,
         _client_state: &Self::ClientState,
@@ -276,6 +302,130 @@ pub fn verify_header<V: HostFns>(
     }
 }
 
+/// Verify the upgraded client and consensus state committed to by the upgrade module of the
+/// counterparty chain, following ibc-go's `VerifyUpgradeAndUpdateState`.
+///
+/// Both are proven against the consensus state at the latest height of the client. The chain-chosen
+/// parameters are taken from the upgraded client, while the client-chosen parameters (trust level,
+/// trusting period and max clock drift) are kept from the current client.
+pub fn verify_upgrade(
+    client_state: ClientState,
+    consensus_state: &ConsensusState,
+    new_client_state: ClientState,
+    new_consensus_state: ConsensusState,
+    proof_upgrade_client: MerkleProof,
+    proof_upgrade_consensus_state: MerkleProof,
+) -> Result<StateUpdate<TendermintLightClient>, Error> {
+    let [upgrade_store_key, upgrade_key_prefix] = client_state.upgrade_path.as_slice() else {
+        return Err(Error::InvalidUpgradePath(client_state.upgrade_path));
+    };
+
+    if new_client_state.latest_height <= client_state.latest_height {
+        return Err(Error::UpgradedHeightNotGreater {
+            upgraded_height: new_client_state.latest_height,
+            latest_height: client_state.latest_height,
+        });
+    }
+
+    let upgrade_path = |key: &str| {
+        [
+            upgrade_store_key.as_bytes().to_vec(),
+            format!(
+                "{upgrade_key_prefix}/{}/{key}",
+                client_state.latest_height.height()
+            )
+            .into_bytes(),
+        ]
+    };
+
+    ics23::ibc_api::verify_membership(
+        &proof_upgrade_client,
+        &SDK_SPECS,
+        &consensus_state.root,
+        &upgrade_path(UPGRADED_CLIENT_KEY),
+        upgraded_client_state_commitment(&new_client_state),
+    )
+    .map_err(Error::VerifyUpgradedClientState)?;
+
+    ics23::ibc_api::verify_membership(
+        &proof_upgrade_consensus_state,
+        &SDK_SPECS,
+        &consensus_state.root,
+        &upgrade_path(UPGRADED_CONSENSUS_STATE_KEY),
+        upgraded_consensus_state_commitment(&new_consensus_state),
+    )
+    .map_err(Error::VerifyUpgradedConsensusState)?;
+
+    let height = new_client_state.latest_height.height();
+
+    Ok(StateUpdate::new(
+        height,
+        ConsensusState {
+            timestamp: new_consensus_state.timestamp,
+            // the upgraded consensus state has no root, proofs can only be verified against the
+            // consensus states of subsequent updates
+            root: MerkleRoot {
+                hash: H256::default(),
+            },
+            next_validators_hash: new_consensus_state.next_validators_hash,
+        },
+    )
+    .overwrite_client_state(ClientState {
+        chain_id: new_client_state.chain_id,
+        trust_level: client_state.trust_level,
+        trusting_period: client_state.trusting_period,
+        unbonding_period: new_client_state.unbonding_period,
+        max_clock_drift: client_state.max_clock_drift,
+        frozen_height: None,
+        latest_height: new_client_state.latest_height,
+        proof_specs: new_client_state.proof_specs,
+        upgrade_path: new_client_state.upgrade_path,
+        contract_address: client_state.contract_address,
+    }))
+}
+
+/// The value the upgrade module commits to for the upgraded client: the `Any` encoded client state,
+/// with all of the client-chosen fields zeroed.
+pub fn upgraded_client_state_commitment(client_state: &ClientState) -> Vec<u8> {
+    let client_state = protos::ibc::lightclients::tendermint::v1::ClientState {
+        chain_id: client_state.chain_id.clone(),
+        unbonding_period: Some(client_state.unbonding_period.into()),
+        latest_height: Some(client_state.latest_height.into()),
+        proof_specs: client_state
+            .proof_specs
+            .iter()
+            .cloned()
+            .map(Into::into)
+            .collect(),
+        upgrade_path: client_state.upgrade_path.clone(),
+        // these fields are not nullable in ibc-go, and as such are always encoded
+        trust_level: Some(Default::default()),
+        trusting_period: Some(Default::default()),
+        max_clock_drift: Some(Default::default()),
+        frozen_height: Some(Default::default()),
+        ..Default::default()
+    };
+
+    encode_any(&client_state)
+}
+
+/// The value the upgrade module commits to for the upgraded consensus state. The counterparty does
+/// not know the root of the upgraded chain, so it is always empty.
+pub fn upgraded_consensus_state_commitment(consensus_state: &ConsensusState) -> Vec<u8> {
+    encode_any(&protos::ibc::lightclients::tendermint::v1::ConsensusState {
+        timestamp: Some(consensus_state.timestamp.into()),
+        root: Some(Default::default()),
+        next_validators_hash: consensus_state.next_validators_hash.get().to_vec(),
+    })
+}
+
+fn encode_any<T: prost::Name>(msg: &T) -> Vec<u8> {
+    prost::Message::encode_to_vec(&protos::google::protobuf::Any {
+        type_url: T::type_url(),
+        value: prost::Message::encode_to_vec(msg),
+    })
+}
+
 pub fn set_total_voting_power(validator_set: &mut ValidatorSet) -> Result<(), MathOverflow> {
     validator_set.total_voting_power =
         validator_set
@@ -413,6 +563,283 @@ pub fn verify_non_membership(
     .map_err(Error::VerifyMembership)
 }
 
+#[cfg(test)]
+mod upgrade_tests {
+    use std::num::NonZeroU64;
+
+    use ics23::{
+        existence_proof::calculate_root,
+        ibc_api::{IAVL_PROOF_SPEC, TENDERMINT_PROOF_SPEC},
+    };
+    use tendermint_light_client_types::Fraction;
+    use unionlabs::{
+        cosmos::ics23::{
+            commitment_proof::CommitmentProof, existence_proof::ExistenceProof, inner_op::InnerOp,
+            leaf_op::LeafOp, proof_spec::ProofSpec,
+        },
+        google::protobuf::duration::Duration,
+    };
+
+    use super::*;
+
+    const UPGRADE_PLAN_HEIGHT: u64 = 10;
+
+    fn client_state() -> ClientState {
+        ClientState {
+            chain_id: "test-chain-1".to_owned(),
+            trust_level: Fraction {
+                numerator: 1,
+                denominator: NonZeroU64::new(3).unwrap(),
+            },
+            trusting_period: Duration::new(100, 0).unwrap(),
+            unbonding_period: Duration::new(200, 0).unwrap(),
+            max_clock_drift: Duration::new(10, 0).unwrap(),
+            frozen_height: None,
+            latest_height: Height::new_with_revision(1, UPGRADE_PLAN_HEIGHT),
+            proof_specs: SDK_SPECS.to_vec(),
+            upgrade_path: vec!["upgrade".to_owned(), "upgradedIBCState".to_owned()],
+            contract_address: H256::new([0xaa; 32]),
+        }
+    }
+
+    fn upgraded_client_state() -> ClientState {
+        ClientState {
+            chain_id: "test-chain-2".to_owned(),
+            unbonding_period: Duration::new(300, 0).unwrap(),
+            latest_height: Height::new_with_revision(2, 1),
+            ..client_state()
+        }
+    }
+
+    fn upgraded_consensus_state() -> ConsensusState {
+        ConsensusState {
+            timestamp: Timestamp::try_from_unix_nanos(1_000_000_000).unwrap(),
+            root: MerkleRoot {
+                hash: H256::default(),
+            },
+            next_validators_hash: H256::new([0xbb; 32]),
+        }
+    }
+
+    fn leaf(spec: &ProofSpec, prefix: &[u8], key: &str, value: Vec<u8>) -> ExistenceProof {
+        ExistenceProof {
+            key: key.as_bytes().to_vec().into(),
+            value: value.into(),
+            leaf: LeafOp {
+                prefix: prefix.to_vec().into(),
+                ..spec.leaf_spec.clone()
+            },
+            path: vec![],
+        }
+    }
+
+    /// Build the proofs of `client_value` and `consensus_value` in an upgrade store containing only
+    /// these two keys, committed to by a multistore containing only the upgrade store. Returns the
+    /// proofs along with the root of the multistore.
+    fn upgrade_store_proofs(
+        client_value: Vec<u8>,
+        consensus_value: Vec<u8>,
+    ) -> (MerkleProof, MerkleProof, MerkleRoot) {
+        // height 0, size 1, version 1
+        const IAVL_LEAF_PREFIX: [u8; 3] = [0x00, 0x02, 0x02];
+        // height 1, size 2, version 1, followed by the length of the left child
+        const IAVL_INNER_PREFIX: [u8; 4] = [0x02, 0x04, 0x02, 0x20];
+
+        let mut client_leaf = leaf(
+            &IAVL_PROOF_SPEC,
+            &IAVL_LEAF_PREFIX,
+            &format!("upgradedIBCState/{UPGRADE_PLAN_HEIGHT}/{UPGRADED_CLIENT_KEY}"),
+            client_value,
+        );
+        let mut consensus_leaf = leaf(
+            &IAVL_PROOF_SPEC,
+            &IAVL_LEAF_PREFIX,
+            &format!("upgradedIBCState/{UPGRADE_PLAN_HEIGHT}/{UPGRADED_CONSENSUS_STATE_KEY}"),
+            consensus_value,
+        );
+
+        let client_leaf_hash = calculate_root(&client_leaf).unwrap();
+        let consensus_leaf_hash = calculate_root(&consensus_leaf).unwrap();
+
+        client_leaf.path = vec![InnerOp {
+            hash: IAVL_PROOF_SPEC.inner_spec.hash,
+            prefix: IAVL_INNER_PREFIX.to_vec().into(),
+            suffix: [0x20].into_iter().chain(consensus_leaf_hash).collect(),
+        }];
+        consensus_leaf.path = vec![InnerOp {
+            hash: IAVL_PROOF_SPEC.inner_spec.hash,
+            prefix: IAVL_INNER_PREFIX
+                .into_iter()
+                .chain(client_leaf_hash)
+                .chain([0x20])
+                .collect(),
+            suffix: vec![].into(),
+        }];
+
+        let store_root = calculate_root(&client_leaf).unwrap();
+        assert_eq!(store_root, calculate_root(&consensus_leaf).unwrap());
+
+        let multistore = leaf(&TENDERMINT_PROOF_SPEC, &[0x00], "upgrade", store_root);
+        let root = MerkleRoot {
+            hash: H256::new(calculate_root(&multistore).unwrap().try_into().unwrap()),
+        };
+
+        let proof = |leaf| MerkleProof {
+            proofs: vec![
+                CommitmentProof::Exist(leaf),
+                CommitmentProof::Exist(multistore.clone()),
+            ],
+        };
+
+        (proof(client_leaf), proof(consensus_leaf), root)
+    }
+
+    fn trusted_consensus_state(root: MerkleRoot) -> ConsensusState {
+        ConsensusState {
+            root,
+            ..upgraded_consensus_state()
+        }
+    }
+
+    #[test]
+    fn upgrade() {
+        let (proof_client, proof_consensus, root) = upgrade_store_proofs(
+            upgraded_client_state_commitment(&upgraded_client_state()),
+            upgraded_consensus_state_commitment(&upgraded_consensus_state()),
+        );
+
+        // the client-chosen fields are not committed to, and must be ignored
+        let new_client_state = ClientState {
+            trusting_period: Duration::new(1, 0).unwrap(),
+            frozen_height: Some(Height::new(1)),
+            ..upgraded_client_state()
+        };
+
+        let state_update = verify_upgrade(
+            client_state(),
+            &trusted_consensus_state(root),
+            new_client_state,
+            upgraded_consensus_state(),
+            proof_client,
+            proof_consensus,
+        )
+        .unwrap();
+
+        assert_eq!(state_update.height, 1);
+        assert_eq!(
+            state_update.client_state,
+            Some(ClientState {
+                chain_id: "test-chain-2".to_owned(),
+                unbonding_period: Duration::new(300, 0).unwrap(),
+                latest_height: Height::new_with_revision(2, 1),
+                ..client_state()
+            })
+        );
+        assert_eq!(
+            state_update.consensus_state.unwrap().next_validators_hash,
+            upgraded_consensus_state().next_validators_hash
+        );
+    }
+
+    #[test]
+    fn upgrade_rejects_uncommitted_client_state() {
+        let (proof_client, proof_consensus, root) = upgrade_store_proofs(
+            upgraded_client_state_commitment(&upgraded_client_state()),
+            upgraded_consensus_state_commitment(&upgraded_consensus_state()),
+        );
+
+        let new_client_state = ClientState {
+            unbonding_period: Duration::new(400, 0).unwrap(),
+            ..upgraded_client_state()
+        };
+
+        assert!(matches!(
+            verify_upgrade(
+                client_state(),
+                &trusted_consensus_state(root),
+                new_client_state,
+                upgraded_consensus_state(),
+                proof_client,
+                proof_consensus,
+            ),
+            Err(Error::VerifyUpgradedClientState(_))
+        ));
+    }
+
+    #[test]
+    fn upgrade_rejects_uncommitted_consensus_state() {
+        let (proof_client, proof_consensus, root) = upgrade_store_proofs(
+            upgraded_client_state_commitment(&upgraded_client_state()),
+            upgraded_consensus_state_commitment(&upgraded_consensus_state()),
+        );
+
+        let new_consensus_state = ConsensusState {
+            next_validators_hash: H256::new([0xcc; 32]),
+            ..upgraded_consensus_state()
+        };
+
+        assert!(matches!(
+            verify_upgrade(
+                client_state(),
+                &trusted_consensus_state(root),
+                upgraded_client_state(),
+                new_consensus_state,
+                proof_client,
+                proof_consensus,
+            ),
+            Err(Error::VerifyUpgradedConsensusState(_))
+        ));
+    }
+
+    #[test]
+    fn upgrade_rejects_lower_height() {
+        let (proof_client, proof_consensus, root) = upgrade_store_proofs(
+            upgraded_client_state_commitment(&upgraded_client_state()),
+            upgraded_consensus_state_commitment(&upgraded_consensus_state()),
+        );
+
+        let new_client_state = ClientState {
+            latest_height: Height::new_with_revision(1, UPGRADE_PLAN_HEIGHT),
+            ..upgraded_client_state()
+        };
+
+        assert!(matches!(
+            verify_upgrade(
+                client_state(),
+                &trusted_consensus_state(root),
+                new_client_state,
+                upgraded_consensus_state(),
+                proof_client,
+                proof_consensus,
+            ),
+            Err(Error::UpgradedHeightNotGreater { .. })
+        ));
+    }
+
+    #[test]
+    fn upgrade_rejects_invalid_upgrade_path() {
+        let (proof_client, proof_consensus, root) = upgrade_store_proofs(
+            upgraded_client_state_commitment(&upgraded_client_state()),
+            upgraded_consensus_state_commitment(&upgraded_consensus_state()),
+        );
+
+        assert!(matches!(
+            verify_upgrade(
+                ClientState {
+                    upgrade_path: vec![],
+                    ..client_state()
+                },
+                &trusted_consensus_state(root),
+                upgraded_client_state(),
+                upgraded_consensus_state(),
+                proof_client,
+                proof_consensus,
+            ),
+            Err(Error::InvalidUpgradePath(_))
+        ));
+    }
+}
+
 // #[cfg(test)]
 // mod tests {
 //     use std::fs;
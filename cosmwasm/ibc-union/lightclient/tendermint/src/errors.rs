@@ -2,7 +2,7 @@ use ibc_union_light_client::IbcClientError;
 use tendermint_light_client_types::{ClientState, Header};
 use unionlabs::{
     encoding::{DecodeErrorOf, Proto},
-    ibc::core::commitment::merkle_proof::MerkleProof,
+    ibc::core::{client::height::Height, commitment::merkle_proof::MerkleProof},
     primitives::H256,
 };
 
@@ -60,6 +60,21 @@ pub enum Error {
 
     #[error("invalid or empty validator set, supported keys are: bls12381 and ed25519")]
     InvalidValidatorSet,
+
+    #[error("the upgrade path must contain exactly two keys, found {0:?}")]
+    InvalidUpgradePath(Vec<String>),
+
+    #[error("the upgraded client's latest height ({upgraded_height}) must be greater than the latest height ({latest_height})")]
+    UpgradedHeightNotGreater {
+        upgraded_height: Height,
+        latest_height: Height,
+    },
+
+    #[error("unable to verify the upgraded client state")]
+    VerifyUpgradedClientState(#[source] ics23::ibc_api::VerifyMembershipError),
+
+    #[error("unable to verify the upgraded consensus state")]
+    VerifyUpgradedConsensusState(#[source] ics23::ibc_api::VerifyMembershipError),
 }
 
 #[derive(Debug, Clone, PartialEq, thiserror::Error)]
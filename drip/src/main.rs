@@ -15,6 +15,7 @@ use axum::{
 };
 use chrono::{NaiveDateTime, Utc};
 use clap::Parser;
+use concurrent_keyring::{Key, KeyError, KeyringConfigEntry};
 use cosmos_client::{
     gas::{
         any::GasFiller,
@@ -22,7 +23,7 @@ use cosmos_client::{
         fixed, osmosis_eip1559_feemarket,
     },
     rpc::{Rpc, RpcT},
-    wallet::{LocalSigner, WalletT},
+    wallet::{AnySigner, KeyType, WalletT},
     TxClient,
 };
 use prost::{Message, Name};
@@ -137,7 +138,10 @@ async fn poll_loop(pool: Pool, chain: Chain, batch_size: usize) {
                 // recreate each time so that if this task panics, the keyring gets rebuilt
                 // make sure to panic *here* so that the tokio task will catch the panic!
                 info!("creating chain client");
-                let chain_client = ChainClient::new(&chain).await;
+                let chain_client = match ChainClient::new(&chain).await {
+                    Ok(chain_client) => chain_client,
+                    Err(err) => panic!("unable to create chain client: {}", ErrorReporter(&*err)),
+                };
                 info!("entering polling loop");
                 loop {
                     let chain = chain.clone();
@@ -308,11 +312,29 @@ pub struct Chain {
     pub bech32_prefix: String,
     pub rpc_url: String,
     pub gas_config: GasFillerConfig,
-    pub signer: H256,
+    pub signer: ChainSigner,
     pub coins: Vec<Coin>,
     pub memo: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ChainSigner {
+    /// The private key stored in plaintext.
+    Raw(H256),
+    /// Any key supported by the keyring, including encrypted keys and remote signers.
+    Keyring(KeyringConfigEntry),
+}
+
+impl ChainSigner {
+    pub fn key(&self) -> Result<Key, KeyError> {
+        match self {
+            ChainSigner::Raw(private_key) => Ok(Key::Local(private_key.get().to_vec())),
+            ChainSigner::Keyring(entry) => entry.key(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case", tag = "type", content = "config")]
 pub enum GasFillerConfig {
@@ -381,12 +403,12 @@ pub struct CaptchaBypassSecret(pub String);
 #[derive(Clone)]
 struct ChainClient {
     pub chain: Chain,
-    pub cosmos_ctx: Arc<TxClient<LocalSigner, Rpc, GasFiller>>,
+    pub cosmos_ctx: Arc<TxClient<AnySigner, Rpc, GasFiller>>,
 }
 
 impl ChainClient {
     #[instrument(skip_all, fields(chain_id = %chain.id))]
-    pub async fn new(chain: &Chain) -> Self {
+    pub async fn new(chain: &Chain) -> anyhow::Result<Self> {
         let rpc = Rpc::new(chain.rpc_url.clone()).await.unwrap();
 
         let bech32_prefix = rpc
@@ -411,7 +433,7 @@ impl ChainClient {
             .await;
 
         let ctx = TxClient::new(
-            LocalSigner::new(chain.signer, bech32_prefix),
+            AnySigner::new(chain.signer.key()?, KeyType::Secp256k1, bech32_prefix)?,
             rpc,
             gas_filler,
         );
@@ -425,10 +447,10 @@ impl ChainClient {
             chain.rpc_url, chain.id
         );
 
-        Self {
+        Ok(Self {
             chain: chain.clone(),
             cosmos_ctx: Arc::new(ctx),
-        }
+        })
     }
 }
 
@@ -0,0 +1,7 @@
+use voyager_finality_module_mock::Module;
+use voyager_sdk::plugin::FinalityModule;
+
+#[tokio::main(flavor = "multi_thread")]
+async fn main() {
+    Module::run().await
+}
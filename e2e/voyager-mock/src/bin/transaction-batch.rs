@@ -0,0 +1,7 @@
+use voyager_plugin_transaction_batch::Module;
+use voyager_sdk::plugin::Plugin;
+
+#[tokio::main(flavor = "multi_thread")]
+async fn main() {
+    Module::run().await
+}
@@ -0,0 +1,133 @@
+//! Time out a packet that was never relayed with `voyager-plugin-clear-packets`. There is no
+//! transaction batch plugin running, so the packet is only ever handled by clearing it.
+
+use std::time::Duration;
+
+use ibc_union_spec::{
+    path::{BatchPacketsPath, ChannelPath, COMMITMENT_MAGIC_ACK},
+    Channel, ChannelId, ChannelState, Duration as IbcDuration,
+};
+use mock_chain::{rpc, ChainConfig, MockNetwork};
+use serde_json::json;
+use unionlabs::primitives::H256;
+use voyager_plugin_clear_packets::call::{ClearPackets, ModuleCall};
+use voyager_plugin_open_path::call::PathConfig;
+use voyager_sdk::{
+    message::{PluginMessage, VoyagerMessage},
+    primitives::ClientType,
+    vm::call,
+};
+
+use crate::common::{
+    create_client_op, enqueue, localhost, plugin, plugins, query, start_engine, wait_for,
+};
+
+mod common;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn clear_timed_out_packet() {
+    let network = MockNetwork::new();
+
+    let chain_a = network.add_chain(ChainConfig::new("mock-a")).unwrap();
+    let chain_b = network
+        .add_chain(ChainConfig::new("mock-b").with_finality_lag(2))
+        .unwrap();
+
+    for _ in 0..3 {
+        network.produce_blocks();
+    }
+
+    let (rpc_a, _server_a) = rpc::serve(chain_a.clone(), localhost(0)).await.unwrap();
+    let (rpc_b, _server_b) = rpc::serve(chain_b.clone(), localhost(0)).await.unwrap();
+
+    let chains = [(&chain_a, rpc_a), (&chain_b, rpc_b)];
+
+    let (engine, rest_laddr) = start_engine(
+        &chains,
+        chains
+            .iter()
+            .flat_map(|(chain, rpc_addr)| plugins(chain, *rpc_addr))
+            .chain([
+                plugin("open-path", json!({})),
+                plugin("clear-packets", json!({})),
+            ])
+            .collect(),
+    )
+    .await;
+
+    let block_producer = network.spawn_block_producer(Duration::from_millis(200));
+
+    enqueue(
+        rest_laddr,
+        voyager_plugin_open_path::open_path(
+            PathConfig {
+                chain_a: chain_a.chain_id().clone(),
+                chain_b: chain_b.chain_id().clone(),
+                client_type_a: ClientType::new(ClientType::MOCK),
+                client_type_b: ClientType::new(ClientType::MOCK),
+                port_a: b"port-a".as_slice().into(),
+                port_b: b"port-b".as_slice().into(),
+                version: "mock-1".to_owned(),
+            },
+            create_client_op(&chain_a, &chain_b),
+            create_client_op(&chain_b, &chain_a),
+        ),
+    )
+    .await;
+
+    let channel_id = ChannelId::from_raw(1).unwrap();
+
+    wait_for(|| {
+        query::<Channel>(&chain_a, ChannelPath { channel_id })
+            .filter(|channel| channel.state == ChannelState::Open)
+    })
+    .await;
+
+    // ten blocks after the latest finalized block of the destination chain
+    let timeout_timestamp = chain_b
+        .block(chain_b.latest_height(true))
+        .unwrap()
+        .timestamp
+        .plus_duration(IbcDuration::from_secs(10))
+        .unwrap();
+
+    let tx_hash = chain_a.send_packet(channel_id, b"hello".as_slice().into(), timeout_timestamp);
+
+    let packet = wait_for(|| chain_a.sent_packet(tx_hash)).await;
+
+    wait_for(|| {
+        (chain_b
+            .block(chain_b.latest_height(true))
+            .unwrap()
+            .timestamp
+            >= timeout_timestamp)
+            .then_some(())
+    })
+    .await;
+
+    enqueue(
+        rest_laddr,
+        call::<VoyagerMessage>(PluginMessage::new(
+            voyager_plugin_clear_packets::plugin_name(),
+            ModuleCall::from(ClearPackets {
+                chain_id: chain_a.chain_id().clone(),
+                channel_id,
+                from_height: 1,
+                to_height: None,
+            }),
+        )),
+    )
+    .await;
+
+    // the commitment is overwritten once the packet is timed out
+    let commitment_path = BatchPacketsPath::from_packets(&[packet]);
+
+    wait_for(|| {
+        (query::<H256>(&chain_a, commitment_path.clone()) == Some(COMMITMENT_MAGIC_ACK))
+            .then_some(())
+    })
+    .await;
+
+    block_producer.abort();
+    engine.abort();
+}
@@ -0,0 +1,301 @@
+//! Shared harness for the tests that run the full voyager engine against mock chains, using the
+//! mock modules and plugins in `voyager/modules/*/mock` and `voyager/plugins/*/mock`, built as
+//! binaries of this package so that cargo builds them before the tests run.
+
+// each test only uses part of the harness
+#![allow(dead_code)]
+
+use std::{
+    net::{Ipv4Addr, SocketAddr, TcpListener},
+    path::PathBuf,
+    time::Duration,
+};
+
+use ibc_union_spec::{
+    datagram::{Datagram, MsgCreateClient},
+    path::StorePath,
+    IbcUnion,
+};
+use mock_chain::{types::encode, MockChain, MockNetwork, TxResult};
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+use tokio::task::JoinHandle;
+use unionlabs::ibc::core::client::height::Height;
+use voyager_core::{
+    context::{ModuleConfig, ModulesConfig, PluginConfig},
+    Engine,
+};
+use voyager_sdk::{
+    message::{
+        call::{Call, Index, SubmitTx},
+        data::IbcDatagram,
+        VoyagerMessage,
+    },
+    primitives::{ClientType, ConsensusType, IbcInterface, IbcSpec},
+    rpc::types::{
+        ClientBootstrapModuleInfo, ClientModuleInfo, FinalityModuleInfo, ProofModuleInfo,
+        StateModuleInfo,
+    },
+    vm::{call, Op},
+};
+
+pub const TIMEOUT: Duration = Duration::from_secs(120);
+
+/// The path to `name`, one of the voyager binaries built by this package.
+pub fn bin(name: &str) -> PathBuf {
+    match name {
+        "state-module" => env!("CARGO_BIN_EXE_state-module"),
+        "proof-module" => env!("CARGO_BIN_EXE_proof-module"),
+        "finality-module" => env!("CARGO_BIN_EXE_finality-module"),
+        "client-module" => env!("CARGO_BIN_EXE_client-module"),
+        "client-bootstrap-module" => env!("CARGO_BIN_EXE_client-bootstrap-module"),
+        "event-source-plugin" => env!("CARGO_BIN_EXE_event-source-plugin"),
+        "client-update-plugin" => env!("CARGO_BIN_EXE_client-update-plugin"),
+        "transaction-plugin" => env!("CARGO_BIN_EXE_transaction-plugin"),
+        "transaction-batch" => env!("CARGO_BIN_EXE_transaction-batch"),
+        "open-path" => env!("CARGO_BIN_EXE_open-path"),
+        "clear-packets" => env!("CARGO_BIN_EXE_clear-packets"),
+        _ => panic!("unknown binary {name}"),
+    }
+    .into()
+}
+
+pub fn localhost(port: u16) -> SocketAddr {
+    SocketAddr::from((Ipv4Addr::LOCALHOST, port))
+}
+
+pub fn free_port() -> u16 {
+    TcpListener::bind(localhost(0))
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port()
+}
+
+/// The op to create a client on `host` tracking `counterparty`, submitted through voyager.
+pub fn create_client_op(host: &MockChain, counterparty: &MockChain) -> Op<VoyagerMessage> {
+    let height = counterparty.latest_height(true);
+
+    call(SubmitTx {
+        chain_id: host.chain_id().clone(),
+        datagrams: vec![IbcDatagram::new::<IbcUnion>(Datagram::from(
+            MsgCreateClient {
+                client_type: ClientType::new(ClientType::MOCK),
+                client_state_bytes: encode(&counterparty.self_client_state(height).unwrap()),
+                consensus_state_bytes: encode(&counterparty.self_consensus_state(height).unwrap()),
+            },
+        ))],
+    })
+}
+
+/// Create a client on `host` tracking `counterparty`, and include it in a block.
+pub fn create_client(network: &MockNetwork, host: &MockChain, counterparty: &MockChain) {
+    let height = counterparty.latest_height(true);
+
+    let tx_hash = host
+        .submit(vec![Datagram::from(MsgCreateClient {
+            client_type: ClientType::new(ClientType::MOCK),
+            client_state_bytes: encode(&counterparty.self_client_state(height).unwrap()),
+            consensus_state_bytes: encode(&counterparty.self_consensus_state(height).unwrap()),
+        })])
+        .unwrap();
+
+    network.produce_blocks();
+
+    assert!(
+        matches!(host.tx_result(tx_hash), Some(TxResult::Success { .. })),
+        "client creation failed: {:?}",
+        host.tx_result(tx_hash)
+    );
+}
+
+pub async fn submit(chain: &MockChain, datagram: Datagram) {
+    let tx_hash = wait_for(|| chain.submit(vec![datagram.clone()]).ok()).await;
+
+    let result = wait_for(|| chain.tx_result(tx_hash).filter(|r| *r != TxResult::Pending)).await;
+
+    assert!(
+        matches!(result, TxResult::Success { .. }),
+        "transaction failed: {result:?}"
+    );
+}
+
+pub async fn wait_for<T>(mut f: impl FnMut() -> Option<T>) -> T {
+    tokio::time::timeout(TIMEOUT, async {
+        loop {
+            if let Some(t) = f() {
+                return t;
+            }
+
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+    })
+    .await
+    .expect("timed out")
+}
+
+/// Enqueue `op` into the engine, retrying until the REST server is up.
+pub async fn enqueue(rest_laddr: SocketAddr, op: Op<VoyagerMessage>) {
+    let client = reqwest::Client::new();
+
+    tokio::time::timeout(TIMEOUT, async {
+        loop {
+            let res = client
+                .post(format!("http://{rest_laddr}/enqueue"))
+                .json(&op)
+                .send()
+                .await
+                .and_then(|res| res.error_for_status());
+
+            match res {
+                Ok(_) => return,
+                Err(_) => tokio::time::sleep(Duration::from_millis(100)).await,
+            }
+        }
+    })
+    .await
+    .expect("timed out");
+}
+
+/// Start an engine with the mock modules for `chains` and `plugins`, returning the engine task and the address of its REST server. Each chain is indexed from its latest finalized height.
+pub async fn start_engine(
+    chains: &[(&MockChain, SocketAddr)],
+    plugins: Vec<PluginConfig>,
+) -> (JoinHandle<()>, SocketAddr) {
+    let rest_laddr = localhost(free_port());
+
+    let engine = Engine::builder()
+        .with_plugins(plugins)
+        .with_modules(modules(chains))
+        .with_rest_laddr(rest_laddr)
+        .with_rpc_laddr(localhost(free_port()))
+        .register_ibc_spec_handler::<IbcUnion>()
+        .build()
+        .await
+        .unwrap();
+
+    let engine = tokio::spawn(async move { engine.run().await });
+
+    for (chain, _) in chains {
+        enqueue(
+            rest_laddr,
+            call(Call::Index(Index {
+                chain_id: chain.chain_id().clone(),
+                start_height: Height::new(chain.latest_height(true)),
+            })),
+        )
+        .await;
+    }
+
+    (engine, rest_laddr)
+}
+
+/// Query the state at `path` on `chain` at its latest height.
+pub fn query<T: DeserializeOwned>(chain: &MockChain, path: impl Into<StorePath>) -> Option<T> {
+    serde_json::from_value(
+        chain
+            .query_ibc_state(chain.latest_height(false), &path.into())
+            .unwrap(),
+    )
+    .unwrap()
+}
+
+/// The plugin `name`, see [`bin`].
+pub fn plugin(name: &str, config: Value) -> PluginConfig {
+    PluginConfig {
+        path: bin(name),
+        config,
+        enabled: true,
+    }
+}
+
+/// The mock event source, client update, and transaction plugins for `chain`.
+pub fn plugins(chain: &MockChain, rpc_addr: SocketAddr) -> Vec<PluginConfig> {
+    let config = json!({ "chain_id": chain.chain_id(), "rpc_url": format!("http://{rpc_addr}") });
+
+    [
+        "event-source-plugin",
+        "client-update-plugin",
+        "transaction-plugin",
+    ]
+    .into_iter()
+    .map(|name| plugin(name, config.clone()))
+    .collect()
+}
+
+fn modules(chains: &[(&MockChain, SocketAddr)]) -> ModulesConfig {
+    let module = |name: &str, info, rpc_addr: Option<SocketAddr>| ModuleConfig {
+        path: bin(name),
+        info,
+        config: match rpc_addr {
+            Some(rpc_addr) => json!({ "rpc_url": format!("http://{rpc_addr}") }),
+            None => json!({}),
+        },
+        enabled: true,
+    };
+
+    ModulesConfig {
+        state: chains
+            .iter()
+            .map(|(chain, rpc_addr)| {
+                module(
+                    "state-module",
+                    StateModuleInfo {
+                        chain_id: chain.chain_id().clone(),
+                        ibc_spec_id: IbcUnion::ID,
+                    },
+                    Some(*rpc_addr),
+                )
+            })
+            .collect(),
+        proof: chains
+            .iter()
+            .map(|(chain, rpc_addr)| {
+                module(
+                    "proof-module",
+                    ProofModuleInfo {
+                        chain_id: chain.chain_id().clone(),
+                        ibc_spec_id: IbcUnion::ID,
+                    },
+                    Some(*rpc_addr),
+                )
+            })
+            .collect(),
+        consensus: chains
+            .iter()
+            .map(|(chain, rpc_addr)| {
+                module(
+                    "finality-module",
+                    FinalityModuleInfo {
+                        chain_id: chain.chain_id().clone(),
+                        consensus_type: ConsensusType::new(ConsensusType::MOCK),
+                    },
+                    Some(*rpc_addr),
+                )
+            })
+            .collect(),
+        client: vec![module(
+            "client-module",
+            ClientModuleInfo {
+                client_type: ClientType::new(ClientType::MOCK),
+                consensus_type: ConsensusType::new(ConsensusType::MOCK),
+                ibc_interface: IbcInterface::new(IbcInterface::MOCK),
+                ibc_spec_id: IbcUnion::ID,
+            },
+            None,
+        )],
+        client_bootstrap: chains
+            .iter()
+            .map(|(chain, rpc_addr)| {
+                module(
+                    "client-bootstrap-module",
+                    ClientBootstrapModuleInfo {
+                        client_type: ClientType::new(ClientType::MOCK),
+                        chain_id: chain.chain_id().clone(),
+                    },
+                    Some(*rpc_addr),
+                )
+            })
+            .collect(),
+    }
+}
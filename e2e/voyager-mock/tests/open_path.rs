@@ -0,0 +1,155 @@
+//! Open a path between two mock chains with `voyager-plugin-open-path`, where both chains already
+//! have clients and connections of the same type that the path must not bind to.
+
+use std::time::Duration;
+
+use ibc_union_spec::{
+    datagram::{Datagram, MsgConnectionOpenInit},
+    path::{ChannelPath, ConnectionPath},
+    Channel, ChannelId, ChannelState, ClientId, Connection, ConnectionId, ConnectionState,
+};
+use mock_chain::{rpc, ChainConfig, MockChain, MockNetwork};
+use serde_json::json;
+use voyager_plugin_open_path::call::PathConfig;
+use voyager_sdk::primitives::ClientType;
+
+use crate::common::{
+    create_client, create_client_op, enqueue, localhost, plugin, plugins, query, start_engine,
+    submit, wait_for,
+};
+
+mod common;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn open_path() {
+    let network = MockNetwork::new();
+
+    let chain_a = network.add_chain(ChainConfig::new("mock-a")).unwrap();
+    let chain_b = network
+        .add_chain(ChainConfig::new("mock-b").with_finality_lag(2))
+        .unwrap();
+
+    for _ in 0..3 {
+        network.produce_blocks();
+    }
+
+    let (rpc_a, _server_a) = rpc::serve(chain_a.clone(), localhost(0)).await.unwrap();
+    let (rpc_b, _server_b) = rpc::serve(chain_b.clone(), localhost(0)).await.unwrap();
+
+    // clients of the same type as the ones created for the path, tracking the same chains
+    create_client(&network, &chain_a, &chain_b);
+    create_client(&network, &chain_b, &chain_a);
+
+    let id = |id| ClientId::from_raw(id).unwrap();
+
+    let chains = [(&chain_a, rpc_a), (&chain_b, rpc_b)];
+
+    let (engine, rest_laddr) = start_engine(
+        &chains,
+        chains
+            .iter()
+            .flat_map(|(chain, rpc_addr)| plugins(chain, *rpc_addr))
+            .chain([plugin("open-path", json!({}))])
+            .collect(),
+    )
+    .await;
+
+    let block_producer = network.spawn_block_producer(Duration::from_millis(200));
+
+    // a connection that is never opened, between the existing clients
+    submit(
+        &chain_a,
+        Datagram::from(MsgConnectionOpenInit {
+            client_id: id(1),
+            counterparty_client_id: id(1),
+        }),
+    )
+    .await;
+
+    enqueue(
+        rest_laddr,
+        voyager_plugin_open_path::open_path(
+            PathConfig {
+                chain_a: chain_a.chain_id().clone(),
+                chain_b: chain_b.chain_id().clone(),
+                client_type_a: ClientType::new(ClientType::MOCK),
+                client_type_b: ClientType::new(ClientType::MOCK),
+                port_a: b"port-a".as_slice().into(),
+                port_b: b"port-b".as_slice().into(),
+                version: "mock-1".to_owned(),
+            },
+            create_client_op(&chain_a, &chain_b),
+            create_client_op(&chain_b, &chain_a),
+        ),
+    )
+    .await;
+
+    let channel_b = wait_for(|| {
+        query::<Channel>(
+            &chain_b,
+            ChannelPath {
+                channel_id: ChannelId::from_raw(1).unwrap(),
+            },
+        )
+        .filter(|channel| channel.state == ChannelState::Open)
+    })
+    .await;
+
+    // the path is opened over the newly created clients and connections
+    let connection = |chain: &MockChain, connection_id| {
+        query::<Connection>(
+            chain,
+            ConnectionPath {
+                connection_id: ConnectionId::from_raw(connection_id).unwrap(),
+            },
+        )
+        .unwrap()
+    };
+
+    assert_eq!(
+        connection(&chain_a, 2),
+        Connection {
+            state: ConnectionState::Open,
+            client_id: id(2),
+            counterparty_client_id: id(2),
+            counterparty_connection_id: Some(ConnectionId::from_raw(1).unwrap()),
+        }
+    );
+    assert_eq!(
+        connection(&chain_b, 1),
+        Connection {
+            state: ConnectionState::Open,
+            client_id: id(2),
+            counterparty_client_id: id(2),
+            counterparty_connection_id: Some(ConnectionId::from_raw(2).unwrap()),
+        }
+    );
+    assert_eq!(connection(&chain_a, 1).state, ConnectionState::Init);
+
+    assert_eq!(
+        channel_b,
+        Channel {
+            state: ChannelState::Open,
+            connection_id: ConnectionId::from_raw(1).unwrap(),
+            counterparty_channel_id: Some(ChannelId::from_raw(1).unwrap()),
+            counterparty_port_id: b"port-a".as_slice().into(),
+            version: "mock-1".to_owned(),
+        }
+    );
+
+    let channel_a = wait_for(|| {
+        query::<Channel>(
+            &chain_a,
+            ChannelPath {
+                channel_id: ChannelId::from_raw(1).unwrap(),
+            },
+        )
+    })
+    .await;
+
+    assert_eq!(channel_a.state, ChannelState::Open);
+    assert_eq!(channel_a.connection_id, ConnectionId::from_raw(2).unwrap());
+
+    block_producer.abort();
+    engine.abort();
+}
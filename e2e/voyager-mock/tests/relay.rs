@@ -0,0 +1,141 @@
+//! Relay a packet between two mock chains with the full voyager engine, using the mock modules and
+//! plugins in `voyager/modules/*/mock` and `voyager/plugins/*/mock`.
+
+use std::{future::Future, time::Duration};
+
+use ibc_union_spec::{
+    datagram::{Datagram, MsgChannelOpenInit, MsgConnectionOpenInit},
+    path::{BatchPacketsPath, ChannelPath, ConnectionPath, COMMITMENT_MAGIC_ACK},
+    Channel, ChannelId, ChannelState, ClientId, Connection, ConnectionId, ConnectionState,
+    Timestamp,
+};
+use mock_chain::{rpc, ChainConfig, MockChain, MockNetwork};
+use serde_json::json;
+use unionlabs::primitives::H256;
+
+use crate::common::{
+    create_client, localhost, plugin, plugins, query, start_engine, submit, wait_for,
+};
+
+mod common;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn relay_packet() {
+    let network = MockNetwork::new();
+
+    let chain_a = network.add_chain(ChainConfig::new("mock-a")).unwrap();
+    let chain_b = network
+        .add_chain(ChainConfig::new("mock-b").with_finality_lag(2))
+        .unwrap();
+
+    for _ in 0..3 {
+        network.produce_blocks();
+    }
+
+    let (rpc_a, _server_a) = rpc::serve(chain_a.clone(), localhost(0)).await.unwrap();
+    let (rpc_b, _server_b) = rpc::serve(chain_b.clone(), localhost(0)).await.unwrap();
+
+    create_client(&network, &chain_a, &chain_b);
+    create_client(&network, &chain_b, &chain_a);
+
+    let client_id = ClientId::from_raw(1).unwrap();
+
+    let chains = [(&chain_a, rpc_a), (&chain_b, rpc_b)];
+
+    let (engine, _) = start_engine(
+        &chains,
+        chains
+            .iter()
+            .flat_map(|(chain, rpc_addr)| {
+                plugins(chain, *rpc_addr).into_iter().chain([plugin(
+                    "transaction-batch",
+                    json!({
+                        "chain_id": chain.chain_id(),
+                        "client_configs": {
+                            "min_batch_size": 1,
+                            "max_batch_size": 3,
+                            "max_wait_time": { "secs": 1, "nanos": 0 }
+                        }
+                    }),
+                )])
+            })
+            .collect(),
+    )
+    .await;
+
+    let block_producer = network.spawn_block_producer(Duration::from_millis(200));
+
+    // the relayer must recover from transient rpc and submission failures
+    chain_a.fail_rpc_requests(3);
+    chain_b.fail_submissions(1);
+
+    submit(
+        &chain_a,
+        Datagram::from(MsgConnectionOpenInit {
+            client_id,
+            counterparty_client_id: client_id,
+        }),
+    )
+    .await;
+
+    wait_for_open_channel(&chain_a, ChannelId::from_raw(1).unwrap(), || {
+        submit(
+            &chain_a,
+            Datagram::from(MsgChannelOpenInit {
+                port_id: b"port-a".as_slice().into(),
+                counterparty_port_id: b"port-b".as_slice().into(),
+                connection_id: ConnectionId::from_raw(1).unwrap(),
+                version: "mock-1".to_owned(),
+            }),
+        )
+    })
+    .await;
+
+    let tx_hash = chain_a.send_packet(
+        ChannelId::from_raw(1).unwrap(),
+        b"hello".as_slice().into(),
+        Timestamp::from_secs(u64::from(u32::MAX)),
+    );
+
+    let packet = wait_for(|| chain_a.sent_packet(tx_hash)).await;
+
+    // revert the block on the destination chain that the packet may have already been received in
+    chain_b.reorg(1).unwrap();
+
+    let commitment_path = BatchPacketsPath::from_packets(&[packet]);
+
+    wait_for(|| {
+        (query::<H256>(&chain_a, commitment_path.clone()) == Some(COMMITMENT_MAGIC_ACK))
+            .then_some(())
+    })
+    .await;
+
+    block_producer.abort();
+    engine.abort();
+}
+
+/// Wait for `channel_id` to be open on `chain`, submitting the channel handshake with `open` once
+/// the connection is open.
+async fn wait_for_open_channel<F>(chain: &MockChain, channel_id: ChannelId, open: impl Fn() -> F)
+where
+    F: Future<Output = ()>,
+{
+    wait_for(|| {
+        query::<Connection>(
+            chain,
+            ConnectionPath {
+                connection_id: ConnectionId::from_raw(1).unwrap(),
+            },
+        )
+        .filter(|connection| connection.state == ConnectionState::Open)
+    })
+    .await;
+
+    open().await;
+
+    wait_for(|| {
+        query::<Channel>(chain, ChannelPath { channel_id })
+            .filter(|channel| channel.state == ChannelState::Open)
+    })
+    .await;
+}
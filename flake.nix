@@ -112,6 +112,10 @@
       url = "https://github.com/ethereum/consensus-spec-tests/releases/download/v1.4.0/general.tar.gz";
       flake = false;
     };
+    ethereum-consensus-spec-tests-minimal = {
+      url = "https://github.com/ethereum/consensus-spec-tests/releases/download/v1.6.0/minimal.tar.gz";
+      flake = false;
+    };
 
     # uniond versions
     v1_0_0 = {
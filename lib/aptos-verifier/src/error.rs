@@ -1,4 +1,4 @@
-use unionlabs::primitives::H256;
+use unionlabs::primitives::{Bytes, H256};
 
 use crate::MAX_ACCUMULATOR_PROOF_DEPTH;
 
@@ -24,6 +24,26 @@ pub enum StorageVerificationError {
     ExpectedMembershipVerification,
     #[error("expected non-membership verification")]
     ExpectedNonMembershipVerification,
+    #[error(
+        "leaf ({leaf}) is not the only leaf in the subtree of the key ({key}), \
+        {common_prefix_bits} common prefix bits but {siblings} siblings"
+    )]
+    InvalidNonMembershipLeaf {
+        key: H256,
+        leaf: H256,
+        common_prefix_bits: usize,
+        siblings: usize,
+    },
     #[error("root hash mismatch (({0}, {1}))")]
     RootHashMismatch(H256, H256),
+    #[error("membership proof without a value")]
+    MembershipProofWithoutValue,
+    #[error("non-membership proof with a value")]
+    NonMembershipProofWithValue,
+    #[error("proof value {proof_value} doesn't match the given value {given}")]
+    ProofValueMismatch { proof_value: Bytes, given: Bytes },
+    #[error("proof value hash doesn't match the calculated one")]
+    ProofValueHashMismatch,
+    #[error("proof key hash doesn't match the calculated one")]
+    ProofKeyMismatch,
 }
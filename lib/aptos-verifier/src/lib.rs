@@ -12,7 +12,7 @@ use unionlabs::{
     aptos::{
         account::AccountAddress,
         sparse_merkle_proof::{SparseMerkleLeafNode, SparseMerkleProof},
-        storage_proof::StateValue,
+        storage_proof::{StateValue, StorageProof},
         transaction_info::TransactionInfo,
         transaction_proof::TransactionInfoWithProof,
     },
@@ -122,9 +122,113 @@ pub fn verify_existence_proof(
         .into());
     }
 
-    let current_hash = proof.leaf.map_or(SPARSE_MERKLE_PLACEHOLDER_HASH, |leaf| {
-        hash_sparse_merkle_leaf_node(&leaf)
-    });
+    verify_root_hash(&proof, expected_root_hash, element_key)
+}
+
+/// Verifies that no element with the key `element_key` exists in the sparse merkle tree whose root
+/// hash is `expected_root_hash` using the provided proof.
+///
+/// The proof either proves an empty subtree (no leaf), or a subtree containing a single leaf with a
+/// different key, on the path that `element_key` would take.
+pub fn verify_non_membership(
+    proof: SparseMerkleProof,
+    expected_root_hash: [u8; 32],
+    element_key: [u8; 32],
+) -> Result<(), Error> {
+    if proof.siblings.len() > 256 {
+        return Err(
+            StorageVerificationError::MaxSiblingsExceeded(256, proof.siblings.len()).into(),
+        );
+    }
+
+    if let Some(leaf) = &proof.leaf {
+        if &element_key == leaf.key.get() {
+            return Err(StorageVerificationError::ExpectedNonMembershipVerification.into());
+        }
+
+        // the key would not have ended up in the subtree where the leaf in the proof is the only
+        // existing key, so this is not a valid non-inclusion proof
+        let common_prefix_bits = common_prefix_bits_len(&element_key, leaf.key.get());
+        if common_prefix_bits < proof.siblings.len() {
+            return Err(StorageVerificationError::InvalidNonMembershipLeaf {
+                key: H256::new(element_key),
+                leaf: H256::new(*leaf.key.get()),
+                common_prefix_bits,
+                siblings: proof.siblings.len(),
+            }
+            .into());
+        }
+    }
+
+    verify_root_hash(&proof, expected_root_hash, element_key)
+}
+
+/// Verify a membership proof of `value` at `path` in the `aptos_std::table` with the handle
+/// `table_handle`, against `state_root`.
+pub fn verify_table_membership(
+    path: &[u8],
+    state_root: H256,
+    table_handle: AccountAddress,
+    proof: StorageProof,
+    value: &[u8],
+) -> Result<(), Error> {
+    let Some(proof_value) = &proof.state_value else {
+        return Err(StorageVerificationError::MembershipProofWithoutValue.into());
+    };
+
+    // `aptos_std::table` stores the value as bcs encoded
+    let given_value = bcs::to_bytes(&value).expect("cannot fail");
+    if proof_value.data() != given_value {
+        return Err(StorageVerificationError::ProofValueMismatch {
+            proof_value: proof_value.data().to_vec().into(),
+            given: given_value.into(),
+        }
+        .into());
+    }
+
+    let Some(proof_leaf) = proof.proof.leaf.as_ref() else {
+        return Err(StorageVerificationError::MembershipProofWithoutValue.into());
+    };
+
+    if hash_state_value(proof_value) != *proof_leaf.value_hash.get() {
+        return Err(StorageVerificationError::ProofValueHashMismatch.into());
+    }
+
+    let key = hash_table_key(&bcs::to_bytes(path).expect("cannot fail"), &table_handle);
+
+    if key != *proof_leaf.key.get() {
+        return Err(StorageVerificationError::ProofKeyMismatch.into());
+    }
+
+    verify_membership(proof.proof, state_root.into())
+}
+
+/// Verify that no value is stored at `path` in the `aptos_std::table` with the handle
+/// `table_handle`, against `state_root`.
+pub fn verify_table_non_membership(
+    path: &[u8],
+    state_root: H256,
+    table_handle: AccountAddress,
+    proof: StorageProof,
+) -> Result<(), Error> {
+    if proof.state_value.is_some() {
+        return Err(StorageVerificationError::NonMembershipProofWithValue.into());
+    }
+
+    let key = hash_table_key(&bcs::to_bytes(path).expect("cannot fail"), &table_handle);
+
+    verify_non_membership(proof.proof, state_root.into(), key)
+}
+
+fn verify_root_hash(
+    proof: &SparseMerkleProof,
+    expected_root_hash: [u8; 32],
+    element_key: [u8; 32],
+) -> Result<(), Error> {
+    let current_hash = proof
+        .leaf
+        .as_ref()
+        .map_or(SPARSE_MERKLE_PLACEHOLDER_HASH, hash_sparse_merkle_leaf_node);
     let actual_root_hash = proof
         .siblings
         .iter()
@@ -153,6 +257,13 @@ pub fn verify_existence_proof(
     Ok(())
 }
 
+fn common_prefix_bits_len(a: &[u8; 32], b: &[u8; 32]) -> usize {
+    BytesBitIterator::new(a)
+        .zip(BytesBitIterator::new(b))
+        .take_while(|(a, b)| a == b)
+        .count()
+}
+
 pub fn hash_state_value(value: &StateValue) -> [u8; 32] {
     Sha3_256::new()
         .chain_update(Sha3_256::new().chain_update("APTOS::StateValue").finalize())
@@ -186,7 +297,7 @@ fn hash_tx_info(tx_info: &TransactionInfo) -> [u8; 32] {
     state.finalize().into()
 }
 
-fn hash_sparse_merkle_leaf_node(leaf: &SparseMerkleLeafNode) -> [u8; 32] {
+pub fn hash_sparse_merkle_leaf_node(leaf: &SparseMerkleLeafNode) -> [u8; 32] {
     let mut state = Sha3_256::new();
     state.update(
         Sha3_256::new()
@@ -235,3 +346,132 @@ impl SparseMerkleInternalNode {
         state.finalize().into()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TABLE_HANDLE: AccountAddress = AccountAddress(H256::new([0xAA; 32]));
+
+    fn key(path: &[u8]) -> [u8; 32] {
+        hash_table_key(&bcs::to_bytes(path).unwrap(), &TABLE_HANDLE)
+    }
+
+    /// A tree containing a single leaf for `path`, the root of which is the hash of the leaf.
+    fn single_leaf_tree(path: &[u8], value: &[u8]) -> (H256, StorageProof) {
+        let state_value = StateValue::V0(bcs::to_bytes(value).unwrap());
+
+        let leaf = SparseMerkleLeafNode {
+            key: key(path).into(),
+            value_hash: hash_state_value(&state_value).into(),
+        };
+
+        (
+            H256::new(hash_sparse_merkle_leaf_node(&leaf)),
+            StorageProof {
+                state_value: Some(state_value),
+                proof: SparseMerkleProof {
+                    leaf: Some(leaf),
+                    siblings: vec![],
+                },
+            },
+        )
+    }
+
+    #[test]
+    fn membership_single_leaf() {
+        let (root, proof) = single_leaf_tree(b"commitment", b"value");
+
+        assert_eq!(
+            verify_table_membership(b"commitment", root, TABLE_HANDLE, proof, b"value"),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn membership_fails_for_different_value() {
+        let (root, proof) = single_leaf_tree(b"commitment", b"value");
+
+        assert!(matches!(
+            verify_table_membership(b"commitment", root, TABLE_HANDLE, proof, b"other"),
+            Err(Error::StorageVerification(
+                StorageVerificationError::ProofValueMismatch { .. }
+            ))
+        ));
+    }
+
+    #[test]
+    fn membership_fails_for_different_path() {
+        let (root, proof) = single_leaf_tree(b"commitment", b"value");
+
+        assert_eq!(
+            verify_table_membership(b"other", root, TABLE_HANDLE, proof, b"value"),
+            Err(StorageVerificationError::ProofKeyMismatch.into())
+        );
+    }
+
+    #[test]
+    fn non_membership_single_leaf() {
+        let (root, proof) = single_leaf_tree(b"commitment", b"value");
+
+        assert_eq!(
+            verify_table_non_membership(
+                b"other",
+                root,
+                TABLE_HANDLE,
+                StorageProof {
+                    state_value: None,
+                    ..proof
+                },
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn non_membership_empty_tree() {
+        assert_eq!(
+            verify_table_non_membership(
+                b"commitment",
+                H256::new(SPARSE_MERKLE_PLACEHOLDER_HASH),
+                TABLE_HANDLE,
+                StorageProof {
+                    state_value: None,
+                    proof: SparseMerkleProof {
+                        leaf: None,
+                        siblings: vec![],
+                    },
+                },
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn non_membership_fails_for_existing_key() {
+        let (root, proof) = single_leaf_tree(b"commitment", b"value");
+
+        assert_eq!(
+            verify_table_non_membership(
+                b"commitment",
+                root,
+                TABLE_HANDLE,
+                StorageProof {
+                    state_value: None,
+                    ..proof
+                },
+            ),
+            Err(StorageVerificationError::ExpectedNonMembershipVerification.into())
+        );
+    }
+
+    #[test]
+    fn non_membership_fails_with_value() {
+        let (root, proof) = single_leaf_tree(b"commitment", b"value");
+
+        assert_eq!(
+            verify_table_non_membership(b"other", root, TABLE_HANDLE, proof),
+            Err(StorageVerificationError::NonMembershipProofWithValue.into())
+        );
+    }
+}
@@ -0,0 +1,12 @@
+use crate::Header;
+
+/// Two headers for the same L2 block with different hashes, both of which are confirmed by an
+/// assertion node provable against the L1. This can only happen if the sequencer or the L1 client
+/// is compromised, and the client will be frozen.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+pub struct Misbehaviour {
+    pub header_a: Header,
+    pub header_b: Header,
+}
@@ -17,6 +17,10 @@ pub enum Error {
     InvalidNodeConfirmDataProof(#[source] evm_storage_verifier::error::Error),
     #[error("invalid L2 proof")]
     InvalidL2Proof(#[source] evm_storage_verifier::error::Error),
+    #[error("misbehaviour headers are for different l2 blocks ({a} != {b})")]
+    MisbehaviourL2BlockMismatch { a: U256, b: U256 },
+    #[error("misbehaviour headers are for the same l2 block ({0})")]
+    MisbehaviourL2HeadersEqual(H256),
 }
 
 /// Verify the provided header against the provided client state and L1 state root.
@@ -81,6 +85,41 @@ pub fn verify_header_v1(
     Ok(())
 }
 
+/// Verify that the two provided headers are a valid proof of misbehaviour.
+///
+/// Misbehaviour verification for arbitrum consists of 3 steps:
+///
+/// 1. Verify that both headers are for the same L2 block number.
+/// 2. Verify that the L2 headers are different (i.e. have different hashes).
+/// 3. Verify both headers against their respective L1 state roots, as in [`verify_header_v1`].
+pub fn verify_misbehaviour_v1(
+    client_state: &ClientStateV1,
+    header_a: &Header,
+    l1_state_root_a: H256,
+    header_b: &Header,
+    l1_state_root_b: H256,
+) -> Result<(), Error> {
+    // 1.
+    if header_a.l2_header.number != header_b.l2_header.number {
+        return Err(Error::MisbehaviourL2BlockMismatch {
+            a: header_a.l2_header.number,
+            b: header_b.l2_header.number,
+        });
+    }
+
+    // 2.
+    let l2_header_hash_a = header_a.l2_header.hash();
+    if l2_header_hash_a == header_b.l2_header.hash() {
+        return Err(Error::MisbehaviourL2HeadersEqual(l2_header_hash_a));
+    }
+
+    // 3.
+    verify_header_v1(client_state, header_a, l1_state_root_a)?;
+    verify_header_v1(client_state, header_b, l1_state_root_b)?;
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use arbitrum_light_client_types::ClientState;
@@ -115,11 +154,37 @@ mod tests {
 
         let ClientState::V1(client_state) = serde_json::from_str::<ClientState>(r#"{"v1":{"chain_id":"21000001","latest_height":175633,"l1_client_id":8,"l1_contract_address":"0xd318638594a5b17b50a1389b0c0580576226c0ae","frozen_height":"0","ibc_contract_address":"0x06a409cbed33caa9bf8181ef3aff2c504e1cfb95"}}"#).unwrap();
 
-        verify_header_v1(
-            &client_state,
-            &header,
-            hex!("8fac75a71de3961ea66fe8de326346d4d8b4ec98a769639a65c61dc539b80d8b").into(),
-        )
-        .unwrap();
+        let l1_state_root =
+            hex!("8fac75a71de3961ea66fe8de326346d4d8b4ec98a769639a65c61dc539b80d8b").into();
+
+        verify_header_v1(&client_state, &header, l1_state_root).unwrap();
+
+        assert_eq!(
+            verify_misbehaviour_v1(
+                &client_state,
+                &header,
+                l1_state_root,
+                &header,
+                l1_state_root
+            ),
+            Err(Error::MisbehaviourL2HeadersEqual(header.l2_header.hash()))
+        );
+
+        let mut other_header = header.clone();
+        other_header.l2_header.number = U256::from(0_u64);
+
+        assert_eq!(
+            verify_misbehaviour_v1(
+                &client_state,
+                &header,
+                l1_state_root,
+                &other_header,
+                l1_state_root
+            ),
+            Err(Error::MisbehaviourL2BlockMismatch {
+                a: header.l2_header.number,
+                b: U256::from(0_u64),
+            })
+        );
     }
 }
@@ -4,26 +4,80 @@ use unionlabs::primitives::{H160, U256};
 
 use crate::DisputeGameFactory::gameAtIndexReturn;
 
+/// `GameStatus.DEFENDER_WINS`, the status of a game whose root claim has been resolved as valid.
+pub const GAME_STATUS_DEFENDER_WINS: u8 = 2;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error(transparent)]
+    Contract(#[from] alloy::contract::Error),
+    #[error("no game has been resolved in favour of the defender as of l1 block {0}")]
+    NoResolvedGame(u64),
+}
+
 #[instrument(skip_all, fields(%l1_block_number, %l1_dispute_game_factory_proxy))]
 pub async fn finalized_l2_block_number_of_l1_block_number(
     l1_provider: &impl Provider,
     l1_dispute_game_factory_proxy: H160,
     l1_block_number: u64,
-) -> Result<u64, alloy::contract::Error> {
-    let count = latest_game_of_l1_block_number(
+) -> Result<u64, Error> {
+    let game_index = latest_resolved_game_of_l1_block_number(
         &l1_provider,
         l1_block_number,
         l1_dispute_game_factory_proxy,
     )
     .await?;
 
-    finalized_l2_block_of_game_index(
+    Ok(finalized_l2_block_of_game_index(
         l1_provider,
         l1_block_number,
         l1_dispute_game_factory_proxy,
-        count - U256::ONE,
+        game_index,
     )
-    .await
+    .await?)
+}
+
+/// Find the index of the latest game that has been resolved in favour of the defender as of
+/// `l1_block_number`. The root claim of a game is only final once it has been resolved, so this is
+/// the latest game that the l2 can be considered finalized up to.
+#[instrument(skip_all, fields(%l1_block_number, %l1_dispute_game_factory_proxy))]
+pub async fn latest_resolved_game_of_l1_block_number(
+    l1_provider: &impl Provider,
+    l1_block_number: u64,
+    l1_dispute_game_factory_proxy: H160,
+) -> Result<U256, Error> {
+    let mut game_index = latest_game_of_l1_block_number(
+        &l1_provider,
+        l1_block_number,
+        l1_dispute_game_factory_proxy,
+    )
+    .await?;
+
+    let c = DisputeGameFactory::new(l1_dispute_game_factory_proxy.into(), &l1_provider);
+
+    while game_index > U256::ZERO {
+        game_index -= U256::ONE;
+
+        let gameAtIndexReturn { proxy_, .. } = c
+            .gameAtIndex(game_index.into())
+            .block(l1_block_number.into())
+            .call()
+            .await?;
+
+        let status = FaultDisputeGame::new(proxy_, &l1_provider)
+            .status()
+            .block(l1_block_number.into())
+            .call()
+            .await?;
+
+        debug!(%game_index, %proxy_, status);
+
+        if status == GAME_STATUS_DEFENDER_WINS {
+            return Ok(game_index);
+        }
+    }
+
+    Err(Error::NoResolvedGame(l1_block_number))
 }
 
 #[instrument(skip_all, fields(%l1_block_number, %l1_dispute_game_factory_proxy))]
@@ -91,5 +145,7 @@ sol! {
 
     interface FaultDisputeGame {
         function l2BlockNumber() returns (uint256 l2BlockNumber);
+        // GameStatus is a uint8 enum
+        function status() returns (uint8 status);
     }
 }
@@ -14,6 +14,8 @@ pub struct Header {
     pub game_index: U256,
     pub game_proof: StorageProof,
     pub game_account_proof: AccountProof,
+    /// Proof of the first storage slot of the game account, which contains the status of the game.
+    pub game_status_proof: StorageProof,
     pub game_account_code: Bytes,
     pub l2_ibc_account_proof: AccountProof,
     pub l2_header: L2Header,
@@ -1,11 +1,27 @@
 pub use crate::altair::{
+    beacon_block::BeaconBlock, beacon_block_body::BeaconBlockBody,
     light_client_bootstrap::LightClientBootstrap,
     light_client_finality_update::LightClientFinalityUpdate,
-    light_client_header::LightClientHeader, light_client_update::LightClientUpdate,
+    light_client_header::LightClientHeader,
+    light_client_optimistic_update::LightClientOptimisticUpdate,
+    light_client_update::LightClientUpdate, signed_beacon_block::SignedBeaconBlock,
     sync_aggregate::SyncAggregate, sync_committee::SyncCommittee,
 };
 #[cfg(feature = "ssz")]
-pub use crate::altair::{sync_aggregate::SyncAggregateSsz, sync_committee::SyncCommitteeSsz};
+pub use crate::altair::{
+    beacon_block::BeaconBlockSsz, beacon_block_body::BeaconBlockBodySsz,
+    signed_beacon_block::SignedBeaconBlockSsz, sync_aggregate::SyncAggregateSsz,
+    sync_committee::SyncCommitteeSsz,
+};
+
+/// <https://github.com/ethereum/consensus-specs/blob/dev/specs/altair/beacon-chain.md#beaconblockbody>
+pub mod beacon_block_body;
+
+/// Updated indirectly.
+pub mod beacon_block;
+
+/// Updated indirectly.
+pub mod signed_beacon_block;
 
 /// <https://github.com/ethereum/consensus-specs/blob/dev/specs/altair/light-client/sync-protocol.md#lightclientbootstrap>
 pub mod light_client_bootstrap;
@@ -16,6 +32,9 @@ pub mod light_client_finality_update;
 /// <https://github.com/ethereum/consensus-specs/blob/dev/specs/altair/light-client/sync-protocol.md#lightclientheader>
 pub mod light_client_header;
 
+/// <https://github.com/ethereum/consensus-specs/blob/dev/specs/altair/light-client/sync-protocol.md#lightclientoptimisticupdate>
+pub mod light_client_optimistic_update;
+
 /// <https://github.com/ethereum/consensus-specs/blob/dev/specs/altair/light-client/sync-protocol.md#lightclientupdate>
 pub mod light_client_update;
 
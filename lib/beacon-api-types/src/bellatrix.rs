@@ -3,8 +3,9 @@ pub use crate::bellatrix::{
     execution_payload::ExecutionPayload, execution_payload_header::ExecutionPayloadHeader,
     light_client_bootstrap::LightClientBootstrap,
     light_client_finality_update::LightClientFinalityUpdate,
-    light_client_header::LightClientHeader, light_client_update::LightClientUpdate,
-    signed_beacon_block::SignedBeaconBlock,
+    light_client_header::LightClientHeader,
+    light_client_optimistic_update::LightClientOptimisticUpdate,
+    light_client_update::LightClientUpdate, signed_beacon_block::SignedBeaconBlock,
 };
 #[cfg(feature = "ssz")]
 pub use crate::bellatrix::{
@@ -39,3 +40,6 @@ pub mod light_client_bootstrap;
 
 /// Updated indirectly.
 pub mod light_client_finality_update;
+
+/// Updated indirectly.
+pub mod light_client_optimistic_update;
@@ -0,0 +1,12 @@
+use crate::{altair::SyncAggregate, bellatrix::LightClientHeader, custom_types::Slot};
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LightClientOptimisticUpdate {
+    /// Header attested to by the sync committee
+    pub attested_header: LightClientHeader,
+    /// Sync committee aggregate signature
+    pub sync_aggregate: SyncAggregate,
+    /// Slot at which the aggregate signature was created (untrusted)
+    pub signature_slot: Slot,
+}
@@ -3,8 +3,9 @@ pub use crate::capella::{
     bls_to_execution_change::BlsToExecutionChange, execution_payload::ExecutionPayload,
     execution_payload_header::ExecutionPayloadHeader, light_client_bootstrap::LightClientBootstrap,
     light_client_finality_update::LightClientFinalityUpdate,
-    light_client_header::LightClientHeader, light_client_update::LightClientUpdate,
-    signed_beacon_block::SignedBeaconBlock,
+    light_client_header::LightClientHeader,
+    light_client_optimistic_update::LightClientOptimisticUpdate,
+    light_client_update::LightClientUpdate, signed_beacon_block::SignedBeaconBlock,
     signed_bls_to_execution_change::SignedBlsToExecutionChange, withdrawal::Withdrawal,
 };
 #[cfg(feature = "ssz")]
@@ -35,6 +36,9 @@ pub mod light_client_bootstrap;
 /// Updated indirectly.
 pub mod light_client_finality_update;
 
+/// Updated indirectly.
+pub mod light_client_optimistic_update;
+
 /// <https://github.com/ethereum/consensus-specs/blob/dev/specs/capella/light-client/sync-protocol.md#modified-lightclientheader>
 pub mod light_client_header;
 
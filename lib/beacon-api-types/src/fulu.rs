@@ -0,0 +1,17 @@
+// Fulu does not modify any of the beacon block or light client containers, only the `BeaconState`
+// (which is not defined in this crate). The containers from the previous forks are re-exported here
+// so that consumers can refer to the types of the current fork by name.
+
+#[cfg(feature = "ssz")]
+pub use crate::electra::{
+    AttestationSsz, AttesterSlashingSsz, BeaconBlockBodySsz, BeaconBlockSsz, ExecutionRequestsSsz,
+    IndexedAttestationSsz, SignedBeaconBlockSsz,
+};
+pub use crate::{
+    deneb::{LightClientHeader, LightClientOptimisticUpdate},
+    electra::{
+        Attestation, AttesterSlashing, BeaconBlock, BeaconBlockBody, ConsolidationRequest,
+        DepositRequest, ExecutionRequests, IndexedAttestation, LightClientBootstrap,
+        LightClientFinalityUpdate, LightClientUpdate, SignedBeaconBlock, WithdrawalRequest,
+    },
+};
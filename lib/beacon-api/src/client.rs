@@ -5,23 +5,27 @@ use std::{
     time::Duration,
 };
 
-use beacon_api_types::custom_types::Slot;
+use beacon_api_types::{chain_spec::ChainSpec, custom_types::Slot};
 use moka::{future::Cache, ops::compute::Op};
-use reqwest::{Client, StatusCode};
+use reqwest::{header::ACCEPT, Client, Response, StatusCode};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use serde_json::{value::RawValue, Value};
+use ssz::{merkle_proof::GeneralizedIndex, Ssz};
 use tracing::{debug, info, trace};
 use unionlabs::{primitives::H256, ErrorReporter};
 
 use crate::{
     errors::Error,
     routes::{
-        block::BeaconBlockResponse,
+        block::{BeaconBlockResponse, BeaconBlockSszResponseTypes},
+        events::{EventStream, EventTopic},
         genesis::{GenesisData, GenesisResponse},
         header::BeaconBlockHeaderResponse,
         light_client_bootstrap::LightClientBootstrapResponseTypes,
         light_client_finality_update::LightClientFinalityUpdateResponseTypes,
+        light_client_optimistic_update::LightClientOptimisticUpdateResponseTypes,
         light_client_updates::LightClientUpdateResponseTypes,
+        proof::{compact_multiproof_descriptor, Multiproof},
         spec::{Spec, SpecResponse},
     },
 };
@@ -81,6 +85,13 @@ impl BeaconApiClient {
             .await
     }
 
+    pub async fn optimistic_update(
+        &self,
+    ) -> Result<VersionedResponse<LightClientOptimisticUpdateResponseTypes>> {
+        self.get_json("/eth/v1/beacon/light_client/optimistic_update")
+            .await
+    }
+
     pub async fn header(&self, block_id: BlockId) -> Result<BeaconBlockHeaderResponse> {
         self.get_json(format!("/eth/v1/beacon/headers/{block_id}"))
             .await
@@ -91,6 +102,75 @@ impl BeaconApiClient {
             .await
     }
 
+    /// Fetch a block SSZ encoded, which is significantly smaller and faster to decode than the JSON
+    /// response of [`Self::block`].
+    pub async fn block_ssz<C: ChainSpec>(
+        &self,
+        block_id: BlockId,
+    ) -> Result<VersionedResponse<BeaconBlockSszResponseTypes<C>>> {
+        let (version, bytes) = self
+            .get_ssz(format!("/eth/v2/beacon/blocks/{block_id}"))
+            .await?;
+
+        VersionedResponse::from_ssz_bytes(version.as_deref(), &bytes)
+    }
+
+    /// Fetch a multiproof of the nodes at `gindices` in the tree of the state at `state_id`.
+    ///
+    /// NOTE: This is not part of the standard beacon API, and is currently only implemented by
+    /// lodestar.
+    pub async fn state_proof(
+        &self,
+        state_id: StateId,
+        gindices: &[GeneralizedIndex],
+    ) -> Result<Multiproof> {
+        let (_, bytes) = self
+            .get_ssz(format!(
+                "/eth/v0/beacon/proof/state/{state_id}?format={}",
+                serde_utils::to_hex(compact_multiproof_descriptor(gindices)?)
+            ))
+            .await?;
+
+        Multiproof::from_compact(gindices, &bytes)
+    }
+
+    /// Fetch a multiproof of the nodes at `gindices` in the tree of the block at `block_id`.
+    ///
+    /// NOTE: This is not part of the standard beacon API, and is currently only implemented by
+    /// lodestar.
+    pub async fn block_proof(
+        &self,
+        block_id: BlockId,
+        gindices: &[GeneralizedIndex],
+    ) -> Result<Multiproof> {
+        let (_, bytes) = self
+            .get_ssz(format!(
+                "/eth/v0/beacon/proof/block/{block_id}?format={}",
+                serde_utils::to_hex(compact_multiproof_descriptor(gindices)?)
+            ))
+            .await?;
+
+        Multiproof::from_compact(gindices, &bytes)
+    }
+
+    /// Subscribe to the server-sent events of `topics`.
+    pub async fn events(&self, topics: &[EventTopic]) -> Result<EventStream> {
+        let topics = topics
+            .iter()
+            .map(EventTopic::as_str)
+            .collect::<Vec<_>>()
+            .join(",");
+
+        let res = self
+            .get(
+                format!("/eth/v1/events?topics={topics}"),
+                "text/event-stream",
+            )
+            .await?;
+
+        Ok(EventStream::new(res))
+    }
+
     pub async fn bootstrap(
         &self,
         finalized_root: H256,
@@ -148,6 +228,7 @@ impl BeaconApiClient {
             VersionedResponse::Capella(block) => block.message.body.execution_payload.block_number,
             VersionedResponse::Deneb(block) => block.message.body.execution_payload.block_number,
             VersionedResponse::Electra(block) => block.message.body.execution_payload.block_number,
+            VersionedResponse::Fulu(block) => block.message.body.execution_payload.block_number,
         };
 
         debug!("beacon height {block_id} is execution height {height}");
@@ -207,20 +288,42 @@ impl BeaconApiClient {
     // Helper functions
 
     async fn get_json<T: DeserializeOwned>(&self, path: impl Into<String>) -> Result<T> {
-        let url = format!("{}{}", self.base_url, path.into());
+        let res = self.get(path, Encoding::Json.content_type()).await?;
 
-        debug!(%url, "get_json");
+        let bytes = res.bytes().await?;
 
-        let res = self.client.get(url).send().await?;
+        trace!(response = %String::from_utf8_lossy(&bytes), "get_json");
 
-        match res.status() {
-            StatusCode::OK => {
-                let bytes = res.bytes().await?;
+        serde_json::from_slice(&bytes).map_err(Error::Json)
+    }
 
-                trace!(response = %String::from_utf8_lossy(&bytes), "get_json");
+    /// Returns the SSZ encoded response body, along with the consensus version of the response as
+    /// specified by the `Eth-Consensus-Version` header (if present).
+    async fn get_ssz(&self, path: impl Into<String>) -> Result<(Option<String>, Vec<u8>)> {
+        let res = self.get(path, Encoding::Ssz.content_type()).await?;
 
-                Ok(serde_json::from_slice(&bytes).map_err(Error::Json)?)
-            }
+        let version = res
+            .headers()
+            .get("Eth-Consensus-Version")
+            .and_then(|version| version.to_str().ok())
+            .map(ToOwned::to_owned);
+
+        let bytes = res.bytes().await?;
+
+        trace!(?version, len = bytes.len(), "get_ssz");
+
+        Ok((version, bytes.to_vec()))
+    }
+
+    async fn get(&self, path: impl Into<String>, accept: &str) -> Result<Response> {
+        let url = format!("{}{}", self.base_url, path.into());
+
+        debug!(%url, %accept, "get");
+
+        let res = self.client.get(url).header(ACCEPT, accept).send().await?;
+
+        match res.status() {
+            StatusCode::OK => Ok(res),
             StatusCode::NOT_FOUND => {
                 let raw = res.json::<Value>().await?;
                 trace!(%raw, "not found");
@@ -239,11 +342,22 @@ impl BeaconApiClient {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Encoding {
     Json,
     Ssz,
 }
 
+impl Encoding {
+    #[must_use]
+    pub const fn content_type(&self) -> &'static str {
+        match self {
+            Encoding::Json => "application/json",
+            Encoding::Ssz => "application/octet-stream",
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 // #[serde(
 //     tag = "version",
@@ -262,6 +376,7 @@ pub enum VersionedResponse<T: VersionedResponseTypes> {
     Capella(T::Capella),
     Deneb(T::Deneb),
     Electra(T::Electra),
+    Fulu(T::Fulu),
 }
 
 #[derive(Deserialize)]
@@ -291,7 +406,10 @@ impl<'a, T: VersionedResponseTypes> TryFrom<VersionedResponseRaw<'a>> for Versio
             Some("deneb") => Self::Deneb(
                 serde_json::from_str(value.data.get()).map_err(|e| ErrorReporter(e).to_string())?,
             ),
-            Some("electra") | None => Self::Electra(
+            Some("electra") => Self::Electra(
+                serde_json::from_str(value.data.get()).map_err(|e| ErrorReporter(e).to_string())?,
+            ),
+            Some("fulu") | None => Self::Fulu(
                 serde_json::from_str(value.data.get()).map_err(|e| ErrorReporter(e).to_string())?,
             ),
             v => return Err(format!("unknown version {v:?}")),
@@ -308,6 +426,7 @@ where
         Capella = <T as VersionedResponseTypes>::Phase0,
         Deneb = <T as VersionedResponseTypes>::Phase0,
         Electra = <T as VersionedResponseTypes>::Phase0,
+        Fulu = <T as VersionedResponseTypes>::Phase0,
     >,
 {
     /// "Unwrap" the inner type. This is only possible if all of the inner types are all the same.
@@ -319,11 +438,39 @@ where
             VersionedResponse::Capella(t) => t,
             VersionedResponse::Deneb(t) => t,
             VersionedResponse::Electra(t) => t,
+            VersionedResponse::Fulu(t) => t,
         }
     }
 }
 
+impl<T: VersionedResponseTypes> VersionedResponse<T>
+where
+    T::Phase0: Ssz,
+    T::Altair: Ssz,
+    T::Bellatrix: Ssz,
+    T::Capella: Ssz,
+    T::Deneb: Ssz,
+    T::Electra: Ssz,
+    T::Fulu: Ssz,
+{
+    /// Decode the SSZ encoding of the type of `version`, as specified by the `Eth-Consensus-Version`
+    /// header of the response.
+    pub fn from_ssz_bytes(version: Option<&str>, bytes: &[u8]) -> Result<Self> {
+        Ok(match version {
+            Some("phase0") => Self::Phase0(Ssz::from_ssz_bytes(bytes)?),
+            Some("altair") => Self::Altair(Ssz::from_ssz_bytes(bytes)?),
+            Some("bellatrix") => Self::Bellatrix(Ssz::from_ssz_bytes(bytes)?),
+            Some("capella") => Self::Capella(Ssz::from_ssz_bytes(bytes)?),
+            Some("deneb") => Self::Deneb(Ssz::from_ssz_bytes(bytes)?),
+            Some("electra") => Self::Electra(Ssz::from_ssz_bytes(bytes)?),
+            Some("fulu") => Self::Fulu(Ssz::from_ssz_bytes(bytes)?),
+            _ => return Err(Error::UnknownVersion(version.map(ToOwned::to_owned))),
+        })
+    }
+}
+
 impl<T: VersionedResponseTypes> VersionedResponse<T> {
+    #[allow(clippy::too_many_arguments)]
     pub fn fold<U>(
         self,
         phase0: impl FnOnce(T::Phase0) -> U,
@@ -332,6 +479,7 @@ impl<T: VersionedResponseTypes> VersionedResponse<T> {
         capella: impl FnOnce(T::Capella) -> U,
         deneb: impl FnOnce(T::Deneb) -> U,
         electra: impl FnOnce(T::Electra) -> U,
+        fulu: impl FnOnce(T::Fulu) -> U,
     ) -> U {
         match self {
             VersionedResponse::Phase0(t) => phase0(t),
@@ -340,9 +488,11 @@ impl<T: VersionedResponseTypes> VersionedResponse<T> {
             VersionedResponse::Capella(t) => capella(t),
             VersionedResponse::Deneb(t) => deneb(t),
             VersionedResponse::Electra(t) => electra(t),
+            VersionedResponse::Fulu(t) => fulu(t),
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn fold_ref<U>(
         &self,
         phase0: impl FnOnce(&T::Phase0) -> U,
@@ -351,6 +501,7 @@ impl<T: VersionedResponseTypes> VersionedResponse<T> {
         capella: impl FnOnce(&T::Capella) -> U,
         deneb: impl FnOnce(&T::Deneb) -> U,
         electra: impl FnOnce(&T::Electra) -> U,
+        fulu: impl FnOnce(&T::Fulu) -> U,
     ) -> U {
         match self {
             VersionedResponse::Phase0(t) => phase0(t),
@@ -359,6 +510,7 @@ impl<T: VersionedResponseTypes> VersionedResponse<T> {
             VersionedResponse::Capella(t) => capella(t),
             VersionedResponse::Deneb(t) => deneb(t),
             VersionedResponse::Electra(t) => electra(t),
+            VersionedResponse::Fulu(t) => fulu(t),
         }
     }
 }
@@ -370,6 +522,7 @@ pub trait VersionedResponseTypes {
     type Capella: Debug + Serialize + DeserializeOwned;
     type Deneb: Debug + Serialize + DeserializeOwned;
     type Electra: Debug + Serialize + DeserializeOwned;
+    type Fulu: Debug + Serialize + DeserializeOwned;
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -404,3 +557,38 @@ impl Display for BlockId {
         }
     }
 }
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StateId {
+    Head,
+    Genesis,
+    Finalized,
+    Justified,
+    Slot(Slot),
+    Root(H256),
+}
+
+impl From<Slot> for StateId {
+    fn from(slot: Slot) -> Self {
+        StateId::Slot(slot)
+    }
+}
+
+impl From<H256> for StateId {
+    fn from(root: H256) -> Self {
+        StateId::Root(root)
+    }
+}
+
+impl Display for StateId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StateId::Head => write!(f, "head"),
+            StateId::Genesis => write!(f, "genesis"),
+            StateId::Finalized => write!(f, "finalized"),
+            StateId::Justified => write!(f, "justified"),
+            StateId::Slot(slot) => write!(f, "{slot}"),
+            StateId::Root(root) => write!(f, "{root}"),
+        }
+    }
+}
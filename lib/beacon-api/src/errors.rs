@@ -12,6 +12,16 @@ pub enum Error {
     NotFound(Value),
     #[error("json deserialization error")]
     Json(#[from] serde_json::Error),
+    #[error("ssz decoding error")]
+    Ssz(#[from] ssz::decode::DecodeError),
+    #[error("unknown consensus version {0:?}")]
+    UnknownVersion(Option<String>),
+    #[error("invalid event stream message ({0})")]
+    InvalidEvent(String),
+    #[error("invalid proof request")]
+    Proof(#[from] ssz::merkle_proof::ProofError),
+    #[error("invalid proof response length, expected {expected} bytes but found {found}")]
+    InvalidProofLength { expected: usize, found: usize },
     #[error("unknown error ({code}): {text}")]
     Other { code: StatusCode, text: String },
 }
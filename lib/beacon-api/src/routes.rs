@@ -1,7 +1,10 @@
 pub mod block;
+pub mod events;
 pub mod genesis;
 pub mod header;
 pub mod light_client_bootstrap;
 pub mod light_client_finality_update;
+pub mod light_client_optimistic_update;
 pub mod light_client_updates;
+pub mod proof;
 pub mod spec;
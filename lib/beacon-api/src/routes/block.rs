@@ -1,4 +1,8 @@
-use beacon_api_types::{bellatrix, capella, deneb, electra, phase0};
+use std::marker::PhantomData;
+
+use beacon_api_types::{
+    altair, bellatrix, capella, chain_spec::ChainSpec, deneb, electra, fulu, phase0,
+};
 use serde::{Deserialize, Serialize};
 use serde_json::value::RawValue;
 use unionlabs::{primitives::H256, ErrorReporter};
@@ -26,11 +30,28 @@ pub enum BeaconBlockResponseTypes {}
 
 impl VersionedResponseTypes for BeaconBlockResponseTypes {
     type Phase0 = phase0::SignedBeaconBlock;
-    type Altair = phase0::SignedBeaconBlock;
+    type Altair = altair::SignedBeaconBlock;
     type Bellatrix = bellatrix::SignedBeaconBlock;
     type Capella = capella::SignedBeaconBlock;
     type Deneb = deneb::SignedBeaconBlock;
     type Electra = electra::SignedBeaconBlock;
+    type Fulu = fulu::SignedBeaconBlock;
+}
+
+/// The SSZ encodings of the blocks of each fork, as returned by `/eth/v2/beacon/blocks/{block_id}`
+/// with `Accept: application/octet-stream`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(bound(serialize = "", deserialize = ""))]
+pub struct BeaconBlockSszResponseTypes<C: ChainSpec>(PhantomData<fn() -> C>);
+
+impl<C: ChainSpec> VersionedResponseTypes for BeaconBlockSszResponseTypes<C> {
+    type Phase0 = phase0::SignedBeaconBlockSsz<C>;
+    type Altair = altair::SignedBeaconBlockSsz<C>;
+    type Bellatrix = bellatrix::SignedBeaconBlockSsz<C>;
+    type Capella = capella::SignedBeaconBlockSsz<C>;
+    type Deneb = deneb::SignedBeaconBlockSsz<C>;
+    type Electra = electra::SignedBeaconBlockSsz<C>;
+    type Fulu = fulu::SignedBeaconBlockSsz<C>;
 }
 
 #[derive(Deserialize)]
@@ -88,7 +109,7 @@ impl<'a> TryFrom<BeaconBlockResponseRaw<'a>> for BeaconBlockResponse {
                         .map_err(|e| ErrorReporter(e).to_string())?,
                 ),
             },
-            Some("electra") | None => Self {
+            Some("electra") => Self {
                 execution_optimistic: value.execution_optimistic,
                 finalized: value.finalized,
                 response: VersionedResponse::Electra(
@@ -96,7 +117,80 @@ impl<'a> TryFrom<BeaconBlockResponseRaw<'a>> for BeaconBlockResponse {
                         .map_err(|e| ErrorReporter(e).to_string())?,
                 ),
             },
+            Some("fulu") | None => Self {
+                execution_optimistic: value.execution_optimistic,
+                finalized: value.finalized,
+                response: VersionedResponse::Fulu(
+                    serde_json::from_str(value.data.get())
+                        .map_err(|e| ErrorReporter(e).to_string())?,
+                ),
+            },
             v => return Err(format!("unknown version {v:?}")),
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use beacon_api_types::{
+        altair::{BeaconBlockBodySsz, BeaconBlockSsz, SignedBeaconBlockSsz, SyncAggregateSsz},
+        chain_spec::Minimal,
+        custom_types::{Slot, ValidatorIndex},
+        phase0::Eth1Data,
+    };
+    use ssz::Ssz;
+    use unionlabs::primitives::{H256, H768};
+
+    use super::*;
+
+    #[test]
+    fn altair_block_ssz() {
+        let block = SignedBeaconBlockSsz::<Minimal> {
+            message: BeaconBlockSsz {
+                slot: Slot::new(1),
+                proposer_index: ValidatorIndex::new(2),
+                parent_root: H256::new([3; 32]),
+                state_root: H256::new([4; 32]),
+                body: BeaconBlockBodySsz {
+                    randao_reveal: H768::new([5; 96]),
+                    eth1_data: Eth1Data {
+                        deposit_root: H256::new([6; 32]),
+                        deposit_count: 7,
+                        block_hash: H256::new([8; 32]),
+                    },
+                    graffiti: H256::new([9; 32]),
+                    proposer_slashings: Default::default(),
+                    attester_slashings: Default::default(),
+                    attestations: Default::default(),
+                    deposits: Default::default(),
+                    voluntary_exits: Default::default(),
+                    sync_aggregate: SyncAggregateSsz {
+                        sync_committee_bits: Default::default(),
+                        sync_committee_signature: H768::new([10; 96]),
+                    },
+                },
+            },
+            signature: H768::new([11; 96]),
+        };
+
+        let bytes = block.as_ssz_bytes();
+
+        assert_eq!(
+            VersionedResponse::<BeaconBlockSszResponseTypes<Minimal>>::from_ssz_bytes(
+                Some("altair"),
+                &bytes
+            )
+            .unwrap(),
+            VersionedResponse::Altair(block)
+        );
+
+        // the sync aggregate is not part of a phase0 block
+        assert!(
+            VersionedResponse::<BeaconBlockSszResponseTypes<Minimal>>::from_ssz_bytes(
+                Some("phase0"),
+                &bytes
+            )
+            .is_err()
+        );
+    }
+}
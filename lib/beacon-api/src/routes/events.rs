@@ -0,0 +1,248 @@
+use std::{
+    collections::VecDeque,
+    fmt::Display,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use beacon_api_types::custom_types::{Epoch, Slot};
+use futures::{stream::BoxStream, Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use unionlabs::{primitives::H256, ErrorReporter};
+
+use crate::{
+    client::VersionedResponse, errors::Error,
+    routes::light_client_finality_update::LightClientFinalityUpdateResponseTypes,
+};
+
+/// The topics that can be subscribed to with [`BeaconApiClient::events`].
+///
+/// [`BeaconApiClient::events`]: crate::client::BeaconApiClient::events
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EventTopic {
+    Head,
+    FinalizedCheckpoint,
+    LightClientFinalityUpdate,
+}
+
+impl EventTopic {
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            EventTopic::Head => "head",
+            EventTopic::FinalizedCheckpoint => "finalized_checkpoint",
+            EventTopic::LightClientFinalityUpdate => "light_client_finality_update",
+        }
+    }
+}
+
+impl Display for EventTopic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Event {
+    Head(HeadEvent),
+    FinalizedCheckpoint(FinalizedCheckpointEvent),
+    LightClientFinalityUpdate(Box<VersionedResponse<LightClientFinalityUpdateResponseTypes>>),
+}
+
+impl Event {
+    fn parse(event: &str, data: &str) -> Result<Self, Error> {
+        match event {
+            "head" => serde_json::from_str(data).map(Self::Head),
+            "finalized_checkpoint" => serde_json::from_str(data).map(Self::FinalizedCheckpoint),
+            "light_client_finality_update" => {
+                serde_json::from_str(data).map(Self::LightClientFinalityUpdate)
+            }
+            _ => return Err(Error::InvalidEvent(format!("unknown event {event:?}"))),
+        }
+        .map_err(|e| {
+            Error::InvalidEvent(format!(
+                "unable to parse {event} event: {}",
+                ErrorReporter(e)
+            ))
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HeadEvent {
+    pub slot: Slot,
+    pub block: H256,
+    pub state: H256,
+    pub epoch_transition: bool,
+    pub previous_duty_dependent_root: Option<H256>,
+    pub current_duty_dependent_root: Option<H256>,
+    pub execution_optimistic: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FinalizedCheckpointEvent {
+    pub block: H256,
+    pub state: H256,
+    pub epoch: Epoch,
+    pub execution_optimistic: Option<bool>,
+}
+
+/// A stream of [`Event`]s, parsed from the `text/event-stream` response of `/eth/v1/events`.
+///
+/// The stream ends when the beacon node closes the connection.
+pub struct EventStream {
+    body: BoxStream<'static, reqwest::Result<Vec<u8>>>,
+    parser: EventStreamParser,
+}
+
+impl EventStream {
+    pub(crate) fn new(res: reqwest::Response) -> Self {
+        Self {
+            body: res
+                .bytes_stream()
+                .map(|bytes| bytes.map(|bytes| bytes.to_vec()))
+                .boxed(),
+            parser: EventStreamParser::default(),
+        }
+    }
+}
+
+impl Stream for EventStream {
+    type Item = Result<Event, Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            if let Some(message) = self.parser.messages.pop_front() {
+                return Poll::Ready(Some(Event::parse(&message.event, &message.data)));
+            }
+
+            match self.body.poll_next_unpin(cx) {
+                Poll::Ready(Some(Ok(bytes))) => self.parser.push(&bytes),
+                Poll::Ready(Some(Err(err))) => return Poll::Ready(Some(Err(err.into()))),
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct EventStreamMessage {
+    event: String,
+    data: String,
+}
+
+/// Incremental parser for the [server-sent events] format. Only the `event` and `data` fields are
+/// used by the beacon API, all other fields and comments are ignored.
+///
+/// [server-sent events]: https://html.spec.whatwg.org/multipage/server-sent-events.html#parsing-an-event-stream
+#[derive(Debug, Default)]
+struct EventStreamParser {
+    /// Bytes of the current, incomplete line.
+    line: Vec<u8>,
+    event: Option<String>,
+    data: Option<String>,
+    messages: VecDeque<EventStreamMessage>,
+}
+
+impl EventStreamParser {
+    fn push(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            match byte {
+                b'\n' => {
+                    let mut line = std::mem::take(&mut self.line);
+
+                    if line.last() == Some(&b'\r') {
+                        line.pop();
+                    }
+
+                    self.process_line(&String::from_utf8_lossy(&line));
+                }
+                byte => self.line.push(*byte),
+            }
+        }
+    }
+
+    fn process_line(&mut self, line: &str) {
+        if line.is_empty() {
+            // dispatch the event, if there is one
+            if let Some(data) = self.data.take() {
+                self.messages.push_back(EventStreamMessage {
+                    event: self.event.take().unwrap_or_else(|| "message".to_owned()),
+                    data,
+                });
+            }
+
+            self.event = None;
+
+            return;
+        }
+
+        // comment
+        if line.starts_with(':') {
+            return;
+        }
+
+        let (field, value) = match line.split_once(':') {
+            Some((field, value)) => (field, value.strip_prefix(' ').unwrap_or(value)),
+            None => (line, ""),
+        };
+
+        match field {
+            "event" => self.event = Some(value.to_owned()),
+            "data" => match &mut self.data {
+                Some(data) => {
+                    data.push('\n');
+                    data.push_str(value);
+                }
+                None => self.data = Some(value.to_owned()),
+            },
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_event_stream() {
+        let mut parser = EventStreamParser::default();
+
+        parser.push(b": keepalive\n\nevent: finalized_checkpoint\r\ndata: {\"block\":");
+        assert_eq!(parser.messages, []);
+
+        parser.push(b"1}\n\nevent: head\ndata: a\ndata:b\n\n");
+        assert_eq!(
+            parser.messages,
+            [
+                EventStreamMessage {
+                    event: "finalized_checkpoint".to_owned(),
+                    data: "{\"block\":1}".to_owned(),
+                },
+                EventStreamMessage {
+                    event: "head".to_owned(),
+                    data: "a\nb".to_owned(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_finalized_checkpoint() {
+        let event = Event::parse(
+            "finalized_checkpoint",
+            r#"{"block":"0x9a2fefd2fdb57f74993c7780ea5b9030d2897b615b89f808011ca5aebed54eaf","state":"0x600e852a08c1200654ddf11025f1ceacb3c2e74bdd5c630cde0838b2591b69f9","epoch":"2","execution_optimistic":false}"#,
+        )
+        .unwrap();
+
+        assert!(matches!(
+            event,
+            Event::FinalizedCheckpoint(FinalizedCheckpointEvent { epoch, .. }) if epoch == Epoch::new(2)
+        ));
+
+        assert!(Event::parse("block", "{}").is_err());
+    }
+}
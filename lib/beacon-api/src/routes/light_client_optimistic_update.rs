@@ -0,0 +1,19 @@
+use beacon_api_types::{altair, bellatrix, capella, deneb, fulu};
+use serde::{Deserialize, Serialize};
+use unionlabs::never::Never;
+
+use crate::client::VersionedResponseTypes;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LightClientOptimisticUpdateResponseTypes {}
+
+impl VersionedResponseTypes for LightClientOptimisticUpdateResponseTypes {
+    type Phase0 = Never;
+    type Altair = altair::LightClientOptimisticUpdate;
+    type Bellatrix = bellatrix::LightClientOptimisticUpdate;
+    type Capella = capella::LightClientOptimisticUpdate;
+    type Deneb = deneb::LightClientOptimisticUpdate;
+    // unchanged in electra
+    type Electra = deneb::LightClientOptimisticUpdate;
+    type Fulu = fulu::LightClientOptimisticUpdate;
+}
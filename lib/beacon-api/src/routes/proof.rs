@@ -0,0 +1,170 @@
+use serde::{Deserialize, Serialize};
+use ssz::merkle_proof::{
+    get_generalized_index_length, get_helper_indices, verify_merkle_multiproof, GeneralizedIndex,
+    ProofError,
+};
+use unionlabs::primitives::H256;
+
+use crate::errors::Error;
+
+/// A multiproof of the nodes at `gindices` in the tree of a beacon state or block, in the format
+/// expected by [`verify_merkle_multiproof`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Multiproof {
+    pub gindices: Vec<GeneralizedIndex>,
+    /// The nodes at `gindices`.
+    pub leaves: Vec<H256>,
+    /// The helper nodes, in the order of [`get_helper_indices`].
+    pub proof: Vec<H256>,
+}
+
+impl Multiproof {
+    /// Builds a multiproof from the leaves of a lodestar "compact multiproof" of `gindices`, which
+    /// contains all the nodes of [`compact_multiproof_nodes`] in order.
+    pub fn from_compact(gindices: &[GeneralizedIndex], bytes: &[u8]) -> Result<Self, Error> {
+        let nodes = compact_multiproof_nodes(gindices)?;
+
+        if bytes.len() != nodes.len() * 32 {
+            return Err(Error::InvalidProofLength {
+                expected: nodes.len() * 32,
+                found: bytes.len(),
+            });
+        }
+
+        let node = |gindex: &GeneralizedIndex| {
+            let position = nodes
+                .iter()
+                .position(|node| node == gindex)
+                .expect("all leaves and helper indices are nodes of the proof; qed;");
+
+            H256::try_from(&bytes[position * 32..(position + 1) * 32])
+                .expect("slice is 32 bytes; qed;")
+        };
+
+        Ok(Self {
+            gindices: gindices.to_vec(),
+            leaves: gindices.iter().map(node).collect(),
+            proof: get_helper_indices(gindices).iter().map(node).collect(),
+        })
+    }
+
+    #[must_use]
+    pub fn verify(&self, root: &H256) -> bool {
+        verify_merkle_multiproof(&self.leaves, &self.proof, &self.gindices, root)
+    }
+}
+
+/// All nodes of a proof of `gindices` (the nodes themselves and the helper nodes required to
+/// prove them), in order from left to right.
+///
+/// # Errors
+///
+/// Fails if any of `gindices` is not a valid generalized index.
+pub fn compact_multiproof_nodes(
+    gindices: &[GeneralizedIndex],
+) -> Result<Vec<GeneralizedIndex>, ProofError> {
+    let mut nodes = get_helper_indices(gindices)
+        .into_iter()
+        .chain(gindices.iter().copied())
+        .map(|gindex| Ok((in_order_key(gindex)?, gindex)))
+        .collect::<Result<Vec<_>, ProofError>>()?;
+
+    nodes.sort_unstable();
+    nodes.dedup();
+
+    Ok(nodes.into_iter().map(|(_, gindex)| gindex).collect())
+}
+
+/// The descriptor of a compact multiproof of `gindices`, used to request proofs from lodestar.
+///
+/// This is the pre-order traversal of the proof tree, where each branch node is a `0` bit and each
+/// proof node is a `1` bit, padded with `0` bits to a whole number of bytes.
+///
+/// # Errors
+///
+/// Fails if any of `gindices` is not a valid generalized index.
+pub fn compact_multiproof_descriptor(gindices: &[GeneralizedIndex]) -> Result<Vec<u8>, ProofError> {
+    let bits = compact_multiproof_nodes(gindices)?
+        .into_iter()
+        .flat_map(|gindex| {
+            // every node is preceded by the branch nodes of which it is the leftmost descendant
+            (0..gindex.trailing_zeros()).map(|_| false).chain([true])
+        })
+        .collect::<Vec<_>>();
+
+    Ok(bits
+        .chunks(8)
+        .map(|chunk| {
+            chunk
+                .iter()
+                .enumerate()
+                .fold(0, |byte, (i, bit)| byte | (u8::from(*bit) << (7 - i)))
+        })
+        .collect())
+}
+
+/// Sort key such that nodes are ordered from left to right, with ancestors before their left
+/// descendants.
+fn in_order_key(gindex: GeneralizedIndex) -> Result<(u64, u32), ProofError> {
+    let depth = get_generalized_index_length(gindex)?;
+
+    Ok((gindex << (63 - depth), depth))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn descriptor() {
+        // root -> (2, 3)
+        assert_eq!(compact_multiproof_nodes(&[2]), Ok(vec![2, 3]));
+        assert_eq!(compact_multiproof_descriptor(&[2]), Ok(vec![0b0110_0000]));
+
+        // root -> (2 -> (4, 5), 3 -> (6 -> (12, 13), 7))
+        assert_eq!(
+            compact_multiproof_nodes(&[5, 13]),
+            Ok(vec![4, 5, 12, 13, 7])
+        );
+        assert_eq!(
+            compact_multiproof_descriptor(&[5, 13]),
+            Ok(vec![0b0011_0011, 0b1000_0000])
+        );
+    }
+
+    #[test]
+    fn invalid_gindex() {
+        assert_eq!(
+            compact_multiproof_descriptor(&[5, 0]),
+            Err(ProofError::InvalidGeneralizedIndex(0))
+        );
+
+        assert!(matches!(
+            Multiproof::from_compact(&[0], &[]),
+            Err(Error::Proof(ProofError::InvalidGeneralizedIndex(0)))
+        ));
+    }
+
+    #[test]
+    fn from_compact() {
+        let h256 = |n: u8| -> H256 { H256::new([n; 32]) };
+
+        let bytes = [h256(4), h256(5), h256(12), h256(13), h256(7)]
+            .iter()
+            .flat_map(|h| *h.get())
+            .collect::<Vec<_>>();
+
+        let proof = Multiproof::from_compact(&[13, 5], &bytes).unwrap();
+
+        assert_eq!(proof.leaves, [h256(13), h256(5)]);
+        assert_eq!(proof.proof, [h256(12), h256(7), h256(4)]);
+
+        assert!(matches!(
+            Multiproof::from_compact(&[13, 5], &bytes[32..]),
+            Err(Error::InvalidProofLength {
+                expected: 160,
+                found: 128
+            })
+        ));
+    }
+}
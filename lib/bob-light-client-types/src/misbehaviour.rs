@@ -0,0 +1,12 @@
+use crate::Header;
+
+/// Two headers for the same L2 block that commit to different output roots, both of which are
+/// provable against the L1. This can only happen if the proposer or the L1 client is compromised,
+/// and the client will be frozen.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+pub struct Misbehaviour {
+    pub header_a: Header,
+    pub header_b: Header,
+}
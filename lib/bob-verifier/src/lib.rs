@@ -34,8 +34,29 @@ pub enum Error {
     InvalidIbcContractStorageRoot,
     #[error("the l2 header is not finalized")]
     HeaderNotFinalized,
+    #[error("misbehaviour headers are for different l2 blocks ({a} != {b})")]
+    MisbehaviourL2BlockMismatch { a: U256, b: U256 },
+    #[error("misbehaviour headers commit to the same output root ({0})")]
+    MisbehaviourOutputRootsEqual(H256),
+    #[error("invalid game status proof")]
+    InvalidGameStatusProof(#[source] evm_storage_verifier::error::Error),
+    #[error(
+        "the game has not been resolved in favour of the defender (status {status}), \
+        the root claim is not final"
+    )]
+    GameNotResolved { status: u8 },
 }
 
+/// The storage slot of the `FaultDisputeGame` that `createdAt`, `resolvedAt` and `status` are packed into.
+///
+/// See <https://github.com/ethereum-optimism/optimism/blob/4a7cb8a198a1f027e739d2e51dc170faf02b5d28/packages/contracts-bedrock/src/dispute/FaultDisputeGame.sol>
+pub const FAULT_DISPUTE_GAME_STATUS_SLOT: U256 = U256::ZERO;
+
+/// `GameStatus.DEFENDER_WINS`, the status of a game whose root claim has been resolved as valid.
+///
+/// See <https://github.com/ethereum-optimism/optimism/blob/4a7cb8a198a1f027e739d2e51dc170faf02b5d28/packages/contracts-bedrock/src/dispute/lib/Types.sol>
+pub const GAME_STATUS_DEFENDER_WINS: u8 = 2;
+
 pub fn verify_header(
     client_state: &ClientStateV2,
     header: &Header,
@@ -117,9 +138,78 @@ pub fn verify_header(
     )
     .map_err(Error::InvalidIbcContractProof)?;
 
+    // 7. Verify that the game has been resolved in favour of the defender, as the root claim of a
+    // game is only final once it has been resolved.
+    verify_account_storage_root(
+        l1_state_root,
+        &game_account_address,
+        &header.game_account_proof.proof,
+        &header.game_account_proof.storage_root,
+    )
+    .map_err(Error::InvalidGameStatusProof)?;
+
+    verify_storage_proof(
+        header.game_account_proof.storage_root,
+        FAULT_DISPUTE_GAME_STATUS_SLOT,
+        &rlp::encode(&header.game_status_proof.value),
+        &header.game_status_proof.proof,
+    )
+    .map_err(Error::InvalidGameStatusProof)?;
+
+    let status = game_status(header.game_status_proof.value);
+    if status != GAME_STATUS_DEFENDER_WINS {
+        return Err(Error::GameNotResolved { status });
+    }
+
+    Ok(())
+}
+
+/// Verify that the two provided headers are a valid proof of misbehaviour.
+///
+/// Misbehaviour verification consists of 3 steps:
+///
+/// 1. Verify that both headers are for the same L2 block.
+/// 2. Verify that the output roots of the headers are different. Since the output root commits to
+///    the L2 block hash, two different output roots for the same L2 block are conflicting.
+/// 3. Verify both headers against their respective L1 state roots, as in [`verify_header`].
+pub fn verify_misbehaviour(
+    client_state: &ClientStateV2,
+    header_a: &Header,
+    l1_state_root_a: H256,
+    header_b: &Header,
+    l1_state_root_b: H256,
+) -> Result<(), Error> {
+    // 1.
+    if header_a.l2_header.number != header_b.l2_header.number {
+        return Err(Error::MisbehaviourL2BlockMismatch {
+            a: header_a.l2_header.number,
+            b: header_b.l2_header.number,
+        });
+    }
+
+    // 2.
+    let output_root_a = compute_output_root_proof_hash(&header_a.output_root_proof);
+    let output_root_b = compute_output_root_proof_hash(&header_b.output_root_proof);
+    if output_root_a == output_root_b {
+        return Err(Error::MisbehaviourOutputRootsEqual(output_root_a));
+    }
+
+    // 3.
+    verify_header(client_state, header_a, l1_state_root_a)?;
+    verify_header(client_state, header_b, l1_state_root_b)?;
+
     Ok(())
 }
 
+/// Extract the `status` of a `FaultDisputeGame` from the value of its
+/// [`FAULT_DISPUTE_GAME_STATUS_SLOT`].
+///
+/// The slot is packed as `createdAt (uint64) | resolvedAt (uint64) | status (uint8)`, starting from
+/// the lowest order bytes.
+pub fn game_status(status_slot_value: U256) -> u8 {
+    status_slot_value.to_be_bytes()[15]
+}
+
 pub fn compute_game_slot(dispute_game_list_slot: U256, index: U256) -> U256 {
     let offset = Slot::Offset(dispute_game_list_slot);
     Slot::Array(&offset, index).slot()
@@ -152,3 +242,275 @@ pub fn verify_l2_header_is_related_to_output_root_proof(
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ethereum_light_client_types::{AccountProof, StorageProof};
+    use evm_storage_verifier::{test_utils::TestTrie, Account};
+    use ibc_union_spec::ClientId;
+    use unionlabs::primitives::{Bytes, H2048};
+
+    use super::*;
+
+    const GAME_STATUS_IN_PROGRESS: u8 = 0;
+    const GAME_STATUS_CHALLENGER_WINS: u8 = 1;
+
+    fn mk_client_state() -> ClientStateV2 {
+        ClientStateV2 {
+            chain_id: 60808u32.into(),
+            latest_height: 99,
+            l1_client_id: ClientId!(1),
+            dispute_game_factory_address: H160::new([0xDF; 20]),
+            dispute_game_factory_dispute_game_list_slot: 0x68u32.into(),
+            fault_dispute_game_code_root_claim_index: 98 + 20,
+            frozen_height: 0,
+            ibc_contract_address: H160::new([0x1B; 20]),
+        }
+    }
+
+    fn mk_l2_header() -> L2Header {
+        L2Header {
+            parent_hash: H256::new([1; 32]),
+            sha3_uncles: H256::new([2; 32]),
+            miner: H160::new([3; 20]),
+            state_root: H256::default(),
+            transactions_root: H256::new([4; 32]),
+            receipts_root: H256::new([5; 32]),
+            logs_bloom: Box::new(H2048::default()),
+            difficulty: U256::ZERO,
+            number: U256::from(100_u64),
+            gas_limit: 30_000_000,
+            gas_used: 21_000,
+            timestamp: 1_700_000_000,
+            extra_data: Default::default(),
+            mix_hash: H256::new([6; 32]),
+            nonce: Default::default(),
+            base_fee_per_gas: U256::from(7_u64),
+            withdrawals_root: H256::new([8; 32]),
+            blob_gas_used: 0,
+            excess_blob_gas: 0,
+            parent_beacon_block_root: H256::new([9; 32]),
+            requests_hash: H256::new([10; 32]),
+        }
+    }
+
+    /// Build a header for `l2_header`, settled by a game with the provided `status`, along with
+    /// the l1 state root that the header can be verified against.
+    fn mk_header(
+        client_state: &ClientStateV2,
+        mut l2_header: L2Header,
+        message_passer_storage_root: H256,
+        status: u8,
+    ) -> (Header, H256) {
+        let ibc_account = Account {
+            nonce: 1,
+            balance: U256::ZERO,
+            storage_root: H256::new([0x1C; 32]),
+            code_hash: H256::new([0x1D; 32]),
+        };
+        let l2_state = TestTrie::state([(client_state.ibc_contract_address, ibc_account.clone())]);
+        l2_header.state_root = l2_state.root();
+
+        let output_root_proof = OutputRootProof {
+            version: H256::default(),
+            state_root: l2_header.state_root,
+            message_passer_storage_root,
+            latest_block_hash: l2_header.hash(),
+        };
+
+        // the root claim is part of the immutable args appended to the game proxy bytecode
+        let game_account_code: Bytes = [
+            vec![0; client_state.fault_dispute_game_code_root_claim_index as usize],
+            compute_output_root_proof_hash(&output_root_proof)
+                .get()
+                .to_vec(),
+            l2_header.number.to_be_bytes().to_vec(),
+        ]
+        .concat()
+        .into();
+
+        let game_account_address = H160::new([0x6A; 20]);
+        let mut game_id = [0; 32];
+        game_id[12..].copy_from_slice(game_account_address.get());
+        let game_id = U256::from_be_bytes(game_id);
+
+        let mut game_status_slot = [0; 32];
+        game_status_slot[15] = status;
+        // resolvedAt
+        game_status_slot[16..24].copy_from_slice(&1_700_000_002_u64.to_be_bytes());
+        // createdAt
+        game_status_slot[24..].copy_from_slice(&1_700_000_001_u64.to_be_bytes());
+        let game_status_slot = U256::from_be_bytes(game_status_slot);
+
+        let game_storage = TestTrie::storage([(FAULT_DISPUTE_GAME_STATUS_SLOT, game_status_slot)]);
+
+        let game_index = U256::from(7_u64);
+        let game_slot = compute_game_slot(
+            client_state.dispute_game_factory_dispute_game_list_slot,
+            game_index,
+        );
+        let factory_storage = TestTrie::storage([(game_slot, game_id)]);
+
+        let l1_state = TestTrie::state([
+            (
+                client_state.dispute_game_factory_address,
+                Account {
+                    nonce: 1,
+                    balance: U256::ZERO,
+                    storage_root: factory_storage.root(),
+                    code_hash: H256::new([0xFC; 32]),
+                },
+            ),
+            (
+                game_account_address,
+                Account {
+                    nonce: 1,
+                    balance: U256::ZERO,
+                    storage_root: game_storage.root(),
+                    code_hash: keccak256(&game_account_code),
+                },
+            ),
+        ]);
+
+        let header = Header {
+            l1_height: 1,
+            dispute_game_factory_account_proof: AccountProof {
+                storage_root: factory_storage.root(),
+                proof: l1_state.proof(client_state.dispute_game_factory_address),
+            },
+            game_index,
+            game_proof: StorageProof {
+                key: game_slot,
+                value: game_id,
+                proof: factory_storage.storage_proof(game_slot),
+            },
+            game_account_proof: AccountProof {
+                storage_root: game_storage.root(),
+                proof: l1_state.proof(game_account_address),
+            },
+            game_status_proof: StorageProof {
+                key: FAULT_DISPUTE_GAME_STATUS_SLOT,
+                value: game_status_slot,
+                proof: game_storage.storage_proof(FAULT_DISPUTE_GAME_STATUS_SLOT),
+            },
+            game_account_code,
+            l2_ibc_account_proof: AccountProof {
+                storage_root: ibc_account.storage_root,
+                proof: l2_state.proof(client_state.ibc_contract_address),
+            },
+            l2_header,
+            output_root_proof,
+        };
+
+        (header, l1_state.root())
+    }
+
+    #[test]
+    fn verify_header_works() {
+        let client_state = mk_client_state();
+
+        let (header, l1_state_root) = mk_header(
+            &client_state,
+            mk_l2_header(),
+            H256::new([0xAA; 32]),
+            GAME_STATUS_DEFENDER_WINS,
+        );
+
+        verify_header(&client_state, &header, l1_state_root).unwrap();
+    }
+
+    #[test]
+    fn verify_header_fails_for_unresolved_game() {
+        let client_state = mk_client_state();
+
+        for status in [GAME_STATUS_IN_PROGRESS, GAME_STATUS_CHALLENGER_WINS] {
+            let (header, l1_state_root) =
+                mk_header(&client_state, mk_l2_header(), H256::new([0xAA; 32]), status);
+
+            assert_eq!(
+                verify_header(&client_state, &header, l1_state_root),
+                Err(Error::GameNotResolved { status })
+            );
+        }
+    }
+
+    #[test]
+    fn verify_header_fails_for_invalid_game_status_proof() {
+        let client_state = mk_client_state();
+
+        let (mut header, l1_state_root) = mk_header(
+            &client_state,
+            mk_l2_header(),
+            H256::new([0xAA; 32]),
+            GAME_STATUS_IN_PROGRESS,
+        );
+
+        // claim that the game was resolved without the proof to back it up
+        let mut game_status_slot = header.game_status_proof.value.to_be_bytes();
+        game_status_slot[15] = GAME_STATUS_DEFENDER_WINS;
+        header.game_status_proof.value = U256::from_be_bytes(game_status_slot);
+
+        assert!(matches!(
+            verify_header(&client_state, &header, l1_state_root),
+            Err(Error::InvalidGameStatusProof(_))
+        ));
+    }
+
+    #[test]
+    fn verify_misbehaviour_works() {
+        let client_state = mk_client_state();
+
+        let (header_a, l1_state_root_a) = mk_header(
+            &client_state,
+            mk_l2_header(),
+            H256::new([0xAA; 32]),
+            GAME_STATUS_DEFENDER_WINS,
+        );
+        let (header_b, l1_state_root_b) = mk_header(
+            &client_state,
+            mk_l2_header(),
+            H256::new([0xBB; 32]),
+            GAME_STATUS_DEFENDER_WINS,
+        );
+
+        verify_misbehaviour(
+            &client_state,
+            &header_a,
+            l1_state_root_a,
+            &header_b,
+            l1_state_root_b,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn verify_misbehaviour_fails_for_unresolved_game() {
+        let client_state = mk_client_state();
+
+        let (header_a, l1_state_root_a) = mk_header(
+            &client_state,
+            mk_l2_header(),
+            H256::new([0xAA; 32]),
+            GAME_STATUS_DEFENDER_WINS,
+        );
+        let (header_b, l1_state_root_b) = mk_header(
+            &client_state,
+            mk_l2_header(),
+            H256::new([0xBB; 32]),
+            GAME_STATUS_IN_PROGRESS,
+        );
+
+        assert_eq!(
+            verify_misbehaviour(
+                &client_state,
+                &header_a,
+                l1_state_root_a,
+                &header_b,
+                l1_state_root_b,
+            ),
+            Err(Error::GameNotResolved {
+                status: GAME_STATUS_IN_PROGRESS
+            })
+        );
+    }
+}
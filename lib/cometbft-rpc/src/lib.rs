@@ -6,6 +6,7 @@ use std::{
 };
 
 use ::serde::de::DeserializeOwned;
+use ::serde_json::{value::RawValue, Value};
 use jsonrpsee::{
     core::{
         client::{BatchResponse, ClientT},
@@ -16,6 +17,7 @@ use jsonrpsee::{
     rpc_params,
     ws_client::{PingConfig, WsClientBuilder},
 };
+use rpc_pool::{PoolError, RpcPool};
 use tracing::{debug, debug_span, instrument, trace, Instrument};
 use unionlabs::{
     bounded::{BoundedI64, BoundedU8},
@@ -24,10 +26,18 @@ use unionlabs::{
     result_unwrap, ErrorReporter,
 };
 
-use crate::rpc_types::{
-    AbciQueryResponse, AllValidatorsResponse, BlockResponse, BlockResultsResponse,
-    BlockchainResponse, BroadcastTxSyncResponse, CommitResponse, GrpcAbciQueryResponse, Order,
-    StatusResponse, TxResponse, TxSearchResponse, ValidatorsResponse,
+use crate::{
+    rpc_types::{
+        AbciQueryResponse, AllValidatorsResponse, BlockResponse, BlockResultsResponse,
+        BlockchainResponse, BroadcastTxAsyncResponse, BroadcastTxCommitResponse,
+        BroadcastTxSyncResponse, CommitResponse, ConsensusParamsResponse, GenesisChunkedResponse,
+        GrpcAbciQueryResponse, HeaderResponse, NetInfoResponse, Order, StatusResponse, TxResponse,
+        TxSearchResponse, UnconfirmedTxsResponse, ValidatorsResponse,
+    },
+    types::{
+        abci::response_check_tx::CheckTxResponse,
+        types::{light_block::LightBlock, validator_set::ValidatorSet},
+    },
 };
 
 #[cfg(test)]
@@ -36,6 +46,7 @@ mod tests;
 pub mod rpc_types;
 pub mod serde;
 pub use cometbft_types as types;
+pub use rpc_pool::PoolConfig;
 
 pub type JsonRpcError = jsonrpsee::core::client::Error;
 
@@ -46,34 +57,37 @@ pub struct Client {
 
 impl Client {
     pub async fn new(url: impl AsRef<str>) -> Result<Self, JsonRpcError> {
-        let url = url.as_ref().to_owned();
+        Ok(Self {
+            inner: ClientInner::Single(Transport::connect(url.as_ref().to_owned()).await?),
+        })
+    }
 
-        let inner = match url.split_once("://") {
-            Some(("ws" | "wss", _)) => {
-                let client = reconnecting_jsonrpc_ws_client::Client::new(move || {
-                    WsClientBuilder::default()
-                        .enable_ws_ping(PingConfig::new())
-                        .build(url.clone())
-                        .instrument(debug_span!("cometbft_rpc_client", %url))
-                });
+    /// Create a client that spreads requests across all of the endpoints in `config`, failing
+    /// over to the next endpoint on errors.
+    ///
+    /// If `config` requires a quorum, `commit`, `block` and `header` queries at an explicit height
+    /// are sent to all endpoints, and the header (respectively block id) must be agreed upon by at
+    /// least that many endpoints.
+    pub async fn new_pool(config: PoolConfig) -> Result<Self, JsonRpcError> {
+        let pool = RpcPool::new(config, Transport::connect)
+            .await
+            .map_err(pool_error)?;
 
-                // TODO: Config
-                client
-                    .wait_until_connected(Duration::from_secs(5))
-                    .await
-                    .map_err(|e| JsonRpcError::Custom(e.to_string()))?;
+        pool.spawn_health_check(|transport: Transport| async move {
+            let status = transport
+                .request::<StatusResponse, _>("status", rpc_params!())
+                .await?;
 
-                ClientInner::Ws(client)
+            if status.sync_info.catching_up {
+                Err(JsonRpcError::Custom("node is catching up".to_owned()))
+            } else {
+                Ok(status.sync_info.latest_block_height)
             }
-            Some(("http" | "https", _)) => ClientInner::Http(Box::new(
-                HttpClientBuilder::default()
-                    .max_response_size(100 * 1024 * 1024)
-                    .build(url)?,
-            )),
-            _ => return Err(JsonRpcError::Custom(format!("invalid url {url}"))),
-        };
+        });
 
-        Ok(Self { inner })
+        Ok(Self {
+            inner: ClientInner::Pool(pool),
+        })
     }
 
     pub async fn commit(&self, height: Option<NonZeroU64>) -> Result<CommitResponse, JsonRpcError> {
@@ -82,6 +96,64 @@ impl Client {
             .await
     }
 
+    /// Fetch the signed header and validator set at `height`, as required to verify the header
+    /// with the light client verification algorithm.
+    ///
+    /// The proposer of the validator set is the validator with the highest proposer priority, as
+    /// per [`ValidatorSet.GetProposer`].
+    ///
+    /// [`ValidatorSet.GetProposer`]: https://github.com/cometbft/cometbft/blob/v0.38.17/types/validator_set.go
+    pub async fn light_block(
+        &self,
+        height: Option<NonZeroU64>,
+    ) -> Result<LightBlock, JsonRpcError> {
+        let signed_header = self.commit(height).await?.signed_header;
+
+        let validators = self
+            .all_validators(Some(
+                signed_header
+                    .header
+                    .height
+                    .inner()
+                    .try_into()
+                    .ok()
+                    .and_then(NonZeroU64::new)
+                    .ok_or_else(|| {
+                        JsonRpcError::Custom(format!(
+                            "invalid header height {}",
+                            signed_header.header.height
+                        ))
+                    })?,
+            ))
+            .await?
+            .validators;
+
+        let proposer = validators
+            .iter()
+            // ties are broken by the lowest address
+            .max_by(|a, b| {
+                a.proposer_priority
+                    .cmp(&b.proposer_priority)
+                    .then_with(|| b.address.cmp(&a.address))
+            })
+            .cloned()
+            .ok_or_else(|| JsonRpcError::Custom("empty validator set".to_owned()))?;
+
+        let total_voting_power = validators
+            .iter()
+            .map(|validator| validator.voting_power.inner())
+            .sum();
+
+        Ok(LightBlock {
+            signed_header,
+            validator_set: ValidatorSet {
+                validators,
+                proposer,
+                total_voting_power,
+            },
+        })
+    }
+
     pub async fn validators(
         &self,
         height: Option<NonZeroU64>,
@@ -242,6 +314,42 @@ impl Client {
         self.inner.request("status", rpc_params!()).await
     }
 
+    pub async fn net_info(&self) -> Result<NetInfoResponse, JsonRpcError> {
+        self.inner.request("net_info", rpc_params!()).await
+    }
+
+    /// Fetch chunk `chunk` of the genesis document. Chunks are 16MB each, and the chunks must be
+    /// concatenated to decode the genesis document.
+    pub async fn genesis_chunked(
+        &self,
+        chunk: u64,
+    ) -> Result<GenesisChunkedResponse, JsonRpcError> {
+        self.inner
+            .request("genesis_chunked", (chunk.to_string(),))
+            .await
+    }
+
+    pub async fn header(&self, height: Option<NonZeroU64>) -> Result<HeaderResponse, JsonRpcError> {
+        self.inner
+            .request("header", (height.map(|x| x.to_string()),))
+            .await
+    }
+
+    pub async fn header_by_hash(&self, hash: H256) -> Result<HeaderResponse, JsonRpcError> {
+        self.inner
+            .request("header_by_hash", (hash.to_string(),))
+            .await
+    }
+
+    pub async fn consensus_params(
+        &self,
+        height: Option<NonZeroU64>,
+    ) -> Result<ConsensusParamsResponse, JsonRpcError> {
+        self.inner
+            .request("consensus_params", (height.map(|x| x.to_string()),))
+            .await
+    }
+
     pub async fn block(&self, height: Option<NonZeroU64>) -> Result<BlockResponse, JsonRpcError> {
         self.inner
             .request("block", (height.map(|x| x.to_string()),))
@@ -326,6 +434,58 @@ impl Client {
             .await
     }
 
+    /// Broadcast a transaction without waiting for `CheckTx`.
+    pub async fn broadcast_tx_async(
+        &self,
+        tx: &[u8],
+    ) -> Result<BroadcastTxAsyncResponse, JsonRpcError> {
+        use base64::prelude::*;
+
+        self.inner
+            .request(
+                "broadcast_tx_async",
+                rpc_params![BASE64_STANDARD.encode(tx)],
+            )
+            .await
+    }
+
+    /// Broadcast a transaction and wait until it is included in a block.
+    ///
+    /// NOTE: This is not recommended for production use, as the request will time out if the
+    /// transaction is not included in a block within the node's `timeout_broadcast_tx_commit`.
+    pub async fn broadcast_tx_commit(
+        &self,
+        tx: &[u8],
+    ) -> Result<BroadcastTxCommitResponse, JsonRpcError> {
+        use base64::prelude::*;
+
+        self.inner
+            .request(
+                "broadcast_tx_commit",
+                rpc_params![BASE64_STANDARD.encode(tx)],
+            )
+            .await
+    }
+
+    /// Run `CheckTx` on a transaction without adding it to the mempool.
+    pub async fn check_tx(&self, tx: &[u8]) -> Result<CheckTxResponse, JsonRpcError> {
+        use base64::prelude::*;
+
+        self.inner
+            .request("check_tx", rpc_params![BASE64_STANDARD.encode(tx)])
+            .await
+    }
+
+    /// Fetch up to `limit` (default 30) transactions from the mempool.
+    pub async fn unconfirmed_txs(
+        &self,
+        limit: Option<BoundedU8<1, 100>>,
+    ) -> Result<UnconfirmedTxsResponse, JsonRpcError> {
+        self.inner
+            .request("unconfirmed_txs", rpc_params![limit.map(|x| x.to_string())])
+            .await
+    }
+
     pub async fn block_results(
         &self,
         height: Option<NonZeroU64>,
@@ -338,18 +498,168 @@ impl Client {
 
 #[derive(Debug, Clone)]
 enum ClientInner {
+    Single(Transport),
+    Pool(RpcPool<Transport>),
+}
+
+impl ClientT for ClientInner {
+    async fn notification<Params>(&self, method: &str, params: Params) -> Result<(), JsonRpcError>
+    where
+        Params: ToRpcParams + Send,
+    {
+        match self {
+            ClientInner::Single(transport) => transport.notification(method, params).await,
+            ClientInner::Pool(pool) => {
+                let params = RawParams(params.to_rpc_params()?);
+
+                pool.call(|transport| {
+                    let params = params.clone();
+                    async move { transport.notification(method, params).await }
+                })
+                .await
+                .map_err(pool_error)
+            }
+        }
+    }
+
+    async fn request<R, Params>(&self, method: &str, params: Params) -> Result<R, JsonRpcError>
+    where
+        R: DeserializeOwned,
+        Params: ToRpcParams + Send,
+    {
+        match self {
+            ClientInner::Single(transport) => transport.request(method, params).await,
+            ClientInner::Pool(pool) => {
+                let params = RawParams(params.to_rpc_params()?);
+
+                match quorum_pointer(method, &params) {
+                    Some(pointer) => {
+                        let value = pool
+                            .quorum(
+                                |transport| {
+                                    let params = params.clone();
+                                    async move {
+                                        transport.request::<Value, _>(method, params).await
+                                    }
+                                },
+                                |value: &Value| value.pointer(pointer).cloned(),
+                            )
+                            .await
+                            .map_err(pool_error)?;
+
+                        Ok(serde_json::from_value(value)?)
+                    }
+                    None => pool
+                        .call(|transport| {
+                            let params = params.clone();
+                            async move { transport.request(method, params).await }
+                        })
+                        .await
+                        .map_err(pool_error),
+                }
+            }
+        }
+    }
+
+    async fn batch_request<'a, R>(
+        &self,
+        batch: BatchRequestBuilder<'a>,
+    ) -> Result<BatchResponse<'a, R>, JsonRpcError>
+    where
+        R: DeserializeOwned + fmt::Debug + 'a,
+    {
+        match self {
+            ClientInner::Single(transport) => transport.batch_request(batch).await,
+            ClientInner::Pool(pool) => pool
+                .call(|transport| {
+                    let batch = batch.clone();
+                    async move { transport.batch_request(batch).await }
+                })
+                .await
+                .map_err(pool_error),
+        }
+    }
+}
+
+/// The JSON pointer to compare the results of a pooled request with, if the pool requires a
+/// quorum. Only queries at an explicit height are checked, as the latest block is expected to
+/// differ between endpoints.
+fn quorum_pointer(method: &str, params: &RawParams) -> Option<&'static str> {
+    let pointer = match method {
+        "commit" => "/signed_header/header",
+        "block" => "/block_id",
+        "header" => "/header",
+        _ => return None,
+    };
+
+    let params = serde_json::from_str::<Vec<Value>>(params.0.as_ref()?.get()).ok()?;
+
+    params
+        .first()
+        .is_some_and(|height| !height.is_null())
+        .then_some(pointer)
+}
+
+fn pool_error(err: PoolError<JsonRpcError>) -> JsonRpcError {
+    err.into_first_endpoint_error()
+        .unwrap_or_else(|err| JsonRpcError::Custom(ErrorReporter(err).to_string()))
+}
+
+/// Already serialized params, such that a request can be sent to multiple endpoints.
+#[derive(Debug, Clone)]
+struct RawParams(Option<Box<RawValue>>);
+
+impl ToRpcParams for RawParams {
+    fn to_rpc_params(self) -> Result<Option<Box<RawValue>>, serde_json::Error> {
+        Ok(self.0)
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Transport {
     Http(Box<HttpClient>),
     Ws(reconnecting_jsonrpc_ws_client::Client),
 }
 
-impl ClientT for ClientInner {
+impl Transport {
+    async fn connect(url: String) -> Result<Self, JsonRpcError> {
+        let transport = match url.split_once("://") {
+            Some(("ws" | "wss", _)) => {
+                let client = reconnecting_jsonrpc_ws_client::Client::new(move || {
+                    WsClientBuilder::default()
+                        .enable_ws_ping(PingConfig::new())
+                        .build(url.clone())
+                        .instrument(debug_span!("cometbft_rpc_client", %url))
+                });
+
+                // TODO: Config
+                client
+                    .wait_until_connected(Duration::from_secs(5))
+                    .await
+                    .map_err(|e| JsonRpcError::Custom(e.to_string()))?;
+
+                Transport::Ws(client)
+            }
+            Some(("http" | "https", _)) => Transport::Http(Box::new(
+                HttpClientBuilder::default()
+                    .max_response_size(100 * 1024 * 1024)
+                    .build(url)?,
+            )),
+            _ => return Err(JsonRpcError::Custom(format!("invalid url {url}"))),
+        };
+
+        Ok(transport)
+    }
+}
+
+impl ClientT for Transport {
     async fn notification<Params>(&self, method: &str, params: Params) -> Result<(), JsonRpcError>
     where
         Params: ToRpcParams + Send,
     {
         match self {
-            ClientInner::Http(client) => client.notification(method, params).await,
-            ClientInner::Ws(client) => client.notification(method, params).await,
+            Transport::Http(client) => client.notification(method, params).await,
+            Transport::Ws(client) => client.notification(method, params).await,
         }
     }
 
@@ -359,8 +669,8 @@ impl ClientT for ClientInner {
         Params: ToRpcParams + Send,
     {
         match self {
-            ClientInner::Http(client) => client.request(method, params).await,
-            ClientInner::Ws(client) => client.request(method, params).await,
+            Transport::Http(client) => client.request(method, params).await,
+            Transport::Ws(client) => client.request(method, params).await,
         }
     }
 
@@ -372,8 +682,8 @@ impl ClientT for ClientInner {
         R: DeserializeOwned + fmt::Debug + 'a,
     {
         match self {
-            ClientInner::Http(client) => client.batch_request(batch).await,
-            ClientInner::Ws(client) => client.batch_request(batch).await,
+            Transport::Http(client) => client.batch_request(batch).await,
+            Transport::Ws(client) => client.batch_request(batch).await,
         }
     }
 }
@@ -1,13 +1,16 @@
 use std::num::{NonZeroU32, NonZeroU64};
 
 use cometbft_types::{
-    abci::{event::Event, exec_tx_result::ExecTxResult, response_query::QueryResponse},
+    abci::{
+        event::Event, exec_tx_result::ExecTxResult, response_check_tx::CheckTxResponse,
+        response_query::QueryResponse,
+    },
     code::Code,
     crypto::{proof_ops::ProofOps, public_key::PublicKey},
     p2p::default_node_info::DefaultNodeInfo,
     types::{
-        block::Block, block_id::BlockId, header::Header, signed_header::SignedHeader,
-        tx_proof::TxProof, validator::Validator,
+        block::Block, block_id::BlockId, consensus_params::ConsensusParams, header::Header,
+        signed_header::SignedHeader, tx_proof::TxProof, validator::Validator,
     },
 };
 use serde::{Deserialize, Serialize};
@@ -205,3 +208,135 @@ pub struct BroadcastTxSyncResponse {
     pub log: String,
     pub hash: H256<HexUnprefixed>,
 }
+
+/// `broadcast_tx_async` returns without waiting for `CheckTx`, so only `hash` is meaningful.
+pub type BroadcastTxAsyncResponse = BroadcastTxSyncResponse;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BroadcastTxCommitResponse {
+    pub check_tx: CheckTxResponse,
+    /// Named `deliver_tx` on nodes < v0.38.
+    #[serde(alias = "deliver_tx")]
+    pub tx_result: ExecTxResult,
+    pub hash: H256<HexUnprefixed>,
+    /// `0` if the transaction failed `CheckTx`.
+    #[serde(with = "::serde_utils::string")]
+    pub height: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct HeaderResponse {
+    pub header: Header,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ConsensusParamsResponse {
+    #[serde(with = "::serde_utils::string")]
+    pub block_height: NonZeroU64,
+    pub consensus_params: ConsensusParams,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GenesisChunkedResponse {
+    #[serde(with = "::serde_utils::string")]
+    pub chunk: u64,
+    #[serde(with = "::serde_utils::string")]
+    pub total: u64,
+    /// A chunk of the JSON encoded genesis document.
+    pub data: Bytes<Base64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NetInfoResponse {
+    pub listening: bool,
+    pub listeners: Vec<String>,
+    #[serde(with = "::serde_utils::string")]
+    pub n_peers: u64,
+    pub peers: Vec<Peer>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Peer {
+    pub node_info: DefaultNodeInfo,
+    pub is_outbound: bool,
+    pub connection_status: ConnectionStatus,
+    pub remote_ip: String,
+}
+
+// https://github.com/cometbft/cometbft/blob/v0.38.17/p2p/conn/connection.go
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "PascalCase")]
+pub struct ConnectionStatus {
+    /// Nanoseconds since the connection was established.
+    #[serde(with = "::serde_utils::string")]
+    pub duration: i64,
+    pub send_monitor: FlowStatus,
+    pub recv_monitor: FlowStatus,
+    pub channels: Vec<ChannelStatus>,
+}
+
+/// Transfer statistics of a connection. All durations are in nanoseconds, and all rates are in
+/// bytes per second.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "PascalCase")]
+pub struct FlowStatus {
+    pub start: Timestamp,
+    #[serde(with = "::serde_utils::string")]
+    pub bytes: i64,
+    #[serde(with = "::serde_utils::string")]
+    pub samples: i64,
+    #[serde(with = "::serde_utils::string")]
+    pub inst_rate: i64,
+    #[serde(with = "::serde_utils::string")]
+    pub cur_rate: i64,
+    #[serde(with = "::serde_utils::string")]
+    pub avg_rate: i64,
+    #[serde(with = "::serde_utils::string")]
+    pub peak_rate: i64,
+    #[serde(with = "::serde_utils::string")]
+    pub bytes_rem: i64,
+    #[serde(with = "::serde_utils::string")]
+    pub duration: i64,
+    #[serde(with = "::serde_utils::string")]
+    pub idle: i64,
+    #[serde(with = "::serde_utils::string")]
+    pub time_rem: i64,
+    pub progress: u32,
+    pub active: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "PascalCase")]
+pub struct ChannelStatus {
+    #[serde(rename = "ID")]
+    pub id: u8,
+    #[serde(with = "::serde_utils::string")]
+    pub send_queue_capacity: u64,
+    #[serde(with = "::serde_utils::string")]
+    pub send_queue_size: u64,
+    #[serde(with = "::serde_utils::string")]
+    pub priority: i64,
+    #[serde(with = "::serde_utils::string")]
+    pub recently_sent: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct UnconfirmedTxsResponse {
+    /// The number of transactions returned.
+    #[serde(with = "::serde_utils::string")]
+    pub n_txs: u64,
+    /// The total number of transactions in the mempool.
+    #[serde(with = "::serde_utils::string")]
+    pub total: u64,
+    /// The total size of all transactions in the mempool, in bytes.
+    #[serde(with = "::serde_utils::string")]
+    pub total_bytes: u64,
+    pub txs: Vec<Bytes<Base64>>,
+}
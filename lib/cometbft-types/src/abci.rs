@@ -1,4 +1,5 @@
 pub mod event;
 pub mod event_attribute;
 pub mod exec_tx_result;
+pub mod response_check_tx;
 pub mod response_query;
@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+use unionlabs::{
+    bounded::BoundedI64,
+    primitives::{encoding::Base64, Bytes},
+};
+
+use crate::{abci::event::Event, code::Code};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CheckTxResponse {
+    pub code: Code,
+    pub data: Option<Bytes<Base64>>,
+    /// nondeterministic
+    pub log: String,
+    /// nondeterministic
+    pub info: String,
+    #[serde(with = "::serde_utils::string")]
+    pub gas_wanted: BoundedI64<0, { i64::MAX }>,
+    #[serde(with = "::serde_utils::string")]
+    pub gas_used: BoundedI64<0, { i64::MAX }>,
+    pub events: Vec<Event>,
+    pub codespace: String,
+}
+
+#[cfg(feature = "proto")]
+pub mod proto {
+    use unionlabs::bounded::BoundedIntError;
+
+    use crate::abci::response_check_tx::CheckTxResponse;
+
+    impl From<CheckTxResponse> for protos::cometbft::abci::v1::CheckTxResponse {
+        fn from(value: CheckTxResponse) -> Self {
+            Self {
+                code: value.code.into(),
+                data: value.data.unwrap_or_default().into(),
+                log: value.log,
+                info: value.info,
+                gas_wanted: value.gas_wanted.into(),
+                gas_used: value.gas_used.into(),
+                events: value.events.into_iter().map(Into::into).collect(),
+                codespace: value.codespace,
+            }
+        }
+    }
+
+    #[derive(Debug, PartialEq, Clone, thiserror::Error)]
+    pub enum Error {
+        #[error("invalid gas_wanted")]
+        GasWanted(#[source] BoundedIntError<i64>),
+        #[error("invalid gas_used")]
+        GasUsed(#[source] BoundedIntError<i64>),
+    }
+
+    impl TryFrom<protos::cometbft::abci::v1::CheckTxResponse> for CheckTxResponse {
+        type Error = Error;
+
+        fn try_from(
+            value: protos::cometbft::abci::v1::CheckTxResponse,
+        ) -> Result<Self, Self::Error> {
+            Ok(Self {
+                code: value.code.into(),
+                data: Some(value.data.into()),
+                log: value.log,
+                info: value.info,
+                gas_wanted: value.gas_wanted.try_into().map_err(Error::GasWanted)?,
+                gas_used: value.gas_used.try_into().map_err(Error::GasUsed)?,
+                events: value.events.into_iter().map(Into::into).collect(),
+                codespace: value.codespace,
+            })
+        }
+    }
+}
@@ -24,6 +24,46 @@ pub mod utils {
             )
         })
     }
+
+    #[cfg(feature = "proto")]
+    use unionlabs::google::protobuf::duration::{Duration, DurationError};
+
+    /// Converts a duration in nanoseconds, as used in the JSON representation of durations, to the
+    /// protobuf representation.
+    #[cfg(feature = "proto")]
+    #[must_use]
+    pub fn duration_from_nanos(nanos: i64) -> protos::google::protobuf::Duration {
+        const NANOS_PER_SECOND: i64 = 1_000_000_000;
+
+        protos::google::protobuf::Duration {
+            seconds: nanos / NANOS_PER_SECOND,
+            nanos: (nanos % NANOS_PER_SECOND)
+                .try_into()
+                .expect("value is within +/- NANOS_PER_SECOND; qed;"),
+        }
+    }
+
+    /// Converts the protobuf representation of a duration to nanoseconds, as used in the JSON
+    /// representation of durations.
+    #[cfg(feature = "proto")]
+    pub fn duration_as_nanos(
+        duration: protos::google::protobuf::Duration,
+    ) -> Result<i64, InvalidDuration> {
+        Duration::try_from(duration)?
+            .as_nanos()
+            .inner()
+            .try_into()
+            .map_err(|_| InvalidDuration::Overflow)
+    }
+
+    #[cfg(feature = "proto")]
+    #[derive(Debug, Clone, PartialEq, thiserror::Error)]
+    pub enum InvalidDuration {
+        #[error(transparent)]
+        Duration(#[from] DurationError),
+        #[error("duration overflows i64 nanoseconds")]
+        Overflow,
+    }
 }
 
 pub mod serde {
@@ -1,15 +1,20 @@
+pub mod abci_params;
 pub mod block;
 pub mod block_id;
 pub mod block_id_flag;
+pub mod block_params;
 pub mod canonical_block_id;
 pub mod canonical_part_set_header;
 pub mod canonical_vote;
 pub mod commit;
 pub mod commit_sig;
+pub mod consensus_params;
 pub mod data;
 pub mod duplicate_vote_evidence;
 pub mod evidence;
 pub mod evidence_list;
+pub mod evidence_params;
+pub mod feature_params;
 pub mod header;
 pub mod light_block;
 pub mod light_client_attack_evidence;
@@ -17,7 +22,10 @@ pub mod part_set_header;
 pub mod signed_header;
 pub mod signed_msg_type;
 pub mod simple_validator;
+pub mod synchrony_params;
 pub mod tx_proof;
 pub mod validator;
+pub mod validator_params;
 pub mod validator_set;
+pub mod version_params;
 pub mod vote;
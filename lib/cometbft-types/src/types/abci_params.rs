@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+/// Deprecated in cometbft v1 in favour of [`FeatureParams`], but still returned by v0.38 nodes.
+///
+/// [`FeatureParams`]: crate::types::feature_params::FeatureParams
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AbciParams {
+    /// The height at which vote extensions are enabled. `0` means they are disabled.
+    #[serde(with = "::serde_utils::string")]
+    pub vote_extensions_enable_height: i64,
+}
+
+#[cfg(feature = "proto")]
+pub mod proto {
+    use crate::types::abci_params::AbciParams;
+
+    impl From<AbciParams> for protos::cometbft::types::v1::AbciParams {
+        fn from(value: AbciParams) -> Self {
+            Self {
+                vote_extensions_enable_height: value.vote_extensions_enable_height,
+            }
+        }
+    }
+
+    impl From<protos::cometbft::types::v1::AbciParams> for AbciParams {
+        fn from(value: protos::cometbft::types::v1::AbciParams) -> Self {
+            Self {
+                vote_extensions_enable_height: value.vote_extensions_enable_height,
+            }
+        }
+    }
+}
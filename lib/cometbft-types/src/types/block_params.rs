@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlockParams {
+    /// Max block size, in bytes. `-1` means the maximum possible block size.
+    #[serde(with = "::serde_utils::string")]
+    pub max_bytes: i64,
+    /// Max gas per block. `-1` means no limit.
+    #[serde(with = "::serde_utils::string")]
+    pub max_gas: i64,
+}
+
+#[cfg(feature = "proto")]
+pub mod proto {
+    use crate::types::block_params::BlockParams;
+
+    impl From<BlockParams> for protos::cometbft::types::v1::BlockParams {
+        fn from(value: BlockParams) -> Self {
+            Self {
+                max_bytes: value.max_bytes,
+                max_gas: value.max_gas,
+            }
+        }
+    }
+
+    impl From<protos::cometbft::types::v1::BlockParams> for BlockParams {
+        fn from(value: protos::cometbft::types::v1::BlockParams) -> Self {
+            Self {
+                max_bytes: value.max_bytes,
+                max_gas: value.max_gas,
+            }
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::{
+    abci_params::AbciParams, block_params::BlockParams, evidence_params::EvidenceParams,
+    feature_params::FeatureParams, synchrony_params::SynchronyParams,
+    validator_params::ValidatorParams, version_params::VersionParams,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConsensusParams {
+    pub block: BlockParams,
+    pub evidence: EvidenceParams,
+    pub validator: ValidatorParams,
+    pub version: VersionParams,
+    /// Only present on nodes < v1.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub abci: Option<AbciParams>,
+    /// Only present on nodes >= v1.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub synchrony: Option<SynchronyParams>,
+    /// Only present on nodes >= v1.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub feature: Option<FeatureParams>,
+}
+
+#[cfg(feature = "proto")]
+pub mod proto {
+    use unionlabs::{errors::MissingField, required};
+
+    use crate::types::{consensus_params::ConsensusParams, evidence_params, synchrony_params};
+
+    impl From<ConsensusParams> for protos::cometbft::types::v1::ConsensusParams {
+        fn from(value: ConsensusParams) -> Self {
+            #[allow(deprecated)]
+            Self {
+                block: Some(value.block.into()),
+                evidence: Some(value.evidence.into()),
+                validator: Some(value.validator.into()),
+                version: Some(value.version.into()),
+                abci: value.abci.map(Into::into),
+                synchrony: value.synchrony.map(Into::into),
+                feature: value.feature.map(Into::into),
+            }
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq, thiserror::Error)]
+    pub enum Error {
+        #[error(transparent)]
+        MissingField(#[from] MissingField),
+        #[error("invalid evidence")]
+        Evidence(#[from] evidence_params::proto::Error),
+        #[error("invalid synchrony")]
+        Synchrony(#[from] synchrony_params::proto::Error),
+    }
+
+    impl TryFrom<protos::cometbft::types::v1::ConsensusParams> for ConsensusParams {
+        type Error = Error;
+
+        fn try_from(
+            value: protos::cometbft::types::v1::ConsensusParams,
+        ) -> Result<Self, Self::Error> {
+            #[allow(deprecated)]
+            Ok(Self {
+                block: required!(value.block)?.into(),
+                evidence: required!(value.evidence)?.try_into()?,
+                validator: required!(value.validator)?.into(),
+                version: required!(value.version)?.into(),
+                abci: value.abci.map(Into::into),
+                synchrony: value.synchrony.map(TryInto::try_into).transpose()?,
+                feature: value.feature.map(Into::into),
+            })
+        }
+    }
+}
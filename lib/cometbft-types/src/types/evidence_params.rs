@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EvidenceParams {
+    /// Max age of evidence, in blocks.
+    #[serde(with = "::serde_utils::string")]
+    pub max_age_num_blocks: i64,
+    /// Max age of evidence, in nanoseconds.
+    #[serde(with = "::serde_utils::string")]
+    pub max_age_duration: i64,
+    /// Max total size of evidence per block, in bytes.
+    #[serde(with = "::serde_utils::string")]
+    pub max_bytes: i64,
+}
+
+#[cfg(feature = "proto")]
+pub mod proto {
+    use unionlabs::{errors::MissingField, required};
+
+    use crate::{
+        types::evidence_params::EvidenceParams,
+        utils::{duration_as_nanos, duration_from_nanos, InvalidDuration},
+    };
+
+    impl From<EvidenceParams> for protos::cometbft::types::v1::EvidenceParams {
+        fn from(value: EvidenceParams) -> Self {
+            Self {
+                max_age_num_blocks: value.max_age_num_blocks,
+                max_age_duration: Some(duration_from_nanos(value.max_age_duration)),
+                max_bytes: value.max_bytes,
+            }
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq, thiserror::Error)]
+    pub enum Error {
+        #[error(transparent)]
+        MissingField(#[from] MissingField),
+        #[error("invalid max_age_duration")]
+        MaxAgeDuration(#[source] InvalidDuration),
+    }
+
+    impl TryFrom<protos::cometbft::types::v1::EvidenceParams> for EvidenceParams {
+        type Error = Error;
+
+        fn try_from(
+            value: protos::cometbft::types::v1::EvidenceParams,
+        ) -> Result<Self, Self::Error> {
+            Ok(Self {
+                max_age_num_blocks: value.max_age_num_blocks,
+                max_age_duration: duration_as_nanos(required!(value.max_age_duration)?)
+                    .map_err(Error::MaxAgeDuration)?,
+                max_bytes: value.max_bytes,
+            })
+        }
+    }
+}
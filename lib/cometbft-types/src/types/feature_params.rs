@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeatureParams {
+    /// The height at which vote extensions are enabled. `0` means they are disabled.
+    #[serde(with = "::serde_utils::string")]
+    pub vote_extensions_enable_height: i64,
+    /// The height at which proposer-based timestamps are enabled. `0` means they are disabled.
+    #[serde(with = "::serde_utils::string")]
+    pub pbts_enable_height: i64,
+}
+
+#[cfg(feature = "proto")]
+pub mod proto {
+    use protos::google::protobuf::Int64Value;
+
+    use crate::types::feature_params::FeatureParams;
+
+    impl From<FeatureParams> for protos::cometbft::types::v1::FeatureParams {
+        fn from(value: FeatureParams) -> Self {
+            Self {
+                vote_extensions_enable_height: Some(Int64Value {
+                    value: value.vote_extensions_enable_height,
+                }),
+                pbts_enable_height: Some(Int64Value {
+                    value: value.pbts_enable_height,
+                }),
+            }
+        }
+    }
+
+    impl From<protos::cometbft::types::v1::FeatureParams> for FeatureParams {
+        fn from(value: protos::cometbft::types::v1::FeatureParams) -> Self {
+            Self {
+                vote_extensions_enable_height: value
+                    .vote_extensions_enable_height
+                    .unwrap_or_default()
+                    .value,
+                pbts_enable_height: value.pbts_enable_height.unwrap_or_default().value,
+            }
+        }
+    }
+}
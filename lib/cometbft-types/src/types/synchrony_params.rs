@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SynchronyParams {
+    /// Bound for how skewed a proposer's clock may be from any validator on the network, in
+    /// nanoseconds.
+    #[serde(with = "::serde_utils::string")]
+    pub precision: i64,
+    /// Bound for how long a proposal message may take to reach all validators on a network, in
+    /// nanoseconds.
+    #[serde(with = "::serde_utils::string")]
+    pub message_delay: i64,
+}
+
+#[cfg(feature = "proto")]
+pub mod proto {
+    use unionlabs::{errors::MissingField, required};
+
+    use crate::{
+        types::synchrony_params::SynchronyParams,
+        utils::{duration_as_nanos, duration_from_nanos, InvalidDuration},
+    };
+
+    impl From<SynchronyParams> for protos::cometbft::types::v1::SynchronyParams {
+        fn from(value: SynchronyParams) -> Self {
+            Self {
+                precision: Some(duration_from_nanos(value.precision)),
+                message_delay: Some(duration_from_nanos(value.message_delay)),
+            }
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq, thiserror::Error)]
+    pub enum Error {
+        #[error(transparent)]
+        MissingField(#[from] MissingField),
+        #[error("invalid precision")]
+        Precision(#[source] InvalidDuration),
+        #[error("invalid message_delay")]
+        MessageDelay(#[source] InvalidDuration),
+    }
+
+    impl TryFrom<protos::cometbft::types::v1::SynchronyParams> for SynchronyParams {
+        type Error = Error;
+
+        fn try_from(
+            value: protos::cometbft::types::v1::SynchronyParams,
+        ) -> Result<Self, Self::Error> {
+            Ok(Self {
+                precision: duration_as_nanos(required!(value.precision)?)
+                    .map_err(Error::Precision)?,
+                message_delay: duration_as_nanos(required!(value.message_delay)?)
+                    .map_err(Error::MessageDelay)?,
+            })
+        }
+    }
+}
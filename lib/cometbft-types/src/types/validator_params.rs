@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ValidatorParams {
+    /// The public key types validators can use, i.e. `ed25519`.
+    pub pub_key_types: Vec<String>,
+}
+
+#[cfg(feature = "proto")]
+pub mod proto {
+    use crate::types::validator_params::ValidatorParams;
+
+    impl From<ValidatorParams> for protos::cometbft::types::v1::ValidatorParams {
+        fn from(value: ValidatorParams) -> Self {
+            Self {
+                pub_key_types: value.pub_key_types,
+            }
+        }
+    }
+
+    impl From<protos::cometbft::types::v1::ValidatorParams> for ValidatorParams {
+        fn from(value: protos::cometbft::types::v1::ValidatorParams) -> Self {
+            Self {
+                pub_key_types: value.pub_key_types,
+            }
+        }
+    }
+}
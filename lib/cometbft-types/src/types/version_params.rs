@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VersionParams {
+    /// The ABCI application version.
+    #[serde(with = "::serde_utils::string")]
+    pub app: u64,
+}
+
+#[cfg(feature = "proto")]
+pub mod proto {
+    use crate::types::version_params::VersionParams;
+
+    impl From<VersionParams> for protos::cometbft::types::v1::VersionParams {
+        fn from(value: VersionParams) -> Self {
+            Self { app: value.app }
+        }
+    }
+
+    impl From<protos::cometbft::types::v1::VersionParams> for VersionParams {
+        fn from(value: protos::cometbft::types::v1::VersionParams) -> Self {
+            Self { app: value.app }
+        }
+    }
+}
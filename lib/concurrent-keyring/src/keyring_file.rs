@@ -0,0 +1,227 @@
+//! Decryption of keys stored in the `file` backend of the cosmos-sdk keyring (i.e. keys created
+//! with `<appd> keys add <name> --keyring-backend file`).
+//!
+//! Each key is stored in `<keyring-dir>/<name>.info` as a [JWE] compact serialization encrypted with
+//! `PBES2-HS256+A128KW` and `A256GCM`, the payload of which is a [99designs/keyring] item whose data
+//! is a protobuf encoded [`Record`]. Keys created with cosmos-sdk versions prior to v0.46 are
+//! amino encoded and are not supported.
+//!
+//! [JWE]: https://datatracker.ietf.org/doc/html/rfc7516
+//! [99designs/keyring]: https://github.com/99designs/keyring
+
+use aes::cipher::{generic_array::GenericArray, BlockDecrypt, KeyInit};
+use aes_gcm::{
+    aead::{Aead, Payload},
+    Aes256Gcm, Nonce,
+};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use hmac::Hmac;
+use protos::cosmos::crypto::{
+    keyring::v1::{record, Record},
+    secp256k1::PrivKey,
+};
+use serde::Deserialize;
+use sha2::Sha256;
+use unionlabs::{primitives::H256, prost::Message};
+
+const ALG: &str = "PBES2-HS256+A128KW";
+const ENC: &str = "A256GCM";
+
+const SECP256K1_PRIV_KEY_TYPE_URL: &str = "/cosmos.crypto.secp256k1.PrivKey";
+
+/// The initial value of RFC 3394 key wrapping.
+const AES_KW_IV: [u8; 8] = [0xA6; 8];
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum KeyringFileError {
+    #[error("invalid jwe, expected 5 parts but found {0}")]
+    InvalidPartsCount(usize),
+    #[error("invalid base64 in jwe")]
+    Base64,
+    #[error("invalid jwe header")]
+    InvalidHeader,
+    #[error("unsupported jwe algorithm {alg} with encryption {enc}, expected {ALG} with {ENC}")]
+    UnsupportedAlgorithm { alg: String, enc: String },
+    #[error("unable to unwrap the content encryption key, the password is likely incorrect")]
+    KeyUnwrap,
+    #[error("unable to decrypt the keyring item")]
+    Decryption,
+    #[error("invalid keyring item")]
+    InvalidItem,
+    #[error(
+        "unable to decode the key record, note that legacy amino encoded keys are not supported"
+    )]
+    InvalidRecord,
+    #[error("key {0} is not a local key")]
+    NotLocal(String),
+    #[error("unsupported key type {0}, only secp256k1 keys are supported")]
+    UnsupportedKeyType(String),
+    #[error("invalid private key length {0}, must be 32 bytes")]
+    InvalidPrivateKeyLength(usize),
+}
+
+#[derive(Debug, Deserialize)]
+struct JweHeader {
+    alg: String,
+    enc: String,
+    p2c: u32,
+    p2s: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct Item {
+    #[serde(with = "::serde_utils::base64")]
+    data: Vec<u8>,
+}
+
+/// Decrypts the contents of a `<name>.info` file of a cosmos-sdk `file` keyring with `password`,
+/// returning the secp256k1 private key stored in it.
+pub fn decrypt(token: &str, password: &str) -> Result<H256, KeyringFileError> {
+    let parts = token.trim().split('.').collect::<Vec<_>>();
+
+    let [header_b64, encrypted_key, iv, ciphertext, tag] = parts[..] else {
+        return Err(KeyringFileError::InvalidPartsCount(parts.len()));
+    };
+
+    let decode = |s: &str| {
+        URL_SAFE_NO_PAD
+            .decode(s)
+            .map_err(|_| KeyringFileError::Base64)
+    };
+
+    let header = serde_json::from_slice::<JweHeader>(&decode(header_b64)?)
+        .map_err(|_| KeyringFileError::InvalidHeader)?;
+
+    if header.alg != ALG || header.enc != ENC {
+        return Err(KeyringFileError::UnsupportedAlgorithm {
+            alg: header.alg,
+            enc: header.enc,
+        });
+    }
+
+    // https://datatracker.ietf.org/doc/html/rfc7518#section-4.8.1.1
+    let salt = [ALG.as_bytes(), &[0], &decode(&header.p2s)?].concat();
+
+    let mut kek = [0; 16];
+    pbkdf2::pbkdf2::<Hmac<Sha256>>(password.as_bytes(), &salt, header.p2c, &mut kek);
+
+    let cek = aes_key_unwrap(&kek, &decode(encrypted_key)?)?;
+
+    let iv = decode(iv)?;
+    if iv.len() != 12 {
+        return Err(KeyringFileError::Decryption);
+    }
+
+    let payload = Aes256Gcm::new_from_slice(&cek)
+        .map_err(|_| KeyringFileError::Decryption)?
+        .decrypt(
+            Nonce::from_slice(&iv),
+            Payload {
+                msg: &[decode(ciphertext)?, decode(tag)?].concat(),
+                aad: header_b64.as_bytes(),
+            },
+        )
+        .map_err(|_| KeyringFileError::Decryption)?;
+
+    let item =
+        serde_json::from_slice::<Item>(&payload).map_err(|_| KeyringFileError::InvalidItem)?;
+
+    let record = Record::decode(&*item.data).map_err(|_| KeyringFileError::InvalidRecord)?;
+
+    let Some(record::Item::Local(record::Local {
+        priv_key: Some(priv_key),
+    })) = record.item
+    else {
+        return Err(KeyringFileError::NotLocal(record.name));
+    };
+
+    if priv_key.type_url != SECP256K1_PRIV_KEY_TYPE_URL {
+        return Err(KeyringFileError::UnsupportedKeyType(priv_key.type_url));
+    }
+
+    let priv_key =
+        PrivKey::decode(&*priv_key.value).map_err(|_| KeyringFileError::InvalidRecord)?;
+
+    H256::try_from(priv_key.key.as_slice())
+        .map_err(|_| KeyringFileError::InvalidPrivateKeyLength(priv_key.key.len()))
+}
+
+/// AES key unwrap as specified in [RFC 3394](https://datatracker.ietf.org/doc/html/rfc3394#section-2.2.2).
+fn aes_key_unwrap(kek: &[u8; 16], wrapped: &[u8]) -> Result<Vec<u8>, KeyringFileError> {
+    if wrapped.len() % 8 != 0 || wrapped.len() < 24 {
+        return Err(KeyringFileError::KeyUnwrap);
+    }
+
+    let cipher = aes::Aes128::new(GenericArray::from_slice(kek));
+
+    let n = wrapped.len() / 8 - 1;
+
+    let mut a: [u8; 8] = wrapped[..8].try_into().expect("length is checked; qed;");
+    let mut r = wrapped[8..]
+        .chunks_exact(8)
+        .map(|chunk| <[u8; 8]>::try_from(chunk).expect("chunks are 8 bytes; qed;"))
+        .collect::<Vec<_>>();
+
+    for j in (0..6).rev() {
+        for i in (1..=n).rev() {
+            let t = (n * j + i) as u64;
+
+            let mut block = GenericArray::clone_from_slice(
+                &[(u64::from_be_bytes(a) ^ t).to_be_bytes(), r[i - 1]].concat(),
+            );
+            cipher.decrypt_block(&mut block);
+
+            a.copy_from_slice(&block[..8]);
+            r[i - 1].copy_from_slice(&block[8..]);
+        }
+    }
+
+    if a != AES_KW_IV {
+        return Err(KeyringFileError::KeyUnwrap);
+    }
+
+    Ok(r.concat())
+}
+
+#[cfg(test)]
+mod tests {
+    use hex_literal::hex;
+
+    use super::*;
+
+    // generated with the same parameters as the cosmos-sdk file keyring, with the key "alice"
+    const ALICE_INFO: &str = "eyJhbGciOiJQQkVTMi1IUzI1NitBMTI4S1ciLCJjcmVhdGVkIjoiMjAyNS0wNS0xMiAwMjozMjoyNC4yNDAwMTczNDcgKzAwMDAgVVRDIG09KzAuMDEzIiwiZW5jIjoiQTI1NkdDTSIsInAyYyI6ODE5MiwicDJzIjoiQUFFQ0F3UUZCZ2NJQ1FvTCJ9.XhNkk85yU1TcLGYKn0mNzYy97ywfoIkQjCm8bDxyIY4k_uy6xEVkpg.yMnKy8zNzs_Q0dLT.F2DR4CZ0X1DE1T_FYNBLBOYnBo6L9w_s5jrDMC8l_KfhZ0fw7K_xo2goHqJNKmtIv5QGW3FB5GaWokc8MLOb7VRTiTKPaOLniH1l3pCbI1Gb1OGjcfsIuHPwf_pvKE0Si_TVQNRw7dPCds-noHN1cZK4RJYbKJ3ii9MKqOrnZBSTaG27rN3vquAjEtaeiwNfFO24f9-X6oeWXrFz16W6Se-a3O4djHxyQ0Mul62gR0khXOVIj939_ziaWV7uwBSzDdnmZ7kEK7m9ybcNKQEhVA3vPKkH_yVSF559lQ6AAQaZ1J2CVANERULzYoO1GBRauw.FXakcpTkyH13APvrV-nPEw";
+
+    #[test]
+    fn decrypt_info() {
+        assert_eq!(
+            decrypt(ALICE_INFO, "correct horse battery staple"),
+            Ok(H256::new(hex!(
+                "8dea7f3a5e2ae1f1a9b86b2d5c1f6c1d3f4e2a6b7c8d9e0f1a2b3c4d5e6f7081"
+            )))
+        );
+
+        assert_eq!(
+            decrypt(ALICE_INFO, "incorrect horse battery staple"),
+            Err(KeyringFileError::KeyUnwrap)
+        );
+
+        assert_eq!(
+            decrypt("a.b.c", "correct horse battery staple"),
+            Err(KeyringFileError::InvalidPartsCount(3))
+        );
+    }
+
+    #[test]
+    fn key_unwrap() {
+        // https://datatracker.ietf.org/doc/html/rfc3394#section-4.1
+        assert_eq!(
+            aes_key_unwrap(
+                &hex!("000102030405060708090A0B0C0D0E0F"),
+                &hex!("1FA68B0A8112B447AEF34BD8FB5A7B829D3E862371D2CFE5")
+            ),
+            Ok(hex!("00112233445566778899AABBCCDDEEFF").to_vec())
+        );
+    }
+}
@@ -0,0 +1,285 @@
+//! Decryption of [EIP-2335] BLS/secp256k1 keystores, as produced by `ethdo`, `eth2.0-deposit-cli`,
+//! `web3signer` and most other Ethereum key management tools.
+//!
+//! [EIP-2335]: https://eips.ethereum.org/EIPS/eip-2335
+
+use aes::cipher::{KeyIvInit, StreamCipher};
+use hmac::Hmac;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use unicode_normalization::UnicodeNormalization;
+use unionlabs::primitives::{encoding::HexUnprefixed, Bytes, H256};
+
+type Aes128Ctr = ctr::Ctr128BE<aes::Aes128>;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Keystore {
+    pub crypto: Crypto,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pubkey: Option<Bytes<HexUnprefixed>>,
+    pub path: String,
+    pub uuid: String,
+    pub version: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Crypto {
+    pub kdf: Module<Kdf>,
+    pub checksum: Module<Checksum>,
+    pub cipher: Module<Cipher>,
+}
+
+/// A step of the decryption process. `message` is empty for the kdf module.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Module<T> {
+    #[serde(flatten)]
+    pub function: T,
+    pub message: Bytes<HexUnprefixed>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "function", content = "params", rename_all = "lowercase")]
+pub enum Kdf {
+    Scrypt {
+        dklen: usize,
+        n: u32,
+        r: u32,
+        p: u32,
+        salt: Bytes<HexUnprefixed>,
+    },
+    Pbkdf2 {
+        dklen: usize,
+        c: u32,
+        prf: Prf,
+        salt: Bytes<HexUnprefixed>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Prf {
+    HmacSha256,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "function", content = "params", rename_all = "lowercase")]
+pub enum Checksum {
+    Sha256 {},
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "function", content = "params")]
+pub enum Cipher {
+    #[serde(rename = "aes-128-ctr")]
+    Aes128Ctr { iv: Bytes<HexUnprefixed> },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum KeystoreError {
+    #[error("unsupported keystore version {0}, only version 4 is supported")]
+    UnsupportedVersion(u32),
+    #[error("invalid kdf parameters")]
+    InvalidKdfParams,
+    #[error("invalid derived key length {0}, must be 32 bytes")]
+    InvalidDerivedKeyLength(usize),
+    #[error("invalid iv length {0}, must be 16 bytes")]
+    InvalidIvLength(usize),
+    #[error("invalid secret length {0}, must be 32 bytes")]
+    InvalidSecretLength(usize),
+    #[error("checksum mismatch, the password is likely incorrect")]
+    ChecksumMismatch,
+}
+
+impl Keystore {
+    /// Decrypts the secret stored in this keystore with `password`.
+    pub fn decrypt(&self, password: &str) -> Result<H256, KeystoreError> {
+        if self.version != 4 {
+            return Err(KeystoreError::UnsupportedVersion(self.version));
+        }
+
+        let decryption_key = self
+            .crypto
+            .kdf
+            .function
+            .derive_key(&normalize_password(password))?;
+
+        let checksum = Sha256::new()
+            .chain_update(&decryption_key[16..32])
+            .chain_update(&self.crypto.cipher.message)
+            .finalize();
+
+        if checksum.as_slice() != &*self.crypto.checksum.message {
+            return Err(KeystoreError::ChecksumMismatch);
+        }
+
+        let Cipher::Aes128Ctr { iv } = &self.crypto.cipher.function;
+
+        let mut secret = self.crypto.cipher.message.to_vec();
+
+        Aes128Ctr::new_from_slices(&decryption_key[..16], iv)
+            .map_err(|_| KeystoreError::InvalidIvLength(iv.len()))?
+            .apply_keystream(&mut secret);
+
+        H256::try_from(secret.as_slice())
+            .map_err(|_| KeystoreError::InvalidSecretLength(secret.len()))
+    }
+}
+
+impl Kdf {
+    fn derive_key(&self, password: &[u8]) -> Result<Vec<u8>, KeystoreError> {
+        let (dklen, derived_key) = match self {
+            Kdf::Scrypt {
+                dklen,
+                n,
+                r,
+                p,
+                salt,
+            } => {
+                if !n.is_power_of_two() {
+                    return Err(KeystoreError::InvalidKdfParams);
+                }
+
+                let params = scrypt::Params::new(n.ilog2() as u8, *r, *p)
+                    .map_err(|_| KeystoreError::InvalidKdfParams)?;
+
+                let mut derived_key = vec![0; *dklen];
+                scrypt::scrypt(password, salt, &params, &mut derived_key)
+                    .map_err(|_| KeystoreError::InvalidKdfParams)?;
+
+                (*dklen, derived_key)
+            }
+            Kdf::Pbkdf2 {
+                dklen,
+                c,
+                prf: Prf::HmacSha256,
+                salt,
+            } => {
+                let mut derived_key = vec![0; *dklen];
+                pbkdf2::pbkdf2::<Hmac<Sha256>>(password, salt, *c, &mut derived_key);
+
+                (*dklen, derived_key)
+            }
+        };
+
+        if dklen != 32 {
+            return Err(KeystoreError::InvalidDerivedKeyLength(dklen));
+        }
+
+        Ok(derived_key)
+    }
+}
+
+/// Normalizes the password to NFKD and strips the C0, C1 and `Delete` control codes, as specified
+/// in [EIP-2335](https://eips.ethereum.org/EIPS/eip-2335#password-requirements).
+fn normalize_password(password: &str) -> Vec<u8> {
+    password
+        .nfkd()
+        .filter(|c| !matches!(*c as u32, 0x00..=0x1F | 0x7F..=0x9F))
+        .collect::<String>()
+        .into_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use hex_literal::hex;
+
+    use super::*;
+
+    const PASSWORD: &str = "𝔱𝔢𝔰𝔱𝔭𝔞𝔰𝔰𝔴𝔬𝔯𝔡🔑";
+
+    const SECRET: H256 = H256::new(hex!(
+        "000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f"
+    ));
+
+    // https://eips.ethereum.org/EIPS/eip-2335#test-cases
+    const SCRYPT: &str = r#"{
+        "crypto": {
+            "kdf": {
+                "function": "scrypt",
+                "params": {
+                    "dklen": 32,
+                    "n": 262144,
+                    "p": 1,
+                    "r": 8,
+                    "salt": "d4e56740f876aef8c010b86a40d5f56745a118d0906a34e69aec8c0db1cb8fa3"
+                },
+                "message": ""
+            },
+            "checksum": {
+                "function": "sha256",
+                "params": {},
+                "message": "d2217fe5f3e9a1e34581ef8a78f7c9928e436d36dacc5e846690a5581e8ea484"
+            },
+            "cipher": {
+                "function": "aes-128-ctr",
+                "params": {
+                    "iv": "264daa3f303d7259501c93d997d84fe6"
+                },
+                "message": "06ae90d55fe0a6e9c5c3bc5b170827b2e5cce3929ed3f116c2811e6366dfe20f"
+            }
+        },
+        "description": "This is a test keystore that uses scrypt to secure the secret.",
+        "pubkey": "9612d7a727c9d0a22e185a1c768478dfe919cada9266988cb32359c11f2b7b27f4ae4040902382ae2910c15e2b420d07",
+        "path": "m/12381/60/3141592653/589793238",
+        "uuid": "1d85ae20-35c5-4611-98e8-aa14a633906f",
+        "version": 4
+    }"#;
+
+    const PBKDF2: &str = r#"{
+        "crypto": {
+            "kdf": {
+                "function": "pbkdf2",
+                "params": {
+                    "dklen": 32,
+                    "c": 262144,
+                    "prf": "hmac-sha256",
+                    "salt": "d4e56740f876aef8c010b86a40d5f56745a118d0906a34e69aec8c0db1cb8fa3"
+                },
+                "message": ""
+            },
+            "checksum": {
+                "function": "sha256",
+                "params": {},
+                "message": "8a9f5d9912ed7e75ea794bc5a89bca5f193721d30868ade6f73043c6ea6febf1"
+            },
+            "cipher": {
+                "function": "aes-128-ctr",
+                "params": {
+                    "iv": "264daa3f303d7259501c93d997d84fe6"
+                },
+                "message": "cee03fde2af33149775b7223e7845e4fb2c8ae1792e5f99fe9ecf474cc8c16ad"
+            }
+        },
+        "description": "This is a test keystore that uses PBKDF2 to secure the secret.",
+        "pubkey": "9612d7a727c9d0a22e185a1c768478dfe919cada9266988cb32359c11f2b7b27f4ae4040902382ae2910c15e2b420d07",
+        "path": "m/12381/60/0/0",
+        "uuid": "64625def-3331-4eea-ab6f-782f3ed16a83",
+        "version": 4
+    }"#;
+
+    #[test]
+    fn decrypt_scrypt() {
+        let keystore = serde_json::from_str::<Keystore>(SCRYPT).unwrap();
+
+        assert_eq!(keystore.decrypt(PASSWORD), Ok(SECRET));
+        assert_eq!(
+            keystore.decrypt("testpassword"),
+            Err(KeystoreError::ChecksumMismatch)
+        );
+    }
+
+    #[test]
+    fn decrypt_pbkdf2() {
+        let keystore = serde_json::from_str::<Keystore>(PBKDF2).unwrap();
+
+        assert_eq!(keystore.decrypt(PASSWORD), Ok(SECRET));
+    }
+
+    #[test]
+    fn password_normalization() {
+        assert_eq!(normalize_password(PASSWORD), "testpassword🔑".as_bytes());
+        assert_eq!(normalize_password("a\u{7f}b\u{0}\u{85}c"), b"abc");
+    }
+}
@@ -1,9 +1,16 @@
 #![feature(trait_alias)]
 
+pub mod keyring_file;
+pub mod keystore;
 pub mod private_key;
 
 use std::{
-    collections::HashMap, fmt::Display, hash::Hash, panic::UnwindSafe, path::PathBuf, sync::Arc,
+    collections::HashMap,
+    fmt::Display,
+    hash::Hash,
+    panic::UnwindSafe,
+    path::{Path, PathBuf},
+    sync::Arc,
 };
 
 use crossbeam_queue::ArrayQueue;
@@ -11,7 +18,12 @@ use futures::{Future, FutureExt};
 use rand::prelude::SliceRandom;
 use serde::{Deserialize, Serialize};
 use tracing::{debug, info_span, warn, Instrument};
-use unionlabs::primitives::H256;
+use unionlabs::{primitives::H256, ErrorReporter};
+
+use crate::{
+    keyring_file::KeyringFileError,
+    keystore::{Keystore, KeystoreError},
+};
 
 pub trait ChainKeyring {
     type Address: Hash + Eq + Clone + Display + Send + Sync;
@@ -133,15 +145,48 @@ pub struct KeyringConfig {
 }
 
 impl KeyringConfigEntry {
-    pub fn value(&self) -> Vec<u8> {
-        match &self {
-            KeyringConfigEntry::File { path } => std::fs::read_to_string(path)
-                .expect("key does not exist")
+    /// Loads the key described by this entry, decrypting it if required.
+    pub fn key(&self) -> Result<Key, KeyError> {
+        match self {
+            KeyringConfigEntry::File { path } => read_to_string(path)?
                 .trim()
                 .parse::<H256>()
-                .expect("key is in an invalid format")
-                .into(),
-            KeyringConfigEntry::Raw { name: _, key } => key.clone(),
+                .map(|key| Key::Local(key.into()))
+                .map_err(|_| KeyError::InvalidKey { path: path.clone() }),
+            KeyringConfigEntry::Raw { name: _, key } => Ok(Key::Local(key.clone())),
+            KeyringConfigEntry::Keystore { path, password } => {
+                let keystore = serde_json::from_str::<Keystore>(&read_to_string(path)?)
+                    .map_err(|_| KeyError::InvalidKey { path: path.clone() })?;
+
+                Ok(Key::Local(keystore.decrypt(&password.value()?)?.into()))
+            }
+            KeyringConfigEntry::KeyringFile { path, password } => Ok(Key::Local(
+                keyring_file::decrypt(&read_to_string(path)?, &password.value()?)?.into(),
+            )),
+            KeyringConfigEntry::Remote {
+                name: _,
+                url,
+                public_key,
+            } => Ok(Key::Remote {
+                url: url.clone(),
+                public_key: public_key.clone(),
+            }),
+        }
+    }
+
+    /// Loads the private key of this entry.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key cannot be loaded, or if this is a [`KeyringConfigEntry::Remote`] entry.
+    /// Use [`Self::key`] to support remote signers.
+    pub fn value(&self) -> Vec<u8> {
+        match self.key() {
+            Ok(Key::Local(key)) => key,
+            Ok(Key::Remote { url, .. }) => {
+                panic!("key is held by the remote signer {url} and has no local value")
+            }
+            Err(err) => panic!("unable to load key: {}", ErrorReporter(err)),
         }
     }
 }
@@ -157,4 +202,91 @@ pub enum KeyringConfigEntry {
         #[serde(with = "::serde_utils::hex_string")]
         key: Vec<u8>,
     },
+    /// An [EIP-2335](https://eips.ethereum.org/EIPS/eip-2335) encrypted keystore.
+    Keystore {
+        path: PathBuf,
+        password: Password,
+    },
+    /// The `<name>.info` file of a key in a cosmos-sdk `file` keyring.
+    KeyringFile {
+        path: PathBuf,
+        password: Password,
+    },
+    /// A key held by a remote signer, identified by its public key. The private key is never
+    /// available locally.
+    ///
+    /// The ethereum transaction plugin expects a [Web3Signer](https://docs.web3signer.consensys.io)
+    /// at `url`, while the cosmos-sdk transaction plugin and drip expect a service implementing the
+    /// protocol described on `cosmos_client::wallet::RemoteSigner`. Remote signers are not
+    /// supported for sui.
+    Remote {
+        name: String,
+        url: String,
+        #[serde(with = "::serde_utils::hex_string")]
+        public_key: Vec<u8>,
+    },
+}
+
+/// The password of an encrypted key.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Password {
+    /// Read the password from this environment variable.
+    Env(String),
+    /// Read the password from this file. Trailing newlines are ignored.
+    File(PathBuf),
+    /// The password stored in plaintext.
+    Raw(String),
+}
+
+impl Password {
+    pub fn value(&self) -> Result<String, KeyError> {
+        match self {
+            Password::Env(var) => std::env::var(var).map_err(|err| KeyError::Env {
+                var: var.clone(),
+                err,
+            }),
+            Password::File(path) => read_to_string(path)
+                .map(|password| password.trim_end_matches(['\r', '\n']).to_owned()),
+            Password::Raw(password) => Ok(password.clone()),
+        }
+    }
+}
+
+/// A key loaded from a [`KeyringConfigEntry`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Key {
+    /// The raw private key.
+    Local(Vec<u8>),
+    /// A key held by the remote signer at `url`.
+    Remote { url: String, public_key: Vec<u8> },
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum KeyError {
+    #[error("unable to read {}", path.display())]
+    Io {
+        path: PathBuf,
+        #[source]
+        err: std::io::Error,
+    },
+    #[error("unable to read environment variable {var}")]
+    Env {
+        var: String,
+        #[source]
+        err: std::env::VarError,
+    },
+    #[error("key {} is in an invalid format", path.display())]
+    InvalidKey { path: PathBuf },
+    #[error("unable to decrypt keystore")]
+    Keystore(#[from] KeystoreError),
+    #[error("unable to decrypt keyring file")]
+    KeyringFile(#[from] KeyringFileError),
+}
+
+fn read_to_string(path: &Path) -> Result<String, KeyError> {
+    std::fs::read_to_string(path).map_err(|err| KeyError::Io {
+        path: path.to_owned(),
+        err,
+    })
 }
@@ -1,4 +1,4 @@
-#![warn(clippy::unwrap_used)]
+#![cfg_attr(not(test), warn(clippy::unwrap_used))]
 #![allow(async_fn_in_trait)]
 
 use std::num::NonZeroU32;
@@ -15,21 +15,25 @@ use unionlabs::{
     cosmos::{
         auth::base_account::BaseAccount,
         base::abci::gas_info::GasInfo,
-        crypto::{secp256k1, AnyPubKey},
         tx::{
-            auth_info::AuthInfo, mode_info::ModeInfo, sign_doc::SignDoc, signer_info::SignerInfo,
-            signing::sign_info::SignMode, tx::Tx, tx_body::TxBody, tx_raw::TxRaw,
+            auth_info::AuthInfo, fee::Fee, mode_info::ModeInfo, sign_doc::SignDoc,
+            signer_info::SignerInfo, signing::sign_info::SignMode, tx::Tx, tx_body::TxBody,
+            tx_raw::TxRaw,
         },
     },
     cosmwasm::wasm::msg_update_instantiate_config::response::MsgUpdateInstantiateConfigResponse,
     encoding::{Decode, EncodeAs, Proto},
     google::protobuf::any::{Any, RawAny, TryFromAnyError},
-    primitives::{encoding::HexUnprefixed, Bech32, H256},
+    primitives::{encoding::HexUnprefixed, Bech32, Bytes, H256},
     prost::{self, Message},
     ErrorReporter, Msg, TypeUrl,
 };
 
-use crate::{gas::GasFillerT, rpc::RpcT, wallet::WalletT};
+use crate::{
+    gas::GasFillerT,
+    rpc::RpcT,
+    wallet::{WalletError, WalletT},
+};
 
 pub mod gas;
 pub mod rpc;
@@ -39,11 +43,38 @@ pub struct TxClient<W, Q, G> {
     wallet: W,
     rpc: Q,
     gas: G,
+    fee_granter: Option<Bech32<Bytes>>,
+    authz_granter: Option<Bech32<Bytes>>,
 }
 
 impl<W, Q, G> TxClient<W, Q, G> {
     pub fn new(wallet: W, rpc: Q, gas: G) -> Self {
-        Self { wallet, rpc, gas }
+        Self {
+            wallet,
+            rpc,
+            gas,
+            fee_granter: None,
+            authz_granter: None,
+        }
+    }
+
+    /// Pay the fees of all transactions with the fee grant given to the wallet by `granter`.
+    #[must_use]
+    pub fn with_fee_granter(mut self, granter: Option<Bech32<Bytes>>) -> Self {
+        self.fee_granter = granter;
+        self
+    }
+
+    /// Execute all messages on behalf of `granter` by wrapping them in an authz `MsgExec`, with the
+    /// wallet as the grantee. The messages must be signed by `granter`, not by the wallet.
+    #[must_use]
+    pub fn with_authz_granter(mut self, granter: Option<Bech32<Bytes>>) -> Self {
+        self.authz_granter = granter;
+        self
+    }
+
+    pub fn authz_granter(&self) -> Option<&Bech32<Bytes>> {
+        self.authz_granter.as_ref()
     }
 
     pub fn wallet(&self) -> &W {
@@ -82,20 +113,36 @@ impl<W: WalletT, Q: RpcT, G: GasFillerT> TxClient<W, Q, G> {
         )
         .map_err(TxError::TxMsgDataDecode)?;
 
-        Ok((
-            result.hash,
-            <Any<M::Response>>::try_from(
-                response
-                    .msg_responses
-                    .pop()
-                    .map(|any| protos::google::protobuf::Any {
-                        type_url: any.type_url,
-                        value: any.value,
-                    })
-                    .expect("must contain at least one msg response"),
-            )?
-            .0,
-        ))
+        let msg_response = response
+            .msg_responses
+            .pop()
+            .map(|any| protos::google::protobuf::Any {
+                type_url: any.type_url,
+                value: any.value,
+            })
+            .expect("must contain at least one msg response");
+
+        if self.authz_granter.is_some() {
+            // the responses of the executed messages are returned as raw bytes in the MsgExecResponse
+            let mut exec_response =
+                <protos::cosmos::authz::v1beta1::MsgExecResponse as Message>::decode(
+                    &*msg_response.value,
+                )
+                .map_err(TxError::TxMsgDataDecode)?;
+
+            Ok((
+                result.hash,
+                M::Response::decode(
+                    &exec_response
+                        .results
+                        .pop()
+                        .expect("must contain at least one msg exec result"),
+                )
+                .map_err(TryFromAnyError::Decode)?,
+            ))
+        } else {
+            Ok((result.hash, <Any<M::Response>>::try_from(msg_response)?.0))
+        }
     }
 
     /// - simulate tx
@@ -135,7 +182,7 @@ impl<W: WalletT, Q: RpcT, G: GasFillerT> TxClient<W, Q, G> {
             "tx simulation successful"
         );
 
-        auth_info.fee = self.gas.mk_fee(simulation_gas_info.gas_used).await;
+        auth_info.fee = self.mk_fee(simulation_gas_info.gas_used).await;
 
         info!(
             fee = %auth_info.fee.amount[0].amount,
@@ -143,15 +190,18 @@ impl<W: WalletT, Q: RpcT, G: GasFillerT> TxClient<W, Q, G> {
         );
 
         // re-sign the new auth info with the simulated gas
-        let signature = self.wallet.sign(
-            &SignDoc {
-                body_bytes: tx_body.clone().encode_as::<Proto>(),
-                auth_info_bytes: auth_info.clone().encode_as::<Proto>(),
-                chain_id: self.rpc.chain_id().to_string(),
-                account_number: account.account_number,
-            }
-            .encode_as::<Proto>(),
-        );
+        let signature = self
+            .wallet
+            .sign(
+                &SignDoc {
+                    body_bytes: tx_body.clone().encode_as::<Proto>(),
+                    auth_info_bytes: auth_info.clone().encode_as::<Proto>(),
+                    chain_id: self.rpc.chain_id().to_string(),
+                    account_number: account.account_number,
+                }
+                .encode_as::<Proto>(),
+            )
+            .await?;
 
         let tx_raw_bytes = TxRaw {
             body_bytes: tx_body.clone().encode_as::<Proto>(),
@@ -246,15 +296,18 @@ impl<W: WalletT, Q: RpcT, G: GasFillerT> TxClient<W, Q, G> {
 
         let (tx_body, auth_info) = self.tx_info(messages, memo, &account).await;
 
-        let simulation_signature = self.wallet.sign(
-            &SignDoc {
-                body_bytes: tx_body.clone().encode_as::<Proto>(),
-                auth_info_bytes: auth_info.clone().encode_as::<Proto>(),
-                chain_id: self.rpc.chain_id().to_string(),
-                account_number: account.account_number,
-            }
-            .encode_as::<Proto>(),
-        );
+        let simulation_signature = self
+            .wallet
+            .sign(
+                &SignDoc {
+                    body_bytes: tx_body.clone().encode_as::<Proto>(),
+                    auth_info_bytes: auth_info.clone().encode_as::<Proto>(),
+                    chain_id: self.rpc.chain_id().to_string(),
+                    account_number: account.account_number,
+                }
+                .encode_as::<Proto>(),
+            )
+            .await?;
 
         let simulate_response = self
             .rpc
@@ -290,9 +343,23 @@ impl<W: WalletT, Q: RpcT, G: GasFillerT> TxClient<W, Q, G> {
         memo: impl AsRef<str>,
         account: &BaseAccount,
     ) -> (TxBody, AuthInfo) {
+        let messages = messages.clone().into_iter().map(Into::<RawAny>::into);
+
+        let messages = match &self.authz_granter {
+            Some(_) => vec![RawAny {
+                type_url: <protos::cosmos::authz::v1beta1::MsgExec as prost::Name>::type_url(),
+                value: protos::cosmos::authz::v1beta1::MsgExec {
+                    grantee: self.wallet.address().to_string(),
+                    msgs: messages.map(Into::into).collect(),
+                }
+                .encode_to_vec(),
+            }],
+            None => messages.collect(),
+        };
+
         let tx_body = TxBody {
             // TODO: Use RawAny here
-            messages: messages.clone().into_iter().map(Into::into).collect(),
+            messages,
             memo: memo.as_ref().to_owned(),
             timeout_height: 0,
             extension_options: vec![],
@@ -303,21 +370,29 @@ impl<W: WalletT, Q: RpcT, G: GasFillerT> TxClient<W, Q, G> {
 
         let auth_info = AuthInfo {
             signer_infos: [SignerInfo {
-                public_key: Some(AnyPubKey::Secp256k1(Any(secp256k1::PubKey {
-                    key: self.wallet.public_key().into_encoding(),
-                }))),
+                public_key: Some(self.wallet.any_public_key()),
                 mode_info: ModeInfo::Single {
                     mode: SignMode::Direct,
                 },
                 sequence: account.sequence,
             }]
             .to_vec(),
-            fee: self.gas.mk_fee(self.gas.max_gas().await).await,
+            fee: self.mk_fee(self.gas.max_gas().await).await,
         };
 
         (tx_body, auth_info)
     }
 
+    async fn mk_fee(&self, gas: u64) -> Fee {
+        let mut fee = self.gas.mk_fee(gas).await;
+
+        if let Some(granter) = &self.fee_granter {
+            fee.granter = granter.to_string();
+        }
+
+        fee
+    }
+
     pub async fn account_info<T: Clone + AsRef<[u8]>>(
         &self,
         account: Bech32<T>,
@@ -370,6 +445,8 @@ pub enum BroadcastTxCommitError {
     Query(#[from] GrpcAbciQueryError),
     #[error("error decoding account")]
     AccountDecode(#[from] TryFromAnyError<BaseAccount>),
+    #[error("error signing tx")]
+    Sign(#[from] WalletError),
     #[error("tx failed: code={error_code}, codespace={codespace}, log={log}")]
     TxFailed {
         codespace: String,
@@ -1,5 +1,15 @@
+use bip32::secp256k1::ecdsa::{
+    signature::{hazmat::PrehashSigner, Verifier},
+    Signature, SigningKey, VerifyingKey,
+};
+use concurrent_keyring::Key;
+use ripemd::Digest;
+use serde::{Deserialize, Serialize};
+use sha3::Keccak256;
 use unionlabs::{
-    primitives::{Bech32, FixedBytes, H160, H256, H512},
+    cosmos::crypto::{ethsecp256k1, secp256k1, AnyPubKey},
+    google::protobuf::any::Any,
+    primitives::{encoding::Base64, Bech32, Bytes, FixedBytes, H160, H256, H512},
     signer::CosmosSigner,
 };
 
@@ -8,7 +18,44 @@ pub trait WalletT {
 
     fn public_key(&self) -> FixedBytes<33>;
 
-    fn sign(&self, bz: &[u8]) -> H512;
+    /// The public key of this wallet as it is set in the signer info of transactions. Defaults to
+    /// a `/cosmos.crypto.secp256k1.PubKey`.
+    fn any_public_key(&self) -> AnyPubKey {
+        AnyPubKey::Secp256k1(Any(secp256k1::PubKey {
+            key: self.public_key().into_encoding(),
+        }))
+    }
+
+    async fn sign(&self, bz: &[u8]) -> Result<H512, WalletError>;
+}
+
+/// The kind of account controlled by a key.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyType {
+    /// A standard cosmos-sdk account, signing the sha256 hash of the sign bytes.
+    #[default]
+    Secp256k1,
+    /// An ethermint-style account, signing the keccak256 hash of the sign bytes. See
+    /// [`EthSecp256k1Signer`].
+    EthSecp256k1 {
+        /// The type url of the public key type on the chain, i.e.
+        /// `/cosmos.evm.crypto.v1.ethsecp256k1.PubKey`.
+        #[serde(default = "default_eth_secp256k1_type_url")]
+        type_url: String,
+    },
+}
+
+fn default_eth_secp256k1_type_url() -> String {
+    ethsecp256k1::PubKey::ETHERMINT_TYPE_URL.to_owned()
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum WalletError {
+    #[error("error requesting signature from remote signer")]
+    Remote(#[from] reqwest::Error),
+    #[error("remote signer returned an invalid signature")]
+    InvalidSignature(#[source] bip32::secp256k1::ecdsa::Error),
 }
 
 #[derive(Debug)]
@@ -37,12 +84,276 @@ impl WalletT for LocalSigner {
         self.signer.public_key()
     }
 
-    fn sign(&self, bz: &[u8]) -> H512 {
-        self.signer
+    async fn sign(&self, bz: &[u8]) -> Result<H512, WalletError> {
+        Ok(self
+            .signer
             .try_sign(bz)
             .expect("infallible")
             .to_bytes()
-            .into()
+            .into())
+    }
+}
+
+/// A local signer for the `ethsecp256k1` accounts used by ethermint-based chains.
+///
+/// The address of the account is the ethereum address of the key (the last 20 bytes of the
+/// keccak256 hash of the uncompressed public key), and transactions are signed over the keccak256
+/// hash of the sign bytes.
+#[derive(Debug)]
+pub struct EthSecp256k1Signer {
+    signing_key: SigningKey,
+    type_url: String,
+    address: Bech32<H160>,
+}
+
+impl EthSecp256k1Signer {
+    pub fn new(private_key: H256, prefix: impl Into<String>, type_url: impl Into<String>) -> Self {
+        let signing_key =
+            SigningKey::from_bytes(&(*private_key.get()).into()).expect("invalid private key");
+
+        let address = Bech32::new(
+            prefix.into(),
+            <H160>::try_from(
+                &Keccak256::digest(
+                    &signing_key
+                        .verifying_key()
+                        .to_encoded_point(false)
+                        .as_bytes()[1..],
+                )[12..],
+            )
+            .expect("keccak256 output is 32 bytes; qed;"),
+        );
+
+        Self {
+            signing_key,
+            type_url: type_url.into(),
+            address,
+        }
+    }
+}
+
+impl WalletT for EthSecp256k1Signer {
+    fn address(&self) -> Bech32<H160> {
+        self.address.clone()
+    }
+
+    fn public_key(&self) -> FixedBytes<33> {
+        self.signing_key
+            .verifying_key()
+            .to_encoded_point(true)
+            .as_bytes()
+            .try_into()
+            .expect("compressed public key is 33 bytes; qed;")
+    }
+
+    fn any_public_key(&self) -> AnyPubKey {
+        AnyPubKey::EthSecp256k1(ethsecp256k1::PubKey {
+            type_url: self.type_url.clone(),
+            key: self.public_key().into_encoding(),
+        })
+    }
+
+    async fn sign(&self, bz: &[u8]) -> Result<H512, WalletError> {
+        let signature: Signature = self
+            .signing_key
+            .sign_prehash(&Keccak256::digest(bz))
+            .expect("infallible");
+
+        Ok(signature.to_bytes().into())
+    }
+}
+
+/// A signer that holds no key material, and instead requests signatures from a remote signing
+/// service.
+///
+/// There is no standard protocol for remote signing of cosmos-sdk transactions (tmkms and horcrux
+/// only sign consensus messages over the CometBFT privval protocol), so this uses a minimal JSON
+/// over HTTP protocol that the signing service is expected to implement. The service must expose
+/// `POST /sign`, which takes the sign bytes of a transaction and returns the secp256k1 ECDSA
+/// signature over the sha256 hash of them:
+///
+/// ```json
+/// // request
+/// { "public_key": "<base64 compressed public key>", "sign_bytes": "<base64 sign doc>" }
+/// // response
+/// { "signature": "<base64 64 byte r || s>" }
+/// ```
+///
+/// Returned signatures are verified against the public key before being used.
+#[derive(Debug)]
+pub struct RemoteSigner {
+    client: reqwest::Client,
+    url: String,
+    verifying_key: VerifyingKey,
+    address: Bech32<H160>,
+}
+
+#[derive(Debug, Serialize)]
+struct SignRequest {
+    public_key: Bytes<Base64>,
+    sign_bytes: Bytes<Base64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SignResponse {
+    signature: H512<Base64>,
+}
+
+impl RemoteSigner {
+    /// Create a new remote signer for the key `public_key` (either SEC1 compressed or
+    /// uncompressed) held by the signing service at `url`.
+    pub fn new(
+        url: impl Into<String>,
+        public_key: &[u8],
+        prefix: impl Into<String>,
+    ) -> Result<Self, bip32::secp256k1::ecdsa::Error> {
+        let verifying_key = VerifyingKey::from_sec1_bytes(public_key)?;
+
+        // bech32(prefix, ripemd(sha256(pubkey)))
+        let address = Bech32::new(
+            prefix.into(),
+            ripemd::Ripemd160::new()
+                .chain_update(sha2::Sha256::digest(
+                    verifying_key.to_encoded_point(true).as_bytes(),
+                ))
+                .finalize()
+                .into(),
+        );
+
+        Ok(Self {
+            client: reqwest::Client::new(),
+            url: url.into().trim_end_matches('/').to_owned(),
+            verifying_key,
+            address,
+        })
+    }
+}
+
+impl WalletT for RemoteSigner {
+    fn address(&self) -> Bech32<H160> {
+        self.address.clone()
+    }
+
+    fn public_key(&self) -> FixedBytes<33> {
+        self.verifying_key
+            .to_encoded_point(true)
+            .as_bytes()
+            .try_into()
+            .expect("compressed public key is 33 bytes; qed;")
+    }
+
+    async fn sign(&self, bz: &[u8]) -> Result<H512, WalletError> {
+        let response = self
+            .client
+            .post(format!("{}/sign", self.url))
+            .json(&SignRequest {
+                public_key: self.public_key().get().into(),
+                sign_bytes: bz.to_vec().into(),
+            })
+            .send()
+            .await?
+            .error_for_status()?
+            .json::<SignResponse>()
+            .await?;
+
+        let signature = Signature::from_slice(response.signature.get())
+            .map_err(WalletError::InvalidSignature)?;
+
+        // cosmos-sdk only accepts low-s signatures (as does the verifier below)
+        let signature = signature.normalize_s().unwrap_or(signature);
+
+        self.verifying_key
+            .verify(bz, &signature)
+            .map_err(WalletError::InvalidSignature)?;
+
+        Ok(signature.to_bytes().into())
+    }
+}
+
+/// A [`LocalSigner`], [`EthSecp256k1Signer`] or [`RemoteSigner`], depending on the [`Key`] and
+/// [`KeyType`] it was created from.
+#[derive(Debug)]
+pub enum AnySigner {
+    Local(LocalSigner),
+    EthSecp256k1(EthSecp256k1Signer),
+    Remote(RemoteSigner),
+}
+
+impl AnySigner {
+    pub fn new(
+        key: Key,
+        key_type: KeyType,
+        prefix: impl Into<String>,
+    ) -> Result<Self, AnySignerError> {
+        match (key, key_type) {
+            (Key::Local(private_key), key_type) => {
+                let private_key = H256::new(
+                    SigningKey::from_slice(&private_key)
+                        .map_err(AnySignerError::InvalidPrivateKey)?
+                        .to_bytes()
+                        .into(),
+                );
+
+                Ok(match key_type {
+                    KeyType::Secp256k1 => Self::Local(LocalSigner::new(private_key, prefix)),
+                    KeyType::EthSecp256k1 { type_url } => {
+                        Self::EthSecp256k1(EthSecp256k1Signer::new(private_key, prefix, type_url))
+                    }
+                })
+            }
+            (Key::Remote { url, public_key }, KeyType::Secp256k1) => {
+                RemoteSigner::new(url, &public_key, prefix)
+                    .map(Self::Remote)
+                    .map_err(AnySignerError::InvalidPublicKey)
+            }
+            (Key::Remote { .. }, KeyType::EthSecp256k1 { .. }) => {
+                Err(AnySignerError::UnsupportedRemoteKeyType)
+            }
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum AnySignerError {
+    #[error("invalid private key")]
+    InvalidPrivateKey(#[source] bip32::secp256k1::ecdsa::Error),
+    #[error("invalid public key")]
+    InvalidPublicKey(#[source] bip32::secp256k1::ecdsa::Error),
+    #[error("remote signers only support secp256k1 keys")]
+    UnsupportedRemoteKeyType,
+}
+
+impl WalletT for AnySigner {
+    fn address(&self) -> Bech32<H160> {
+        match self {
+            Self::Local(s) => s.address(),
+            Self::EthSecp256k1(s) => s.address(),
+            Self::Remote(s) => s.address(),
+        }
+    }
+
+    fn public_key(&self) -> FixedBytes<33> {
+        match self {
+            Self::Local(s) => s.public_key(),
+            Self::EthSecp256k1(s) => s.public_key(),
+            Self::Remote(s) => s.public_key(),
+        }
+    }
+
+    fn any_public_key(&self) -> AnyPubKey {
+        match self {
+            Self::Local(s) => s.any_public_key(),
+            Self::EthSecp256k1(s) => s.any_public_key(),
+            Self::Remote(s) => s.any_public_key(),
+        }
+    }
+
+    async fn sign(&self, bz: &[u8]) -> Result<H512, WalletError> {
+        match self {
+            Self::Local(s) => s.sign(bz).await,
+            Self::EthSecp256k1(s) => s.sign(bz).await,
+            Self::Remote(s) => s.sign(bz).await,
+        }
     }
 }
 
@@ -55,7 +366,190 @@ impl<T: WalletT> WalletT for &T {
         (*self).public_key()
     }
 
-    fn sign(&self, bz: &[u8]) -> H512 {
-        (*self).sign(bz)
+    fn any_public_key(&self) -> AnyPubKey {
+        (*self).any_public_key()
+    }
+
+    async fn sign(&self, bz: &[u8]) -> Result<H512, WalletError> {
+        (*self).sign(bz).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bip32::secp256k1::ecdsa::signature::Signer;
+    use hex_literal::hex;
+    use serde_json::{json, Value};
+    use tokio::{
+        io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader},
+        net::TcpListener,
+    };
+
+    use super::*;
+
+    const PREFIX: &str = "union";
+
+    /// Serve `POST /sign` requests with `sign`, returning the url of the server.
+    async fn serve(sign: impl Fn(&[u8]) -> Signature + Send + Sync + 'static) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            loop {
+                let (stream, _) = listener.accept().await.unwrap();
+                let mut stream = BufReader::new(stream);
+
+                loop {
+                    let mut request_line = String::new();
+                    if stream.read_line(&mut request_line).await.unwrap() == 0 {
+                        break;
+                    }
+                    assert!(request_line.starts_with("POST /sign "), "{request_line}");
+
+                    let mut content_length = 0;
+                    loop {
+                        let mut line = String::new();
+                        stream.read_line(&mut line).await.unwrap();
+                        match line.trim_end().split_once(':') {
+                            Some((k, v)) if k.eq_ignore_ascii_case("content-length") => {
+                                content_length = v.trim().parse().unwrap();
+                            }
+                            None if line.trim_end().is_empty() => break,
+                            _ => {}
+                        }
+                    }
+
+                    let mut body = vec![0; content_length];
+                    stream.read_exact(&mut body).await.unwrap();
+                    let request = serde_json::from_slice::<Value>(&body).unwrap();
+
+                    let sign_bytes =
+                        serde_json::from_value::<Bytes<Base64>>(request["sign_bytes"].clone())
+                            .unwrap();
+
+                    let body = json!({
+                        "signature": <H512<Base64>>::from(sign(&sign_bytes).to_bytes()),
+                    })
+                    .to_string();
+
+                    stream
+                        .get_mut()
+                        .write_all(
+                            format!(
+                                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n{body}",
+                                body.len()
+                            )
+                            .as_bytes(),
+                        )
+                        .await
+                        .unwrap();
+                }
+            }
+        });
+
+        format!("http://{addr}")
+    }
+
+    fn signing_key(byte: u8) -> SigningKey {
+        SigningKey::from_slice(&[byte; 32]).unwrap()
+    }
+
+    fn remote_signer_for(url: String, signing_key: &SigningKey) -> RemoteSigner {
+        RemoteSigner::new(
+            url,
+            signing_key
+                .verifying_key()
+                .to_encoded_point(true)
+                .as_bytes(),
+            PREFIX,
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn remote_signer() {
+        let key = signing_key(1);
+        let local = LocalSigner::new(H256::new([1; 32]), PREFIX);
+
+        let remote = remote_signer_for(serve(move |bz| key.sign(bz)).await, &signing_key(1));
+
+        assert_eq!(remote.address(), local.address());
+        assert_eq!(remote.public_key(), local.public_key());
+        assert_eq!(
+            remote.sign(b"sign bytes").await.unwrap(),
+            local.sign(b"sign bytes").await.unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn remote_signer_normalizes_s() {
+        let key = signing_key(1);
+        let local = LocalSigner::new(H256::new([1; 32]), PREFIX);
+
+        let remote = remote_signer_for(
+            serve(move |bz| {
+                let signature: Signature = key.sign(bz);
+                // the same signature, with s in the upper half of the order
+                Signature::from_scalars(signature.r(), -*signature.s()).unwrap()
+            })
+            .await,
+            &signing_key(1),
+        );
+
+        assert_eq!(
+            remote.sign(b"sign bytes").await.unwrap(),
+            local.sign(b"sign bytes").await.unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn remote_signer_wrong_key() {
+        let other_key = signing_key(2);
+
+        let remote = remote_signer_for(serve(move |bz| other_key.sign(bz)).await, &signing_key(1));
+
+        assert!(matches!(
+            remote.sign(b"sign bytes").await,
+            Err(WalletError::InvalidSignature(_))
+        ));
+    }
+
+    // the signing key, address and signature from the example in EIP-155
+    // https://eips.ethereum.org/EIPS/eip-155#example
+    #[tokio::test]
+    async fn eth_secp256k1_signer() {
+        let signer = EthSecp256k1Signer::new(
+            H256::new([0x46; 32]),
+            "evmos",
+            ethsecp256k1::PubKey::ETHERMINT_TYPE_URL,
+        );
+
+        assert_eq!(
+            signer.address().data().get(),
+            &hex!("9d8a62f656a8d1615c1294fd71e9cfb3e4855a4f")
+        );
+        assert_eq!(
+            signer.address().to_string(),
+            "evmos1nk9x9ajk4rgkzhqjjn7hr6w0k0jg2kj07me7uu"
+        );
+
+        assert_eq!(
+            signer
+                .sign(&hex!(
+                    "ec098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a764000080018080"
+                ))
+                .await
+                .unwrap(),
+            <H512>::new(hex!(
+                "28ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276"
+                "67cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83"
+            ))
+        );
+
+        assert!(matches!(
+            signer.any_public_key(),
+            AnyPubKey::EthSecp256k1(ethsecp256k1::PubKey { type_url, .. })
+                if type_url == ethsecp256k1::PubKey::ETHERMINT_TYPE_URL
+        ));
     }
 }
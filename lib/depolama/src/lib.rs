@@ -417,6 +417,13 @@ pub trait QuerierExt {
     /// This will error if the cross contract call fails, or if the value cannot be decoded.
     fn read<S: Store>(&self, addr: &Addr, key: &S::Key) -> StdResult<S::Value>;
 
+    /// Read a value from the store of another contract, returning `None` if the value is not found.
+    ///
+    /// # Errors
+    ///
+    /// This will error if the cross contract call fails, or if the value cannot be decoded.
+    fn maybe_read<S: Store>(&self, addr: &Addr, key: &S::Key) -> StdResult<Option<S::Value>>;
+
     /// Read a value from the item store of another contract.
     ///
     /// # Errors
@@ -428,20 +435,21 @@ pub trait QuerierExt {
 impl QuerierExt for dyn Querier + '_ {
     #[inline]
     fn read<S: Store>(&self, addr: &Addr, key: &S::Key) -> StdResult<S::Value> {
-        let raw_value = self
-            .raw_query(
-                &to_json_binary(&QueryRequest::<Empty>::Wasm(WasmQuery::Raw {
-                    contract_addr: addr.into(),
-                    key: raw_key::<S>(key).into_vec().into(),
-                }))
-                .expect("serialization is infallible; qed;"),
-            )
-            .into_result()
-            .map_err(|e| StdError::generic_err(e.to_string()))?
-            .into_result()
-            .map_err(StdError::generic_err)?;
-
-        S::decode_value(&<Vec<u8>>::from(raw_value).into())
+        let raw_value = raw_query::<S>(self, addr, key)?;
+
+        S::decode_value(&raw_value.into())
+    }
+
+    #[inline]
+    fn maybe_read<S: Store>(&self, addr: &Addr, key: &S::Key) -> StdResult<Option<S::Value>> {
+        let raw_value = raw_query::<S>(self, addr, key)?;
+
+        // raw queries return an empty value if the key is not set
+        if raw_value.is_empty() {
+            Ok(None)
+        } else {
+            S::decode_value(&raw_value.into()).map(Some)
+        }
     }
 
     #[inline]
@@ -450,6 +458,26 @@ impl QuerierExt for dyn Querier + '_ {
     }
 }
 
+fn raw_query<S: Store>(
+    querier: &(dyn Querier + '_),
+    addr: &Addr,
+    key: &S::Key,
+) -> StdResult<Vec<u8>> {
+    querier
+        .raw_query(
+            &to_json_binary(&QueryRequest::<Empty>::Wasm(WasmQuery::Raw {
+                contract_addr: addr.into(),
+                key: raw_key::<S>(key).into_vec().into(),
+            }))
+            .expect("serialization is infallible; qed;"),
+        )
+        .into_result()
+        .map_err(|e| StdError::generic_err(e.to_string()))?
+        .into_result()
+        .map_err(StdError::generic_err)
+        .map(Into::into)
+}
+
 /// A raw, un-prefixed store implementation.
 ///
 /// This is equivalent to directly reading `deps.storage`, but with a slightly nicer api and
@@ -13,6 +13,7 @@ use unionlabs::{
 #[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
 pub enum ClientState {
     V1(ClientStateV1),
+    V2(ClientStateV2),
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -35,6 +36,61 @@ pub struct ClientStateV1 {
     pub initial_sync_committee: Option<InitialSyncCommittee>,
 }
 
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+pub struct ClientStateV2 {
+    pub chain_id: u64,
+    pub chain_spec: PresetBaseKind,
+    pub genesis_validators_root: H256,
+    pub genesis_time: u64,
+    pub latest_height: u64,
+    pub frozen_height: Height,
+    /// the ibc contract on the counterparty chain that contains the ICS23 commitments
+    pub ibc_contract_address: H160,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default),
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub initial_sync_committee: Option<InitialSyncCommittee>,
+    /// Whether [`LightClientUpdate::Optimistic`](crate::LightClientUpdate::Optimistic) updates
+    /// are accepted by this client. Optimistic updates track the attested head of the beacon chain
+    /// instead of the finalized head, trading safety for latency.
+    pub allow_optimistic_updates: bool,
+    /// Whether this client applies the best valid update without a sync committee supermajority
+    /// once no finalized update has been seen for `UPDATE_TIMEOUT` slots, as per the consensus
+    /// spec's `process_light_client_store_force_update`.
+    pub allow_force_updates: bool,
+}
+
+impl From<ClientStateV1> for ClientStateV2 {
+    /// [`ClientStateV1`] clients accept neither optimistic nor force updates.
+    fn from(value: ClientStateV1) -> Self {
+        Self {
+            chain_id: value.chain_id,
+            chain_spec: value.chain_spec,
+            genesis_validators_root: value.genesis_validators_root,
+            genesis_time: value.genesis_time,
+            latest_height: value.latest_height,
+            frozen_height: value.frozen_height,
+            ibc_contract_address: value.ibc_contract_address,
+            initial_sync_committee: value.initial_sync_committee,
+            allow_optimistic_updates: false,
+            allow_force_updates: false,
+        }
+    }
+}
+
+impl From<ClientState> for ClientStateV2 {
+    fn from(value: ClientState) -> Self {
+        match value {
+            ClientState::V1(v1) => v1.into(),
+            ClientState::V2(v2) => v2,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
@@ -53,7 +109,7 @@ mod tests {
 
     use super::*;
 
-    fn mk_client_state() -> ClientState {
+    fn mk_client_state_v1() -> ClientState {
         ClientState::V1(ClientStateV1 {
             chain_id: 1,
             chain_spec: PresetBaseKind::Minimal,
@@ -66,13 +122,42 @@ mod tests {
         })
     }
 
+    fn mk_client_state_v2() -> ClientState {
+        ClientState::V2(ClientStateV2 {
+            chain_id: 1,
+            chain_spec: PresetBaseKind::Minimal,
+            genesis_validators_root: H256::new([0xAA; 32]),
+            genesis_time: 123,
+            latest_height: 987,
+            frozen_height: Height::new(1),
+            ibc_contract_address: H160::new([0xAA; 20]),
+            initial_sync_committee: None,
+            allow_optimistic_updates: true,
+            allow_force_updates: true,
+        })
+    }
+
     #[test]
     fn bincode_iso() {
-        assert_codec_iso::<_, Bincode>(&mk_client_state());
+        assert_codec_iso::<_, Bincode>(&mk_client_state_v1());
+        assert_codec_iso::<_, Bincode>(&mk_client_state_v2());
     }
 
     #[test]
     fn json_iso() {
-        assert_codec_iso::<_, Json>(&mk_client_state());
+        assert_codec_iso::<_, Json>(&mk_client_state_v1());
+        assert_codec_iso::<_, Json>(&mk_client_state_v2());
+    }
+
+    #[test]
+    fn v1_upgrades_with_updates_disabled() {
+        let ClientStateV2 {
+            allow_optimistic_updates,
+            allow_force_updates,
+            ..
+        } = mk_client_state_v1().into();
+
+        assert!(!allow_optimistic_updates);
+        assert!(!allow_force_updates);
     }
 }
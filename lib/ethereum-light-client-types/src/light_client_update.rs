@@ -1,4 +1,8 @@
-use beacon_api_types::altair::SyncCommittee;
+use beacon_api_types::{
+    altair::{SyncAggregate, SyncCommittee},
+    custom_types::Slot,
+};
+use ethereum_sync_protocol_types::{LightClientHeader, LightClientOptimisticUpdate};
 use unionlabs::primitives::H256;
 
 use crate::LightClientUpdateData;
@@ -10,6 +14,9 @@ use crate::LightClientUpdateData;
 pub enum LightClientUpdate {
     SyncCommitteePeriodChange(Box<SyncCommitteePeriodChangeUpdate>),
     WithinSyncCommitteePeriod(Box<WithinSyncCommitteePeriodUpdate>),
+    /// An update to the attested header, without finality. This is only accepted by clients that
+    /// allow optimistic updates.
+    Optimistic(Box<LightClientOptimisticUpdate>),
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -34,15 +41,65 @@ pub struct WithinSyncCommitteePeriodUpdate {
 }
 
 impl LightClientUpdate {
-    pub fn update_data(&self) -> &LightClientUpdateData {
+    /// The finality carrying data of this update, or `None` if this is an optimistic update.
+    pub fn update_data(&self) -> Option<&LightClientUpdateData> {
         match self {
-            LightClientUpdate::SyncCommitteePeriodChange(update) => &update.update_data,
-            LightClientUpdate::WithinSyncCommitteePeriod(update) => &update.update_data,
+            LightClientUpdate::SyncCommitteePeriodChange(update) => Some(&update.update_data),
+            LightClientUpdate::WithinSyncCommitteePeriod(update) => Some(&update.update_data),
+            LightClientUpdate::Optimistic(_) => None,
         }
     }
 
-    pub fn into_light_client_update(self) -> ethereum_sync_protocol_types::LightClientUpdate {
+    pub fn update_data_mut(&mut self) -> Option<&mut LightClientUpdateData> {
         match self {
+            LightClientUpdate::SyncCommitteePeriodChange(update) => Some(&mut update.update_data),
+            LightClientUpdate::WithinSyncCommitteePeriod(update) => Some(&mut update.update_data),
+            LightClientUpdate::Optimistic(_) => None,
+        }
+    }
+
+    pub fn attested_header(&self) -> &LightClientHeader {
+        match self {
+            LightClientUpdate::SyncCommitteePeriodChange(update) => {
+                &update.update_data.attested_header
+            }
+            LightClientUpdate::WithinSyncCommitteePeriod(update) => {
+                &update.update_data.attested_header
+            }
+            LightClientUpdate::Optimistic(update) => &update.attested_header,
+        }
+    }
+
+    pub fn sync_aggregate(&self) -> &SyncAggregate {
+        match self {
+            LightClientUpdate::SyncCommitteePeriodChange(update) => {
+                &update.update_data.sync_aggregate
+            }
+            LightClientUpdate::WithinSyncCommitteePeriod(update) => {
+                &update.update_data.sync_aggregate
+            }
+            LightClientUpdate::Optimistic(update) => &update.sync_aggregate,
+        }
+    }
+
+    pub fn signature_slot(&self) -> Slot {
+        match self {
+            LightClientUpdate::SyncCommitteePeriodChange(update) => {
+                update.update_data.signature_slot
+            }
+            LightClientUpdate::WithinSyncCommitteePeriod(update) => {
+                update.update_data.signature_slot
+            }
+            LightClientUpdate::Optimistic(update) => update.signature_slot,
+        }
+    }
+
+    /// Convert this update into the sync protocol representation, or `None` if this is an
+    /// optimistic update (which has no finalized header).
+    pub fn into_light_client_update(
+        self,
+    ) -> Option<ethereum_sync_protocol_types::LightClientUpdate> {
+        Some(match self {
             LightClientUpdate::SyncCommitteePeriodChange(u) => {
                 ethereum_sync_protocol_types::LightClientUpdate {
                     attested_header: u.update_data.attested_header,
@@ -65,19 +122,18 @@ impl LightClientUpdate {
                     signature_slot: u.update_data.signature_slot,
                 }
             }
-        }
+            LightClientUpdate::Optimistic(_) => return None,
+        })
     }
 }
 
 #[cfg(test)]
 mod tests {
     use beacon_api_types::{
-        altair::SyncAggregate,
-        custom_types::{Gas, Slot, ValidatorIndex},
+        custom_types::{Gas, ValidatorIndex},
         deneb::ExecutionPayloadHeader,
         phase0::BeaconBlockHeader,
     };
-    use ethereum_sync_protocol_types::LightClientHeader;
     use unionlabs::{
         encoding::{Bincode, Json},
         primitives::{H160, H256, H384, H768, U256},
@@ -197,6 +253,18 @@ mod tests {
         )));
     }
 
+    #[test]
+    fn optimistic_update_iso() {
+        let update = LightClientUpdate::Optimistic(Box::new(LightClientOptimisticUpdate {
+            attested_header: mk_light_client_update_data().attested_header,
+            sync_aggregate: mk_light_client_update_data().sync_aggregate,
+            signature_slot: Slot::new(124),
+        }));
+
+        assert_codec_iso::<_, Bincode>(&update);
+        assert_codec_iso::<_, Json>(&update);
+    }
+
     #[test]
     fn within_epoch_update_json_iso() {
         assert_codec_iso::<_, Json>(&mk_within_epoch_update());
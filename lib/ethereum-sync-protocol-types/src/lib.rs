@@ -1,5 +1,11 @@
-pub use crate::{light_client_header::LightClientHeader, light_client_update::LightClientUpdate};
+pub use crate::{
+    light_client_header::LightClientHeader,
+    light_client_optimistic_update::LightClientOptimisticUpdate,
+    light_client_update::LightClientUpdate,
+};
 
 pub mod light_client_header;
 
+pub mod light_client_optimistic_update;
+
 pub mod light_client_update;
@@ -0,0 +1,29 @@
+use beacon_api_types::{altair::SyncAggregate, custom_types::Slot, deneb};
+
+use crate::LightClientHeader;
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(deny_unknown_fields)
+)]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+pub struct LightClientOptimisticUpdate {
+    /// Header attested to by the sync committee
+    pub attested_header: LightClientHeader,
+    /// Sync committee aggregate signature
+    pub sync_aggregate: SyncAggregate,
+    /// Slot at which the aggregate signature was created (untrusted)
+    pub signature_slot: Slot,
+}
+
+impl From<deneb::LightClientOptimisticUpdate> for LightClientOptimisticUpdate {
+    fn from(value: deneb::LightClientOptimisticUpdate) -> Self {
+        Self {
+            attested_header: value.attested_header.into(),
+            sync_aggregate: value.sync_aggregate,
+            signature_slot: value.signature_slot,
+        }
+    }
+}
@@ -65,6 +65,22 @@ pub enum Error {
         update_attested_slot: Slot,
         update_finalized_slot: Slot,
     },
+    #[error(
+        "(update_signature_slot > update_attested_slot) must hold, \
+        found: ({update_signature_slot} > {update_attested_slot})"
+    )]
+    InvalidOptimisticSlots {
+        update_signature_slot: Slot,
+        update_attested_slot: Slot,
+    },
+    #[error(
+        "irrelevant optimistic update, the update_attested_slot (found {update_attested_slot}) \
+        must be > the trusted_slot (found {trusted_slot})"
+    )]
+    IrrelevantOptimisticUpdate {
+        update_attested_slot: Slot,
+        trusted_slot: Slot,
+    },
     #[error(
         "update slot {update_signature_slot} is more recent than the \
         calculated current slot {current_slot}"
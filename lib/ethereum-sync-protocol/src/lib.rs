@@ -14,9 +14,9 @@ use beacon_api_types::{
         NEXT_SYNC_COMMITTEE_GINDEX, NEXT_SYNC_COMMITTEE_GINDEX_ELECTRA,
     },
     custom_types::{DomainType, Slot},
-    deneb,
+    deneb, phase0,
 };
-use ethereum_sync_protocol_types::LightClientHeader;
+use ethereum_sync_protocol_types::{LightClientHeader, LightClientOptimisticUpdate};
 use fork_schedules::{ForkSchedule, Forks};
 use ssz::Ssz;
 use typenum::Unsigned;
@@ -230,6 +230,154 @@ pub fn validate_light_client_update<C: ChainSpec, V: BlsVerify>(
     Ok(())
 }
 
+/// Verifies if the light client optimistic `update` is valid.
+///
+/// * `update`: The optimistic update we want to verify.
+/// * `sync_committee`: The sync committee of the period of `update.signature_slot`.
+/// * `current_slot`: The slot number computed based on the current timestamp.
+/// * `trusted_slot`: The slot of the trusted state the update is applied against.
+/// * `genesis_validators_root`: The latest `genesis_validators_root` that is saved by the light client.
+/// * `bls_verifier`: BLS verification implementation.
+///
+/// An optimistic update only carries the attested header, so it is processed the same way as a
+/// [`LightClientUpdate`][ethereum_sync_protocol_types::LightClientUpdate] without finality and
+/// without a next sync committee. The caller is responsible for picking the correct sync committee
+/// for the signature period, and for deciding whether the participation is sufficient.
+///
+/// [See in consensus-spec](https://github.com/ethereum/consensus-specs/blob/dev/specs/altair/light-client/sync-protocol.md#process_light_client_optimistic_update)
+#[allow(clippy::too_many_arguments)]
+pub fn validate_light_client_optimistic_update<C: ChainSpec, V: BlsVerify>(
+    chain_id: u64,
+    update: &LightClientOptimisticUpdate,
+    sync_committee: &SyncCommittee,
+    current_slot: Slot,
+    trusted_slot: Slot,
+    genesis_validators_root: H256,
+    bls_verifier: V,
+) -> Result<(), Error> {
+    // verify that the sync committee has sufficient participants
+    let sync_aggregate: SyncAggregateSsz<C> = update.sync_aggregate.clone().try_into()?;
+
+    let set_bits = sync_aggregate.sync_committee_bits.num_set_bits();
+    ensure(
+        set_bits >= C::MIN_SYNC_COMMITTEE_PARTICIPANTS::USIZE,
+        Error::InsufficientSyncCommitteeParticipants(set_bits),
+    )?;
+
+    is_valid_light_client_header::<C>(chain_id, &update.attested_header)?;
+
+    let update_attested_slot = update.attested_header.beacon.slot;
+
+    ensure(
+        current_slot >= update.signature_slot,
+        Error::UpdateMoreRecentThanCurrentSlot {
+            current_slot,
+            update_signature_slot: update.signature_slot,
+        },
+    )?;
+
+    ensure(
+        update.signature_slot > update_attested_slot,
+        Error::InvalidOptimisticSlots {
+            update_signature_slot: update.signature_slot,
+            update_attested_slot,
+        },
+    )?;
+
+    // without a next sync committee, the only way for an optimistic update to be relevant is to
+    // move the attested header forward
+    ensure(
+        update_attested_slot > trusted_slot,
+        Error::IrrelevantOptimisticUpdate {
+            update_attested_slot,
+            trusted_slot,
+        },
+    )?;
+
+    verify_sync_aggregate_signature::<C, V>(
+        chain_id,
+        &update.attested_header.beacon,
+        update.signature_slot,
+        sync_aggregate,
+        update.sync_aggregate.sync_committee_signature,
+        genesis_validators_root,
+        sync_committee,
+        bls_verifier,
+    )
+}
+
+/// The amount of slots after which the best valid update can be force-applied if no finalized
+/// update with a supermajority was seen.
+///
+/// [See in consensus-spec](https://github.com/ethereum/consensus-specs/blob/dev/specs/altair/light-client/sync-protocol.md#constants)
+pub fn update_timeout<C: ChainSpec>() -> u64 {
+    C::SLOTS_PER_EPOCH::U64 * C::EPOCHS_PER_SYNC_COMMITTEE_PERIOD::U64
+}
+
+/// Returns whether `new_update` is a better update than `old_update`, as per the ranking used for
+/// `best_valid_update` in the light client store.
+///
+/// [See in consensus-spec](https://github.com/ethereum/consensus-specs/blob/dev/specs/altair/light-client/sync-protocol.md#is_better_update)
+pub fn is_better_update<C: ChainSpec>(
+    new_update: &ethereum_sync_protocol_types::LightClientUpdate,
+    old_update: &ethereum_sync_protocol_types::LightClientUpdate,
+) -> Result<bool, Error> {
+    let new_sync_aggregate: SyncAggregateSsz<C> = new_update.sync_aggregate.clone().try_into()?;
+    let old_sync_aggregate: SyncAggregateSsz<C> = old_update.sync_aggregate.clone().try_into()?;
+
+    // Compare supermajority (> 2/3) sync committee participation
+    let max_active_participants = C::SYNC_COMMITTEE_SIZE::USIZE;
+    let new_num_active_participants = new_sync_aggregate.sync_committee_bits.num_set_bits();
+    let old_num_active_participants = old_sync_aggregate.sync_committee_bits.num_set_bits();
+    let new_has_supermajority = new_num_active_participants * 3 >= max_active_participants * 2;
+    let old_has_supermajority = old_num_active_participants * 3 >= max_active_participants * 2;
+    if new_has_supermajority != old_has_supermajority {
+        return Ok(new_has_supermajority);
+    }
+    if !new_has_supermajority && new_num_active_participants != old_num_active_participants {
+        return Ok(new_num_active_participants > old_num_active_participants);
+    }
+
+    // Compare presence of relevant sync committee
+    let has_relevant_sync_committee = |update: &ethereum_sync_protocol_types::LightClientUpdate| {
+        update.next_sync_committee.is_some()
+            && compute_sync_committee_period_at_slot::<C>(update.attested_header.beacon.slot)
+                == compute_sync_committee_period_at_slot::<C>(update.signature_slot)
+    };
+    let new_has_relevant_sync_committee = has_relevant_sync_committee(new_update);
+    let old_has_relevant_sync_committee = has_relevant_sync_committee(old_update);
+    if new_has_relevant_sync_committee != old_has_relevant_sync_committee {
+        return Ok(new_has_relevant_sync_committee);
+    }
+
+    // Compare indication of any finality
+    //
+    // NOTE: Updates in this crate always carry a finalized header, so both updates have finality.
+    // Compare sync committee finality
+    let has_sync_committee_finality = |update: &ethereum_sync_protocol_types::LightClientUpdate| {
+        compute_sync_committee_period_at_slot::<C>(update.finalized_header.beacon.slot)
+            == compute_sync_committee_period_at_slot::<C>(update.attested_header.beacon.slot)
+    };
+    let new_has_sync_committee_finality = has_sync_committee_finality(new_update);
+    let old_has_sync_committee_finality = has_sync_committee_finality(old_update);
+    if new_has_sync_committee_finality != old_has_sync_committee_finality {
+        return Ok(new_has_sync_committee_finality);
+    }
+
+    // Tiebreaker 1: Sync committee participation beyond supermajority
+    if new_num_active_participants != old_num_active_participants {
+        return Ok(new_num_active_participants > old_num_active_participants);
+    }
+
+    // Tiebreaker 2: Prefer older data (fewer changes to best)
+    if new_update.attested_header.beacon.slot != old_update.attested_header.beacon.slot {
+        return Ok(new_update.attested_header.beacon.slot < old_update.attested_header.beacon.slot);
+    }
+
+    // Tiebreaker 3: Prefer updates with earlier signature slots
+    Ok(new_update.signature_slot < old_update.signature_slot)
+}
+
 pub fn verify_signature<C: ChainSpec, V: BlsVerify>(
     chain_id: u64,
     update: &ethereum_sync_protocol_types::LightClientUpdate,
@@ -238,7 +386,31 @@ pub fn verify_signature<C: ChainSpec, V: BlsVerify>(
     sync_committee: &SyncCommittee,
     bls_verifier: V,
 ) -> Result<(), Error> {
-    let fork_version_slot = Slot::new(std::cmp::max(update.signature_slot.get(), 1) - 1);
+    verify_sync_aggregate_signature::<C, V>(
+        chain_id,
+        &update.attested_header.beacon,
+        update.signature_slot,
+        sync_aggregate,
+        update.sync_aggregate.sync_committee_signature,
+        genesis_validators_root,
+        sync_committee,
+        bls_verifier,
+    )
+}
+
+/// Verifies the sync committee signature over `attested_header`, signed at `signature_slot`.
+#[allow(clippy::too_many_arguments)]
+fn verify_sync_aggregate_signature<C: ChainSpec, V: BlsVerify>(
+    chain_id: u64,
+    attested_header: &phase0::BeaconBlockHeader,
+    signature_slot: Slot,
+    sync_aggregate: SyncAggregateSsz<C>,
+    sync_committee_signature: H768,
+    genesis_validators_root: H256,
+    sync_committee: &SyncCommittee,
+    bls_verifier: V,
+) -> Result<(), Error> {
+    let fork_version_slot = Slot::new(std::cmp::max(signature_slot.get(), 1) - 1);
     let fork_version =
         compute_fork_version(chain_id, compute_epoch_at_slot::<C>(fork_version_slot));
 
@@ -250,7 +422,7 @@ pub fn verify_signature<C: ChainSpec, V: BlsVerify>(
             .genesis()
             .current_version,
     );
-    let signing_root = compute_signing_root(&update.attested_header.beacon, domain);
+    let signing_root = compute_signing_root(attested_header, domain);
 
     let participant_pubkeys = sync_aggregate
         .sync_committee_bits
@@ -269,7 +441,7 @@ pub fn verify_signature<C: ChainSpec, V: BlsVerify>(
             })
             .collect::<Vec<_>>(),
         signing_root.as_ref().to_owned(),
-        update.sync_aggregate.sync_committee_signature,
+        sync_committee_signature,
     )?;
 
     Ok(())
@@ -283,6 +455,15 @@ pub fn get_lc_execution_root<C: ChainSpec>(chain_id: u64, header: &LightClientHe
 
     let epoch = compute_epoch_at_slot::<C>(header.beacon.slot);
 
+    // No new field in fulu
+    if let Some(fork) = fs.fork(Forks::Fulu)
+        && epoch >= fork.epoch
+    {
+        return TryInto::<deneb::ExecutionPayloadHeaderSsz<C>>::try_into(header.execution.clone())
+            .unwrap()
+            .tree_hash_root();
+    }
+
     // No new field in electra
     if let Some(fork) = fs.fork(Forks::Electra)
         && epoch >= fork.epoch
@@ -343,15 +524,17 @@ pub fn is_valid_light_client_header<C: ChainSpec>(
     )?)
 }
 
+/// Whether `slot` is in electra or any later fork. The beacon state is extended again in fulu, but
+/// the new field does not change the depth of the tree, so the electra gindices still apply.
+fn is_electra_or_later<C: ChainSpec>(chain_id: u64, slot: Slot) -> bool {
+    ForkSchedule::for_chain_id(chain_id)
+        .fork(Forks::Electra)
+        .is_some_and(|fork| compute_epoch_at_slot::<C>(slot) >= fork.epoch)
+}
+
 /// <https://github.com/ethereum/consensus-specs/blob/dev/specs/altair/light-client/sync-protocol.md#finalized_root_gindex_at_slot>
 pub fn finalized_root_gindex_at_slot<C: ChainSpec>(chain_id: u64, slot: Slot) -> u64 {
-    let fs = ForkSchedule::for_chain_id(chain_id);
-
-    let epoch = compute_epoch_at_slot::<C>(slot);
-
-    if let Some(fork) = fs.fork(Forks::Electra)
-        && epoch >= fork.epoch
-    {
+    if is_electra_or_later::<C>(chain_id, slot) {
         return FINALIZED_ROOT_GINDEX_ELECTRA;
     }
 
@@ -360,13 +543,7 @@ pub fn finalized_root_gindex_at_slot<C: ChainSpec>(chain_id: u64, slot: Slot) ->
 
 /// <https://github.com/ethereum/consensus-specs/blob/dev/specs/altair/light-client/sync-protocol.md#current_sync_committee_gindex_at_slot>
 pub fn current_sync_committee_gindex_at_slot<C: ChainSpec>(chain_id: u64, slot: Slot) -> u64 {
-    let fs = ForkSchedule::for_chain_id(chain_id);
-
-    let epoch = compute_epoch_at_slot::<C>(slot);
-
-    if let Some(fork) = fs.fork(Forks::Electra)
-        && epoch >= fork.epoch
-    {
+    if is_electra_or_later::<C>(chain_id, slot) {
         return CURRENT_SYNC_COMMITTEE_GINDEX_ELECTRA;
     }
 
@@ -375,13 +552,7 @@ pub fn current_sync_committee_gindex_at_slot<C: ChainSpec>(chain_id: u64, slot:
 
 /// <https://github.com/ethereum/consensus-specs/blob/dev/specs/altair/light-client/sync-protocol.md#next_sync_committee_gindex_at_slot>
 pub fn next_sync_committee_gindex_at_slot<C: ChainSpec>(chain_id: u64, slot: Slot) -> u64 {
-    let fs = ForkSchedule::for_chain_id(chain_id);
-
-    let epoch = compute_epoch_at_slot::<C>(slot);
-
-    if let Some(fork) = fs.fork(Forks::Electra)
-        && epoch >= fork.epoch
-    {
+    if is_electra_or_later::<C>(chain_id, slot) {
         return NEXT_SYNC_COMMITTEE_GINDEX_ELECTRA;
     }
 
@@ -390,7 +561,7 @@ pub fn next_sync_committee_gindex_at_slot<C: ChainSpec>(chain_id: u64, slot: Slo
 
 #[cfg(test)]
 mod tests {
-    use beacon_api_types::chain_spec::Mainnet;
+    use beacon_api_types::{chain_spec::Mainnet, custom_types::Version};
     use hex_literal::hex;
 
     use super::*;
@@ -697,6 +868,153 @@ mod tests {
         ));
     }
 
+    fn optimistic_update_6553725() -> LightClientOptimisticUpdate {
+        LightClientOptimisticUpdate {
+            attested_header: data_6553725::UPDATE.attested_header.clone(),
+            sync_aggregate: data_6553725::UPDATE.sync_aggregate.clone(),
+            signature_slot: data_6553725::UPDATE.signature_slot,
+        }
+    }
+
+    #[test]
+    fn validate_optimistic_update_works() {
+        assert_eq!(
+            validate_light_client_optimistic_update::<Mainnet, _>(
+                SEPOLIA_CHAIN_ID,
+                &optimistic_update_6553725(),
+                &data_6553725::SYNC_COMMITTEE,
+                Slot::new(6553726),
+                Slot::new(6553718),
+                data_6553725::GENESIS_VALIDATORS_ROOT,
+                AlwaysSuccessBlsVerifier,
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn validate_optimistic_update_fails_irrelevant_update() {
+        let update = optimistic_update_6553725();
+
+        assert_eq!(
+            validate_light_client_optimistic_update::<Mainnet, _>(
+                SEPOLIA_CHAIN_ID,
+                &update,
+                &data_6553725::SYNC_COMMITTEE,
+                Slot::new(6553726),
+                update.attested_header.beacon.slot,
+                data_6553725::GENESIS_VALIDATORS_ROOT,
+                AlwaysSuccessBlsVerifier,
+            ),
+            Err(Error::IrrelevantOptimisticUpdate {
+                update_attested_slot: update.attested_header.beacon.slot,
+                trusted_slot: update.attested_header.beacon.slot,
+            })
+        );
+    }
+
+    #[test]
+    fn validate_optimistic_update_slot_ordering_checks() {
+        let mut update = optimistic_update_6553725();
+        update.signature_slot = update.attested_header.beacon.slot;
+
+        assert!(matches!(
+            validate_light_client_optimistic_update::<Mainnet, _>(
+                SEPOLIA_CHAIN_ID,
+                &update,
+                &data_6553725::SYNC_COMMITTEE,
+                Slot::new(6553726),
+                Slot::new(6553718),
+                data_6553725::GENESIS_VALIDATORS_ROOT,
+                AlwaysSuccessBlsVerifier,
+            ),
+            Err(Error::InvalidOptimisticSlots { .. })
+        ));
+
+        update.signature_slot = Slot::new(6553727);
+
+        assert!(matches!(
+            validate_light_client_optimistic_update::<Mainnet, _>(
+                SEPOLIA_CHAIN_ID,
+                &update,
+                &data_6553725::SYNC_COMMITTEE,
+                Slot::new(6553726),
+                Slot::new(6553718),
+                data_6553725::GENESIS_VALIDATORS_ROOT,
+                AlwaysSuccessBlsVerifier,
+            ),
+            Err(Error::UpdateMoreRecentThanCurrentSlot { .. })
+        ));
+    }
+
+    #[test]
+    fn is_better_update_prefers_participation() {
+        let update = data_6553725::UPDATE.clone();
+
+        let mut full_participation = update.clone();
+        full_participation.sync_aggregate.sync_committee_bits = [0xFF; 64].into();
+
+        assert_eq!(
+            is_better_update::<Mainnet>(&full_participation, &update),
+            Ok(true)
+        );
+        assert_eq!(
+            is_better_update::<Mainnet>(&update, &full_participation),
+            Ok(false)
+        );
+
+        // no supermajority is always worse than a supermajority
+        let mut low_participation = update.clone();
+        low_participation.sync_aggregate.sync_committee_bits =
+            [[0xFF; 16].as_slice(), [0x00; 48].as_slice()]
+                .concat()
+                .into();
+
+        assert_eq!(
+            is_better_update::<Mainnet>(&update, &low_participation),
+            Ok(true)
+        );
+        assert_eq!(
+            is_better_update::<Mainnet>(&low_participation, &update),
+            Ok(false)
+        );
+    }
+
+    #[test]
+    fn is_better_update_tiebreakers() {
+        let update = data_6553725::UPDATE.clone();
+
+        // equal updates are not better than each other
+        assert_eq!(is_better_update::<Mainnet>(&update, &update), Ok(false));
+
+        // prefer updates with a relevant sync committee
+        let mut without_sync_committee = update.clone();
+        without_sync_committee.next_sync_committee = None;
+        without_sync_committee.next_sync_committee_branch = None;
+
+        assert_eq!(
+            is_better_update::<Mainnet>(&update, &without_sync_committee),
+            Ok(true)
+        );
+
+        // prefer older attested headers
+        let mut newer = update.clone();
+        newer.attested_header.beacon.slot = update.attested_header.beacon.slot + Slot::new(1);
+        newer.signature_slot = update.signature_slot + Slot::new(1);
+
+        assert_eq!(is_better_update::<Mainnet>(&update, &newer), Ok(true));
+        assert_eq!(is_better_update::<Mainnet>(&newer, &update), Ok(false));
+
+        // prefer earlier signature slots
+        let mut later_signature = update.clone();
+        later_signature.signature_slot = update.signature_slot + Slot::new(1);
+
+        assert_eq!(
+            is_better_update::<Mainnet>(&update, &later_signature),
+            Ok(true)
+        );
+    }
+
     #[test]
     fn finalized_groot_index_correct() {
         assert_eq!(
@@ -725,4 +1043,38 @@ mod tests {
             CURRENT_SYNC_COMMITTEE_GINDEX_ELECTRA
         );
     }
+
+    #[test]
+    fn fulu_gindices_and_fork_version() {
+        // first slot of the fulu fork on sepolia
+        let slot = Slot::new(272640 * 32);
+
+        assert_eq!(
+            finalized_root_gindex_at_slot::<Mainnet>(SEPOLIA_CHAIN_ID, slot),
+            FINALIZED_ROOT_GINDEX_ELECTRA
+        );
+
+        assert_eq!(
+            current_sync_committee_gindex_at_slot::<Mainnet>(SEPOLIA_CHAIN_ID, slot),
+            CURRENT_SYNC_COMMITTEE_GINDEX_ELECTRA
+        );
+
+        assert_eq!(
+            next_sync_committee_gindex_at_slot::<Mainnet>(SEPOLIA_CHAIN_ID, slot),
+            NEXT_SYNC_COMMITTEE_GINDEX_ELECTRA
+        );
+
+        assert_eq!(
+            compute_fork_version(SEPOLIA_CHAIN_ID, compute_epoch_at_slot::<Mainnet>(slot)),
+            Version(hex!("90000075").into())
+        );
+
+        assert_eq!(
+            compute_fork_version(
+                SEPOLIA_CHAIN_ID,
+                compute_epoch_at_slot::<Mainnet>(Slot::new(slot.get() - 1))
+            ),
+            Version(hex!("90000074").into())
+        );
+    }
 }
@@ -14,11 +14,15 @@ use crate::{error::InvalidMerkleBranch, GENESIS_SLOT};
 
 /// Returns the fork version based on the `epoch` and `chain_id`.
 ///
-/// [See in consensus-spec](https://github.com/ethereum/consensus-specs/blob/dev/specs/electra/fork.md#modified-compute_fork_version)
+/// [See in consensus-spec](https://github.com/ethereum/consensus-specs/blob/dev/specs/fulu/fork.md#modified-compute_fork_version)
 pub fn compute_fork_version(chain_id: u64, epoch: Epoch) -> Version {
     let fs = ForkSchedule::for_chain_id(chain_id);
 
-    if let Some(fork) = fs.fork(Forks::Electra)
+    if let Some(fork) = fs.fork(Forks::Fulu)
+        && epoch >= fork.epoch
+    {
+        fork.current_version
+    } else if let Some(fork) = fs.fork(Forks::Electra)
         && epoch >= fork.epoch
     {
         fork.current_version
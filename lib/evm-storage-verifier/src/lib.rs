@@ -1,7 +1,7 @@
 use error::Error;
 use hash_db::HashDB;
 use memory_db::{HashKey, MemoryDB};
-use rlp::RlpDecodable;
+use rlp::{RlpDecodable, RlpEncodable};
 use trie_db::{Trie, TrieDBBuilder};
 use unionlabs::{
     ensure,
@@ -12,6 +12,8 @@ use crate::rlp_node_codec::{keccak_256, EthLayout, KeccakHasher};
 
 pub mod error;
 mod rlp_node_codec;
+#[cfg(any(feature = "test-utils", test))]
+pub mod test_utils;
 
 pub const GENESIS_SLOT: u64 = 0;
 pub const GENESIS_EPOCH: u64 = 0;
@@ -20,7 +22,7 @@ pub const FAR_FUTURE_EPOCH: u64 = u64::MAX;
 pub const BLS_WITHDRAWAL_PREFIX: u8 = 0x00;
 pub const ETH1_ADDRESS_WITHDRAWAL_PREFIX: u8 = 0x01;
 
-#[derive(Debug, Clone, RlpDecodable)]
+#[derive(Debug, Clone, RlpEncodable, RlpDecodable)]
 pub struct Account {
     pub nonce: u64,
     pub balance: U256,
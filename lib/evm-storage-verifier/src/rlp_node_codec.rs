@@ -218,9 +218,13 @@ impl NodeCodec for RlpNodeCodec<KeccakHasher> {
         &[0x80]
     }
 
-    fn leaf_node(partial: impl Iterator<Item = u8>, _: usize, value: Value) -> Vec<u8> {
+    fn leaf_node(partial: impl Iterator<Item = u8>, number_nibble: usize, value: Value) -> Vec<u8> {
         let mut stream = RlpStream::new_list(2);
-        stream.append_iter(partial.collect::<Vec<_>>());
+        stream.append_iter(encode_partial_from_iterator_iter(
+            partial,
+            number_nibble % 2 > 0,
+            true,
+        ));
         stream.append(&match value {
             Value::Inline(v) => v,
             Value::Node(v) => v,
@@ -290,3 +294,55 @@ impl NodeCodec for RlpNodeCodec<KeccakHasher> {
         unreachable!("This codec is only used with a trie Layout that uses extension node.")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use hex_literal::hex;
+    use memory_db::{HashKey, MemoryDB};
+    use trie_db::{TrieDBMutBuilder, TrieMut};
+
+    use super::*;
+
+    fn root(entries: &[(&str, &str)]) -> H256 {
+        let mut db = MemoryDB::<KeccakHasher, HashKey<_>, Vec<u8>>::new(&rlp::NULL_RLP);
+        let mut root = H256::default();
+
+        let mut trie = TrieDBMutBuilder::<EthLayout>::new(&mut db, &mut root).build();
+        for (key, value) in entries {
+            trie.insert(key.as_bytes(), value.as_bytes()).unwrap();
+        }
+        drop(trie);
+
+        root
+    }
+
+    // the leaf partial path must be hex-prefix encoded (with the leaf flag and the parity of the
+    // path), otherwise the built trie does not match the one built by ethereum clients and the
+    // proofs generated from it cannot be decoded by `decode_plan`
+    #[test]
+    fn leaf_node_matches_ethereum_trie_roots() {
+        // https://github.com/ethereum/tests/blob/develop/TrieTests/trieanyorder.json
+        assert_eq!(
+            root(&[
+                ("do", "verb"),
+                ("horse", "stallion"),
+                ("doge", "coin"),
+                ("dog", "puppy"),
+            ]),
+            <H256>::new(hex!(
+                "5991bb8c6514148a29db676a14ac506cd2cd5775ace63c30a4fe457715e9ac84"
+            ))
+        );
+
+        assert_eq!(
+            root(&[
+                ("doe", "reindeer"),
+                ("dog", "puppy"),
+                ("dogglesworth", "cat")
+            ]),
+            <H256>::new(hex!(
+                "8aad789dff2f538bca5d8ea56e8abe10f4c7ba3a5dea95fea4cd6e7c3a1168d3"
+            ))
+        );
+    }
+}
@@ -0,0 +1,156 @@
+use memory_db::{HashKey, MemoryDB};
+use trie_db::{Recorder, Trie, TrieDBBuilder, TrieDBMutBuilder, TrieMut};
+use unionlabs::primitives::{Bytes, H160, H256, U256};
+
+use crate::{
+    rlp_node_codec::{keccak_256, EthLayout, KeccakHasher},
+    Account,
+};
+
+/// An in-memory merkle patricia trie, laid out the same way as the ethereum state and storage
+/// tries (i.e. keyed by the keccak256 hash of the key).
+///
+/// This is intended to be used to build state roots and proofs for tests, in place of fetching
+/// them from a live chain.
+pub struct TestTrie {
+    db: MemoryDB<KeccakHasher, HashKey<KeccakHasher>, Vec<u8>>,
+    root: H256,
+}
+
+impl TestTrie {
+    /// Build a trie containing all of the provided `(key, value)` entries.
+    pub fn new<K: AsRef<[u8]>, V: AsRef<[u8]>>(entries: impl IntoIterator<Item = (K, V)>) -> Self {
+        // the empty trie is the rlp encoding of the empty string
+        let mut db = MemoryDB::<KeccakHasher, HashKey<_>, Vec<u8>>::new(&rlp::NULL_RLP);
+        let mut root = H256::default();
+
+        {
+            let mut trie = TrieDBMutBuilder::<EthLayout>::new(&mut db, &mut root).build();
+            for (key, value) in entries {
+                trie.insert(&keccak_256(key.as_ref()), value.as_ref())
+                    .expect("trie insertion is infallible for an in-memory db; qed;");
+            }
+        }
+
+        Self { db, root }
+    }
+
+    /// Build a storage trie containing all of the provided `(slot, value)` entries.
+    pub fn storage(slots: impl IntoIterator<Item = (U256, U256)>) -> Self {
+        Self::new(
+            slots
+                .into_iter()
+                .map(|(slot, value)| (slot.to_be_bytes(), rlp::encode(&value))),
+        )
+    }
+
+    /// Build a state trie containing all of the provided `(address, account)` entries.
+    pub fn state(accounts: impl IntoIterator<Item = (H160, Account)>) -> Self {
+        Self::new(
+            accounts
+                .into_iter()
+                .map(|(address, account)| (address, rlp::encode(&account))),
+        )
+    }
+
+    pub fn root(&self) -> H256 {
+        self.root
+    }
+
+    /// Generate a proof of the value stored at `key`, in the format expected by the verification
+    /// functions in this crate.
+    pub fn proof(&self, key: impl AsRef<[u8]>) -> Vec<Bytes> {
+        let mut recorder = Recorder::<EthLayout>::new();
+
+        TrieDBBuilder::<EthLayout>::new(&self.db, &self.root)
+            .with_recorder(&mut recorder)
+            .build()
+            .get(&keccak_256(key.as_ref()))
+            .expect("trie lookups are infallible for an in-memory db; qed;");
+
+        recorder
+            .drain()
+            .into_iter()
+            .map(|record| record.data.into())
+            .collect()
+    }
+
+    /// Generate a proof of the value stored at `slot` in a storage trie.
+    pub fn storage_proof(&self, slot: U256) -> Vec<Bytes> {
+        self.proof(slot.to_be_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        verify_account_code_hash, verify_account_storage_root, verify_storage_absence,
+        verify_storage_proof,
+    };
+
+    #[test]
+    fn storage_proofs_verify() {
+        let slots = (0..64_u64).map(|i| (U256::from(i), U256::from(i * 1000 + 1)));
+        let trie = TestTrie::storage(slots.clone());
+
+        for (slot, value) in slots {
+            verify_storage_proof(
+                trie.root(),
+                slot,
+                &rlp::encode(&value),
+                trie.storage_proof(slot),
+            )
+            .unwrap();
+        }
+
+        assert_eq!(
+            verify_storage_absence(
+                trie.root(),
+                U256::from(1000_u64),
+                trie.storage_proof(U256::from(1000_u64))
+            ),
+            Ok(true)
+        );
+    }
+
+    #[test]
+    fn account_proofs_verify() {
+        let address = H160::new([0xAA; 20]);
+        let account = Account {
+            nonce: 1,
+            balance: U256::from(100_u64),
+            storage_root: H256::new([1; 32]),
+            code_hash: H256::new([2; 32]),
+        };
+
+        let trie = TestTrie::state([
+            (address, account.clone()),
+            (
+                H160::new([0xBB; 20]),
+                Account {
+                    nonce: 0,
+                    balance: U256::ZERO,
+                    storage_root: H256::new([3; 32]),
+                    code_hash: H256::new([4; 32]),
+                },
+            ),
+        ]);
+
+        verify_account_storage_root(
+            trie.root(),
+            &address,
+            trie.proof(address),
+            &account.storage_root,
+        )
+        .unwrap();
+
+        verify_account_code_hash(
+            trie.root(),
+            &address,
+            trie.proof(address),
+            &account.code_hash,
+        )
+        .unwrap();
+    }
+}
@@ -167,11 +167,11 @@ impl ForkSchedule {
     /// Note that this will return `None` if the fork schedule has not yet been updated to the specified fork:
     ///
     /// ```rust
-    /// # use fork_schedules::{MAINNET, Forks};
+    /// # use fork_schedules::{LATEST, Forks};
     /// # use hex_literal::hex;
     /// # use beacon_api_types::custom_types::Version;
     /// assert_eq!(
-    ///     MAINNET.fork(Forks::Fulu),
+    ///     LATEST.fork(Forks::Fulu),
     ///     None
     /// );
     /// ```
@@ -235,6 +235,7 @@ pub const MAINNET: _ = [
     ("03000000", 194048), // capella
     ("04000000", 269568), // deneb
     ("05000000", 364032), // electra
+    ("06000000", 411392), // fulu
 ];
 
 /// Mainnet chain id.
@@ -253,6 +254,7 @@ pub const SEPOLIA: _ = [
     ("90000072", 56832),  // capella
     ("90000073", 132608), // deneb
     ("90000074", 222464), // electra
+    ("90000075", 272640), // fulu
 ];
 
 /// Sepolia chain id.
@@ -271,6 +273,7 @@ pub const HOLESKY: _ = [
     ("04017000", 256),    // capella
     ("05017000", 29696),  // deneb
     ("06017000", 115968), // electra
+    ("07017000", 165120), // fulu
 ];
 
 /// Holesky chain id.
@@ -330,6 +333,11 @@ mod tests {
                     current_version: Version(hex!("05000000").into()),
                     epoch: Epoch::new(364032),
                 },
+                Fork {
+                    previous_version: Version(hex!("05000000").into()),
+                    current_version: Version(hex!("06000000").into()),
+                    epoch: Epoch::new(411392),
+                },
             ]
         );
     }
@@ -371,10 +379,37 @@ mod tests {
                 epoch: Epoch::new(364032),
             }),
         );
+
+        assert_eq!(
+            MAINNET.fork(Forks::Fulu),
+            Some(Fork {
+                previous_version: Version(hex!("05000000").into()),
+                current_version: Version(hex!("06000000").into()),
+                epoch: Epoch::new(411392),
+            }),
+        );
+
+        assert_eq!(
+            SEPOLIA.fork(Forks::Fulu),
+            Some(Fork {
+                previous_version: Version(hex!("90000074").into()),
+                current_version: Version(hex!("90000075").into()),
+                epoch: Epoch::new(272640),
+            }),
+        );
+
+        assert_eq!(
+            HOLESKY.fork(Forks::Fulu),
+            Some(Fork {
+                previous_version: Version(hex!("06017000").into()),
+                current_version: Version(hex!("07017000").into()),
+                epoch: Epoch::new(165120),
+            }),
+        );
     }
 
     #[test]
     fn test_len() {
-        assert_eq!(MAINNET.len(), 6);
+        assert_eq!(MAINNET.len(), 7);
     }
 }
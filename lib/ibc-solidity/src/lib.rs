@@ -568,6 +568,10 @@ maybe_sol_attr! {
         }
 
         interface ILightClient {
+            function getLatestHeight(
+                uint32 client_id
+            ) external view returns (uint64 height);
+
             function getClientState(
                 uint32 client_id
             ) external view returns (bytes memory);
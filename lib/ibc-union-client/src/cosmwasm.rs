@@ -0,0 +1,461 @@
+use std::collections::BTreeSet;
+
+use alloy_sol_types::SolValue;
+use cometbft_rpc::{
+    rpc_types::{GrpcAbciQueryError, TxResponse},
+    types::abci::event::Event,
+    JsonRpcError,
+};
+use cosmos_client::{
+    gas::GasFillerT, rpc::RpcT, wallet::WalletT, BroadcastTxCommitError, TxClient,
+};
+use cosmos_sdk_event::CosmosSdkEvent;
+use ibc_union_msg::{
+    msg::{ExecuteMsg, MsgCreateClient, MsgRegisterClient},
+    query::QueryMsg,
+};
+use ibc_union_spec::{Channel, ChannelId, ClientId, Connection, ConnectionId, Packet, Timestamp};
+use protos::cosmwasm::wasm::v1::{QuerySmartContractStateRequest, QuerySmartContractStateResponse};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use ucs03_zkgm::com::Instruction;
+use unionlabs::{
+    cosmos::base::coin::Coin,
+    cosmwasm::wasm::msg_execute_contract::MsgExecuteContract,
+    google::protobuf::any::Any,
+    primitives::{Bech32, Bytes, H256},
+};
+
+/// A typed client for an `ibc-union` deployment on a cosmwasm chain.
+///
+/// Messages are sent by the wallet of the wrapped [`TxClient`], or by its authz granter if one is
+/// configured.
+pub struct Client<W, Q, G> {
+    tx_client: TxClient<W, Q, G>,
+    ibc_host: Bech32<H256>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("error broadcasting transaction")]
+    BroadcastTxCommit(#[from] BroadcastTxCommitError),
+    #[error("jsonrpc error")]
+    JsonRpc(#[from] JsonRpcError),
+    #[error("smart query failed")]
+    Query(#[from] GrpcAbciQueryError),
+    #[error("smart query returned an empty response")]
+    NoResponse,
+    #[error("unable to deserialize smart query response")]
+    Deserialize(#[from] serde_json::Error),
+    #[error("{0} event not found in tx {1}")]
+    EventNotFound(&'static str, H256),
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", content = "attributes")]
+enum IbcEvent {
+    #[serde(rename = "wasm-create_client")]
+    CreateClient {
+        #[serde(with = "serde_utils::string")]
+        client_id: ClientId,
+    },
+    #[serde(rename = "wasm-packet_send")]
+    PacketSend { packet_hash: H256 },
+}
+
+impl<W, Q, G> Client<W, Q, G> {
+    /// Create a new client for the `ibc-union` contract at `ibc_host`.
+    pub fn new(tx_client: TxClient<W, Q, G>, ibc_host: Bech32<H256>) -> Self {
+        Self {
+            tx_client,
+            ibc_host,
+        }
+    }
+
+    pub fn tx_client(&self) -> &TxClient<W, Q, G> {
+        &self.tx_client
+    }
+
+    pub fn ibc_host(&self) -> &Bech32<H256> {
+        &self.ibc_host
+    }
+}
+
+impl<W: WalletT, Q: RpcT, G: GasFillerT> Client<W, Q, G> {
+    /// The sender of all executed messages.
+    pub fn sender(&self) -> Bech32<Bytes> {
+        self.tx_client.authz_granter().cloned().unwrap_or_else(|| {
+            self.tx_client
+                .wallet()
+                .address()
+                .map_data(|address| address.into())
+        })
+    }
+
+    /// Execute `msg` on `contract`, attaching `funds`.
+    pub async fn execute(
+        &self,
+        contract: Bech32<H256>,
+        msg: &impl Serialize,
+        funds: Vec<Coin>,
+    ) -> Result<TxResponse, Error> {
+        Ok(self
+            .tx_client
+            .broadcast_tx_commit(
+                [Any(MsgExecuteContract {
+                    sender: self.sender(),
+                    contract,
+                    msg: serde_json::to_vec(msg)
+                        .expect("serialization is infallible; qed;")
+                        .into(),
+                    funds,
+                })],
+                "",
+                true,
+            )
+            .await?)
+    }
+
+    /// Run the smart query `msg` against `contract`.
+    pub async fn query<R: DeserializeOwned>(
+        &self,
+        contract: &Bech32<H256>,
+        msg: &impl Serialize,
+    ) -> Result<R, Error> {
+        let response = self
+            .tx_client
+            .rpc()
+            .client()
+            .grpc_abci_query::<_, QuerySmartContractStateResponse>(
+                "/cosmwasm.wasm.v1.Query/SmartContractState",
+                &QuerySmartContractStateRequest {
+                    address: contract.to_string(),
+                    query_data: serde_json::to_vec(msg).expect("serialization is infallible; qed;"),
+                },
+                None,
+                false,
+            )
+            .await?
+            .into_result()?
+            .ok_or(Error::NoResponse)?;
+
+        Ok(serde_json::from_slice(&response.data)?)
+    }
+
+    /// Register `client_address` as the light client implementation for `client_type`. This must
+    /// be sent by the relayer admin.
+    pub async fn register_client(
+        &self,
+        client_type: impl Into<String>,
+        client_address: Bech32<H256>,
+    ) -> Result<TxResponse, Error> {
+        self.execute(
+            self.ibc_host.clone(),
+            &ExecuteMsg::RegisterClient(MsgRegisterClient {
+                client_type: client_type.into(),
+                client_address: client_address.to_string(),
+            }),
+            vec![],
+        )
+        .await
+    }
+
+    /// Create a new client of `client_type`, returning the id of the created client.
+    pub async fn create_client(
+        &self,
+        client_type: impl Into<String>,
+        client_state_bytes: Bytes,
+        consensus_state_bytes: Bytes,
+    ) -> Result<ClientId, Error> {
+        let response = self
+            .execute(
+                self.ibc_host.clone(),
+                &ExecuteMsg::CreateClient(MsgCreateClient {
+                    client_type: client_type.into(),
+                    client_state_bytes,
+                    consensus_state_bytes,
+                    relayer: self.sender().to_string(),
+                }),
+                vec![],
+            )
+            .await?;
+
+        find_event(
+            &self.ibc_host,
+            &response.tx_result.events,
+            |event| match event {
+                IbcEvent::CreateClient { client_id } => Some(client_id),
+                IbcEvent::PacketSend { .. } => None,
+            },
+        )
+        .ok_or(Error::EventNotFound(
+            "create_client",
+            response.hash.into_encoding(),
+        ))
+    }
+
+    /// Send `instruction` through the `ucs03-zkgm` contract at `zkgm`, returning the hash of the
+    /// sent packet.
+    pub async fn send(
+        &self,
+        zkgm: Bech32<H256>,
+        channel_id: ChannelId,
+        timeout_timestamp: Timestamp,
+        salt: H256,
+        instruction: &Instruction,
+        funds: Vec<Coin>,
+    ) -> Result<H256, Error> {
+        let response = self
+            .execute(
+                zkgm,
+                &ucs03_zkgm::msg::ExecuteMsg::Send {
+                    channel_id,
+                    timeout_height: 0_u64.into(),
+                    timeout_timestamp,
+                    salt,
+                    instruction: instruction.abi_encode_params().into(),
+                },
+                funds,
+            )
+            .await?;
+
+        find_event(
+            &self.ibc_host,
+            &response.tx_result.events,
+            |event| match event {
+                IbcEvent::PacketSend { packet_hash } => Some(packet_hash),
+                IbcEvent::CreateClient { .. } => None,
+            },
+        )
+        .ok_or(Error::EventNotFound(
+            "packet_send",
+            response.hash.into_encoding(),
+        ))
+    }
+
+    pub async fn client_type(&self, client_id: ClientId) -> Result<String, Error> {
+        self.query(&self.ibc_host, &QueryMsg::GetClientType { client_id })
+            .await
+    }
+
+    pub async fn latest_height(&self, client_id: ClientId) -> Result<u64, Error> {
+        self.query(&self.ibc_host, &QueryMsg::GetLatestHeight { client_id })
+            .await
+    }
+
+    pub async fn client_state(&self, client_id: ClientId) -> Result<Bytes, Error> {
+        self.query(&self.ibc_host, &QueryMsg::GetClientState { client_id })
+            .await
+    }
+
+    pub async fn consensus_state(&self, client_id: ClientId, height: u64) -> Result<Bytes, Error> {
+        self.query(
+            &self.ibc_host,
+            &QueryMsg::GetConsensusState { client_id, height },
+        )
+        .await
+    }
+
+    pub async fn connection(&self, connection_id: ConnectionId) -> Result<Connection, Error> {
+        self.query(&self.ibc_host, &QueryMsg::GetConnection { connection_id })
+            .await
+    }
+
+    pub async fn channel(&self, channel_id: ChannelId) -> Result<Channel, Error> {
+        self.query(&self.ibc_host, &QueryMsg::GetChannel { channel_id })
+            .await
+    }
+
+    /// The channels owned by `contract`.
+    pub async fn channels(&self, contract: &Bech32<H256>) -> Result<BTreeSet<ChannelId>, Error> {
+        self.query(
+            &self.ibc_host,
+            &QueryMsg::GetChannels {
+                contract: contract.to_string(),
+            },
+        )
+        .await
+    }
+
+    /// The commitment of the batch of sent packets with hash `batch_hash`, if it exists.
+    pub async fn batch_packets(&self, batch_hash: H256) -> Result<Option<H256>, Error> {
+        self.query(&self.ibc_host, &QueryMsg::GetBatchPackets { batch_hash })
+            .await
+    }
+
+    /// The receipt of the batch of received packets with hash `batch_hash`, if it exists.
+    pub async fn batch_receipts(&self, batch_hash: H256) -> Result<Option<H256>, Error> {
+        self.query(&self.ibc_host, &QueryMsg::GetBatchReceipts { batch_hash })
+            .await
+    }
+
+    /// The commitment of `packet`, if it has been sent and not yet acknowledged or timed out.
+    pub async fn packet_commitment(&self, packet: &Packet) -> Result<Option<H256>, Error> {
+        self.batch_packets(packet.hash()).await
+    }
+
+    /// The receipt of `packet`, if it has been received.
+    pub async fn packet_receipt(&self, packet: &Packet) -> Result<Option<H256>, Error> {
+        self.batch_receipts(packet.hash()).await
+    }
+}
+
+/// Find the first event emitted by `ibc_host` in `events` for which `f` returns `Some`.
+fn find_event<T>(
+    ibc_host: &Bech32<H256>,
+    events: &[Event],
+    f: impl Fn(IbcEvent) -> Option<T>,
+) -> Option<T> {
+    events.iter().find_map(|event| {
+        CosmosSdkEvent::<IbcEvent>::new(event.clone())
+            .ok()
+            .filter(|event| event.contract_address.as_ref() == Some(ibc_host))
+            .and_then(|event| f(event.event))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use cometbft_rpc::types::abci::event_attribute::EventAttribute;
+    use serde_json::json;
+
+    use super::*;
+
+    fn event(ty: &str, contract: &Bech32<H256>, attributes: &[(&str, &str)]) -> Event {
+        Event {
+            ty: ty.to_owned(),
+            attributes: [("_contract_address", &*contract.to_string())]
+                .iter()
+                .chain(attributes)
+                .map(|(key, value)| EventAttribute {
+                    key: (*key).to_owned(),
+                    value: (*value).to_owned(),
+                    index: true,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn execute_msg_json() {
+        assert_eq!(
+            serde_json::to_value(ExecuteMsg::CreateClient(MsgCreateClient {
+                client_type: "cometbls".to_owned(),
+                client_state_bytes: vec![1, 2].into(),
+                consensus_state_bytes: vec![3].into(),
+                relayer: "union1relayer".to_owned(),
+            }))
+            .unwrap(),
+            json!({
+                "create_client": {
+                    "client_type": "cometbls",
+                    "client_state_bytes": "0x0102",
+                    "consensus_state_bytes": "0x03",
+                    "relayer": "union1relayer",
+                }
+            })
+        );
+
+        assert_eq!(
+            serde_json::to_value(ucs03_zkgm::msg::ExecuteMsg::Send {
+                channel_id: ChannelId!(1),
+                timeout_height: 0_u64.into(),
+                timeout_timestamp: Timestamp::from_nanos(1000),
+                salt: <H256>::new([0xaa; 32]),
+                instruction: vec![4, 5].into(),
+            })
+            .unwrap(),
+            json!({
+                "send": {
+                    "channel_id": 1,
+                    "timeout_height": "0",
+                    "timeout_timestamp": "1000",
+                    "salt": format!("0x{}", "aa".repeat(32)),
+                    "instruction": "0x0405",
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn query_msg_json() {
+        assert_eq!(
+            serde_json::to_value(QueryMsg::GetConsensusState {
+                client_id: ClientId!(1),
+                height: 10,
+            })
+            .unwrap(),
+            json!({ "get_consensus_state": { "client_id": 1, "height": 10 } })
+        );
+
+        assert_eq!(
+            serde_json::to_value(QueryMsg::GetChannels {
+                contract: "union1zkgm".to_owned(),
+            })
+            .unwrap(),
+            json!({ "get_channels": { "contract": "union1zkgm" } })
+        );
+
+        assert_eq!(
+            serde_json::to_value(QueryMsg::GetBatchReceipts {
+                batch_hash: <H256>::new([0xbb; 32]),
+            })
+            .unwrap(),
+            json!({ "get_batch_receipts": { "batch_hash": format!("0x{}", "bb".repeat(32)) } })
+        );
+    }
+
+    #[test]
+    fn find_events() {
+        let ibc_host = Bech32::new("union".to_owned(), <H256>::new([1; 32]));
+        let other = Bech32::new("union".to_owned(), <H256>::new([2; 32]));
+
+        let own_hash = <H256>::new([0xcc; 32]).to_string();
+        let other_hash = <H256>::new([0xdd; 32]).to_string();
+
+        let events = [
+            Event {
+                ty: "message".to_owned(),
+                attributes: vec![],
+            },
+            event(
+                "wasm-packet_send",
+                &other,
+                &[("channel_id", "1"), ("packet_hash", &other_hash)],
+            ),
+            event(
+                "wasm-create_client",
+                &ibc_host,
+                &[("client_type", "cometbls"), ("client_id", "7")],
+            ),
+            event(
+                "wasm-packet_send",
+                &ibc_host,
+                &[("channel_id", "1"), ("packet_hash", &own_hash)],
+            ),
+        ];
+
+        assert_eq!(
+            find_event(&ibc_host, &events, |event| match event {
+                IbcEvent::CreateClient { client_id } => Some(client_id),
+                IbcEvent::PacketSend { .. } => None,
+            }),
+            Some(ClientId!(7))
+        );
+
+        assert_eq!(
+            find_event(&ibc_host, &events, |event| match event {
+                IbcEvent::PacketSend { packet_hash } => Some(packet_hash),
+                IbcEvent::CreateClient { .. } => None,
+            }),
+            Some(<H256>::new([0xcc; 32]))
+        );
+
+        assert_eq!(
+            find_event(&other, &events, |event| match event {
+                IbcEvent::CreateClient { client_id } => Some(client_id),
+                IbcEvent::PacketSend { .. } => None,
+            }),
+            None
+        );
+    }
+}
@@ -0,0 +1,400 @@
+use std::collections::BTreeSet;
+
+use alloy::{
+    contract::SolCallBuilder,
+    network::{AnyNetwork, AnyTransactionReceipt},
+    providers::{PendingTransactionError, Provider},
+    rpc::types::{Log, TransactionInput, TransactionRequest},
+    serde::WithOtherFields,
+    sol_types::{SolCall, SolEvent, SolValue},
+    transports::TransportError,
+};
+use ibc_solidity::{
+    compat::{InvalidChannelError, InvalidConnectionError},
+    ChannelState, ConnectionState,
+    ILightClient::{self, ILightClientInstance},
+    Ibc::{self, IbcInstance},
+};
+use ibc_union_spec::{
+    path::{BatchPacketsPath, BatchReceiptsPath},
+    Channel, ChannelId, ClientId, Connection, ConnectionId, Packet, Timestamp,
+};
+use unionlabs::primitives::{Bytes, H160, H256, U256};
+
+/// Bindings for the parts of the `ucs03-zkgm` solidity contract used by [`Client`].
+pub mod zkgm {
+    alloy::sol! {
+        #![sol(rpc)]
+
+        struct Instruction {
+            uint8 version;
+            uint8 opcode;
+            bytes operand;
+        }
+
+        contract UCS03Zkgm {
+            function send(
+                uint32 channelId,
+                uint64 timeoutHeight,
+                uint64 timeoutTimestamp,
+                bytes32 salt,
+                Instruction calldata instruction
+            ) public payable;
+        }
+    }
+
+    impl From<&ucs03_zkgm::com::Instruction> for Instruction {
+        fn from(value: &ucs03_zkgm::com::Instruction) -> Self {
+            Self {
+                version: value.version,
+                opcode: value.opcode,
+                operand: value.operand.clone(),
+            }
+        }
+    }
+}
+
+/// A typed client for an `ibc-union` deployment on an evm chain.
+///
+/// Transactions are signed by the wallet of the provider.
+pub struct Client<P> {
+    provider: P,
+    ibc_handler: H160,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("error calling contract")]
+    Contract(#[from] alloy::contract::Error),
+    #[error("error waiting for transaction receipt")]
+    PendingTransaction(#[from] PendingTransactionError),
+    #[error("rpc error")]
+    Rpc(#[from] TransportError),
+    #[error("unable to decode call result")]
+    Decode(#[from] alloy::sol_types::Error),
+    #[error("invalid connection")]
+    InvalidConnection(#[from] InvalidConnectionError),
+    #[error("invalid channel")]
+    InvalidChannel(#[from] InvalidChannelError),
+    #[error("client {0} not found")]
+    ClientNotFound(ClientId),
+    #[error("tx {0} reverted")]
+    Reverted(H256),
+    #[error("{0} event not found in tx {1}")]
+    EventNotFound(&'static str, H256),
+}
+
+impl<P> Client<P> {
+    /// Create a new client for the `ibc-union` contract at `ibc_handler`.
+    pub fn new(provider: P, ibc_handler: H160) -> Self {
+        Self {
+            provider,
+            ibc_handler,
+        }
+    }
+
+    pub fn provider(&self) -> &P {
+        &self.provider
+    }
+}
+
+impl<P: Provider<AnyNetwork>> Client<P> {
+    pub fn ibc_handler(&self) -> IbcInstance<&P, AnyNetwork> {
+        Ibc::new(self.ibc_handler.into(), &self.provider)
+    }
+
+    /// Create a new client of `client_type`, returning the id of the created client.
+    pub async fn create_client(
+        &self,
+        client_type: impl Into<String>,
+        client_state_bytes: Bytes,
+        consensus_state_bytes: Bytes,
+        relayer: H160,
+    ) -> Result<ClientId, Error> {
+        let receipt = submit(
+            self.ibc_handler()
+                .createClient(ibc_solidity::MsgCreateClient {
+                    client_type: client_type.into(),
+                    client_state_bytes: client_state_bytes.into(),
+                    consensus_state_bytes: consensus_state_bytes.into(),
+                    relayer: relayer.into(),
+                }),
+        )
+        .await?;
+
+        find_event::<Ibc::CreateClient>(self.ibc_handler, receipt.inner.inner.logs())
+            .and_then(|event| ClientId::from_raw(event.client_id))
+            .ok_or(Error::EventNotFound(
+                "CreateClient",
+                receipt.inner.transaction_hash.into(),
+            ))
+    }
+
+    /// Send `instruction` through the `ucs03-zkgm` contract at `zkgm`, returning the hash of the
+    /// sent packet.
+    pub async fn send(
+        &self,
+        zkgm: H160,
+        channel_id: ChannelId,
+        timeout_timestamp: Timestamp,
+        salt: H256,
+        instruction: &ucs03_zkgm::com::Instruction,
+        value: U256,
+    ) -> Result<H256, Error> {
+        let receipt = submit(
+            zkgm::UCS03Zkgm::new(zkgm.into(), &self.provider)
+                .send(
+                    channel_id.raw(),
+                    0,
+                    timeout_timestamp.as_nanos(),
+                    salt.into(),
+                    instruction.into(),
+                )
+                .value(value.into()),
+        )
+        .await?;
+
+        find_event::<Ibc::PacketSend>(self.ibc_handler, receipt.inner.inner.logs())
+            .map(|event| event.packet_hash.into())
+            .ok_or(Error::EventNotFound(
+                "PacketSend",
+                receipt.inner.transaction_hash.into(),
+            ))
+    }
+
+    pub async fn client_type(&self, client_id: ClientId) -> Result<String, Error> {
+        Ok(self
+            .ibc_handler()
+            .clientTypes(client_id.raw())
+            .call()
+            .await?)
+    }
+
+    pub async fn latest_height(&self, client_id: ClientId) -> Result<u64, Error> {
+        Ok(self
+            .light_client(client_id)
+            .await?
+            .getLatestHeight(client_id.raw())
+            .call()
+            .await?)
+    }
+
+    pub async fn client_state(&self, client_id: ClientId) -> Result<Bytes, Error> {
+        Ok(self
+            .light_client(client_id)
+            .await?
+            .getClientState(client_id.raw())
+            .call()
+            .await?
+            .into())
+    }
+
+    pub async fn consensus_state(&self, client_id: ClientId, height: u64) -> Result<Bytes, Error> {
+        Ok(self
+            .light_client(client_id)
+            .await?
+            .getConsensusState(client_id.raw(), height)
+            .call()
+            .await?
+            .into())
+    }
+
+    pub async fn connection(
+        &self,
+        connection_id: ConnectionId,
+    ) -> Result<Option<Connection>, Error> {
+        let connection = ibc_solidity::Connection::abi_decode_params_validate(
+            &self
+                .call(Ibc::connectionsCall(connection_id.raw()).abi_encode())
+                .await?,
+        )?;
+
+        if connection.state == ConnectionState::Unspecified {
+            Ok(None)
+        } else {
+            Ok(Some(connection.try_into()?))
+        }
+    }
+
+    pub async fn channel(&self, channel_id: ChannelId) -> Result<Option<Channel>, Error> {
+        let channel = ibc_solidity::Channel::abi_decode_params_validate(
+            &self
+                .call(Ibc::channelsCall(channel_id.raw()).abi_encode())
+                .await?,
+        )?;
+
+        if channel.state == ChannelState::Unspecified {
+            Ok(None)
+        } else {
+            Ok(Some(channel.try_into()?))
+        }
+    }
+
+    /// The channels owned by `contract`.
+    ///
+    /// The solidity contract doesn't index channels by owner, so this reads the owner of every
+    /// channel in turn. Channel ids are allocated sequentially starting at 1, and every allocated
+    /// channel has an owner.
+    pub async fn channels(&self, contract: H160) -> Result<BTreeSet<ChannelId>, Error> {
+        let mut channels = BTreeSet::new();
+
+        for channel_id in (1..).map_while(ChannelId::from_raw) {
+            let owner = <H160>::from(
+                self.ibc_handler()
+                    .channelOwner(channel_id.raw())
+                    .call()
+                    .await?,
+            );
+
+            if owner == <H160>::default() {
+                break;
+            }
+
+            if owner == contract {
+                channels.insert(channel_id);
+            }
+        }
+
+        Ok(channels)
+    }
+
+    /// The commitment of the batch of sent packets with hash `batch_hash`, if it exists.
+    pub async fn batch_packets(&self, batch_hash: H256) -> Result<Option<H256>, Error> {
+        self.commitment(BatchPacketsPath { batch_hash }.key()).await
+    }
+
+    /// The receipt of the batch of received packets with hash `batch_hash`, if it exists.
+    pub async fn batch_receipts(&self, batch_hash: H256) -> Result<Option<H256>, Error> {
+        self.commitment(BatchReceiptsPath { batch_hash }.key())
+            .await
+    }
+
+    /// The commitment of `packet`, if it has been sent and not yet acknowledged or timed out.
+    pub async fn packet_commitment(&self, packet: &Packet) -> Result<Option<H256>, Error> {
+        self.batch_packets(packet.hash()).await
+    }
+
+    /// The receipt of `packet`, if it has been received.
+    pub async fn packet_receipt(&self, packet: &Packet) -> Result<Option<H256>, Error> {
+        self.batch_receipts(packet.hash()).await
+    }
+
+    async fn light_client(
+        &self,
+        client_id: ClientId,
+    ) -> Result<ILightClientInstance<&P, AnyNetwork>, Error> {
+        let client_impl = self
+            .ibc_handler()
+            .clientImpls(client_id.raw())
+            .call()
+            .await?;
+
+        if client_impl.is_zero() {
+            Err(Error::ClientNotFound(client_id))
+        } else {
+            Ok(ILightClient::new(client_impl, &self.provider))
+        }
+    }
+
+    async fn commitment(&self, key: H256) -> Result<Option<H256>, Error> {
+        let commitment = <H256>::from(self.ibc_handler().commitments(key.into()).call().await?);
+
+        Ok((commitment != <H256>::default()).then_some(commitment))
+    }
+
+    // the generated getters for public mappings of structs can't be used directly, see
+    // https://github.com/alloy-rs/core/issues/811
+    async fn call(&self, input: Vec<u8>) -> Result<alloy::primitives::Bytes, Error> {
+        Ok(self
+            .provider
+            .call(WithOtherFields::new(TransactionRequest {
+                to: Some(alloy::primitives::Address::from(self.ibc_handler).into()),
+                input: TransactionInput::new(input.into()),
+                ..Default::default()
+            }))
+            .await?)
+    }
+}
+
+async fn submit<P: Provider<AnyNetwork>, C: SolCall>(
+    call: SolCallBuilder<P, C, AnyNetwork>,
+) -> Result<AnyTransactionReceipt, Error> {
+    let receipt = call.send().await?.get_receipt().await?;
+
+    if receipt.inner.inner.status() {
+        Ok(receipt)
+    } else {
+        Err(Error::Reverted(receipt.inner.transaction_hash.into()))
+    }
+}
+
+/// Find the first `E` event emitted by `address` in `logs`.
+fn find_event<E: SolEvent>(address: H160, logs: &[Log]) -> Option<E> {
+    logs.iter()
+        .filter(|log| <H160>::from(log.address()) == address)
+        .find_map(|log| E::decode_log(&log.inner).ok())
+        .map(|log| log.data)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::LogData;
+
+    use super::*;
+
+    fn log(address: H160, data: LogData) -> Log {
+        Log {
+            inner: alloy::primitives::Log {
+                address: address.into(),
+                data,
+            },
+            ..Default::default()
+        }
+    }
+
+    fn create_client(client_id: u32) -> LogData {
+        Ibc::CreateClient {
+            client_type_index: Default::default(),
+            clientType: "cometbls".to_owned(),
+            client_id,
+            counterparty_chain_id: "union-1".to_owned(),
+        }
+        .encode_log_data()
+    }
+
+    #[test]
+    fn find_events() {
+        let ibc_handler = H160::new([1; 20]);
+        let other = H160::new([2; 20]);
+
+        let logs = [
+            log(other, create_client(9)),
+            log(ibc_handler, create_client(7)),
+            log(
+                ibc_handler,
+                Ibc::PacketSend {
+                    channel_id: 1,
+                    packet_hash: [0xcc; 32].into(),
+                    packet: Default::default(),
+                }
+                .encode_log_data(),
+            ),
+        ];
+
+        assert_eq!(
+            find_event::<Ibc::CreateClient>(ibc_handler, &logs).map(|event| event.client_id),
+            Some(7)
+        );
+
+        assert_eq!(
+            find_event::<Ibc::PacketSend>(ibc_handler, &logs)
+                .map(|event| H256::from(event.packet_hash)),
+            Some(<H256>::new([0xcc; 32]))
+        );
+
+        assert_eq!(
+            find_event::<Ibc::PacketSend>(other, &logs).map(|event| event.channel_id),
+            None
+        );
+    }
+}
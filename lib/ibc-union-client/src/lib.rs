@@ -0,0 +1,12 @@
+//! Typed clients for `ibc-union` deployments, covering both the core IBC contract and the
+//! `ucs03-zkgm` app.
+//!
+//! - `cosmwasm::Client` (behind the `cosmwasm` feature) talks to the cosmwasm contracts through a
+//!   `cosmos_client::TxClient`, building the `ibc-union-msg` execute and query messages.
+//! - `evm::Client` (behind the `evm` feature) talks to the solidity contracts through an alloy
+//!   provider, using the `ibc-solidity` bindings.
+
+#[cfg(feature = "cosmwasm")]
+pub mod cosmwasm;
+#[cfg(feature = "evm")]
+pub mod evm;
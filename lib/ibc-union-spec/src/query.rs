@@ -1,5 +1,5 @@
 use enumorph::Enumorph;
-use unionlabs::primitives::H256;
+use unionlabs::primitives::{Bytes, H256};
 use voyager_primitives::IbcQuery;
 
 use crate::{
@@ -19,6 +19,10 @@ pub enum Query {
     PacketByHash(PacketByHash),
     /// Query the full details of all of the packets in a batch. This is likely not stored on-chain directly, but should be queryable from events.
     PacketsByBatchHash(PacketsByBatchHash),
+    /// Query the full details of all of the packets sent on a channel within a range of heights. This is likely not stored on-chain directly, but should be queryable from events.
+    PacketsByChannel(PacketsByChannel),
+    /// Query the acknowledgement written for a packet received on this chain. This is likely not stored on-chain directly (only its commitment is), but should be queryable from an event.
+    PacketAckByHash(PacketAckByHash),
     /// Query the status of a client.
     ClientStatus(ClientStatus),
 }
@@ -59,6 +63,47 @@ impl IbcQuery for PacketsByBatchHash {
     type Value = Vec<Packet>;
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case", deny_unknown_fields)
+)]
+pub struct PacketsByChannel {
+    /// The source channel of the packets.
+    pub channel_id: ChannelId,
+    /// The first height to search for sent packets in, inclusive.
+    pub from_height: u64,
+    /// The last height to search for sent packets in, inclusive.
+    pub to_height: u64,
+}
+
+impl IbcQuery for PacketsByChannel {
+    type Spec = IbcUnion;
+
+    type Value = Vec<Packet>;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case", deny_unknown_fields)
+)]
+pub struct PacketAckByHash {
+    /// The destination channel of the packet.
+    pub channel_id: ChannelId,
+    pub packet_hash: H256,
+}
+
+impl IbcQuery for PacketAckByHash {
+    type Spec = IbcUnion;
+
+    type Value = Bytes;
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(
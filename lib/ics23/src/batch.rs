@@ -0,0 +1,422 @@
+use unionlabs::{
+    bounded::BoundedI32,
+    cosmos::ics23::{
+        batch_entry::BatchEntry, batch_proof::BatchProof, commitment_proof::CommitmentProof,
+        compressed_batch_entry::CompressedBatchEntry, compressed_batch_proof::CompressedBatchProof,
+        compressed_existence_proof::CompressedExistenceProof,
+        compressed_non_existence_proof::CompressedNonExistenceProof,
+        existence_proof::ExistenceProof, inner_op::InnerOp, non_existence_proof::NonExistenceProof,
+        proof_spec::ProofSpec,
+    },
+    primitives::Bytes,
+};
+
+use crate::{
+    ops::hash_op::HashError,
+    verify::{self, key_for_comparison, VerifyMembershipError},
+};
+
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum DecompressError {
+    #[error("invalid lookup index {index} (lookup_inners length: {lookup_len})")]
+    InvalidLookupIndex { index: usize, lookup_len: usize },
+}
+
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum VerifyBatchError {
+    #[error("decompression failed ({0})")]
+    Decompress(#[from] DecompressError),
+    #[error("no existence proof for key {0} found in batch")]
+    ExistenceProofNotFound(Bytes),
+    #[error("no non existence proof for key {0} found in batch")]
+    NonExistenceProofNotFound(Bytes),
+    #[error("verification of key {key} failed ({error})")]
+    Verify {
+        key: Bytes,
+        error: VerifyMembershipError,
+    },
+    #[error(transparent)]
+    Hash(#[from] HashError),
+}
+
+/// Verifies that every `(key, value)` pair in `items` is proven to exist under `root` by `proof`.
+///
+/// `proof` may be a single existence proof, a batch proof, or a compressed batch proof.
+pub fn verify_batch_membership(
+    spec: &ProofSpec,
+    root: &[u8],
+    proof: &CommitmentProof,
+    items: &[(&[u8], &[u8])],
+) -> Result<(), VerifyBatchError> {
+    let proof = decompress_commitment_proof(proof)?;
+
+    for (key, value) in items {
+        let existence_proof = find_existence_proof(&proof, key)
+            .ok_or_else(|| VerifyBatchError::ExistenceProofNotFound(key.to_vec().into()))?;
+
+        verify::verify_membership(spec, root, existence_proof, key, value).map_err(|error| {
+            VerifyBatchError::Verify {
+                key: key.to_vec().into(),
+                error,
+            }
+        })?;
+    }
+
+    Ok(())
+}
+
+/// Verifies that none of the `keys` exist under `root`, as proven by `proof`.
+///
+/// `proof` may be a single non existence proof, a batch proof, or a compressed batch proof.
+pub fn verify_batch_non_membership(
+    spec: &ProofSpec,
+    root: &[u8],
+    proof: &CommitmentProof,
+    keys: &[&[u8]],
+) -> Result<(), VerifyBatchError> {
+    let proof = decompress_commitment_proof(proof)?;
+
+    for key in keys {
+        let non_existence_proof = find_non_existence_proof(spec, &proof, key)?
+            .ok_or_else(|| VerifyBatchError::NonExistenceProofNotFound(key.to_vec().into()))?;
+
+        verify::verify_non_membership(spec, root, non_existence_proof, key).map_err(|error| {
+            VerifyBatchError::Verify {
+                key: key.to_vec().into(),
+                error,
+            }
+        })?;
+    }
+
+    Ok(())
+}
+
+/// Expands all entries of a compressed batch proof by resolving their paths in the `lookup_inners`
+/// table.
+pub fn decompress(proof: &CompressedBatchProof) -> Result<BatchProof, DecompressError> {
+    Ok(BatchProof {
+        entries: proof
+            .entries
+            .iter()
+            .map(|entry| {
+                Ok(match entry {
+                    CompressedBatchEntry::Exist(exist) => {
+                        BatchEntry::Exist(decompress_existence_proof(exist, &proof.lookup_inners)?)
+                    }
+                    CompressedBatchEntry::Nonexist(nonexist) => {
+                        BatchEntry::Nonexist(NonExistenceProof {
+                            key: nonexist.key.clone(),
+                            left: nonexist
+                                .left
+                                .as_ref()
+                                .map(|left| decompress_existence_proof(left, &proof.lookup_inners))
+                                .transpose()?,
+                            right: nonexist
+                                .right
+                                .as_ref()
+                                .map(|right| {
+                                    decompress_existence_proof(right, &proof.lookup_inners)
+                                })
+                                .transpose()?,
+                        })
+                    }
+                })
+            })
+            .collect::<Result<_, _>>()?,
+    })
+}
+
+/// Deduplicates the inner ops of all entries of a batch proof into a shared `lookup_inners` table.
+#[must_use]
+pub fn compress(proof: &BatchProof) -> CompressedBatchProof {
+    let mut lookup_inners = vec![];
+
+    let entries = proof
+        .entries
+        .iter()
+        .map(|entry| match entry {
+            BatchEntry::Exist(exist) => {
+                CompressedBatchEntry::Exist(compress_existence_proof(exist, &mut lookup_inners))
+            }
+            BatchEntry::Nonexist(nonexist) => {
+                CompressedBatchEntry::Nonexist(CompressedNonExistenceProof {
+                    key: nonexist.key.clone(),
+                    left: nonexist
+                        .left
+                        .as_ref()
+                        .map(|left| compress_existence_proof(left, &mut lookup_inners)),
+                    right: nonexist
+                        .right
+                        .as_ref()
+                        .map(|right| compress_existence_proof(right, &mut lookup_inners)),
+                })
+            }
+        })
+        .collect();
+
+    CompressedBatchProof {
+        entries,
+        lookup_inners,
+    }
+}
+
+fn decompress_existence_proof(
+    proof: &CompressedExistenceProof,
+    lookup_inners: &[InnerOp],
+) -> Result<ExistenceProof, DecompressError> {
+    Ok(ExistenceProof {
+        key: proof.key.clone().into(),
+        value: proof.value.clone().into(),
+        leaf: proof.leaf.clone(),
+        path: proof
+            .path
+            .iter()
+            .map(|index| {
+                // bounded to be non-negative
+                let index = index.inner() as usize;
+
+                lookup_inners
+                    .get(index)
+                    .cloned()
+                    .ok_or(DecompressError::InvalidLookupIndex {
+                        index,
+                        lookup_len: lookup_inners.len(),
+                    })
+            })
+            .collect::<Result<_, _>>()?,
+    })
+}
+
+fn compress_existence_proof(
+    proof: &ExistenceProof,
+    lookup_inners: &mut Vec<InnerOp>,
+) -> CompressedExistenceProof {
+    CompressedExistenceProof {
+        key: proof.key.to_vec(),
+        value: proof.value.to_vec(),
+        leaf: proof.leaf.clone(),
+        path: proof
+            .path
+            .iter()
+            .map(|inner| {
+                let index = match lookup_inners.iter().position(|i| i == inner) {
+                    Some(index) => index,
+                    None => {
+                        lookup_inners.push(inner.clone());
+                        lookup_inners.len() - 1
+                    }
+                };
+
+                BoundedI32::new(index).expect("lookup table length fits in an i32; qed;")
+            })
+            .collect(),
+    }
+}
+
+fn decompress_commitment_proof(proof: &CommitmentProof) -> Result<BatchProof, DecompressError> {
+    Ok(match proof {
+        CommitmentProof::Exist(exist) => BatchProof {
+            entries: vec![BatchEntry::Exist(exist.clone())],
+        },
+        CommitmentProof::Nonexist(nonexist) => BatchProof {
+            entries: vec![BatchEntry::Nonexist(nonexist.clone())],
+        },
+        CommitmentProof::Batch(batch) => batch.clone(),
+        CommitmentProof::CompressedBatch(compressed) => decompress(compressed)?,
+    })
+}
+
+fn find_existence_proof<'a>(proof: &'a BatchProof, key: &[u8]) -> Option<&'a ExistenceProof> {
+    proof.entries.iter().find_map(|entry| match entry {
+        BatchEntry::Exist(exist) if exist.key == key => Some(exist),
+        _ => None,
+    })
+}
+
+/// Finds the non existence proof whose left and right neighbors enclose `key`.
+fn find_non_existence_proof<'a>(
+    spec: &ProofSpec,
+    proof: &'a BatchProof,
+    key: &[u8],
+) -> Result<Option<&'a NonExistenceProof>, HashError> {
+    let key = key_for_comparison(spec, key)?;
+
+    for entry in &proof.entries {
+        let BatchEntry::Nonexist(nonexist) = entry else {
+            continue;
+        };
+
+        let is_left = match &nonexist.left {
+            Some(left) => key_for_comparison(spec, &left.key)? < key,
+            None => true,
+        };
+
+        let is_right = match &nonexist.right {
+            Some(right) => key_for_comparison(spec, &right.key)? > key,
+            None => true,
+        };
+
+        if is_left && is_right {
+            return Ok(Some(nonexist));
+        }
+    }
+
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use hex_literal::hex;
+    use unionlabs::{
+        encoding::{DecodeAs, Proto},
+        ibc::core::commitment::merkle_proof::MerkleProof,
+    };
+
+    use super::*;
+    use crate::proof_specs::IAVL_PROOF_SPEC;
+
+    // the iavl proofs of `connection_exists` and `client_state_exists` in `ibc_api`
+    const CONNECTION_PROOF: [u8; 574] = hex!("0abc020ab9020a18636f6e6e656374696f6e732f636f6e6e656374696f6e2d31125b0a0930382d7761736d2d3112230a0131120d4f524445525f4f524445524544120f4f524445525f554e4f524445524544180222250a0e636f6d6574626c732d6e65772d30120c636f6e6e656374696f6e2d301a050a0369626328061a0c0801180120012a040002f006222c080112050204f006201a212075c4910f51207d3c65960120fe931f138e2624668d75869f51b8442593dd5eab222a080112260408de0a2002b6fcf07091245d162f1196b003c555c564980e02c4d4a9fa0a249798f4b25e20222c08011205060ede0a201a2120ff6b0a04e076eecbabfee4e751c0523cbedba898211b5847404e2d954a2203e3222a08011226081ede0a20635053419cfb6a81c839860d99f3ed002840124a790ddd9f066d8bce63f9df54200afc010af9010a03696263122024b15e198bcf648dee62c7ca1fd8c3950c85c3d898833180c3e3c412ccbc559d1a090801180120012a01002225080112210106b99c0d8119ff1edbcbe165d0f19337dbbc080e677c88e57aa2ae767ebf0f0f222708011201011a20aa650406ea0d76e39dd43d2ea6a91e3fdaa1c908fc21a7ca68e5e62cc8115639222508011221016ac3182364d7cdaa1f52a77b6081e070aa29b2d253f3642169693cde336e2bdc222508011221016376cbd7b917c7105ddac35bdeddd79e6c9cbbc66dd227941599de2b9bc8b3de222708011201011a200d68ac7c3e8daf94c65ccdfe5b7397f50e80325240ef9b2a0ec483afaea30544");
+    const CONNECTION_KEY: &[u8] = b"connections/connection-1";
+    const CONNECTION_VALUE: &[u8] = &hex!("0a0930382d7761736d2d3112230a0131120d4f524445525f4f524445524544120f4f524445525f554e4f524445524544180222250a0e636f6d6574626c732d6e65772d30120c636f6e6e656374696f6e2d301a050a036962632806");
+    const CONNECTION_ROOT: [u8; 32] =
+        hex!("24b15e198bcf648dee62c7ca1fd8c3950c85c3d898833180c3e3c412ccbc559d");
+
+    const CLIENT_STATE_PROOF: [u8; 690] = hex!("0ab0030aad030a1d636c69656e74732f30382d7761736d2d312f636c69656e74537461746512c7010a252f6962632e6c69676874636c69656e74732e7761736d2e76312e436c69656e745374617465129d010a720a20d8ea171f3c94aea21ebc42a1ed61052acf3f9209c00e4efbaaddac09ed9b807818e0fac1950622310a04900000691a060a049000007022060a04900000712a060a049000007232110a040400000010ffffffffffffffffff01280c30203880024204080110034880c2d72f50a0f4a4011220e8dcc770de5a013041588233812f73ac797ec6078b0011cbcbfe49d474f4c1191a051081f2e7011a0c0801180120012a040002ae06222c080112050204b006201a2120980ab410769397da376376a2756754b225f34cc0eea404b068924f64180abcc4222c080112050408b006201a21209d79cf7fc2f248ea0a56cff266ac54cfbc06687e25ffee99aec2884856d0104f222a080112260610b006203e808c2bc895d44d05d7af6d8b0424fabb1d9ab6f53b10cdb084b2996f75bfa620222c08011205081eb006201a212095bb7de983d8ea1282a2d60e2f6c675bec25f82be86aa874ff0f15827c1ab3ed0afc010af9010a036962631220859b7ac80b1c0ca82504e0d8e9de460d42ca66a03e708cbd09869e5216c73a591a090801180120012a01002225080112210106b99c0d8119ff1edbcbe165d0f19337dbbc080e677c88e57aa2ae767ebf0f0f222708011201011a20aa650406ea0d76e39dd43d2ea6a91e3fdaa1c908fc21a7ca68e5e62cc8115639222508011221016ac3182364d7cdaa1f52a77b6081e070aa29b2d253f3642169693cde336e2bdc22250801122101c9d0a585c82dc572f3fcedc70302d4c3fbbc9e84f0618c6b446a70efa312e8dc222708011201011a20952029410a533cf530124179204303bea59a86f5b4993291c5b8ca406412c5f7");
+
+    fn existence_proof(proof: &[u8]) -> ExistenceProof {
+        let MerkleProof { proofs } = MerkleProof::decode_as::<Proto>(proof).unwrap();
+
+        let CommitmentProof::Exist(existence_proof) = &proofs[0] else {
+            panic!("unexpected proof type");
+        };
+
+        existence_proof.clone()
+    }
+
+    fn batch() -> BatchProof {
+        let connection = existence_proof(&CONNECTION_PROOF);
+        let client_state = existence_proof(&CLIENT_STATE_PROOF);
+
+        BatchProof {
+            entries: vec![
+                BatchEntry::Exist(connection.clone()),
+                BatchEntry::Exist(client_state.clone()),
+                BatchEntry::Nonexist(NonExistenceProof {
+                    key: b"connections/connection-0".into(),
+                    left: None,
+                    right: Some(connection),
+                }),
+            ],
+        }
+    }
+
+    #[test]
+    fn compress_decompress_round_trip() {
+        let batch = batch();
+
+        let compressed = compress(&batch);
+
+        let mut inner_ops = vec![];
+        for entry in &batch.entries {
+            let (BatchEntry::Exist(exist)
+            | BatchEntry::Nonexist(NonExistenceProof {
+                right: Some(exist), ..
+            })) = entry
+            else {
+                unreachable!()
+            };
+
+            for inner in &exist.path {
+                if !inner_ops.contains(inner) {
+                    inner_ops.push(inner.clone());
+                }
+            }
+        }
+
+        // the inner ops shared between the proofs are only stored once
+        assert_eq!(compressed.lookup_inners, inner_ops);
+        assert_eq!(decompress(&compressed), Ok(batch));
+    }
+
+    #[test]
+    fn compressed_batch_membership() {
+        let proof = CommitmentProof::CompressedBatch(compress(&BatchProof {
+            entries: vec![BatchEntry::Exist(existence_proof(&CONNECTION_PROOF))],
+        }));
+
+        assert_eq!(
+            verify_batch_membership(
+                &IAVL_PROOF_SPEC,
+                &CONNECTION_ROOT,
+                &proof,
+                &[(CONNECTION_KEY, CONNECTION_VALUE)]
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn decompress_invalid_lookup_index() {
+        let mut compressed = compress(&batch());
+        compressed.lookup_inners.pop();
+
+        let lookup_len = compressed.lookup_inners.len();
+
+        assert_eq!(
+            decompress(&compressed),
+            Err(DecompressError::InvalidLookupIndex {
+                index: lookup_len,
+                lookup_len,
+            })
+        );
+
+        assert_eq!(
+            verify_batch_membership(
+                &IAVL_PROOF_SPEC,
+                &CONNECTION_ROOT,
+                &CommitmentProof::CompressedBatch(compressed),
+                &[(CONNECTION_KEY, CONNECTION_VALUE)]
+            ),
+            Err(VerifyBatchError::Decompress(
+                DecompressError::InvalidLookupIndex {
+                    index: lookup_len,
+                    lookup_len,
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn key_not_in_batch() {
+        let proof = CommitmentProof::Batch(batch());
+
+        assert_eq!(
+            verify_batch_membership(
+                &IAVL_PROOF_SPEC,
+                &CONNECTION_ROOT,
+                &proof,
+                &[
+                    (CONNECTION_KEY, CONNECTION_VALUE),
+                    (b"connections/connection-2", CONNECTION_VALUE)
+                ]
+            ),
+            Err(VerifyBatchError::ExistenceProofNotFound(
+                b"connections/connection-2".into()
+            ))
+        );
+
+        // only keys between the neighbours of a non existence proof are proven to not exist
+        assert_eq!(
+            verify_batch_non_membership(
+                &IAVL_PROOF_SPEC,
+                &CONNECTION_ROOT,
+                &proof,
+                &[b"connections/connection-2"]
+            ),
+            Err(VerifyBatchError::NonExistenceProofNotFound(
+                b"connections/connection-2".into()
+            ))
+        );
+    }
+}
@@ -162,7 +162,10 @@ fn verify_non_existence(
     Ok(())
 }
 
-fn key_for_comparison<'a>(spec: &ProofSpec, key: &'a [u8]) -> Result<Cow<'a, [u8]>, HashError> {
+pub(crate) fn key_for_comparison<'a>(
+    spec: &ProofSpec,
+    key: &'a [u8],
+) -> Result<Cow<'a, [u8]>, HashError> {
     if !spec.prehash_key_before_comparison {
         return Ok(Cow::Borrowed(key));
     }
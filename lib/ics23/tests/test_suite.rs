@@ -2,6 +2,7 @@ use std::{collections::HashMap, fmt::Display, fs, path::PathBuf};
 
 use anyhow::{bail, Context};
 use ics23::{
+    batch::{compress, verify_batch_membership, verify_batch_non_membership},
     existence_proof::{self, calculate_root},
     ops::{hash_op, inner_op, inner_op::check_against_spec, leaf_op},
     proof_specs::{IAVL_PROOF_SPEC, TENDERMINT_PROOF_SPEC},
@@ -13,8 +14,8 @@ use protos::cosmos::ics23::v1::InnerSpec;
 use serde::{de::DeserializeOwned, Deserialize};
 use unionlabs::{
     cosmos::ics23::{
-        commitment_proof::CommitmentProof, hash_op::HashOp, inner_op::InnerOp,
-        proof_spec::ProofSpec,
+        batch_entry::BatchEntry, batch_proof::BatchProof, commitment_proof::CommitmentProof,
+        hash_op::HashOp, inner_op::InnerOp, proof_spec::ProofSpec,
     },
     encoding::{DecodeAs, Proto},
 };
@@ -37,7 +38,8 @@ fn suite() -> anyhow::Result<()> {
         "test empty branch data",
     )?;
 
-    run_vector_tests(testdata_dir)?;
+    run_vector_tests(testdata_dir.clone())?;
+    run_batch_vector_tests(testdata_dir)?;
 
     Ok(())
 }
@@ -540,3 +542,154 @@ impl TestCase for VectorTest {
         }
     }
 }
+
+const BATCH_FILENAMES: [&str; 2] = ["batch_exist.json", "batch_nonexist.json"];
+
+/// Runs the upstream batch vectors, and additionally combines the single vectors of each spec
+/// into one batch, both as a plain and as a compressed batch proof.
+fn run_batch_vector_tests(testdata_dir: PathBuf) -> anyhow::Result<()> {
+    let mut tests = vec![];
+
+    for spec_type in SpecType::all() {
+        let spec = spec_type.proof_spec();
+
+        for file_name in BATCH_FILENAMES {
+            let path = testdata_dir.join(spec_type.path()).join(file_name);
+            let data = read_json::<BatchVectorTestData>(path);
+
+            let proof = CommitmentProof::decode_as::<Proto>(data.proof.as_slice())
+                .map_err(|e| anyhow::anyhow!("cannot parse proof - {e:?}"))?;
+
+            tests.push(BatchVectorTest {
+                name: format!("{} - {}", spec_type.name(), file_name),
+                spec: spec.clone(),
+                proof,
+                items: data
+                    .items
+                    .into_iter()
+                    .map(|item| (data.root.clone(), item.key, item.value))
+                    .collect(),
+            });
+        }
+
+        let vectors = FILENAMES
+            .iter()
+            .map(|file_name| {
+                read_json::<VectorTestData>(testdata_dir.join(spec_type.path()).join(file_name))
+            })
+            .collect::<Vec<_>>();
+
+        let entries = vectors
+            .iter()
+            .map(
+                |vector| match CommitmentProof::decode_as::<Proto>(vector.proof.as_slice()) {
+                    Ok(CommitmentProof::Exist(exist)) => Ok(BatchEntry::Exist(exist)),
+                    Ok(CommitmentProof::Nonexist(nonexist)) => Ok(BatchEntry::Nonexist(nonexist)),
+                    proof => bail!("unexpected proof: {proof:?}"),
+                },
+            )
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        let batch = BatchProof { entries };
+        let items = vectors
+            .iter()
+            .map(|vector| {
+                (
+                    vector.root.clone(),
+                    vector.key.clone(),
+                    vector.value.clone(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        tests.push(BatchVectorTest {
+            name: format!("{} - combined compressed batch", spec_type.name()),
+            spec: spec.clone(),
+            proof: CommitmentProof::CompressedBatch(compress(&batch)),
+            items: items.clone(),
+        });
+
+        tests.push(BatchVectorTest {
+            name: format!("{} - combined batch", spec_type.name()),
+            spec,
+            proof: CommitmentProof::Batch(batch),
+            items,
+        });
+    }
+
+    for test in tests {
+        eprint!("test batch vectors: {}...", &test);
+        test.run()?;
+        eprintln!("OK");
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "lowercase")]
+struct BatchVectorTestData {
+    #[serde(with = "::serde_utils::hex_upper_unprefixed")]
+    root: Vec<u8>,
+    #[serde(with = "::serde_utils::hex_upper_unprefixed")]
+    proof: Vec<u8>,
+    items: Vec<BatchVectorItem>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "lowercase")]
+struct BatchVectorItem {
+    #[serde(with = "::serde_utils::hex_upper_unprefixed")]
+    key: Vec<u8>,
+    #[serde(with = "::serde_utils::hex_upper_unprefixed", default)]
+    value: Vec<u8>,
+}
+
+#[derive(Debug)]
+struct BatchVectorTest {
+    name: String,
+    spec: ProofSpec,
+    proof: CommitmentProof,
+    /// `(root, key, value)`; items with an empty value are expected to be absent.
+    items: Vec<(Vec<u8>, Vec<u8>, Vec<u8>)>,
+}
+
+impl Display for BatchVectorTest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name.as_str())
+    }
+}
+
+impl BatchVectorTest {
+    fn run(self) -> anyhow::Result<()> {
+        let compressed = match &self.proof {
+            CommitmentProof::Batch(batch) => {
+                Some(CommitmentProof::CompressedBatch(compress(batch)))
+            }
+            _ => None,
+        };
+
+        for (root, key, value) in &self.items {
+            // a compressed batch must verify the same as the decompressed one
+            for proof in [Some(&self.proof), compressed.as_ref()]
+                .into_iter()
+                .flatten()
+            {
+                if value.is_empty() {
+                    verify_batch_non_membership(&self.spec, root, proof, &[key.as_slice()])
+                        .context("verify batch non membership")?;
+                } else {
+                    verify_batch_membership(
+                        &self.spec,
+                        root,
+                        proof,
+                        &[(key.as_slice(), value.as_slice())],
+                    )
+                    .context("verify batch membership")?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,88 @@
+use alloy::{
+    eips::BlockNumberOrTag,
+    network::Network,
+    providers::Provider,
+    sol,
+    transports::{RpcError, TransportErrorKind},
+};
+use linea_types::proof::GetProof;
+use tracing::{debug, instrument};
+use unionlabs::primitives::{H160, H256, U256};
+
+/// The latest L2 block number that has been finalized on the L1 by the `LineaRollup` contract, as
+/// of `l1_block_number`.
+#[instrument(skip_all, fields(%l1_block_number, %l1_rollup_contract_address))]
+pub async fn finalized_l2_block_number_of_l1_block_number<N: Network>(
+    l1_provider: &impl Provider<N>,
+    l1_rollup_contract_address: H160,
+    l1_block_number: u64,
+) -> Result<u64, alloy::contract::Error> {
+    let c = LineaRollup::new(l1_rollup_contract_address.into(), l1_provider);
+
+    let block_number = c
+        .currentL2BlockNumber()
+        .block(l1_block_number.into())
+        .call()
+        .await?;
+
+    debug!(%block_number);
+
+    Ok(block_number
+        .try_into()
+        .expect("block number should fit in a u64"))
+}
+
+/// The (sparse merkle tree) state root of `l2_block_number`, as stored in the `LineaRollup`
+/// contract at `l1_block_number`. This will be zero if the L2 block has not been finalized.
+#[instrument(skip_all, fields(%l1_block_number, %l1_rollup_contract_address, %l2_block_number))]
+pub async fn l2_state_root_of_l2_block_number<N: Network>(
+    l1_provider: &impl Provider<N>,
+    l1_rollup_contract_address: H160,
+    l1_block_number: u64,
+    l2_block_number: u64,
+) -> Result<H256, alloy::contract::Error> {
+    let c = LineaRollup::new(l1_rollup_contract_address.into(), l1_provider);
+
+    let state_root = c
+        .stateRootHashes(alloy::primitives::U256::from(l2_block_number))
+        .block(l1_block_number.into())
+        .call()
+        .await?;
+
+    debug!(%state_root);
+
+    Ok(state_root.into())
+}
+
+/// Fetch the sparse merkle tree proofs of `address` and the provided storage keys at
+/// `l2_block_number`, through the `linea_getProof` RPC method exposed by the state manager.
+#[instrument(skip_all, fields(%address, %l2_block_number))]
+pub async fn get_proof<N: Network>(
+    l2_provider: &impl Provider<N>,
+    address: H160,
+    storage_keys: Vec<U256>,
+    l2_block_number: u64,
+) -> Result<GetProof, RpcError<TransportErrorKind>> {
+    l2_provider
+        .raw_request(
+            "linea_getProof".into(),
+            (
+                address,
+                storage_keys
+                    .into_iter()
+                    .map(|key| H256::new(key.to_be_bytes()))
+                    .collect::<Vec<_>>(),
+                BlockNumberOrTag::Number(l2_block_number),
+            ),
+        )
+        .await
+}
+
+sol! {
+    #![sol(rpc)]
+
+    contract LineaRollup {
+        function currentL2BlockNumber() returns (uint256);
+        function stateRootHashes(uint256 blockNumber) returns (bytes32);
+    }
+}
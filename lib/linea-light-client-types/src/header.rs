@@ -1,14 +1,19 @@
 use ethereum_light_client_types::{AccountProof, StorageProof};
 use linea_types::proof::InclusionProof;
-use unionlabs::ibc::core::client::height::Height;
 
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
 pub struct Header {
-    pub l1_height: Height,
+    pub l1_height: u64,
+    /// Proof of the storage root of the `LineaRollup` contract in the L1 state root.
     pub l1_rollup_contract_proof: AccountProof,
-    pub l2_timestamp_proof: StorageProof,
+    /// Proof of `currentL2BlockNumber` in the `LineaRollup` contract storage.
     pub l2_block_number_proof: StorageProof,
+    /// Proof of `stateRootHashes[currentL2BlockNumber]` in the `LineaRollup` contract storage.
     pub l2_state_root_proof: StorageProof,
+    /// Proof of `currentL2Timestamp` in the `LineaRollup` contract storage.
+    pub l2_timestamp_proof: StorageProof,
+    /// Proof of the IBC contract account in the L2 state root.
     pub l2_ibc_contract_proof: InclusionProof,
 }
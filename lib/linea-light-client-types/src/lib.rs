@@ -1,5 +1,13 @@
 pub mod client_state;
 pub mod consensus_state;
 pub mod header;
+pub mod misbehaviour;
 
-pub use crate::{client_state::ClientState, consensus_state::ConsensusState, header::Header};
+pub use linea_types::proof::MerkleProof as StorageProof;
+
+pub use crate::{
+    client_state::{ClientState, ClientStateV1},
+    consensus_state::ConsensusState,
+    header::Header,
+    misbehaviour::Misbehaviour,
+};
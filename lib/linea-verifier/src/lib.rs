@@ -2,7 +2,7 @@ use core::fmt::Debug;
 
 use evm_storage_verifier::{verify_account_storage_root, verify_storage_proof};
 use gnark_mimc::new_mimc_constants_bls12_377;
-use linea_light_client_types::{ClientState, Header};
+use linea_light_client_types::{ClientStateV1, Header};
 use linea_types::account::ZkAccount;
 use unionlabs::{
     ethereum::slot::{MappingKey, Slot},
@@ -21,6 +21,10 @@ pub enum Error {
     InvalidL2StateRootProof(evm_storage_verifier::error::Error),
     #[error("invalid l2 ibc contract proof {0}")]
     InvalidL2IbcContractProof(linea_zktrie::verify::Error),
+    #[error("misbehaviour headers are for different l2 blocks ({a} != {b})")]
+    MisbehaviourL2BlockMismatch { a: U256, b: U256 },
+    #[error("misbehaviour headers commit to the same l2 state root ({0})")]
+    MisbehaviourStateRootsEqual(H256),
 }
 
 // 1. assert rootHash(rollup) in l1StateRoot
@@ -29,8 +33,8 @@ pub enum Error {
 // 4. assert rollup.stateRootHashes[l2BlockNumber] = l2StateRoot
 // 5. assert rootHash(l2IbcContract) in l2StateRoot
 pub fn verify_header(
-    client_state: ClientState,
-    header: Header,
+    client_state: &ClientStateV1,
+    header: &Header,
     l1_state_root: H256,
 ) -> Result<(), Error> {
     // 1.
@@ -78,13 +82,49 @@ pub fn verify_header(
         header.l2_ibc_contract_proof.leaf_index,
         &header.l2_ibc_contract_proof.proof,
         header.l2_state_root_proof.value.to_be_bytes().into(),
-        client_state.l2_ibc_contract_address,
+        client_state.ibc_contract_address,
     )
     .map_err(Error::InvalidL2IbcContractProof)?;
 
     Ok(())
 }
 
+/// Verify that the two provided headers are a valid proof of misbehaviour.
+///
+/// Misbehaviour verification consists of 3 steps:
+///
+/// 1. Verify that both headers are for the same L2 block.
+/// 2. Verify that the L2 state roots of the headers are different.
+/// 3. Verify both headers against their respective L1 state roots, as in [`verify_header`].
+pub fn verify_misbehaviour(
+    client_state: &ClientStateV1,
+    header_a: &Header,
+    l1_state_root_a: H256,
+    header_b: &Header,
+    l1_state_root_b: H256,
+) -> Result<(), Error> {
+    // 1.
+    if header_a.l2_block_number_proof.value != header_b.l2_block_number_proof.value {
+        return Err(Error::MisbehaviourL2BlockMismatch {
+            a: header_a.l2_block_number_proof.value,
+            b: header_b.l2_block_number_proof.value,
+        });
+    }
+
+    // 2.
+    if header_a.l2_state_root_proof.value == header_b.l2_state_root_proof.value {
+        return Err(Error::MisbehaviourStateRootsEqual(
+            header_a.l2_state_root_proof.value.to_be_bytes().into(),
+        ));
+    }
+
+    // 3.
+    verify_header(client_state, header_a, l1_state_root_a)?;
+    verify_header(client_state, header_b, l1_state_root_b)?;
+
+    Ok(())
+}
+
 pub fn state_root_hashes_mapping_key(slot: &U256, l2_block_number: &U256) -> U256 {
     Slot::Mapping(&Slot::Offset(*slot), MappingKey::Uint256(*l2_block_number)).slot()
 }
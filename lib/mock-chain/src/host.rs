@@ -0,0 +1,1168 @@
+//! The IBC state machine of a mock chain, implementing the same semantics as the ibc-union core
+//! contracts.
+
+use std::collections::BTreeMap;
+
+use alloy_sol_types::SolValue;
+use ibc_union_spec::{
+    datagram::{
+        Datagram, MsgChannelOpenAck, MsgChannelOpenConfirm, MsgChannelOpenInit, MsgChannelOpenTry,
+        MsgConnectionOpenAck, MsgConnectionOpenConfirm, MsgConnectionOpenInit,
+        MsgConnectionOpenTry, MsgCreateClient, MsgPacketAcknowledgement, MsgPacketRecv,
+        MsgPacketTimeout, MsgUpdateClient,
+    },
+    event::{
+        ChannelMetadata, ChannelOpenAck, ChannelOpenConfirm, ChannelOpenInit, ChannelOpenTry,
+        ConnectionMetadata, ConnectionOpenAck, ConnectionOpenConfirm, ConnectionOpenInit,
+        ConnectionOpenTry, CreateClient, FullEvent, PacketAck, PacketMetadata, PacketRecv,
+        PacketSend, PacketTimeout, UpdateClient, WriteAck,
+    },
+    path::{
+        BatchPacketsPath, BatchReceiptsPath, ChannelPath, ClientStatePath, ConnectionPath,
+        ConsensusStatePath, StorePath, COMMITMENT_MAGIC, COMMITMENT_MAGIC_ACK,
+    },
+    Channel, ChannelId, ChannelState, ClientId, Connection, ConnectionId, ConnectionState,
+    MustBeZero, Packet, Status, Timestamp,
+};
+use serde_json::Value;
+use unionlabs::{
+    ethereum::keccak256,
+    primitives::{Bytes, H256},
+};
+use voyager_primitives::{ChainId, ClientType};
+
+use crate::types::{
+    decode, encode, BlockHeader, ClientState, ConsensusState, Header, ProofError, StateProof,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum HostError {
+    #[error("unsupported datagram `{0}`")]
+    UnsupportedDatagram(&'static str),
+    #[error("unsupported client type `{0}`")]
+    UnsupportedClientType(ClientType),
+    #[error("unable to decode {what}: {error}")]
+    Decode { what: &'static str, error: String },
+    #[error("client {0} not found")]
+    ClientNotFound(ClientId),
+    #[error("client {0} is frozen")]
+    ClientFrozen(ClientId),
+    #[error("consensus state for client {client_id} at height {height} not found")]
+    ConsensusStateNotFound { client_id: ClientId, height: u64 },
+    #[error("invalid header for client {client_id} at height {height}: {reason}")]
+    InvalidHeader {
+        client_id: ClientId,
+        height: u64,
+        reason: String,
+    },
+    #[error("connection {0} not found")]
+    ConnectionNotFound(ConnectionId),
+    #[error("connection {connection_id} is in state {found:?}, expected {expected:?}")]
+    InvalidConnectionState {
+        connection_id: ConnectionId,
+        expected: ConnectionState,
+        found: ConnectionState,
+    },
+    #[error("channel {0} not found")]
+    ChannelNotFound(ChannelId),
+    #[error("channel {channel_id} is in state {found}, expected {expected}")]
+    InvalidChannelState {
+        channel_id: ChannelId,
+        expected: ChannelState,
+        found: ChannelState,
+    },
+    #[error("packet source channel {found} does not match the counterparty channel {expected}")]
+    InvalidPacketSource {
+        expected: ChannelId,
+        found: ChannelId,
+    },
+    #[error("packets in a batch must all be on the same channel")]
+    MixedChannels,
+    #[error("batch must not be empty")]
+    EmptyBatch,
+    #[error("expected {expected} acknowledgements, found {found}")]
+    AcknowledgementsLength { expected: usize, found: usize },
+    #[error("packet {0} has already been sent")]
+    PacketAlreadySent(H256),
+    #[error("packet {0} was not sent or has already been acknowledged or timed out")]
+    PacketNotSent(H256),
+    #[error(
+        "packet {packet_hash} timed out at {timeout_timestamp}, current timestamp is {timestamp}"
+    )]
+    PacketTimedOut {
+        packet_hash: H256,
+        timeout_timestamp: Timestamp,
+        timestamp: Timestamp,
+    },
+    #[error("packet {packet_hash} times out at {timeout_timestamp}, but the proof timestamp is {proof_timestamp}")]
+    TimeoutNotReached {
+        packet_hash: H256,
+        timeout_timestamp: Timestamp,
+        proof_timestamp: Timestamp,
+    },
+    #[error("invalid proof")]
+    InvalidProof(#[source] ProofError),
+}
+
+/// Provides access to the canonical chains of the other chains in the network, used to verify
+/// [`Header`]s.
+pub trait Counterparties {
+    /// Returns the canonical, finalized block at `height` on `chain_id`, if it exists.
+    fn finalized_block(&self, chain_id: &ChainId, height: u64) -> Option<BlockHeader>;
+}
+
+#[derive(Debug, Clone)]
+pub struct ClientEntry {
+    pub client_type: ClientType,
+    pub client_state: ClientState,
+    pub consensus_states: BTreeMap<u64, ConsensusState>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ChannelEntry {
+    pub port_id: Bytes,
+    pub channel: Channel,
+}
+
+/// The full IBC state of a mock chain at a specific height.
+#[derive(Debug, Clone, Default)]
+pub struct IbcState {
+    next_client_id: u32,
+    next_connection_id: u32,
+    next_channel_id: u32,
+    pub clients: BTreeMap<ClientId, ClientEntry>,
+    pub connections: BTreeMap<ConnectionId, Connection>,
+    pub channels: BTreeMap<ChannelId, ChannelEntry>,
+    pub commitments: BTreeMap<H256, H256>,
+    /// All packets sent from this chain, indexed by their hash.
+    pub packets: BTreeMap<H256, Packet>,
+}
+
+/// The context a transaction is executed in.
+pub struct ExecutionContext<'a, C> {
+    /// The timestamp of the block the transaction is being included in.
+    pub timestamp: Timestamp,
+    pub counterparties: &'a C,
+}
+
+impl IbcState {
+    #[must_use]
+    pub fn proof(&self) -> StateProof {
+        StateProof {
+            commitments: self
+                .commitments
+                .iter()
+                .map(|(key, value)| (*key, *value))
+                .collect(),
+        }
+    }
+
+    #[must_use]
+    pub fn state_root(&self) -> H256 {
+        self.proof().root()
+    }
+
+    /// Query the value stored at `path`, returning it as JSON in the same form as the
+    /// [`IbcStorePathKey::Value`](voyager_primitives::IbcStorePathKey::Value) of the path,
+    /// wrapped in an `Option`.
+    #[must_use]
+    pub fn query(&self, path: &StorePath) -> Value {
+        match path {
+            StorePath::ClientState(path) => into_value(
+                self.clients
+                    .get(&path.client_id)
+                    .map(|client| encode(&client.client_state)),
+            ),
+            StorePath::ConsensusState(path) => into_value(
+                self.clients
+                    .get(&path.client_id)
+                    .and_then(|client| client.consensus_states.get(&path.height))
+                    .map(encode),
+            ),
+            StorePath::Connection(path) => into_value(self.connections.get(&path.connection_id)),
+            StorePath::Channel(path) => into_value(
+                self.channels
+                    .get(&path.channel_id)
+                    .map(|entry| &entry.channel),
+            ),
+            StorePath::BatchReceipts(_) | StorePath::BatchPackets(_) => {
+                into_value(self.commitments.get(&path.key()))
+            }
+        }
+    }
+
+    #[must_use]
+    pub fn client_status(&self, client_id: ClientId) -> Option<Status> {
+        self.clients.get(&client_id).map(|client| {
+            if client.client_state.frozen {
+                Status::Frozen
+            } else {
+                Status::Active
+            }
+        })
+    }
+
+    /// Execute a single datagram, returning the emitted events. If this returns an error, `self`
+    /// may have been partially modified and must be discarded.
+    pub fn execute<C: Counterparties>(
+        &mut self,
+        ctx: &ExecutionContext<'_, C>,
+        datagram: Datagram,
+    ) -> Result<Vec<(ClientId, FullEvent)>, HostError> {
+        match datagram {
+            Datagram::CreateClient(msg) => self.create_client(msg),
+            Datagram::UpdateClient(msg) => self.update_client(ctx, msg),
+            Datagram::RecoverClient(_) => Err(HostError::UnsupportedDatagram("RecoverClient")),
+            Datagram::UpgradeClient(_) => Err(HostError::UnsupportedDatagram("UpgradeClient")),
+            Datagram::ConnectionOpenInit(msg) => self.connection_open_init(msg),
+            Datagram::ConnectionOpenTry(msg) => self.connection_open_try(msg),
+            Datagram::ConnectionOpenAck(msg) => self.connection_open_ack(msg),
+            Datagram::ConnectionOpenConfirm(msg) => self.connection_open_confirm(msg),
+            Datagram::ChannelOpenInit(msg) => self.channel_open_init(msg),
+            Datagram::ChannelOpenTry(msg) => self.channel_open_try(msg),
+            Datagram::ChannelOpenAck(msg) => self.channel_open_ack(msg),
+            Datagram::ChannelOpenConfirm(msg) => self.channel_open_confirm(msg),
+            Datagram::PacketRecv(msg) => self.packet_recv(ctx, msg),
+            Datagram::PacketAcknowledgement(msg) => self.packet_acknowledgement(msg),
+            Datagram::PacketTimeout(msg) => self.packet_timeout(msg),
+            Datagram::ChannelCloseInit(_) => {
+                Err(HostError::UnsupportedDatagram("ChannelCloseInit"))
+            }
+            Datagram::ChannelCloseConfirm(_) => {
+                Err(HostError::UnsupportedDatagram("ChannelCloseConfirm"))
+            }
+            Datagram::IntentPacketRecv(_) => {
+                Err(HostError::UnsupportedDatagram("IntentPacketRecv"))
+            }
+            Datagram::BatchSend(_) => Err(HostError::UnsupportedDatagram("BatchSend")),
+            Datagram::BatchAcks(_) => Err(HostError::UnsupportedDatagram("BatchAcks")),
+        }
+    }
+
+    /// Send a packet on `channel_id`, as an application on this chain would.
+    pub fn send_packet(
+        &mut self,
+        channel_id: ChannelId,
+        data: Bytes,
+        timeout_timestamp: Timestamp,
+    ) -> Result<Vec<(ClientId, FullEvent)>, HostError> {
+        let entry = self.open_channel(channel_id)?;
+
+        let packet = Packet {
+            source_channel_id: channel_id,
+            destination_channel_id: entry
+                .channel
+                .counterparty_channel_id
+                .expect("channel is open; qed;"),
+            data,
+            timeout_height: MustBeZero,
+            timeout_timestamp,
+        };
+
+        let packet_hash = packet.hash();
+
+        let key = BatchPacketsPath::from_packets(&[packet.clone()]).key();
+        if self.commitments.contains_key(&key) {
+            return Err(HostError::PacketAlreadySent(packet_hash));
+        }
+        self.commitments.insert(key, COMMITMENT_MAGIC);
+        self.packets.insert(packet_hash, packet.clone());
+
+        let (client_id, metadata) = self.packet_metadata(channel_id, &packet)?;
+
+        Ok(vec![(
+            client_id,
+            PacketSend {
+                packet_data: packet.data,
+                packet: metadata,
+            }
+            .into(),
+        )])
+    }
+
+    fn create_client(
+        &mut self,
+        msg: MsgCreateClient,
+    ) -> Result<Vec<(ClientId, FullEvent)>, HostError> {
+        if msg.client_type.as_str() != ClientType::MOCK {
+            return Err(HostError::UnsupportedClientType(msg.client_type));
+        }
+
+        let client_state =
+            decode::<ClientState>(&msg.client_state_bytes).map_err(|e| HostError::Decode {
+                what: "client state",
+                error: e.to_string(),
+            })?;
+        let consensus_state =
+            decode::<ConsensusState>(&msg.consensus_state_bytes).map_err(|e| {
+                HostError::Decode {
+                    what: "consensus state",
+                    error: e.to_string(),
+                }
+            })?;
+
+        self.next_client_id += 1;
+        let client_id = ClientId::from_raw(self.next_client_id).expect("non-zero; qed;");
+
+        self.commit_client_state(client_id, &client_state);
+        self.commit_consensus_state(client_id, client_state.latest_height, &consensus_state);
+
+        self.clients.insert(
+            client_id,
+            ClientEntry {
+                client_type: msg.client_type.clone(),
+                consensus_states: [(client_state.latest_height, consensus_state)].into(),
+                client_state,
+            },
+        );
+
+        Ok(vec![(
+            client_id,
+            CreateClient {
+                client_type: msg.client_type,
+                client_id,
+            }
+            .into(),
+        )])
+    }
+
+    fn update_client<C: Counterparties>(
+        &mut self,
+        ctx: &ExecutionContext<'_, C>,
+        msg: MsgUpdateClient,
+    ) -> Result<Vec<(ClientId, FullEvent)>, HostError> {
+        let header = decode::<Header>(&msg.client_message).map_err(|e| HostError::Decode {
+            what: "header",
+            error: e.to_string(),
+        })?;
+
+        let client = self.active_client(msg.client_id)?;
+
+        let invalid_header = |reason: String| HostError::InvalidHeader {
+            client_id: msg.client_id,
+            height: header.height,
+            reason,
+        };
+
+        let block = ctx
+            .counterparties
+            .finalized_block(&client.client_state.chain_id, header.height)
+            .ok_or_else(|| {
+                invalid_header(format!(
+                    "no finalized block at this height on {}",
+                    client.client_state.chain_id
+                ))
+            })?;
+
+        if block.to_header() != header {
+            return Err(invalid_header(format!(
+                "header does not match the canonical block {}",
+                block.hash
+            )));
+        }
+
+        let consensus_state = block.to_consensus_state();
+
+        let mut client_state = client.client_state.clone();
+        client_state.latest_height = client_state.latest_height.max(header.height);
+        let client_type = client.client_type.clone();
+
+        self.commit_client_state(msg.client_id, &client_state);
+        self.commit_consensus_state(msg.client_id, header.height, &consensus_state);
+
+        let client = self
+            .clients
+            .get_mut(&msg.client_id)
+            .expect("client exists; qed;");
+        client.client_state = client_state;
+        client
+            .consensus_states
+            .insert(header.height, consensus_state);
+
+        Ok(vec![(
+            msg.client_id,
+            UpdateClient {
+                client_type,
+                client_id: msg.client_id,
+                height: header.height,
+            }
+            .into(),
+        )])
+    }
+
+    fn connection_open_init(
+        &mut self,
+        msg: MsgConnectionOpenInit,
+    ) -> Result<Vec<(ClientId, FullEvent)>, HostError> {
+        self.active_client(msg.client_id)?;
+
+        let connection_id = self.next_connection_id();
+
+        self.save_connection(
+            connection_id,
+            Connection {
+                state: ConnectionState::Init,
+                client_id: msg.client_id,
+                counterparty_client_id: msg.counterparty_client_id,
+                counterparty_connection_id: None,
+            },
+        );
+
+        Ok(vec![(
+            msg.client_id,
+            ConnectionOpenInit {
+                connection_id,
+                client_id: msg.client_id,
+                counterparty_client_id: msg.counterparty_client_id,
+            }
+            .into(),
+        )])
+    }
+
+    fn connection_open_try(
+        &mut self,
+        msg: MsgConnectionOpenTry,
+    ) -> Result<Vec<(ClientId, FullEvent)>, HostError> {
+        let expected = Connection {
+            state: ConnectionState::Init,
+            client_id: msg.counterparty_client_id,
+            counterparty_client_id: msg.client_id,
+            counterparty_connection_id: None,
+        };
+
+        self.verify_membership(
+            msg.client_id,
+            msg.proof_height,
+            &msg.proof_init,
+            ConnectionPath {
+                connection_id: msg.counterparty_connection_id,
+            }
+            .key(),
+            commit_connection(&expected),
+        )?;
+
+        let connection_id = self.next_connection_id();
+
+        self.save_connection(
+            connection_id,
+            Connection {
+                state: ConnectionState::TryOpen,
+                client_id: msg.client_id,
+                counterparty_client_id: msg.counterparty_client_id,
+                counterparty_connection_id: Some(msg.counterparty_connection_id),
+            },
+        );
+
+        Ok(vec![(
+            msg.client_id,
+            ConnectionOpenTry {
+                connection_id,
+                client_id: msg.client_id,
+                counterparty_client_id: msg.counterparty_client_id,
+                counterparty_connection_id: msg.counterparty_connection_id,
+            }
+            .into(),
+        )])
+    }
+
+    fn connection_open_ack(
+        &mut self,
+        msg: MsgConnectionOpenAck,
+    ) -> Result<Vec<(ClientId, FullEvent)>, HostError> {
+        let mut connection = self.connection(msg.connection_id, ConnectionState::Init)?;
+
+        let expected = Connection {
+            state: ConnectionState::TryOpen,
+            client_id: connection.counterparty_client_id,
+            counterparty_client_id: connection.client_id,
+            counterparty_connection_id: Some(msg.connection_id),
+        };
+
+        self.verify_membership(
+            connection.client_id,
+            msg.proof_height,
+            &msg.proof_try,
+            ConnectionPath {
+                connection_id: msg.counterparty_connection_id,
+            }
+            .key(),
+            commit_connection(&expected),
+        )?;
+
+        connection.state = ConnectionState::Open;
+        connection.counterparty_connection_id = Some(msg.counterparty_connection_id);
+
+        self.save_connection(msg.connection_id, connection.clone());
+
+        Ok(vec![(
+            connection.client_id,
+            ConnectionOpenAck {
+                connection_id: msg.connection_id,
+                client_id: connection.client_id,
+                counterparty_client_id: connection.counterparty_client_id,
+                counterparty_connection_id: msg.counterparty_connection_id,
+            }
+            .into(),
+        )])
+    }
+
+    fn connection_open_confirm(
+        &mut self,
+        msg: MsgConnectionOpenConfirm,
+    ) -> Result<Vec<(ClientId, FullEvent)>, HostError> {
+        let mut connection = self.connection(msg.connection_id, ConnectionState::TryOpen)?;
+
+        let counterparty_connection_id = connection
+            .counterparty_connection_id
+            .expect("connection is in TryOpen; qed;");
+
+        let expected = Connection {
+            state: ConnectionState::Open,
+            client_id: connection.counterparty_client_id,
+            counterparty_client_id: connection.client_id,
+            counterparty_connection_id: Some(msg.connection_id),
+        };
+
+        self.verify_membership(
+            connection.client_id,
+            msg.proof_height,
+            &msg.proof_ack,
+            ConnectionPath {
+                connection_id: counterparty_connection_id,
+            }
+            .key(),
+            commit_connection(&expected),
+        )?;
+
+        connection.state = ConnectionState::Open;
+
+        self.save_connection(msg.connection_id, connection.clone());
+
+        Ok(vec![(
+            connection.client_id,
+            ConnectionOpenConfirm {
+                connection_id: msg.connection_id,
+                client_id: connection.client_id,
+                counterparty_client_id: connection.counterparty_client_id,
+                counterparty_connection_id,
+            }
+            .into(),
+        )])
+    }
+
+    fn channel_open_init(
+        &mut self,
+        msg: MsgChannelOpenInit,
+    ) -> Result<Vec<(ClientId, FullEvent)>, HostError> {
+        let connection = self.connection(msg.connection_id, ConnectionState::Open)?;
+
+        let channel_id = self.next_channel_id();
+
+        self.save_channel(
+            channel_id,
+            msg.port_id.clone(),
+            Channel {
+                state: ChannelState::Init,
+                connection_id: msg.connection_id,
+                counterparty_channel_id: None,
+                counterparty_port_id: msg.counterparty_port_id.clone(),
+                version: msg.version.clone(),
+            },
+        );
+
+        Ok(vec![(
+            connection.client_id,
+            ChannelOpenInit {
+                port_id: msg.port_id,
+                channel_id,
+                counterparty_port_id: msg.counterparty_port_id,
+                connection,
+                version: msg.version,
+            }
+            .into(),
+        )])
+    }
+
+    fn channel_open_try(
+        &mut self,
+        msg: MsgChannelOpenTry,
+    ) -> Result<Vec<(ClientId, FullEvent)>, HostError> {
+        let channel_id = self.next_channel_id();
+
+        if msg.channel.state != ChannelState::TryOpen {
+            return Err(HostError::InvalidChannelState {
+                channel_id,
+                expected: ChannelState::TryOpen,
+                found: msg.channel.state,
+            });
+        }
+
+        let connection = self.connection(msg.channel.connection_id, ConnectionState::Open)?;
+
+        let counterparty_channel_id =
+            msg.channel
+                .counterparty_channel_id
+                .ok_or(HostError::InvalidChannelState {
+                    channel_id,
+                    expected: ChannelState::TryOpen,
+                    found: msg.channel.state,
+                })?;
+
+        let expected = Channel {
+            state: ChannelState::Init,
+            connection_id: connection
+                .counterparty_connection_id
+                .expect("connection is open; qed;"),
+            counterparty_channel_id: None,
+            counterparty_port_id: msg.port_id.clone(),
+            version: msg.counterparty_version,
+        };
+
+        self.verify_membership(
+            connection.client_id,
+            msg.proof_height,
+            &msg.proof_init,
+            ChannelPath {
+                channel_id: counterparty_channel_id,
+            }
+            .key(),
+            commit_channel(&expected),
+        )?;
+
+        self.save_channel(channel_id, msg.port_id.clone(), msg.channel.clone());
+
+        Ok(vec![(
+            connection.client_id,
+            ChannelOpenTry {
+                port_id: msg.port_id,
+                channel_id,
+                counterparty_port_id: msg.channel.counterparty_port_id,
+                counterparty_channel_id,
+                connection,
+                version: msg.channel.version,
+            }
+            .into(),
+        )])
+    }
+
+    fn channel_open_ack(
+        &mut self,
+        msg: MsgChannelOpenAck,
+    ) -> Result<Vec<(ClientId, FullEvent)>, HostError> {
+        let ChannelEntry {
+            port_id,
+            mut channel,
+        } = self.channel(msg.channel_id, ChannelState::Init)?;
+
+        let connection = self.connection(channel.connection_id, ConnectionState::Open)?;
+
+        let expected = Channel {
+            state: ChannelState::TryOpen,
+            connection_id: connection
+                .counterparty_connection_id
+                .expect("connection is open; qed;"),
+            counterparty_channel_id: Some(msg.channel_id),
+            counterparty_port_id: port_id.clone(),
+            version: msg.counterparty_version.clone(),
+        };
+
+        self.verify_membership(
+            connection.client_id,
+            msg.proof_height,
+            &msg.proof_try,
+            ChannelPath {
+                channel_id: msg.counterparty_channel_id,
+            }
+            .key(),
+            commit_channel(&expected),
+        )?;
+
+        channel.state = ChannelState::Open;
+        channel.counterparty_channel_id = Some(msg.counterparty_channel_id);
+        channel.version = msg.counterparty_version;
+
+        self.save_channel(msg.channel_id, port_id.clone(), channel.clone());
+
+        Ok(vec![(
+            connection.client_id,
+            ChannelOpenAck {
+                port_id,
+                channel_id: msg.channel_id,
+                counterparty_port_id: channel.counterparty_port_id,
+                counterparty_channel_id: msg.counterparty_channel_id,
+                connection,
+                version: channel.version,
+            }
+            .into(),
+        )])
+    }
+
+    fn channel_open_confirm(
+        &mut self,
+        msg: MsgChannelOpenConfirm,
+    ) -> Result<Vec<(ClientId, FullEvent)>, HostError> {
+        let ChannelEntry {
+            port_id,
+            mut channel,
+        } = self.channel(msg.channel_id, ChannelState::TryOpen)?;
+
+        let connection = self.connection(channel.connection_id, ConnectionState::Open)?;
+
+        let counterparty_channel_id = channel
+            .counterparty_channel_id
+            .expect("channel is in TryOpen; qed;");
+
+        let expected = Channel {
+            state: ChannelState::Open,
+            connection_id: connection
+                .counterparty_connection_id
+                .expect("connection is open; qed;"),
+            counterparty_channel_id: Some(msg.channel_id),
+            counterparty_port_id: port_id.clone(),
+            version: channel.version.clone(),
+        };
+
+        self.verify_membership(
+            connection.client_id,
+            msg.proof_height,
+            &msg.proof_ack,
+            ChannelPath {
+                channel_id: counterparty_channel_id,
+            }
+            .key(),
+            commit_channel(&expected),
+        )?;
+
+        channel.state = ChannelState::Open;
+
+        self.save_channel(msg.channel_id, port_id.clone(), channel.clone());
+
+        Ok(vec![(
+            connection.client_id,
+            ChannelOpenConfirm {
+                port_id,
+                channel_id: msg.channel_id,
+                counterparty_port_id: channel.counterparty_port_id,
+                counterparty_channel_id,
+                connection,
+                version: channel.version,
+            }
+            .into(),
+        )])
+    }
+
+    fn packet_recv<C: Counterparties>(
+        &mut self,
+        ctx: &ExecutionContext<'_, C>,
+        msg: MsgPacketRecv,
+    ) -> Result<Vec<(ClientId, FullEvent)>, HostError> {
+        let channel_id = batch_channel(&msg.packets, |packet| packet.destination_channel_id)?;
+
+        let entry = self.open_channel(channel_id)?;
+        let connection = self.connection(entry.channel.connection_id, ConnectionState::Open)?;
+        let counterparty_channel_id = entry
+            .channel
+            .counterparty_channel_id
+            .expect("channel is open; qed;");
+
+        for packet in &msg.packets {
+            if packet.source_channel_id != counterparty_channel_id {
+                return Err(HostError::InvalidPacketSource {
+                    expected: counterparty_channel_id,
+                    found: packet.source_channel_id,
+                });
+            }
+
+            if !packet.timeout_timestamp.is_zero() && ctx.timestamp >= packet.timeout_timestamp {
+                return Err(HostError::PacketTimedOut {
+                    packet_hash: packet.hash(),
+                    timeout_timestamp: packet.timeout_timestamp,
+                    timestamp: ctx.timestamp,
+                });
+            }
+        }
+
+        self.verify_membership(
+            connection.client_id,
+            msg.proof_height,
+            &msg.proof,
+            BatchPacketsPath::from_packets(&msg.packets).key(),
+            COMMITMENT_MAGIC,
+        )?;
+
+        let mut events = vec![];
+
+        for (idx, packet) in msg.packets.into_iter().enumerate() {
+            let receipt_key = BatchReceiptsPath::from_packets(&[packet.clone()]).key();
+
+            // already received packets are a noop, to allow for racing relayers
+            if self.commitments.contains_key(&receipt_key) {
+                continue;
+            }
+
+            self.commitments.insert(receipt_key, COMMITMENT_MAGIC);
+
+            let (client_id, metadata) = self.packet_metadata(channel_id, &packet)?;
+
+            events.push((
+                client_id,
+                PacketRecv {
+                    packet_data: packet.data.clone(),
+                    packet: metadata.clone(),
+                    maker_msg: msg.relayer_msgs.get(idx).cloned().unwrap_or_default(),
+                }
+                .into(),
+            ));
+
+            // the mock application synchronously acknowledges every packet by echoing the data
+            let acknowledgement = packet.data.clone();
+
+            self.commitments
+                .insert(receipt_key, commit_acks(&[acknowledgement.clone()]));
+
+            events.push((
+                client_id,
+                WriteAck {
+                    packet_data: packet.data,
+                    packet: metadata,
+                    acknowledgement,
+                }
+                .into(),
+            ));
+        }
+
+        Ok(events)
+    }
+
+    fn packet_acknowledgement(
+        &mut self,
+        msg: MsgPacketAcknowledgement,
+    ) -> Result<Vec<(ClientId, FullEvent)>, HostError> {
+        let channel_id = batch_channel(&msg.packets, |packet| packet.source_channel_id)?;
+
+        if msg.packets.len() != msg.acknowledgements.len() {
+            return Err(HostError::AcknowledgementsLength {
+                expected: msg.packets.len(),
+                found: msg.acknowledgements.len(),
+            });
+        }
+
+        let entry = self.open_channel(channel_id)?;
+        let connection = self.connection(entry.channel.connection_id, ConnectionState::Open)?;
+
+        self.verify_membership(
+            connection.client_id,
+            msg.proof_height,
+            &msg.proof,
+            BatchReceiptsPath::from_packets(&msg.packets).key(),
+            commit_acks(&msg.acknowledgements),
+        )?;
+
+        let mut events = vec![];
+
+        for (packet, acknowledgement) in msg.packets.into_iter().zip(msg.acknowledgements) {
+            self.mark_packet_done(&packet)?;
+
+            let (client_id, metadata) = self.packet_metadata(channel_id, &packet)?;
+
+            events.push((
+                client_id,
+                PacketAck {
+                    packet_data: packet.data,
+                    packet: metadata,
+                    acknowledgement,
+                }
+                .into(),
+            ));
+        }
+
+        Ok(events)
+    }
+
+    fn packet_timeout(
+        &mut self,
+        msg: MsgPacketTimeout,
+    ) -> Result<Vec<(ClientId, FullEvent)>, HostError> {
+        let channel_id = msg.packet.source_channel_id;
+
+        let entry = self.open_channel(channel_id)?;
+        let connection = self.connection(entry.channel.connection_id, ConnectionState::Open)?;
+
+        let proof_timestamp = self
+            .consensus_state(connection.client_id, msg.proof_height)?
+            .timestamp;
+
+        if proof_timestamp < msg.packet.timeout_timestamp {
+            return Err(HostError::TimeoutNotReached {
+                packet_hash: msg.packet.hash(),
+                timeout_timestamp: msg.packet.timeout_timestamp,
+                proof_timestamp,
+            });
+        }
+
+        self.verify_non_membership(
+            connection.client_id,
+            msg.proof_height,
+            &msg.proof,
+            BatchReceiptsPath::from_packets(&[msg.packet.clone()]).key(),
+        )?;
+
+        self.mark_packet_done(&msg.packet)?;
+
+        let (client_id, metadata) = self.packet_metadata(channel_id, &msg.packet)?;
+
+        Ok(vec![(
+            client_id,
+            PacketTimeout {
+                packet_data: msg.packet.data,
+                packet: metadata,
+            }
+            .into(),
+        )])
+    }
+
+    fn mark_packet_done(&mut self, packet: &Packet) -> Result<(), HostError> {
+        let key = BatchPacketsPath::from_packets(&[packet.clone()]).key();
+
+        match self.commitments.get_mut(&key) {
+            Some(commitment) if *commitment == COMMITMENT_MAGIC => {
+                *commitment = COMMITMENT_MAGIC_ACK;
+                Ok(())
+            }
+            _ => Err(HostError::PacketNotSent(packet.hash())),
+        }
+    }
+
+    /// Build the metadata for a packet sent or received on `channel_id` on this chain, returning
+    /// the client the channel is built on.
+    fn packet_metadata(
+        &self,
+        channel_id: ChannelId,
+        packet: &Packet,
+    ) -> Result<(ClientId, PacketMetadata), HostError> {
+        let entry = self.open_channel(channel_id)?;
+        let connection = self.connection(entry.channel.connection_id, ConnectionState::Open)?;
+
+        let this = ChannelMetadata {
+            channel_id,
+            version: entry.channel.version.clone(),
+            connection: ConnectionMetadata {
+                client_id: connection.client_id,
+                connection_id: entry.channel.connection_id,
+            },
+        };
+
+        let counterparty = ChannelMetadata {
+            channel_id: entry
+                .channel
+                .counterparty_channel_id
+                .expect("channel is open; qed;"),
+            version: entry.channel.version.clone(),
+            connection: ConnectionMetadata {
+                client_id: connection.counterparty_client_id,
+                connection_id: connection
+                    .counterparty_connection_id
+                    .expect("connection is open; qed;"),
+            },
+        };
+
+        let (source_channel, destination_channel) = if packet.source_channel_id == channel_id {
+            (this, counterparty)
+        } else {
+            (counterparty, this)
+        };
+
+        Ok((
+            connection.client_id,
+            PacketMetadata {
+                source_channel,
+                destination_channel,
+                timeout_timestamp: packet.timeout_timestamp,
+            },
+        ))
+    }
+
+    fn active_client(&self, client_id: ClientId) -> Result<&ClientEntry, HostError> {
+        let client = self
+            .clients
+            .get(&client_id)
+            .ok_or(HostError::ClientNotFound(client_id))?;
+
+        if client.client_state.frozen {
+            Err(HostError::ClientFrozen(client_id))
+        } else {
+            Ok(client)
+        }
+    }
+
+    fn consensus_state(
+        &self,
+        client_id: ClientId,
+        height: u64,
+    ) -> Result<&ConsensusState, HostError> {
+        self.active_client(client_id)?
+            .consensus_states
+            .get(&height)
+            .ok_or(HostError::ConsensusStateNotFound { client_id, height })
+    }
+
+    fn verify_membership(
+        &self,
+        client_id: ClientId,
+        height: u64,
+        proof: &[u8],
+        key: H256,
+        value: H256,
+    ) -> Result<(), HostError> {
+        let root = self.consensus_state(client_id, height)?.state_root;
+
+        decode_proof(proof)?
+            .verify_membership(&root, &key, &value)
+            .map_err(HostError::InvalidProof)
+    }
+
+    fn verify_non_membership(
+        &self,
+        client_id: ClientId,
+        height: u64,
+        proof: &[u8],
+        key: H256,
+    ) -> Result<(), HostError> {
+        let root = self.consensus_state(client_id, height)?.state_root;
+
+        decode_proof(proof)?
+            .verify_non_membership(&root, &key)
+            .map_err(HostError::InvalidProof)
+    }
+
+    fn connection(
+        &self,
+        connection_id: ConnectionId,
+        expected: ConnectionState,
+    ) -> Result<Connection, HostError> {
+        let connection = self
+            .connections
+            .get(&connection_id)
+            .ok_or(HostError::ConnectionNotFound(connection_id))?;
+
+        if connection.state == expected {
+            Ok(connection.clone())
+        } else {
+            Err(HostError::InvalidConnectionState {
+                connection_id,
+                expected,
+                found: connection.state,
+            })
+        }
+    }
+
+    fn channel(
+        &self,
+        channel_id: ChannelId,
+        expected: ChannelState,
+    ) -> Result<ChannelEntry, HostError> {
+        let entry = self
+            .channels
+            .get(&channel_id)
+            .ok_or(HostError::ChannelNotFound(channel_id))?;
+
+        if entry.channel.state == expected {
+            Ok(entry.clone())
+        } else {
+            Err(HostError::InvalidChannelState {
+                channel_id,
+                expected,
+                found: entry.channel.state,
+            })
+        }
+    }
+
+    fn open_channel(&self, channel_id: ChannelId) -> Result<ChannelEntry, HostError> {
+        self.channel(channel_id, ChannelState::Open)
+    }
+
+    fn next_connection_id(&mut self) -> ConnectionId {
+        self.next_connection_id += 1;
+        ConnectionId::from_raw(self.next_connection_id).expect("non-zero; qed;")
+    }
+
+    fn next_channel_id(&mut self) -> ChannelId {
+        self.next_channel_id += 1;
+        ChannelId::from_raw(self.next_channel_id).expect("non-zero; qed;")
+    }
+
+    fn save_connection(&mut self, connection_id: ConnectionId, connection: Connection) {
+        self.commitments.insert(
+            ConnectionPath { connection_id }.key(),
+            commit_connection(&connection),
+        );
+        self.connections.insert(connection_id, connection);
+    }
+
+    fn save_channel(&mut self, channel_id: ChannelId, port_id: Bytes, channel: Channel) {
+        self.commitments
+            .insert(ChannelPath { channel_id }.key(), commit_channel(&channel));
+        self.channels
+            .insert(channel_id, ChannelEntry { port_id, channel });
+    }
+
+    fn commit_client_state(&mut self, client_id: ClientId, client_state: &ClientState) {
+        self.commitments.insert(
+            ClientStatePath { client_id }.key(),
+            keccak256(encode(client_state)),
+        );
+    }
+
+    fn commit_consensus_state(
+        &mut self,
+        client_id: ClientId,
+        height: u64,
+        consensus_state: &ConsensusState,
+    ) {
+        self.commitments.insert(
+            ConsensusStatePath { client_id, height }.key(),
+            keccak256(encode(consensus_state)),
+        );
+    }
+}
+
+/// Ensure that all of the packets in the batch are on the same channel, returning that channel.
+fn batch_channel(
+    packets: &[Packet],
+    f: impl Fn(&Packet) -> ChannelId,
+) -> Result<ChannelId, HostError> {
+    let channel_id = f(packets.first().ok_or(HostError::EmptyBatch)?);
+
+    if packets.iter().all(|packet| f(packet) == channel_id) {
+        Ok(channel_id)
+    } else {
+        Err(HostError::MixedChannels)
+    }
+}
+
+fn decode_proof(proof: &[u8]) -> Result<StateProof, HostError> {
+    decode(proof).map_err(|e| HostError::Decode {
+        what: "proof",
+        error: e.to_string(),
+    })
+}
+
+fn into_value<T: serde::Serialize>(t: T) -> Value {
+    serde_json::to_value(t).expect("serialization is infallible; qed;")
+}
+
+fn commit_connection(connection: &Connection) -> H256 {
+    keccak256(connection.abi_encode_params())
+}
+
+fn commit_channel(channel: &Channel) -> H256 {
+    keccak256(channel.abi_encode_params())
+}
+
+/// Same as the acknowledgement commitment in the ibc-union core contracts.
+#[must_use]
+pub fn commit_acks(acks: &[Bytes]) -> H256 {
+    let mut commitment = keccak256(acks.abi_encode());
+    commitment.get_mut()[0] = 0x01;
+    commitment
+}
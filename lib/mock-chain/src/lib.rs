@@ -0,0 +1,34 @@
+//! An in-memory, deterministic simulation of a set of chains running the ibc-union protocol.
+//!
+//! This is intended to be used to test voyager end-to-end without running any real chains: each
+//! [`MockChain`] is exposed over JSON-RPC (see [`rpc`]), and the voyager modules and plugins in
+//! `voyager/modules/*/mock` and `voyager/plugins/*/mock` connect to it like they would to any
+//! other chain. Tests drive block production, reorgs and failures through the [`MockNetwork`] and
+//! [`MockChain`] handles.
+
+use voyager_primitives::ChainId;
+
+pub mod host;
+pub mod rpc;
+pub mod types;
+
+mod network;
+
+pub use crate::{
+    network::{ChainConfig, MockChain, MockNetwork},
+    types::{BlockHeader, ClientState, ConsensusState, Header, MockEvent, StateProof, TxResult},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum Error {
+    #[error("chain {0} already exists")]
+    DuplicateChain(ChainId),
+    #[error("unknown chain {0}")]
+    UnknownChain(ChainId),
+    #[error("block {0} does not exist")]
+    MissingBlock(u64),
+    #[error("cannot reorg {depth} blocks, only {max} blocks are not finalized")]
+    ReorgTooDeep { depth: u64, max: u64 },
+    #[error("injected failure")]
+    InjectedFailure,
+}
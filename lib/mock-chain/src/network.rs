@@ -0,0 +1,667 @@
+use std::{
+    collections::{BTreeMap, VecDeque},
+    sync::{Arc, Mutex, MutexGuard},
+    time::Duration,
+};
+
+use ibc_union_spec::{
+    datagram::Datagram, event::FullEvent, path::StorePath, ChannelId, ClientId, Packet, Status,
+    Timestamp,
+};
+use serde_json::Value;
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn};
+use unionlabs::{
+    ethereum::keccak256,
+    primitives::{Bytes, H256},
+};
+use voyager_primitives::{ChainId, ClientType};
+
+use crate::{
+    host::{Counterparties, ExecutionContext, HostError, IbcState},
+    types::{BlockHeader, ClientState, ConsensusState, MockEvent, StateProof, TxResult},
+    Error,
+};
+
+/// The configuration of a single chain in a [`MockNetwork`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChainConfig {
+    pub chain_id: ChainId,
+    /// The timestamp of the genesis block (height 1).
+    pub genesis_timestamp: Timestamp,
+    /// The time between blocks. Block timestamps are derived from this, not from the wall clock.
+    pub block_time: Duration,
+    /// The number of blocks behind the latest block that the finalized block is.
+    pub finality_lag: u64,
+}
+
+impl ChainConfig {
+    #[must_use]
+    pub fn new(chain_id: impl Into<String>) -> Self {
+        Self {
+            chain_id: ChainId::new(chain_id.into()),
+            genesis_timestamp: Timestamp::from_secs(1_700_000_000),
+            block_time: Duration::from_secs(1),
+            finality_lag: 0,
+        }
+    }
+
+    #[must_use]
+    pub fn with_finality_lag(mut self, finality_lag: u64) -> Self {
+        self.finality_lag = finality_lag;
+        self
+    }
+
+    #[must_use]
+    pub fn with_block_time(mut self, block_time: Duration) -> Self {
+        self.block_time = block_time;
+        self
+    }
+}
+
+/// A set of simulated chains that can track each other with [`ClientType::MOCK`] clients.
+///
+/// All state is held in memory, and blocks are only produced when requested (either explicitly
+/// via [`MockChain::produce_block`] or by [`MockNetwork::spawn_block_producer`]), so the behaviour
+/// of the network is fully deterministic.
+#[derive(Debug, Clone, Default)]
+pub struct MockNetwork {
+    inner: Arc<Mutex<BTreeMap<ChainId, ChainState>>>,
+}
+
+impl MockNetwork {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a new chain to the network, producing its genesis block.
+    pub fn add_chain(&self, config: ChainConfig) -> Result<MockChain, Error> {
+        let mut chains = self.lock();
+
+        if chains.contains_key(&config.chain_id) {
+            return Err(Error::DuplicateChain(config.chain_id));
+        }
+
+        let chain_id = config.chain_id.clone();
+
+        chains.insert(chain_id.clone(), ChainState::new(config));
+
+        Ok(MockChain {
+            chain_id,
+            network: self.clone(),
+        })
+    }
+
+    #[must_use]
+    pub fn chain(&self, chain_id: &ChainId) -> Option<MockChain> {
+        self.lock().contains_key(chain_id).then(|| MockChain {
+            chain_id: chain_id.clone(),
+            network: self.clone(),
+        })
+    }
+
+    /// Produce one block on every chain in the network.
+    pub fn produce_blocks(&self) {
+        let chain_ids = self.lock().keys().cloned().collect::<Vec<_>>();
+
+        for chain_id in chain_ids {
+            self.produce_block(&chain_id)
+                .expect("chain exists in the network; qed;");
+        }
+    }
+
+    /// Spawn a task that calls [`Self::produce_blocks`] every `interval`. The task runs until the
+    /// returned handle is aborted.
+    pub fn spawn_block_producer(&self, interval: Duration) -> JoinHandle<()> {
+        let network = self.clone();
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(interval);
+
+            loop {
+                interval.tick().await;
+                network.produce_blocks();
+            }
+        })
+    }
+
+    fn lock(&self) -> MutexGuard<'_, BTreeMap<ChainId, ChainState>> {
+        // a panic while holding the lock can't leave the network in an inconsistent state, since
+        // all mutations are applied to cloned state and swapped in at the end
+        self.inner.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn with_chain<T>(
+        &self,
+        chain_id: &ChainId,
+        f: impl FnOnce(&mut ChainState) -> Result<T, Error>,
+    ) -> Result<T, Error> {
+        let mut chains = self.lock();
+
+        let chain = chains
+            .get_mut(chain_id)
+            .ok_or_else(|| Error::UnknownChain(chain_id.clone()))?;
+
+        f(chain)
+    }
+
+    fn produce_block(&self, chain_id: &ChainId) -> Result<BlockHeader, Error> {
+        let mut chains = self.lock();
+
+        // take the chain out of the network so that the rest of the network can be used to verify
+        // client updates while the block is being built
+        let mut chain = chains
+            .remove(chain_id)
+            .ok_or_else(|| Error::UnknownChain(chain_id.clone()))?;
+
+        let header = chain.produce_block(&*chains);
+
+        chains.insert(chain_id.clone(), chain);
+
+        Ok(header)
+    }
+}
+
+impl Counterparties for BTreeMap<ChainId, ChainState> {
+    fn finalized_block(&self, chain_id: &ChainId, height: u64) -> Option<BlockHeader> {
+        self.get(chain_id)
+            .filter(|chain| height <= chain.finalized_height())
+            .and_then(|chain| chain.block(height))
+            .map(|block| block.header.clone())
+    }
+}
+
+/// A handle to a single chain in a [`MockNetwork`].
+#[derive(Debug, Clone)]
+pub struct MockChain {
+    chain_id: ChainId,
+    network: MockNetwork,
+}
+
+impl MockChain {
+    #[must_use]
+    pub fn chain_id(&self) -> &ChainId {
+        &self.chain_id
+    }
+
+    #[must_use]
+    pub fn network(&self) -> &MockNetwork {
+        &self.network
+    }
+
+    /// Produce a new block, including all of the transactions currently in the mempool.
+    pub fn produce_block(&self) -> BlockHeader {
+        self.network
+            .produce_block(&self.chain_id)
+            .expect("chain exists in the network; qed;")
+    }
+
+    /// Revert the latest `depth` blocks. All transactions in the reverted blocks are put back into
+    /// the mempool, and will be included again in the next block.
+    ///
+    /// Only non-finalized blocks can be reverted.
+    pub fn reorg(&self, depth: u64) -> Result<(), Error> {
+        self.network
+            .with_chain(&self.chain_id, |chain| chain.reorg(depth))
+    }
+
+    pub fn set_finality_lag(&self, finality_lag: u64) {
+        self.network
+            .with_chain(&self.chain_id, |chain| {
+                chain.config.finality_lag = finality_lag;
+                Ok(())
+            })
+            .expect("chain exists in the network; qed;");
+    }
+
+    /// Fail the next `n` RPC requests made to this chain.
+    pub fn fail_rpc_requests(&self, n: u64) {
+        self.network
+            .with_chain(&self.chain_id, |chain| {
+                chain.failures.rpc_requests += n;
+                Ok(())
+            })
+            .expect("chain exists in the network; qed;");
+    }
+
+    /// Reject the next `n` transactions submitted to this chain.
+    pub fn fail_submissions(&self, n: u64) {
+        self.network
+            .with_chain(&self.chain_id, |chain| {
+                chain.failures.submissions += n;
+                Ok(())
+            })
+            .expect("chain exists in the network; qed;");
+    }
+
+    /// Returns an error if an RPC failure has been injected with [`Self::fail_rpc_requests`].
+    pub fn check_rpc_failure(&self) -> Result<(), Error> {
+        self.network.with_chain(&self.chain_id, |chain| {
+            if chain.failures.rpc_requests > 0 {
+                chain.failures.rpc_requests -= 1;
+                Err(Error::InjectedFailure)
+            } else {
+                Ok(())
+            }
+        })
+    }
+
+    #[must_use]
+    pub fn latest_height(&self, finalized: bool) -> u64 {
+        self.network
+            .with_chain(&self.chain_id, |chain| {
+                Ok(if finalized {
+                    chain.finalized_height()
+                } else {
+                    chain.latest_height()
+                })
+            })
+            .expect("chain exists in the network; qed;")
+    }
+
+    #[must_use]
+    pub fn block(&self, height: u64) -> Option<BlockHeader> {
+        self.network
+            .with_chain(&self.chain_id, |chain| {
+                Ok(chain.block(height).map(|block| block.header.clone()))
+            })
+            .expect("chain exists in the network; qed;")
+    }
+
+    /// Submit a transaction to the mempool, returning the hash of the transaction. All of the
+    /// datagrams in the transaction are executed atomically.
+    pub fn submit(&self, datagrams: Vec<Datagram>) -> Result<H256, Error> {
+        self.network.with_chain(&self.chain_id, |chain| {
+            if chain.failures.submissions > 0 {
+                chain.failures.submissions -= 1;
+                return Err(Error::InjectedFailure);
+            }
+
+            Ok(chain.push_tx(TxBody::Datagrams(datagrams)))
+        })
+    }
+
+    /// Send a packet from the mock application on this chain. The packet is sent in a transaction
+    /// in the next block; the hash of the packet can be read from the `PacketSend` event of the
+    /// transaction.
+    pub fn send_packet(
+        &self,
+        channel_id: ChannelId,
+        data: Bytes,
+        timeout_timestamp: Timestamp,
+    ) -> H256 {
+        self.network
+            .with_chain(&self.chain_id, |chain| {
+                Ok(chain.push_tx(TxBody::SendPacket {
+                    channel_id,
+                    data,
+                    timeout_timestamp,
+                }))
+            })
+            .expect("chain exists in the network; qed;")
+    }
+
+    /// Returns the packet sent in the transaction `tx_hash`, if the transaction was successfully
+    /// included and sent a packet.
+    #[must_use]
+    pub fn sent_packet(&self, tx_hash: H256) -> Option<Packet> {
+        match self.tx_result(tx_hash)? {
+            TxResult::Success { events, .. } => events.into_iter().find_map(|event| match event {
+                FullEvent::PacketSend(event) => Some(event.packet()),
+                _ => None,
+            }),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn tx_result(&self, tx_hash: H256) -> Option<TxResult> {
+        self.network
+            .with_chain(&self.chain_id, |chain| {
+                Ok(chain.tx_results.get(&tx_hash).cloned())
+            })
+            .expect("chain exists in the network; qed;")
+    }
+
+    pub fn query_ibc_state(&self, height: u64, path: &StorePath) -> Result<Value, Error> {
+        self.at(height, |state| state.query(path))
+    }
+
+    pub fn query_ibc_proof(&self, height: u64, _path: &StorePath) -> Result<StateProof, Error> {
+        // every proof contains the full set of commitments, so the path is only used to determine
+        // the type of the proof by the caller
+        self.at(height, IbcState::proof)
+    }
+
+    pub fn client_type(&self, client_id: ClientId) -> Option<ClientType> {
+        self.at_latest(|state| {
+            state
+                .clients
+                .get(&client_id)
+                .map(|client| client.client_type.clone())
+        })
+    }
+
+    pub fn client_status(&self, height: u64, client_id: ClientId) -> Result<Option<Status>, Error> {
+        self.at(height, |state| state.client_status(client_id))
+    }
+
+    pub fn client_state(
+        &self,
+        height: u64,
+        client_id: ClientId,
+    ) -> Result<Option<ClientState>, Error> {
+        self.at(height, |state| {
+            state
+                .clients
+                .get(&client_id)
+                .map(|client| client.client_state.clone())
+        })
+    }
+
+    pub fn events(&self, height: u64) -> Result<Vec<MockEvent>, Error> {
+        self.network.with_chain(&self.chain_id, |chain| {
+            chain
+                .block(height)
+                .map(|block| block.events.clone())
+                .ok_or(Error::MissingBlock(height))
+        })
+    }
+
+    /// Look up a packet sent from this chain by its hash.
+    #[must_use]
+    pub fn packet_by_hash(&self, channel_id: ChannelId, packet_hash: H256) -> Option<Packet> {
+        self.at_latest(|state| {
+            state
+                .packets
+                .get(&packet_hash)
+                .filter(|packet| packet.source_channel_id == channel_id)
+                .cloned()
+        })
+    }
+
+    /// The client state of a [`ClientType::MOCK`] client tracking this chain at `height`.
+    pub fn self_client_state(&self, height: u64) -> Result<ClientState, Error> {
+        self.block(height)
+            .map(|_| ClientState {
+                chain_id: self.chain_id.clone(),
+                latest_height: height,
+                frozen: false,
+            })
+            .ok_or(Error::MissingBlock(height))
+    }
+
+    /// The consensus state of a [`ClientType::MOCK`] client tracking this chain at `height`.
+    pub fn self_consensus_state(&self, height: u64) -> Result<ConsensusState, Error> {
+        self.block(height)
+            .map(|block| block.to_consensus_state())
+            .ok_or(Error::MissingBlock(height))
+    }
+
+    fn at<T>(&self, height: u64, f: impl FnOnce(&IbcState) -> T) -> Result<T, Error> {
+        self.network.with_chain(&self.chain_id, |chain| {
+            chain
+                .block(height)
+                .map(|block| f(&block.state))
+                .ok_or(Error::MissingBlock(height))
+        })
+    }
+
+    fn at_latest<T>(&self, f: impl FnOnce(&IbcState) -> T) -> T {
+        self.network
+            .with_chain(&self.chain_id, |chain| {
+                Ok(f(&chain
+                    .blocks
+                    .last()
+                    .expect("genesis block exists; qed;")
+                    .state))
+            })
+            .expect("chain exists in the network; qed;")
+    }
+}
+
+#[derive(Debug, Clone)]
+enum TxBody {
+    Datagrams(Vec<Datagram>),
+    SendPacket {
+        channel_id: ChannelId,
+        data: Bytes,
+        timeout_timestamp: Timestamp,
+    },
+}
+
+#[derive(Debug, Clone)]
+struct Tx {
+    hash: H256,
+    body: TxBody,
+}
+
+#[derive(Debug, Clone)]
+struct Block {
+    header: BlockHeader,
+    state: IbcState,
+    events: Vec<MockEvent>,
+    txs: Vec<Tx>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Failures {
+    rpc_requests: u64,
+    submissions: u64,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct ChainState {
+    config: ChainConfig,
+    /// The canonical chain. `blocks[0]` is the genesis block at height 1.
+    blocks: Vec<Block>,
+    mempool: VecDeque<Tx>,
+    tx_results: BTreeMap<H256, TxResult>,
+    tx_count: u64,
+    /// The number of reorgs that have occurred on this chain. This is mixed into the block
+    /// timestamps so that blocks produced after a reorg are distinct from the reverted ones.
+    reorgs: u64,
+    failures: Failures,
+}
+
+impl ChainState {
+    fn new(config: ChainConfig) -> Self {
+        let state = IbcState::default();
+
+        let genesis = Block {
+            header: BlockHeader {
+                height: 1,
+                hash: block_hash(
+                    H256::default(),
+                    1,
+                    config.genesis_timestamp,
+                    state.state_root(),
+                ),
+                parent_hash: H256::default(),
+                timestamp: config.genesis_timestamp,
+                state_root: state.state_root(),
+            },
+            state,
+            events: vec![],
+            txs: vec![],
+        };
+
+        Self {
+            config,
+            blocks: vec![genesis],
+            mempool: VecDeque::new(),
+            tx_results: BTreeMap::new(),
+            tx_count: 0,
+            reorgs: 0,
+            failures: Failures::default(),
+        }
+    }
+
+    fn latest_height(&self) -> u64 {
+        self.blocks.len() as u64
+    }
+
+    fn finalized_height(&self) -> u64 {
+        self.latest_height()
+            .saturating_sub(self.config.finality_lag)
+            .max(1)
+    }
+
+    fn block(&self, height: u64) -> Option<&Block> {
+        height
+            .checked_sub(1)
+            .and_then(|idx| self.blocks.get(usize::try_from(idx).ok()?))
+    }
+
+    fn push_tx(&mut self, body: TxBody) -> H256 {
+        self.tx_count += 1;
+
+        let hash = keccak256(
+            self.config
+                .chain_id
+                .as_str()
+                .as_bytes()
+                .iter()
+                .chain(&self.tx_count.to_be_bytes())
+                .copied()
+                .collect::<Vec<_>>(),
+        );
+
+        debug!(chain_id = %self.config.chain_id, %hash, "new transaction");
+
+        self.tx_results.insert(hash, TxResult::Pending);
+        self.mempool.push_back(Tx { hash, body });
+
+        hash
+    }
+
+    fn produce_block(&mut self, counterparties: &impl Counterparties) -> BlockHeader {
+        let parent = self.blocks.last().expect("genesis block exists; qed;");
+
+        let height = parent.header.height + 1;
+        let block_time =
+            u64::try_from(self.config.block_time.as_nanos()).expect("block time is too large");
+        let timestamp = Timestamp::from_nanos(
+            self.config.genesis_timestamp.as_nanos() + (block_time * (height - 1)) + self.reorgs,
+        );
+
+        let ctx = ExecutionContext {
+            timestamp,
+            counterparties,
+        };
+
+        let mut state = parent.state.clone();
+        let mut events = vec![];
+        let mut txs = vec![];
+
+        for tx in std::mem::take(&mut self.mempool) {
+            let mut tx_state = state.clone();
+
+            let res = match tx.body.clone() {
+                TxBody::Datagrams(datagrams) => datagrams
+                    .into_iter()
+                    .map(|datagram| tx_state.execute(&ctx, datagram))
+                    .collect::<Result<Vec<_>, HostError>>()
+                    .map(|events| events.into_iter().flatten().collect::<Vec<_>>()),
+                TxBody::SendPacket {
+                    channel_id,
+                    data,
+                    timeout_timestamp,
+                } => tx_state.send_packet(channel_id, data, timeout_timestamp),
+            };
+
+            let result = match res {
+                Ok(tx_events) => {
+                    state = tx_state;
+
+                    events.extend(tx_events.iter().map(|(client_id, event)| MockEvent {
+                        tx_hash: tx.hash,
+                        client_id: *client_id,
+                        event: event.clone(),
+                    }));
+
+                    TxResult::Success {
+                        height,
+                        events: tx_events.into_iter().map(|(_, event)| event).collect(),
+                    }
+                }
+                Err(error) => {
+                    warn!(
+                        chain_id = %self.config.chain_id,
+                        tx_hash = %tx.hash,
+                        %error,
+                        "transaction failed"
+                    );
+
+                    TxResult::Failure {
+                        height,
+                        error: error.to_string(),
+                    }
+                }
+            };
+
+            self.tx_results.insert(tx.hash, result);
+            txs.push(tx);
+        }
+
+        let state_root = state.state_root();
+
+        let header = BlockHeader {
+            height,
+            hash: block_hash(parent.header.hash, height, timestamp, state_root),
+            parent_hash: parent.header.hash,
+            timestamp,
+            state_root,
+        };
+
+        debug!(chain_id = %self.config.chain_id, %height, hash = %header.hash, "produced block");
+
+        self.blocks.push(Block {
+            header: header.clone(),
+            state,
+            events,
+            txs,
+        });
+
+        header
+    }
+
+    fn reorg(&mut self, depth: u64) -> Result<(), Error> {
+        let max = self.latest_height() - self.finalized_height();
+
+        if depth > max {
+            return Err(Error::ReorgTooDeep { depth, max });
+        }
+
+        let new_len = self.blocks.len() - usize::try_from(depth).expect("depth <= max; qed;");
+
+        let reverted = self.blocks.split_off(new_len);
+
+        info!(chain_id = %self.config.chain_id, %depth, "reorg");
+
+        // put the reverted transactions back in the mempool in their original order, ahead of any
+        // transactions that have been submitted since
+        for tx in reverted.into_iter().flat_map(|block| block.txs).rev() {
+            self.tx_results.insert(tx.hash, TxResult::Pending);
+            self.mempool.push_front(tx);
+        }
+
+        self.reorgs += 1;
+
+        Ok(())
+    }
+}
+
+fn block_hash(parent_hash: H256, height: u64, timestamp: Timestamp, state_root: H256) -> H256 {
+    keccak256(
+        parent_hash
+            .get()
+            .iter()
+            .chain(&height.to_be_bytes())
+            .chain(&timestamp.as_nanos().to_be_bytes())
+            .chain(state_root.get())
+            .copied()
+            .collect::<Vec<_>>(),
+    )
+}
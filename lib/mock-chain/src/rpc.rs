@@ -0,0 +1,173 @@
+//! The JSON-RPC interface of a [`MockChain`], used by the voyager modules and plugins for mock
+//! chains.
+
+use std::net::SocketAddr;
+
+use ibc_union_spec::{datagram::Datagram, path::StorePath, ChannelId, ClientId, Packet, Status};
+use jsonrpsee::{
+    core::{async_trait, RpcResult},
+    proc_macros::rpc,
+    server::ServerHandle,
+    types::ErrorObject,
+};
+use serde_json::Value;
+use unionlabs::primitives::H256;
+use voyager_primitives::{ChainId, ClientType};
+
+use crate::{
+    types::{BlockHeader, ClientState, ConsensusState, MockEvent, StateProof, TxResult},
+    Error, MockChain,
+};
+
+#[rpc(client, server, namespace = "mock")]
+pub trait MockChainRpc {
+    #[method(name = "chainId")]
+    async fn chain_id(&self) -> RpcResult<ChainId>;
+
+    #[method(name = "latestHeight")]
+    async fn latest_height(&self, finalized: bool) -> RpcResult<u64>;
+
+    #[method(name = "block")]
+    async fn block(&self, height: u64) -> RpcResult<Option<BlockHeader>>;
+
+    /// Query the value stored at `path` at `height`. See [`IbcState::query`] for the format of
+    /// the returned value.
+    ///
+    /// [`IbcState::query`]: crate::host::IbcState::query
+    #[method(name = "ibcState")]
+    async fn ibc_state(&self, height: u64, path: StorePath) -> RpcResult<Value>;
+
+    #[method(name = "ibcProof")]
+    async fn ibc_proof(&self, height: u64, path: StorePath) -> RpcResult<StateProof>;
+
+    #[method(name = "clientType")]
+    async fn client_type(&self, client_id: ClientId) -> RpcResult<Option<ClientType>>;
+
+    #[method(name = "clientStatus")]
+    async fn client_status(&self, height: u64, client_id: ClientId) -> RpcResult<Option<Status>>;
+
+    #[method(name = "events")]
+    async fn events(&self, height: u64) -> RpcResult<Vec<MockEvent>>;
+
+    #[method(name = "packetByHash")]
+    async fn packet_by_hash(
+        &self,
+        channel_id: ChannelId,
+        packet_hash: H256,
+    ) -> RpcResult<Option<Packet>>;
+
+    #[method(name = "selfClientState")]
+    async fn self_client_state(&self, height: u64) -> RpcResult<ClientState>;
+
+    #[method(name = "selfConsensusState")]
+    async fn self_consensus_state(&self, height: u64) -> RpcResult<ConsensusState>;
+
+    /// Submit a transaction, returning the transaction hash.
+    #[method(name = "submit")]
+    async fn submit(&self, datagrams: Vec<Datagram>) -> RpcResult<H256>;
+
+    #[method(name = "txResult")]
+    async fn tx_result(&self, tx_hash: H256) -> RpcResult<Option<TxResult>>;
+}
+
+/// Serve the JSON-RPC interface of `chain` on `laddr`, returning the address the server is
+/// listening on.
+pub async fn serve(
+    chain: MockChain,
+    laddr: SocketAddr,
+) -> std::io::Result<(SocketAddr, ServerHandle)> {
+    let server = jsonrpsee::server::Server::builder().build(laddr).await?;
+
+    let addr = server.local_addr()?;
+
+    Ok((addr, server.start(chain.into_rpc())))
+}
+
+fn rpc_error(e: Error) -> ErrorObject<'static> {
+    ErrorObject::owned(-1, e.to_string(), None::<()>)
+}
+
+#[async_trait]
+impl MockChainRpcServer for MockChain {
+    async fn chain_id(&self) -> RpcResult<ChainId> {
+        self.check_rpc_failure().map_err(rpc_error)?;
+
+        Ok(MockChain::chain_id(self).clone())
+    }
+
+    async fn latest_height(&self, finalized: bool) -> RpcResult<u64> {
+        self.check_rpc_failure().map_err(rpc_error)?;
+
+        Ok(MockChain::latest_height(self, finalized))
+    }
+
+    async fn block(&self, height: u64) -> RpcResult<Option<BlockHeader>> {
+        self.check_rpc_failure().map_err(rpc_error)?;
+
+        Ok(MockChain::block(self, height))
+    }
+
+    async fn ibc_state(&self, height: u64, path: StorePath) -> RpcResult<Value> {
+        self.check_rpc_failure().map_err(rpc_error)?;
+
+        self.query_ibc_state(height, &path).map_err(rpc_error)
+    }
+
+    async fn ibc_proof(&self, height: u64, path: StorePath) -> RpcResult<StateProof> {
+        self.check_rpc_failure().map_err(rpc_error)?;
+
+        self.query_ibc_proof(height, &path).map_err(rpc_error)
+    }
+
+    async fn client_type(&self, client_id: ClientId) -> RpcResult<Option<ClientType>> {
+        self.check_rpc_failure().map_err(rpc_error)?;
+
+        Ok(MockChain::client_type(self, client_id))
+    }
+
+    async fn client_status(&self, height: u64, client_id: ClientId) -> RpcResult<Option<Status>> {
+        self.check_rpc_failure().map_err(rpc_error)?;
+
+        MockChain::client_status(self, height, client_id).map_err(rpc_error)
+    }
+
+    async fn events(&self, height: u64) -> RpcResult<Vec<MockEvent>> {
+        self.check_rpc_failure().map_err(rpc_error)?;
+
+        MockChain::events(self, height).map_err(rpc_error)
+    }
+
+    async fn packet_by_hash(
+        &self,
+        channel_id: ChannelId,
+        packet_hash: H256,
+    ) -> RpcResult<Option<Packet>> {
+        self.check_rpc_failure().map_err(rpc_error)?;
+
+        Ok(MockChain::packet_by_hash(self, channel_id, packet_hash))
+    }
+
+    async fn self_client_state(&self, height: u64) -> RpcResult<ClientState> {
+        self.check_rpc_failure().map_err(rpc_error)?;
+
+        MockChain::self_client_state(self, height).map_err(rpc_error)
+    }
+
+    async fn self_consensus_state(&self, height: u64) -> RpcResult<ConsensusState> {
+        self.check_rpc_failure().map_err(rpc_error)?;
+
+        MockChain::self_consensus_state(self, height).map_err(rpc_error)
+    }
+
+    async fn submit(&self, datagrams: Vec<Datagram>) -> RpcResult<H256> {
+        self.check_rpc_failure().map_err(rpc_error)?;
+
+        MockChain::submit(self, datagrams).map_err(rpc_error)
+    }
+
+    async fn tx_result(&self, tx_hash: H256) -> RpcResult<Option<TxResult>> {
+        self.check_rpc_failure().map_err(rpc_error)?;
+
+        Ok(MockChain::tx_result(self, tx_hash))
+    }
+}
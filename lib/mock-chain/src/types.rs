@@ -0,0 +1,206 @@
+use ibc_union_spec::{event::FullEvent, ClientId, Timestamp};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use unionlabs::{
+    ethereum::keccak256,
+    primitives::{Bytes, H256},
+};
+use voyager_primitives::ChainId;
+
+/// The client state of a [`ClientType::MOCK`] client.
+///
+/// [`ClientType::MOCK`]: voyager_primitives::ClientType::MOCK
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ClientState {
+    /// The mock chain tracked by this client.
+    pub chain_id: ChainId,
+    pub latest_height: u64,
+    pub frozen: bool,
+}
+
+/// The consensus state of a [`ClientType::MOCK`] client.
+///
+/// [`ClientType::MOCK`]: voyager_primitives::ClientType::MOCK
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ConsensusState {
+    pub timestamp: Timestamp,
+    /// The [`StateProof::root`] of the IBC commitments at this height.
+    pub state_root: H256,
+}
+
+/// The header used to update a [`ClientType::MOCK`] client.
+///
+/// Headers carry no signatures; instead, the host checks them directly against the canonical
+/// chain of the counterparty in the same [`MockNetwork`](crate::MockNetwork).
+///
+/// [`ClientType::MOCK`]: voyager_primitives::ClientType::MOCK
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Header {
+    pub height: u64,
+    pub timestamp: Timestamp,
+    pub state_root: H256,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BlockHeader {
+    pub height: u64,
+    pub hash: H256,
+    pub parent_hash: H256,
+    pub timestamp: Timestamp,
+    pub state_root: H256,
+}
+
+impl BlockHeader {
+    #[must_use]
+    pub fn to_header(&self) -> Header {
+        Header {
+            height: self.height,
+            timestamp: self.timestamp,
+            state_root: self.state_root,
+        }
+    }
+
+    #[must_use]
+    pub fn to_consensus_state(&self) -> ConsensusState {
+        ConsensusState {
+            timestamp: self.timestamp,
+            state_root: self.state_root,
+        }
+    }
+}
+
+/// A proof of the IBC commitments of a mock chain at a specific height.
+///
+/// This is simply the full (sorted) set of commitments, which allows for both membership and
+/// non-membership proofs to be verified against the same root.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct StateProof {
+    pub commitments: Vec<(H256, H256)>,
+}
+
+impl StateProof {
+    #[must_use]
+    pub fn root(&self) -> H256 {
+        keccak256(
+            self.commitments
+                .iter()
+                .flat_map(|(key, value)| key.get().iter().chain(value.get()))
+                .copied()
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    #[must_use]
+    pub fn get(&self, key: &H256) -> Option<&H256> {
+        self.commitments
+            .binary_search_by(|(k, _)| k.cmp(key))
+            .ok()
+            .map(|idx| &self.commitments[idx].1)
+    }
+
+    pub fn verify_membership(
+        &self,
+        root: &H256,
+        key: &H256,
+        value: &H256,
+    ) -> Result<(), ProofError> {
+        self.verify_root(root)?;
+
+        match self.get(key) {
+            Some(found) if found == value => Ok(()),
+            Some(found) => Err(ProofError::ValueMismatch {
+                key: *key,
+                expected: *value,
+                found: *found,
+            }),
+            None => Err(ProofError::KeyNotFound { key: *key }),
+        }
+    }
+
+    pub fn verify_non_membership(&self, root: &H256, key: &H256) -> Result<(), ProofError> {
+        self.verify_root(root)?;
+
+        match self.get(key) {
+            Some(_) => Err(ProofError::KeyExists { key: *key }),
+            None => Ok(()),
+        }
+    }
+
+    fn verify_root(&self, root: &H256) -> Result<(), ProofError> {
+        if !self.commitments.is_sorted_by(|(a, _), (b, _)| a < b) {
+            return Err(ProofError::Unsorted);
+        }
+
+        let found = self.root();
+
+        if &found == root {
+            Ok(())
+        } else {
+            Err(ProofError::RootMismatch {
+                expected: *root,
+                found,
+            })
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ProofError {
+    #[error("proof commitments are not sorted")]
+    Unsorted,
+    #[error("invalid proof root: expected {expected}, found {found}")]
+    RootMismatch { expected: H256, found: H256 },
+    #[error("key {key} not found")]
+    KeyNotFound { key: H256 },
+    #[error("key {key} exists")]
+    KeyExists { key: H256 },
+    #[error("invalid value for key {key}: expected {expected}, found {found}")]
+    ValueMismatch {
+        key: H256,
+        expected: H256,
+        found: H256,
+    },
+}
+
+/// An IBC event emitted by a mock chain, along with the transaction it was emitted in and the
+/// client on the emitting chain that it is related to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MockEvent {
+    pub tx_hash: H256,
+    pub client_id: ClientId,
+    pub event: FullEvent,
+}
+
+/// The result of a transaction submitted to a mock chain.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case", deny_unknown_fields)]
+pub enum TxResult {
+    /// The transaction is in the mempool, either because it has not been included in a block yet
+    /// or because the block it was included in was reorged out.
+    Pending,
+    Success {
+        height: u64,
+        events: Vec<FullEvent>,
+    },
+    Failure {
+        height: u64,
+        error: String,
+    },
+}
+
+/// All of the state types are encoded as JSON.
+#[must_use]
+pub fn encode<T: Serialize>(t: &T) -> Bytes {
+    serde_json::to_vec(t)
+        .expect("serialization is infallible; qed;")
+        .into()
+}
+
+pub fn decode<T: DeserializeOwned>(bz: &[u8]) -> Result<T, serde_json::Error> {
+    serde_json::from_slice(bz)
+}
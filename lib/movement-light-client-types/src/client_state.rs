@@ -1,5 +1,8 @@
+use ibc_union_spec::ClientId;
 use unionlabs::{
-    aptos::account::AccountAddress, ibc::core::client::height::Height, primitives::H160,
+    aptos::account::AccountAddress,
+    ibc::core::client::height::Height,
+    primitives::{H160, U256},
 };
 
 #[derive(Debug, Clone, PartialEq)]
@@ -7,8 +10,11 @@ use unionlabs::{
 #[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
 pub struct ClientState {
     pub chain_id: String,
-    pub l1_client_id: u32,
+    pub l1_client_id: ClientId,
     pub l1_contract_address: H160,
+    /// Slot of the mapping of block heights to the accepted block commitments within the
+    /// settlement contract on the L1.
+    pub l1_block_commitments_slot: U256,
     pub l2_contract_address: AccountAddress,
     pub table_handle: AccountAddress,
     pub frozen_height: Height,
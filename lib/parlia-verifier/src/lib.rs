@@ -183,8 +183,7 @@ pub fn verify_header<C: VerificationContext>(
             header_timestamp_plus_unbonding_period < ctx.current_timestamp()
         })
     {
-        // TODO: Re-enable this for mainnet
-        // return Err(Error::HeaderExpired);
+        return Err(Error::HeaderExpired);
     }
 
     // 2.
@@ -347,8 +346,9 @@ mod tests {
         serde_json::from_str(json).unwrap()
     }
 
-    #[test]
-    fn verify_header_works_testnet() {
+    /// Source, target and attestation headers for testnet block 55475596, along with the valset that signed
+    /// the attestation (the valset of epoch 55475000).
+    fn testnet_55475596() -> (ParliaHeader, ParliaHeader, ParliaHeader, Valset) {
         // 55475596
         let source = deser_header(
             r#"{"hash":"0x053e82c0a815b741826b338cb3fd1346a098ea0d69dcca5f56673731279be1db","parentHash":"0xdd0eaf7f4e73b61b6b232358e235ee879b8e321d7c8f976c4242db55275c80a2","sha3Uncles":"0x1dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347","miner":"0x90409f56966b5da954166eb005cb1a8790430ba1","stateRoot":"0x8cd0286815e0e5afc095bf6a1aed01e82e906f23b8f8536149a31e0d42c0b01c","transactionsRoot":"0x406e687cb846a88d971394b5f1927ef01287cd8c0a2cfe20817d345517f78d38","receiptsRoot":"0x90bb10b203eb8e2462bff1cc0a0055b20d74f7d31b354243dd1950b06cf30c80","logsBloom":"0x000008004000000000010040000020000020400000000000000002000000000000001080000000000000000000000000000800000000000000000000002000000040000400800000000000081000000022100002000000000000000000100002000810200002000000000000010000000a0000000000000000000010000000000000002000000000000000000002000001000400004000008400000000000020020000000000002008000000028000000000000000000000000000000000000000000002000000000000140000000000000000000000001000104002000000000010800000000000010000040000010000008000000000004100000000001000","difficulty":"0x2","number":"0x34e7d8c","gasLimit":"0x5e29eed","gasUsed":"0x83351","timestamp":"0x6855884c","extraData":"0xd88301050d846765746888676f312e32332e39856c696e757800000038469028f8b27fb860a721d42755113b5cbdac611e235d97b9119eb22a44b9e14c0be9db09726ca0e4daad7b0897608eba03e70d8bb516ed740257857fbaa2f7d2213eeba4f4481b62b8fe89de0ef1a562abc3ff84810b38647420407ebf0847d048148afbf523c051f84c84034e7d8aa06e1316485b6da89f3d4c0e434d9206488843a4a9e54462ab8746976e57822dfd84034e7d8ba0dd0eaf7f4e73b61b6b232358e235ee879b8e321d7c8f976c4242db55275c80a280f33ec24f7c83f578fcd505ae3a1b83c424f04098df9382b97cc81d5f28221d6b67c789880790fc9427d2e156bb9ad51eeb4efa479fa429cf4f1f11263a3cbdd700","mixHash":"0x00000000000000000000000000000000000000000000000000000000000000fa","nonce":"0x0000000000000000","baseFeePerGas":"0x0","withdrawalsRoot":"0x56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421","blobGasUsed":"0x0","excessBlobGas":"0x0","parentBeaconBlockRoot":"0x0000000000000000000000000000000000000000000000000000000000000000","requestsHash":"0xe3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855","totalDifficulty":"0x6993713","size":"0x681","uncles":[],"transactions":[{"type":"0x0","chainId":"0x61","nonce":"0xd1","gasPrice":"0x66758af","gas":"0x6b155","to":"0x58cfe0e9e376981c1b7ba7cd2673a08f60690625","value":"0x0","input":"0x599682880000000000000000000000000000000000000000000000000e8b3e728c9c000000000000000000000000000000000000000000000000000014d1120d7b16000000000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000005d868f081b9da000000000","r":"0x5e6b0334111df188c74ef165b6f44a640e250f6ff1a14783c02f3ca9d98bf53d","s":"0x38f233b657879682c0a782e1c50c36b0f282e4475e31fc655218553bdbda70bf","v":"0xe6","hash":"0xca6df32828acd652070f3751a83af313c57bc8095115d6631f93948d200371a8","blockHash":"0x053e82c0a815b741826b338cb3fd1346a098ea0d69dcca5f56673731279be1db","blockNumber":"0x34e7d8c","transactionIndex":"0x0","from":"0x2dfd57da36f1ff5c11fa5047c7843ad5ddc28e35"},{"type":"0x0","chainId":"0x61","nonce":"0x35c","gasPrice":"0x64babf8","gas":"0x73c04","to":"0x64e6416d5ef820f23e09c53adf6ac4ab061a305d","value":"0x0","input":"0x46322c3700000000000000000000000000000000000000000000000000000000000006ef0000000000000000000000000000000000000000000000000de0b6b3a76400000000000000000000000000000000000000000000000000000000000000000000","r":"0x996409d184b4c8126f89203f52d660c629ad201edfee40be1257bbb60d5448a1","s":"0x11a3f508ccf29805e30a9257d2c6abd8123cc2cea720023a2276728a60017d34","v":"0xe6","hash":"0x0432ec67be63215640d00b338ddd5b58a78b5c65bd069d2434737f39030ed882","blockHash":"0x053e82c0a815b741826b338cb3fd1346a098ea0d69dcca5f56673731279be1db","blockNumber":"0x34e7d8c","transactionIndex":"0x1","from":"0xdfd0b7f8ad5ceb600acac6524395c2886f4a11a8"},{"type":"0x0","chainId":"0x61","nonce":"0x950","gasPrice":"0x5f5e100","gas":"0x67ae","to":"0x14fb578f334bd6cfa80dedcabcc173620d56801c","value":"0x0","input":"0x095ea7b3000000000000000000000000d99d1c33f9fc3444f8101754abc46c52416550d1000000000000000000000000000000000000000000084595161401484a000000","r":"0x903d63ec55f53957346833f32b63ee4015021e442b0df9a3295f74883cd27e0a","s":"0x15ba87bb025c4db7aba180aad2bfd55652c3dfbc152f22020896c3b60d7d9e64","v":"0xe5","hash":"0x8cba86a8ace29d59fbe0058a2b6993f09408d8ebfc3e4931ea7f28ab2f5f21d6","blockHash":"0x053e82c0a815b741826b338cb3fd1346a098ea0d69dcca5f56673731279be1db","blockNumber":"0x34e7d8c","transactionIndex":"0x2","from":"0x2256be9c1832750fce12f309638e55cb26714607"},{"type":"0x0","chainId":"0x61","nonce":"0x5851f","gasPrice":"0x0","gas":"0x7fffffffffffffff","to":"0x0000000000000000000000000000000000001000","value":"0x2e9cc0fb2d84","input":"0xf340fa0100000000000000000000000090409f56966b5da954166eb005cb1a8790430ba1","r":"0x360f39b1f54a9187df0212129971ee10dfd9be23c639882dba33c8df4c87273d","s":"0x50d56bd784de7546e3808f9c0bcb424de42986dde80aa1f1d746940c62349395","v":"0xe6","hash":"0x5a5581d8d9dbd5ad3ac6f8ad1674140ef9bb77e47d77690d974049ac9dc74b92","blockHash":"0x053e82c0a815b741826b338cb3fd1346a098ea0d69dcca5f56673731279be1db","blockNumber":"0x34e7d8c","transactionIndex":"0x3","from":"0x90409f56966b5da954166eb005cb1a8790430ba1"}],"withdrawals":[],"milliTimestamp":"0x1978e1c69da"}"#,
@@ -362,6 +362,13 @@ mod tests {
 
         let (_, valset) = parse_epoch_rotation_header_extra_data(&hex!("d883010510846765746888676f312e32332e39856c696e7578000000384690280708265da01e1a65d62b903c7b34c08cb389bf3d9996f763f030b1adcfb369c5a5df4a18e1529baffe7feaec66db3dbd1bc06810f7f6f88b7be6645418a7e2a2a3f40514c21a3d9d7a717d64e6088ac937d5aacdd3e20ca963979974cd8ff90cbf097023dc8c448245ceff671e965d57d82eaf9be91478cfa0f24d2993e0c5f43a6c5a4cd99850023053387f3321fd69d1e030bb921230dfb188826affaa39ebf1c38b190851e4db0588a3e90142c5299041fb8a0db3bb9a1fa4bdf0dae84ca37ee12a6b8c26caab775f0e007b76d76ee8823de52a1a431884c2ca930c5e72bff3803af79641cf964cc001671017f0b680f93b7dde085b24bbc67b2a562a216f903ac878c5477641328172a353f1e493cf7f5f2cf1aec83bf0c74df566a41aa7ed65ea84ea99e3849ef31887c0f880a0feb92f356f58fbd023a82f5311fc87a5883a662e9ebbbefc90bf13aa533c2438a4113804bf90409f56966b5da954166eb005cb1a8790430ba1962a2342bac4831c6de73fcb77ad08669aaaa0a2ba6c6973a02b8928dbe573d17864e48c3521f238ace1c16e160bb7f5d447b49cd040d20bc21e49ffea6487f5638e4346ad9fc6d1ec30e28016d3892b51a7898bd354cfe78643453fd3868410da412de7f2883180d0a2840111ad2e043fa403eb10f8b27fb860a18a293898150b2c5c7112c7cd482121e3a50f30b03a4fa9bb39cac2397b2058089eb019af04dc6c43a59189f6a438400fc612012b27a9df59a91531b3cc36b75dbdd51008f61a7bf4a0c7da5fe2b7df9eefa6e9c42b9dedca7f32bcb6edc907f84c84034e7b36a0d40e88dd0303dd4ca0772ba4e9c5d36b82f4e526bd294f2cd98b1b45886a60ea84034e7b37a0def92aee98a20bf20f2c7a6ddc5807700a95353c75c2f7b9329c9be2d1e6a6c480c692716571c53a20a0de653cefffd0da1d1c092d01e47a96daa1195431420b453cb0e01350b790d8523a7c3ef4edffeb776187c57e1c96a06ebc582baabc889301")).unwrap();
 
+        (source, target, attestation, valset)
+    }
+
+    #[test]
+    fn verify_header_works_testnet() {
+        let (source, target, attestation, valset) = testnet_55475596();
+
         let res = verify_header(
             &source,
             &target,
@@ -378,6 +385,87 @@ mod tests {
         assert_eq!(res, None);
     }
 
+    #[test]
+    fn verify_header_works_at_end_of_unbond_period() {
+        let (source, target, attestation, valset) = testnet_55475596();
+
+        let unbond_period = Duration::from_secs(604800);
+
+        let res = verify_header(
+            &source,
+            &target,
+            &attestation,
+            unbond_period,
+            55475000,
+            BlstContext {
+                current_timestamp: attestation
+                    .full_timestamp()
+                    .plus_duration(unbond_period)
+                    .unwrap(),
+                epoch_valsets: [(55475000_u64, valset)].into_iter().collect(),
+            },
+        )
+        .unwrap();
+
+        assert_eq!(res, None);
+    }
+
+    #[test]
+    fn verify_header_fails_after_unbond_period() {
+        let (source, target, attestation, valset) = testnet_55475596();
+
+        let unbond_period = Duration::from_secs(604800);
+
+        let err = verify_header(
+            &source,
+            &target,
+            &attestation,
+            unbond_period,
+            55475000,
+            BlstContext {
+                current_timestamp: attestation
+                    .full_timestamp()
+                    .plus_duration(unbond_period)
+                    .unwrap()
+                    .plus_duration(Duration::from_nanos(1))
+                    .unwrap(),
+                epoch_valsets: [(55475000_u64, valset)].into_iter().collect(),
+            },
+        )
+        .unwrap_err();
+
+        assert!(matches!(err, Error::HeaderExpired), "{err:?}");
+    }
+
+    #[test]
+    fn verify_header_fails_with_wrong_trusted_valset_epoch() {
+        let (source, target, attestation, valset) = testnet_55475596();
+
+        let err = verify_header(
+            &source,
+            &target,
+            &attestation,
+            Duration::from_secs(604800),
+            55474000,
+            BlstContext {
+                current_timestamp: Timestamp::from_nanos(1750435993000000000),
+                epoch_valsets: [(55474000_u64, valset)].into_iter().collect(),
+            },
+        )
+        .unwrap_err();
+
+        assert!(
+            matches!(
+                err,
+                Error::InvalidTrustedValsetEpochBlockNumber {
+                    expected: 55474000,
+                    found: 55475000
+                }
+            ),
+            "{err:?}"
+        );
+    }
+
     #[test]
     fn verify_header_works_valset_rotation_testnet() {
         // 55475000
@@ -490,6 +578,50 @@ mod tests {
         }
     }
 
+    #[test]
+    fn calculate_signing_valset_epoch_block_number_epoch_boundary() {
+        // the new valset only starts signing once every validator in the previous valset has had a
+        // turn after the epoch block, i.e. after TURN_LENGTH * ceil(valset_size / 2) blocks
+        let cases: &[(u64, u64, u64)] = &[
+            // 7 validators (testnet), delay of 64 blocks
+            (55475000, 7, 55474000),
+            (55475064, 7, 55474000),
+            (55475065, 7, 55475000),
+            (55475999, 7, 55475000),
+            (55476000, 7, 55475000),
+            // 21 validators (mainnet), delay of 176 blocks
+            (55475000, 21, 55474000),
+            (55475176, 21, 55474000),
+            (55475177, 21, 55475000),
+            (55476176, 21, 55475000),
+            (55476177, 21, 55476000),
+        ];
+
+        for &(h, valset_size, expected) in cases {
+            assert_eq!(
+                calculate_signing_valset_epoch_block_number(h, valset_size),
+                expected,
+                "h = {h}, valset_size = {valset_size}"
+            );
+        }
+    }
+
+    #[test]
+    fn is_epoch_rotation_header_works() {
+        let (mut header, _, _, _) = testnet_55475596();
+
+        assert!(!is_epoch_rotation_header(&header));
+
+        header.number = U256::from(55475000_u64);
+        assert!(is_epoch_rotation_header(&header));
+
+        header.number = U256::from(55475001_u64);
+        assert!(!is_epoch_rotation_header(&header));
+
+        header.number = U256::from(55474999_u64);
+        assert!(!is_epoch_rotation_header(&header));
+    }
+
     #[test]
     fn header_hash() {
         let header = deser_header(
@@ -518,4 +650,75 @@ mod tests {
 
         dbg!(res);
     }
+
+    fn epoch_rotation_extra_data(vals: &[(H160, H384)], num: u8, turn_length: u8) -> Vec<u8> {
+        [0; EXTRA_VANITY_LEN]
+            .into_iter()
+            .chain([num])
+            .chain(
+                vals.iter()
+                    .flat_map(|(address, pubkey)| address.get().iter().chain(pubkey.get()))
+                    .copied(),
+            )
+            .chain([turn_length])
+            .chain([0; EXTRA_SEAL_LEN])
+            .collect()
+    }
+
+    fn vals() -> Vec<(H160, H384)> {
+        vec![
+            (H160::new([1; 20]), H384::new([2; 48])),
+            (H160::new([3; 20]), H384::new([4; 48])),
+        ]
+    }
+
+    #[test]
+    fn parse_epoch_rotation_header_extra_data_turn_length() {
+        let (va, valset) = parse_epoch_rotation_header_extra_data(&epoch_rotation_extra_data(
+            &vals(),
+            2,
+            TURN_LENGTH as u8,
+        ))
+        .unwrap();
+
+        assert!(va.is_none());
+        assert_eq!(valset, Valset::new(vals()));
+    }
+
+    #[test]
+    fn parse_epoch_rotation_header_extra_data_pre_maxwell_turn_length() {
+        // turn length was 8 before the maxwell hardfork
+        let err = parse_epoch_rotation_header_extra_data(&epoch_rotation_extra_data(&vals(), 2, 8))
+            .unwrap_err();
+
+        assert!(
+            matches!(err, ExtraDataDecodeError::InvalidTurnLength(8)),
+            "{err:?}"
+        );
+    }
+
+    #[test]
+    fn parse_epoch_rotation_header_extra_data_not_enough_vals() {
+        let err = parse_epoch_rotation_header_extra_data(&epoch_rotation_extra_data(
+            &vals(),
+            3,
+            TURN_LENGTH as u8,
+        ))
+        .unwrap_err();
+
+        assert!(
+            matches!(err, ExtraDataDecodeError::NotEnoughVals),
+            "{err:?}"
+        );
+    }
+
+    #[test]
+    fn parse_epoch_rotation_header_extra_data_too_short() {
+        let err = parse_epoch_rotation_header_extra_data(&[0; EXTRA_DATA_MIN_LEN]).unwrap_err();
+
+        assert!(
+            matches!(err, ExtraDataDecodeError::InvalidExtraDataLen),
+            "{err:?}"
+        );
+    }
 }
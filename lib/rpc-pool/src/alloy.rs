@@ -0,0 +1,217 @@
+//! A pooled alloy transport.
+//!
+//! ```ignore
+//! let provider = ProviderBuilder::new()
+//!     .network::<AnyNetwork>()
+//!     .connect_client(rpc_pool::alloy::client(config.rpc_url).await?);
+//! ```
+
+use std::task::{Context, Poll};
+
+use alloy::{
+    primitives::U64,
+    rpc::{
+        client::{BuiltInConnectionString, RpcClient},
+        json_rpc::{Id, Request, RequestPacket, ResponsePacket, ResponsePayload},
+    },
+    transports::{BoxTransport, TransportError, TransportErrorKind, TransportFut},
+};
+use serde_json::Value;
+use tower::Service;
+
+use crate::{PoolConfig, PoolError, RpcPool};
+
+/// Methods that are sent to multiple endpoints if the pool requires a quorum, along with the
+/// position of the block parameter and a JSON pointer to the part of the result that must match
+/// (the empty pointer being the entire result).
+///
+/// Only reads of finalized or explicitly numbered blocks are checked, as the latest block is
+/// expected to differ between endpoints.
+const QUORUM_METHODS: &[(&str, usize, &str)] = &[
+    // block hash
+    ("eth_getBlockByNumber", 0, "/hash"),
+    // the entire proof, as the account and storage proofs are used as is
+    ("eth_getProof", 2, ""),
+];
+
+/// A [`Service`] implementing an alloy transport on top of a pool of endpoints.
+#[derive(Debug, Clone)]
+pub struct PoolTransport {
+    pool: RpcPool<BoxTransport>,
+}
+
+impl PoolTransport {
+    /// Connect to all endpoints in `config`, and start checking their health in the background.
+    pub async fn connect(config: PoolConfig) -> Result<Self, PoolError<TransportError>> {
+        let pool = RpcPool::new(config, |url| async move {
+            url.parse::<BuiltInConnectionString>()?
+                .connect_boxed()
+                .await
+        })
+        .await?;
+
+        pool.spawn_health_check(latest_height);
+
+        Ok(Self { pool })
+    }
+
+    #[must_use]
+    pub fn pool(&self) -> &RpcPool<BoxTransport> {
+        &self.pool
+    }
+}
+
+/// Connect to all endpoints in `config`, returning an [`RpcClient`] that can be used to construct
+/// a provider.
+pub async fn client(config: PoolConfig) -> Result<RpcClient, PoolError<TransportError>> {
+    let is_local = config
+        .endpoints
+        .iter()
+        .all(|endpoint| is_local_url(&endpoint.url));
+
+    Ok(RpcClient::new(
+        PoolTransport::connect(config).await?,
+        is_local,
+    ))
+}
+
+fn is_local_url(url: &str) -> bool {
+    url.split_once("://")
+        .map_or(url, |(_, rest)| rest)
+        .split(['/', ':'])
+        .next()
+        .is_some_and(|host| matches!(host, "localhost" | "127.0.0.1"))
+}
+
+async fn latest_height(mut transport: BoxTransport) -> Result<u64, TransportError> {
+    let request = Request::new("eth_blockNumber", Id::Number(0), ())
+        .serialize()
+        .map_err(TransportError::ser_err)?;
+
+    match transport.call(RequestPacket::Single(request)).await? {
+        ResponsePacket::Single(response) => match response.payload {
+            ResponsePayload::Success(result) => serde_json::from_str::<U64>(result.get())
+                .map(|height| height.to::<u64>())
+                .map_err(|err| TransportError::deser_err(err, result.get())),
+            ResponsePayload::Failure(error) => Err(TransportError::ErrorResp(error)),
+        },
+        ResponsePacket::Batch(_) => Err(TransportErrorKind::custom_str(
+            "unexpected batch response to eth_blockNumber",
+        )),
+    }
+}
+
+/// The JSON pointer to compare the results of `request` with, if `request` requires a quorum.
+fn quorum_pointer(request: &RequestPacket) -> Option<&'static str> {
+    let RequestPacket::Single(request) = request else {
+        return None;
+    };
+
+    let (_, block_param_idx, pointer) = QUORUM_METHODS
+        .iter()
+        .find(|(method, _, _)| *method == request.method())?;
+
+    let params = serde_json::from_str::<Vec<Value>>(request.params()?.get()).ok()?;
+
+    match params.get(*block_param_idx)?.as_str()? {
+        "finalized" => Some(*pointer),
+        block if block.starts_with("0x") => Some(*pointer),
+        // latest, pending, safe, earliest
+        _ => None,
+    }
+}
+
+/// The value that endpoints must agree on. Error responses are compared by their code, such that
+/// endpoints agreeing on an error (i.e. a block not existing) still reach a quorum.
+fn quorum_key(response: &ResponsePacket, pointer: &str) -> Option<Value> {
+    let ResponsePacket::Single(response) = response else {
+        return None;
+    };
+
+    match &response.payload {
+        ResponsePayload::Success(result) => serde_json::from_str::<Value>(result.get())
+            .ok()?
+            .pointer(pointer)
+            .cloned(),
+        ResponsePayload::Failure(error) => Some(Value::from(error.code)),
+    }
+}
+
+impl Service<RequestPacket> for PoolTransport {
+    type Response = ResponsePacket;
+    type Error = TransportError;
+    type Future = TransportFut<'static>;
+
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: RequestPacket) -> Self::Future {
+        let pool = self.pool.clone();
+
+        Box::pin(async move {
+            let send = |mut transport: BoxTransport| {
+                let request = request.clone();
+                async move { transport.call(request).await }
+            };
+
+            let res = match quorum_pointer(&request) {
+                Some(pointer) => {
+                    pool.quorum(send, |response: &ResponsePacket| {
+                        quorum_key(response, pointer)
+                    })
+                    .await
+                }
+                None => pool.call(send).await,
+            };
+
+            res.map_err(|err| match err.into_first_endpoint_error() {
+                Ok(err) => err,
+                Err(err) => TransportErrorKind::custom(err),
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::rpc::json_rpc::Response;
+    use serde_json::value::RawValue;
+
+    use super::*;
+
+    fn get_proof(block: &str) -> RequestPacket {
+        RequestPacket::Single(
+            Request::new(
+                "eth_getProof",
+                Id::Number(0),
+                ("0x0000000000000000000000000000000000000000", [""; 0], block),
+            )
+            .serialize()
+            .unwrap(),
+        )
+    }
+
+    fn response(result: &str) -> ResponsePacket {
+        ResponsePacket::Single(Response {
+            id: Id::Number(0),
+            payload: ResponsePayload::Success(RawValue::from_string(result.to_owned()).unwrap()),
+        })
+    }
+
+    #[test]
+    fn get_proof_quorum_compares_full_response() {
+        let pointer = quorum_pointer(&get_proof("0x1")).unwrap();
+
+        let a = response(r#"{ "storageHash": "0x01", "accountProof": ["0x02"] }"#);
+        let b = response(r#"{ "storageHash": "0x01", "accountProof": ["0x03"] }"#);
+
+        assert_eq!(quorum_key(&a, pointer), quorum_key(&a, pointer));
+        assert_ne!(quorum_key(&a, pointer), quorum_key(&b, pointer));
+    }
+
+    #[test]
+    fn get_proof_at_latest_is_not_quorum_checked() {
+        assert_eq!(quorum_pointer(&get_proof("latest")), None);
+    }
+}
@@ -0,0 +1,176 @@
+use std::{num::NonZeroUsize, time::Duration};
+
+use serde::{Deserialize, Serialize};
+
+/// Configuration for an [`RpcPool`](crate::RpcPool).
+///
+/// For backwards compatibility with the single `rpc_url` configs, this can be deserialized from
+/// either a single url, a list of endpoints, or the full config object:
+///
+/// ```json
+/// "https://rpc.example.com"
+/// ["https://rpc-1.example.com", { "url": "https://rpc-2.example.com", "weight": 2 }]
+/// { "endpoints": ["https://rpc-1.example.com", "https://rpc-2.example.com"], "quorum": 2 }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "PoolConfigRepr")]
+pub struct PoolConfig {
+    /// The endpoints in this pool.
+    pub endpoints: Vec<EndpointConfig>,
+
+    /// The amount of endpoints that have to agree on the result of a quorum read. Defaults to 1,
+    /// which disables quorum reads.
+    pub quorum: NonZeroUsize,
+
+    /// The maximum amount of blocks an endpoint may lag behind the most up to date endpoint in
+    /// the pool before it is considered unhealthy. If not set, lag is not taken into account.
+    pub max_lag: Option<u64>,
+
+    /// The amount of consecutive failed requests after which an endpoint is considered unhealthy.
+    pub max_failures: u32,
+
+    /// How long an unhealthy endpoint is skipped for, in seconds.
+    pub cooldown_secs: u64,
+
+    /// How often the latest height of every endpoint is checked, in seconds.
+    pub health_check_interval_secs: u64,
+}
+
+impl PoolConfig {
+    pub const DEFAULT_MAX_FAILURES: u32 = 3;
+    pub const DEFAULT_COOLDOWN_SECS: u64 = 30;
+    pub const DEFAULT_HEALTH_CHECK_INTERVAL_SECS: u64 = 10;
+
+    /// A pool consisting of a single endpoint with the default settings.
+    pub fn single(url: impl Into<String>) -> Self {
+        Self::from(PoolConfigRepr::Url(url.into()))
+    }
+
+    #[must_use]
+    pub fn cooldown(&self) -> Duration {
+        Duration::from_secs(self.cooldown_secs)
+    }
+
+    #[must_use]
+    pub fn health_check_interval(&self) -> Duration {
+        Duration::from_secs(self.health_check_interval_secs)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "EndpointConfigRepr")]
+pub struct EndpointConfig {
+    pub url: String,
+    /// The relative share of requests sent to this endpoint. An endpoint with a weight of 0 is
+    /// only used as a fallback if all other endpoints fail.
+    pub weight: u32,
+}
+
+impl EndpointConfig {
+    pub const DEFAULT_WEIGHT: u32 = 1;
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum PoolConfigRepr {
+    Url(String),
+    Endpoints(Vec<EndpointConfig>),
+    Full(FullPoolConfig),
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FullPoolConfig {
+    endpoints: Vec<EndpointConfig>,
+    #[serde(default = "default_quorum")]
+    quorum: NonZeroUsize,
+    #[serde(default)]
+    max_lag: Option<u64>,
+    #[serde(default = "default_max_failures")]
+    max_failures: u32,
+    #[serde(default = "default_cooldown_secs")]
+    cooldown_secs: u64,
+    #[serde(default = "default_health_check_interval_secs")]
+    health_check_interval_secs: u64,
+}
+
+fn default_quorum() -> NonZeroUsize {
+    NonZeroUsize::MIN
+}
+
+fn default_max_failures() -> u32 {
+    PoolConfig::DEFAULT_MAX_FAILURES
+}
+
+fn default_cooldown_secs() -> u64 {
+    PoolConfig::DEFAULT_COOLDOWN_SECS
+}
+
+fn default_health_check_interval_secs() -> u64 {
+    PoolConfig::DEFAULT_HEALTH_CHECK_INTERVAL_SECS
+}
+
+impl From<PoolConfigRepr> for PoolConfig {
+    fn from(value: PoolConfigRepr) -> Self {
+        let endpoints_only = |endpoints| FullPoolConfig {
+            endpoints,
+            quorum: default_quorum(),
+            max_lag: None,
+            max_failures: default_max_failures(),
+            cooldown_secs: default_cooldown_secs(),
+            health_check_interval_secs: default_health_check_interval_secs(),
+        };
+
+        let FullPoolConfig {
+            endpoints,
+            quorum,
+            max_lag,
+            max_failures,
+            cooldown_secs,
+            health_check_interval_secs,
+        } = match value {
+            PoolConfigRepr::Url(url) => endpoints_only(vec![EndpointConfig {
+                url,
+                weight: EndpointConfig::DEFAULT_WEIGHT,
+            }]),
+            PoolConfigRepr::Endpoints(endpoints) => endpoints_only(endpoints),
+            PoolConfigRepr::Full(config) => config,
+        };
+
+        Self {
+            endpoints,
+            quorum,
+            max_lag,
+            max_failures,
+            cooldown_secs,
+            health_check_interval_secs,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum EndpointConfigRepr {
+    Url(String),
+    Full {
+        url: String,
+        #[serde(default = "default_weight")]
+        weight: u32,
+    },
+}
+
+fn default_weight() -> u32 {
+    EndpointConfig::DEFAULT_WEIGHT
+}
+
+impl From<EndpointConfigRepr> for EndpointConfig {
+    fn from(value: EndpointConfigRepr) -> Self {
+        match value {
+            EndpointConfigRepr::Url(url) => Self {
+                url,
+                weight: EndpointConfig::DEFAULT_WEIGHT,
+            },
+            EndpointConfigRepr::Full { url, weight } => Self { url, weight },
+        }
+    }
+}
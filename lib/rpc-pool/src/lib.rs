@@ -0,0 +1,501 @@
+//! A pool of RPC endpoints for a single chain, with weighted load balancing, failover, lag
+//! detection and optional N-of-M quorum reads.
+//!
+//! The pool is agnostic over the client type; see the [`alloy`] module for a pooled alloy
+//! transport, and `cometbft_rpc::Client::new_pool` for a pooled cometbft rpc client.
+
+use std::{
+    fmt::{self, Display},
+    future::Future,
+    sync::{Arc, Mutex, Weak},
+    time::Instant,
+};
+
+use futures::{stream::FuturesUnordered, StreamExt};
+use tracing::{debug, trace, warn};
+use unionlabs::ErrorReporter;
+
+pub use crate::config::{EndpointConfig, PoolConfig};
+
+#[cfg(feature = "alloy")]
+pub mod alloy;
+mod config;
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug)]
+pub struct RpcPool<C> {
+    inner: Arc<Inner<C>>,
+}
+
+impl<C> Clone for RpcPool<C> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Inner<C> {
+    config: PoolConfig,
+    endpoints: Vec<Endpoint<C>>,
+    state: Mutex<Vec<EndpointState>>,
+}
+
+#[derive(Debug)]
+struct Endpoint<C> {
+    url: String,
+    weight: u32,
+    client: C,
+}
+
+#[derive(Debug, Default)]
+struct EndpointState {
+    /// Used for smooth weighted round robin selection.
+    current_weight: i64,
+    consecutive_failures: u32,
+    unhealthy_until: Option<Instant>,
+    latest_height: Option<u64>,
+}
+
+#[derive(Debug)]
+pub enum PoolError<E> {
+    NoEndpoints,
+    InvalidQuorum {
+        quorum: usize,
+        endpoints: usize,
+    },
+    AllFailed(Vec<EndpointError<E>>),
+    QuorumUnreachable {
+        quorum: usize,
+        connected: usize,
+        errors: Vec<EndpointError<E>>,
+    },
+    NoQuorum {
+        required: usize,
+        agreeing: usize,
+        errors: Vec<EndpointError<E>>,
+    },
+}
+
+impl<E: Display> Display for PoolError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoEndpoints => write!(f, "no endpoints configured"),
+            Self::InvalidQuorum { quorum, endpoints } => write!(
+                f,
+                "quorum of {quorum} is larger than the amount of endpoints ({endpoints})"
+            ),
+            Self::AllFailed(errors) => {
+                write!(f, "all endpoints failed: {}", display_errors(errors))
+            }
+            Self::QuorumUnreachable {
+                quorum,
+                connected,
+                errors,
+            } => write!(
+                f,
+                "quorum of {quorum} is larger than the amount of connected endpoints \
+                ({connected}): {}",
+                display_errors(errors)
+            ),
+            Self::NoQuorum {
+                required,
+                agreeing,
+                errors,
+            } => write!(
+                f,
+                "no quorum reached, {agreeing} of the required {required} endpoints agreed: {}",
+                display_errors(errors)
+            ),
+        }
+    }
+}
+
+impl<E: core::error::Error> core::error::Error for PoolError<E> {}
+
+impl<E> PoolError<E> {
+    /// Unwrap the error returned by the preferred endpoint, if this error was caused by every
+    /// endpoint failing. This is useful to preserve the error type of the underlying client for
+    /// callers that inspect it.
+    pub fn into_first_endpoint_error(self) -> Result<E, Self> {
+        match self {
+            Self::AllFailed(errors) if !errors.is_empty() => {
+                Ok(errors.into_iter().next().expect("not empty; qed;").error)
+            }
+            err => Err(err),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("{url}: {error}")]
+pub struct EndpointError<E> {
+    pub url: String,
+    pub error: E,
+}
+
+fn display_errors<E: Display>(errors: &[EndpointError<E>]) -> String {
+    errors
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+impl<C> RpcPool<C> {
+    /// Connect to all of the endpoints in `config`. Endpoints that fail to connect are not part of
+    /// the pool; this only fails if no endpoint could be connected to, or if fewer endpoints than
+    /// the configured quorum could be connected to, as no quorum read would ever succeed.
+    pub async fn new<F, Fut, E>(config: PoolConfig, connect: F) -> Result<Self, PoolError<E>>
+    where
+        F: Fn(String) -> Fut,
+        Fut: Future<Output = Result<C, E>>,
+        E: core::error::Error,
+    {
+        if config.endpoints.is_empty() {
+            return Err(PoolError::NoEndpoints);
+        }
+
+        if config.quorum.get() > config.endpoints.len() {
+            return Err(PoolError::InvalidQuorum {
+                quorum: config.quorum.get(),
+                endpoints: config.endpoints.len(),
+            });
+        }
+
+        let mut endpoints = vec![];
+        let mut errors = vec![];
+
+        for endpoint in &config.endpoints {
+            match connect(endpoint.url.clone()).await {
+                Ok(client) => endpoints.push(Endpoint {
+                    url: endpoint.url.clone(),
+                    weight: endpoint.weight,
+                    client,
+                }),
+                Err(error) => {
+                    warn!(
+                        url = %endpoint.url,
+                        "unable to connect to endpoint: {}",
+                        ErrorReporter(&error)
+                    );
+
+                    errors.push(EndpointError {
+                        url: endpoint.url.clone(),
+                        error,
+                    });
+                }
+            }
+        }
+
+        if endpoints.is_empty() {
+            return Err(PoolError::AllFailed(errors));
+        }
+
+        if config.quorum.get() > endpoints.len() {
+            return Err(PoolError::QuorumUnreachable {
+                quorum: config.quorum.get(),
+                connected: endpoints.len(),
+                errors,
+            });
+        }
+
+        Ok(Self {
+            inner: Arc::new(Inner {
+                state: Mutex::new(endpoints.iter().map(|_| EndpointState::default()).collect()),
+                endpoints,
+                config,
+            }),
+        })
+    }
+
+    #[must_use]
+    pub fn config(&self) -> &PoolConfig {
+        &self.inner.config
+    }
+
+    /// The amount of endpoints that must agree on the result of [`Self::quorum`].
+    #[must_use]
+    pub fn quorum_size(&self) -> usize {
+        self.inner.config.quorum.get()
+    }
+
+    /// Run `f` against the endpoints of the pool, in order of preference, until one succeeds.
+    pub async fn call<T, E, F, Fut>(&self, f: F) -> Result<T, PoolError<E>>
+    where
+        C: Clone,
+        F: Fn(C) -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+        E: core::error::Error,
+    {
+        let mut errors = vec![];
+
+        for idx in self.order() {
+            let endpoint = &self.inner.endpoints[idx];
+
+            match f(endpoint.client.clone()).await {
+                Ok(t) => {
+                    self.record_success(idx);
+
+                    return Ok(t);
+                }
+                Err(error) => {
+                    debug!(
+                        url = %endpoint.url,
+                        "request failed, trying next endpoint: {}",
+                        ErrorReporter(&error)
+                    );
+
+                    self.record_failure(idx);
+
+                    errors.push(EndpointError {
+                        url: endpoint.url.clone(),
+                        error,
+                    });
+                }
+            }
+        }
+
+        Err(PoolError::AllFailed(errors))
+    }
+
+    /// Run `f` against all endpoints of the pool concurrently, returning the first result that
+    /// [`Self::quorum_size`] endpoints agree on, as determined by `key`. If the quorum is 1, this
+    /// is equivalent to [`Self::call`].
+    pub async fn quorum<T, K, E, F, Fut>(
+        &self,
+        f: F,
+        key: impl Fn(&T) -> K,
+    ) -> Result<T, PoolError<E>>
+    where
+        C: Clone,
+        F: Fn(C) -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+        K: PartialEq,
+        E: core::error::Error,
+    {
+        let required = self.quorum_size();
+
+        if required == 1 {
+            return self.call(f).await;
+        }
+
+        let mut responses = self
+            .order()
+            .into_iter()
+            .map(|idx| {
+                let fut = f(self.inner.endpoints[idx].client.clone());
+                async move { (idx, fut.await) }
+            })
+            .collect::<FuturesUnordered<_>>();
+
+        // (key, first response with this key, amount of endpoints that returned this key)
+        let mut groups = Vec::<(K, T, usize)>::new();
+        let mut errors = vec![];
+
+        while let Some((idx, res)) = responses.next().await {
+            let endpoint = &self.inner.endpoints[idx];
+
+            match res {
+                Ok(t) => {
+                    self.record_success(idx);
+
+                    let k = key(&t);
+
+                    match groups.iter_mut().position(|(group, _, _)| *group == k) {
+                        Some(pos) => groups[pos].2 += 1,
+                        None => groups.push((k, t, 1)),
+                    }
+
+                    if let Some(pos) = groups.iter().position(|(_, _, count)| *count >= required) {
+                        trace!(%required, "quorum reached");
+
+                        return Ok(groups.swap_remove(pos).1);
+                    }
+                }
+                Err(error) => {
+                    debug!(url = %endpoint.url, "quorum request failed: {}", ErrorReporter(&error));
+
+                    self.record_failure(idx);
+
+                    errors.push(EndpointError {
+                        url: endpoint.url.clone(),
+                        error,
+                    });
+                }
+            }
+        }
+
+        let agreeing = groups.iter().map(|(_, _, count)| *count).max().unwrap_or(0);
+
+        warn!(%required, %agreeing, "no quorum reached");
+
+        Err(PoolError::NoQuorum {
+            required,
+            agreeing,
+            errors,
+        })
+    }
+
+    /// Query the latest height of every endpoint. Endpoints that fail this check are treated the
+    /// same as endpoints that fail a request, and the heights are used to detect lagging
+    /// endpoints if [`PoolConfig::max_lag`] is set.
+    pub async fn check_health<E, F, Fut>(&self, latest_height: F)
+    where
+        C: Clone,
+        F: Fn(C) -> Fut,
+        Fut: Future<Output = Result<u64, E>>,
+        E: core::error::Error,
+    {
+        let mut responses = self
+            .inner
+            .endpoints
+            .iter()
+            .enumerate()
+            .map(|(idx, endpoint)| {
+                let fut = latest_height(endpoint.client.clone());
+                async move { (idx, fut.await) }
+            })
+            .collect::<FuturesUnordered<_>>();
+
+        while let Some((idx, res)) = responses.next().await {
+            match res {
+                Ok(height) => {
+                    trace!(url = %self.inner.endpoints[idx].url, %height, "health check");
+
+                    self.record_height(idx, height);
+                    self.record_success(idx);
+                }
+                Err(error) => {
+                    debug!(
+                        url = %self.inner.endpoints[idx].url,
+                        "health check failed: {}",
+                        ErrorReporter(&error)
+                    );
+
+                    self.record_failure(idx);
+                }
+            }
+        }
+    }
+
+    /// Spawn a task that runs [`Self::check_health`] every
+    /// [`PoolConfig::health_check_interval_secs`]. The task stops once all handles to the pool are
+    /// dropped.
+    pub fn spawn_health_check<E, F, Fut>(&self, latest_height: F)
+    where
+        C: Clone + Send + Sync + 'static,
+        F: Fn(C) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<u64, E>> + Send + 'static,
+        E: core::error::Error + Send + 'static,
+    {
+        let pool = Arc::downgrade(&self.inner);
+        let interval = self.inner.config.health_check_interval();
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(interval);
+
+            loop {
+                interval.tick().await;
+
+                let Some(inner) = Weak::upgrade(&pool) else {
+                    debug!("pool dropped, stopping health check");
+                    return;
+                };
+
+                RpcPool { inner }.check_health(&latest_height).await;
+            }
+        });
+    }
+
+    /// The indices of the endpoints in the order they should be tried.
+    ///
+    /// The first endpoint is selected with smooth weighted round robin among the healthy
+    /// endpoints, followed by the remaining healthy endpoints by weight, and finally the
+    /// unhealthy endpoints as a last resort.
+    fn order(&self) -> Vec<usize> {
+        let now = Instant::now();
+
+        let mut state = self.inner.state.lock().expect("lock is not poisoned; qed;");
+
+        let best_height = state.iter().filter_map(|s| s.latest_height).max();
+
+        let is_healthy = |s: &EndpointState| {
+            let cooling_down = s.unhealthy_until.is_some_and(|until| until > now);
+
+            let lagging = match (self.inner.config.max_lag, best_height, s.latest_height) {
+                (Some(max_lag), Some(best_height), Some(height)) => best_height - height > max_lag,
+                _ => false,
+            };
+
+            !cooling_down && !lagging
+        };
+
+        let (mut healthy, mut unhealthy): (Vec<_>, Vec<_>) =
+            (0..state.len()).partition(|idx| is_healthy(&state[*idx]));
+
+        let total_weight = healthy
+            .iter()
+            .map(|idx| i64::from(self.inner.endpoints[*idx].weight))
+            .sum::<i64>();
+
+        for idx in &healthy {
+            state[*idx].current_weight += i64::from(self.inner.endpoints[*idx].weight);
+        }
+
+        // highest weight first, stable so that config order breaks ties
+        healthy.sort_by_key(|idx| core::cmp::Reverse(self.inner.endpoints[*idx].weight));
+
+        if let Some(pos) = healthy
+            .iter()
+            .enumerate()
+            .max_by_key(|(pos, idx)| (state[**idx].current_weight, core::cmp::Reverse(*pos)))
+            .map(|(pos, _)| pos)
+        {
+            state[healthy[pos]].current_weight -= total_weight;
+
+            let selected = healthy.remove(pos);
+            healthy.insert(0, selected);
+        }
+
+        unhealthy.sort_by_key(|idx| state[*idx].unhealthy_until);
+
+        healthy.extend(unhealthy);
+
+        healthy
+    }
+
+    fn record_success(&self, idx: usize) {
+        let mut state = self.inner.state.lock().expect("lock is not poisoned; qed;");
+
+        state[idx].consecutive_failures = 0;
+        state[idx].unhealthy_until = None;
+    }
+
+    fn record_failure(&self, idx: usize) {
+        let mut state = self.inner.state.lock().expect("lock is not poisoned; qed;");
+
+        let s = &mut state[idx];
+
+        s.consecutive_failures += 1;
+
+        if s.consecutive_failures >= self.inner.config.max_failures {
+            warn!(
+                url = %self.inner.endpoints[idx].url,
+                consecutive_failures = %s.consecutive_failures,
+                "endpoint is unhealthy"
+            );
+
+            s.unhealthy_until = Some(Instant::now() + self.inner.config.cooldown());
+        }
+    }
+
+    fn record_height(&self, idx: usize, height: u64) {
+        let mut state = self.inner.state.lock().expect("lock is not poisoned; qed;");
+
+        state[idx].latest_height = Some(height);
+    }
+}
@@ -0,0 +1,244 @@
+use std::{
+    num::NonZeroUsize,
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+use super::*;
+
+#[derive(Debug, thiserror::Error)]
+#[error("endpoint {0} failed")]
+struct MockError(usize);
+
+#[derive(Debug)]
+struct MockEndpoint {
+    idx: usize,
+    result: u64,
+    failing: AtomicBool,
+    calls: AtomicUsize,
+}
+
+type MockClient = Arc<MockEndpoint>;
+
+async fn pool(endpoints: &[(u32, u64)], quorum: usize) -> RpcPool<MockClient> {
+    let config = PoolConfig {
+        endpoints: endpoints
+            .iter()
+            .enumerate()
+            .map(|(idx, (weight, _))| EndpointConfig {
+                url: idx.to_string(),
+                weight: *weight,
+            })
+            .collect(),
+        quorum: NonZeroUsize::new(quorum).unwrap(),
+        max_lag: Some(5),
+        max_failures: 1,
+        cooldown_secs: 60,
+        health_check_interval_secs: 60,
+    };
+
+    RpcPool::new(config, |url| async move {
+        let idx = url.parse::<usize>().unwrap();
+
+        Ok::<_, MockError>(Arc::new(MockEndpoint {
+            idx,
+            result: endpoints[idx].1,
+            failing: AtomicBool::new(false),
+            calls: AtomicUsize::new(0),
+        }))
+    })
+    .await
+    .unwrap()
+}
+
+async fn request(client: MockClient) -> Result<u64, MockError> {
+    client.calls.fetch_add(1, Ordering::SeqCst);
+
+    if client.failing.load(Ordering::SeqCst) {
+        Err(MockError(client.idx))
+    } else {
+        Ok(client.result)
+    }
+}
+
+fn endpoint(pool: &RpcPool<MockClient>, idx: usize) -> &MockEndpoint {
+    &pool.inner.endpoints[idx].client
+}
+
+fn calls(pool: &RpcPool<MockClient>) -> Vec<usize> {
+    pool.inner
+        .endpoints
+        .iter()
+        .map(|endpoint| endpoint.client.calls.load(Ordering::SeqCst))
+        .collect()
+}
+
+#[test]
+fn config_from_url() {
+    let config = serde_json::from_str::<PoolConfig>(r#""https://rpc.example.com""#).unwrap();
+
+    assert_eq!(config, PoolConfig::single("https://rpc.example.com"));
+    assert_eq!(config.quorum.get(), 1);
+}
+
+#[test]
+fn config_from_endpoints() {
+    let config = serde_json::from_str::<PoolConfig>(
+        r#"["https://rpc-1.example.com", { "url": "https://rpc-2.example.com", "weight": 2 }]"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        config.endpoints,
+        [
+            EndpointConfig {
+                url: "https://rpc-1.example.com".to_owned(),
+                weight: 1,
+            },
+            EndpointConfig {
+                url: "https://rpc-2.example.com".to_owned(),
+                weight: 2,
+            },
+        ]
+    );
+}
+
+#[test]
+fn config_roundtrip() {
+    let config = serde_json::from_str::<PoolConfig>(
+        r#"{ "endpoints": ["https://rpc-1.example.com", "https://rpc-2.example.com"], "quorum": 2, "max_lag": 10 }"#,
+    )
+    .unwrap();
+
+    assert_eq!(config.quorum.get(), 2);
+    assert_eq!(config.max_lag, Some(10));
+    assert_eq!(config.max_failures, PoolConfig::DEFAULT_MAX_FAILURES);
+
+    assert_eq!(
+        serde_json::from_value::<PoolConfig>(serde_json::to_value(&config).unwrap()).unwrap(),
+        config
+    );
+}
+
+#[tokio::test]
+async fn invalid_quorum() {
+    let res = RpcPool::new(
+        serde_json::from_str::<PoolConfig>(r#"{ "endpoints": ["a"], "quorum": 2 }"#).unwrap(),
+        |_| async { Ok::<_, MockError>(()) },
+    )
+    .await;
+
+    assert!(matches!(
+        res,
+        Err(PoolError::InvalidQuorum {
+            quorum: 2,
+            endpoints: 1
+        })
+    ));
+}
+
+#[tokio::test]
+async fn quorum_unreachable_with_failed_endpoints() {
+    let res = RpcPool::new(
+        serde_json::from_str::<PoolConfig>(r#"{ "endpoints": ["a", "b"], "quorum": 2 }"#).unwrap(),
+        |url| async move {
+            if url == "a" {
+                Ok(())
+            } else {
+                Err(MockError(1))
+            }
+        },
+    )
+    .await;
+
+    assert!(matches!(
+        res,
+        Err(PoolError::QuorumUnreachable {
+            quorum: 2,
+            connected: 1,
+            errors,
+        }) if errors.len() == 1
+    ));
+}
+
+#[tokio::test]
+async fn weighted_round_robin() {
+    let pool = pool(&[(2, 0), (1, 0)], 1).await;
+
+    for _ in 0..6 {
+        pool.call(request).await.unwrap();
+    }
+
+    assert_eq!(calls(&pool), [4, 2]);
+}
+
+#[tokio::test]
+async fn fails_over() {
+    let pool = pool(&[(1, 0), (0, 1)], 1).await;
+
+    endpoint(&pool, 0).failing.store(true, Ordering::SeqCst);
+
+    assert_eq!(pool.call(request).await.unwrap(), 1);
+
+    // the failing endpoint is now cooling down and is not tried first anymore
+    assert_eq!(pool.call(request).await.unwrap(), 1);
+    assert_eq!(calls(&pool), [1, 2]);
+}
+
+#[tokio::test]
+async fn all_failed() {
+    let pool = pool(&[(1, 0), (1, 0)], 1).await;
+
+    endpoint(&pool, 0).failing.store(true, Ordering::SeqCst);
+    endpoint(&pool, 1).failing.store(true, Ordering::SeqCst);
+
+    let err = pool.call(request).await.unwrap_err();
+
+    assert!(matches!(&err, PoolError::AllFailed(errors) if errors.len() == 2));
+    assert!(matches!(err.into_first_endpoint_error(), Ok(MockError(0))));
+}
+
+#[tokio::test]
+async fn lagging_endpoint_is_skipped() {
+    let pool = pool(&[(1, 0), (0, 1)], 1).await;
+
+    pool.check_health(|client: MockClient| async move {
+        Ok::<_, MockError>(if client.idx == 0 { 100 } else { 110 })
+    })
+    .await;
+
+    assert_eq!(pool.call(request).await.unwrap(), 1);
+    assert_eq!(calls(&pool), [0, 1]);
+}
+
+#[tokio::test]
+async fn quorum_reached() {
+    let pool = pool(&[(1, 1), (1, 2), (1, 2)], 2).await;
+
+    assert_eq!(pool.quorum(request, |res| *res).await.unwrap(), 2);
+}
+
+#[tokio::test]
+async fn quorum_not_reached() {
+    let pool = pool(&[(1, 1), (1, 2), (1, 3)], 2).await;
+
+    assert!(matches!(
+        pool.quorum(request, |res| *res).await,
+        Err(PoolError::NoQuorum {
+            required: 2,
+            agreeing: 1,
+            ..
+        })
+    ));
+}
+
+#[tokio::test]
+async fn quorum_ignores_failing_endpoints() {
+    let pool = pool(&[(1, 1), (1, 1), (1, 1)], 2).await;
+
+    endpoint(&pool, 1).failing.store(true, Ordering::SeqCst);
+
+    assert_eq!(pool.quorum(request, |res| *res).await.unwrap(), 1);
+}
@@ -29,6 +29,18 @@ impl ScrollClient {
             .unwrap()
     }
 
+    #[instrument(level = "debug", skip(self))]
+    pub async fn chunks(&self, batch: u64) -> ChunksResponse {
+        self.client
+            .get(format!("{}/api/chunks?batch_index={batch}", self.base_url))
+            .send()
+            .await
+            .unwrap()
+            .json()
+            .await
+            .unwrap()
+    }
+
     // #[instrument(level = "debug", skip(self))]
     // pub async fn batches(&self, page: u64, per_page: u64) -> BatchResponse {
     //     self.client
@@ -74,6 +86,22 @@ pub struct BatchResponse {
     pub batch: BatchResponseData,
 }
 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct ChunkResponseData {
+    /// The data hash of the chunk.
+    pub hash: H256,
+    pub index: u64,
+    pub start_block_number: u64,
+    pub end_block_number: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct ChunksResponse {
+    pub chunks: Vec<ChunkResponseData>,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum RollupStatus {
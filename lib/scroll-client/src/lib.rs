@@ -0,0 +1,143 @@
+use alloy::{
+    consensus::Transaction,
+    providers::Provider,
+    sol,
+    transports::{RpcError, TransportErrorKind},
+};
+use alloy_sol_types::SolCall;
+use scroll_api::ScrollClient;
+use scroll_codec::{
+    batch_header::{BatchHeaderV3, BatchHeaderV3DecodeError},
+    finalizeBundleCall, finalizeBundleWithProofCall,
+};
+use tracing::{debug, instrument};
+use unionlabs::primitives::{Bytes, H160, H256};
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error(transparent)]
+    Contract(#[from] alloy::contract::Error),
+    #[error(transparent)]
+    Rpc(#[from] RpcError<TransportErrorKind>),
+    #[error("batch {0} has not been finalized")]
+    BatchNotFinalized(u64),
+    #[error("finalize transaction {0} not found")]
+    FinalizeTransactionNotFound(H256),
+    #[error("finalize transaction {0} is not a call to finalizeBundle or finalizeBundleWithProof")]
+    UnknownFinalizeCall(H256),
+    #[error("invalid batch header in finalize transaction {tx_hash}")]
+    BatchHeaderDecode {
+        tx_hash: H256,
+        #[source]
+        err: BatchHeaderV3DecodeError,
+    },
+    #[error("finalize transaction {tx_hash} finalized batch {found}, expected batch {expected}")]
+    BatchIndexMismatch {
+        tx_hash: H256,
+        expected: u64,
+        found: u64,
+    },
+}
+
+/// The index of the latest batch that has been finalized on the L1 by the `ScrollChain` contract,
+/// as of `l1_block_number`.
+#[instrument(skip_all, fields(%l1_block_number, %l1_rollup_contract_address))]
+pub async fn finalized_batch_index_of_l1_block_number(
+    l1_provider: &impl Provider,
+    l1_rollup_contract_address: H160,
+    l1_block_number: u64,
+) -> Result<u64, alloy::contract::Error> {
+    let c = ScrollChain::new(l1_rollup_contract_address.into(), l1_provider);
+
+    let batch_index = c
+        .lastFinalizedBatchIndex()
+        .block(l1_block_number.into())
+        .call()
+        .await?;
+
+    debug!(%batch_index);
+
+    Ok(batch_index
+        .try_into()
+        .expect("batch index should fit in a u64"))
+}
+
+/// The last L2 block number of the latest batch that has been finalized on the L1 by the
+/// `ScrollChain` contract, as of `l1_block_number`.
+#[instrument(skip_all, fields(%l1_block_number, %l1_rollup_contract_address))]
+pub async fn finalized_l2_block_number_of_l1_block_number(
+    l1_provider: &impl Provider,
+    scroll_api_client: &ScrollClient,
+    l1_rollup_contract_address: H160,
+    l1_block_number: u64,
+) -> Result<u64, alloy::contract::Error> {
+    let batch_index = finalized_batch_index_of_l1_block_number(
+        l1_provider,
+        l1_rollup_contract_address,
+        l1_block_number,
+    )
+    .await?;
+
+    let block_number = scroll_api_client
+        .batch(batch_index)
+        .await
+        .batch
+        .end_block_number;
+
+    debug!(%block_number);
+
+    Ok(block_number)
+}
+
+/// Fetch the header of the finalized batch `batch_index`.
+///
+/// Only the header of the last batch of a bundle is submitted to the L1, as part of the calldata
+/// of the `finalizeBundle`/`finalizeBundleWithProof` call. This will fail if `batch_index` is not
+/// the last batch of its bundle, which is always the case for the `lastFinalizedBatchIndex`.
+#[instrument(skip_all, fields(%batch_index))]
+pub async fn batch_header_of_batch_index(
+    l1_provider: &impl Provider,
+    scroll_api_client: &ScrollClient,
+    batch_index: u64,
+) -> Result<Bytes, Error> {
+    let batch = scroll_api_client.batch(batch_index).await.batch;
+
+    let tx_hash = batch
+        .finalize_tx_hash
+        .ok_or(Error::BatchNotFinalized(batch_index))?;
+
+    let tx = l1_provider
+        .get_transaction_by_hash(tx_hash.into())
+        .await?
+        .ok_or(Error::FinalizeTransactionNotFound(tx_hash))?;
+
+    let batch_header = if let Ok(call) = finalizeBundleWithProofCall::abi_decode(tx.input()) {
+        call._batchHeader
+    } else if let Ok(call) = finalizeBundleCall::abi_decode(tx.input()) {
+        call._batchHeader
+    } else {
+        return Err(Error::UnknownFinalizeCall(tx_hash));
+    };
+
+    let found = BatchHeaderV3::decode(&batch_header)
+        .map_err(|err| Error::BatchHeaderDecode { tx_hash, err })?
+        .batch_index;
+
+    if found != batch_index {
+        return Err(Error::BatchIndexMismatch {
+            tx_hash,
+            expected: batch_index,
+            found,
+        });
+    }
+
+    Ok(batch_header.into())
+}
+
+sol! {
+    #![sol(rpc)]
+
+    contract ScrollChain {
+        function lastFinalizedBatchIndex() returns (uint256);
+    }
+}
@@ -0,0 +1,173 @@
+use sha3::{Digest, Keccak256};
+use unionlabs::{
+    errors::{ExpectedLength, InvalidLength},
+    primitives::{ByteArrayExt, H256, U256},
+};
+
+/// @dev Below is the encoding for `BlockContext`, total 60 bytes.
+/// ```text
+///   * Field                   Bytes      Type         Index  Comments
+///   * blockNumber             8          uint64       0      The height of this block.
+///   * timestamp               8          uint64       8      The timestamp of this block.
+///   * baseFee                 32         uint256      16     The base fee of this block.
+///   * gasLimit                8          uint64       48     The gas limit of this block.
+///   * numTransactions         2          uint16       56     The number of transactions in this block, both L1 & L2 txs.
+///   * numL1Messages           2          uint16       58     The number of l1 messages in this block.
+/// ```
+/// Only the first 58 bytes (i.e. without `numL1Messages`) are committed to in the chunk data hash,
+/// so that is the encoding used here.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlockContext {
+    /// The height of this block.
+    pub block_number: u64,
+    /// The timestamp of this block.
+    pub timestamp: u64,
+    /// The base fee of this block.
+    pub base_fee: U256,
+    /// The gas limit of this block.
+    pub gas_limit: u64,
+    /// The number of transactions in this block, both L1 & L2 txs.
+    pub num_transactions: u16,
+}
+
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum BlockContextDecodeError {
+    #[error("invalid block context length")]
+    InvalidLength(#[source] InvalidLength),
+}
+
+impl BlockContext {
+    pub const HASHED_LENGTH: usize = 58;
+
+    /// Try to decode the block context from the input stream.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the length of the stream doesn't match [`Self::HASHED_LENGTH`].
+    pub fn decode(bz: impl AsRef<[u8]>) -> Result<Self, BlockContextDecodeError> {
+        let bz = bz.as_ref();
+
+        let slice: [u8; Self::HASHED_LENGTH] = bz.try_into().map_err(|_| {
+            BlockContextDecodeError::InvalidLength(InvalidLength {
+                expected: ExpectedLength::Exact(Self::HASHED_LENGTH),
+                found: bz.len(),
+            })
+        })?;
+
+        Ok(Self {
+            block_number: u64::from_be_bytes(slice.array_slice::<0, 8>()),
+            timestamp: u64::from_be_bytes(slice.array_slice::<8, 8>()),
+            base_fee: U256::from_be_bytes(slice.array_slice::<16, 32>()),
+            gas_limit: u64::from_be_bytes(slice.array_slice::<48, 8>()),
+            num_transactions: u16::from_be_bytes(slice.array_slice::<56, 2>()),
+        })
+    }
+
+    #[must_use]
+    pub fn encode(&self) -> [u8; Self::HASHED_LENGTH] {
+        let mut bz = [0; Self::HASHED_LENGTH];
+
+        bz[0..8].copy_from_slice(&self.block_number.to_be_bytes());
+        bz[8..16].copy_from_slice(&self.timestamp.to_be_bytes());
+        bz[16..48].copy_from_slice(&self.base_fee.to_be_bytes());
+        bz[48..56].copy_from_slice(&self.gas_limit.to_be_bytes());
+        bz[56..58].copy_from_slice(&self.num_transactions.to_be_bytes());
+
+        bz
+    }
+}
+
+/// Compute the data hash of a chunk, which is the hash of the block contexts of the blocks in the
+/// chunk followed by the hashes of the L1 messages included in the chunk.
+///
+/// See `_commitChunkV1` in the `ScrollChain` contract.
+#[must_use]
+pub fn compute_chunk_data_hash<'a>(
+    block_contexts: impl IntoIterator<Item = &'a BlockContext>,
+    l1_message_hashes: impl IntoIterator<Item = &'a H256>,
+) -> H256 {
+    let mut hasher = Keccak256::new();
+
+    for block_context in block_contexts {
+        hasher.update(block_context.encode());
+    }
+
+    for l1_message_hash in l1_message_hashes {
+        hasher.update(l1_message_hash);
+    }
+
+    hasher.finalize().into()
+}
+
+/// Compute the data hash of a batch, which is the hash of the data hashes of the chunks in the
+/// batch.
+#[must_use]
+pub fn compute_batch_data_hash<'a>(chunk_data_hashes: impl IntoIterator<Item = &'a H256>) -> H256 {
+    let mut hasher = Keccak256::new();
+
+    for chunk_data_hash in chunk_data_hashes {
+        hasher.update(chunk_data_hash);
+    }
+
+    hasher.finalize().into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn block_context_roundtrip() {
+        let block_context = BlockContext {
+            block_number: 0x0102_0304_0506_0708,
+            timestamp: 1_723_000_000,
+            base_fee: U256::from(0x0123_4567_u64),
+            gas_limit: 10_000_000,
+            num_transactions: 7,
+        };
+
+        let encoded = block_context.encode();
+
+        assert_eq!(encoded[0..8], [1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(BlockContext::decode(encoded), Ok(block_context));
+    }
+
+    #[test]
+    fn block_context_invalid_length() {
+        assert!(matches!(
+            BlockContext::decode([0; 60]),
+            Err(BlockContextDecodeError::InvalidLength(InvalidLength {
+                found: 60,
+                ..
+            }))
+        ));
+    }
+
+    #[test]
+    fn data_hashes_are_keccak_of_concatenation() {
+        let block_context = BlockContext {
+            block_number: 1,
+            timestamp: 2,
+            base_fee: U256::from(3_u64),
+            gas_limit: 4,
+            num_transactions: 5,
+        };
+        let l1_message_hash = H256::new([0xAA; 32]);
+
+        let chunk_data_hash = compute_chunk_data_hash([&block_context], [&l1_message_hash]);
+
+        assert_eq!(
+            chunk_data_hash,
+            <H256>::from(<[u8; 32]>::from(Keccak256::digest(
+                [block_context.encode().as_slice(), l1_message_hash.get()].concat()
+            )))
+        );
+
+        assert_eq!(
+            compute_batch_data_hash([&chunk_data_hash, &chunk_data_hash]),
+            <H256>::from(<[u8; 32]>::from(Keccak256::digest(
+                [chunk_data_hash.get().as_slice(), chunk_data_hash.get()].concat()
+            )))
+        );
+    }
+}
@@ -1,19 +1,29 @@
-use unionlabs::{
-    ibc::core::client::height::Height,
-    primitives::{H160, U256},
-};
+use ibc_union_spec::ClientId;
+use unionlabs::primitives::{H160, U256};
 
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct ClientState {
-    pub chain_id: u64,
-    pub frozen_height: Height,
-    pub ibc_contract_address: H160,
-    // TODO: This should be ClientId
-    pub l1_client_id: u32,
-    pub l2_committed_batches_slot: U256,
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+pub enum ClientState {
+    V1(ClientStateV1),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+pub struct ClientStateV1 {
+    pub chain_id: U256,
+    pub latest_height: u64,
+    /// Client id of the client tracking the L1 that the chain this client tracks settles on
+    pub l1_client_id: ClientId,
+    /// Address of the `ScrollChain` rollup contract on the L1.
     pub l2_contract_address: H160,
-    pub l2_finalized_state_roots_slot: U256,
+    /// Slot of `lastFinalizedBatchIndex` within the `ScrollChain` contract.
     pub latest_batch_index_slot: U256,
-    pub latest_slot: u64,
+    /// Slot of the `finalizedStateRoots` mapping within the `ScrollChain` contract.
+    pub l2_finalized_state_roots_slot: U256,
+    /// Slot of the `committedBatches` mapping within the `ScrollChain` contract.
+    pub l2_committed_batches_slot: U256,
+    pub frozen_height: u64,
+    pub ibc_contract_address: H160,
 }
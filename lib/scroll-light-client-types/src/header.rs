@@ -1,15 +1,38 @@
 use ethereum_light_client_types::{AccountProof, StorageProof};
-use unionlabs::ibc::core::client::height::Height;
+use unionlabs::primitives::{Bytes, H256};
 
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
 pub struct Header {
-    pub l1_height: Height,
+    pub l1_height: u64,
+    /// The number of the last L2 block in the finalized batch, proven by `l2_height_proof`.
+    pub l2_height: u64,
+    /// Proof of the storage root of the `ScrollChain` contract in the L1 state root.
     pub l1_account_proof: AccountProof,
     /// This is the finalized state root proof, i.e. the l2 state on the l1
     pub l2_state_root_proof: StorageProof,
     pub last_batch_index_proof: StorageProof,
     pub batch_hash_proof: StorageProof,
     pub l2_ibc_account_proof: AccountProof,
-    pub batch_header: Vec<u8>,
+    /// The header of the last finalized batch, which must hash to the value proven by
+    /// `batch_hash_proof`.
+    pub batch_header: Bytes,
+    pub l2_height_proof: L2HeightProof,
+}
+
+/// Proof of the number of the last L2 block in a batch, against the data hash of the batch header.
+///
+/// The data hash of a batch is the hash of the data hashes of its chunks, and the data hash of a
+/// chunk commits to the block contexts of its blocks, which start with the block number.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+pub struct L2HeightProof {
+    /// The data hashes of all chunks in the batch except for the last one.
+    pub previous_chunk_data_hashes: Vec<H256>,
+    /// The encoded block contexts of all blocks in the last chunk of the batch.
+    pub last_chunk_block_contexts: Vec<Bytes>,
+    /// The hashes of the L1 messages included in the last chunk of the batch.
+    pub last_chunk_l1_message_hashes: Vec<H256>,
 }
@@ -29,6 +29,38 @@ pub struct ScrollEip1186ProofResponse {
     pub storage_proof: Vec<()>,
 }
 
+/// The subset of an `eth_getBlockByNumber` response that is committed to in a chunk.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScrollBlock {
+    #[serde(with = "::serde_utils::u64_hex")]
+    pub number: u64,
+    #[serde(with = "::serde_utils::u64_hex")]
+    pub timestamp: u64,
+    #[serde(with = "unionlabs::primitives::uint::u256_big_endian_hex")]
+    pub base_fee_per_gas: U256,
+    #[serde(with = "::serde_utils::u64_hex")]
+    pub gas_limit: u64,
+    pub transactions: Vec<ScrollTransaction>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ScrollTransaction {
+    pub hash: H256,
+    #[serde(rename = "type", with = "::serde_utils::u64_hex")]
+    pub ty: u64,
+}
+
+impl ScrollTransaction {
+    /// The transaction type of L1 messages.
+    pub const L1_MESSAGE_TYPE: u64 = 0x7e;
+
+    #[must_use]
+    pub fn is_l1_message(&self) -> bool {
+        self.ty == Self::L1_MESSAGE_TYPE
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct JsonRpcClient {
     client: Arc<WsClient>,
@@ -54,13 +86,22 @@ impl JsonRpcClient {
                     address,
                     location
                         .into_iter()
-                        .map(|u| (u.to_be_hex_packed()))
+                        .map(|u| u.to_be_hex_packed())
                         .collect::<Vec<_>>(),
                     block.to_string(),
                 ),
             )
             .await
     }
+
+    pub async fn get_block(
+        &self,
+        block: BlockId,
+    ) -> Result<ScrollBlock, jsonrpsee::core::client::Error> {
+        self.client
+            .request("eth_getBlockByNumber", (block.to_string(), true))
+            .await
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -1,8 +1,14 @@
 use core::fmt::Debug;
 
 use evm_storage_verifier::{verify_account_storage_root, verify_storage_proof};
-use scroll_codec::{hash_batch, HashBatchError};
-use scroll_light_client_types::{ClientState, Header};
+use scroll_codec::{
+    batch_header::{BatchHeaderV3, BatchHeaderV3DecodeError},
+    chunk::{
+        compute_batch_data_hash, compute_chunk_data_hash, BlockContext, BlockContextDecodeError,
+    },
+    hash_batch, HashBatchError,
+};
+use scroll_light_client_types::{ClientStateV1, Header, L2HeightProof};
 use scroll_types::account::Account;
 use unionlabs::{
     ethereum::slot::{MappingKey, Slot},
@@ -26,6 +32,22 @@ pub enum Error {
     ValueMismatch,
     #[error(transparent)]
     HashBatch(#[from] HashBatchError),
+    #[error(transparent)]
+    BatchHeaderDecode(#[from] BatchHeaderV3DecodeError),
+    #[error(transparent)]
+    BlockContextDecode(#[from] BlockContextDecodeError),
+    #[error("the last chunk of the batch contains no blocks")]
+    EmptyLastChunk,
+    #[error("batch data hash mismatch, batch header: {batch_header}, proof: {proof}")]
+    DataHashMismatch { batch_header: H256, proof: H256 },
+    #[error("l2 height mismatch, header: {header}, proof: {proof}")]
+    L2HeightMismatch { header: u64, proof: u64 },
+    #[error("l2 timestamp mismatch, batch header: {batch_header}, proof: {proof}")]
+    L2TimestampMismatch { batch_header: u64, proof: u64 },
+    #[error("misbehaviour headers are for different batches ({a} != {b})")]
+    MisbehaviourBatchIndexMismatch { a: U256, b: U256 },
+    #[error("misbehaviour headers commit to the same l2 state root ({0})")]
+    MisbehaviourStateRootsEqual(H256),
 }
 
 // 1. rollupContractOnL1 ∈ L1Stateroot
@@ -33,8 +55,22 @@ pub enum Error {
 // 3. L2stateRoot ≡ rollupContractOnL1.finalized[lastBatchIndex]
 // 4. batchHash ≡ rollupContractOnL1.batchHashes[lastBatchIndex]
 // 5. ibcContractOnL2 ∈ L2StateRoot
+// 6. l2Height ≡ lastBlock(batchHeader.dataHash)
 pub fn verify_header(
-    client_state: &ClientState,
+    client_state: &ClientStateV1,
+    header: &Header,
+    l1_state_root: H256,
+) -> Result<(), Error> {
+    verify_batch(client_state, header, l1_state_root)?;
+    verify_l2_height(header)?;
+
+    Ok(())
+}
+
+/// Verify steps 1-5 of [`verify_header`], i.e. that the batch and the L2 state root in the header
+/// are finalized on the L1, and that the ibc account proof is valid against the L2 state root.
+pub fn verify_batch(
+    client_state: &ClientStateV1,
     header: &Header,
     l1_state_root: H256,
 ) -> Result<(), Error> {
@@ -93,6 +129,98 @@ pub fn verify_header(
     Ok(())
 }
 
+/// Verify that the `l2_height` of the header is the number of the last block in the batch, i.e. the
+/// block that the finalized L2 state root is the state root of.
+///
+/// The block contexts of the last chunk are hashed into the data hash of the last chunk, which is
+/// then hashed together with the data hashes of the previous chunks into the data hash of the
+/// batch. This must match the data hash committed to in the batch header, which has been proven to
+/// be committed to on the L1 in [`verify_batch`].
+pub fn verify_l2_height(header: &Header) -> Result<(), Error> {
+    let L2HeightProof {
+        previous_chunk_data_hashes,
+        last_chunk_block_contexts,
+        last_chunk_l1_message_hashes,
+    } = &header.l2_height_proof;
+
+    let batch_header = BatchHeaderV3::decode(&header.batch_header)?;
+
+    let block_contexts = last_chunk_block_contexts
+        .iter()
+        .map(BlockContext::decode)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let last_block_context = block_contexts.last().ok_or(Error::EmptyLastChunk)?;
+
+    let last_chunk_data_hash =
+        compute_chunk_data_hash(&block_contexts, last_chunk_l1_message_hashes);
+
+    let data_hash = compute_batch_data_hash(
+        previous_chunk_data_hashes
+            .iter()
+            .chain([&last_chunk_data_hash]),
+    );
+
+    if data_hash != batch_header.data_hash {
+        return Err(Error::DataHashMismatch {
+            batch_header: batch_header.data_hash,
+            proof: data_hash,
+        });
+    }
+
+    if last_block_context.timestamp != batch_header.last_block_timestamp {
+        return Err(Error::L2TimestampMismatch {
+            batch_header: batch_header.last_block_timestamp,
+            proof: last_block_context.timestamp,
+        });
+    }
+
+    if last_block_context.block_number != header.l2_height {
+        return Err(Error::L2HeightMismatch {
+            header: header.l2_height,
+            proof: last_block_context.block_number,
+        });
+    }
+
+    Ok(())
+}
+
+/// Verify that the two provided headers are a valid proof of misbehaviour.
+///
+/// Misbehaviour verification consists of 3 steps:
+///
+/// 1. Verify that both headers are for the same finalized batch.
+/// 2. Verify that the L2 state roots of the headers are different.
+/// 3. Verify both headers against their respective L1 state roots, as in [`verify_header`].
+pub fn verify_misbehaviour(
+    client_state: &ClientStateV1,
+    header_a: &Header,
+    l1_state_root_a: H256,
+    header_b: &Header,
+    l1_state_root_b: H256,
+) -> Result<(), Error> {
+    // 1.
+    if header_a.last_batch_index_proof.value != header_b.last_batch_index_proof.value {
+        return Err(Error::MisbehaviourBatchIndexMismatch {
+            a: header_a.last_batch_index_proof.value,
+            b: header_b.last_batch_index_proof.value,
+        });
+    }
+
+    // 2.
+    if header_a.l2_state_root_proof.value == header_b.l2_state_root_proof.value {
+        return Err(Error::MisbehaviourStateRootsEqual(
+            header_a.l2_state_root_proof.value.to_be_bytes().into(),
+        ));
+    }
+
+    // 3.
+    verify_header(client_state, header_a, l1_state_root_a)?;
+    verify_header(client_state, header_b, l1_state_root_b)?;
+
+    Ok(())
+}
+
 /// Storage slot of a `mapping(uint256 => bytes32)` mapping, where the mapping is at slot `slot` and the `uint256` is the `batch_index`.
 pub fn mapping_index_to_slot_key(slot: U256, batch_index: U256) -> U256 {
     Slot::Mapping(&Slot::Offset(slot), MappingKey::Uint256(batch_index)).slot()
@@ -156,78 +284,282 @@ pub fn scroll_verify_zktrie_account_storage_root(
     }
 }
 
-// #[cfg(test)]
-// mod tests {
-//     use hex_literal::hex;
-//     use scroll_light_client_types::{ClientState, Header};
-//     use unionlabs::{
-//         primitives::{H160, H256},
-//         ibc::{core::client::height::Height, lightclients::ethereum::storage_proof::StorageProof},
-//     };
-
-//     use crate::{verify_header, verify_zktrie_storage_absence, verify_zktrie_storage_proof};
-
-//     #[test]
-//     fn test_update_header() {
-//         let scroll_client_state = ClientState {
-//             l1_client_id: "cometbls-1".to_string(),
-//             chain_id: 534351.into(),
-//             latest_slot: 65327,
-//             latest_batch_index_slot: 156.into(),
-//             frozen_height: Height {
-//                 revision_number: 0,
-//                 revision_height: 0,
-//             },
-//             l2_contract_address: H160::new(hex!("2d567ece699eabe5afcd141edb7a4f2d0d6ce8a0")),
-//             l2_finalized_state_roots_slot: 158.into(),
-//             l2_committed_batches_slot: 157.into(),
-//             // Dummy contract address for the sake of testing
-//             ibc_contract_address: H160::new(hex!("0000000000000000000000000000000000000000")),
-//         };
-//         let scroll_header: Header =
-//             serde_json::from_str(&std::fs::read_to_string("tests/scroll_header.json").unwrap())
-//                 .unwrap();
-//         let l1_state_root = H256::new(hex!(
-//             "40ab3b90af84c30c31eb0fe9fc8cc5260b59f619d770706750ea3e474ca47c59"
-//         ));
-//         assert_eq!(
-//             verify_header(scroll_client_state, scroll_header, l1_state_root),
-//             Ok(())
-//         );
-//     }
-
-//     #[test]
-//     fn test_l2_contract_slot_exist() {
-//         let proof: StorageProof =
-//             serde_json::from_str(&std::fs::read_to_string("tests/scroll_proof.json").unwrap())
-//                 .unwrap();
-//         assert_eq!(
-//             verify_zktrie_storage_proof(
-//                 H256::new(hex!(
-//                     "1b52888cae05bdba27f8470293a7d2bc3b9a9c822d96affe05ef243e0dfd44a0"
-//                 )),
-//                 proof.key.to_be_bytes().into(),
-//                 &proof.value.to_be_bytes(),
-//                 &proof.proof
-//             ),
-//             Ok(())
-//         )
-//     }
-
-//     #[test]
-//     fn test_l2_contract_slot_absent() {
-//         let proof: StorageProof =
-//             serde_json::from_str(&std::fs::read_to_string("tests/scroll_absent.json").unwrap())
-//                 .unwrap();
-//         assert_eq!(
-//             verify_zktrie_storage_absence(
-//                 H256::new(hex!(
-//                     "1b52888cae05bdba27f8470293a7d2bc3b9a9c822d96affe05ef243e0dfd44a0"
-//                 )),
-//                 proof.key.to_be_bytes().into(),
-//                 &proof.proof
-//             ),
-//             Ok(())
-//         )
-//     }
-// }
+#[cfg(test)]
+mod tests {
+    use ethereum_light_client_types::StorageProof;
+    use hex_literal::hex;
+    use ibc_union_spec::ClientId;
+    use scroll_light_client_types::{ClientStateV1, Header, L2HeightProof};
+    use unionlabs::primitives::{H160, H256};
+
+    use super::*;
+
+    const L1_STATE_ROOT: H256 = H256::new(hex!(
+        "40ab3b90af84c30c31eb0fe9fc8cc5260b59f619d770706750ea3e474ca47c59"
+    ));
+
+    const L2_STATE_ROOT: H256 = H256::new(hex!(
+        "1b52888cae05bdba27f8470293a7d2bc3b9a9c822d96affe05ef243e0dfd44a0"
+    ));
+
+    fn client_state() -> ClientStateV1 {
+        ClientStateV1 {
+            chain_id: 534351_u64.into(),
+            latest_height: 0,
+            l1_client_id: ClientId!(1),
+            l2_contract_address: H160::new(hex!("2d567ece699eabe5afcd141edb7a4f2d0d6ce8a0")),
+            latest_batch_index_slot: 156_u64.into(),
+            l2_finalized_state_roots_slot: 158_u64.into(),
+            l2_committed_batches_slot: 157_u64.into(),
+            frozen_height: 0,
+            // Dummy contract address for the sake of testing
+            ibc_contract_address: H160::new(hex!("0000000000000000000000000000000000000000")),
+        }
+    }
+
+    fn header() -> Header {
+        let mut header = serde_json::from_str::<serde_json::Value>(
+            &std::fs::read_to_string("tests/scroll_header.json").unwrap(),
+        )
+        .unwrap();
+
+        // the fixture was recorded before the l2 height was part of the header, so it only
+        // passes verify_batch
+        let l1_height = header["l1_height"]
+            .as_str()
+            .unwrap()
+            .parse::<u64>()
+            .unwrap();
+        header["l1_height"] = l1_height.into();
+        header["l2_height"] = 0.into();
+        header["l2_height_proof"] = serde_json::json!({
+            "previous_chunk_data_hashes": [],
+            "last_chunk_block_contexts": [],
+            "last_chunk_l1_message_hashes": [],
+        });
+
+        serde_json::from_value(header).unwrap()
+    }
+
+    fn block_context(block_number: u64, timestamp: u64) -> BlockContext {
+        BlockContext {
+            block_number,
+            timestamp,
+            base_fee: U256::from(1_u64),
+            gas_limit: 10_000_000,
+            num_transactions: 1,
+        }
+    }
+
+    /// A header for a batch with two chunks, the last of which contains blocks 100 and 101.
+    fn header_with_l2_height_proof() -> Header {
+        let block_contexts = [block_context(100, 1_000), block_context(101, 1_003)];
+        let l1_message_hashes = vec![H256::new([0xAA; 32])];
+        let previous_chunk_data_hashes = vec![H256::new([0xBB; 32])];
+
+        let data_hash = compute_batch_data_hash(previous_chunk_data_hashes.iter().chain([
+            &compute_chunk_data_hash(&block_contexts, &l1_message_hashes),
+        ]));
+
+        let mut header = header();
+
+        let mut batch_header = header.batch_header.to_vec();
+        batch_header[25..57].copy_from_slice(data_hash.get());
+        batch_header[121..129].copy_from_slice(&1_003_u64.to_be_bytes());
+        header.batch_header = batch_header.into();
+
+        header.l2_height = 101;
+        header.l2_height_proof = L2HeightProof {
+            previous_chunk_data_hashes,
+            last_chunk_block_contexts: block_contexts
+                .iter()
+                .map(|block_context| block_context.encode().to_vec().into())
+                .collect(),
+            last_chunk_l1_message_hashes: l1_message_hashes,
+        };
+
+        header
+    }
+
+    #[test]
+    fn test_verify_batch() {
+        assert_eq!(
+            verify_batch(&client_state(), &header(), L1_STATE_ROOT),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_update_header_missing_l2_height_proof() {
+        assert_eq!(
+            verify_header(&client_state(), &header(), L1_STATE_ROOT),
+            Err(Error::EmptyLastChunk)
+        );
+    }
+
+    #[test]
+    fn test_verify_l2_height() {
+        assert_eq!(verify_l2_height(&header_with_l2_height_proof()), Ok(()));
+    }
+
+    #[test]
+    fn test_verify_l2_height_mismatch() {
+        let mut header = header_with_l2_height_proof();
+        header.l2_height = 100;
+
+        assert_eq!(
+            verify_l2_height(&header),
+            Err(Error::L2HeightMismatch {
+                header: 100,
+                proof: 101
+            })
+        );
+    }
+
+    #[test]
+    fn test_verify_l2_height_not_last_block() {
+        let mut header = header_with_l2_height_proof();
+        header.l2_height = 100;
+        header.l2_height_proof.last_chunk_block_contexts.pop();
+
+        assert!(matches!(
+            verify_l2_height(&header),
+            Err(Error::DataHashMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_verify_l2_height_invalid_previous_chunks() {
+        let mut header = header_with_l2_height_proof();
+        header.l2_height_proof.previous_chunk_data_hashes = vec![];
+
+        assert!(matches!(
+            verify_l2_height(&header),
+            Err(Error::DataHashMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_verify_l2_height_timestamp_mismatch() {
+        let mut header = header_with_l2_height_proof();
+        let mut batch_header = header.batch_header.to_vec();
+        batch_header[121..129].copy_from_slice(&1_004_u64.to_be_bytes());
+        header.batch_header = batch_header.into();
+
+        assert_eq!(
+            verify_l2_height(&header),
+            Err(Error::L2TimestampMismatch {
+                batch_header: 1_004,
+                proof: 1_003
+            })
+        );
+    }
+
+    #[test]
+    fn test_update_header_invalid_l1_state_root() {
+        assert!(matches!(
+            verify_header(&client_state(), &header(), H256::default()),
+            Err(Error::InvalidL1AccountProof(_))
+        ));
+    }
+
+    #[test]
+    fn test_update_header_invalid_batch_header() {
+        let mut header = header();
+        let mut batch_header = header.batch_header.to_vec();
+        *batch_header.last_mut().unwrap() ^= 1;
+        header.batch_header = batch_header.into();
+
+        assert!(matches!(
+            verify_header(&client_state(), &header, L1_STATE_ROOT),
+            Err(Error::InvalidBatchHashProof(_))
+        ));
+    }
+
+    #[test]
+    fn test_misbehaviour_same_state_root() {
+        let header = header();
+
+        assert_eq!(
+            verify_misbehaviour(
+                &client_state(),
+                &header,
+                L1_STATE_ROOT,
+                &header,
+                L1_STATE_ROOT
+            ),
+            Err(Error::MisbehaviourStateRootsEqual(
+                header.l2_state_root_proof.value.to_be_bytes().into()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_misbehaviour_different_batches() {
+        let header_a = header();
+        let mut header_b = header_a.clone();
+        header_b.last_batch_index_proof.value += U256::from(1_u64);
+
+        assert!(matches!(
+            verify_misbehaviour(
+                &client_state(),
+                &header_a,
+                L1_STATE_ROOT,
+                &header_b,
+                L1_STATE_ROOT
+            ),
+            Err(Error::MisbehaviourBatchIndexMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_misbehaviour_invalid_conflicting_header() {
+        let header_b = header();
+        let mut header_a = header_b.clone();
+        header_a.l2_state_root_proof.value = U256::from(1_u64);
+
+        assert!(matches!(
+            verify_misbehaviour(
+                &client_state(),
+                &header_a,
+                L1_STATE_ROOT,
+                &header_b,
+                L1_STATE_ROOT
+            ),
+            Err(Error::InvalidL2FinalizedStateRootProof(_))
+        ));
+    }
+
+    #[test]
+    fn test_l2_contract_slot_exist() {
+        let proof: StorageProof =
+            serde_json::from_str(&std::fs::read_to_string("tests/scroll_proof.json").unwrap())
+                .unwrap();
+
+        assert_eq!(
+            verify_zktrie_storage_proof(
+                L2_STATE_ROOT,
+                proof.key.to_be_bytes().into(),
+                &proof.value.to_be_bytes(),
+                &proof.proof
+            ),
+            Ok(())
+        )
+    }
+
+    #[test]
+    fn test_l2_contract_slot_absent() {
+        let proof: StorageProof =
+            serde_json::from_str(&std::fs::read_to_string("tests/scroll_absent.json").unwrap())
+                .unwrap();
+
+        assert_eq!(
+            verify_zktrie_storage_absence(
+                L2_STATE_ROOT,
+                proof.key.to_be_bytes().into(),
+                &proof.proof
+            ),
+            Ok(())
+        )
+    }
+}
@@ -7,6 +7,8 @@
 //!
 //! - `#[ssz(union)]`: encodes and decodes an `enum` with a one-byte variant selector.
 //! - `#[ssz(transparent)]`: encodes and decodes a `struct` with exactly one field as if the outermost `struct` does not exist.
+//! - `#[ssz(stable_container(N))]`: encodes and decodes a `struct` as an [EIP-7495](https://eips.ethereum.org/EIPS/eip-7495) `StableContainer[N]`. All fields must be `Option`s.
+//! - `#[ssz(profile(B))]`: encodes and decodes a `struct` as a `Profile[B]`, where `B` is a `#[ssz(stable_container(N))]` `struct`. Fields must be in the same order as in `B`, and are optional if they are `Option`s.
 //!
 //! The following field attributes are available:
 //!
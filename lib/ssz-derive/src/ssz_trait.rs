@@ -1,8 +1,8 @@
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
 use syn::{
-    parse::ParseStream, spanned::Spanned, Attribute, Data, DataEnum, DataStruct, DeriveInput,
-    Fields,
+    ext::IdentExt, parse::ParseStream, spanned::Spanned, Attribute, Data, DataEnum, DataStruct,
+    DeriveInput, Fields, GenericArgument, Ident, LitInt, LitStr, Path, PathArguments, Type,
 };
 
 use crate::MAX_UNION_SELECTOR;
@@ -32,6 +32,12 @@ pub fn do_ssz(derive_input: DeriveInput) -> Result<TokenStream, syn::Error> {
         (Data::Struct(s), None) => container(&derive_input, s),
         // wrapper
         (Data::Struct(s), Some(SszMeta::Transparent(_))) => wrapper(&derive_input, s),
+        // stable container
+        (Data::Struct(s), Some(SszMeta::StableContainer(_, max_fields))) => {
+            stable_container(&derive_input, s, &max_fields)
+        }
+        // profile
+        (Data::Struct(s), Some(SszMeta::Profile(_, base))) => profile(&derive_input, s, &base),
         (Data::Struct(_), Some(SszMeta::Union(span))) => Err(syn::Error::new(
             span,
             "`#[ssz(union)]` is only valid on enums",
@@ -46,6 +52,12 @@ pub fn do_ssz(derive_input: DeriveInput) -> Result<TokenStream, syn::Error> {
             "`#[ssz(transparent)]` is not supported on enums due to ambiguities in decoding",
         )),
         (Data::Enum(e), Some(SszMeta::Union(_))) => enum_union(&derive_input, e),
+        (Data::Enum(_), Some(SszMeta::StableContainer(span, _) | SszMeta::Profile(span, _))) => {
+            Err(syn::Error::new(
+                span,
+                "`#[ssz(stable_container(...))]` and `#[ssz(profile(...))]` are only valid on structs",
+            ))
+        }
         (Data::Union(u), _) => Err(syn::Error::new(
             u.union_token.span(),
             "unions are not supported",
@@ -56,6 +68,10 @@ pub fn do_ssz(derive_input: DeriveInput) -> Result<TokenStream, syn::Error> {
 enum SszMeta {
     Transparent(Span),
     Union(Span),
+    /// `#[ssz(stable_container(N))]`
+    StableContainer(Span, LitInt),
+    /// `#[ssz(profile(Base))]`
+    Profile(Span, Path),
 }
 
 impl SszMeta {
@@ -63,12 +79,16 @@ impl SszMeta {
         match self {
             SszMeta::Transparent(span) => *span,
             SszMeta::Union(span) => *span,
+            SszMeta::StableContainer(span, _) => *span,
+            SszMeta::Profile(span, _) => *span,
         }
     }
 
     fn try_from_attribute(attr: &Attribute) -> syn::Result<Option<Self>> {
         syn::custom_keyword!(transparent);
         syn::custom_keyword!(union);
+        syn::custom_keyword!(stable_container);
+        syn::custom_keyword!(profile);
 
         let mut ssz_meta = None;
 
@@ -100,6 +120,18 @@ impl SszMeta {
                 union => Union;
             );
 
+            if let Some(kw) = input.parse::<Option<stable_container>>()? {
+                let content;
+                syn::parenthesized!(content in input);
+
+                ssz_meta = Some(SszMeta::StableContainer(kw.span, content.parse()?));
+            } else if let Some(kw) = input.parse::<Option<profile>>()? {
+                let content;
+                syn::parenthesized!(content in input);
+
+                ssz_meta = Some(SszMeta::Profile(kw.span, content.parse()?));
+            }
+
             if !input.is_empty() {
                 return Err(input.error("unexpected extra tokens"));
             }
@@ -143,6 +175,12 @@ fn container(
 
     let num_leaves = ident.len();
 
+    let field_index = (0..num_leaves).collect::<Vec<_>>();
+    let field_name = ident
+        .iter()
+        .map(|ident| LitStr::new(&ident.unraw().to_string(), ident.span()))
+        .collect::<Vec<_>>();
+
     let output = quote! {
         impl #impl_generics ::ssz::Ssz for #name #ty_generics #where_clause {
             const SSZ_FIXED_LEN: Option<::core::num::NonZeroUsize> = '___SSZ_FIXED_LEN: {
@@ -173,6 +211,47 @@ fn container(
                 hasher.finish().expect("tree hash derive should not have a remaining buffer")
             }
 
+            fn generalized_index(
+                path: &[::ssz::merkle_proof::PathElement<'_>],
+            ) -> ::core::result::Result<::ssz::merkle_proof::GeneralizedIndex, ::ssz::merkle_proof::ProofError> {
+                match path {
+                    [] => ::core::result::Result::Ok(1),
+                    [::ssz::merkle_proof::PathElement::Field(field), rest @ ..] => {
+                        let (index, gindex) = match *field {
+                            #(
+                                #field_name => (#field_index, <#ty as ::ssz::Ssz>::generalized_index(rest)?),
+                            )*
+                            _ => return ::core::result::Result::Err(::ssz::merkle_proof::ProofError::UnknownField((*field).to_owned())),
+                        };
+
+                        ::ssz::merkle_proof::concat_generalized_indices(&[
+                            ::ssz::merkle_proof::leaf_generalized_index(#num_leaves, index)?,
+                            gindex,
+                        ])
+                    }
+                    [element, ..] => ::core::result::Result::Err(::ssz::merkle_proof::ProofError::InvalidPathElement(
+                        ::std::format!("{element:?}"),
+                    )),
+                }
+            }
+
+            fn tree_hash_node(
+                &self,
+                gindex: ::ssz::merkle_proof::GeneralizedIndex,
+            ) -> ::core::result::Result<::ssz::H256, ::ssz::merkle_proof::ProofError> {
+                ::ssz::merkle_proof::chunk_tree_node(
+                    &[#(::ssz::Ssz::tree_hash_root(&self.#ident),)*],
+                    #num_leaves,
+                    gindex,
+                    |index, gindex| match index {
+                        #(
+                            #field_index => ::ssz::Ssz::tree_hash_node(&self.#ident, gindex),
+                        )*
+                        _ => unreachable!("only existing leaves are descended into; qed;"),
+                    },
+                )
+            }
+
             fn ssz_bytes_len(&self) -> ::core::num::NonZeroUsize {
                 match <Self as ::ssz::Ssz>::SSZ_FIXED_LEN {
                     Some(len) => len,
@@ -286,6 +365,439 @@ fn container(
     Ok(output)
 }
 
+/// A field of a stable container or a profile.
+struct StableField<'a> {
+    ident: &'a Ident,
+    /// The type of the field, with the `Option` stripped if the field is optional.
+    ty: &'a Type,
+    /// The index of the field in the active fields bitvector, if the field is optional.
+    active_index: Option<usize>,
+}
+
+fn stable_fields(struct_data: &DataStruct) -> Result<Vec<StableField<'_>>, syn::Error> {
+    let Fields::Named(..) = &struct_data.fields else {
+        return Err(syn::Error::new(
+            struct_data.fields.span(),
+            "stable containers and profiles only support named fields",
+        ));
+    };
+
+    if struct_data.fields.is_empty() {
+        return Err(syn::Error::new(
+            struct_data.fields.span(),
+            "stable containers and profiles require at least one field",
+        ));
+    }
+
+    let mut active_fields = 0;
+
+    Ok(struct_data
+        .fields
+        .iter()
+        .map(|field| {
+            let ident = field
+                .ident
+                .as_ref()
+                .expect("fields checked above to be named; qed;");
+
+            match option_inner(&field.ty) {
+                Some(ty) => {
+                    active_fields += 1;
+
+                    StableField {
+                        ident,
+                        ty,
+                        active_index: Some(active_fields - 1),
+                    }
+                }
+                None => StableField {
+                    ident,
+                    ty: &field.ty,
+                    active_index: None,
+                },
+            }
+        })
+        .collect())
+}
+
+/// Returns `T` if `ty` is `Option<T>`.
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+
+    let segment = path.path.segments.last()?;
+
+    if segment.ident != "Option" {
+        return None;
+    }
+
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+
+    let mut args = args.args.iter();
+
+    match (args.next(), args.next()) {
+        (Some(GenericArgument::Type(ty)), None) => Some(ty),
+        _ => None,
+    }
+}
+
+fn stable_container(
+    derive_input: &DeriveInput,
+    struct_data: &DataStruct,
+    max_fields: &LitInt,
+) -> Result<TokenStream, syn::Error> {
+    let name = &derive_input.ident;
+    let (impl_generics, ty_generics, where_clause) = &derive_input.generics.split_for_impl();
+
+    let fields = stable_fields(struct_data)?;
+
+    if let Some(field) = fields.iter().find(|field| field.active_index.is_none()) {
+        return Err(syn::Error::new(
+            field.ident.span(),
+            "all fields of a stable container must be `Option`s",
+        ));
+    }
+
+    let max_fields_value = max_fields.base10_parse::<usize>()?;
+
+    if fields.len() > max_fields_value {
+        return Err(syn::Error::new(
+            max_fields.span(),
+            format!(
+                "stable container has {} fields, but a capacity of {max_fields_value}",
+                fields.len()
+            ),
+        ));
+    }
+
+    let field_count = fields.len();
+    let field_name = fields
+        .iter()
+        .map(|field| LitStr::new(&field.ident.unraw().to_string(), field.ident.span()))
+        .collect::<Vec<_>>();
+    let base_index = (0..field_count)
+        .map(|index| quote!(#index))
+        .collect::<Vec<_>>();
+
+    let ssz_impl = stable_ssz_impl(
+        derive_input,
+        &fields,
+        &quote!(#max_fields_value),
+        &base_index,
+        Some((quote!(#max_fields_value), field_count)),
+        &quote!(None),
+    );
+
+    Ok(quote! {
+        #ssz_impl
+
+        impl #impl_generics ::ssz::types::stable_container::StableContainer for #name #ty_generics #where_clause {
+            const MAX_FIELDS: usize = #max_fields_value;
+
+            const FIELDS: &'static [&'static str] = &[#(#field_name,)*];
+        }
+    })
+}
+
+fn profile(
+    derive_input: &DeriveInput,
+    struct_data: &DataStruct,
+    base: &Path,
+) -> Result<TokenStream, syn::Error> {
+    let name = &derive_input.ident;
+    let (impl_generics, ty_generics, where_clause) = &derive_input.generics.split_for_impl();
+
+    let fields = stable_fields(struct_data)?;
+
+    let field_count = fields.len();
+    let field_name = fields
+        .iter()
+        .map(|field| LitStr::new(&field.ident.unraw().to_string(), field.ident.span()))
+        .collect::<Vec<_>>();
+    let base_index = (0..field_count)
+        .map(
+            |index| quote!(<Self as ::ssz::types::stable_container::Profile>::BASE_INDICES[#index]),
+        )
+        .collect::<Vec<_>>();
+
+    let optional_fields = fields
+        .iter()
+        .filter(|field| field.active_index.is_some())
+        .count();
+
+    // a profile without optional fields has no active fields bitvector, and is fixed length if all
+    // of its fields are
+    let (active_fields, ssz_fixed_len) = if optional_fields == 0 {
+        let ty = fields.iter().map(|field| field.ty);
+
+        (
+            None,
+            quote!('___SSZ_FIXED_LEN: {
+                let mut total = 0;
+
+                #(
+                    match <#ty as ::ssz::Ssz>::SSZ_FIXED_LEN {
+                        Some(v) => {
+                            total += v.get();
+                        }
+                        None => break '___SSZ_FIXED_LEN None,
+                    }
+                )*
+
+                ::core::num::NonZeroUsize::new(total)
+            }),
+        )
+    } else {
+        (
+            Some((quote!(#optional_fields), optional_fields)),
+            quote!(None),
+        )
+    };
+
+    let ssz_impl = stable_ssz_impl(
+        derive_input,
+        &fields,
+        &quote!(<#base as ::ssz::types::stable_container::StableContainer>::MAX_FIELDS),
+        &base_index,
+        active_fields,
+        &ssz_fixed_len,
+    );
+
+    Ok(quote! {
+        #ssz_impl
+
+        impl #impl_generics ::ssz::types::stable_container::Profile for #name #ty_generics #where_clause {
+            type Base = #base;
+
+            const BASE_INDICES: &'static [usize] = &::ssz::types::stable_container::base_indices::<#base, #field_count>([
+                #(#field_name,)*
+            ]);
+        }
+    })
+}
+
+/// The `Ssz` implementation shared by stable containers and profiles.
+///
+/// `active_fields` is the length of the active fields bitvector and the number of fields it covers,
+/// if the type has one.
+fn stable_ssz_impl(
+    derive_input: &DeriveInput,
+    fields: &[StableField<'_>],
+    max_fields: &TokenStream,
+    base_index: &[TokenStream],
+    active_fields: Option<(TokenStream, usize)>,
+    ssz_fixed_len: &TokenStream,
+) -> TokenStream {
+    let name = &derive_input.ident;
+    let (impl_generics, ty_generics, where_clause) = &derive_input.generics.split_for_impl();
+
+    let ident = fields.iter().map(|field| field.ident).collect::<Vec<_>>();
+    let ty = fields.iter().map(|field| field.ty).collect::<Vec<_>>();
+    let field_name = fields
+        .iter()
+        .map(|field| LitStr::new(&field.ident.unraw().to_string(), field.ident.span()))
+        .collect::<Vec<_>>();
+
+    // the field as an `Option<&T>`, such that optional and required fields can be treated the same
+    let as_option = fields
+        .iter()
+        .map(
+            |StableField {
+                 ident,
+                 active_index,
+                 ..
+             }| match active_index {
+                Some(_) => quote!(self.#ident.as_ref()),
+                None => quote!(::core::option::Option::Some(&self.#ident)),
+            },
+        )
+        .collect::<Vec<_>>();
+
+    let is_active = fields
+        .iter()
+        .map(|field| match field.active_index {
+            Some(index) => quote!(active_fields[#index]),
+            None => quote!(true),
+        })
+        .collect::<Vec<_>>();
+
+    let decode_field = fields
+        .iter()
+        .map(
+            |StableField {
+                 ty, active_index, ..
+             }| match active_index {
+                Some(index) => quote! {
+                    if active_fields[#index] {
+                        ::core::option::Option::Some(decoder.decode_next::<#ty>()?)
+                    } else {
+                        ::core::option::Option::None
+                    }
+                },
+                None => quote!(decoder.decode_next::<#ty>()?),
+            },
+        )
+        .collect::<Vec<_>>();
+
+    let (active_fields_len, append_active_fields, split_active_fields) = match active_fields {
+        Some((len, field_count)) => {
+            let optional_ident = fields
+                .iter()
+                .filter(|field| field.active_index.is_some())
+                .map(|field| field.ident);
+
+            (
+                quote!(::ssz::types::stable_container::active_fields_len(#len)),
+                quote! {
+                    buf.extend_from_slice(&::ssz::types::stable_container::active_fields_bytes(
+                        #len,
+                        [#(self.#optional_ident.is_some(),)*],
+                    ));
+                },
+                quote! {
+                    let (active_fields, bytes) = ::ssz::types::stable_container::split_active_fields(
+                        bytes,
+                        #len,
+                        #field_count,
+                    )?;
+                },
+            )
+        }
+        None => (quote!(0), quote!(), quote!()),
+    };
+
+    quote! {
+        impl #impl_generics ::ssz::Ssz for #name #ty_generics #where_clause {
+            const SSZ_FIXED_LEN: Option<::core::num::NonZeroUsize> = #ssz_fixed_len;
+
+            const TREE_HASH_TYPE: ::ssz::tree_hash::TreeHashType = ::ssz::tree_hash::TreeHashType::Container;
+
+            fn tree_hash_root(&self) -> ::ssz::H256 {
+                ::ssz::types::stable_container::tree_hash_root(
+                    #max_fields,
+                    [#((#base_index, #as_option.map(::ssz::Ssz::tree_hash_root)),)*],
+                )
+            }
+
+            fn generalized_index(
+                path: &[::ssz::merkle_proof::PathElement<'_>],
+            ) -> ::core::result::Result<::ssz::merkle_proof::GeneralizedIndex, ::ssz::merkle_proof::ProofError> {
+                match path {
+                    [] => ::core::result::Result::Ok(1),
+                    [::ssz::merkle_proof::PathElement::Field(field), rest @ ..] => {
+                        let (index, gindex) = match *field {
+                            #(
+                                #field_name => (#base_index, <#ty as ::ssz::Ssz>::generalized_index(rest)?),
+                            )*
+                            _ => return ::core::result::Result::Err(::ssz::merkle_proof::ProofError::UnknownField((*field).to_owned())),
+                        };
+
+                        ::ssz::types::stable_container::generalized_index(#max_fields, index, gindex)
+                    }
+                    [element, ..] => ::core::result::Result::Err(::ssz::merkle_proof::ProofError::InvalidPathElement(
+                        ::std::format!("{element:?}"),
+                    )),
+                }
+            }
+
+            fn tree_hash_node(
+                &self,
+                gindex: ::ssz::merkle_proof::GeneralizedIndex,
+            ) -> ::core::result::Result<::ssz::H256, ::ssz::merkle_proof::ProofError> {
+                ::ssz::types::stable_container::tree_hash_node(
+                    #max_fields,
+                    &[#((#base_index, #as_option.map(::ssz::Ssz::tree_hash_root)),)*],
+                    gindex,
+                    |index, field_gindex| {
+                        #(
+                            if index == #base_index {
+                                // inactive fields are zero leaves, which can't be descended into
+                                return match #as_option {
+                                    ::core::option::Option::Some(field) => ::ssz::Ssz::tree_hash_node(field, field_gindex),
+                                    ::core::option::Option::None => ::core::result::Result::Err(
+                                        ::ssz::merkle_proof::ProofError::InvalidGeneralizedIndex(gindex),
+                                    ),
+                                };
+                            }
+                        )*
+
+                        ::core::result::Result::Err(::ssz::merkle_proof::ProofError::InvalidGeneralizedIndex(gindex))
+                    },
+                )
+            }
+
+            fn ssz_bytes_len(&self) -> ::core::num::NonZeroUsize {
+                let mut len: usize = #active_fields_len;
+
+                #(
+                    if let ::core::option::Option::Some(field) = #as_option {
+                        len = len
+                            .checked_add(match <#ty as ::ssz::Ssz>::SSZ_FIXED_LEN {
+                                Some(fixed_len) => fixed_len.get(),
+                                None => ::ssz::BYTES_PER_LENGTH_OFFSET
+                                    .checked_add(::ssz::Ssz::ssz_bytes_len(field).get())
+                                    .expect("encode ssz_bytes_len length overflow for bytes"),
+                            })
+                            .expect("encode ssz_bytes_len length overflow");
+                    }
+                )*
+
+                ::core::num::NonZeroUsize::new(len).expect("sum of non-zero numbers is non-zero; qed;")
+            }
+
+            fn ssz_append(&self, buf: &mut Vec<u8>) {
+                #append_active_fields
+
+                let mut offset: usize = 0;
+                #(
+                    if #as_option.is_some() {
+                        offset = offset
+                            .checked_add(<#ty as ::ssz::Ssz>::SSZ_FIXED_LEN.map(|x| x.get()).unwrap_or(::ssz::BYTES_PER_LENGTH_OFFSET))
+                            .expect("encode ssz_append offset overflow");
+                    }
+                )*
+
+                let mut encoder = ::ssz::encode::SszEncoder::container(buf, offset);
+
+                #(
+                    if let ::core::option::Option::Some(field) = #as_option {
+                        encoder.append(field);
+                    }
+                )*
+
+                encoder.finalize();
+            }
+
+            fn from_ssz_bytes(bytes: &[u8]) -> std::result::Result<Self, ::ssz::decode::DecodeError> {
+                #split_active_fields
+
+                let mut builder = ::ssz::decode::SszDecoderBuilder::new(bytes);
+
+                #(
+                    if #is_active {
+                        builder.register_type::<#ty>()?;
+                    }
+                )*
+
+                let mut decoder = builder.build()?;
+
+                #(
+                    let #ident = #decode_field;
+                )*
+
+                Ok(Self {
+                    #(
+                        #ident,
+                    )*
+                })
+            }
+        }
+    }
+}
+
 fn wrapper(item: &DeriveInput, struct_data: &DataStruct) -> Result<TokenStream, syn::Error> {
     let name = &item.ident;
     let (impl_generics, ty_generics, where_clause) = &item.generics.split_for_impl();
@@ -340,6 +852,19 @@ fn wrapper(item: &DeriveInput, struct_data: &DataStruct) -> Result<TokenStream,
                 self.#ident.tree_hash_root()
             }
 
+            fn generalized_index(
+                path: &[::ssz::merkle_proof::PathElement<'_>],
+            ) -> ::core::result::Result<::ssz::merkle_proof::GeneralizedIndex, ::ssz::merkle_proof::ProofError> {
+                <#ty as ::ssz::Ssz>::generalized_index(path)
+            }
+
+            fn tree_hash_node(
+                &self,
+                gindex: ::ssz::merkle_proof::GeneralizedIndex,
+            ) -> ::core::result::Result<::ssz::H256, ::ssz::merkle_proof::ProofError> {
+                ::ssz::Ssz::tree_hash_node(&self.#ident, gindex)
+            }
+
             fn ssz_bytes_len(&self) -> ::core::num::NonZeroUsize {
                 self.#ident.ssz_bytes_len()
             }
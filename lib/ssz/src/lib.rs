@@ -33,6 +33,7 @@
 
 pub mod decode;
 pub mod encode;
+pub mod merkle_proof;
 mod union_selector;
 
 pub mod tree_hash;
@@ -43,7 +44,12 @@ extern crate ssz_derive;
 pub use ssz_derive::*;
 pub use unionlabs_primitives::H256;
 
-use crate::{decode::DecodeError, tree_hash::TreeHashType, types::tree_hash::vec_tree_hash_root};
+use crate::{
+    decode::DecodeError,
+    merkle_proof::{GeneralizedIndex, PathElement, ProofError},
+    tree_hash::TreeHashType,
+    types::tree_hash::{vec_generalized_index, vec_tree_hash_node, vec_tree_hash_root},
+};
 
 pub mod types;
 
@@ -70,6 +76,27 @@ pub trait Ssz: Sized {
 
     fn tree_hash_root(&self) -> H256;
 
+    /// Returns the generalized index of the node at `path` in the Merkle tree of this type.
+    ///
+    /// The default implementation only supports the empty path, which is the root (`1`).
+    fn generalized_index(path: &[PathElement<'_>]) -> Result<GeneralizedIndex, ProofError> {
+        match path {
+            [] => Ok(1),
+            [element, ..] => Err(ProofError::InvalidPathElement(format!("{element:?}"))),
+        }
+    }
+
+    /// Returns the node at `gindex` in the Merkle tree of `self`, where `1` is
+    /// [`Self::tree_hash_root`].
+    ///
+    /// The default implementation only supports the root.
+    fn tree_hash_node(&self, gindex: GeneralizedIndex) -> Result<H256, ProofError> {
+        match gindex {
+            1 => Ok(self.tree_hash_root()),
+            _ => Err(ProofError::InvalidGeneralizedIndex(gindex)),
+        }
+    }
+
     /// Append the encoding `self` to `buf`.
     ///
     /// Note, variable length objects need only to append their "variable length" portion, they do
@@ -239,6 +266,14 @@ where
         vec_tree_hash_root::<T, U<N>>(self)
     }
 
+    fn generalized_index(path: &[PathElement<'_>]) -> Result<GeneralizedIndex, ProofError> {
+        vec_generalized_index::<T, U<N>>(path)
+    }
+
+    fn tree_hash_node(&self, gindex: GeneralizedIndex) -> Result<H256, ProofError> {
+        vec_tree_hash_node::<T, U<N>>(self, gindex)
+    }
+
     fn ssz_append(&self, buf: &mut Vec<u8>) {
         sequence_ssz_append::<_, T>(self, buf);
     }
@@ -452,6 +487,14 @@ where
         <[u8; BYTES] as Ssz>::tree_hash_root(self.get())
     }
 
+    fn generalized_index(path: &[PathElement<'_>]) -> Result<GeneralizedIndex, ProofError> {
+        <[u8; BYTES] as Ssz>::generalized_index(path)
+    }
+
+    fn tree_hash_node(&self, gindex: GeneralizedIndex) -> Result<H256, ProofError> {
+        <[u8; BYTES] as Ssz>::tree_hash_node(self.get(), gindex)
+    }
+
     fn ssz_append(&self, buf: &mut Vec<u8>) {
         <[u8; BYTES] as Ssz>::ssz_append(self.get(), buf);
     }
@@ -0,0 +1,418 @@
+//! Generalized indices and Merkle proofs over the tree hash of SSZ values.
+//!
+//! Adheres to the [SSZ Merkle proofs
+//! specification](https://github.com/ethereum/consensus-specs/blob/dev/ssz/merkle-proofs.md).
+//!
+//! ## Example
+//!
+//! ```rust
+//! use ssz::{
+//!     merkle_proof::{merkle_proof, verify_merkle_proof, PathElement},
+//!     types::{typenum::U8, List},
+//!     Ssz,
+//! };
+//!
+//! #[derive(Ssz)]
+//! struct Foo {
+//!     a: u64,
+//!     b: List<u64, U8>,
+//! }
+//!
+//! let foo = Foo {
+//!     a: 42,
+//!     b: vec![1, 3, 3, 7].try_into().unwrap(),
+//! };
+//!
+//! let gindex = Foo::generalized_index(&[PathElement::Field("b"), PathElement::Length]).unwrap();
+//! let proof = merkle_proof(&foo, gindex).unwrap();
+//!
+//! assert!(verify_merkle_proof(
+//!     &4_u64.tree_hash_root(),
+//!     &proof,
+//!     gindex,
+//!     &foo.tree_hash_root(),
+//! ));
+//! ```
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use unionlabs_primitives::H256;
+
+use crate::{
+    tree_hash::{hash_concat, merkle_root, ZERO_HASHES, ZERO_HASHES_MAX_INDEX},
+    Ssz,
+};
+
+/// The index of a node in a binary Merkle tree, where the root is `1` and the children of node `i`
+/// are `2i` and `2i + 1`.
+pub type GeneralizedIndex = u64;
+
+/// An element of a path into an SSZ type, as passed to [`Ssz::generalized_index`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PathElement<'a> {
+    /// A field of a container.
+    Field(&'a str),
+    /// An element of a list or vector.
+    Index(usize),
+    /// The length mixed into the root of a list.
+    Length,
+}
+
+#[derive(Debug, PartialEq, Clone, thiserror::Error)]
+pub enum ProofError {
+    #[error("unknown field `{0}`")]
+    UnknownField(String),
+    #[error("index {index} is out of bounds (limit: {limit})")]
+    IndexOutOfBounds { index: usize, limit: usize },
+    #[error("path element {0} is not valid for this type")]
+    InvalidPathElement(String),
+    #[error("generalized index {0} is not part of the tree")]
+    InvalidGeneralizedIndex(GeneralizedIndex),
+    #[error("generalized index overflow")]
+    GeneralizedIndexOverflow,
+    #[error("invalid proof length, expected {expected} but found {found}")]
+    InvalidProofLength { expected: usize, found: usize },
+    #[error("invalid leaves length, expected {expected} but found {found}")]
+    InvalidLeavesLength { expected: usize, found: usize },
+}
+
+/// The depth of a generalized index, i.e. the length of a Merkle branch proving it.
+///
+/// # Errors
+///
+/// Fails if `index` is 0, which is not a node of any tree.
+pub const fn get_generalized_index_length(index: GeneralizedIndex) -> Result<u32, ProofError> {
+    match index.checked_ilog2() {
+        Some(length) => Ok(length),
+        None => Err(ProofError::InvalidGeneralizedIndex(index)),
+    }
+}
+
+/// Whether the bit at `position` of `index` is set, i.e. whether the node at that depth (counted
+/// from the bottom) is a right child.
+#[must_use]
+pub const fn get_generalized_index_bit(index: GeneralizedIndex, position: u32) -> bool {
+    (index >> position) & 1 == 1
+}
+
+#[must_use]
+pub const fn generalized_index_sibling(index: GeneralizedIndex) -> GeneralizedIndex {
+    index ^ 1
+}
+
+#[must_use]
+pub const fn generalized_index_child(
+    index: GeneralizedIndex,
+    right_side: bool,
+) -> GeneralizedIndex {
+    index * 2 + right_side as GeneralizedIndex
+}
+
+#[must_use]
+pub const fn generalized_index_parent(index: GeneralizedIndex) -> GeneralizedIndex {
+    index / 2
+}
+
+/// Given generalized indices `i1` for `A -> B`, `i2` for `B -> C` ... `i_n` for `Y -> Z`, returns
+/// the generalized index for `A -> Z`.
+pub fn concat_generalized_indices(
+    indices: &[GeneralizedIndex],
+) -> Result<GeneralizedIndex, ProofError> {
+    indices.iter().try_fold(1, |acc: GeneralizedIndex, index| {
+        let depth = index
+            .checked_ilog2()
+            .ok_or(ProofError::InvalidGeneralizedIndex(*index))?;
+
+        acc.checked_shl(depth)
+            .filter(|shifted| shifted >> depth == acc)
+            .map(|shifted| shifted | (index - (1 << depth)))
+            .ok_or(ProofError::GeneralizedIndexOverflow)
+    })
+}
+
+/// The generalized index of the `index`th leaf of a tree with `leaf_count` leaves (padded to the
+/// next power of two).
+pub fn leaf_generalized_index(
+    leaf_count: usize,
+    index: usize,
+) -> Result<GeneralizedIndex, ProofError> {
+    if index >= leaf_count {
+        return Err(ProofError::IndexOutOfBounds {
+            index,
+            limit: leaf_count,
+        });
+    }
+
+    1_u64
+        .checked_shl(tree_depth(leaf_count))
+        .and_then(|first_leaf| first_leaf.checked_add(index as GeneralizedIndex))
+        .ok_or(ProofError::GeneralizedIndexOverflow)
+}
+
+/// The generalized indices of the sister chunks along the path from the node at `tree_index` to
+/// the root, ordered from the bottom up.
+#[must_use]
+pub fn get_branch_indices(tree_index: GeneralizedIndex) -> Vec<GeneralizedIndex> {
+    get_path_indices(tree_index)
+        .into_iter()
+        .map(generalized_index_sibling)
+        .collect()
+}
+
+/// The generalized indices of the chunks along the path from the node at `tree_index` to the
+/// root (excluding the root), ordered from the bottom up.
+#[must_use]
+pub fn get_path_indices(tree_index: GeneralizedIndex) -> Vec<GeneralizedIndex> {
+    std::iter::successors(Some(tree_index), |index| {
+        Some(generalized_index_parent(*index)).filter(|parent| *parent > 1)
+    })
+    .take_while(|index| *index > 1)
+    .collect()
+}
+
+/// The generalized indices of all the chunks required to prove the nodes at `indices`, in
+/// descending order.
+#[must_use]
+pub fn get_helper_indices(indices: &[GeneralizedIndex]) -> Vec<GeneralizedIndex> {
+    let helper_indices = indices
+        .iter()
+        .flat_map(|index| get_branch_indices(*index))
+        .collect::<BTreeSet<_>>();
+
+    let path_indices = indices
+        .iter()
+        .flat_map(|index| get_path_indices(*index))
+        .collect::<BTreeSet<_>>();
+
+    helper_indices
+        .into_iter()
+        .rev()
+        .filter(|index| !path_indices.contains(index))
+        .collect()
+}
+
+pub fn calculate_merkle_root(
+    leaf: &H256,
+    proof: &[H256],
+    index: GeneralizedIndex,
+) -> Result<H256, ProofError> {
+    let depth = index
+        .checked_ilog2()
+        .ok_or(ProofError::InvalidGeneralizedIndex(index))?;
+
+    if proof.len() != depth as usize {
+        return Err(ProofError::InvalidProofLength {
+            expected: depth as usize,
+            found: proof.len(),
+        });
+    }
+
+    Ok(proof
+        .iter()
+        .zip(0..)
+        .fold(*leaf, |node, (sibling, position)| {
+            if get_generalized_index_bit(index, position) {
+                hash_concat(sibling.get(), node.get())
+            } else {
+                hash_concat(node.get(), sibling.get())
+            }
+        }))
+}
+
+/// Check if `leaf` at `index` verifies against the Merkle `root` and `proof`.
+#[must_use]
+pub fn verify_merkle_proof(
+    leaf: &H256,
+    proof: &[H256],
+    index: GeneralizedIndex,
+    root: &H256,
+) -> bool {
+    calculate_merkle_root(leaf, proof, index).is_ok_and(|calculated| calculated == *root)
+}
+
+pub fn calculate_multi_merkle_root(
+    leaves: &[H256],
+    proof: &[H256],
+    indices: &[GeneralizedIndex],
+) -> Result<H256, ProofError> {
+    if leaves.len() != indices.len() {
+        return Err(ProofError::InvalidLeavesLength {
+            expected: indices.len(),
+            found: leaves.len(),
+        });
+    }
+
+    let helper_indices = get_helper_indices(indices);
+
+    if proof.len() != helper_indices.len() {
+        return Err(ProofError::InvalidProofLength {
+            expected: helper_indices.len(),
+            found: proof.len(),
+        });
+    }
+
+    let mut objects = indices
+        .iter()
+        .copied()
+        .zip(leaves.iter().copied())
+        .chain(helper_indices.iter().copied().zip(proof.iter().copied()))
+        .collect::<BTreeMap<_, _>>();
+
+    let mut keys = objects.keys().rev().copied().collect::<Vec<_>>();
+
+    let mut pos = 0;
+    while let Some(&k) = keys.get(pos) {
+        let parent = generalized_index_parent(k);
+
+        if k > 1 && !objects.contains_key(&parent) {
+            if let (Some(left), Some(right)) = (objects.get(&(k & !1)), objects.get(&(k | 1))) {
+                objects.insert(parent, hash_concat(left.get(), right.get()));
+                keys.push(parent);
+            }
+        }
+
+        pos += 1;
+    }
+
+    objects
+        .get(&1)
+        .copied()
+        .ok_or(ProofError::InvalidGeneralizedIndex(1))
+}
+
+/// Check if `leaves` at `indices` verify against the Merkle `root` and `proof`.
+#[must_use]
+pub fn verify_merkle_multiproof(
+    leaves: &[H256],
+    proof: &[H256],
+    indices: &[GeneralizedIndex],
+    root: &H256,
+) -> bool {
+    calculate_multi_merkle_root(leaves, proof, indices).is_ok_and(|calculated| calculated == *root)
+}
+
+/// Generates the branch proving the node at `gindex` in the tree of `value`, ordered from the
+/// bottom up as expected by [`verify_merkle_proof`].
+pub fn merkle_proof<T: Ssz>(value: &T, gindex: GeneralizedIndex) -> Result<Vec<H256>, ProofError> {
+    if gindex == 0 {
+        return Err(ProofError::InvalidGeneralizedIndex(gindex));
+    }
+
+    get_branch_indices(gindex)
+        .into_iter()
+        .map(|index| value.tree_hash_node(index))
+        .collect()
+}
+
+/// Generates the helper nodes proving the nodes at `indices` in the tree of `value`, as expected by
+/// [`verify_merkle_multiproof`].
+pub fn merkle_multiproof<T: Ssz>(
+    value: &T,
+    indices: &[GeneralizedIndex],
+) -> Result<Vec<H256>, ProofError> {
+    if let Some(index) = indices.iter().find(|index| **index == 0) {
+        return Err(ProofError::InvalidGeneralizedIndex(*index));
+    }
+
+    get_helper_indices(indices)
+        .into_iter()
+        .map(|index| value.tree_hash_node(index))
+        .collect()
+}
+
+/// The depth of a tree with `leaf_count` leaves, padded to the next power of two.
+pub(crate) fn tree_depth(leaf_count: usize) -> u32 {
+    leaf_count.next_power_of_two().ilog2()
+}
+
+/// Returns the node at `gindex` in a tree with `limit` leaves (padded to the next power of two),
+/// where the first `leaves.len()` leaves are given and the rest are zero.
+///
+/// Nodes below the leaf level are resolved by calling `descend` with the index of the leaf and the
+/// generalized index of the node relative to that leaf.
+pub fn chunk_tree_node(
+    leaves: &[H256],
+    limit: usize,
+    gindex: GeneralizedIndex,
+    descend: impl FnOnce(usize, GeneralizedIndex) -> Result<H256, ProofError>,
+) -> Result<H256, ProofError> {
+    let depth = tree_depth(limit);
+
+    let node_depth = gindex
+        .checked_ilog2()
+        .ok_or(ProofError::InvalidGeneralizedIndex(gindex))?;
+
+    if node_depth <= depth {
+        let height = depth - node_depth;
+        let first_leaf = usize::try_from(gindex - (1 << node_depth))
+            .ok()
+            .and_then(|position| position.checked_shl(height))
+            .ok_or(ProofError::InvalidGeneralizedIndex(gindex))?;
+
+        if first_leaf >= leaves.len() {
+            return ZERO_HASHES
+                .get(height as usize)
+                .copied()
+                .ok_or(ProofError::InvalidGeneralizedIndex(gindex));
+        }
+
+        if height as usize > ZERO_HASHES_MAX_INDEX {
+            return Err(ProofError::InvalidGeneralizedIndex(gindex));
+        }
+
+        let bytes = leaves[first_leaf..]
+            .iter()
+            .take(1 << height)
+            .flat_map(|leaf| *leaf.get())
+            .collect::<Vec<_>>();
+
+        Ok(merkle_root(&bytes, 1 << height))
+    } else {
+        let sub_depth = node_depth - depth;
+        let leaf = usize::try_from((gindex >> sub_depth) - (1 << depth))
+            .map_err(|_| ProofError::InvalidGeneralizedIndex(gindex))?;
+
+        if leaf >= leaves.len() {
+            return Err(ProofError::InvalidGeneralizedIndex(gindex));
+        }
+
+        descend(leaf, (1 << sub_depth) | (gindex & ((1 << sub_depth) - 1)))
+    }
+}
+
+/// Returns the node at `gindex` in the tree of a list, where the left subtree of the root is the
+/// tree of the list's data and the right child is its length.
+pub fn list_tree_node(
+    root: H256,
+    len: usize,
+    gindex: GeneralizedIndex,
+    data_node: impl FnOnce(GeneralizedIndex) -> Result<H256, ProofError>,
+) -> Result<H256, ProofError> {
+    mix_in_tree_node(root, len.tree_hash_root(), gindex, data_node)
+}
+
+/// Returns the node at `gindex` in a tree whose root is the hash of the root of some data (the
+/// left subtree) and an auxiliary chunk `aux` (the right child), such as the length of a list or
+/// the active fields of a stable container.
+pub fn mix_in_tree_node(
+    root: H256,
+    aux: H256,
+    gindex: GeneralizedIndex,
+    data_node: impl FnOnce(GeneralizedIndex) -> Result<H256, ProofError>,
+) -> Result<H256, ProofError> {
+    match gindex {
+        0 => Err(ProofError::InvalidGeneralizedIndex(gindex)),
+        1 => Ok(root),
+        3 => Ok(aux),
+        _ => {
+            let depth = get_generalized_index_length(gindex)?;
+
+            // the subtree of the data root (2) is every node whose second highest bit is unset
+            if get_generalized_index_bit(gindex, depth - 1) {
+                Err(ProofError::InvalidGeneralizedIndex(gindex))
+            } else {
+                data_node((1 << (depth - 1)) | (gindex & ((1 << (depth - 1)) - 1)))
+            }
+        }
+    }
+}
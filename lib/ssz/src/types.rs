@@ -4,6 +4,7 @@
 //! - `List`: A heap-allocated list that cannot grow past a type-level maximum length.
 //! - `BitList`: A heap-allocated bitfield that with a type-level _maximum_ length.
 //! - `BitVector`: A heap-allocated bitfield that with a type-level _fixed__ length.
+//! - `StableContainer` and `Profile`: Containers with optional fields and stable merkleization.
 //!
 //! These structs are required as SSZ serialization and Merklization rely upon type-level lengths
 //! for padding and verification.
@@ -40,11 +41,13 @@
 #[macro_use]
 pub mod bitfield;
 pub mod list;
+pub mod stable_container;
 pub mod tree_hash;
 pub mod vector;
 
 pub use bitfield::{BitList, BitVector, Bitfield};
 pub use list::List;
+pub use stable_container::{Profile, StableContainer};
 pub use typenum;
 pub use vector::Vector;
 
@@ -13,8 +13,15 @@ use typenum::{NonZero, Unsigned};
 use unionlabs_primitives::H256;
 
 use crate::{
-    decode::TryFromIter, decode_list_of_variable_length_items, sequence_ssz_append,
-    sequence_ssz_bytes_len, tree_hash::TreeHashType, types::tree_hash::vec_tree_hash_root, Ssz,
+    decode::TryFromIter,
+    decode_list_of_variable_length_items,
+    merkle_proof::{
+        concat_generalized_indices, list_tree_node, GeneralizedIndex, PathElement, ProofError,
+    },
+    sequence_ssz_append, sequence_ssz_bytes_len,
+    tree_hash::TreeHashType,
+    types::tree_hash::{vec_generalized_index, vec_tree_hash_node, vec_tree_hash_root},
+    Ssz,
 };
 
 /// Emulates a SSZ `List`.
@@ -221,6 +228,20 @@ where
         crate::tree_hash::mix_in_length(&root, self.len())
     }
 
+    fn generalized_index(path: &[PathElement<'_>]) -> Result<GeneralizedIndex, ProofError> {
+        match path {
+            [] => Ok(1),
+            [PathElement::Length] => Ok(3),
+            path => concat_generalized_indices(&[2, vec_generalized_index::<T, N>(path)?]),
+        }
+    }
+
+    fn tree_hash_node(&self, gindex: GeneralizedIndex) -> Result<H256, ProofError> {
+        list_tree_node(self.tree_hash_root(), self.len(), gindex, |gindex| {
+            vec_tree_hash_node::<T, N>(&self.vec, gindex)
+        })
+    }
+
     fn ssz_append(&self, buf: &mut Vec<u8>) {
         sequence_ssz_append(self.iter(), buf);
     }
@@ -0,0 +1,280 @@
+//! Support for the `StableContainer` and `Profile` types as defined in
+//! [EIP-7495](https://eips.ethereum.org/EIPS/eip-7495).
+//!
+//! A `StableContainer[N]` is a container whose fields are all optional, with a capacity of `N`
+//! fields that is fixed forever. This keeps the generalized index of every field stable across
+//! forks that add fields, such that verifiers don't need to be updated for every fork.
+//!
+//! A `Profile[B]` is a subset of the fields of a stable container `B`, where each field may be
+//! either required or optional. It is serialized more compactly than `B`, but has the same tree
+//! hash root as the equivalent `B` value.
+//!
+//! Both are implemented with `#[derive(Ssz)]`:
+//!
+//! ```rust
+//! use ssz::Ssz;
+//!
+//! #[derive(Debug, PartialEq, Ssz)]
+//! #[ssz(stable_container(4))]
+//! struct Shape {
+//!     side: Option<u16>,
+//!     color: Option<u8>,
+//!     radius: Option<u16>,
+//! }
+//!
+//! #[derive(Debug, PartialEq, Ssz)]
+//! #[ssz(profile(Shape))]
+//! struct Square {
+//!     side: u16,
+//!     color: u8,
+//! }
+//!
+//! let shape = Shape {
+//!     side: Some(0x42),
+//!     color: Some(1),
+//!     radius: None,
+//! };
+//! let square = Square {
+//!     side: 0x42,
+//!     color: 1,
+//! };
+//!
+//! assert_eq!(shape.as_ssz_bytes(), [0x03, 0x42, 0x00, 0x01]);
+//! assert_eq!(square.as_ssz_bytes(), [0x42, 0x00, 0x01]);
+//! assert_eq!(shape.tree_hash_root(), square.tree_hash_root());
+//! ```
+
+use unionlabs_primitives::H256;
+
+use crate::{
+    decode::DecodeError,
+    merkle_proof::{
+        chunk_tree_node, concat_generalized_indices, leaf_generalized_index, mix_in_tree_node,
+        GeneralizedIndex, ProofError,
+    },
+    tree_hash::{hash_concat, merkle_root, BYTES_PER_CHUNK},
+    Ssz,
+};
+
+/// A `StableContainer[N]`, implemented with `#[ssz(stable_container(N))]`.
+pub trait StableContainer: Ssz {
+    /// The capacity `N` of this stable container, i.e. the maximum number of fields it will ever
+    /// have.
+    const MAX_FIELDS: usize;
+
+    /// The names of the fields of this stable container, in order.
+    const FIELDS: &'static [&'static str];
+}
+
+/// A `Profile[B]`, implemented with `#[ssz(profile(B))]`.
+pub trait Profile: Ssz {
+    type Base: StableContainer;
+
+    /// The index of each field of this profile in [`Self::Base`], in order.
+    const BASE_INDICES: &'static [usize];
+}
+
+/// Returns the index of the field `name` in `T`.
+///
+/// # Panics
+///
+/// Panics if `T` has no field `name`. Since this is intended to be called in const contexts, this
+/// results in a compile error.
+#[must_use]
+pub const fn field_index<T: StableContainer>(name: &str) -> usize {
+    let mut i = 0;
+
+    while i < T::FIELDS.len() {
+        if const_str_eq(T::FIELDS[i], name) {
+            return i;
+        }
+
+        i += 1;
+    }
+
+    panic!("field is not a field of the stable container")
+}
+
+/// Returns the index in `T` of each of the fields `names` of a profile of `T`.
+///
+/// # Panics
+///
+/// Panics if any of `names` is not a field of `T`, or if `names` are not in the same order as the
+/// fields of `T`.
+#[must_use]
+pub const fn base_indices<T: StableContainer, const N: usize>(names: [&str; N]) -> [usize; N] {
+    let mut indices = [0; N];
+    let mut i = 0;
+
+    while i < N {
+        indices[i] = field_index::<T>(names[i]);
+
+        assert!(
+            i == 0 || indices[i - 1] < indices[i],
+            "profile fields must be in the same order as the fields of the base stable container"
+        );
+
+        i += 1;
+    }
+
+    indices
+}
+
+const fn const_str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let mut i = 0;
+
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+
+        i += 1;
+    }
+
+    true
+}
+
+/// The length of the serialized `Bitvector[len]` of active fields.
+#[must_use]
+pub const fn active_fields_len(len: usize) -> usize {
+    len.div_ceil(8)
+}
+
+/// Serializes `active` as a `Bitvector[len]`.
+#[must_use]
+pub fn active_fields_bytes(len: usize, active: impl IntoIterator<Item = bool>) -> Vec<u8> {
+    let mut bytes = vec![0; active_fields_len(len)];
+
+    for (i, _) in active
+        .into_iter()
+        .enumerate()
+        .filter(|(_, is_active)| *is_active)
+    {
+        bytes[i / 8] |= 1 << (i % 8);
+    }
+
+    bytes
+}
+
+/// Splits the `Bitvector[len]` of active fields off the front of `bytes`, returning which of the
+/// first `field_count` fields are active along with the remaining bytes.
+///
+/// Bits for fields past `field_count` must not be set.
+pub fn split_active_fields(
+    bytes: &[u8],
+    len: usize,
+    field_count: usize,
+) -> Result<(Vec<bool>, &[u8]), DecodeError> {
+    if bytes.len() < active_fields_len(len) {
+        return Err(DecodeError::InvalidByteLength {
+            found: bytes.len(),
+            expected: active_fields_len(len),
+        });
+    }
+
+    let (active_fields, bytes) = bytes.split_at(active_fields_len(len));
+
+    let is_active = |i: usize| active_fields[i / 8] & (1 << (i % 8)) != 0;
+
+    if let Some(i) = (field_count..active_fields.len() * 8).find(|i| is_active(*i)) {
+        return Err(DecodeError::BytesInvalid(format!(
+            "active field {i} is out of bounds (field count: {field_count})"
+        )));
+    }
+
+    Ok(((0..field_count).map(is_active).collect(), bytes))
+}
+
+/// Computes the tree hash root of a stable container with a capacity of `max_fields`, given the
+/// index and (if active) root of each of its fields.
+///
+/// `hash_tree_root` of a profile is the same as that of its base stable container, so this is used
+/// for both.
+#[must_use]
+pub fn tree_hash_root(
+    max_fields: usize,
+    fields: impl IntoIterator<Item = (usize, Option<H256>)>,
+) -> H256 {
+    let (data_root, active_fields_root) = roots(max_fields, fields);
+
+    hash_concat(data_root.get(), active_fields_root.get())
+}
+
+/// The generalized index of the field at `index` of a stable container with a capacity of
+/// `max_fields`, concatenated with the generalized index `gindex` relative to that field.
+pub fn generalized_index(
+    max_fields: usize,
+    index: usize,
+    gindex: GeneralizedIndex,
+) -> Result<GeneralizedIndex, ProofError> {
+    concat_generalized_indices(&[2, leaf_generalized_index(max_fields, index)?, gindex])
+}
+
+/// Returns the node at `gindex` in the tree of a stable container with a capacity of
+/// `max_fields`, given the index and (if active) root of each of its fields.
+///
+/// Nodes below the field level are resolved by calling `descend` with the index of the field and
+/// the generalized index of the node relative to that field.
+pub fn tree_hash_node(
+    max_fields: usize,
+    fields: &[(usize, Option<H256>)],
+    gindex: GeneralizedIndex,
+    descend: impl FnOnce(usize, GeneralizedIndex) -> Result<H256, ProofError>,
+) -> Result<H256, ProofError> {
+    let (data_root, active_fields_root) = roots(max_fields, fields.iter().copied());
+
+    mix_in_tree_node(
+        hash_concat(data_root.get(), active_fields_root.get()),
+        active_fields_root,
+        gindex,
+        |gindex| chunk_tree_node(&leaves(fields), max_fields, gindex, descend),
+    )
+}
+
+fn leaves(fields: &[(usize, Option<H256>)]) -> Vec<H256> {
+    let mut leaves = vec![
+        H256::default();
+        fields
+            .iter()
+            .map(|(index, _)| index + 1)
+            .max()
+            .unwrap_or_default()
+    ];
+
+    for (index, root) in fields {
+        leaves[*index] = root.unwrap_or_default();
+    }
+
+    leaves
+}
+
+fn roots(
+    max_fields: usize,
+    fields: impl IntoIterator<Item = (usize, Option<H256>)>,
+) -> (H256, H256) {
+    let fields = fields.into_iter().collect::<Vec<_>>();
+
+    let data = leaves(&fields)
+        .iter()
+        .flat_map(|leaf| *leaf.get())
+        .collect::<Vec<_>>();
+
+    let mut active = vec![false; max_fields];
+    for (index, root) in &fields {
+        active[*index] = root.is_some();
+    }
+
+    (
+        merkle_root(&data, max_fields),
+        merkle_root(
+            &active_fields_bytes(max_fields, active),
+            max_fields.div_ceil(BYTES_PER_CHUNK * 8),
+        ),
+    )
+}
@@ -2,6 +2,10 @@ use typenum::Unsigned;
 use unionlabs_primitives::H256;
 
 use crate::{
+    merkle_proof::{
+        chunk_tree_node, concat_generalized_indices, leaf_generalized_index, GeneralizedIndex,
+        PathElement, ProofError,
+    },
     tree_hash::{MerkleHasher, TreeHashType, BYTES_PER_CHUNK},
     Ssz,
 };
@@ -43,6 +47,86 @@ where
     }
 }
 
+/// A helper function providing common functionality between the `generalized_index`
+/// implementations for `Vector` and `List`, with the path being relative to the root of the
+/// elements (i.e. without the length mixed in).
+pub fn vec_generalized_index<T, N>(path: &[PathElement<'_>]) -> Result<GeneralizedIndex, ProofError>
+where
+    T: Ssz,
+    N: Unsigned,
+{
+    match path {
+        [] => Ok(1),
+        [PathElement::Index(index), rest @ ..] => {
+            if *index >= N::USIZE {
+                return Err(ProofError::IndexOutOfBounds {
+                    index: *index,
+                    limit: N::USIZE,
+                });
+            }
+
+            match T::TREE_HASH_TYPE {
+                TreeHashType::Basic { size } => match rest {
+                    // basic types are packed into chunks and cannot be descended into
+                    [] => leaf_generalized_index(
+                        chunk_count_basic_list_or_vector::<N>(size),
+                        (index * size as usize) / BYTES_PER_CHUNK,
+                    ),
+                    [element, ..] => Err(ProofError::InvalidPathElement(format!("{element:?}"))),
+                },
+                TreeHashType::Container | TreeHashType::List | TreeHashType::Vector => {
+                    concat_generalized_indices(&[
+                        leaf_generalized_index(N::USIZE, *index)?,
+                        T::generalized_index(rest)?,
+                    ])
+                }
+            }
+        }
+        [element, ..] => Err(ProofError::InvalidPathElement(format!("{element:?}"))),
+    }
+}
+
+/// A helper function providing common functionality between the `tree_hash_node`
+/// implementations for `Vector` and `List`, with `gindex` being relative to the root of the
+/// elements (i.e. without the length mixed in).
+pub fn vec_tree_hash_node<T, N>(vec: &[T], gindex: GeneralizedIndex) -> Result<H256, ProofError>
+where
+    T: Ssz,
+    N: Unsigned,
+{
+    match T::TREE_HASH_TYPE {
+        TreeHashType::Basic { size } => {
+            let bytes = vec
+                .iter()
+                .flat_map(|item| item.tree_hash_root().get()[..(size as usize)].to_vec())
+                .collect::<Vec<_>>();
+
+            let chunks = bytes
+                .chunks(BYTES_PER_CHUNK)
+                .map(|chunk| {
+                    let mut leaf = [0; BYTES_PER_CHUNK];
+                    leaf[..chunk.len()].copy_from_slice(chunk);
+                    H256::new(leaf)
+                })
+                .collect::<Vec<_>>();
+
+            chunk_tree_node(
+                &chunks,
+                chunk_count_basic_list_or_vector::<N>(size),
+                gindex,
+                |_, _| Err(ProofError::InvalidGeneralizedIndex(gindex)),
+            )
+        }
+        TreeHashType::Container | TreeHashType::List | TreeHashType::Vector => {
+            let leaves = vec.iter().map(Ssz::tree_hash_root).collect::<Vec<_>>();
+
+            chunk_tree_node(&leaves, N::USIZE, gindex, |index, gindex| {
+                vec[index].tree_hash_node(gindex)
+            })
+        }
+    }
+}
+
 /// Corresponds to `chunk_count(type)` definition for `List[B, N]` and `Vector[B, N]` from [the spec](https://github.com/ethereum/consensus-specs/blob/dev/ssz/simple-serialize.md#merkleization).
 #[inline]
 fn chunk_count_basic_list_or_vector<N>(size: u8) -> usize
@@ -13,8 +13,12 @@ use typenum::{Const, NonZero, ToUInt, Unsigned, U};
 use unionlabs_primitives::H256;
 
 use crate::{
-    decode::TryFromIter, decode_list_of_variable_length_items, sequence_ssz_append,
-    sequence_ssz_bytes_len, tree_hash::TreeHashType, types::tree_hash::vec_tree_hash_root,
+    decode::TryFromIter,
+    decode_list_of_variable_length_items,
+    merkle_proof::{GeneralizedIndex, PathElement, ProofError},
+    sequence_ssz_append, sequence_ssz_bytes_len,
+    tree_hash::TreeHashType,
+    types::tree_hash::{vec_generalized_index, vec_tree_hash_node, vec_tree_hash_root},
     DecodeError, Ssz,
 };
 
@@ -200,6 +204,14 @@ where
         vec_tree_hash_root::<T, N>(&self.vec)
     }
 
+    fn generalized_index(path: &[PathElement<'_>]) -> Result<GeneralizedIndex, ProofError> {
+        vec_generalized_index::<T, N>(path)
+    }
+
+    fn tree_hash_node(&self, gindex: GeneralizedIndex) -> Result<H256, ProofError> {
+        vec_tree_hash_node::<T, N>(&self.vec, gindex)
+    }
+
     fn ssz_bytes_len(&self) -> NonZeroUsize {
         sequence_ssz_bytes_len(&self.vec)
     }
@@ -13,6 +13,8 @@
     let
       spec_compliance_tests_dir = "lib/ssz/tests/spec_conformance";
       spec_compliance_tests_file = "lib/ssz/tests/spec_conformance.rs";
+      ssz_static_tests_dir = "lib/beacon-api-types/tests/spec_conformance";
+      ssz_static_tests_file = "lib/beacon-api-types/tests/spec_conformance.rs";
 
       tests-generator = crane.buildWorkspaceMember "lib/ssz/tests-generator" { };
 
@@ -25,11 +27,13 @@
             config.treefmt.build.programs.rustfmt
           ];
           buildPhase = ''
-            mkdir -p $out/tests
+            mkdir -p $out/tests $out/ssz_static_tests
 
-            ssz-tests-generator "${inputs.ethereum-consensus-specs}" $out/spec_conformance.rs $out/tests
+            ssz-tests-generator \
+              "${inputs.ethereum-consensus-specs}" $out/spec_conformance.rs $out/tests \
+              "${inputs.ethereum-consensus-spec-tests-minimal}" $out/ssz_static_spec_conformance.rs $out/ssz_static_tests
 
-            rustfmt --config-path ${../../rustfmt.toml} --config skip_children=true $out/tests/* $out/spec_conformance.rs
+            rustfmt --config-path ${../../rustfmt.toml} --config skip_children=true $out/tests/* $out/spec_conformance.rs $out/ssz_static_tests/* $out/ssz_static_spec_conformance.rs
           '';
         }
       );
@@ -42,6 +46,10 @@
 
             cp --no-preserve=mode ${ssz-compliance-tests}/tests/* ${spec_compliance_tests_dir}
             cp --no-preserve=mode ${ssz-compliance-tests}/spec_conformance.rs ${spec_compliance_tests_file}
+
+            mkdir -p ${ssz_static_tests_dir}
+            cp --no-preserve=mode ${ssz-compliance-tests}/ssz_static_tests/* ${ssz_static_tests_dir}
+            cp --no-preserve=mode ${ssz-compliance-tests}/ssz_static_spec_conformance.rs ${ssz_static_tests_file}
           '';
         }
       );
@@ -56,6 +64,8 @@
             checkPhase = ''
               diff ${ssz-compliance-tests}/tests ${spec_compliance_tests_dir}
               diff ${ssz-compliance-tests}/spec_conformance.rs ${spec_compliance_tests_file}
+              diff ${ssz-compliance-tests}/ssz_static_tests ${ssz_static_tests_dir}
+              diff ${ssz-compliance-tests}/ssz_static_spec_conformance.rs ${ssz_static_tests_file}
 
               touch $out
             '';
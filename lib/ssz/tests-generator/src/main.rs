@@ -23,13 +23,31 @@ type uint256 = U256;
 pub mod container_types;
 use container_types::*;
 
+mod ssz_static;
+
 fn main() {
-    let [ethereum_consensus_specs_dir, spec_conformance_out_file, out_dir] = std::env::args_os()
+    let args = std::env::args_os()
         .skip(1)
         .map(PathBuf::from)
-        .collect::<Vec<_>>()
-        .try_into()
-        .expect("expected two arguments [ethereum_consensus_specs_dir, spec_conformance_out_file, out_dir]");
+        .collect::<Vec<_>>();
+
+    let (
+        [ethereum_consensus_specs_dir, spec_conformance_out_file, out_dir],
+        ssz_static_args,
+    ) = match <[PathBuf; 6]>::try_from(args) {
+        Ok([a, b, c, d, e, f]) => ([a, b, c], Some([d, e, f])),
+        Err(args) => (
+            args.try_into().expect(
+                "expected three arguments [ethereum_consensus_specs_dir, spec_conformance_out_file, out_dir], \
+                optionally followed by three more arguments [ethereum_consensus_spec_tests_minimal_dir, ssz_static_spec_conformance_out_file, ssz_static_out_dir]",
+            ),
+            None,
+        ),
+    };
+
+    if let Some([spec_tests_dir, ssz_static_out_file, ssz_static_out_dir]) = ssz_static_args {
+        ssz_static::generate(&spec_tests_dir, &ssz_static_out_file, &ssz_static_out_dir);
+    }
 
     let testdata_dir = ethereum_consensus_specs_dir.join("general/phase0/ssz_generic/");
 
@@ -0,0 +1,186 @@
+//! Generator for the `ssz_static` consensus spec tests, which test the beacon chain containers
+//! defined in `beacon-api-types`.
+//!
+//! Since the values in these tests are in the consensus spec yaml format (which differs from the
+//! beacon api json format that the `beacon-api-types` serde implementations follow), only the
+//! encoding and the hash tree root are checked, by roundtripping the serialized value.
+
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    fs::{self, read_dir},
+    path::Path,
+};
+
+use serde::Deserialize;
+use unionlabs::primitives::H256;
+
+use crate::{read_snappy_file, AsRaw};
+
+const HEADER: &str = r#"
+#![allow(non_snake_case, unused_imports)]
+
+use beacon_api_types::{altair, chain_spec::Minimal, deneb, fulu, phase0};
+use hex_literal::hex;
+use ssz::Ssz;
+use unionlabs::primitives::H256;
+"#;
+
+/// The containers to generate tests for, as `(fork, spec type name, rust type)`.
+///
+/// Fulu does not modify any of the containers defined in `beacon-api-types`, so the types of the
+/// previous forks are tested against the fulu vectors.
+const CONTAINERS: &[(&str, &str, &str)] = &[
+    ("fulu", "Attestation", "fulu::AttestationSsz<Minimal>"),
+    (
+        "fulu",
+        "AttesterSlashing",
+        "fulu::AttesterSlashingSsz<Minimal>",
+    ),
+    ("fulu", "BeaconBlock", "fulu::BeaconBlockSsz<Minimal>"),
+    (
+        "fulu",
+        "BeaconBlockBody",
+        "fulu::BeaconBlockBodySsz<Minimal>",
+    ),
+    ("fulu", "BeaconBlockHeader", "phase0::BeaconBlockHeader"),
+    ("fulu", "ConsolidationRequest", "fulu::ConsolidationRequest"),
+    ("fulu", "DepositRequest", "fulu::DepositRequest"),
+    (
+        "fulu",
+        "ExecutionPayload",
+        "deneb::ExecutionPayloadSsz<Minimal>",
+    ),
+    (
+        "fulu",
+        "ExecutionPayloadHeader",
+        "deneb::ExecutionPayloadHeaderSsz<Minimal>",
+    ),
+    (
+        "fulu",
+        "ExecutionRequests",
+        "fulu::ExecutionRequestsSsz<Minimal>",
+    ),
+    (
+        "fulu",
+        "IndexedAttestation",
+        "fulu::IndexedAttestationSsz<Minimal>",
+    ),
+    (
+        "fulu",
+        "SignedBeaconBlock",
+        "fulu::SignedBeaconBlockSsz<Minimal>",
+    ),
+    ("fulu", "SyncAggregate", "altair::SyncAggregateSsz<Minimal>"),
+    ("fulu", "SyncCommittee", "altair::SyncCommitteeSsz<Minimal>"),
+    ("fulu", "WithdrawalRequest", "fulu::WithdrawalRequest"),
+];
+
+#[derive(Deserialize)]
+struct Roots {
+    root: H256,
+}
+
+/// Generate the `ssz_static` tests for the minimal preset.
+///
+/// `ethereum_consensus_spec_tests_dir` is expected to be the root of the extracted
+/// `minimal.tar.gz` consensus spec tests release artifact.
+pub fn generate(
+    ethereum_consensus_spec_tests_dir: &Path,
+    spec_conformance_out_file: &Path,
+    out_dir: &Path,
+) {
+    let mods = CONTAINERS
+        .iter()
+        .map(|(fork, spec_type_name, type_name)| {
+            let mod_name = format!("{fork}_{}", to_snake_case(spec_type_name));
+
+            let testdata_dir = ethereum_consensus_spec_tests_dir
+                .join(format!("minimal/{fork}/ssz_static/{spec_type_name}"));
+
+            fs::write(
+                out_dir.join(format!("{mod_name}.rs")),
+                format!("{HEADER} {}", read_cases(&testdata_dir, type_name)),
+            )
+            .unwrap();
+
+            mod_name
+        })
+        .fold(String::new(), |mut s, m| {
+            writeln!(s, "mod {m};").unwrap();
+            s
+        });
+
+    fs::write(
+        spec_conformance_out_file,
+        format!("mod spec_conformance {{ {mods} }}"),
+    )
+    .unwrap();
+}
+
+fn read_cases(testdata_dir: &Path, type_name: &str) -> String {
+    let mut cases = BTreeMap::new();
+
+    // ssz_random, ssz_random_chaos, etc
+    for handler in read_dir(testdata_dir).unwrap() {
+        let handler = handler.unwrap();
+        let handler_name = handler.file_name().to_str().unwrap().to_owned();
+
+        for case in read_dir(handler.path()).unwrap() {
+            let case = case.unwrap();
+            let case_name = case.file_name().to_str().unwrap().to_owned();
+
+            let path = case.path();
+
+            let expected_encoding = read_snappy_file(&path);
+            let roots = serde_yaml::from_str::<Roots>(
+                &fs::read_to_string(path.join("roots.yaml")).unwrap(),
+            )
+            .unwrap();
+
+            cases.insert(
+                format!("{handler_name}_{case_name}"),
+                roundtrip(type_name, expected_encoding, roots.root),
+            );
+        }
+    }
+
+    cases
+        .into_iter()
+        .fold(String::new(), |mut s, (name, body)| {
+            writeln!(s, "#[test] fn {name}() {{ {body} }}").unwrap();
+            s
+        })
+}
+
+fn roundtrip(type_name: &str, expected_encoding: Vec<u8>, expected_root: H256) -> String {
+    format!(
+        r#"
+            const EXPECTED_ENCODING: &[u8] = &{expected_encoding};
+            const EXPECTED_ROOT: H256 = {expected_root};
+
+            let value = <{type_name} as Ssz>::from_ssz_bytes(EXPECTED_ENCODING).unwrap();
+
+            assert_eq!(
+                EXPECTED_ENCODING,
+                <{type_name} as Ssz>::as_ssz_bytes(&value)
+            );
+
+            assert_eq!(EXPECTED_ROOT, value.tree_hash_root());
+        "#,
+        expected_encoding = expected_encoding.as_raw(),
+        expected_root = expected_root.as_raw(),
+    )
+}
+
+fn to_snake_case(s: &str) -> String {
+    s.chars()
+        .enumerate()
+        .fold(String::new(), |mut acc, (i, c)| {
+            if c.is_ascii_uppercase() && i != 0 {
+                acc.push('_');
+            }
+            acc.push(c.to_ascii_lowercase());
+            acc
+        })
+}
@@ -0,0 +1,341 @@
+use ssz::{
+    merkle_proof::{
+        calculate_merkle_root, concat_generalized_indices, get_generalized_index_length,
+        get_helper_indices, merkle_multiproof, merkle_proof, verify_merkle_multiproof,
+        verify_merkle_proof, PathElement, ProofError,
+    },
+    types::{
+        typenum::{U1099511627776, U4, U8},
+        List, Vector,
+    },
+    Ssz, H256,
+};
+
+#[derive(Debug, Clone, PartialEq, Ssz)]
+struct Checkpoint {
+    epoch: u64,
+    root: H256,
+}
+
+#[derive(Debug, Clone, PartialEq, Ssz)]
+struct Validator {
+    pubkey: H256,
+    effective_balance: u64,
+    slashed: bool,
+}
+
+/// Has the same shape as the altair `BeaconState` up to the fields that are proven by the light
+/// client sync protocol.
+#[derive(Debug, Clone, PartialEq, Ssz)]
+struct BeaconState {
+    genesis_time: u64,
+    genesis_validators_root: H256,
+    slot: u64,
+    fork: u64,
+    latest_block_header: H256,
+    block_roots: Vector<H256, U8>,
+    state_roots: Vector<H256, U8>,
+    historical_roots: List<H256, U8>,
+    eth1_data: H256,
+    eth1_data_votes: List<H256, U8>,
+    eth1_deposit_index: u64,
+    validators: List<Validator, U1099511627776>,
+    balances: List<u64, U1099511627776>,
+    randao_mixes: Vector<H256, U8>,
+    slashings: Vector<u64, U8>,
+    previous_epoch_participation: List<u8, U8>,
+    current_epoch_participation: List<u8, U8>,
+    justification_bits: u8,
+    previous_justified_checkpoint: Checkpoint,
+    current_justified_checkpoint: Checkpoint,
+    finalized_checkpoint: Checkpoint,
+    inactivity_scores: List<u64, U8>,
+    current_sync_committee: Vector<H256, U4>,
+    next_sync_committee: Vector<H256, U4>,
+}
+
+fn h256(n: u8) -> H256 {
+    H256::new([n; 32])
+}
+
+fn beacon_state() -> BeaconState {
+    BeaconState {
+        genesis_time: 1606824023,
+        genesis_validators_root: h256(1),
+        slot: 4700013,
+        fork: 4,
+        latest_block_header: h256(2),
+        block_roots: vec![h256(3); 8].try_into().unwrap(),
+        state_roots: vec![h256(4); 8].try_into().unwrap(),
+        historical_roots: vec![h256(5), h256(6)].try_into().unwrap(),
+        eth1_data: h256(7),
+        eth1_data_votes: vec![].try_into().unwrap(),
+        eth1_deposit_index: 1337,
+        validators: vec![
+            Validator {
+                pubkey: h256(8),
+                effective_balance: 32_000_000_000,
+                slashed: false,
+            },
+            Validator {
+                pubkey: h256(9),
+                effective_balance: 31_000_000_000,
+                slashed: true,
+            },
+        ]
+        .try_into()
+        .unwrap(),
+        balances: vec![32_000_000_000, 31_000_000_000, 1, 2, 3]
+            .try_into()
+            .unwrap(),
+        randao_mixes: vec![h256(10); 8].try_into().unwrap(),
+        slashings: vec![0, 1, 2, 3, 4, 5, 6, 7].try_into().unwrap(),
+        previous_epoch_participation: vec![7, 7, 3].try_into().unwrap(),
+        current_epoch_participation: vec![7, 0, 3].try_into().unwrap(),
+        justification_bits: 0b1111,
+        previous_justified_checkpoint: Checkpoint {
+            epoch: 146874,
+            root: h256(11),
+        },
+        current_justified_checkpoint: Checkpoint {
+            epoch: 146875,
+            root: h256(12),
+        },
+        finalized_checkpoint: Checkpoint {
+            epoch: 146874,
+            root: h256(13),
+        },
+        inactivity_scores: vec![0, 0].try_into().unwrap(),
+        current_sync_committee: vec![h256(14); 4].try_into().unwrap(),
+        next_sync_committee: vec![h256(15); 4].try_into().unwrap(),
+    }
+}
+
+#[test]
+fn sync_protocol_gindices() {
+    // FINALIZED_ROOT_GINDEX
+    assert_eq!(
+        BeaconState::generalized_index(&[
+            PathElement::Field("finalized_checkpoint"),
+            PathElement::Field("root"),
+        ]),
+        Ok(105)
+    );
+    // CURRENT_SYNC_COMMITTEE_GINDEX
+    assert_eq!(
+        BeaconState::generalized_index(&[PathElement::Field("current_sync_committee")]),
+        Ok(54)
+    );
+    // NEXT_SYNC_COMMITTEE_GINDEX
+    assert_eq!(
+        BeaconState::generalized_index(&[PathElement::Field("next_sync_committee")]),
+        Ok(55)
+    );
+}
+
+#[test]
+fn list_gindices() {
+    // 2 ** 40 leaves, mixed in with the length
+    assert_eq!(
+        <List<Validator, U1099511627776>>::generalized_index(&[PathElement::Index(3)]),
+        Ok(2 * (1 << 40) + 3)
+    );
+    assert_eq!(
+        <List<Validator, U1099511627776>>::generalized_index(&[PathElement::Length]),
+        Ok(3)
+    );
+    // 4 u64s are packed into one chunk
+    assert_eq!(
+        <List<u64, U8>>::generalized_index(&[PathElement::Index(5)]),
+        Ok(2 * 2 + 1)
+    );
+    assert_eq!(
+        <Vector<u64, U8>>::generalized_index(&[PathElement::Index(3)]),
+        Ok(2)
+    );
+}
+
+#[test]
+fn invalid_paths() {
+    assert_eq!(
+        BeaconState::generalized_index(&[PathElement::Field("unknown")]),
+        Err(ProofError::UnknownField("unknown".to_owned()))
+    );
+    assert_eq!(
+        BeaconState::generalized_index(&[PathElement::Index(0)]),
+        Err(ProofError::InvalidPathElement("Index(0)".to_owned()))
+    );
+    assert_eq!(
+        <List<u64, U8>>::generalized_index(&[PathElement::Index(8)]),
+        Err(ProofError::IndexOutOfBounds { index: 8, limit: 8 })
+    );
+    assert!(matches!(
+        <List<u64, U8>>::generalized_index(&[PathElement::Index(0), PathElement::Length]),
+        Err(ProofError::InvalidPathElement(_))
+    ));
+}
+
+#[test]
+fn concat() {
+    assert_eq!(concat_generalized_indices(&[52, 3]), Ok(105));
+    assert_eq!(concat_generalized_indices(&[1, 1, 5]), Ok(5));
+    assert_eq!(
+        concat_generalized_indices(&[1 << 40, 1 << 40]),
+        Err(ProofError::GeneralizedIndexOverflow)
+    );
+}
+
+#[test]
+fn generalized_index_length() {
+    assert_eq!(get_generalized_index_length(1), Ok(0));
+    assert_eq!(get_generalized_index_length(105), Ok(6));
+    assert_eq!(
+        get_generalized_index_length(0),
+        Err(ProofError::InvalidGeneralizedIndex(0))
+    );
+}
+
+#[test]
+fn finalized_root_proof() {
+    let state = beacon_state();
+    let root = state.tree_hash_root();
+
+    let gindex = BeaconState::generalized_index(&[
+        PathElement::Field("finalized_checkpoint"),
+        PathElement::Field("root"),
+    ])
+    .unwrap();
+
+    let proof = merkle_proof(&state, gindex).unwrap();
+
+    assert_eq!(proof.len(), 6);
+    assert_eq!(state.tree_hash_node(gindex), Ok(h256(13)));
+    assert!(verify_merkle_proof(&h256(13), &proof, gindex, &root));
+    assert!(!verify_merkle_proof(&h256(12), &proof, gindex, &root));
+    assert!(!verify_merkle_proof(&h256(13), &proof, gindex + 1, &root));
+    assert_eq!(
+        calculate_merkle_root(&h256(13), &proof[1..], gindex),
+        Err(ProofError::InvalidProofLength {
+            expected: 6,
+            found: 5
+        })
+    );
+}
+
+#[test]
+fn list_element_proofs() {
+    let state = beacon_state();
+    let root = state.tree_hash_root();
+
+    let proofs = [
+        (
+            vec![
+                PathElement::Field("validators"),
+                PathElement::Index(1),
+                PathElement::Field("effective_balance"),
+            ],
+            31_000_000_000_u64.tree_hash_root(),
+        ),
+        (
+            vec![PathElement::Field("validators"), PathElement::Index(1)],
+            state.validators[1].tree_hash_root(),
+        ),
+        (
+            vec![PathElement::Field("validators"), PathElement::Length],
+            2_usize.tree_hash_root(),
+        ),
+        (
+            vec![PathElement::Field("block_roots"), PathElement::Index(7)],
+            h256(3),
+        ),
+    ];
+
+    for (path, leaf) in proofs {
+        let gindex = BeaconState::generalized_index(&path).unwrap();
+        let proof = merkle_proof(&state, gindex).unwrap();
+
+        assert!(
+            verify_merkle_proof(&leaf, &proof, gindex, &root),
+            "{path:?}"
+        );
+    }
+
+    // the chunk containing balances 4..8 (of which only 4 is set)
+    let gindex =
+        BeaconState::generalized_index(&[PathElement::Field("balances"), PathElement::Index(4)])
+            .unwrap();
+    let proof = merkle_proof(&state, gindex).unwrap();
+
+    assert!(verify_merkle_proof(
+        &3_u64.tree_hash_root(),
+        &proof,
+        gindex,
+        &root
+    ));
+
+    // empty leaves are zero hashes
+    let gindex = BeaconState::generalized_index(&[
+        PathElement::Field("validators"),
+        PathElement::Index(1 << 39),
+    ])
+    .unwrap();
+    let proof = merkle_proof(&state, gindex).unwrap();
+
+    assert!(verify_merkle_proof(&H256::default(), &proof, gindex, &root));
+}
+
+#[test]
+fn multiproof() {
+    let state = beacon_state();
+    let root = state.tree_hash_root();
+
+    let paths = [
+        &[
+            PathElement::Field("finalized_checkpoint"),
+            PathElement::Field("root"),
+        ][..],
+        &[PathElement::Field("current_sync_committee")],
+        &[PathElement::Field("next_sync_committee")],
+        &[PathElement::Field("slot")],
+    ];
+
+    let indices = paths
+        .iter()
+        .map(|path| BeaconState::generalized_index(path).unwrap())
+        .collect::<Vec<_>>();
+
+    let leaves = [
+        h256(13),
+        state.current_sync_committee.tree_hash_root(),
+        state.next_sync_committee.tree_hash_root(),
+        state.slot.tree_hash_root(),
+    ];
+
+    let proof = merkle_multiproof(&state, &indices).unwrap();
+
+    assert_eq!(proof.len(), get_helper_indices(&indices).len());
+    assert!(verify_merkle_multiproof(&leaves, &proof, &indices, &root));
+
+    let mut invalid_leaves = leaves;
+    invalid_leaves[3] = 0_u64.tree_hash_root();
+
+    assert!(!verify_merkle_multiproof(
+        &invalid_leaves,
+        &proof,
+        &indices,
+        &root
+    ));
+    assert!(!verify_merkle_multiproof(
+        &leaves[1..],
+        &proof,
+        &indices,
+        &root
+    ));
+}
+
+#[test]
+fn helper_indices() {
+    // https://github.com/ethereum/consensus-specs/blob/dev/ssz/merkle-proofs.md#merkle-multiproofs
+    assert_eq!(get_helper_indices(&[8, 9, 14]), [15, 6, 5]);
+    assert_eq!(get_helper_indices(&[5]), [4, 3]);
+}
@@ -0,0 +1,314 @@
+use std::num::NonZeroUsize;
+
+use hex_literal::hex;
+use ssz::{
+    merkle_proof::{
+        concat_generalized_indices, merkle_proof, verify_merkle_proof, PathElement, ProofError,
+    },
+    tree_hash::{hash_concat, merkle_root},
+    types::{typenum::U4, List, Profile, StableContainer},
+    Ssz, H256,
+};
+
+// The consensus spec tests pinned in the flake (v1.4.0) do not contain any EIP-7495 vectors, so
+// these tests are the only coverage for `StableContainer` and `Profile`.
+
+// https://eips.ethereum.org/EIPS/eip-7495#profile
+#[derive(Debug, Clone, PartialEq, Ssz)]
+#[ssz(stable_container(4))]
+struct Shape {
+    side: Option<u16>,
+    color: Option<u8>,
+    radius: Option<u16>,
+}
+
+#[derive(Debug, Clone, PartialEq, Ssz)]
+#[ssz(profile(Shape))]
+struct Square {
+    side: u16,
+    color: u8,
+}
+
+#[derive(Debug, Clone, PartialEq, Ssz)]
+#[ssz(profile(Shape))]
+struct Circle {
+    color: u8,
+    radius: Option<u16>,
+}
+
+#[derive(Debug, Clone, PartialEq, Ssz)]
+#[ssz(stable_container(8))]
+struct Variable {
+    a: Option<u8>,
+    b: Option<List<u16, U4>>,
+    c: Option<u32>,
+}
+
+#[test]
+fn traits() {
+    assert_eq!(<Shape as StableContainer>::MAX_FIELDS, 4);
+    assert_eq!(
+        <Shape as StableContainer>::FIELDS,
+        ["side", "color", "radius"]
+    );
+    assert_eq!(<Square as Profile>::BASE_INDICES, [0, 1]);
+    assert_eq!(<Circle as Profile>::BASE_INDICES, [1, 2]);
+
+    assert_eq!(Shape::SSZ_FIXED_LEN, None);
+    assert_eq!(Square::SSZ_FIXED_LEN, NonZeroUsize::new(3));
+    assert_eq!(Circle::SSZ_FIXED_LEN, None);
+}
+
+#[test]
+fn encoding() {
+    let values = [
+        (
+            Shape {
+                side: Some(0x42),
+                color: Some(1),
+                radius: None,
+            }
+            .as_ssz_bytes(),
+            hex!("03420001").to_vec(),
+        ),
+        (
+            Square {
+                side: 0x42,
+                color: 1,
+            }
+            .as_ssz_bytes(),
+            hex!("420001").to_vec(),
+        ),
+        (
+            Shape {
+                side: None,
+                color: Some(1),
+                radius: Some(0x42),
+            }
+            .as_ssz_bytes(),
+            hex!("06014200").to_vec(),
+        ),
+        (
+            Circle {
+                color: 1,
+                radius: Some(0x42),
+            }
+            .as_ssz_bytes(),
+            hex!("01014200").to_vec(),
+        ),
+        (
+            Circle {
+                color: 1,
+                radius: None,
+            }
+            .as_ssz_bytes(),
+            hex!("0001").to_vec(),
+        ),
+    ];
+
+    for (encoded, expected) in values {
+        assert_eq!(encoded, expected);
+    }
+}
+
+#[test]
+fn round_trip() {
+    let shape = Shape {
+        side: None,
+        color: Some(1),
+        radius: Some(0x42),
+    };
+    assert_eq!(Shape::from_ssz_bytes(&shape.as_ssz_bytes()), Ok(shape));
+
+    let square = Square {
+        side: 0x42,
+        color: 1,
+    };
+    assert_eq!(Square::from_ssz_bytes(&square.as_ssz_bytes()), Ok(square));
+
+    for radius in [None, Some(0x42)] {
+        let circle = Circle { color: 1, radius };
+        assert_eq!(Circle::from_ssz_bytes(&circle.as_ssz_bytes()), Ok(circle));
+    }
+
+    let variable = Variable {
+        a: Some(7),
+        b: Some(vec![1, 2, 3].try_into().unwrap()),
+        c: None,
+    };
+    let encoded = variable.as_ssz_bytes();
+
+    assert_eq!(encoded, hex!("030705000000010002000300"));
+    assert_eq!(variable.ssz_bytes_len().get(), encoded.len());
+    assert_eq!(Variable::from_ssz_bytes(&encoded), Ok(variable));
+
+    let empty = Variable {
+        a: None,
+        b: None,
+        c: None,
+    };
+    assert_eq!(empty.as_ssz_bytes(), [0]);
+    assert_eq!(Variable::from_ssz_bytes(&[0]), Ok(empty));
+}
+
+#[test]
+fn invalid_active_fields() {
+    // only 3 of the 4 fields of shape are defined
+    assert!(Shape::from_ssz_bytes(&hex!("08")).is_err());
+    // circle only has one optional field
+    assert!(Circle::from_ssz_bytes(&hex!("02014200")).is_err());
+    // missing data for an active field
+    assert!(Shape::from_ssz_bytes(&hex!("0342")).is_err());
+    // extra data for an inactive field
+    assert!(Shape::from_ssz_bytes(&hex!("0242000100")).is_err());
+    assert!(Shape::from_ssz_bytes(&[]).is_err());
+    assert!(Square::from_ssz_bytes(&hex!("42000100")).is_err());
+}
+
+#[test]
+fn tree_hash_root() {
+    let shape = Shape {
+        side: Some(0x42),
+        color: Some(1),
+        radius: None,
+    };
+
+    let mut data = [0; 4 * 32];
+    data[0] = 0x42;
+    data[32] = 1;
+
+    assert_eq!(
+        shape.tree_hash_root(),
+        hash_concat(merkle_root(&data, 4).get(), merkle_root(&[0b011], 1).get())
+    );
+
+    assert_eq!(
+        shape.tree_hash_root(),
+        Square {
+            side: 0x42,
+            color: 1,
+        }
+        .tree_hash_root()
+    );
+
+    assert_eq!(
+        Shape {
+            side: None,
+            color: Some(1),
+            radius: Some(0x42),
+        }
+        .tree_hash_root(),
+        Circle {
+            color: 1,
+            radius: Some(0x42),
+        }
+        .tree_hash_root()
+    );
+
+    assert_eq!(
+        Shape {
+            side: None,
+            color: Some(1),
+            radius: None,
+        }
+        .tree_hash_root(),
+        Circle {
+            color: 1,
+            radius: None,
+        }
+        .tree_hash_root()
+    );
+}
+
+#[test]
+fn gindices() {
+    // the data root is the left child of the root, mixed in with the active fields
+    for (name, gindex) in [("side", 8), ("color", 9), ("radius", 10)] {
+        assert_eq!(
+            Shape::generalized_index(&[PathElement::Field(name)]),
+            Ok(gindex)
+        );
+    }
+
+    // gindices are stable across profiles
+    assert_eq!(
+        Square::generalized_index(&[PathElement::Field("color")]),
+        Ok(9)
+    );
+    assert_eq!(
+        Circle::generalized_index(&[PathElement::Field("radius")]),
+        Ok(10)
+    );
+
+    assert_eq!(
+        Circle::generalized_index(&[PathElement::Field("side")]),
+        Err(ProofError::UnknownField("side".to_owned()))
+    );
+
+    // 8 leaves
+    assert_eq!(
+        Variable::generalized_index(&[PathElement::Field("b"), PathElement::Length]),
+        concat_generalized_indices(&[2, 9, 3])
+    );
+}
+
+#[test]
+fn proofs() {
+    let circle = Circle {
+        color: 1,
+        radius: Some(0x42),
+    };
+    let root = circle.tree_hash_root();
+
+    let gindex = Circle::generalized_index(&[PathElement::Field("radius")]).unwrap();
+    let proof = merkle_proof(&circle, gindex).unwrap();
+
+    assert!(verify_merkle_proof(
+        &0x42_u16.tree_hash_root(),
+        &proof,
+        gindex,
+        &root
+    ));
+
+    // the active fields
+    let proof = merkle_proof(&circle, 3).unwrap();
+
+    assert!(verify_merkle_proof(
+        &H256::new(hex!(
+            "0600000000000000000000000000000000000000000000000000000000000000"
+        )),
+        &proof,
+        3,
+        &root
+    ));
+
+    // fields of the base that are not in the profile are zero
+    assert_eq!(circle.tree_hash_node(8), Ok(H256::default()));
+
+    let variable = Variable {
+        a: None,
+        b: Some(vec![1, 2, 3].try_into().unwrap()),
+        c: None,
+    };
+    let gindex =
+        Variable::generalized_index(&[PathElement::Field("b"), PathElement::Length]).unwrap();
+    let proof = merkle_proof(&variable, gindex).unwrap();
+
+    assert!(verify_merkle_proof(
+        &3_usize.tree_hash_root(),
+        &proof,
+        gindex,
+        &variable.tree_hash_root()
+    ));
+
+    // inactive fields can't be descended into
+    assert_eq!(
+        Variable {
+            a: None,
+            b: None,
+            c: None,
+        }
+        .tree_hash_node(gindex),
+        Err(ProofError::InvalidGeneralizedIndex(gindex))
+    );
+}
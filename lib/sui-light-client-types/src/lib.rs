@@ -10,6 +10,7 @@ pub mod consensus_state;
 pub mod crypto;
 pub mod digest;
 pub mod header;
+pub mod misbehaviour;
 pub mod object;
 pub mod storage_proof;
 pub mod transaction;
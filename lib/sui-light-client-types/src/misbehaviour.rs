@@ -0,0 +1,11 @@
+use crate::header::Header;
+
+/// Two different checkpoint summaries with the same sequence number, each certified by the
+/// committee of its epoch.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+pub struct Misbehaviour {
+    pub header_a: Header,
+    pub header_b: Header,
+}
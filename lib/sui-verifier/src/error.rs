@@ -18,6 +18,9 @@ pub enum Error {
     #[error("an effect to object ({0}) not found in the given effects")]
     EffectNotFound(ObjectID),
 
+    #[error("the given effects ({0}) are not included in the checkpoint contents")]
+    EffectsNotInCheckpoint(Digest),
+
     #[error(
         "proven object's digest ({proven}) does not match the given object's digest ({given})"
     )]
@@ -134,17 +134,30 @@ pub fn verify_membership(
         }
     }
 
-    // STEP 4: find the effect digest in `checkpoint_contents.transactions` to verify it exists
-    let CheckpointContents::V1(checkpoint_contents) = checkpoint_contents;
+    // STEP 4 & 5: verify that `effects` are included in the checkpoint
+    verify_effects_in_checkpoint(&effects, &checkpoint_contents, contents_digest)
+}
+
+/// Verify that `effects` are included in `checkpoint_contents`, and that the digest of
+/// `checkpoint_contents` is `contents_digest`, which is verified previously by the client.
+fn verify_effects_in_checkpoint(
+    effects: &TransactionEffects,
+    checkpoint_contents: &CheckpointContents,
+    contents_digest: Digest,
+) -> Result<(), Error> {
+    // find the effect digest in `checkpoint_contents.transactions` to verify it exists
+    let CheckpointContents::V1(checkpoint_contents_v1) = checkpoint_contents;
     let effects_digest = effects.digest();
-    let _ = checkpoint_contents
+    if !checkpoint_contents_v1
         .transactions
         .iter()
-        .find(|e| e.effects == effects_digest)
-        .expect("execution digests do not contain the given effect");
+        .any(|e| e.effects == effects_digest)
+    {
+        return Err(Error::EffectsNotInCheckpoint(effects_digest));
+    }
 
-    // STEP 5: compare the digest of `checkpoint_contents` with the `contents_digest` which is verified previously by the client
-    let calc_contents_digest = CheckpointContents::V1(checkpoint_contents.clone()).digest();
+    // compare the digest of `checkpoint_contents` with the `contents_digest`
+    let calc_contents_digest = checkpoint_contents.digest();
     if contents_digest != calc_contents_digest {
         return Err(Error::ContentsDigestMismatch {
             given: contents_digest,
@@ -196,3 +209,88 @@ pub fn calculate_dynamic_field_object_id(parent: [u8; 32], key_bytes: &[u8]) ->
 
     ObjectID::new(hash.into())
 }
+
+#[cfg(test)]
+mod tests {
+    use sui_light_client_types::{
+        checkpoint_summary::{CheckpointContentsV1, ExecutionDigests, GasCostSummary},
+        transaction_effects::{ExecutionStatus, TransactionEffectsV1},
+        Owner, U64,
+    };
+    use unionlabs_primitives::FixedBytes;
+
+    use super::*;
+
+    fn digest(b: u8) -> Digest {
+        Digest(FixedBytes::new([b; 32]))
+    }
+
+    fn effects() -> TransactionEffects {
+        TransactionEffects::V1(Box::new(TransactionEffectsV1 {
+            status: ExecutionStatus::Success,
+            executed_epoch: 1,
+            gas_used: GasCostSummary {
+                computation_cost: U64(0),
+                storage_cost: U64(0),
+                storage_rebate: U64(0),
+                non_refundable_storage_fee: U64(0),
+            },
+            modified_at_versions: vec![],
+            shared_objects: vec![],
+            transaction_digest: digest(1),
+            created: vec![],
+            mutated: vec![],
+            unwrapped: vec![],
+            deleted: vec![],
+            unwrapped_then_deleted: vec![],
+            wrapped: vec![],
+            gas_object: (
+                (ObjectID::new([2; 32]), 1, digest(3)),
+                Owner::AddressOwner(Default::default()),
+            ),
+            events_digest: None,
+            dependencies: vec![],
+        }))
+    }
+
+    fn checkpoint_contents(effects: Digest) -> CheckpointContents {
+        CheckpointContents::V1(CheckpointContentsV1 {
+            transactions: vec![ExecutionDigests {
+                transaction: digest(1),
+                effects,
+            }],
+            user_signatures: vec![vec![]],
+        })
+    }
+
+    #[test]
+    fn effects_in_checkpoint() {
+        let effects = effects();
+        let checkpoint_contents = checkpoint_contents(effects.digest());
+
+        verify_effects_in_checkpoint(&effects, &checkpoint_contents, checkpoint_contents.digest())
+            .unwrap();
+    }
+
+    #[test]
+    fn effects_not_in_checkpoint() {
+        let effects = effects();
+        let checkpoint_contents = checkpoint_contents(digest(4));
+
+        assert!(matches!(
+            verify_effects_in_checkpoint(&effects, &checkpoint_contents, checkpoint_contents.digest()),
+            Err(Error::EffectsNotInCheckpoint(d)) if d == effects.digest()
+        ));
+    }
+
+    #[test]
+    fn checkpoint_contents_digest_mismatch() {
+        let effects = effects();
+        let checkpoint_contents = checkpoint_contents(effects.digest());
+
+        assert!(matches!(
+            verify_effects_in_checkpoint(&effects, &checkpoint_contents, digest(5)),
+            Err(Error::ContentsDigestMismatch { .. })
+        ));
+    }
+}
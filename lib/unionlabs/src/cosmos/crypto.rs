@@ -6,6 +6,7 @@ use crate::google::protobuf::any::Any;
 
 pub mod bn254;
 pub mod ed25519;
+pub mod ethsecp256k1;
 pub mod multisig;
 pub mod secp256k1;
 pub mod secp256r1;
@@ -15,6 +16,7 @@ pub enum AnyPubKey {
     Bn254(Any<bn254::PubKey>),
     Ed25519(Any<ed25519::PubKey>),
     Secp256k1(Any<secp256k1::PubKey>),
+    EthSecp256k1(ethsecp256k1::PubKey),
 }
 
 impl AnyPubKey {
@@ -39,9 +41,9 @@ impl AnyPubKey {
 
 #[cfg(feature = "proto")]
 pub mod proto {
-    use super::{bn254, ed25519, secp256k1, AnyPubKey};
+    use super::{bn254, ed25519, ethsecp256k1, secp256k1, AnyPubKey};
     use crate::{
-        encoding::{DecodeAs, Proto},
+        encoding::{DecodeAs, EncodeAs, Proto},
         google::protobuf::any::Any,
         impl_proto_via_try_from_into,
         primitives::FixedBytesError,
@@ -84,6 +86,19 @@ pub mod proto {
                     .map(Any)
                     .map(Self::Secp256k1)
                     .map_err(TryFromAnyPubKeyError::TryFromProto)
+            } else if value
+                .type_url
+                .ends_with(ethsecp256k1::PubKey::TYPE_URL_SUFFIX)
+            {
+                // the ethsecp256k1 message has the same shape as the secp256k1 one
+                secp256k1::PubKey::decode_as::<Proto>(&value.value)
+                    .map(|secp256k1::PubKey { key }| {
+                        Self::EthSecp256k1(ethsecp256k1::PubKey {
+                            type_url: value.type_url,
+                            key,
+                        })
+                    })
+                    .map_err(TryFromAnyPubKeyError::TryFromProto)
             } else {
                 Err(TryFromAnyPubKeyError::InvalidTypeUrl {
                     found: value.type_url,
@@ -91,6 +106,7 @@ pub mod proto {
                         bn254::PubKey::type_url(),
                         ed25519::PubKey::type_url(),
                         secp256k1::PubKey::type_url(),
+                        format!("*{}", ethsecp256k1::PubKey::TYPE_URL_SUFFIX),
                     ],
                 })
             }
@@ -103,6 +119,10 @@ pub mod proto {
                 AnyPubKey::Bn254(key) => key.into(),
                 AnyPubKey::Ed25519(key) => key.into(),
                 AnyPubKey::Secp256k1(key) => key.into(),
+                AnyPubKey::EthSecp256k1(ethsecp256k1::PubKey { type_url, key }) => Self {
+                    type_url,
+                    value: secp256k1::PubKey { key }.encode_as::<Proto>(),
+                },
             }
         }
     }
@@ -125,3 +145,32 @@ impl From<Any<secp256k1::PubKey>> for AnyPubKey {
         Self::Secp256k1(value)
     }
 }
+
+impl From<ethsecp256k1::PubKey> for AnyPubKey {
+    fn from(value: ethsecp256k1::PubKey) -> Self {
+        Self::EthSecp256k1(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hex_literal::hex;
+
+    use super::*;
+    use crate::test_utils::assert_proto_roundtrip;
+
+    #[test]
+    fn ethsecp256k1_proto_roundtrip() {
+        for type_url in [
+            ethsecp256k1::PubKey::ETHERMINT_TYPE_URL,
+            "/cosmos.evm.crypto.v1.ethsecp256k1.PubKey",
+            "/injective.crypto.v1beta1.ethsecp256k1.PubKey",
+        ] {
+            assert_proto_roundtrip(&AnyPubKey::EthSecp256k1(ethsecp256k1::PubKey {
+                type_url: type_url.to_owned(),
+                key: hex!("02a1633cafcc01ebfb6d78e39f687a1f0995c62fc95f51ead10a02ee0be551b5dc")
+                    .into(),
+            }));
+        }
+    }
+}
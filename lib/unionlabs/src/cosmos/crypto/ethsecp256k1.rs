@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+use crate::primitives::{encoding::Base64, FixedBytes};
+
+/// A secp256k1 public key of an ethermint-style account, the address of which is derived the same
+/// way as an ethereum address.
+///
+/// Every chain defines this message in its own protobuf package (i.e.
+/// `ethermint.crypto.v1.ethsecp256k1`, `cosmos.evm.crypto.v1.ethsecp256k1`,
+/// `injective.crypto.v1beta1.ethsecp256k1`), so the type url is stored alongside the key. The
+/// message itself is identical to [`secp256k1::PubKey`](super::secp256k1::PubKey).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PubKey {
+    pub type_url: String,
+    pub key: FixedBytes<33, Base64>,
+}
+
+impl PubKey {
+    /// The suffix shared by the type urls of all `ethsecp256k1` public keys.
+    pub const TYPE_URL_SUFFIX: &'static str = ".ethsecp256k1.PubKey";
+
+    /// The type url used by ethermint and most chains derived from it.
+    pub const ETHERMINT_TYPE_URL: &'static str = "/ethermint.crypto.v1.ethsecp256k1.PubKey";
+}
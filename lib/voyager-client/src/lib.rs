@@ -1,4 +1,4 @@
-use std::{fmt::Debug, future::Future};
+use std::{fmt::Debug, future::Future, time::Duration};
 
 use jsonrpsee::{
     core::{
@@ -11,7 +11,7 @@ use jsonrpsee::{
 };
 use serde::de::DeserializeOwned;
 use serde_json::{json, Value};
-use tracing::instrument;
+use tracing::{debug, instrument, warn};
 use unionlabs::{ibc::core::client::height::Height, primitives::Bytes, ErrorReporter};
 use voyager_primitives::{
     ChainId, ClientInfo, ClientStateMeta, ClientType, ConsensusStateMeta, IbcInterface, IbcQuery,
@@ -24,7 +24,7 @@ use voyager_rpc::{
     },
     VoyagerRpcClient, FATAL_JSONRPC_ERROR_CODE, MISSING_STATE_ERROR_CODE,
 };
-use voyager_types::RawClientId;
+use voyager_types::{IbcProof, ProofVerification, RawClientId};
 
 #[derive(Debug, Clone)]
 pub struct VoyagerClient<C: ClientT>(C);
@@ -313,6 +313,120 @@ impl<C: VoyagerRpcClient> VoyagerClient<C> {
         Ok(ibc_proof)
     }
 
+    pub async fn verify_ibc_proof<P: IbcStorePathKey>(
+        &self,
+        chain_id: ChainId,
+        path: P,
+        value: Option<Bytes>,
+        proof: Value,
+        root: Bytes,
+    ) -> RpcResult<ProofVerification> {
+        self.0
+            .verify_ibc_proof(
+                chain_id,
+                P::Spec::ID,
+                serde_json::to_value(<P::Spec as IbcSpec>::StorePath::from(path.into())).unwrap(),
+                value,
+                proof,
+                root,
+            )
+            .await
+            .map_err(json_rpc_error_to_error_object)
+    }
+
+    /// Query a proof of `path` on `chain_id` at `height`, and verify it locally against the state
+    /// root stored in the consensus state of `client_id` on `counterparty_chain_id` at `height`.
+    /// The proof must prove `value`, the raw value expected to be stored at `path` (or its absence
+    /// if `value` is `None`). Proofs that fail verification are refetched up to `retries` times
+    /// before giving up.
+    ///
+    /// Verification is skipped if the consensus state does not exist yet (i.e. if the update to
+    /// `height` is submitted in the same transaction as the proof), if the client module does not
+    /// expose the state root, or if the proof module does not support verification.
+    #[allow(clippy::too_many_arguments)]
+    #[instrument(skip_all, fields(%chain_id, %height, %counterparty_chain_id, %client_id))]
+    pub async fn query_verified_ibc_proof<P: IbcStorePathKey>(
+        &self,
+        chain_id: ChainId,
+        height: Height,
+        path: P,
+        value: Option<Bytes>,
+        counterparty_chain_id: ChainId,
+        client_id: <P::Spec as IbcSpec>::ClientId,
+        retries: u32,
+    ) -> RpcResult<IbcProof> {
+        let root = self
+            .maybe_consensus_state_meta::<P::Spec>(
+                counterparty_chain_id,
+                QueryHeight::Latest,
+                client_id,
+                height,
+            )
+            .await?
+            .and_then(|consensus_state_meta| consensus_state_meta.root);
+
+        let Some(root) = root else {
+            debug!("no state root available at the proof height, not verifying proof");
+
+            return self
+                .query_ibc_proof(chain_id, QueryHeight::Specific(height), path)
+                .await?
+                .into_result();
+        };
+
+        let mut attempt = 0;
+
+        loop {
+            let proof = self
+                .query_ibc_proof(
+                    chain_id.clone(),
+                    QueryHeight::Specific(height),
+                    path.clone(),
+                )
+                .await?
+                .into_result()?;
+
+            match self
+                .verify_ibc_proof(
+                    chain_id.clone(),
+                    path.clone(),
+                    value.clone(),
+                    proof.proof.clone(),
+                    root.clone(),
+                )
+                .await?
+            {
+                ProofVerification::Valid => return Ok(proof),
+                ProofVerification::Unsupported => {
+                    debug!("proof module does not support proof verification");
+
+                    return Ok(proof);
+                }
+                ProofVerification::Invalid { reason } if attempt < retries => {
+                    attempt += 1;
+
+                    warn!(%reason, attempt, "proof failed local verification, refetching");
+
+                    tokio::time::sleep(Duration::from_secs(attempt.into())).await;
+                }
+                ProofVerification::Invalid { reason } => {
+                    return Err(ErrorObject::owned(
+                        -1,
+                        format!(
+                            "proof failed local verification after {attempt} retries: {reason}"
+                        ),
+                        Some(json!({
+                            "chain_id": chain_id,
+                            "height": height,
+                            "path": <P::Spec as IbcSpec>::StorePath::from(path.into()),
+                            "root": root,
+                        })),
+                    ));
+                }
+            }
+        }
+    }
+
     pub async fn equivalent_chain_ids(&self, chain_id: ChainId) -> RpcResult<Vec<ChainId>> {
         self.0
             .equivalent_chain_ids(chain_id)
@@ -626,3 +740,181 @@ impl<C: ClientT> ClientT for VoyagerPluginClient<'_, C> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::VecDeque,
+        num::NonZeroU32,
+        sync::{
+            atomic::{AtomicU32, Ordering},
+            Mutex,
+        },
+    };
+
+    use ibc_union_spec::{path::BatchPacketsPath, ClientId};
+    use jsonrpsee::core::client::Error;
+    use unionlabs::primitives::H256;
+    use voyager_types::ProofType;
+
+    use super::*;
+
+    /// A [`ClientT`] that answers the calls made by [`VoyagerClient::query_verified_ibc_proof`],
+    /// returning the queued verification results in order.
+    struct MockClient {
+        root: Option<Bytes>,
+        verifications: Mutex<VecDeque<ProofVerification>>,
+        proof_queries: AtomicU32,
+        verify_calls: AtomicU32,
+    }
+
+    impl MockClient {
+        fn new(
+            root: Option<Bytes>,
+            verifications: impl IntoIterator<Item = ProofVerification>,
+        ) -> Self {
+            Self {
+                root,
+                verifications: Mutex::new(verifications.into_iter().collect()),
+                proof_queries: AtomicU32::new(0),
+                verify_calls: AtomicU32::new(0),
+            }
+        }
+
+        fn respond(&self, method: &str) -> Value {
+            match method {
+                "voyager_consensusStateMeta" => serde_json::to_value(ConsensusStateMeta {
+                    timestamp: Timestamp::from_nanos(1),
+                    root: self.root.clone(),
+                })
+                .unwrap(),
+                "voyager_queryIbcProof" => {
+                    let attempt = self.proof_queries.fetch_add(1, Ordering::SeqCst);
+
+                    serde_json::to_value(IbcProofResponse::Proof(IbcProof {
+                        proof_type: ProofType::Membership,
+                        height: Height::new(1),
+                        proof: json!(attempt),
+                    }))
+                    .unwrap()
+                }
+                "voyager_verifyIbcProof" => {
+                    self.verify_calls.fetch_add(1, Ordering::SeqCst);
+
+                    serde_json::to_value(
+                        self.verifications
+                            .lock()
+                            .unwrap()
+                            .pop_front()
+                            .expect("unexpected verification"),
+                    )
+                    .unwrap()
+                }
+                _ => panic!("unexpected method {method}"),
+            }
+        }
+    }
+
+    impl ClientT for MockClient {
+        async fn notification<Params>(&self, _: &str, _: Params) -> Result<(), Error>
+        where
+            Params: ToRpcParams + Send,
+        {
+            unimplemented!()
+        }
+
+        async fn request<R, Params>(&self, method: &str, _: Params) -> Result<R, Error>
+        where
+            R: DeserializeOwned,
+            Params: ToRpcParams + Send,
+        {
+            Ok(serde_json::from_value(self.respond(method))?)
+        }
+
+        async fn batch_request<'a, R>(
+            &self,
+            _: BatchRequestBuilder<'a>,
+        ) -> Result<BatchResponse<'a, R>, Error>
+        where
+            R: DeserializeOwned + Debug + 'a,
+        {
+            unimplemented!()
+        }
+    }
+
+    fn invalid() -> ProofVerification {
+        ProofVerification::Invalid {
+            reason: "invalid".to_owned(),
+        }
+    }
+
+    async fn query(client: &VoyagerClient<MockClient>, retries: u32) -> RpcResult<IbcProof> {
+        client
+            .query_verified_ibc_proof(
+                ChainId::new("chain"),
+                Height::new(1),
+                BatchPacketsPath {
+                    batch_hash: H256::new([1; 32]),
+                },
+                Some(b"value".into()),
+                ChainId::new("counterparty"),
+                ClientId::new(NonZeroU32::MIN),
+                retries,
+            )
+            .await
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn refetches_invalid_proofs() {
+        let client = VoyagerClient::new(MockClient::new(
+            Some(b"root".into()),
+            [invalid(), invalid(), ProofVerification::Valid],
+        ));
+
+        let proof = query(&client, 2).await.unwrap();
+
+        // the proof from the third query is returned
+        assert_eq!(proof.proof, json!(2));
+        assert_eq!(client.0.proof_queries.load(Ordering::SeqCst), 3);
+        assert_eq!(client.0.verify_calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn fails_after_retries() {
+        let client = VoyagerClient::new(MockClient::new(
+            Some(b"root".into()),
+            [invalid(), invalid(), invalid()],
+        ));
+
+        let err = query(&client, 2).await.unwrap_err();
+
+        assert_eq!(
+            err.message(),
+            "proof failed local verification after 2 retries: invalid"
+        );
+        assert_eq!(client.0.proof_queries.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn unsupported_verification() {
+        let client = VoyagerClient::new(MockClient::new(
+            Some(b"root".into()),
+            [ProofVerification::Unsupported],
+        ));
+
+        query(&client, 2).await.unwrap();
+
+        assert_eq!(client.0.proof_queries.load(Ordering::SeqCst), 1);
+        assert_eq!(client.0.verify_calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn no_root() {
+        let client = VoyagerClient::new(MockClient::new(None, []));
+
+        query(&client, 2).await.unwrap();
+
+        assert_eq!(client.0.proof_queries.load(Ordering::SeqCst), 1);
+        assert_eq!(client.0.verify_calls.load(Ordering::SeqCst), 0);
+    }
+}
@@ -34,7 +34,9 @@ use voyager_message::{
     PluginMessage, VoyagerMessage,
 };
 use voyager_plugin_protocol::{
-    coordinator_server, worker_child_process, WithId, WorkerClient, INVALID_CONFIG_EXIT_CODE,
+    coordinator_server,
+    record::{Recorder, Replay},
+    worker_child_process, WithId, WorkerClient, INVALID_CONFIG_EXIT_CODE,
 };
 use voyager_primitives::{ClientInfo, IbcSpec, QueryHeight};
 use voyager_rpc::{
@@ -98,6 +100,8 @@ impl Engine<InMemoryQueue<VoyagerMessage>> {
             rest_laddr: default_rest_laddr(),
             rpc_laddr: default_rpc_laddr(),
             optimizer_delay_milliseconds: default_optimizer_delay_milliseconds(),
+            recorder: None,
+            replay: None,
             queue_config: (),
         }
     }
@@ -225,8 +229,7 @@ impl<Q: Queue<VoyagerMessage>> Engine<Q> {
                                     .get()
                                     .unwrap()
                                     .plugin(&plugin_name)
-                                    .expect("plugin exists")
-                                    .client(),
+                                    .expect("plugin exists"),
                             );
 
                             loop {
@@ -303,6 +306,8 @@ pub struct EngineBuilder<Q: Queue<VoyagerMessage> = InMemoryQueue<VoyagerMessage
     rest_laddr: SocketAddr,
     rpc_laddr: SocketAddr,
     optimizer_delay_milliseconds: u64,
+    recorder: Option<Recorder>,
+    replay: Option<Replay>,
 }
 
 impl<Q: Queue<VoyagerMessage>> EngineBuilder<Q> {
@@ -370,6 +375,22 @@ impl<Q: Queue<VoyagerMessage>> EngineBuilder<Q> {
         }
     }
 
+    /// Record all traffic between voyager and the plugins and modules.
+    pub fn with_recorder(self, recorder: Recorder) -> Self {
+        Self {
+            recorder: Some(recorder),
+            ..self
+        }
+    }
+
+    /// Replace all modules with the provided recording. Plugins are still run as normal.
+    pub fn with_replay(self, replay: Replay) -> Self {
+        Self {
+            replay: Some(replay),
+            ..self
+        }
+    }
+
     pub fn register_ibc_spec_handler<S: IbcSpec>(mut self) -> Self {
         self.ibc_spec_handlers.register::<S>();
         self
@@ -392,6 +413,8 @@ impl<Q: Queue<VoyagerMessage>> EngineBuilder<Q> {
             rest_laddr: self.rest_laddr,
             rpc_laddr: self.rpc_laddr,
             optimizer_delay_milliseconds: self.optimizer_delay_milliseconds,
+            recorder: self.recorder,
+            replay: self.replay,
         }
     }
 }
@@ -445,8 +468,13 @@ impl<Q: Queue<VoyagerMessage>> EngineBuilder<Q> {
                 debug!("starting rpc server for plugin {}", plugin_info.name);
 
                 tokio::spawn(
-                    coordinator_server(&plugin_info.name, server, logger_middleware_layer.clone())
-                        .await?,
+                    coordinator_server(
+                        &plugin_info.name,
+                        server,
+                        self.recorder.clone(),
+                        logger_middleware_layer.clone(),
+                    )
+                    .await?,
                 );
 
                 debug!("started rpc server for plugin {}", plugin_info.name);
@@ -474,7 +502,11 @@ impl<Q: Queue<VoyagerMessage>> EngineBuilder<Q> {
                             .chain(self.metrics_endpoint.clone()),
                     ));
 
-                    let rpc_client = WorkerClient::new(&name, self.ipc_client_request_timeout);
+                    let mut rpc_client = WorkerClient::new(&name, self.ipc_client_request_timeout);
+
+                    if let Some(recorder) = &self.recorder {
+                        rpc_client = rpc_client.with_recorder(recorder.clone());
+                    }
 
                     let prev = context_inner
                         .plugins
@@ -501,6 +533,8 @@ impl<Q: Queue<VoyagerMessage>> EngineBuilder<Q> {
             cancellation_token.clone(),
             Server::new(cache.clone(), context.clone()),
             self.ipc_client_request_timeout,
+            self.recorder.clone(),
+            self.replay.clone(),
             |info| info.id(),
             |StateModuleInfo {
                  chain_id,
@@ -537,6 +571,8 @@ impl<Q: Queue<VoyagerMessage>> EngineBuilder<Q> {
             cancellation_token.clone(),
             Server::new(cache.clone(), context.clone()),
             self.ipc_client_request_timeout,
+            self.recorder.clone(),
+            self.replay.clone(),
             |info| info.id(),
             |ProofModuleInfo {
                  chain_id,
@@ -573,6 +609,8 @@ impl<Q: Queue<VoyagerMessage>> EngineBuilder<Q> {
             cancellation_token.clone(),
             Server::new(cache.clone(), context.clone()),
             self.ipc_client_request_timeout,
+            self.recorder.clone(),
+            self.replay.clone(),
             |info| info.id(),
             |FinalityModuleInfo {
                  chain_id,
@@ -615,6 +653,8 @@ impl<Q: Queue<VoyagerMessage>> EngineBuilder<Q> {
             cancellation_token.clone(),
             Server::new(cache.clone(), context.clone()),
             self.ipc_client_request_timeout,
+            self.recorder.clone(),
+            self.replay.clone(),
             |info| info.id(),
             |ClientModuleInfo {
                  client_type,
@@ -676,6 +716,8 @@ impl<Q: Queue<VoyagerMessage>> EngineBuilder<Q> {
             cancellation_token.clone(),
             Server::new(cache.clone(), context.clone()),
             self.ipc_client_request_timeout,
+            self.recorder.clone(),
+            self.replay.clone(),
             |info| info.id(),
             |ClientBootstrapModuleInfo {
                  client_type,
@@ -729,7 +771,6 @@ impl<Q: Queue<VoyagerMessage>> EngineBuilder<Q> {
             .iter()
             .map(|(name, client)| async move {
                 match client
-                    .inner()
                     .wait_until_connected(Duration::from_secs(10))
                     .instrument(debug_span!("health check", %name))
                     .await
@@ -1283,10 +1324,14 @@ async fn modules_startup<Info: Serialize + Clone + Unpin + Send + 'static>(
     cancellation_token: CancellationToken,
     server: Server,
     ipc_client_request_timeout: Duration,
+    recorder: Option<Recorder>,
+    replay: Option<Replay>,
     id_f: fn(&Info) -> String,
     mut push_f: impl FnMut(&Info, WorkerClient) -> anyhow::Result<()>,
     metrics_endpoint: Option<String>,
 ) -> anyhow::Result<()> {
+    let replaying = replay.is_some();
+
     stream::iter(configs)
         .filter(|module_config| {
             future::ready(if !module_config.enabled {
@@ -1302,16 +1347,21 @@ async fn modules_startup<Info: Serialize + Clone + Unpin + Send + 'static>(
         .zip(stream::repeat((
             server.clone(),
             logger_middleware_layer.clone(),
+            recorder.clone(),
         )))
         .map::<anyhow::Result<_>, _>(anyhow::Result::Ok)
         .try_filter_map(
-            |(module_config, (server, logger_middleware_layer))| async move {
+            |(module_config, (server, logger_middleware_layer, recorder))| async move {
                 if !module_config.enabled {
                     info!(
                         module_path = %module_config.path.to_string_lossy(),
                         "module is not enabled, skipping"
                     );
                     anyhow::Result::Ok(None)
+                } else if replaying {
+                    // replayed modules never make requests back into voyager, so there is no need
+                    // for a coordinator server
+                    anyhow::Result::Ok(Some(module_config))
                 } else {
                     debug!(
                         "starting rpc server for module {}",
@@ -1321,6 +1371,7 @@ async fn modules_startup<Info: Serialize + Clone + Unpin + Send + 'static>(
                         coordinator_server(
                             &id_f(&module_config.info),
                             server,
+                            recorder,
                             logger_middleware_layer,
                         )
                         .await?,
@@ -1338,6 +1389,17 @@ async fn modules_startup<Info: Serialize + Clone + Unpin + Send + 'static>(
 
             debug!("registering module {}", id);
 
+            if let Some(replay) = &replay {
+                push_f(
+                    &module_config.info,
+                    WorkerClient::replay(&id, replay.clone()),
+                )?;
+
+                info!("registered replayed module {id}");
+
+                return Ok(());
+            }
+
             tokio::spawn(worker_child_process(
                 id.clone(),
                 module_config.path,
@@ -1350,7 +1412,11 @@ async fn modules_startup<Info: Serialize + Clone + Unpin + Send + 'static>(
                 .chain(metrics_endpoint.clone()),
             ));
 
-            let rpc_client = WorkerClient::new(&id, ipc_client_request_timeout);
+            let mut rpc_client = WorkerClient::new(&id, ipc_client_request_timeout);
+
+            if let Some(recorder) = &recorder {
+                rpc_client = rpc_client.with_recorder(recorder.clone());
+            }
 
             push_f(&module_config.info, rpc_client)?;
 
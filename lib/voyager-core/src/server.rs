@@ -26,7 +26,7 @@ use voyager_rpc::{
     ClientBootstrapModuleClient, ClientModuleClient, FinalityModuleClient, PluginClient,
     RawProofModuleClient, RawStateModuleClient, VoyagerRpcServer, FATAL_JSONRPC_ERROR_CODE,
 };
-use voyager_types::{IbcProof, RawClientId};
+use voyager_types::{IbcProof, ProofVerification, RawClientId};
 use voyager_vm::ItemId;
 
 use crate::{
@@ -563,6 +563,37 @@ impl Server {
             .await
     }
 
+    #[instrument(skip_all, fields(%chain_id, %ibc_spec_id, %path, %root))]
+    async fn verify_ibc_proof_raw(
+        &self,
+        chain_id: ChainId,
+        ibc_spec_id: IbcSpecId,
+        path: Value,
+        value: Option<Bytes>,
+        proof: Value,
+        root: Bytes,
+    ) -> RpcResult<ProofVerification> {
+        self.span()
+            .in_scope(|| async {
+                debug!("verifying ibc proof");
+
+                let proof_module = self
+                    .context()?
+                    .proof_module(&chain_id, &ibc_spec_id)?
+                    .with_id(self.item_id);
+
+                let res = proof_module
+                    .verify_ibc_proof_raw(path, value, proof, root)
+                    .await
+                    .map_err(json_rpc_error_to_error_object)?;
+
+                debug!(result = ?res, "verified ibc proof");
+
+                Ok(res)
+            })
+            .await
+    }
+
     #[instrument(skip_all, fields(%chain_id, %height, ?path))]
     pub async fn query_ibc_state<P: IbcStorePathKey>(
         &self,
@@ -1020,6 +1051,22 @@ impl VoyagerRpcServer for Server {
             .await
     }
 
+    #[instrument(skip_all, fields(%chain_id, %ibc_spec_id, %path, %root))]
+    async fn verify_ibc_proof(
+        &self,
+        e: &Extensions,
+        chain_id: ChainId,
+        ibc_spec_id: IbcSpecId,
+        path: Value,
+        value: Option<Bytes>,
+        proof: Value,
+        root: Bytes,
+    ) -> RpcResult<ProofVerification> {
+        self.with_id(e.try_get().ok().cloned())
+            .verify_ibc_proof_raw(chain_id, ibc_spec_id, path, value, proof, root)
+            .await
+    }
+
     // ==========
     // SELF STATE
     // ==========
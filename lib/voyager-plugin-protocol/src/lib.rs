@@ -17,6 +17,10 @@
 //! 3. Worker starts it's server, listening on [`worker_socket_path`].
 //! 4. Worker creates a client connecting to [`coordinator_socket_path`].
 //! 5. Coordinator client now connects to the booted worker.
+//!
+//! # Recording
+//!
+//! All traffic between the coordinator and a worker can optionally be captured with a [`Recorder`], and later replayed in place of the worker with a [`Replay`]. See the [`record`] module for more information.
 
 use std::{
     borrow::Cow,
@@ -56,15 +60,22 @@ use voyager_client::VoyagerClient;
 use voyager_rpc::VoyagerRpcServer;
 use voyager_vm::ItemId;
 
+use crate::record::{
+    Direction, RecordServiceLayer, RecordedCall, RecordedResponse, Recorder, Replay,
+};
+
+pub mod record;
+
 pub const INVALID_CONFIG_EXIT_CODE: u8 = 13;
 pub const STARTUP_ERROR_EXIT_CODE: u8 = 14;
 
 /// Run the coordinator server.
 ///
-/// This will listen to messages on [`coordinator_socket_path`]`(name)`.
+/// This will listen to messages on [`coordinator_socket_path`]`(name)`. If a [`Recorder`] is provided, all requests made by the worker will be recorded.
 pub async fn coordinator_server(
     name: &str,
     server: impl VoyagerRpcServer,
+    recorder: Option<Recorder>,
     layer: impl Layer<
             RpcService,
             Service: Clone
@@ -85,6 +96,10 @@ pub async fn coordinator_server(
         .set_rpc_middleware(
             reth_ipc::server::RpcServiceBuilder::new()
                 .layer(ExtractItemIdServiceLayer)
+                .layer(RecordServiceLayer {
+                    worker: name.to_owned(),
+                    recorder,
+                })
                 .layer(layer),
         )
         .build(coordinator_socket.clone());
@@ -166,22 +181,23 @@ pub async fn worker_server<T>(
 /// The RPC client to communicate with a worker from the coordinator.
 ///
 /// This is a thin wrapper around a [`reconnecting_jsonrpc_ws_client::Client`]. If the worker crashes or restarts, it will automatically attempt to reconnect.
+///
+/// When constructed with [`WorkerClient::replay`], there is no worker process at all and all requests are answered from the provided [`Replay`].
 #[derive(Clone)]
 pub struct WorkerClient {
-    client: reconnecting_jsonrpc_ws_client::Client,
-    #[allow(dead_code)]
+    transport: WorkerTransport,
     name: String,
 }
 
-impl WorkerClient {
-    pub fn inner(&self) -> &reconnecting_jsonrpc_ws_client::Client {
-        &self.client
-    }
+#[derive(Clone)]
+enum WorkerTransport {
+    Ipc {
+        client: reconnecting_jsonrpc_ws_client::Client,
+        recorder: Option<Recorder>,
+    },
+    Replay(Replay),
 }
 
-delegate_client_impl!(WorkerClient: |this| this.client);
-delegate_client_impl!(&WorkerClient: |this| this.client);
-
 impl WorkerClient {
     pub fn new(name: &str, request_timeout: Duration) -> Self {
         let worker_socket = worker_socket_path(name);
@@ -206,13 +222,172 @@ impl WorkerClient {
         });
 
         Self {
-            client,
+            transport: WorkerTransport::Ipc {
+                client,
+                recorder: None,
+            },
             name: name.to_owned(),
         }
     }
 
-    pub fn client(&self) -> &reconnecting_jsonrpc_ws_client::Client {
-        &self.client
+    /// Create a client that answers all requests from the provided recording, instead of from a live worker.
+    pub fn replay(name: &str, replay: Replay) -> Self {
+        Self {
+            transport: WorkerTransport::Replay(replay),
+            name: name.to_owned(),
+        }
+    }
+
+    /// Record all requests made to the worker with the provided [`Recorder`].
+    ///
+    /// This has no effect on clients created with [`WorkerClient::replay`].
+    pub fn with_recorder(mut self, recorder: Recorder) -> Self {
+        if let WorkerTransport::Ipc {
+            recorder: ref mut r,
+            ..
+        } = self.transport
+        {
+            *r = Some(recorder);
+        }
+
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Wait until the worker is connected, or `timeout` has elapsed. Replayed workers are always connected.
+    pub async fn wait_until_connected(
+        &self,
+        timeout: Duration,
+    ) -> Result<(), reconnecting_jsonrpc_ws_client::ConnectionTimeoutError> {
+        match &self.transport {
+            WorkerTransport::Ipc { client, .. } => client.wait_until_connected(timeout).await,
+            WorkerTransport::Replay(_) => Ok(()),
+        }
+    }
+}
+
+impl ClientT for WorkerClient {
+    async fn notification<Params>(
+        &self,
+        method: &str,
+        params: Params,
+    ) -> Result<(), jsonrpsee::core::client::Error>
+    where
+        Params: ToRpcParams + Send,
+    {
+        match &self.transport {
+            WorkerTransport::Ipc { client, .. } => client.notification(method, params).await,
+            WorkerTransport::Replay(_) => Err(jsonrpsee::core::client::Error::Custom(
+                "notifications are not supported when replaying".to_owned(),
+            )),
+        }
+    }
+
+    async fn request<R, Params>(
+        &self,
+        method: &str,
+        params: Params,
+    ) -> Result<R, jsonrpsee::core::client::Error>
+    where
+        R: DeserializeOwned,
+        Params: ToRpcParams + Send,
+    {
+        match &self.transport {
+            WorkerTransport::Ipc {
+                client,
+                recorder: None,
+            } => client.request(method, params).await,
+            WorkerTransport::Ipc {
+                client,
+                recorder: Some(recorder),
+            } => {
+                let params = params.to_rpc_params()?;
+                let (item_id, unthreaded_params) = unthread_params(params.as_deref());
+
+                let res = client
+                    .request::<serde_json::Value, _>(method, RawParams(params))
+                    .await;
+
+                let response = match &res {
+                    Ok(value) => Some(RecordedResponse::Ok(value.clone())),
+                    Err(jsonrpsee::core::client::Error::Call(error)) => {
+                        Some(RecordedResponse::Err(error.into()))
+                    }
+                    // transport errors are not a response from the worker, and as such are not recorded
+                    Err(_) => None,
+                };
+
+                if let Some(response) = response {
+                    recorder.record(&RecordedCall {
+                        direction: Direction::CoordinatorToWorker,
+                        worker: self.name.clone(),
+                        item_id,
+                        method: method.to_owned(),
+                        params: unthreaded_params,
+                        response,
+                    });
+                }
+
+                serde_json::from_value(res?).map_err(jsonrpsee::core::client::Error::ParseError)
+            }
+            WorkerTransport::Replay(replay) => {
+                let params = params.to_rpc_params()?;
+                let (_, unthreaded_params) = unthread_params(params.as_deref());
+
+                let value = replay.respond(&self.name, method, unthreaded_params.as_ref())?;
+
+                serde_json::from_value(value).map_err(jsonrpsee::core::client::Error::ParseError)
+            }
+        }
+    }
+
+    async fn batch_request<'a, R>(
+        &self,
+        batch: BatchRequestBuilder<'a>,
+    ) -> Result<BatchResponse<'a, R>, jsonrpsee::core::client::Error>
+    where
+        R: DeserializeOwned + Debug + 'a,
+    {
+        match &self.transport {
+            WorkerTransport::Ipc { client, .. } => client.batch_request(batch).await,
+            WorkerTransport::Replay(_) => Err(jsonrpsee::core::client::Error::Custom(
+                "batch requests are not supported when replaying".to_owned(),
+            )),
+        }
+    }
+}
+
+delegate_client_impl!(&WorkerClient: |this| *this);
+
+/// Split params threaded by an [`IdThreadClient`] into the item id and the original params.
+fn unthread_params(params: Option<&RawValue>) -> (Option<ItemId>, Option<serde_json::Value>) {
+    let Some(params) = params else {
+        return (None, None);
+    };
+
+    let parse = |raw: &RawValue| serde_json::from_str(raw.get()).expect("raw value is valid json");
+
+    // serde will also happily deserialize a struct from a sequence, so positional params such as
+    // `[1, "a"]` would otherwise be mistaken for threaded params
+    if !params.get().trim_start().starts_with('{') {
+        return (None, Some(parse(params)));
+    }
+
+    match serde_json::from_str::<ParamsWithItemId>(params.get()) {
+        Ok(ParamsWithItemId { item_id, params }) => (Some(item_id), params.as_deref().map(parse)),
+        Err(_) => (None, Some(parse(params))),
+    }
+}
+
+/// Already serialized params, to be forwarded as-is.
+struct RawParams(Option<Box<RawValue>>);
+
+impl ToRpcParams for RawParams {
+    fn to_rpc_params(self) -> Result<Option<Box<RawValue>>, serde_json::Error> {
+        Ok(self.0)
     }
 }
 
@@ -745,3 +920,71 @@ macro_rules! delegate_client_impl {
         }
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use jsonrpsee::rpc_params;
+    use serde_json::json;
+
+    use super::*;
+
+    fn raw(value: serde_json::Value) -> Box<RawValue> {
+        RawValue::from_string(value.to_string()).unwrap()
+    }
+
+    fn threaded(item_id: i64, params: Option<serde_json::Value>) -> Box<RawValue> {
+        ParamsWithItemId {
+            item_id: ItemId::new(item_id).unwrap(),
+            params: params.map(|params| Cow::Owned(raw(params))),
+        }
+        .to_rpc_params()
+        .unwrap()
+        .unwrap()
+    }
+
+    #[test]
+    fn unthread_params_works() {
+        assert_eq!(unthread_params(None), (None, None));
+
+        assert_eq!(
+            unthread_params(Some(&raw(json!([1, "a"])))),
+            (None, Some(json!([1, "a"])))
+        );
+
+        assert_eq!(
+            unthread_params(Some(&threaded(3, Some(json!({ "height": 1 }))))),
+            (Some(ItemId::new(3).unwrap()), Some(json!({ "height": 1 })))
+        );
+
+        assert_eq!(
+            unthread_params(Some(&threaded(3, None))),
+            (Some(ItemId::new(3).unwrap()), None)
+        );
+    }
+
+    #[tokio::test]
+    async fn replay_ignores_threaded_item_id() {
+        let client = WorkerClient::replay(
+            "worker",
+            Replay::from_calls([RecordedCall {
+                direction: Direction::CoordinatorToWorker,
+                worker: "worker".to_owned(),
+                item_id: Some(ItemId::new(1).unwrap()),
+                method: "query".to_owned(),
+                params: Some(json!([1])),
+                response: RecordedResponse::Ok(json!("ok")),
+            }]),
+        );
+
+        let res = client
+            .request::<String, _>("query", RawParams(Some(threaded(2, Some(json!([1]))))))
+            .await
+            .unwrap();
+        assert_eq!(res, "ok");
+
+        assert!(client
+            .request::<String, _>("query", rpc_params![1])
+            .await
+            .is_err());
+    }
+}
@@ -0,0 +1,561 @@
+//! Recording and replaying of the JSON-RPC traffic between the coordinator and its workers.
+//!
+//! A [`Recorder`] appends every request handled by a worker (and every request a worker makes back into the coordinator) to a JSON-lines file as a [`RecordedCall`]. The resulting file can then be loaded as a [`Replay`], which answers coordinator-to-worker requests from the recording instead of from a live worker. This allows for a failing `Op` to be re-run deterministically without access to the original chains or RPC endpoints.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    fs::File,
+    future::Future,
+    io::{self, BufRead, BufReader, BufWriter, Write},
+    path::Path,
+    sync::{Arc, Mutex},
+};
+
+use futures::FutureExt;
+use jsonrpsee::{
+    core::client,
+    server::middleware::rpc::RpcServiceT,
+    types::{ErrorObject, ErrorObjectOwned, Response, ResponsePayload},
+    MethodResponse,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tower::Layer;
+use tracing::{error, trace};
+use unionlabs::ErrorReporter;
+use voyager_vm::ItemId;
+
+/// The direction of a [`RecordedCall`], relative to the coordinator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Direction {
+    /// A request sent from the coordinator to a worker, such as `query_ibc_proof` or `call`.
+    CoordinatorToWorker,
+    /// A request sent from a worker back into the coordinator, via the worker's voyager client.
+    WorkerToCoordinator,
+}
+
+/// A single request/response pair, as captured by a [`Recorder`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RecordedCall {
+    pub direction: Direction,
+    /// The name of the worker that handled (or made) the request.
+    pub worker: String,
+    /// The item id threaded through the request, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub item_id: Option<ItemId>,
+    pub method: String,
+    /// The request params, with any threaded item id removed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub params: Option<Value>,
+    pub response: RecordedResponse,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub enum RecordedResponse {
+    Ok(Value),
+    Err(RecordedError),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RecordedError {
+    pub code: i32,
+    pub message: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<Value>,
+}
+
+impl From<&ErrorObject<'_>> for RecordedError {
+    fn from(error: &ErrorObject<'_>) -> Self {
+        Self {
+            code: error.code(),
+            message: error.message().to_owned(),
+            data: error
+                .data()
+                .map(|data| serde_json::from_str(data.get()).expect("raw value is valid json")),
+        }
+    }
+}
+
+impl From<RecordedError> for ErrorObjectOwned {
+    fn from(error: RecordedError) -> Self {
+        ErrorObject::owned(error.code, error.message, error.data)
+    }
+}
+
+/// Appends [`RecordedCall`]s to a JSON-lines file.
+///
+/// This is cheap to clone, all clones write to the same file.
+#[derive(Debug, Clone)]
+pub struct Recorder {
+    file: Arc<Mutex<BufWriter<File>>>,
+}
+
+impl Recorder {
+    /// Create a recorder writing to `path`. If the file already exists, new calls will be appended to it.
+    pub fn create(path: impl AsRef<Path>) -> io::Result<Self> {
+        let file = File::options().create(true).append(true).open(path)?;
+
+        Ok(Self {
+            file: Arc::new(Mutex::new(BufWriter::new(file))),
+        })
+    }
+
+    /// Write a call to the recording.
+    ///
+    /// Failing to write to the recording is logged but otherwise ignored, since a broken recording should never take down the coordinator.
+    pub fn record(&self, call: &RecordedCall) {
+        trace!(worker = %call.worker, method = %call.method, "recording call");
+
+        let mut file = self.file.lock().expect("mutex is poisoned");
+
+        let res = serde_json::to_writer(&mut *file, call)
+            .map_err(io::Error::from)
+            .and_then(|()| file.write_all(b"\n"))
+            .and_then(|()| file.flush());
+
+        if let Err(err) = res {
+            error!(err = %ErrorReporter(err), "unable to write to recording");
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ReplayError {
+    #[error("unable to read recording")]
+    Io(#[from] io::Error),
+    #[error("invalid recorded call on line {line}")]
+    Parse {
+        line: usize,
+        #[source]
+        error: serde_json::Error,
+    },
+}
+
+/// A recording loaded for replay.
+///
+/// Requests are matched against the recording by worker name, method, and params (ignoring the threaded item id, which is not stable across runs). If the same request was recorded multiple times, the responses are returned in the order they were recorded. Once all recorded responses for a request have been returned, any further identical requests will fail, since the replayed run has diverged from the recording.
+#[derive(Debug, Clone)]
+pub struct Replay {
+    responses: Arc<Mutex<HashMap<ReplayKey, VecDeque<RecordedResponse>>>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct ReplayKey {
+    worker: String,
+    method: String,
+    params: Option<String>,
+}
+
+impl ReplayKey {
+    fn new(worker: &str, method: &str, params: Option<&Value>) -> Self {
+        Self {
+            worker: worker.to_owned(),
+            method: method.to_owned(),
+            params: params.map(|params| params.to_string()),
+        }
+    }
+}
+
+impl Replay {
+    /// Load a recording previously written by a [`Recorder`].
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ReplayError> {
+        let reader = BufReader::new(File::open(path)?);
+
+        let mut calls = vec![];
+
+        for (idx, line) in reader.lines().enumerate() {
+            let line = line?;
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            calls.push(
+                serde_json::from_str::<RecordedCall>(&line).map_err(|error| {
+                    ReplayError::Parse {
+                        line: idx + 1,
+                        error,
+                    }
+                })?,
+            );
+        }
+
+        Ok(Self::from_calls(calls))
+    }
+
+    /// Build a replay from an in-memory list of calls.
+    ///
+    /// Only [`Direction::CoordinatorToWorker`] calls are used, since those are the requests that a replayed worker needs to answer.
+    pub fn from_calls(calls: impl IntoIterator<Item = RecordedCall>) -> Self {
+        let mut responses = HashMap::<_, VecDeque<_>>::new();
+
+        for call in calls {
+            if call.direction != Direction::CoordinatorToWorker {
+                continue;
+            }
+
+            responses
+                .entry(ReplayKey::new(
+                    &call.worker,
+                    &call.method,
+                    call.params.as_ref(),
+                ))
+                .or_default()
+                .push_back(call.response);
+        }
+
+        Self {
+            responses: Arc::new(Mutex::new(responses)),
+        }
+    }
+
+    /// Respond to a request to `worker` from the recording.
+    pub fn respond(
+        &self,
+        worker: &str,
+        method: &str,
+        params: Option<&Value>,
+    ) -> Result<Value, client::Error> {
+        let mut responses = self.responses.lock().expect("mutex is poisoned");
+
+        let params_str = || params.map_or_else(|| "null".to_owned(), |params| params.to_string());
+
+        let response = responses
+            .get_mut(&ReplayKey::new(worker, method, params))
+            .ok_or_else(|| {
+                client::Error::Custom(format!(
+                    "no recorded response for {method} in {worker} with params {}",
+                    params_str()
+                ))
+            })?
+            .pop_front()
+            .ok_or_else(|| {
+                client::Error::Custom(format!(
+                    "all recorded responses for {method} in {worker} with params {} have already \
+                    been replayed",
+                    params_str()
+                ))
+            })?;
+
+        match response {
+            RecordedResponse::Ok(value) => Ok(value),
+            RecordedResponse::Err(error) => Err(client::Error::Call(error.into())),
+        }
+    }
+}
+
+/// An [`RpcServiceT`] layer to record all requests made by a worker into the coordinator.
+///
+/// NOTE: This must be layered after [`ExtractItemIdServiceLayer`](crate::ExtractItemIdServiceLayer), such that the recorded params do not contain the threaded item id.
+#[derive(Clone)]
+pub(crate) struct RecordServiceLayer {
+    pub(crate) worker: String,
+    pub(crate) recorder: Option<Recorder>,
+}
+
+impl<S> Layer<S> for RecordServiceLayer {
+    type Service = RecordService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RecordService {
+            service: inner,
+            worker: self.worker.clone(),
+            recorder: self.recorder.clone(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct RecordService<S> {
+    service: S,
+    worker: String,
+    recorder: Option<Recorder>,
+}
+
+impl<S> RpcServiceT for RecordService<S>
+where
+    S: RpcServiceT<MethodResponse = MethodResponse> + Send + Sync + Clone + 'static,
+{
+    type MethodResponse = S::MethodResponse;
+    type NotificationResponse = S::NotificationResponse;
+    type BatchResponse = S::BatchResponse;
+
+    fn call<'a>(
+        &self,
+        request: jsonrpsee::types::Request<'a>,
+    ) -> impl Future<Output = Self::MethodResponse> + Send + 'a {
+        let recorder = self.recorder.clone().map(|recorder| {
+            (
+                recorder,
+                self.worker.clone(),
+                request.extensions.get::<ItemId>().copied(),
+                request.method_name().to_owned(),
+                request
+                    .params()
+                    .as_str()
+                    .and_then(|params| serde_json::from_str::<Value>(params).ok()),
+            )
+        });
+
+        self.service.call(request).map(move |method_response| {
+            if let Some((recorder, worker, item_id, method, params)) = recorder {
+                match serde_json::from_str::<Response<Value>>(method_response.as_json().get()) {
+                    Ok(response) => recorder.record(&RecordedCall {
+                        direction: Direction::WorkerToCoordinator,
+                        worker,
+                        item_id,
+                        method,
+                        params,
+                        response: match response.payload {
+                            ResponsePayload::Success(value) => {
+                                RecordedResponse::Ok(value.into_owned())
+                            }
+                            ResponsePayload::Error(error) => RecordedResponse::Err((&error).into()),
+                        },
+                    }),
+                    Err(err) => {
+                        error!(err = %ErrorReporter(err), "unable to parse response for recording")
+                    }
+                }
+            }
+
+            method_response
+        })
+    }
+
+    fn batch<'a>(
+        &self,
+        requests: jsonrpsee::core::middleware::Batch<'a>,
+    ) -> impl Future<Output = Self::BatchResponse> + Send + 'a {
+        self.service.batch(requests)
+    }
+
+    fn notification<'a>(
+        &self,
+        n: jsonrpsee::core::middleware::Notification<'a>,
+    ) -> impl Future<Output = Self::NotificationResponse> + Send + 'a {
+        self.service.notification(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn call(
+        direction: Direction,
+        worker: &str,
+        method: &str,
+        params: Option<Value>,
+        response: RecordedResponse,
+    ) -> RecordedCall {
+        RecordedCall {
+            direction,
+            worker: worker.to_owned(),
+            item_id: None,
+            method: method.to_owned(),
+            params,
+            response,
+        }
+    }
+
+    #[test]
+    fn matches_worker_method_and_params() {
+        let replay = Replay::from_calls([
+            call(
+                Direction::CoordinatorToWorker,
+                "a",
+                "query",
+                Some(json!([1])),
+                RecordedResponse::Ok(json!("a-1")),
+            ),
+            call(
+                Direction::CoordinatorToWorker,
+                "a",
+                "query",
+                Some(json!([2])),
+                RecordedResponse::Ok(json!("a-2")),
+            ),
+            call(
+                Direction::CoordinatorToWorker,
+                "b",
+                "query",
+                Some(json!([1])),
+                RecordedResponse::Ok(json!("b-1")),
+            ),
+            call(
+                Direction::CoordinatorToWorker,
+                "b",
+                "info",
+                None,
+                RecordedResponse::Ok(json!("b-info")),
+            ),
+        ]);
+
+        assert_eq!(replay.respond("b", "info", None).unwrap(), json!("b-info"));
+        assert_eq!(
+            replay.respond("b", "query", Some(&json!([1]))).unwrap(),
+            json!("b-1")
+        );
+        assert_eq!(
+            replay.respond("a", "query", Some(&json!([2]))).unwrap(),
+            json!("a-2")
+        );
+        assert_eq!(
+            replay.respond("a", "query", Some(&json!([1]))).unwrap(),
+            json!("a-1")
+        );
+
+        assert!(matches!(
+            replay.respond("a", "query", Some(&json!([3]))),
+            Err(client::Error::Custom(_))
+        ));
+        assert!(matches!(
+            replay.respond("c", "query", Some(&json!([1]))),
+            Err(client::Error::Custom(_))
+        ));
+        assert!(matches!(
+            replay.respond("b", "info", Some(&json!([]))),
+            Err(client::Error::Custom(_))
+        ));
+    }
+
+    #[test]
+    fn responses_are_replayed_in_order_and_exhausted() {
+        let replay = Replay::from_calls([
+            call(
+                Direction::CoordinatorToWorker,
+                "a",
+                "query",
+                Some(json!([1])),
+                RecordedResponse::Ok(json!(1)),
+            ),
+            call(
+                Direction::CoordinatorToWorker,
+                "a",
+                "query",
+                Some(json!([1])),
+                RecordedResponse::Err(RecordedError {
+                    code: -1,
+                    message: "error".to_owned(),
+                    data: Some(json!({ "key": "value" })),
+                }),
+            ),
+            call(
+                Direction::CoordinatorToWorker,
+                "a",
+                "query",
+                Some(json!([1])),
+                RecordedResponse::Ok(json!(3)),
+            ),
+        ]);
+
+        assert_eq!(
+            replay.respond("a", "query", Some(&json!([1]))).unwrap(),
+            json!(1)
+        );
+
+        match replay.respond("a", "query", Some(&json!([1]))) {
+            Err(client::Error::Call(error)) => {
+                assert_eq!(error.code(), -1);
+                assert_eq!(error.message(), "error");
+                assert_eq!(error.data().unwrap().get(), r#"{"key":"value"}"#);
+            }
+            res => panic!("unexpected response: {res:?}"),
+        }
+
+        assert_eq!(
+            replay.respond("a", "query", Some(&json!([1]))).unwrap(),
+            json!(3)
+        );
+
+        assert!(matches!(
+            replay.respond("a", "query", Some(&json!([1]))),
+            Err(client::Error::Custom(_))
+        ));
+    }
+
+    #[test]
+    fn worker_to_coordinator_calls_are_not_replayed() {
+        let replay = Replay::from_calls([call(
+            Direction::WorkerToCoordinator,
+            "a",
+            "query",
+            None,
+            RecordedResponse::Ok(json!(1)),
+        )]);
+
+        assert!(matches!(
+            replay.respond("a", "query", None),
+            Err(client::Error::Custom(_))
+        ));
+    }
+
+    #[test]
+    fn record_then_load() {
+        let path = std::env::temp_dir().join(format!(
+            "voyager-plugin-protocol-record-then-load-{}.jsonl",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&path);
+
+        let calls = [
+            RecordedCall {
+                item_id: Some(ItemId::new(7).unwrap()),
+                ..call(
+                    Direction::CoordinatorToWorker,
+                    "a",
+                    "query",
+                    Some(json!({ "height": 1 })),
+                    RecordedResponse::Ok(json!(null)),
+                )
+            },
+            call(
+                Direction::WorkerToCoordinator,
+                "a",
+                "info",
+                None,
+                RecordedResponse::Err(RecordedError {
+                    code: 1,
+                    message: "error".to_owned(),
+                    data: None,
+                }),
+            ),
+        ];
+
+        let recorder = Recorder::create(&path).unwrap();
+        for call in &calls {
+            recorder.record(call);
+        }
+        drop(recorder);
+
+        let lines = std::fs::read_to_string(&path)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str::<RecordedCall>(line).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(lines, calls);
+
+        let replay = Replay::load(&path).unwrap();
+        assert_eq!(
+            replay
+                .respond("a", "query", Some(&json!({ "height": 1 })))
+                .unwrap(),
+            json!(null)
+        );
+
+        std::fs::write(&path, "{}\n").unwrap();
+        assert!(matches!(
+            Replay::load(&path),
+            Err(ReplayError::Parse { line: 1, .. })
+        ));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -56,6 +56,10 @@ impl IbcInterface {
 
     pub const IBC_MOVE_SUI: &'static str = "ibc-move/sui";
 
+    /// The in-memory IBC host provided by the `mock-chain` crate. This is only
+    /// intended to be used in tests.
+    pub const MOCK: &'static str = "mock";
+
     // lots more to come - near, fuel - stay tuned
 }
 
@@ -161,6 +165,12 @@ impl ClientType {
     /// [L2 settlement]: https://github.com/base/contracts
     pub const BASE: &'static str = "base";
 
+    /// A client tracking the state of the [Linea] zkEVM L2, settling on
+    /// Ethereum, verified by verifying the L2 settlement on the L1.
+    ///
+    /// [Linea]: https://github.com/Consensys/linea-contracts
+    pub const LINEA: &'static str = "linea";
+
     pub const SUI: &'static str = "sui";
 
     /// A client tracking the state of a chain with [Parlia] consensus, verified by verifying the [fast finality].
@@ -169,7 +179,11 @@ impl ClientType {
     /// [fast finality]: https://docs.bnbchain.org/bnb-smart-chain/developers/json_rpc/bsc-api-list/#economic-finality-fast-finality
     pub const PARLIA: &'static str = "parlia";
 
-    // lots more to come - near, linea, polygon - stay tuned
+    /// A client tracking a chain provided by the `mock-chain` crate. Headers are checked directly
+    /// against the counterparty's canonical chain, so this must only be used in tests.
+    pub const MOCK: &'static str = "mock";
+
+    // lots more to come - near, polygon - stay tuned
 }
 
 /// Newtype for consensus types. A consensus is verifiable by potentially many [`ClientType`]s.
@@ -241,7 +255,16 @@ impl ConsensusType {
     /// [custom OP stack]: https://github.com/base/contracts
     pub const BASE: &'static str = "base";
 
-    // lots more to come - near, linea, polygon - stay tuned
+    /// [Linea] zkEVM L2, settling on Ethereum.
+    ///
+    /// [Linea]: https://github.com/Consensys/linea-contracts
+    pub const LINEA: &'static str = "linea";
+
+    /// The simulated consensus of a chain provided by the `mock-chain` crate. This is only
+    /// intended to be used in tests.
+    pub const MOCK: &'static str = "mock";
+
+    // lots more to come - near, polygon - stay tuned
 }
 
 #[cfg(feature = "serde")]
@@ -421,6 +444,16 @@ pub struct ConsensusStateMeta {
     /// The timestamp of the counterparty at the height represented by this
     /// consensus state.
     pub timestamp: Timestamp,
+
+    /// The state root of the counterparty at the height represented by this
+    /// consensus state, that proofs of the counterparty's IBC state are
+    /// verified against. This is `None` if the consensus state does not
+    /// commit to a single root (or the client module does not expose it).
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub root: Option<Bytes>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
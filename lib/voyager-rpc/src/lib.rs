@@ -1,4 +1,7 @@
 #![feature(trait_alias)]
+// the generated server trait for `VoyagerRpc::verify_ibc_proof` takes one too many arguments, and
+// jsonrpsee does not forward lint attributes from the trait methods
+#![allow(clippy::too_many_arguments)]
 
 use std::{
     collections::VecDeque,
@@ -23,7 +26,7 @@ use voyager_primitives::{
     ChainId, ClientInfo, ClientStateMeta, ClientType, ConsensusStateMeta, IbcInterface, IbcSpec,
     IbcSpecId, QueryHeight, Timestamp,
 };
-use voyager_types::{ProofType, RawClientId};
+use voyager_types::{ProofType, ProofVerification, RawClientId};
 use voyager_vm::{pass::PassResult, Op, QueueError};
 
 use crate::types::{
@@ -218,6 +221,17 @@ pub trait VoyagerRpc {
         path: Value,
     ) -> RpcResult<IbcProofResponse>;
 
+    #[method(name = "verifyIbcProof", with_extensions)]
+    async fn verify_ibc_proof(
+        &self,
+        chain_id: ChainId,
+        ibc_spec_id: IbcSpecId,
+        path: Value,
+        value: Option<Bytes>,
+        proof: Value,
+        root: Bytes,
+    ) -> RpcResult<ProofVerification>;
+
     // ========================================
     // self state queries, for creating clients
     // ========================================
@@ -400,6 +414,29 @@ pub trait ProofModule<V: IbcSpec> {
         at: Height,
         path: V::StorePath,
     ) -> RpcResult<Option<(Value, ProofType)>>;
+
+    /// Verify a proof returned by [`query_ibc_proof`] against the state
+    /// `root` of this chain, as stored in a counterparty consensus state at
+    /// the height the proof was read at (see [`ConsensusStateMeta::root`]).
+    ///
+    /// `value` is the raw value that the caller expects to be stored at
+    /// `path` (i.e. the commitment, as the counterparty will verify it), or
+    /// `None` if `path` is expected to be empty. The proof must prove exactly
+    /// this value; the value contained in the proof itself is not trusted.
+    ///
+    /// This is used to check proofs locally before they are submitted to the
+    /// counterparty. Proof modules that can't verify their proofs return
+    /// [`ProofVerification::Unsupported`].
+    ///
+    /// [`query_ibc_proof`]: ProofModuleServer::query_ibc_proof
+    #[method(name = "verifyIbcProof", with_extensions)]
+    async fn verify_ibc_proof(
+        &self,
+        path: V::StorePath,
+        value: Option<Bytes>,
+        proof: Value,
+        root: Bytes,
+    ) -> RpcResult<ProofVerification>;
 }
 
 /// Type-erased version of [`ProofModuleClient`].
@@ -411,6 +448,15 @@ pub trait RawProofModule {
         at: Height,
         path: Value,
     ) -> RpcResult<Option<(Value, ProofType)>>;
+
+    #[method(name = "verifyIbcProof")]
+    async fn verify_ibc_proof_raw(
+        &self,
+        path: Value,
+        value: Option<Bytes>,
+        proof: Value,
+        root: Bytes,
+    ) -> RpcResult<ProofVerification>;
 }
 
 /// Client modules provide functionality to interact with a single light client
@@ -5,10 +5,18 @@ use std::fmt::Debug;
 use jsonrpsee::{async_client, core::RpcResult, types::ErrorObject, Extensions};
 use serde::Serialize;
 use serde_json::Value;
-use tracing::error;
-use unionlabs::ErrorReporter;
+use tracing::{debug, error};
+use unionlabs::{ibc::core::client::height::Height, ErrorReporter};
+use voyager_message::{
+    call::{FetchUpdateHeaders, WaitForTrustedHeight},
+    callback::AggregateSubmitTxFromOrderedHeaders,
+    VoyagerMessage,
+};
 use voyager_plugin::protocol::{ArcClient, IdThreadClient};
+use voyager_primitives::{ChainId, IbcSpec, QueryHeight};
 use voyager_rpc::FATAL_JSONRPC_ERROR_CODE;
+use voyager_types::RawClientId;
+use voyager_vm::{call, promise, seq, Op};
 #[doc(no_inline)]
 pub use {
     anyhow, jsonrpsee, serde_json, voyager_client as client, voyager_message as message,
@@ -67,5 +75,57 @@ impl ExtensionsExt for Extensions {
     }
 }
 
+/// Build the ops to update `client_id` on `chain_id` to `height` of the chain it tracks, and then wait for the update to be included. Returns `None` if the client already trusts `height`.
+pub async fn update_client_to<V: IbcSpec>(
+    voyager_client: &VoyagerClient,
+    chain_id: &ChainId,
+    client_id: V::ClientId,
+    height: Height,
+) -> RpcResult<Option<Op<VoyagerMessage>>> {
+    let client_state_meta = voyager_client
+        .client_state_meta::<V>(chain_id.clone(), QueryHeight::Latest, client_id.clone())
+        .await?;
+
+    if client_state_meta.counterparty_height >= height {
+        debug!(
+            %client_id,
+            "client is already updated to {} (>= {height})",
+            client_state_meta.counterparty_height,
+        );
+
+        return Ok(None);
+    }
+
+    let client_info = voyager_client
+        .client_info::<V>(chain_id.clone(), client_id.clone())
+        .await?;
+
+    Ok(Some(seq([
+        promise(
+            [call(FetchUpdateHeaders {
+                client_type: client_info.client_type,
+                chain_id: client_state_meta.counterparty_chain_id,
+                counterparty_chain_id: chain_id.clone(),
+                client_id: RawClientId::new(&client_id),
+                update_from: client_state_meta.counterparty_height,
+                update_to: height,
+            })],
+            [],
+            AggregateSubmitTxFromOrderedHeaders {
+                ibc_spec_id: V::ID,
+                chain_id: chain_id.clone(),
+                client_id: RawClientId::new(&client_id),
+            },
+        ),
+        call(WaitForTrustedHeight {
+            chain_id: chain_id.clone(),
+            ibc_spec_id: V::ID,
+            client_id: RawClientId::new(client_id),
+            height,
+            finalized: false,
+        }),
+    ])))
+}
+
 #[derive(clap::Subcommand)]
 pub enum DefaultCmd {}
@@ -63,3 +63,18 @@ pub enum ProofType {
     Membership,
     NonMembership,
 }
+
+/// The result of verifying a proof locally, before it is submitted to the
+/// counterparty.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ProofVerification {
+    /// The proof is valid against the provided root.
+    Valid,
+    /// The proof is not valid against the provided root. This usually means
+    /// that the proof was read at a different height than requested, i.e. from
+    /// a lagging RPC.
+    Invalid { reason: String },
+    /// The proof module does not support verifying proofs locally.
+    Unsupported,
+}
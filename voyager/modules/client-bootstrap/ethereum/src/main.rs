@@ -6,7 +6,7 @@ use alloy::providers::{layers::CacheLayer, DynProvider, Provider, ProviderBuilde
 use beacon_api::client::BeaconApiClient;
 use beacon_api_types::{altair::SyncCommittee, chain_spec::PresetBaseKind, custom_types::Slot};
 use ethereum_light_client_types::{
-    client_state::InitialSyncCommittee, ClientState, ClientStateV1, ConsensusState,
+    client_state::InitialSyncCommittee, ClientState, ClientStateV2, ConsensusState,
 };
 use jsonrpsee::{
     core::{async_trait, RpcResult},
@@ -43,6 +43,13 @@ pub struct Module {
     /// The address of the `IBCHandler` smart contract.
     pub ibc_handler_address: H160,
 
+    /// Whether clients created by this module accept optimistic (non-finalized) updates.
+    pub allow_optimistic_updates: bool,
+
+    /// Whether clients created by this module force-apply the best valid update after the update
+    /// timeout has passed without a finalized update.
+    pub allow_force_updates: bool,
+
     pub provider: DynProvider,
     pub beacon_api_client: BeaconApiClient,
 }
@@ -60,6 +67,15 @@ pub struct Config {
     /// The RPC endpoint for the beacon chain.
     pub beacon_rpc_url: String,
 
+    /// Whether clients created by this module accept optimistic (non-finalized) updates.
+    #[serde(default)]
+    pub allow_optimistic_updates: bool,
+
+    /// Whether clients created by this module force-apply the best valid update after the update
+    /// timeout has passed without a finalized update.
+    #[serde(default)]
+    pub allow_force_updates: bool,
+
     #[serde(default)]
     pub max_cache_size: u32,
 }
@@ -111,6 +127,7 @@ impl Module {
                 |b| b.message.slot,
                 |b| b.message.slot,
                 |b| b.message.slot,
+                |b| b.message.slot,
             );
 
         trace!("beacon slot of exution block {block_number} is {beacon_slot}");
@@ -151,6 +168,8 @@ impl ClientBootstrapModule for Module {
             chain_id,
             chain_spec: spec.preset_base,
             ibc_handler_address: config.ibc_handler_address,
+            allow_optimistic_updates: config.allow_optimistic_updates,
+            allow_force_updates: config.allow_force_updates,
             provider,
             beacon_api_client,
         })
@@ -225,6 +244,7 @@ impl ClientBootstrapModuleServer for Module {
                     |_| todo!("capella not supported"),
                     |u| u.into(),
                     |u| u.into(),
+                    |u| u.into(),
                 )
         };
 
@@ -259,9 +279,10 @@ impl ClientBootstrapModuleServer for Module {
                 |l| l.current_sync_committee,
                 |l| l.current_sync_committee,
                 |l| l.current_sync_committee,
+                |l| l.current_sync_committee,
             );
 
-        Ok(serde_json::to_value(ClientState::V1(ClientStateV1 {
+        Ok(serde_json::to_value(ClientState::V2(ClientStateV2 {
             chain_id: self
                 .chain_id
                 .as_str()
@@ -279,6 +300,8 @@ impl ClientBootstrapModuleServer for Module {
                     .next_sync_committee
                     .expect("next sync committee should exist"),
             }),
+            allow_optimistic_updates: self.allow_optimistic_updates,
+            allow_force_updates: self.allow_force_updates,
         }))
         .expect("infallible"))
     }
@@ -328,6 +351,7 @@ impl ClientBootstrapModuleServer for Module {
                 |_| todo!("capella not supported"),
                 |l| l.header.into(),
                 |l| l.header.into(),
+                |l| l.header.into(),
             );
 
         assert_eq!(bootstrap_header.execution.block_number, height.height());
@@ -0,0 +1,90 @@
+use jsonrpsee::{
+    core::{async_trait, RpcResult},
+    http_client::{HttpClient, HttpClientBuilder},
+    types::ErrorObject,
+    Extensions,
+};
+use mock_chain::rpc::MockChainRpcClient;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tracing::instrument;
+use unionlabs::{ibc::core::client::height::Height, ErrorReporter};
+use voyager_sdk::{
+    anyhow, ensure_null, into_value,
+    plugin::ClientBootstrapModule,
+    primitives::{ChainId, ClientType},
+    rpc::{types::ClientBootstrapModuleInfo, ClientBootstrapModuleServer},
+};
+
+#[derive(Debug, Clone)]
+pub struct Module {
+    pub chain_id: ChainId,
+
+    pub client: HttpClient,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// The RPC endpoint of the mock chain.
+    pub rpc_url: String,
+}
+
+impl ClientBootstrapModule for Module {
+    type Config = Config;
+
+    async fn new(config: Self::Config, info: ClientBootstrapModuleInfo) -> anyhow::Result<Self> {
+        let client = HttpClientBuilder::default().build(&config.rpc_url)?;
+
+        let chain_id = client.chain_id().await?;
+
+        info.ensure_chain_id(chain_id.to_string())?;
+        info.ensure_client_type(ClientType::MOCK)?;
+
+        Ok(Self { chain_id, client })
+    }
+}
+
+fn rpc_error(e: jsonrpsee::core::ClientError) -> ErrorObject<'static> {
+    ErrorObject::owned(
+        -1,
+        ErrorReporter(e).with_message("mock chain rpc error"),
+        None::<()>,
+    )
+}
+
+#[async_trait]
+impl ClientBootstrapModuleServer for Module {
+    #[instrument(skip_all, fields(chain_id = %self.chain_id, %height))]
+    async fn self_client_state(
+        &self,
+        _: &Extensions,
+        height: Height,
+        config: Value,
+    ) -> RpcResult<Value> {
+        ensure_null(config)?;
+
+        self.client
+            .self_client_state(height.height())
+            .await
+            .map(into_value)
+            .map_err(rpc_error)
+    }
+
+    /// The consensus state on this chain at the specified `Height`.
+    #[instrument(skip_all, fields(chain_id = %self.chain_id, %height))]
+    async fn self_consensus_state(
+        &self,
+        _: &Extensions,
+        height: Height,
+        config: Value,
+    ) -> RpcResult<Value> {
+        ensure_null(config)?;
+
+        self.client
+            .self_consensus_state(height.height())
+            .await
+            .map(into_value)
+            .map_err(rpc_error)
+    }
+}
@@ -0,0 +1,7 @@
+use voyager_client_bootstrap_module_mock::Module;
+use voyager_sdk::plugin::ClientBootstrapModule;
+
+#[tokio::main(flavor = "multi_thread")]
+async fn main() {
+    Module::run().await
+}
@@ -1,7 +1,9 @@
 use aptos_move_ibc::ibc::ClientExt;
 use aptos_rest_client::error::RestError;
+use ibc_union_spec::ClientId;
 use jsonrpsee::{
     core::{async_trait, RpcResult},
+    types::ErrorObject,
     Extensions,
 };
 use movement_light_client_types::{ClientState, ConsensusState};
@@ -10,11 +12,12 @@ use serde_json::Value;
 use tracing::{debug, instrument};
 use unionlabs::{
     aptos::{
-        account::AccountAddress, state_proof::StateProof,
+        account::AccountAddress, state_proof::StateProof, transaction_info::TransactionInfo,
         transaction_proof::TransactionInfoWithProof,
     },
     ibc::core::client::height::Height,
-    primitives::{FixedBytes, H160, U256},
+    primitives::{FixedBytes, H160, H256, U256},
+    ErrorReporter,
 };
 use voyager_sdk::{
     anyhow, ensure_null,
@@ -45,7 +48,10 @@ pub struct Module {
     /// The address of the settlement contract on Eth.
     pub l1_settlement_address: H160,
 
-    pub l1_client_id: u32,
+    /// The slot of the block commitments mapping in the settlement contract.
+    pub l1_block_commitments_slot: U256,
+
+    pub l1_client_id: ClientId,
 
     pub aptos_client: aptos_rest_client::Client,
 
@@ -70,6 +76,7 @@ impl ClientBootstrapModule for Module {
             ibc_handler_address: config.ibc_handler_address,
             aptos_client,
             l1_settlement_address: config.l1_settlement_address,
+            l1_block_commitments_slot: config.l1_block_commitments_slot,
             l1_client_id: config.l1_client_id,
             movement_rest_url: config.movement_rest_url,
             whitelisted_relayers: config
@@ -96,8 +103,11 @@ pub struct Config {
     /// The address of the settlement contract on Eth.
     pub l1_settlement_address: H160,
 
+    /// The slot of the block commitments mapping in the settlement contract.
+    pub l1_block_commitments_slot: U256,
+
     /// Id of the light client that this client depends on
-    pub l1_client_id: u32,
+    pub l1_client_id: ClientId,
 
     /// The RPC endpoint for aptos.
     pub aptos_rest_api: String,
@@ -180,6 +190,7 @@ impl ClientBootstrapModuleServer for Module {
             chain_id: self.chain_id.to_string(),
             l1_client_id: self.l1_client_id,
             l1_contract_address: self.l1_settlement_address,
+            l1_block_commitments_slot: self.l1_block_commitments_slot,
             l2_contract_address: self.ibc_handler_address,
             table_handle: AccountAddress(FixedBytes::new(
                 U256::from_be_hex(table_handle).unwrap().to_be_bytes(),
@@ -196,15 +207,59 @@ impl ClientBootstrapModuleServer for Module {
     async fn self_consensus_state(
         &self,
         _: &Extensions,
-        _height: Height,
+        height: Height,
         config: Value,
     ) -> RpcResult<Value> {
         ensure_null(config)?;
 
+        // NOTE(aeryz): This only works with Union's custom Movement node, see
+        // https://github.com/movementlabsxyz/movement/pull/645
+        let state_proof: StateProofResponse = reqwest::Client::new()
+            .get(format!(
+                "{}/movement/v1/state-proof/{}",
+                self.movement_rest_url,
+                height.height()
+            ))
+            .send()
+            .await
+            .map_err(|e| {
+                ErrorObject::owned(
+                    -1,
+                    ErrorReporter(e).with_message("error fetching the state proof"),
+                    None::<()>,
+                )
+            })?
+            .json()
+            .await
+            .map_err(|e| {
+                ErrorObject::owned(
+                    -1,
+                    ErrorReporter(e).with_message("error decoding the state proof"),
+                    None::<()>,
+                )
+            })?;
+
+        // same as the consensus state the light client produces on update
+        let TransactionInfo::V0(tx_info) = state_proof.tx_proof.transaction_info;
+
+        let state_checkpoint_hash = tx_info.state_checkpoint_hash.ok_or_else(|| {
+            ErrorObject::owned(
+                -1,
+                format!("transaction info at height {height} has no state checkpoint hash"),
+                None::<()>,
+            )
+        })?;
+
         Ok(serde_json::to_value(ConsensusState {
-            state_root: Default::default(),
-            // TODO: Fix this - this is currently effectively broken.
-            timestamp: Timestamp::from_secs(1000),
+            state_root: H256::new(*state_checkpoint_hash.get()),
+            timestamp: Timestamp::from_nanos(
+                state_proof
+                    .state_proof
+                    .latest_ledger_info()
+                    .commit_info
+                    .timestamp_usecs
+                    * 1_000,
+            ),
             state_proof_hash: Default::default(),
         })
         .expect("infallible"))
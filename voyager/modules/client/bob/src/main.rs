@@ -104,6 +104,7 @@ impl ClientModuleServer for Module {
 
         Ok(ConsensusStateMeta {
             timestamp: cs.timestamp,
+            root: Some(cs.ibc_storage_root.into()),
         })
     }
 
@@ -197,6 +197,7 @@ impl ClientModuleServer for Module {
 
         Ok(ConsensusStateMeta {
             timestamp: cs.timestamp,
+            root: Some(cs.app_hash.hash.into()),
         })
     }
 
@@ -0,0 +1,7 @@
+use voyager_client_module_mock::Module;
+use voyager_sdk::plugin::ClientModule;
+
+#[tokio::main(flavor = "multi_thread")]
+async fn main() {
+    Module::run().await
+}
@@ -103,6 +103,7 @@ impl ClientModuleServer for Module {
 
         Ok(ConsensusStateMeta {
             timestamp: cs.timestamp,
+            root: Some(cs.state_root.into()),
         })
     }
 
@@ -106,6 +106,7 @@ impl ClientModuleServer for Module {
 
         Ok(ConsensusStateMeta {
             timestamp: Timestamp::from_nanos(cs.timestamp),
+            root: Some(cs.content_digest.0.into()),
         })
     }
 
@@ -167,6 +167,7 @@ impl ClientModuleServer for Module {
 
         Ok(ConsensusStateMeta {
             timestamp: Timestamp::from_nanos(cs.timestamp.as_unix_nanos()),
+            root: Some(cs.root.hash.into()),
         })
     }
 
@@ -16,6 +16,7 @@ use jsonrpsee::{
     types::ErrorObject,
     Extensions,
 };
+use rpc_pool::PoolConfig;
 use serde::{Deserialize, Serialize};
 use tracing::{debug, instrument, trace};
 use unionlabs::{ibc::core::client::height::Height, primitives::H256, ErrorReporter};
@@ -47,8 +48,8 @@ pub struct Module {
 pub struct Config {
     pub chain_spec: PresetBaseKind,
 
-    /// The RPC endpoint for the execution chain.
-    pub rpc_url: String,
+    /// The RPC endpoints for the execution chain.
+    pub rpc_url: PoolConfig,
     /// The RPC endpoint for the beacon chain.
     pub beacon_rpc_url: String,
 
@@ -81,6 +82,7 @@ impl Module {
                             |f| Some(f.finalized_header.execution.timestamp),
                             |f| Some(f.finalized_header.execution.timestamp),
                             |f| Some(f.finalized_header.execution.timestamp),
+                            |f| Some(f.finalized_header.execution.timestamp),
                         ) else {
                             return put().await;
                         };
@@ -149,6 +151,12 @@ impl Module {
                         f.finalized_header.execution.timestamp,
                     )
                 },
+                |f| {
+                    (
+                        f.finalized_header.execution.block_number,
+                        f.finalized_header.execution.timestamp,
+                    )
+                },
             ))
     }
 
@@ -198,6 +206,7 @@ impl Module {
                 |b| b.message.slot,
                 |b| b.message.slot,
                 |b| b.message.slot,
+                |b| b.message.slot,
             );
 
         trace!("beacon slot of exution block {block_number} is {beacon_slot}");
@@ -213,8 +222,7 @@ impl FinalityModule for Module {
         let provider = DynProvider::new(
             ProviderBuilder::new()
                 .layer(CacheLayer::new(config.max_cache_size))
-                .connect(&config.rpc_url)
-                .await?,
+                .connect_client(rpc_pool::alloy::client(config.rpc_url).await?),
         );
 
         let chain_id = ChainId::new(provider.get_chain_id().await?.to_string());
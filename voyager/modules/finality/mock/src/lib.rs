@@ -0,0 +1,87 @@
+use jsonrpsee::{
+    core::{async_trait, RpcResult},
+    http_client::{HttpClient, HttpClientBuilder},
+    types::ErrorObject,
+    Extensions,
+};
+use mock_chain::rpc::MockChainRpcClient;
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+use unionlabs::{ibc::core::client::height::Height, ErrorReporter};
+use voyager_sdk::{
+    anyhow,
+    plugin::FinalityModule,
+    primitives::{ChainId, ConsensusType, Timestamp},
+    rpc::{types::FinalityModuleInfo, FinalityModuleServer},
+};
+
+#[derive(Debug, Clone)]
+pub struct Module {
+    pub chain_id: ChainId,
+
+    pub client: HttpClient,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// The RPC endpoint of the mock chain.
+    pub rpc_url: String,
+}
+
+impl FinalityModule for Module {
+    type Config = Config;
+
+    async fn new(config: Self::Config, info: FinalityModuleInfo) -> anyhow::Result<Self> {
+        let client = HttpClientBuilder::default().build(&config.rpc_url)?;
+
+        let chain_id = client.chain_id().await?;
+
+        info.ensure_chain_id(chain_id.to_string())?;
+        info.ensure_consensus_type(ConsensusType::MOCK)?;
+
+        Ok(Self { chain_id, client })
+    }
+}
+
+fn rpc_error(e: jsonrpsee::core::ClientError) -> ErrorObject<'static> {
+    ErrorObject::owned(
+        -1,
+        ErrorReporter(e).with_message("mock chain rpc error"),
+        None::<()>,
+    )
+}
+
+#[async_trait]
+impl FinalityModuleServer for Module {
+    /// Query the latest finalized height of this chain.
+    #[instrument(skip_all, fields(chain_id = %self.chain_id, finalized))]
+    async fn query_latest_height(&self, _: &Extensions, finalized: bool) -> RpcResult<Height> {
+        self.client
+            .latest_height(finalized)
+            .await
+            .map(Height::new)
+            .map_err(rpc_error)
+    }
+
+    /// Query the latest finalized timestamp of this chain.
+    #[instrument(skip_all, fields(chain_id = %self.chain_id, finalized))]
+    async fn query_latest_timestamp(
+        &self,
+        _: &Extensions,
+        finalized: bool,
+    ) -> RpcResult<Timestamp> {
+        let height = self
+            .client
+            .latest_height(finalized)
+            .await
+            .map_err(rpc_error)?;
+
+        self.client
+            .block(height)
+            .await
+            .map_err(rpc_error)?
+            .map(|block| block.timestamp)
+            .ok_or_else(|| ErrorObject::owned(-1, format!("block {height} not found"), None::<()>))
+    }
+}
@@ -1,5 +1,6 @@
 use std::{fmt::Debug, num::ParseIntError};
 
+use cometbft_rpc::PoolConfig;
 use jsonrpsee::{
     core::{async_trait, RpcResult},
     types::ErrorObject,
@@ -31,14 +32,15 @@ pub struct Module {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct Config {
-    pub rpc_url: String,
+    /// The RPC endpoints of the chain.
+    pub rpc_url: PoolConfig,
 }
 
 impl FinalityModule for Module {
     type Config = Config;
 
     async fn new(config: Self::Config, info: FinalityModuleInfo) -> anyhow::Result<Self> {
-        let tm_client = cometbft_rpc::Client::new(config.rpc_url).await?;
+        let tm_client = cometbft_rpc::Client::new_pool(config.rpc_url).await?;
 
         let chain_id = tm_client.status().await?.node_info.network.to_string();
 
@@ -6,6 +6,7 @@ use ibc_union_spec::{
     path::{StorePath, IBC_UNION_COSMWASM_COMMITMENT_PREFIX},
     IbcUnion,
 };
+use ics23::ibc_api::{verify_membership, verify_non_membership, SDK_SPECS};
 use jsonrpsee::{
     core::{async_trait, RpcResult},
     types::ErrorObject,
@@ -17,8 +18,11 @@ use tracing::{error, instrument, warn};
 use unionlabs::{
     bounded::BoundedI64,
     cosmos::ics23::commitment_proof::CommitmentProof,
-    ibc::core::{client::height::Height, commitment::merkle_proof::MerkleProof},
-    primitives::{Bech32, H256},
+    ibc::core::{
+        client::height::Height,
+        commitment::{merkle_proof::MerkleProof, merkle_root::MerkleRoot},
+    },
+    primitives::{Bech32, Bytes, H256},
     ErrorReporter,
 };
 use voyager_sdk::{
@@ -26,7 +30,7 @@ use voyager_sdk::{
     plugin::ProofModule,
     primitives::ChainId,
     rpc::{rpc_error, types::ProofModuleInfo, ProofModuleServer, FATAL_JSONRPC_ERROR_CODE},
-    types::ProofType,
+    types::{ProofType, ProofVerification},
 };
 
 #[tokio::main(flavor = "multi_thread")]
@@ -113,13 +117,7 @@ impl ProofModuleServer<IbcUnion> for Module {
         at: Height,
         path: StorePath,
     ) -> RpcResult<Option<(Value, ProofType)>> {
-        // TODO: Extract this into a function somewhere, reuse in lightclients
-        let data = [0x03]
-            .into_iter()
-            .chain(*self.ibc_host_contract_address.data())
-            .chain(IBC_UNION_COSMWASM_COMMITMENT_PREFIX)
-            .chain(path.key())
-            .collect::<Vec<_>>();
+        let data = commitment_store_key(self.ibc_host_contract_address.data(), path.key());
 
         let query_result = self
             .cometbft_client
@@ -186,4 +184,142 @@ impl ProofModuleServer<IbcUnion> for Module {
 
         Ok(Some((into_value(proof), proof_type)))
     }
+
+    #[instrument(skip_all, fields(chain_id = %self.chain_id, ?path, %root))]
+    async fn verify_ibc_proof(
+        &self,
+        _: &Extensions,
+        path: StorePath,
+        value: Option<Bytes>,
+        proof: Value,
+        root: Bytes,
+    ) -> RpcResult<ProofVerification> {
+        let proof = serde_json::from_value::<MerkleProof>(proof).map_err(|e| {
+            ErrorObject::owned(
+                FATAL_JSONRPC_ERROR_CODE,
+                format!("invalid proof: {}", ErrorReporter(e)),
+                None::<()>,
+            )
+        })?;
+
+        let root = MerkleRoot {
+            hash: H256::try_from(&*root).map_err(|_| {
+                ErrorObject::owned(
+                    FATAL_JSONRPC_ERROR_CODE,
+                    format!("invalid app hash {root}, expected 32 bytes"),
+                    None::<()>,
+                )
+            })?,
+        };
+
+        Ok(verify_proof(
+            self.ibc_host_contract_address.data(),
+            path.key(),
+            value,
+            &proof,
+            &root,
+        ))
+    }
+}
+
+/// The key of the commitment at `key` in the wasm store, as stored by the ibc host contract at
+/// `ibc_host_contract_address`.
+// TODO: Reuse in lightclients
+fn commitment_store_key(ibc_host_contract_address: &H256, key: H256) -> Vec<u8> {
+    [0x03]
+        .into_iter()
+        .chain(*ibc_host_contract_address)
+        .chain(IBC_UNION_COSMWASM_COMMITMENT_PREFIX)
+        .chain(key)
+        .collect()
+}
+
+/// Verify that `proof` proves `value` at the commitment `key` of the ibc host contract under
+/// `root`, or that nothing is committed at `key` if `value` is `None`.
+fn verify_proof(
+    ibc_host_contract_address: &H256,
+    key: H256,
+    value: Option<Bytes>,
+    proof: &MerkleProof,
+    root: &MerkleRoot,
+) -> ProofVerification {
+    const WASM_STORE_KEY: &[u8] = b"wasm";
+
+    let key_path = [
+        WASM_STORE_KEY.to_vec(),
+        commitment_store_key(ibc_host_contract_address, key),
+    ];
+
+    let res = match value {
+        Some(value) => verify_membership(proof, &SDK_SPECS, root, &key_path, value.into_vec()),
+        None => verify_non_membership(proof, &SDK_SPECS, root, &key_path),
+    };
+
+    match res {
+        Ok(()) => ProofVerification::Valid,
+        Err(e) => ProofVerification::Invalid {
+            reason: ErrorReporter(e).to_string(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hex_literal::hex;
+
+    use super::*;
+
+    // a packet receipt that was not yet written on a union devnet, see
+    // `ics23::ibc_api::tests::packet_timeout_non_membership`
+    const IBC_HOST_CONTRACT_ADDRESS: [u8; 32] =
+        hex!("16d5260c4a4bc907b79822b792cf51c57f05f311f1e79309b7cc3ece4e277af3");
+    const KEY: [u8; 32] = hex!("c8e8bda249383359718501c9fc5e6db0215edfd2ba8a8812b08665c226e57844");
+    const ROOT: [u8; 32] = hex!("21c446360f0235662d8deed989f74eaee1c54827d1eeb9fd76208af2e7461e08");
+    const PROOF: &str = r#"{"proofs":[{"@type":"nonexist","@value":{"key":"0x0316d5260c4a4bc907b79822b792cf51c57f05f311f1e79309b7cc3ece4e277af300c8e8bda249383359718501c9fc5e6db0215edfd2ba8a8812b08665c226e57844","left":{"key":"0x0316d5260c4a4bc907b79822b792cf51c57f05f311f1e79309b7cc3ece4e277af300c8e6b50d683ea8bc68beb2a555a59bac272a6ebd8470d612d11b3eb26a4a5d","value":"0x01502841361a25f62632a8138da2f5da1948539832b0fc2bfb733cc7b081836e","leaf":{"hash":"sha256","prehash_key":"no_hash","prehash_value":"sha256","length":"var_proto","prefix":"0x0002b8a332"},"path":[{"hash":"sha256","prefix":"0x0204dc904220","suffix":"0x20aa4be3b4460f469a54a997e9fc0e4c5a15360c5ae1db92b49cacb4b22214ecb7"},{"hash":"sha256","prefix":"0x04088ec24220","suffix":"0x2014292d77fd88baf068c554db08686c7556f8d789473ee01e91c161718fde1488"},{"hash":"sha256","prefix":"0x060e8ec24220","suffix":"0x20192764ea5e79cb32fb3f11d53665d29a0d777f825a6491961880ba1e54340dcc"},{"hash":"sha256","prefix":"0x0a2686824820396ef3cfb88b312d20207809d07000efd88f2f7dc5229feb6cfda4d68265ceda20","suffix":"0x0"},{"hash":"sha256","prefix":"0x0e5eb2bc4d202060c7dd2d09459ea291a7e3f62d2c3360fcd3ea27086c028517b7e82e2e202920","suffix":"0x0"},{"hash":"sha256","prefix":"0x10a401d88b5120","suffix":"0x200299e87712cc651d04fad22c28362ad369aeaace382f114129927cebc5814b3f"},{"hash":"sha256","prefix":"0x12ee02d8f35420f2b92607b1b503d7b99066321684b44916ec3d8a259e9e5e455998c2a731af9520","suffix":"0x0"},{"hash":"sha256","prefix":"0x14a605dcc25520","suffix":"0x200adfc7b130f05f71c93a3c9279bfc0a5f02a7c4cc0cf8a0259da481133333ec0"},{"hash":"sha256","prefix":"0x169008dcc25520","suffix":"0x2069b0cf34242930daf1c8b4fac14678e5e15fc3887c9747a225da780b6e275290"},{"hash":"sha256","prefix":"0x18c610dcc25520","suffix":"0x20bd446e2cb44c82425f1f283d9ba42301e348822b2d5f91666afe3b94a4f9c662"},{"hash":"sha256","prefix":"0x1ad41da2e85520","suffix":"0x209e357f1f4b212b7f0cd13736eec2a20a094ff17e19f57065076c60325dc5d7f4"},{"hash":"sha256","prefix":"0x1ca836a2e855202011f44d3da40ce2d9b4d69ff3df4ba7b0f60bb4d74b45cdcfffe52a1d7e595920","suffix":"0x0"},{"hash":"sha256","prefix":"0x1eda5aa2e85520ebda986e64b5c8babb379a66e2df0ed13bf5ef0969912eaffbfc8922882774f420","suffix":"0x0"},{"hash":"sha256","prefix":"0x20fe9701a2e855201691ba603c7516af8f6e5e3870b2e9b5d93c15740f6a8b5e067d75ee01fe62c820","suffix":"0x0"},{"hash":"sha256","prefix":"0x228cdf02a4eb5520","suffix":"0x20406d8a6e864102c22e775f263a958ec616400989f622611c39a7acc6183d9b56"},{"hash":"sha256","prefix":"0x24aede05a4eb5520832741e67cb7525c0c6e44f08a2ccab31c4f6e471722e482ef339d3d3c3f993a20","suffix":"0x0"},{"hash":"sha256","prefix":"0x26b4920dd4eb5520f558704bc9c29285f8f12c297be86ab3deca125bb8a20217d82930625210b8cb20","suffix":"0x0"},{"hash":"sha256","prefix":"0x2ab2ab1bd4eb5520","suffix":"0x20337ac646c149de08f877f613c9d7798a96826cbf6977ed64f6ed21c36170ba8d"},{"hash":"sha256","prefix":"0x2ea4f736d4eb5520","suffix":"0x20717db97555e834d39eebe08926de9db1274bd0e4e4646e5fdbd3afd1649e0e62"},{"hash":"sha256","prefix":"0x30849ea701d4eb5520","suffix":"0x20f6d89b3895fbfb8b8d544bf6b55eeaafb38105d3300288f154e638e9de2b676d"},{"hash":"sha256","prefix":"0x34b8aeef02d4eb5520","suffix":"0x2016a4876d8857304b2b073839832c04e964558a6d4dbf276d5153ec910ad55069"},{"hash":"sha256","prefix":"0x36fec9e106d4eb5520","suffix":"0x20c65ebf738e8d4eafce3f004311c65b5eeda4af6fa1246c12b05255b888103801"}]},"right":{"key":"0x0316d5260c4a4bc907b79822b792cf51c57f05f311f1e79309b7cc3ece4e277af300c8ed3c81d8f763521509ba9b4d310c1f46dce8efe732a512cdd14de4ca7d6a","value":"0x01502841361a25f62632a8138da2f5da1948539832b0fc2bfb733cc7b081836e","leaf":{"hash":"sha256","prehash_key":"no_hash","prehash_value":"sha256","length":"var_proto","prefix":"0x0002eaba41"},"path":[{"hash":"sha256","prefix":"0x0204dc90422019e61e56713d298667526e3160efc7939ebec8acdba0a73a0a9a5d7d6e40c25820","suffix":"0x0"},{"hash":"sha256","prefix":"0x04088ec24220","suffix":"0x2014292d77fd88baf068c554db08686c7556f8d789473ee01e91c161718fde1488"},{"hash":"sha256","prefix":"0x060e8ec24220","suffix":"0x20192764ea5e79cb32fb3f11d53665d29a0d777f825a6491961880ba1e54340dcc"},{"hash":"sha256","prefix":"0x0a2686824820396ef3cfb88b312d20207809d07000efd88f2f7dc5229feb6cfda4d68265ceda20","suffix":"0x0"},{"hash":"sha256","prefix":"0x0e5eb2bc4d202060c7dd2d09459ea291a7e3f62d2c3360fcd3ea27086c028517b7e82e2e202920","suffix":"0x0"},{"hash":"sha256","prefix":"0x10a401d88b5120","suffix":"0x200299e87712cc651d04fad22c28362ad369aeaace382f114129927cebc5814b3f"},{"hash":"sha256","prefix":"0x12ee02d8f35420f2b92607b1b503d7b99066321684b44916ec3d8a259e9e5e455998c2a731af9520","suffix":"0x0"},{"hash":"sha256","prefix":"0x14a605dcc25520","suffix":"0x200adfc7b130f05f71c93a3c9279bfc0a5f02a7c4cc0cf8a0259da481133333ec0"},{"hash":"sha256","prefix":"0x169008dcc25520","suffix":"0x2069b0cf34242930daf1c8b4fac14678e5e15fc3887c9747a225da780b6e275290"},{"hash":"sha256","prefix":"0x18c610dcc25520","suffix":"0x20bd446e2cb44c82425f1f283d9ba42301e348822b2d5f91666afe3b94a4f9c662"},{"hash":"sha256","prefix":"0x1ad41da2e85520","suffix":"0x209e357f1f4b212b7f0cd13736eec2a20a094ff17e19f57065076c60325dc5d7f4"},{"hash":"sha256","prefix":"0x1ca836a2e855202011f44d3da40ce2d9b4d69ff3df4ba7b0f60bb4d74b45cdcfffe52a1d7e595920","suffix":"0x0"},{"hash":"sha256","prefix":"0x1eda5aa2e85520ebda986e64b5c8babb379a66e2df0ed13bf5ef0969912eaffbfc8922882774f420","suffix":"0x0"},{"hash":"sha256","prefix":"0x20fe9701a2e855201691ba603c7516af8f6e5e3870b2e9b5d93c15740f6a8b5e067d75ee01fe62c820","suffix":"0x0"},{"hash":"sha256","prefix":"0x228cdf02a4eb5520","suffix":"0x20406d8a6e864102c22e775f263a958ec616400989f622611c39a7acc6183d9b56"},{"hash":"sha256","prefix":"0x24aede05a4eb5520832741e67cb7525c0c6e44f08a2ccab31c4f6e471722e482ef339d3d3c3f993a20","suffix":"0x0"},{"hash":"sha256","prefix":"0x26b4920dd4eb5520f558704bc9c29285f8f12c297be86ab3deca125bb8a20217d82930625210b8cb20","suffix":"0x0"},{"hash":"sha256","prefix":"0x2ab2ab1bd4eb5520","suffix":"0x20337ac646c149de08f877f613c9d7798a96826cbf6977ed64f6ed21c36170ba8d"},{"hash":"sha256","prefix":"0x2ea4f736d4eb5520","suffix":"0x20717db97555e834d39eebe08926de9db1274bd0e4e4646e5fdbd3afd1649e0e62"},{"hash":"sha256","prefix":"0x30849ea701d4eb5520","suffix":"0x20f6d89b3895fbfb8b8d544bf6b55eeaafb38105d3300288f154e638e9de2b676d"},{"hash":"sha256","prefix":"0x34b8aeef02d4eb5520","suffix":"0x2016a4876d8857304b2b073839832c04e964558a6d4dbf276d5153ec910ad55069"},{"hash":"sha256","prefix":"0x36fec9e106d4eb5520","suffix":"0x20c65ebf738e8d4eafce3f004311c65b5eeda4af6fa1246c12b05255b888103801"}]}}},{"@type":"exist","@value":{"key":"0x7761736d","value":"0x4db0491378f725764de609a71a4136ab6be503d84ac8d5ffbab71999a3d1b289","leaf":{"hash":"sha256","prehash_key":"no_hash","prehash_value":"sha256","length":"var_proto","prefix":"0x00"},"path":[{"hash":"sha256","prefix":"0x01a88c8b9f6e116cf2bbefa4e6f8f2536baacb38fc74c2badbc0a80477a773366f","suffix":"0x0"},{"hash":"sha256","prefix":"0x01956cff3b80169c81f4fceb114009c474235f13fedca894f904eff4ade91d8e1f","suffix":"0x0"},{"hash":"sha256","prefix":"0x011f031994027f022042795f47eeda31f89e95cf5a2a8bf63ec239f35a05b5bf68","suffix":"0x0"}]}}]}"#;
+
+    fn verify(key: [u8; 32], value: Option<Bytes>, root: [u8; 32]) -> ProofVerification {
+        verify_proof(
+            &IBC_HOST_CONTRACT_ADDRESS.into(),
+            key.into(),
+            value,
+            &serde_json::from_str(PROOF).expect("proof is valid"),
+            &MerkleRoot { hash: root.into() },
+        )
+    }
+
+    #[test]
+    fn valid_proof() {
+        assert_eq!(verify(KEY, None, ROOT), ProofVerification::Valid);
+    }
+
+    #[test]
+    fn unexpected_absence() {
+        assert!(matches!(
+            verify(KEY, Some([1; 32].into()), ROOT),
+            ProofVerification::Invalid { .. }
+        ));
+    }
+
+    #[test]
+    fn other_key() {
+        // outside of the range between the neighbours proven by the non-existence proof
+        let mut key = KEY;
+        key[0] ^= 1;
+
+        assert!(matches!(
+            verify(key, None, ROOT),
+            ProofVerification::Invalid { .. }
+        ));
+    }
+
+    #[test]
+    fn tampered_root() {
+        let mut root = ROOT;
+        root[0] ^= 1;
+
+        assert!(matches!(
+            verify(KEY, None, root),
+            ProofVerification::Invalid { .. }
+        ));
+    }
 }
@@ -2,7 +2,9 @@
 
 use std::num::{NonZeroU32, ParseIntError};
 
+use cometbft_rpc::PoolConfig;
 use ibc_classic_spec::{IbcClassic, StorePath};
+use ics23::ibc_api::{verify_membership, verify_non_membership, SDK_SPECS};
 use jsonrpsee::{
     core::{async_trait, RpcResult},
     types::ErrorObject,
@@ -13,15 +15,20 @@ use serde_json::{json, Value};
 use tracing::{error, instrument};
 use unionlabs::{
     cosmos::ics23::commitment_proof::CommitmentProof,
-    ibc::core::{client::height::Height, commitment::merkle_proof::MerkleProof},
+    ibc::core::{
+        client::height::Height,
+        commitment::{merkle_proof::MerkleProof, merkle_root::MerkleRoot},
+    },
     option_unwrap,
+    primitives::{Bytes, H256},
+    ErrorReporter,
 };
 use voyager_sdk::{
     anyhow, into_value,
     plugin::ProofModule,
     primitives::ChainId,
-    rpc::{rpc_error, types::ProofModuleInfo, ProofModuleServer},
-    types::ProofType,
+    rpc::{rpc_error, types::ProofModuleInfo, ProofModuleServer, FATAL_JSONRPC_ERROR_CODE},
+    types::{ProofType, ProofVerification},
 };
 
 #[tokio::main(flavor = "multi_thread")]
@@ -46,14 +53,15 @@ pub struct Module {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct Config {
-    pub rpc_url: String,
+    /// The RPC endpoints of the chain.
+    pub rpc_url: PoolConfig,
 }
 
 impl ProofModule<IbcClassic> for Module {
     type Config = Config;
 
     async fn new(config: Self::Config, info: ProofModuleInfo) -> anyhow::Result<Self> {
-        let tm_client = cometbft_rpc::Client::new(config.rpc_url).await?;
+        let tm_client = cometbft_rpc::Client::new_pool(config.rpc_url).await?;
 
         let chain_id = tm_client.status().await?.node_info.network;
 
@@ -173,4 +181,127 @@ impl ProofModuleServer<IbcClassic> for Module {
 
         Ok(Some((into_value(proof), proof_type)))
     }
+
+    #[instrument(skip_all, fields(chain_id = %self.chain_id, %path, %root))]
+    async fn verify_ibc_proof(
+        &self,
+        _: &Extensions,
+        path: StorePath,
+        value: Option<Bytes>,
+        proof: Value,
+        root: Bytes,
+    ) -> RpcResult<ProofVerification> {
+        let proof = serde_json::from_value::<MerkleProof>(proof).map_err(|e| {
+            ErrorObject::owned(
+                FATAL_JSONRPC_ERROR_CODE,
+                format!("invalid proof: {}", ErrorReporter(e)),
+                None::<()>,
+            )
+        })?;
+
+        let root = MerkleRoot {
+            hash: H256::try_from(&*root).map_err(|_| {
+                ErrorObject::owned(
+                    FATAL_JSONRPC_ERROR_CODE,
+                    format!("invalid app hash {root}, expected 32 bytes"),
+                    None::<()>,
+                )
+            })?,
+        };
+
+        Ok(verify_proof(&path, value, &proof, &root))
+    }
+}
+
+/// Verify that `proof` proves `value` at `path` in the ibc store under `root`, or that nothing is
+/// stored at `path` if `value` is `None`.
+fn verify_proof(
+    path: &StorePath,
+    value: Option<Bytes>,
+    proof: &MerkleProof,
+    root: &MerkleRoot,
+) -> ProofVerification {
+    const IBC_STORE_PREFIX: &[u8] = b"ibc";
+
+    let key_path = [IBC_STORE_PREFIX.to_vec(), path.to_string().into_bytes()];
+
+    let res = match value {
+        Some(value) => verify_membership(proof, &SDK_SPECS, root, &key_path, value.into_vec()),
+        None => verify_non_membership(proof, &SDK_SPECS, root, &key_path),
+    };
+
+    match res {
+        Ok(()) => ProofVerification::Valid,
+        Err(e) => ProofVerification::Invalid {
+            reason: ErrorReporter(e).to_string(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hex_literal::hex;
+    use unionlabs::encoding::{DecodeAs, Proto};
+
+    use super::*;
+
+    // connections/connection-1 on a union devnet, see `ics23::ibc_api::tests::connection_exists`
+    const ROOT: [u8; 32] = hex!("899CD0B55A4FEDE9AF3C959C43ED3AE6805293642590A81CD95B4C97F89CC424");
+    const PROOF: &[u8] = &hex!("0abc020ab9020a18636f6e6e656374696f6e732f636f6e6e656374696f6e2d31125b0a0930382d7761736d2d3112230a0131120d4f524445525f4f524445524544120f4f524445525f554e4f524445524544180222250a0e636f6d6574626c732d6e65772d30120c636f6e6e656374696f6e2d301a050a0369626328061a0c0801180120012a040002f006222c080112050204f006201a212075c4910f51207d3c65960120fe931f138e2624668d75869f51b8442593dd5eab222a080112260408de0a2002b6fcf07091245d162f1196b003c555c564980e02c4d4a9fa0a249798f4b25e20222c08011205060ede0a201a2120ff6b0a04e076eecbabfee4e751c0523cbedba898211b5847404e2d954a2203e3222a08011226081ede0a20635053419cfb6a81c839860d99f3ed002840124a790ddd9f066d8bce63f9df54200afc010af9010a03696263122024b15e198bcf648dee62c7ca1fd8c3950c85c3d898833180c3e3c412ccbc559d1a090801180120012a01002225080112210106b99c0d8119ff1edbcbe165d0f19337dbbc080e677c88e57aa2ae767ebf0f0f222708011201011a20aa650406ea0d76e39dd43d2ea6a91e3fdaa1c908fc21a7ca68e5e62cc8115639222508011221016ac3182364d7cdaa1f52a77b6081e070aa29b2d253f3642169693cde336e2bdc222508011221016376cbd7b917c7105ddac35bdeddd79e6c9cbbc66dd227941599de2b9bc8b3de222708011201011a200d68ac7c3e8daf94c65ccdfe5b7397f50e80325240ef9b2a0ec483afaea30544");
+    const VALUE: &[u8] = &hex!("0a0930382d7761736d2d3112230a0131120d4f524445525f4f524445524544120f4f524445525f554e4f524445524544180222250a0e636f6d6574626c732d6e65772d30120c636f6e6e656374696f6e2d301a050a036962632806");
+
+    fn verify(path: &str, value: Option<&[u8]>, root: [u8; 32]) -> ProofVerification {
+        verify_proof(
+            &path.parse().unwrap(),
+            value.map(Into::into),
+            &MerkleProof::decode_as::<Proto>(PROOF).unwrap(),
+            &MerkleRoot { hash: root.into() },
+        )
+    }
+
+    #[test]
+    fn valid_proof() {
+        assert_eq!(
+            verify("connections/connection-1", Some(VALUE), ROOT),
+            ProofVerification::Valid
+        );
+    }
+
+    #[test]
+    fn unexpected_value() {
+        let mut value = VALUE.to_vec();
+        *value.last_mut().unwrap() ^= 1;
+
+        assert!(matches!(
+            verify("connections/connection-1", Some(&value), ROOT),
+            ProofVerification::Invalid { .. }
+        ));
+    }
+
+    #[test]
+    fn unexpected_existence() {
+        assert!(matches!(
+            verify("connections/connection-1", None, ROOT),
+            ProofVerification::Invalid { .. }
+        ));
+    }
+
+    #[test]
+    fn other_path() {
+        assert!(matches!(
+            verify("connections/connection-2", Some(VALUE), ROOT),
+            ProofVerification::Invalid { .. }
+        ));
+    }
+
+    #[test]
+    fn tampered_root() {
+        let mut root = ROOT;
+        root[0] ^= 1;
+
+        assert!(matches!(
+            verify("connections/connection-1", Some(VALUE), root),
+            ProofVerification::Invalid { .. }
+        ));
+    }
 }
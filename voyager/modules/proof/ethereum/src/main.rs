@@ -2,27 +2,29 @@
 
 use alloy::providers::{DynProvider, Provider, ProviderBuilder};
 use ethereum_light_client_types::StorageProof;
+use evm_storage_verifier::{verify_storage_absence, verify_storage_proof};
 use ibc_union_spec::{path::StorePath, IbcUnion};
 use jsonrpsee::{
     core::{async_trait, RpcResult},
     types::ErrorObject,
     Extensions,
 };
+use rpc_pool::PoolConfig;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use tracing::{debug, instrument};
 use unionlabs::{
     ethereum::ibc_commitment_key,
     ibc::core::client::height::Height,
-    primitives::{H160, U256},
+    primitives::{Bytes, H160, H256, U256},
     ErrorReporter,
 };
 use voyager_sdk::{
     anyhow, into_value,
     plugin::ProofModule,
     primitives::ChainId,
-    rpc::{types::ProofModuleInfo, ProofModuleServer},
-    types::ProofType,
+    rpc::{types::ProofModuleInfo, ProofModuleServer, FATAL_JSONRPC_ERROR_CODE},
+    types::{ProofType, ProofVerification},
 };
 
 #[tokio::main(flavor = "multi_thread")]
@@ -45,8 +47,8 @@ pub struct Config {
     /// The address of the `IBCHandler` smart contract.
     pub ibc_handler_address: H160,
 
-    /// The RPC endpoint for the execution chain.
-    pub rpc_url: String,
+    /// The RPC endpoints for the execution chain.
+    pub rpc_url: PoolConfig,
 
     #[serde(default)]
     pub max_cache_size: u32,
@@ -59,8 +61,7 @@ impl ProofModule<IbcUnion> for Module {
         let provider = DynProvider::new(
             ProviderBuilder::new()
                 // .layer(CacheLayer::new(config.max_cache_size))
-                .connect(&config.rpc_url)
-                .await?,
+                .connect_client(rpc_pool::alloy::client(config.rpc_url).await?),
         );
 
         let chain_id = provider.get_chain_id().await?;
@@ -137,4 +138,212 @@ impl ProofModuleServer<IbcUnion> for Module {
 
         Ok(Some((into_value(proof), proof_type)))
     }
+
+    #[instrument(skip_all, fields(chain_id = %self.chain_id, ?path, %root))]
+    async fn verify_ibc_proof(
+        &self,
+        _: &Extensions,
+        path: StorePath,
+        value: Option<Bytes>,
+        proof: Value,
+        root: Bytes,
+    ) -> RpcResult<ProofVerification> {
+        let proof = serde_json::from_value::<StorageProof>(proof).map_err(|e| {
+            ErrorObject::owned(
+                FATAL_JSONRPC_ERROR_CODE,
+                format!("invalid proof: {}", ErrorReporter(e)),
+                None::<()>,
+            )
+        })?;
+
+        let storage_root = H256::try_from(&*root).map_err(|_| {
+            ErrorObject::owned(
+                FATAL_JSONRPC_ERROR_CODE,
+                format!("invalid storage root {root}, expected 32 bytes"),
+                None::<()>,
+            )
+        })?;
+
+        let value = value
+            .map(|value| {
+                H256::try_from(&*value)
+                    .map(|value| U256::from_be_bytes(*value.get()))
+                    .map_err(|_| {
+                        ErrorObject::owned(
+                            FATAL_JSONRPC_ERROR_CODE,
+                            format!("invalid commitment {value}, expected 32 bytes"),
+                            None::<()>,
+                        )
+                    })
+            })
+            .transpose()?;
+
+        Ok(verify_proof(path.key(), value, &proof, storage_root))
+    }
+}
+
+/// Verify that `proof` proves `value` in the commitments mapping of the ibc handler under
+/// `storage_root`, at the slot of the commitment `key`. If `value` is `None`, the proof must prove
+/// that the slot is empty.
+///
+/// The value contained in `proof` is not used, the proof is always checked against `value`.
+fn verify_proof(
+    key: H256,
+    value: Option<U256>,
+    proof: &StorageProof,
+    storage_root: H256,
+) -> ProofVerification {
+    let location = ibc_commitment_key(key);
+
+    if proof.key != location {
+        return ProofVerification::Invalid {
+            reason: format!("proof is for slot {}, expected slot {location}", proof.key),
+        };
+    }
+
+    let res = match value {
+        Some(value) => {
+            verify_storage_proof(storage_root, location, &rlp::encode(&value), &proof.proof)
+                .map_err(|e| ErrorReporter(e).to_string())
+        }
+        None => match verify_storage_absence(storage_root, location, &proof.proof) {
+            Ok(true) => Ok(()),
+            Ok(false) => Err("a value is stored at the proven slot".to_owned()),
+            Err(e) => Err(ErrorReporter(e).to_string()),
+        },
+    };
+
+    match res {
+        Ok(()) => ProofVerification::Valid,
+        Err(reason) => ProofVerification::Invalid { reason },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use evm_storage_verifier::test_utils::TestTrie;
+    use ibc_union_spec::path::BatchPacketsPath;
+
+    use super::*;
+
+    fn key(batch_hash: u8) -> H256 {
+        StorePath::BatchPackets(BatchPacketsPath {
+            batch_hash: H256::new([batch_hash; 32]),
+        })
+        .key()
+    }
+
+    fn trie() -> TestTrie {
+        TestTrie::storage([
+            (ibc_commitment_key(key(1)), U256::from(1_u64)),
+            (ibc_commitment_key(key(2)), U256::from(2_u64)),
+        ])
+    }
+
+    fn proof(trie: &TestTrie, key: H256) -> StorageProof {
+        StorageProof {
+            key: ibc_commitment_key(key),
+            value: U256::ZERO,
+            proof: trie.storage_proof(ibc_commitment_key(key)),
+        }
+    }
+
+    #[test]
+    fn valid_proofs() {
+        let trie = trie();
+
+        assert_eq!(
+            verify_proof(
+                key(1),
+                Some(U256::from(1_u64)),
+                &proof(&trie, key(1)),
+                trie.root()
+            ),
+            ProofVerification::Valid
+        );
+
+        assert_eq!(
+            verify_proof(key(3), None, &proof(&trie, key(3)), trie.root()),
+            ProofVerification::Valid
+        );
+    }
+
+    #[test]
+    fn embedded_value_is_ignored() {
+        let trie = trie();
+
+        let mut proof = proof(&trie, key(1));
+        proof.value = U256::from(2_u64);
+
+        assert_eq!(
+            verify_proof(key(1), Some(U256::from(1_u64)), &proof, trie.root()),
+            ProofVerification::Valid
+        );
+
+        assert!(matches!(
+            verify_proof(key(1), Some(U256::from(2_u64)), &proof, trie.root()),
+            ProofVerification::Invalid { .. }
+        ));
+    }
+
+    #[test]
+    fn unexpected_value() {
+        let trie = trie();
+
+        assert!(matches!(
+            verify_proof(key(1), None, &proof(&trie, key(1)), trie.root()),
+            ProofVerification::Invalid { .. }
+        ));
+
+        assert!(matches!(
+            verify_proof(
+                key(3),
+                Some(U256::from(1_u64)),
+                &proof(&trie, key(3)),
+                trie.root()
+            ),
+            ProofVerification::Invalid { .. }
+        ));
+    }
+
+    #[test]
+    fn other_slot() {
+        let trie = trie();
+
+        assert!(matches!(
+            verify_proof(
+                key(2),
+                Some(U256::from(1_u64)),
+                &proof(&trie, key(1)),
+                trie.root()
+            ),
+            ProofVerification::Invalid { .. }
+        ));
+    }
+
+    #[test]
+    fn tampered_proof() {
+        let trie = trie();
+
+        let mut proof = proof(&trie, key(1));
+        let node = proof.proof.last_mut().expect("proof is not empty");
+        let mut bytes = node.to_vec();
+        *bytes.last_mut().expect("node is not empty") ^= 1;
+        *node = bytes.into();
+
+        assert!(matches!(
+            verify_proof(key(1), Some(U256::from(1_u64)), &proof, trie.root()),
+            ProofVerification::Invalid { .. }
+        ));
+
+        assert!(matches!(
+            verify_proof(
+                key(1),
+                Some(U256::from(1_u64)),
+                &self::proof(&trie, key(1)),
+                H256::new([0xAA; 32])
+            ),
+            ProofVerification::Invalid { .. }
+        ));
+    }
 }
@@ -24,7 +24,7 @@ use voyager_sdk::{
     plugin::ProofModule,
     primitives::ChainId,
     rpc::{rpc_error, types::ProofModuleInfo, ProofModuleServer, FATAL_JSONRPC_ERROR_CODE},
-    types::ProofType,
+    types::{ProofType, ProofVerification},
 };
 
 #[tokio::main(flavor = "multi_thread")]
@@ -193,4 +193,16 @@ impl ProofModuleServer<IbcUnion> for Module {
 
         Ok(Some((into_value(proof), proof_type)))
     }
+
+    #[instrument(skip_all, fields(chain_id = %self.chain_id))]
+    async fn verify_ibc_proof(
+        &self,
+        _: &Extensions,
+        _path: StorePath,
+        _value: Option<Bytes>,
+        _proof: Value,
+        _root: Bytes,
+    ) -> RpcResult<ProofVerification> {
+        Ok(ProofVerification::Unsupported)
+    }
 }
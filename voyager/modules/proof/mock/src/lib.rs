@@ -0,0 +1,92 @@
+use ibc_union_spec::{path::StorePath, IbcUnion};
+use jsonrpsee::{
+    core::{async_trait, RpcResult},
+    http_client::{HttpClient, HttpClientBuilder},
+    types::ErrorObject,
+    Extensions,
+};
+use mock_chain::rpc::MockChainRpcClient;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tracing::instrument;
+use unionlabs::{ibc::core::client::height::Height, primitives::Bytes, ErrorReporter};
+use voyager_sdk::{
+    anyhow, into_value,
+    plugin::ProofModule,
+    primitives::ChainId,
+    rpc::{types::ProofModuleInfo, ProofModuleServer},
+    types::{ProofType, ProofVerification},
+};
+
+#[derive(Debug, Clone)]
+pub struct Module {
+    pub chain_id: ChainId,
+
+    pub client: HttpClient,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// The RPC endpoint of the mock chain.
+    pub rpc_url: String,
+}
+
+impl ProofModule<IbcUnion> for Module {
+    type Config = Config;
+
+    async fn new(config: Self::Config, info: ProofModuleInfo) -> anyhow::Result<Self> {
+        let client = HttpClientBuilder::default().build(&config.rpc_url)?;
+
+        let chain_id = client.chain_id().await?;
+
+        info.ensure_chain_id(chain_id.to_string())?;
+
+        Ok(Self { chain_id, client })
+    }
+}
+
+#[async_trait]
+impl ProofModuleServer<IbcUnion> for Module {
+    #[instrument(skip_all, fields(chain_id = %self.chain_id, %at))]
+    async fn query_ibc_proof(
+        &self,
+        _: &Extensions,
+        at: Height,
+        path: StorePath,
+    ) -> RpcResult<Option<(Value, ProofType)>> {
+        let key = path.key();
+
+        let proof = self
+            .client
+            .ibc_proof(at.height(), path)
+            .await
+            .map_err(|e| {
+                ErrorObject::owned(
+                    -1,
+                    ErrorReporter(e).with_message("error fetching proof"),
+                    None::<()>,
+                )
+            })?;
+
+        let proof_type = if proof.get(&key).is_some() {
+            ProofType::Membership
+        } else {
+            ProofType::NonMembership
+        };
+
+        Ok(Some((into_value(proof), proof_type)))
+    }
+
+    #[instrument(skip_all, fields(chain_id = %self.chain_id))]
+    async fn verify_ibc_proof(
+        &self,
+        _: &Extensions,
+        _path: StorePath,
+        _value: Option<Bytes>,
+        _proof: Value,
+        _root: Bytes,
+    ) -> RpcResult<ProofVerification> {
+        Ok(ProofVerification::Unsupported)
+    }
+}
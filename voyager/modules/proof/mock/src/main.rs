@@ -0,0 +1,7 @@
+use voyager_proof_module_mock::Module;
+use voyager_sdk::plugin::ProofModule;
+
+#[tokio::main(flavor = "multi_thread")]
+async fn main() {
+    Module::run().await
+}
@@ -18,7 +18,7 @@ use unionlabs::{
         storage_proof::{StateValue, StateValueMetadata, StorageProof},
     },
     ibc::core::client::height::Height,
-    primitives::{H256, U256},
+    primitives::{Bytes, H256, U256},
     ErrorReporter,
 };
 use voyager_sdk::{
@@ -26,7 +26,7 @@ use voyager_sdk::{
     plugin::ProofModule,
     primitives::ChainId,
     rpc::{types::ProofModuleInfo, ProofModuleServer},
-    types::ProofType,
+    types::{ProofType, ProofVerification},
 };
 
 #[tokio::main(flavor = "multi_thread")]
@@ -115,52 +115,76 @@ impl ProofModuleServer<IbcUnion> for Module {
         &self,
         _: &Extensions,
         at: Height,
-        _path: StorePath,
+        path: StorePath,
     ) -> RpcResult<Option<(Value, ProofType)>> {
         let ledger_version = self.ledger_version_of_height(at.height()).await;
 
         let vault_addr = self
             .get_vault_addr(self.ibc_handler_address.into(), Some(ledger_version))
             .await
-            .unwrap();
+            .map_err(rest_error_to_rpc_error)?;
 
-        let _address_str = self
+        let commitments_handle = self
             .aptos_client
             .get_account_resource(
                 vault_addr.into(),
                 &format!("{}::ibc::IBCStore", self.ibc_handler_address),
             )
             .await
-            .unwrap()
+            .map_err(rest_error_to_rpc_error)?
             .into_inner()
-            .unwrap()
-            .data["commitments"]["handle"]
-            .clone()
-            .as_str()
-            .unwrap()
-            .to_owned();
-        let _address = <H256>::new(U256::from_be_hex(_address_str).unwrap().to_be_bytes());
-
-        // NOTE(aeryz): This only works with Union's custom Movement node. When the following PR is merged,
-        // we will uncomment this: https://github.com/movementlabsxyz/movement/pull/645
-        // let storage_proof = get_storage_proof(
-        //     &self.ctx.movement_rpc_url,
-        //     address,
-        //     hex::encode(bcs::to_bytes(&path.to_string().as_bytes()).expect("won't fail")),
-        //     at.revision_height,
-        // ).await;
-
-        Ok(Some((
-            into_value(StorageProof {
-                state_value: None,
-                proof: SparseMerkleProof {
-                    leaf: None,
-                    siblings: Vec::new(),
-                },
-            }),
-            // TODO: Implement properly, see above
-            ProofType::Membership,
-        )))
+            .and_then(|resource| {
+                resource.data["commitments"]["handle"]
+                    .as_str()
+                    .map(ToOwned::to_owned)
+            })
+            .ok_or_else(|| {
+                ErrorObject::owned(
+                    -1,
+                    format!("commitments table not found at height {at}"),
+                    None::<()>,
+                )
+            })?;
+
+        let commitments_handle = U256::from_be_hex(&commitments_handle).map_err(|e| {
+            ErrorObject::owned(
+                -1,
+                ErrorReporter(e).with_message("invalid commitments table handle"),
+                None::<()>,
+            )
+        })?;
+
+        // NOTE(aeryz): This only works with Union's custom Movement node, see
+        // https://github.com/movementlabsxyz/movement/pull/645
+        let storage_proof = get_storage_proof(
+            &self.movement_rpc_url,
+            <H256>::new(commitments_handle.to_be_bytes()),
+            hex::encode(bcs::to_bytes(path.key().get().as_slice()).expect("won't fail")),
+            at.height(),
+        )
+        .await?;
+
+        // the table item is removed once the commitment is deleted, in which case the proof is an
+        // absence proof in the sparse merkle tree
+        let proof_type = if storage_proof.state_value.is_some() {
+            ProofType::Membership
+        } else {
+            ProofType::NonMembership
+        };
+
+        Ok(Some((into_value(storage_proof), proof_type)))
+    }
+
+    #[instrument(skip_all, fields(chain_id = %self.chain_id))]
+    async fn verify_ibc_proof(
+        &self,
+        _: &Extensions,
+        _path: StorePath,
+        _value: Option<Bytes>,
+        _proof: Value,
+        _root: Bytes,
+    ) -> RpcResult<ProofVerification> {
+        Ok(ProofVerification::Unsupported)
     }
 }
 
@@ -173,7 +197,7 @@ pub async fn get_storage_proof(
     address: H256,
     key: String,
     height: u64,
-) -> StorageProof {
+) -> RpcResult<StorageProof> {
     let client = reqwest::Client::new();
 
     let req =
@@ -182,9 +206,28 @@ pub async fn get_storage_proof(
     let (state_value, proof): (
         Option<aptos_types::state_store::state_value::StateValue>,
         aptos_types::proof::SparseMerkleProof,
-    ) = client.get(req).send().await.unwrap().json().await.unwrap();
+    ) = client
+        .get(req)
+        .send()
+        .await
+        .map_err(|e| {
+            ErrorObject::owned(
+                -1,
+                ErrorReporter(e).with_message("error fetching the storage proof"),
+                None::<()>,
+            )
+        })?
+        .json()
+        .await
+        .map_err(|e| {
+            ErrorObject::owned(
+                -1,
+                ErrorReporter(e).with_message("error decoding the storage proof"),
+                None::<()>,
+            )
+        })?;
 
-    StorageProof {
+    Ok(StorageProof {
         state_value: state_value.map(|s| {
             let (metadata, data) = s.unpack();
             match metadata.into_persistable() {
@@ -226,5 +269,5 @@ pub async fn get_storage_proof(
                 .map(Into::into)
                 .collect(),
         },
-    }
+    })
 }
@@ -19,19 +19,24 @@ use sui_light_client_types::{
 use sui_sdk::{
     rpc_types::{SuiObjectDataOptions, SuiTransactionBlockResponseOptions},
     types::{
-        base_types::ObjectID as SuiObjectID, effects::TransactionEvents,
-        messages_checkpoint::CertifiedCheckpointSummary, object::Object, transaction::Transaction,
+        base_types::ObjectID as SuiObjectID, digests::TransactionDigest,
+        effects::TransactionEvents, messages_checkpoint::CertifiedCheckpointSummary,
+        object::Object, transaction::Transaction,
     },
     SuiClientBuilder,
 };
 use tracing::instrument;
-use unionlabs::{ibc::core::client::height::Height, ErrorReporter};
+use unionlabs::{
+    ibc::core::client::height::Height,
+    primitives::{Bytes, FixedBytes},
+    ErrorReporter,
+};
 use voyager_sdk::{
     anyhow, into_value,
     plugin::ProofModule,
     primitives::ChainId,
     rpc::{types::ProofModuleInfo, ProofModuleServer, FATAL_JSONRPC_ERROR_CODE},
-    types::ProofType,
+    types::{ProofType, ProofVerification},
 };
 
 #[tokio::main(flavor = "multi_thread")]
@@ -92,6 +97,65 @@ impl Module {
     pub fn make_height(&self, height: u64) -> Height {
         Height::new(height)
     }
+
+    /// Fetch the contents of the checkpoint that includes the transaction `tx_digest`, and the
+    /// effects of the transaction. The proof `height` must be the height of this checkpoint.
+    async fn fetch_transaction_effects(
+        &self,
+        height: Height,
+        tx_digest: TransactionDigest,
+    ) -> RpcResult<(CheckpointContents, TransactionEffects)> {
+        let checkpoint_number = self
+            .sui_client
+            .read_api()
+            .get_transaction_with_options(tx_digest, SuiTransactionBlockResponseOptions::new())
+            .await
+            .map_err(|e| err(e, "error fetching the tx"))?
+            .checkpoint
+            .ok_or_else(|| {
+                ErrorObject::owned(
+                    -1,
+                    format!("the transaction {tx_digest} is not yet included in a checkpoint"),
+                    None::<()>,
+                )
+            })?;
+
+        if height.height() != checkpoint_number {
+            return Err(ErrorObject::owned(
+                FATAL_JSONRPC_ERROR_CODE,
+                format!("the proof height {height} must match the height of the transaction {checkpoint_number} where the object is modified"),
+                None::<()>,
+            ));
+        }
+
+        let client = reqwest::Client::new();
+        let req = format!("{}/{checkpoint_number}.chk", self.sui_object_store_rpc_url);
+        let res = client
+            .get(req)
+            .send()
+            .await
+            .map_err(|e| err(e, "error fetching the tx"))?
+            .bytes()
+            .await
+            .map_err(|e| err(e, "error fetching the tx"))?;
+
+        let (_, checkpoint) = bcs::from_bytes::<(u8, CheckpointData)>(&res)
+            .map_err(|e| err(e, "invalid checkpoint data"))?;
+
+        let tx = checkpoint
+            .transactions
+            .iter()
+            .find(|tx| *tx.transaction.digest() == tx_digest)
+            .ok_or_else(|| {
+                ErrorObject::owned(
+                    FATAL_JSONRPC_ERROR_CODE,
+                    format!("the transaction {tx_digest} is not included in checkpoint {checkpoint_number}"),
+                    None::<()>,
+                )
+            })?;
+
+        Ok((checkpoint.checkpoint_contents, tx.effects.clone()))
+    }
 }
 
 fn err<T: core::error::Error>(e: T, msg: &str) -> ErrorObject {
@@ -131,63 +195,113 @@ impl ProofModuleServer<IbcUnion> for Module {
             )
             .await
             .map_err(|e| err(e, "error fetching the object"))?
-            .data
-            .expect("data is fetched");
+            .data;
 
-        let previous_tx = target_object
-            .previous_transaction
-            .expect("tx info is requested");
-
-        let checkpoint_number = self
-            .sui_client
-            .read_api()
-            .get_transaction_with_options(previous_tx, SuiTransactionBlockResponseOptions::new())
-            .await
-            .map_err(|e| err(e, "error fetching the tx"))?
-            .checkpoint
-            .expect("checkpoint is fetched");
-
-        if height.height() != checkpoint_number {
+        // sui checkpoints do not commit to the object set, so the absence of an object cannot be
+        // proven
+        let Some(target_object) = target_object else {
             return Err(ErrorObject::owned(
                 FATAL_JSONRPC_ERROR_CODE,
-                format!("the proof height {height} must match the height of the transaction {checkpoint_number} where the object is modified"),
+                format!(
+                    "the object {target_object_id} for path {path:?} does not exist, \
+                    non-membership proofs are not supported on sui"
+                ),
                 None::<()>,
             ));
-        }
+        };
 
-        let client = reqwest::Client::new();
-        let req = format!("{}/{checkpoint_number}.chk", self.sui_object_store_rpc_url);
-        let res = client
-            .get(req)
-            .send()
-            .await
-            .map_err(|e| err(e, "error fetching the tx"))?
-            .bytes()
-            .await
-            .map_err(|e| err(e, "error fetching the tx"))?;
-
-        let (_, checkpoint) = bcs::from_bytes::<(u8, CheckpointData)>(&res)
-            .map_err(|e| err(e, "invalid checkpoint data"))?;
+        let previous_tx = target_object.previous_transaction.ok_or_else(|| {
+            ErrorObject::owned(
+                FATAL_JSONRPC_ERROR_CODE,
+                format!("the previous transaction of the object {target_object_id} is not returned by the rpc"),
+                None::<()>,
+            )
+        })?;
 
-        let tx = checkpoint
-            .transactions
-            .iter()
-            .find(|tx| *tx.transaction.digest() == previous_tx)
-            .unwrap();
+        let (checkpoint_contents, transaction_effects) =
+            self.fetch_transaction_effects(height, previous_tx).await?;
 
-        let object = convert_object(target_object.try_into().expect(
-            "target object should have all the info needed to be able to make it into an object",
-        ));
+        let object = convert_object(target_object.try_into().map_err(|e| {
+            ErrorObject::owned(
+                FATAL_JSONRPC_ERROR_CODE,
+                format!("the object {target_object_id} returned by the rpc is incomplete: {e:#}"),
+                None::<()>,
+            )
+        })?);
 
         Ok(Some((
             into_value(StorageProof {
-                checkpoint_contents: checkpoint.checkpoint_contents,
-                transaction_effects: tx.effects.clone(),
+                checkpoint_contents,
+                transaction_effects,
                 object,
             }),
             ProofType::Membership,
         )))
     }
+
+    #[instrument(skip_all, fields(chain_id = %self.chain_id, ?path, %root))]
+    async fn verify_ibc_proof(
+        &self,
+        _: &Extensions,
+        path: StorePath,
+        value: Option<Bytes>,
+        proof: Value,
+        root: Bytes,
+    ) -> RpcResult<ProofVerification> {
+        // non-membership proofs are not supported by the sui verifier
+        let Some(value) = value else {
+            return Ok(ProofVerification::Unsupported);
+        };
+
+        let proof = serde_json::from_value::<StorageProof>(proof).map_err(|e| {
+            ErrorObject::owned(
+                FATAL_JSONRPC_ERROR_CODE,
+                format!("invalid proof: {}", ErrorReporter(e)),
+                None::<()>,
+            )
+        })?;
+
+        let contents_digest = Digest(FixedBytes::try_from(&*root).map_err(|_| {
+            ErrorObject::owned(
+                FATAL_JSONRPC_ERROR_CODE,
+                format!("invalid checkpoint contents digest {root}, expected 32 bytes"),
+                None::<()>,
+            )
+        })?);
+
+        let key = path.key().into_bytes();
+
+        let StorageProof {
+            checkpoint_contents,
+            transaction_effects,
+            object,
+        } = proof;
+
+        // the commitment is stored as a dynamic field, the value being the last element. the value
+        // stored in the object is not trusted, the object is verified against the expected value.
+        let Data::Move(ref object_data) = object.data;
+
+        let res =
+            match bcs::from_bytes::<(ObjectID, Bytes, Bytes)>(&object_data.contents) {
+                Ok(_) => sui_verifier::verify_membership(
+                    self.ibc_commitments_object_id,
+                    key,
+                    value,
+                    object,
+                    transaction_effects,
+                    checkpoint_contents,
+                    contents_digest,
+                )
+                .map_err(|e| ErrorReporter(e).to_string()),
+                Err(e) => Err(ErrorReporter(e)
+                    .with_message("proven object is not a commitment dynamic field")),
+            };
+
+        Ok(match res {
+            Ok(()) => ProofVerification::Valid,
+            Err(reason) => ProofVerification::Invalid { reason },
+        })
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
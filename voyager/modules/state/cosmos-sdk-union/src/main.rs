@@ -3,11 +3,14 @@
 use std::num::{NonZeroU32, NonZeroU8, ParseIntError};
 
 use cometbft_rpc::rpc_types::Order;
+use cometbft_types::abci::event::Event;
 use cosmos_sdk_event::CosmosSdkEvent;
 use futures::{stream::FuturesUnordered, TryStreamExt};
 use ibc_union_spec::{
     path::StorePath,
-    query::{ClientStatus, PacketByHash, PacketsByBatchHash, Query},
+    query::{
+        ClientStatus, PacketAckByHash, PacketByHash, PacketsByBatchHash, PacketsByChannel, Query,
+    },
     Channel, ChannelId, ClientId, Connection, ConnectionId, IbcUnion, MustBeZero, Packet, Status,
     Timestamp,
 };
@@ -23,7 +26,7 @@ use tracing::{debug, error, instrument, trace};
 use unionlabs::{
     ibc::core::client::height::Height,
     option_unwrap,
-    primitives::{Bech32, Bytes, H256},
+    primitives::{encoding::HexUnprefixed, Bech32, Bytes, H256},
     ErrorReporter,
 };
 use voyager_sdk::{
@@ -160,6 +163,149 @@ impl Module {
         })
     }
 
+    /// Decode the events in `events` that were emitted by the IBC host contract.
+    fn ibc_host_events(&self, events: Vec<Event>) -> RpcResult<Vec<IbcEvent>> {
+        events
+            .into_iter()
+            .filter_map(|event| CosmosSdkEvent::<IbcEvent>::new(event).ok())
+            .filter_map(|e| match e.contract_address {
+                Some(contract_address) => {
+                    (contract_address == self.ibc_host_contract_address).then_some(Ok(e.event))
+                }
+                None => Some(Err(ErrorObject::owned(
+                    -1,
+                    format!("ibc event {e:?} has no contract address"),
+                    None::<()>,
+                ))),
+            })
+            .collect()
+    }
+
+    #[instrument(skip_all, fields(%channel_id, %from_height, %to_height))]
+    pub async fn query_packets_by_channel(
+        &self,
+        channel_id: ChannelId,
+        from_height: u64,
+        to_height: u64,
+    ) -> RpcResult<Vec<Packet>> {
+        const PER_PAGE: NonZeroU8 = option_unwrap!(NonZeroU8::new(100));
+
+        let query = format!(
+            "wasm-packet_send.channel_id={channel_id} \
+            AND tx.height>={from_height} AND tx.height<={to_height}"
+        );
+
+        let mut packets = vec![];
+        let mut seen = 0;
+
+        for page in 1.. {
+            let res = self
+                .cometbft_client
+                .tx_search(
+                    &query,
+                    false,
+                    NonZeroU32::new(page).expect("page is non-zero; qed;"),
+                    PER_PAGE,
+                    Order::Asc,
+                )
+                .await
+                .map_err(rpc_error("error querying packets by channel", None))?;
+
+            seen += res.txs.len();
+
+            for res in res.txs {
+                packets.extend(
+                    self.ibc_host_events(res.tx_result.events)?
+                        .into_iter()
+                        .filter_map(|event| match event {
+                            IbcEvent::WasmPacketSend {
+                                packet_source_channel_id,
+                                packet_destination_channel_id,
+                                packet_data,
+                                packet_timeout_height: _,
+                                packet_timeout_timestamp,
+                                channel_id: _,
+                                packet_hash: _,
+                            } if packet_source_channel_id == channel_id => Some(Packet {
+                                source_channel_id: packet_source_channel_id,
+                                destination_channel_id: packet_destination_channel_id,
+                                data: packet_data,
+                                timeout_height: MustBeZero,
+                                timeout_timestamp: packet_timeout_timestamp,
+                            }),
+                            _ => None,
+                        }),
+                );
+            }
+
+            if seen >= res.total_count as usize || seen == 0 {
+                break;
+            }
+        }
+
+        Ok(packets)
+    }
+
+    #[instrument(skip_all, fields(%channel_id, %packet_hash))]
+    pub async fn query_packet_ack_by_hash(
+        &self,
+        channel_id: ChannelId,
+        packet_hash: H256,
+    ) -> RpcResult<Bytes> {
+        let query = format!(
+            "wasm-write_ack.packet_hash='{packet_hash}' AND wasm-write_ack.channel_id={channel_id}"
+        );
+
+        let mut res = self
+            .cometbft_client
+            .tx_search(
+                query,
+                false,
+                option_unwrap!(NonZeroU32::new(1)),
+                option_unwrap!(NonZeroU8::new(1)),
+                Order::Asc,
+            )
+            .await
+            .map_err(rpc_error(
+                "error querying acknowledgement by packet hash",
+                None,
+            ))?;
+
+        if res.total_count != 1 {
+            return Err(ErrorObject::owned(
+                -1,
+                format!(
+                    "error querying for acknowledgement of packet {packet_hash}, \
+                    expected 1 event but found {}",
+                    res.total_count,
+                ),
+                None::<()>,
+            ));
+        }
+
+        let res = res.txs.pop().unwrap();
+
+        self.ibc_host_events(res.tx_result.events)?
+            .into_iter()
+            .find_map(|event| match event {
+                IbcEvent::WasmWriteAck {
+                    channel_id: event_channel_id,
+                    packet_hash: event_packet_hash,
+                    acknowledgement,
+                } if event_channel_id == channel_id && event_packet_hash == packet_hash => {
+                    Some(acknowledgement.into_encoding())
+                }
+                _ => None,
+            })
+            .ok_or_else(|| {
+                ErrorObject::owned(
+                    -1,
+                    format!("write ack event for packet {packet_hash} not found in tx"),
+                    None::<()>,
+                )
+            })
+    }
+
     #[instrument(skip_all, fields(?height))]
     pub async fn query_smart<Q: Serialize, R: DeserializeOwned>(
         &self,
@@ -448,6 +594,21 @@ impl StateModuleServer<IbcUnion> for Module {
 
                 Ok(into_value(packets))
             }
+            Query::PacketsByChannel(PacketsByChannel {
+                channel_id,
+                from_height,
+                to_height,
+            }) => self
+                .query_packets_by_channel(channel_id, from_height, to_height)
+                .await
+                .map(into_value),
+            Query::PacketAckByHash(PacketAckByHash {
+                channel_id,
+                packet_hash,
+            }) => self
+                .query_packet_ack_by_hash(channel_id, packet_hash)
+                .await
+                .map(into_value),
             Query::ClientStatus(ClientStatus { client_id, height }) => {
                 let status = self
                     .query_smart::<_, Status>(
@@ -555,4 +716,11 @@ pub enum IbcEvent {
         packet_hash: H256,
         batch_hash: H256,
     },
+    #[serde(rename = "wasm-write_ack")]
+    WasmWriteAck {
+        #[serde(with = "serde_utils::string")]
+        channel_id: ChannelId,
+        packet_hash: H256,
+        acknowledgement: Bytes<HexUnprefixed>,
+    },
 }
@@ -6,7 +6,7 @@ use std::{
     num::{NonZeroU64, ParseIntError},
 };
 
-use cometbft_rpc::types::abci::response_query::QueryResponse;
+use cometbft_rpc::{types::abci::response_query::QueryResponse, PoolConfig};
 use ibc_classic_spec::{
     AcknowledgementPath, ChannelEndPath, ClientConsensusStatePath, ClientStatePath, CommitmentPath,
     ConnectionPath, IbcClassic, NextClientSequencePath, NextConnectionSequencePath,
@@ -62,7 +62,8 @@ pub struct Module {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct Config {
-    pub rpc_url: String,
+    /// The RPC endpoints of the chain.
+    pub rpc_url: PoolConfig,
     #[serde(default = "default_max_drift")]
     pub max_drift: u64,
 }
@@ -75,7 +76,7 @@ impl StateModule<IbcClassic> for Module {
     type Config = Config;
 
     async fn new(config: Self::Config, info: StateModuleInfo) -> anyhow::Result<Self> {
-        let tm_client = cometbft_rpc::Client::new(config.rpc_url).await?;
+        let tm_client = cometbft_rpc::Client::new_pool(config.rpc_url).await?;
 
         let chain_id = tm_client.status().await?.node_info.network;
 
@@ -26,6 +26,7 @@ use jsonrpsee::{
     types::ErrorObject,
     Extensions,
 };
+use rpc_pool::PoolConfig;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use tracing::{debug, info, instrument, trace};
@@ -67,8 +68,8 @@ pub struct Config {
     /// The address of the `IBCHandler` smart contract.
     pub ibc_handler_address: H160,
 
-    /// The RPC endpoint for the execution chain.
-    pub rpc_url: String,
+    /// The RPC endpoints for the execution chain.
+    pub rpc_url: PoolConfig,
 
     #[serde(default)]
     pub max_query_window: Option<u64>,
@@ -85,8 +86,7 @@ impl StateModule<IbcUnion> for Module {
             ProviderBuilder::new()
                 .layer(CacheLayer::new(config.max_cache_size))
                 .network::<AnyNetwork>()
-                .connect(&config.rpc_url)
-                .await?,
+                .connect_client(rpc_pool::alloy::client(config.rpc_url).await?),
         );
 
         let chain_id = provider.get_chain_id().await?;
@@ -599,6 +599,149 @@ impl Module {
         ))
     }
 
+    #[instrument(skip_all, fields(chain_id = %self.chain_id, %channel_id, %from_height, %to_height))]
+    async fn packets_by_channel(
+        &self,
+        channel_id: ChannelId,
+        from_height: u64,
+        to_height: u64,
+    ) -> RpcResult<Vec<Packet>> {
+        let ibc_handler = self.ibc_handler();
+
+        // an unbounded query window is a single window covering the entire range
+        let window = self.max_query_window.unwrap_or(u64::MAX).max(1);
+
+        let mut packets = vec![];
+
+        let mut from = from_height;
+        while from <= to_height {
+            let to = from.saturating_add(window - 1).min(to_height);
+
+            debug!(%from, %to, "querying range for packets");
+
+            let query = ibc_handler
+                .PacketSend_filter()
+                .topic1(alloy::primitives::U256::from(channel_id.raw()));
+
+            trace!(?query, "raw query");
+
+            let packet_logs = query
+                .from_block(BlockNumberOrTag::Number(from))
+                .to_block(BlockNumberOrTag::Number(to))
+                .query()
+                .await
+                .map_err(|e| {
+                    ErrorObject::owned(
+                        -1,
+                        format!(
+                            "error querying for packets on channel {channel_id}: {}",
+                            ErrorReporter(e)
+                        ),
+                        None::<()>,
+                    )
+                })?;
+
+            for (packet_log, _) in packet_logs {
+                packets.push(packet_log.packet.try_into().map_err(|e| {
+                    ErrorObject::owned(
+                        -1,
+                        format!(
+                            "error decoding packet send event \
+                            on channel {channel_id}: {}",
+                            ErrorReporter(e)
+                        ),
+                        None::<()>,
+                    )
+                })?);
+            }
+
+            let Some(next) = to.checked_add(1) else {
+                break;
+            };
+            from = next;
+        }
+
+        Ok(packets)
+    }
+
+    #[instrument(skip_all, fields(chain_id = %self.chain_id, %channel_id, %packet_hash))]
+    async fn packet_ack_by_hash(
+        &self,
+        channel_id: ChannelId,
+        packet_hash: H256,
+    ) -> RpcResult<Bytes> {
+        let ibc_handler = self.ibc_handler();
+
+        let windows = match self.max_query_window {
+            Some(window) => {
+                let latest_height = self.provider.get_block_number().await.map_err(|e| {
+                    ErrorObject::owned(
+                        -1,
+                        format!(
+                            "error querying latest height while constructing query windows for decoding write ack event for packet {packet_hash}: {}",
+                            ErrorReporter(e)
+                        ),
+                        None::<()>,
+                    )
+                })?;
+                mk_windows(latest_height, window)
+            }
+            None => vec![(BlockNumberOrTag::Earliest, BlockNumberOrTag::Latest)],
+        };
+
+        for (from, to) in windows {
+            debug!(%from, %to, "querying range for acknowledgement");
+
+            let query = ibc_handler
+                .WriteAck_filter()
+                .topic1(alloy::primitives::U256::from(channel_id.raw()))
+                .topic2(alloy::primitives::U256::from_be_bytes(*(packet_hash.get())));
+
+            trace!(?query, "raw query");
+
+            let mut ack_logs = query
+                .from_block(from)
+                .to_block(to)
+                .query()
+                .await
+                .map_err(|e| {
+                    ErrorObject::owned(
+                        -1,
+                        format!(
+                            "error querying for acknowledgement of packet {packet_hash}: {}",
+                            ErrorReporter(e)
+                        ),
+                        None::<()>,
+                    )
+                })?;
+
+            if ack_logs.is_empty() {
+                debug!(%from, %to, "acknowledgement not found in range");
+                continue;
+            } else if ack_logs.len() == 1 {
+                let (ack_log, _) = ack_logs.pop().expect("len is 1; qed;");
+
+                return Ok(ack_log.acknowledgement.to_vec().into());
+            } else {
+                return Err(ErrorObject::owned(
+                    -1,
+                    format!(
+                        "error querying for acknowledgement of packet {packet_hash}, \
+                        expected 1 event but found {}",
+                        ack_logs.len()
+                    ),
+                    None::<()>,
+                ));
+            }
+        }
+
+        Err(ErrorObject::owned(
+            MISSING_STATE_ERROR_CODE,
+            format!("acknowledgement for packet {packet_hash} not found"),
+            None::<()>,
+        ))
+    }
+
     #[instrument(skip_all, fields(chain_id = %self.chain_id, %client_id, %height))]
     async fn client_status(&self, client_id: ClientId, height: u64) -> RpcResult<Status> {
         let client_address = self.client_address(client_id.raw(), height).await?;
@@ -655,6 +798,21 @@ impl StateModuleServer<IbcUnion> for Module {
                 )
                 .await
                 .map(into_value),
+            Query::PacketsByChannel(packets_by_channel) => self
+                .packets_by_channel(
+                    packets_by_channel.channel_id,
+                    packets_by_channel.from_height,
+                    packets_by_channel.to_height,
+                )
+                .await
+                .map(into_value),
+            Query::PacketAckByHash(packet_ack_by_hash) => self
+                .packet_ack_by_hash(
+                    packet_ack_by_hash.channel_id,
+                    packet_ack_by_hash.packet_hash,
+                )
+                .await
+                .map(into_value),
             Query::ClientStatus(client_status) => {
                 let height = match client_status.height {
                     Some(height) => height,
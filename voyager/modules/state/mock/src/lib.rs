@@ -0,0 +1,213 @@
+use ibc_union_spec::{
+    event::FullEvent, path::StorePath, query::Query, ChannelId, ClientId, IbcUnion, Packet,
+};
+use jsonrpsee::{
+    core::{async_trait, RpcResult},
+    http_client::{HttpClient, HttpClientBuilder},
+    types::ErrorObject,
+    Extensions,
+};
+use mock_chain::rpc::MockChainRpcClient;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tracing::instrument;
+use unionlabs::{
+    ibc::core::client::height::Height,
+    primitives::{Bytes, H256},
+    ErrorReporter,
+};
+use voyager_sdk::{
+    anyhow, into_value,
+    plugin::StateModule,
+    primitives::{ChainId, ClientInfo, IbcInterface},
+    rpc::{types::StateModuleInfo, StateModuleServer, MISSING_STATE_ERROR_CODE},
+};
+
+#[derive(Debug, Clone)]
+pub struct Module {
+    pub chain_id: ChainId,
+
+    pub client: HttpClient,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// The RPC endpoint of the mock chain.
+    pub rpc_url: String,
+}
+
+impl StateModule<IbcUnion> for Module {
+    type Config = Config;
+
+    async fn new(config: Self::Config, info: StateModuleInfo) -> anyhow::Result<Self> {
+        let client = HttpClientBuilder::default().build(&config.rpc_url)?;
+
+        let chain_id = client.chain_id().await?;
+
+        info.ensure_chain_id(chain_id.to_string())?;
+
+        Ok(Self { chain_id, client })
+    }
+}
+
+fn rpc_error(e: jsonrpsee::core::ClientError) -> ErrorObject<'static> {
+    ErrorObject::owned(
+        -1,
+        ErrorReporter(e).with_message("mock chain rpc error"),
+        None::<()>,
+    )
+}
+
+impl Module {
+    async fn latest_height(&self) -> RpcResult<u64> {
+        self.client.latest_height(false).await.map_err(rpc_error)
+    }
+
+    /// Mock chains are small enough that scanning the events of every block in the range is fine.
+    async fn packets_by_channel(
+        &self,
+        channel_id: ChannelId,
+        from_height: u64,
+        to_height: u64,
+    ) -> RpcResult<Vec<Packet>> {
+        let to_height = to_height.min(self.latest_height().await?);
+
+        let mut packets = vec![];
+
+        for height in from_height.max(1)..=to_height {
+            for event in self.client.events(height).await.map_err(rpc_error)? {
+                if let FullEvent::PacketSend(packet_send) = event.event {
+                    if packet_send.packet.source_channel.channel_id == channel_id {
+                        packets.push(packet_send.packet());
+                    }
+                }
+            }
+        }
+
+        Ok(packets)
+    }
+
+    async fn packet_ack_by_hash(
+        &self,
+        channel_id: ChannelId,
+        packet_hash: H256,
+    ) -> RpcResult<Bytes> {
+        for height in (1..=self.latest_height().await?).rev() {
+            for event in self.client.events(height).await.map_err(rpc_error)? {
+                if let FullEvent::WriteAck(write_ack) = event.event {
+                    if write_ack.packet.destination_channel.channel_id == channel_id
+                        && write_ack.packet().hash() == packet_hash
+                    {
+                        return Ok(write_ack.acknowledgement);
+                    }
+                }
+            }
+        }
+
+        Err(ErrorObject::owned(
+            MISSING_STATE_ERROR_CODE,
+            format!("acknowledgement for packet {packet_hash} not found"),
+            None::<()>,
+        ))
+    }
+}
+
+#[async_trait]
+impl StateModuleServer<IbcUnion> for Module {
+    #[instrument(skip_all, fields(chain_id = %self.chain_id))]
+    async fn query(&self, _: &Extensions, query: Query) -> RpcResult<Value> {
+        match query {
+            Query::PacketByHash(query) => self
+                .client
+                .packet_by_hash(query.channel_id, query.packet_hash)
+                .await
+                .map_err(rpc_error)?
+                .map(into_value)
+                .ok_or_else(|| {
+                    ErrorObject::owned(
+                        MISSING_STATE_ERROR_CODE,
+                        format!("packet {} not found", query.packet_hash),
+                        None::<()>,
+                    )
+                }),
+            // only single packet batches can be sent on mock chains, so the batch hash is the
+            // packet hash
+            Query::PacketsByBatchHash(query) => self
+                .client
+                .packet_by_hash(query.channel_id, query.batch_hash)
+                .await
+                .map_err(rpc_error)?
+                .map(|packet| into_value(vec![packet]))
+                .ok_or_else(|| {
+                    ErrorObject::owned(
+                        MISSING_STATE_ERROR_CODE,
+                        format!("batch {} not found", query.batch_hash),
+                        None::<()>,
+                    )
+                }),
+            Query::PacketsByChannel(query) => self
+                .packets_by_channel(query.channel_id, query.from_height, query.to_height)
+                .await
+                .map(into_value),
+            Query::PacketAckByHash(query) => self
+                .packet_ack_by_hash(query.channel_id, query.packet_hash)
+                .await
+                .map(into_value),
+            Query::ClientStatus(query) => {
+                let height = match query.height {
+                    Some(height) => height,
+                    None => self.latest_height().await?,
+                };
+
+                self.client
+                    .client_status(height, query.client_id)
+                    .await
+                    .map_err(rpc_error)?
+                    .map(into_value)
+                    .ok_or_else(|| {
+                        ErrorObject::owned(
+                            MISSING_STATE_ERROR_CODE,
+                            format!("client {} not found", query.client_id),
+                            None::<()>,
+                        )
+                    })
+            }
+        }
+    }
+
+    #[instrument(skip_all, fields(chain_id = %self.chain_id, %at))]
+    async fn query_ibc_state(
+        &self,
+        _: &Extensions,
+        at: Height,
+        path: StorePath,
+    ) -> RpcResult<Value> {
+        self.client
+            .ibc_state(at.height(), path)
+            .await
+            .map_err(rpc_error)
+    }
+
+    #[instrument(skip_all, fields(chain_id = %self.chain_id, %client_id))]
+    async fn client_info(&self, _: &Extensions, client_id: ClientId) -> RpcResult<ClientInfo> {
+        let client_type = self
+            .client
+            .client_type(client_id)
+            .await
+            .map_err(rpc_error)?
+            .ok_or_else(|| {
+                ErrorObject::owned(
+                    MISSING_STATE_ERROR_CODE,
+                    format!("client {client_id} not found"),
+                    None::<()>,
+                )
+            })?;
+
+        Ok(ClientInfo {
+            client_type,
+            ibc_interface: IbcInterface::new(IbcInterface::MOCK),
+            metadata: Default::default(),
+        })
+    }
+}
@@ -0,0 +1,7 @@
+use voyager_sdk::plugin::StateModule;
+use voyager_state_module_mock::Module;
+
+#[tokio::main(flavor = "multi_thread")]
+async fn main() {
+    Module::run().await
+}
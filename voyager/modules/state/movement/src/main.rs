@@ -11,7 +11,7 @@ use ibc_union_spec::{
 };
 use jsonrpsee::{
     core::{async_trait, RpcResult},
-    types::{ErrorObject, ErrorObjectOwned},
+    types::{error::METHOD_NOT_FOUND_CODE, ErrorObject, ErrorObjectOwned},
     Extensions,
 };
 use serde::{Deserialize, Serialize};
@@ -162,9 +162,18 @@ impl Module {
 impl StateModuleServer<IbcUnion> for Module {
     #[instrument(skip_all, fields(chain_id = %self.chain_id))]
     async fn query(&self, _: &Extensions, query: Query) -> RpcResult<Value> {
+        // the aptos ibc module doesn't store packets or client statuses, and there is no event
+        // indexer to query them from
         match query {
-            Query::PacketByHash(_packet_by_hash) => todo!(),
-            Query::PacketsByBatchHash(_packets_by_batch_hash) => todo!(),
+            Query::PacketByHash(_)
+            | Query::PacketsByBatchHash(_)
+            | Query::PacketsByChannel(_)
+            | Query::PacketAckByHash(_)
+            | Query::ClientStatus(_) => Err(ErrorObject::owned(
+                METHOD_NOT_FOUND_CODE,
+                format!("query is not supported by this state module: {query:?}"),
+                None::<()>,
+            )),
         }
     }
 
@@ -6,6 +6,7 @@ use ibc_union_spec::{
 };
 use jsonrpsee::{
     core::{async_trait, RpcResult},
+    types::ErrorObject,
     Extensions,
 };
 use serde::{Deserialize, Serialize};
@@ -30,7 +31,7 @@ use voyager_sdk::{
     anyhow, into_value,
     plugin::StateModule,
     primitives::{ChainId, ClientInfo, ClientType, IbcInterface},
-    rpc::{types::StateModuleInfo, StateModuleServer},
+    rpc::{types::StateModuleInfo, StateModuleServer, FATAL_JSONRPC_ERROR_CODE},
 };
 
 #[tokio::main(flavor = "multi_thread")]
@@ -94,6 +95,8 @@ impl StateModuleServer<IbcUnion> for Module {
         match query {
             Query::PacketByHash(_packet_by_hash) => todo!(),
             Query::PacketsByBatchHash(_packets_by_batch_hash) => todo!(),
+            Query::PacketsByChannel(_) => Err(unsupported_query("packets_by_channel")),
+            Query::PacketAckByHash(_) => Err(unsupported_query("packet_ack_by_hash")),
             Query::ClientStatus(_client_status) => todo!(),
         }
     }
@@ -309,3 +312,12 @@ fn convert_channel(channel: SuiChannel) -> Channel {
         version: channel.version,
     }
 }
+
+/// Error for the queries that are answered from packet events, which are not yet indexed by this module.
+fn unsupported_query(query: &str) -> ErrorObject<'static> {
+    ErrorObject::owned(
+        FATAL_JSONRPC_ERROR_CODE,
+        format!("query {query} is not supported by the sui state module"),
+        None::<()>,
+    )
+}
@@ -0,0 +1,40 @@
+use enumorph::Enumorph;
+use ibc_union_spec::{ChannelId, ClientId, Packet};
+use macros::model;
+use voyager_sdk::primitives::ChainId;
+
+#[model]
+#[derive(Enumorph)]
+pub enum ModuleCall {
+    ClearPackets(ClearPackets),
+    MakeMsgTimeout(MakeMsgTimeout),
+}
+
+/// Find all packets sent on a channel within a range of heights that have not yet been fully relayed, and relay them.
+///
+/// A packet is considered to not yet be fully relayed if its commitment is still present on the source chain. Depending on the state of the packet on the destination chain, this will relay either the packet itself, its acknowledgement, or its timeout.
+#[model]
+pub struct ClearPackets {
+    /// The source chain of the packets.
+    pub chain_id: ChainId,
+    /// The channel on `chain_id` that the packets were sent on.
+    pub channel_id: ChannelId,
+    /// The first height on `chain_id` to search for sent packets in, inclusive.
+    pub from_height: u64,
+    /// The last height on `chain_id` to search for sent packets in, inclusive. Defaults to the latest finalized height of `chain_id`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to_height: Option<u64>,
+}
+
+/// Submit a timeout for a packet that was never received on the counterparty chain.
+///
+/// `client_id` is expected to already trust a height of the counterparty chain that is after the packet's timeout.
+#[model]
+pub struct MakeMsgTimeout {
+    /// The source chain of the packet.
+    pub chain_id: ChainId,
+    pub counterparty_chain_id: ChainId,
+    /// The client on `chain_id` tracking `counterparty_chain_id`.
+    pub client_id: ClientId,
+    pub packet: Packet,
+}
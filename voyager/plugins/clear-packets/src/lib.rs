@@ -0,0 +1,528 @@
+use std::{
+    collections::VecDeque,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use ibc_union_spec::{
+    datagram::{Datagram, MsgPacketTimeout},
+    event::{ChannelMetadata, ConnectionMetadata, PacketMetadata, PacketSend, WriteAck},
+    path::{BatchPacketsPath, BatchReceiptsPath, ChannelPath, ConnectionPath, COMMITMENT_MAGIC},
+    query::{PacketAckByHash, PacketsByChannel},
+    IbcUnion, Timestamp,
+};
+use jsonrpsee::{
+    core::{async_trait, RpcResult},
+    types::ErrorObject,
+    Extensions,
+};
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info, instrument, warn};
+use unionlabs::{never::Never, primitives::H256};
+use voyager_plugin_transaction_batch::data::{BatchableEvent, EventBatch, ModuleData};
+use voyager_sdk::{
+    anyhow,
+    message::{
+        call::SubmitTx,
+        data::{Data, EventProvableHeight, IbcDatagram},
+        PluginMessage, VoyagerMessage,
+    },
+    plugin::Plugin,
+    primitives::QueryHeight,
+    rpc::{types::PluginInfo, PluginServer, FATAL_JSONRPC_ERROR_CODE},
+    types::ProofType,
+    vm::{call, conc, data, noop, pass::PassResult, seq, Op},
+    DefaultCmd, ExtensionsExt, VoyagerClient,
+};
+
+use crate::call::{ClearPackets, MakeMsgTimeout, ModuleCall};
+
+pub mod call;
+
+#[derive(Debug, Clone)]
+pub struct Module {}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {}
+
+impl Plugin for Module {
+    type Call = ModuleCall;
+    type Callback = Never;
+
+    type Config = Config;
+    type Cmd = DefaultCmd;
+
+    async fn new(config: Self::Config) -> anyhow::Result<Self> {
+        Ok(Module::new(config))
+    }
+
+    fn info(_config: Self::Config) -> PluginInfo {
+        PluginInfo {
+            name: plugin_name(),
+            // never interested in any messages since this plugin does not utilize a queue
+            interest_filter: "null".to_owned(),
+        }
+    }
+
+    async fn cmd(_config: Self::Config, cmd: Self::Cmd) {
+        match cmd {}
+    }
+}
+
+pub fn plugin_name() -> String {
+    pub const PLUGIN_NAME: &str = env!("CARGO_PKG_NAME");
+
+    PLUGIN_NAME.to_owned()
+}
+
+impl Module {
+    pub fn new(_: Config) -> Self {
+        Self {}
+    }
+
+    #[instrument(
+        skip_all,
+        fields(
+            %chain_id,
+            %channel_id,
+            %from_height,
+            ?to_height,
+        )
+    )]
+    async fn clear_packets(
+        &self,
+        voyager_client: &VoyagerClient,
+        ClearPackets {
+            chain_id,
+            channel_id,
+            from_height,
+            to_height,
+        }: ClearPackets,
+    ) -> RpcResult<Op<VoyagerMessage>> {
+        let height = voyager_client
+            .query_latest_height(chain_id.clone(), true)
+            .await?;
+
+        let to_height = to_height.unwrap_or(height.height());
+
+        let channel = voyager_client
+            .query_ibc_state(
+                chain_id.clone(),
+                QueryHeight::Specific(height),
+                ChannelPath { channel_id },
+            )
+            .await?;
+
+        let connection = voyager_client
+            .query_ibc_state(
+                chain_id.clone(),
+                QueryHeight::Specific(height),
+                ConnectionPath {
+                    connection_id: channel.connection_id,
+                },
+            )
+            .await?;
+
+        let (Some(counterparty_channel_id), Some(counterparty_connection_id)) = (
+            channel.counterparty_channel_id,
+            connection.counterparty_connection_id,
+        ) else {
+            return Err(ErrorObject::owned(
+                FATAL_JSONRPC_ERROR_CODE,
+                format!(
+                    "channel {channel_id} on {chain_id} is not open, no packets can be cleared"
+                ),
+                None::<()>,
+            ));
+        };
+
+        let counterparty_chain_id = voyager_client
+            .client_state_meta::<IbcUnion>(
+                chain_id.clone(),
+                QueryHeight::Specific(height),
+                connection.client_id,
+            )
+            .await?
+            .counterparty_chain_id;
+
+        let counterparty_height = voyager_client
+            .query_latest_height(counterparty_chain_id.clone(), true)
+            .await?;
+
+        let counterparty_timestamp = voyager_client
+            .query_latest_timestamp(counterparty_chain_id.clone(), true)
+            .await?;
+
+        let packets = voyager_client
+            .query(
+                chain_id.clone(),
+                PacketsByChannel {
+                    channel_id,
+                    from_height,
+                    to_height,
+                },
+            )
+            .await?;
+
+        info!(
+            %counterparty_chain_id,
+            %to_height,
+            "found {} packets sent in range",
+            packets.len()
+        );
+
+        let source_channel = ChannelMetadata {
+            channel_id,
+            version: channel.version.clone(),
+            connection: ConnectionMetadata {
+                client_id: connection.client_id,
+                connection_id: channel.connection_id,
+            },
+        };
+
+        let destination_channel = ChannelMetadata {
+            channel_id: counterparty_channel_id,
+            version: channel.version,
+            connection: ConnectionMetadata {
+                client_id: connection.counterparty_client_id,
+                connection_id: counterparty_connection_id,
+            },
+        };
+
+        let first_seen_at: u64 = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis()
+            .try_into()
+            .expect("how many milliseconds can there be man");
+
+        let mut recvs = vec![];
+        let mut acks = vec![];
+        let mut timeouts = vec![];
+
+        for packet in packets {
+            let packet_hash = packet.hash();
+
+            let commitment = voyager_client
+                .maybe_query_ibc_state(
+                    chain_id.clone(),
+                    QueryHeight::Specific(height),
+                    BatchPacketsPath::from_packets(&[packet.clone()]),
+                )
+                .await?
+                .state;
+
+            // the commitment is overwritten once the packet is acknowledged or timed out
+            if commitment != Some(COMMITMENT_MAGIC) {
+                debug!(%packet_hash, "packet is already cleared");
+                continue;
+            }
+
+            let receipt = voyager_client
+                .maybe_query_ibc_state(
+                    counterparty_chain_id.clone(),
+                    QueryHeight::Specific(counterparty_height),
+                    BatchReceiptsPath::from_packets(&[packet.clone()]),
+                )
+                .await?
+                .state;
+
+            let metadata = PacketMetadata {
+                source_channel: source_channel.clone(),
+                destination_channel: destination_channel.clone(),
+                timeout_timestamp: packet.timeout_timestamp,
+            };
+
+            match packet_status(receipt, packet.timeout_timestamp, counterparty_timestamp) {
+                PacketStatus::TimedOut => {
+                    info!(%packet_hash, "packet timed out");
+
+                    timeouts.push(packet);
+                }
+                PacketStatus::NotReceived => {
+                    info!(%packet_hash, "packet not received");
+
+                    recvs.push(BatchableEvent::<IbcUnion> {
+                        first_seen_at,
+                        provable_height: EventProvableHeight::Min(height),
+                        event: PacketSend {
+                            packet_data: packet.data,
+                            packet: metadata,
+                        }
+                        .into(),
+                    });
+                }
+                PacketStatus::NotAcknowledged => {
+                    info!(%packet_hash, "packet received, but not yet acknowledged");
+                }
+                PacketStatus::AckNotRelayed => {
+                    info!(%packet_hash, "packet acknowledgement not relayed");
+
+                    let acknowledgement = voyager_client
+                        .query(
+                            counterparty_chain_id.clone(),
+                            PacketAckByHash {
+                                channel_id: counterparty_channel_id,
+                                packet_hash,
+                            },
+                        )
+                        .await?;
+
+                    acks.push(BatchableEvent::<IbcUnion> {
+                        first_seen_at,
+                        provable_height: EventProvableHeight::Min(counterparty_height),
+                        event: WriteAck {
+                            packet_data: packet.data,
+                            acknowledgement,
+                            packet: metadata,
+                        }
+                        .into(),
+                    });
+                }
+            }
+        }
+
+        info!(
+            recvs = recvs.len(),
+            acks = acks.len(),
+            timeouts = timeouts.len(),
+            "clearing packets"
+        );
+
+        let mut ops = vec![];
+
+        if !recvs.is_empty() {
+            ops.push(data(PluginMessage::new(
+                voyager_plugin_transaction_batch::plugin_name(&counterparty_chain_id),
+                ModuleData::BatchEventsUnion(EventBatch {
+                    client_id: connection.counterparty_client_id,
+                    events: recvs,
+                }),
+            )));
+        }
+
+        if !acks.is_empty() {
+            ops.push(data(PluginMessage::new(
+                voyager_plugin_transaction_batch::plugin_name(&chain_id),
+                ModuleData::BatchEventsUnion(EventBatch {
+                    client_id: connection.client_id,
+                    events: acks,
+                }),
+            )));
+        }
+
+        if !timeouts.is_empty() {
+            ops.push(seq([
+                // the client must trust a height after the timeouts before they can be proven
+                voyager_sdk::update_client_to::<IbcUnion>(
+                    voyager_client,
+                    &chain_id,
+                    connection.client_id,
+                    counterparty_height,
+                )
+                .await?
+                .unwrap_or_else(noop),
+                conc(timeouts.into_iter().map(|packet| {
+                    call(PluginMessage::new(
+                        plugin_name(),
+                        ModuleCall::from(MakeMsgTimeout {
+                            chain_id: chain_id.clone(),
+                            counterparty_chain_id: counterparty_chain_id.clone(),
+                            client_id: connection.client_id,
+                            packet,
+                        }),
+                    ))
+                })),
+            ]));
+        }
+
+        if ops.is_empty() {
+            Ok(noop())
+        } else {
+            Ok(conc(ops))
+        }
+    }
+
+    #[instrument(
+        skip_all,
+        fields(
+            %chain_id,
+            %counterparty_chain_id,
+            %client_id,
+            packet_hash = %packet.hash()
+        )
+    )]
+    async fn make_msg_timeout(
+        &self,
+        voyager_client: &VoyagerClient,
+        MakeMsgTimeout {
+            chain_id,
+            counterparty_chain_id,
+            client_id,
+            packet,
+        }: MakeMsgTimeout,
+    ) -> RpcResult<Op<VoyagerMessage>> {
+        let client_meta = voyager_client
+            .client_state_meta::<IbcUnion>(chain_id.clone(), QueryHeight::Latest, client_id)
+            .await?;
+
+        let proof_unreceived = voyager_client
+            .query_ibc_proof(
+                counterparty_chain_id,
+                QueryHeight::Specific(client_meta.counterparty_height),
+                BatchReceiptsPath::from_packets(&[packet.clone()]),
+            )
+            .await?
+            .into_result()?;
+
+        match proof_unreceived.proof_type {
+            ProofType::NonMembership => {
+                let client_info = voyager_client
+                    .client_info::<IbcUnion>(chain_id.clone(), client_id)
+                    .await?;
+
+                let encoded_proof_commitment = voyager_client
+                    .encode_proof::<IbcUnion>(
+                        client_info.client_type,
+                        client_info.ibc_interface,
+                        proof_unreceived.proof,
+                    )
+                    .await?;
+
+                Ok(call(SubmitTx {
+                    chain_id,
+                    datagrams: vec![IbcDatagram::new::<IbcUnion>(Datagram::from(
+                        MsgPacketTimeout {
+                            packet,
+                            proof: encoded_proof_commitment,
+                            proof_height: client_meta.counterparty_height.height(),
+                        },
+                    ))],
+                }))
+            }
+            ProofType::Membership => {
+                warn!("packet timed out, but it was already received on the counterparty");
+
+                Ok(noop())
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl PluginServer<ModuleCall, Never> for Module {
+    #[instrument(skip_all)]
+    async fn run_pass(
+        &self,
+        _: &Extensions,
+        _: Vec<Op<VoyagerMessage>>,
+    ) -> RpcResult<PassResult<VoyagerMessage>> {
+        info!("this plugin does not utilize a queue");
+
+        Ok(PassResult::default())
+    }
+
+    #[instrument(skip_all)]
+    async fn call(&self, e: &Extensions, msg: ModuleCall) -> RpcResult<Op<VoyagerMessage>> {
+        let voyager_client = e.voyager_client()?;
+
+        match msg {
+            ModuleCall::ClearPackets(clear_packets) => {
+                self.clear_packets(voyager_client, clear_packets).await
+            }
+            ModuleCall::MakeMsgTimeout(make_msg_timeout) => {
+                self.make_msg_timeout(voyager_client, make_msg_timeout)
+                    .await
+            }
+        }
+    }
+
+    #[instrument(skip_all)]
+    async fn callback(
+        &self,
+        _: &Extensions,
+        cb: Never,
+        _data: VecDeque<Data>,
+    ) -> RpcResult<Op<VoyagerMessage>> {
+        match cb {}
+    }
+}
+
+/// The state of a packet that is still committed on the source chain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PacketStatus {
+    /// The packet was not received on the destination chain before its timeout.
+    TimedOut,
+    /// The packet has not yet been received on the destination chain.
+    NotReceived,
+    /// The packet was received on the destination chain, but not yet acknowledged.
+    NotAcknowledged,
+    /// The packet was acknowledged on the destination chain, but the acknowledgement was not relayed back to the source chain.
+    AckNotRelayed,
+}
+
+/// Classify a packet that is still committed on the source chain from its `receipt` on the destination chain and the latest timestamp of the destination chain.
+fn packet_status(
+    receipt: Option<H256>,
+    timeout_timestamp: Timestamp,
+    counterparty_timestamp: Timestamp,
+) -> PacketStatus {
+    match receipt {
+        None if !timeout_timestamp.is_zero() && timeout_timestamp <= counterparty_timestamp => {
+            PacketStatus::TimedOut
+        }
+        None => PacketStatus::NotReceived,
+        // the receipt is overwritten with the acknowledgement commitment once the acknowledgement is written
+        Some(COMMITMENT_MAGIC) => PacketStatus::NotAcknowledged,
+        Some(_) => PacketStatus::AckNotRelayed,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_union_spec::path::COMMITMENT_MAGIC_ACK;
+
+    use super::*;
+
+    const NOW: Timestamp = Timestamp::from_secs(100);
+
+    #[test]
+    fn unreceived_packet_times_out_at_timeout_timestamp() {
+        assert_eq!(
+            packet_status(None, Timestamp::from_secs(101), NOW),
+            PacketStatus::NotReceived
+        );
+        assert_eq!(
+            packet_status(None, Timestamp::from_secs(100), NOW),
+            PacketStatus::TimedOut
+        );
+        assert_eq!(
+            packet_status(None, Timestamp::from_secs(99), NOW),
+            PacketStatus::TimedOut
+        );
+    }
+
+    #[test]
+    fn packet_without_timeout_never_times_out() {
+        assert_eq!(
+            packet_status(None, Timestamp::from_secs(0), NOW),
+            PacketStatus::NotReceived
+        );
+    }
+
+    #[test]
+    fn received_packet_does_not_time_out() {
+        assert_eq!(
+            packet_status(Some(COMMITMENT_MAGIC), Timestamp::from_secs(1), NOW),
+            PacketStatus::NotAcknowledged
+        );
+    }
+
+    #[test]
+    fn acknowledged_packet() {
+        assert_eq!(
+            packet_status(Some(COMMITMENT_MAGIC_ACK), Timestamp::from_secs(0), NOW),
+            PacketStatus::AckNotRelayed
+        );
+    }
+}
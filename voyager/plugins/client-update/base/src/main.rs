@@ -357,6 +357,45 @@ impl Module {
         })
     }
 
+    async fn fetch_game_status_proof(
+        &self,
+        height: u64,
+        game_account: H160,
+    ) -> RpcResult<StorageProof> {
+        let [proof]: [_; 1] = self
+            .l1_provider
+            .get_proof(
+                game_account.into(),
+                vec![base_verifier::FAULT_DISPUTE_GAME_STATUS_SLOT
+                    .to_be_bytes()
+                    .into()],
+            )
+            .block_id(height.into())
+            .await
+            .map_err(|e| {
+                ErrorObject::owned(
+                    -1,
+                    format!("error fetching game status proof: {}", ErrorReporter(e)),
+                    None::<()>,
+                )
+            })?
+            .storage_proof
+            .try_into()
+            .map_err(|_| {
+                ErrorObject::owned(
+                    -1,
+                    "invalid game status proof response, expected exactly one storage proof",
+                    None::<()>,
+                )
+            })?;
+
+        Ok(StorageProof {
+            key: U256::from_be_bytes(proof.key.as_b256().0),
+            value: U256::from_be_bytes(proof.value.to_be_bytes()),
+            proof: proof.proof.into_iter().map(|bytes| bytes.into()).collect(),
+        })
+    }
+
     async fn fetch_ibc_contract_root_proof(&self, height: u64) -> RpcResult<AccountProof> {
         let proof = self
             .l2_provider
@@ -587,10 +626,21 @@ impl Module {
 
         let l1_height = l1_client_meta.counterparty_height.height();
 
-        let l2_block_number = base_client::finalized_l2_block_number_of_l1_block_number(
+        // only resolved games are accepted by the light client, as the root claim of a game is not
+        // final until then
+        let game_index = base_client::latest_resolved_game_of_l1_block_number(
             &self.l1_provider,
+            l1_height,
             self.l1_dispute_game_factory_proxy,
+        )
+        .await
+        .map_err(|err| ErrorObject::owned(-1, ErrorReporter(err).to_string(), None::<()>))?;
+
+        let l2_block_number = base_client::finalized_l2_block_of_game_index(
+            &self.l1_provider,
             l1_height,
+            self.l1_dispute_game_factory_proxy,
+            game_index,
         )
         .await
         .map_err(|err| ErrorObject::owned(-1, ErrorReporter(err).to_string(), None::<()>))?;
@@ -610,16 +660,6 @@ impl Module {
 
         let output_root_proof = self.fetch_output_root_proof(l2_block.header.number).await?;
 
-        let game_index = base_client::latest_game_of_l1_block_number(
-            &self.l1_provider,
-            l1_height,
-            self.l1_dispute_game_factory_proxy,
-        )
-        .await
-        .map_err(|err| ErrorObject::owned(-1, ErrorReporter(err).to_string(), None::<()>))?;
-
-        let game_index = game_index - U256::ONE;
-
         let dispute_game_factory_account_proof = self
             .fetch_dispute_game_factory_account_proof(l1_height)
             .await
@@ -635,6 +675,9 @@ impl Module {
         let game_account_proof = self
             .fetch_game_account_proof(l1_height, game_account_address)
             .await?;
+        let game_status_proof = self
+            .fetch_game_status_proof(l1_height, game_account_address)
+            .await?;
         let game_account_code = self.fetch_game_account_code(game_account_address).await?;
 
         let l2_ibc_account_proof = self
@@ -685,6 +728,7 @@ impl Module {
                     game_index,
                     game_proof,
                     game_account_proof,
+                    game_status_proof,
                     game_account_code: game_account_code.into(),
                 }),
             )],
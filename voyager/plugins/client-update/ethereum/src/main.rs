@@ -10,6 +10,7 @@ use ethereum_light_client_types::{
     AccountProof, Header, LightClientUpdate, LightClientUpdateData,
     SyncCommitteePeriodChangeUpdate, WithinSyncCommitteePeriodUpdate,
 };
+use ethereum_sync_protocol_types::LightClientOptimisticUpdate;
 use futures::{stream, StreamExt, TryStreamExt};
 use jsonrpsee::{
     core::{async_trait, RpcResult},
@@ -35,7 +36,7 @@ use voyager_sdk::{
     },
     plugin::Plugin,
     primitives::{ChainId, ClientType, Timestamp},
-    rpc::{types::PluginInfo, PluginServer},
+    rpc::{types::PluginInfo, PluginServer, FATAL_JSONRPC_ERROR_CODE},
     types::RawClientId,
     vm::{self, call, defer, now, pass::PassResult, seq, Op, Visit},
     DefaultCmd,
@@ -59,6 +60,10 @@ pub struct Module {
     /// The address of the `IBCHandler` smart contract.
     pub ibc_handler_address: H160,
 
+    /// Whether to update clients to the attested (optimistic) head of the beacon chain when
+    /// possible, instead of waiting for finality.
+    pub optimistic_updates: bool,
+
     pub provider: DynProvider,
     pub beacon_api_client: BeaconApiClient,
 }
@@ -78,6 +83,12 @@ pub struct Config {
     /// The RPC endpoint for the beacon chain.
     pub beacon_rpc_url: String,
 
+    /// Whether to update clients to the attested (optimistic) head of the beacon chain when
+    /// possible, instead of waiting for finality. Note that the client being updated must have
+    /// been created with optimistic updates allowed.
+    #[serde(default)]
+    pub optimistic_updates: bool,
+
     #[serde(default)]
     pub max_cache_size: u32,
 }
@@ -179,6 +190,7 @@ impl Plugin for Module {
             chain_id,
             chain_spec: spec.preset_base,
             ibc_handler_address: config.ibc_handler_address,
+            optimistic_updates: config.optimistic_updates,
             provider,
             beacon_api_client,
         })
@@ -306,6 +318,7 @@ impl Module {
             |b| b.message.slot,
             |b| b.message.slot,
             |b| b.message.slot,
+            |b| b.message.slot,
         ))
     }
 
@@ -340,6 +353,19 @@ impl Module {
             return Ok(vm::data(OrderedHeaders { headers: vec![] }));
         }
 
+        if self.optimistic_updates {
+            if let Some(op) = self
+                .fetch_optimistic_update(
+                    update_from_block_number,
+                    update_to_block_number,
+                    &counterparty_chain_id,
+                )
+                .await?
+            {
+                return Ok(op);
+            }
+        }
+
         let finality_update: LightClientUpdateData = self
             .beacon_api_client
             .finality_update()
@@ -370,6 +396,13 @@ impl Module {
                     sync_aggregate: f.sync_aggregate,
                     signature_slot: f.signature_slot,
                 },
+                |f| LightClientUpdateData {
+                    attested_header: f.attested_header.into(),
+                    finalized_header: f.finalized_header.into(),
+                    finality_branch: f.finality_branch.to_vec(),
+                    sync_aggregate: f.sync_aggregate,
+                    signature_slot: f.signature_slot,
+                },
             );
 
         let spec = self.beacon_api_client.spec().await.map_err(|e| {
@@ -463,6 +496,7 @@ impl Module {
                         |_| todo!("capella is not supported"),
                         |u| u.into(),
                         |u| u.into(),
+                        |u| u.into(),
                     )
                 })
                 .collect::<Vec<_>>();
@@ -549,14 +583,7 @@ impl Module {
 
         let last_update_signature_slot = headers
             .iter()
-            .map(|header| match &header.consensus_update {
-                LightClientUpdate::SyncCommitteePeriodChange(update) => {
-                    update.update_data.signature_slot
-                }
-                LightClientUpdate::WithinSyncCommitteePeriod(update) => {
-                    update.update_data.signature_slot
-                }
-            })
+            .map(|header| header.consensus_update.signature_slot())
             .max()
             .expect("expected at least one update");
 
@@ -584,6 +611,9 @@ impl Module {
                                     LightClientUpdate::WithinSyncCommitteePeriod(update) => {
                                         update.update_data.finalized_header.execution.block_number
                                     }
+                                    LightClientUpdate::Optimistic(update) => {
+                                        update.attested_header.execution.block_number
+                                    }
                                 }),
                             },
                             into_value(header),
@@ -594,6 +624,146 @@ impl Module {
         ]))
     }
 
+    /// Fetch an optimistic update from the provided trusted height (`update_from`) to the attested
+    /// head of the beacon chain.
+    ///
+    /// Returns `None` if the current optimistic update can't be used, in which case a regular
+    /// finality update should be generated instead. This is the case if the attested head is not
+    /// past `update_to`, if the update does not have a sync committee supermajority, or if the
+    /// update is signed in a sync committee period that the client can't verify.
+    #[instrument(
+        skip_all,
+        fields(
+            chain_id = %self.chain_id,
+            %counterparty_chain_id,
+            %update_from_block_number,
+            %update_to_block_number
+        )
+    )]
+    async fn fetch_optimistic_update(
+        &self,
+        update_from_block_number: Height,
+        update_to_block_number: Height,
+        counterparty_chain_id: &ChainId,
+    ) -> RpcResult<Option<Op<VoyagerMessage>>> {
+        let optimistic_update: LightClientOptimisticUpdate = self
+            .beacon_api_client
+            .optimistic_update()
+            .await
+            .map_err(|e| {
+                ErrorObject::owned(
+                    -1,
+                    ErrorReporter(e).with_message("error fetching optimistic update"),
+                    None::<()>,
+                )
+            })?
+            .fold::<Result<_, &str>>(
+                |u| match u {},
+                |_| Err("altair"),
+                |_| Err("bellatrix"),
+                |_| Err("capella"),
+                |u| Ok(u.into()),
+                |u| Ok(u.into()),
+                |u| Ok(u.into()),
+            )
+            .map_err(|fork| {
+                ErrorObject::owned(
+                    FATAL_JSONRPC_ERROR_CODE,
+                    format!("optimistic updates are not supported for {fork}"),
+                    None::<()>,
+                )
+            })?;
+
+        let spec = self.beacon_api_client.spec().await.map_err(|e| {
+            ErrorObject::owned(
+                -1,
+                ErrorReporter(e).with_message("error fetching beacon spec"),
+                None::<()>,
+            )
+        })?;
+
+        let sync_committee_bits = BitVec::<u8, Msb0>::try_from(
+            optimistic_update
+                .sync_aggregate
+                .sync_committee_bits
+                .to_vec(),
+        )
+        .expect("sync committee bits should be valid");
+
+        if sync_committee_bits.count_ones() * 3 < sync_committee_bits.len() * 2 {
+            info!(
+                signature_slot = %optimistic_update.signature_slot,
+                "signature supermajority not hit for optimistic update"
+            );
+            return Ok(None);
+        }
+
+        let attested_block_number = optimistic_update.attested_header.execution.block_number;
+
+        if attested_block_number < update_to_block_number.height() {
+            info!(
+                attested_block_number,
+                "optimistic update is not past the requested height"
+            );
+            return Ok(None);
+        }
+
+        let update_from_beacon_slot = self
+            .beacon_slot_of_execution_block_number(update_from_block_number.height())
+            .await?;
+
+        let trusted_period = sync_committee_period(update_from_beacon_slot, spec.period());
+        let signature_period =
+            sync_committee_period(optimistic_update.signature_slot, spec.period());
+
+        // the client only has the sync committees of the trusted period and the period after it
+        if signature_period != trusted_period && signature_period != trusted_period + 1 {
+            info!(
+                trusted_period,
+                signature_period, "optimistic update is not in a verifiable period"
+            );
+            return Ok(None);
+        }
+
+        let genesis = self.beacon_api_client.genesis().await.map_err(|e| {
+            ErrorObject::owned(
+                -1,
+                ErrorReporter(e).with_message("error fetching beacon genesis"),
+                None::<()>,
+            )
+        })?;
+
+        let ibc_account_proof = self.fetch_account_update(attested_block_number).await?;
+
+        let signature_slot = optimistic_update.signature_slot;
+
+        let header = Header {
+            trusted_height: update_from_block_number,
+            consensus_update: LightClientUpdate::Optimistic(Box::new(optimistic_update)),
+            ibc_account_proof,
+        };
+
+        Ok(Some(seq([
+            call(WaitForTimestamp {
+                chain_id: counterparty_chain_id.clone(),
+                // we wait for one more block just to be sure the counterparty's block time has caught up
+                timestamp: Timestamp::from_secs(
+                    (genesis.genesis_time + (signature_slot.get() * spec.seconds_per_slot))
+                        + spec.seconds_per_slot,
+                ),
+                finalized: false,
+            }),
+            vm::data(OrderedHeaders {
+                headers: vec![(
+                    DecodedHeaderMeta {
+                        height: Height::new(attested_block_number),
+                    },
+                    into_value(header),
+                )],
+            }),
+        ])))
+    }
+
     #[instrument(
         skip_all,
         fields(
@@ -0,0 +1,542 @@
+use std::collections::VecDeque;
+
+use alloy::{
+    network::AnyNetwork,
+    providers::{DynProvider, Provider, ProviderBuilder},
+};
+use call::FetchL2Update;
+use ethereum_light_client_types::{AccountProof, StorageProof};
+use ibc_union_spec::{path::ClientStatePath, ClientId, IbcUnion};
+use jsonrpsee::{
+    core::{async_trait, RpcResult},
+    types::ErrorObject,
+    Extensions,
+};
+use linea_light_client_types::{ClientState, Header};
+use linea_types::proof::MerkleProof;
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info, instrument};
+use unionlabs::{
+    ibc::core::client::height::Height,
+    never::Never,
+    primitives::{H160, U256},
+    ErrorReporter,
+};
+use voyager_sdk::{
+    anyhow,
+    hook::UpdateHook,
+    into_value,
+    message::{
+        call::{Call, FetchUpdateHeaders, WaitForHeightRelative, WaitForTrustedHeight},
+        callback::AggregateSubmitTxFromOrderedHeaders,
+        data::{Data, DecodedHeaderMeta, OrderedHeaders},
+        PluginMessage, VoyagerMessage,
+    },
+    plugin::Plugin,
+    primitives::{ChainId, ClientType, IbcSpec, QueryHeight},
+    rpc::{types::PluginInfo, PluginServer, FATAL_JSONRPC_ERROR_CODE},
+    types::RawClientId,
+    vm::{call, conc, data, pass::PassResult, promise, seq, BoxDynError, Op, Visit},
+    DefaultCmd, ExtensionsExt, VoyagerClient,
+};
+
+use crate::call::{FetchUpdate, ModuleCall};
+
+pub mod call;
+
+#[tokio::main]
+async fn main() {
+    Module::run().await
+}
+
+#[derive(Debug, Clone)]
+pub struct Module {
+    pub chain_id: ChainId,
+
+    /// The address of the `LineaRollup` contract on the L1.
+    pub l1_rollup_contract_address: H160,
+    pub l1_rollup_current_l2_block_number_slot: U256,
+    pub l1_rollup_current_l2_timestamp_slot: U256,
+    pub l1_rollup_l2_state_root_hashes_slot: U256,
+
+    pub l1_provider: DynProvider,
+    pub l2_provider: DynProvider<AnyNetwork>,
+
+    /// The address of the `IBCHandler` smart contract.
+    pub ibc_handler_address: H160,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    pub l2_chain_id: ChainId,
+
+    /// The address of the `LineaRollup` contract on the L1.
+    pub l1_rollup_contract_address: H160,
+    pub l1_rollup_current_l2_block_number_slot: U256,
+    pub l1_rollup_current_l2_timestamp_slot: U256,
+    pub l1_rollup_l2_state_root_hashes_slot: U256,
+
+    /// The RPC endpoint for the settlement (L1) execution chain.
+    pub l1_rpc_url: String,
+
+    /// The RPC endpoint for the main (L2) execution chain. This must expose the `linea_getProof`
+    /// method.
+    pub l2_rpc_url: String,
+
+    /// The address of the `IBCHandler` smart contract.
+    pub ibc_handler_address: H160,
+}
+
+fn plugin_name(chain_id: &ChainId) -> String {
+    pub const PLUGIN_NAME: &str = env!("CARGO_PKG_NAME");
+
+    format!("{PLUGIN_NAME}/{}", chain_id)
+}
+
+impl Module {
+    fn plugin_name(&self) -> String {
+        plugin_name(&self.chain_id)
+    }
+}
+
+impl Plugin for Module {
+    type Call = ModuleCall;
+    type Callback = Never;
+
+    type Config = Config;
+    type Cmd = DefaultCmd;
+
+    async fn new(config: Self::Config) -> anyhow::Result<Self> {
+        let l1_provider =
+            DynProvider::new(ProviderBuilder::new().connect(&config.l1_rpc_url).await?);
+
+        let l2_provider = DynProvider::new(
+            ProviderBuilder::new()
+                .network::<AnyNetwork>()
+                .connect(&config.l2_rpc_url)
+                .await?,
+        );
+
+        let l2_chain_id = ChainId::new(l2_provider.get_chain_id().await?.to_string());
+
+        assert_eq!(l2_chain_id, config.l2_chain_id);
+
+        Ok(Self {
+            chain_id: l2_chain_id,
+            l1_rollup_contract_address: config.l1_rollup_contract_address,
+            l1_rollup_current_l2_block_number_slot: config.l1_rollup_current_l2_block_number_slot,
+            l1_rollup_current_l2_timestamp_slot: config.l1_rollup_current_l2_timestamp_slot,
+            l1_rollup_l2_state_root_hashes_slot: config.l1_rollup_l2_state_root_hashes_slot,
+            l1_provider,
+            l2_provider,
+            ibc_handler_address: config.ibc_handler_address,
+        })
+    }
+
+    fn info(config: Self::Config) -> PluginInfo {
+        PluginInfo {
+            name: plugin_name(&config.l2_chain_id),
+            interest_filter: UpdateHook::filter(
+                &config.l2_chain_id,
+                &ClientType::new(ClientType::LINEA),
+            ),
+        }
+    }
+
+    async fn cmd(_config: Self::Config, cmd: Self::Cmd) {
+        match cmd {}
+    }
+}
+
+#[async_trait]
+impl PluginServer<ModuleCall, Never> for Module {
+    #[instrument(skip_all, fields(chain_id = %self.chain_id))]
+    async fn run_pass(
+        &self,
+        _: &Extensions,
+        msgs: Vec<Op<VoyagerMessage>>,
+    ) -> RpcResult<PassResult<VoyagerMessage>> {
+        Ok(PassResult {
+            optimize_further: vec![],
+            ready: msgs
+                .into_iter()
+                .map(|mut op| {
+                    UpdateHook::new(
+                        &self.chain_id,
+                        &ClientType::new(ClientType::LINEA),
+                        |fetch| {
+                            Call::Plugin(PluginMessage::new(
+                                self.plugin_name(),
+                                ModuleCall::from(FetchUpdate {
+                                    from_height: fetch.update_from,
+                                    to_height: fetch.update_to,
+                                    counterparty_chain_id: fetch.counterparty_chain_id.clone(),
+                                    client_id: fetch
+                                        .client_id
+                                        .clone()
+                                        .decode_spec::<IbcUnion>()
+                                        .unwrap(),
+                                }),
+                            ))
+                        },
+                    )
+                    .visit_op(&mut op);
+
+                    op
+                })
+                .enumerate()
+                .map(|(i, op)| (vec![i], op))
+                .collect(),
+        })
+    }
+
+    #[instrument(skip_all, fields(chain_id = %self.chain_id))]
+    async fn call(&self, e: &Extensions, msg: ModuleCall) -> RpcResult<Op<VoyagerMessage>> {
+        match msg {
+            ModuleCall::FetchUpdate(FetchUpdate {
+                from_height,
+                to_height,
+                counterparty_chain_id,
+                client_id,
+            }) => self
+                .fetch_update(
+                    e.voyager_client()?,
+                    from_height,
+                    to_height,
+                    counterparty_chain_id,
+                    client_id,
+                )
+                .await
+                .map_err(|e| {
+                    ErrorObject::owned(
+                        -1,
+                        format!("error fetching update: {}", ErrorReporter(&*e)),
+                        None::<()>,
+                    )
+                }),
+            ModuleCall::FetchL2Update(FetchL2Update {
+                update_from,
+                counterparty_chain_id,
+                client_id,
+            }) => self
+                .fetch_l2_update(
+                    e.voyager_client()?,
+                    update_from,
+                    counterparty_chain_id,
+                    client_id,
+                )
+                .await
+                .map_err(|e| {
+                    ErrorObject::owned(
+                        -1,
+                        format!("error fetching l2 update: {}", ErrorReporter(&*e)),
+                        None::<()>,
+                    )
+                }),
+        }
+    }
+
+    #[instrument(skip_all, fields(chain_id = %self.chain_id))]
+    async fn callback(
+        &self,
+        _: &Extensions,
+        cb: Never,
+        _data: VecDeque<Data>,
+    ) -> RpcResult<Op<VoyagerMessage>> {
+        match cb {}
+    }
+}
+
+impl Module {
+    async fn fetch_linea_client_state(
+        &self,
+        voy_client: &VoyagerClient,
+        counterparty_chain_id: &ChainId,
+        client_id: ClientId,
+    ) -> RpcResult<ClientState> {
+        let counterparty_latest_height = voy_client
+            .query_latest_height(counterparty_chain_id.clone(), false)
+            .await?;
+
+        let raw_linea_client_state = voy_client
+            .query_ibc_state(
+                counterparty_chain_id.clone(),
+                QueryHeight::Specific(counterparty_latest_height),
+                ClientStatePath { client_id },
+            )
+            .await?;
+
+        debug!(?raw_linea_client_state);
+
+        let linea_client_state_info = voy_client
+            .client_info::<IbcUnion>(counterparty_chain_id.clone(), client_id)
+            .await?;
+
+        debug!(?linea_client_state_info);
+
+        let linea_client_state = voy_client
+            .decode_client_state::<IbcUnion, ClientState>(
+                linea_client_state_info.client_type,
+                linea_client_state_info.ibc_interface,
+                raw_linea_client_state,
+            )
+            .await?;
+
+        debug!(?linea_client_state);
+
+        Ok(linea_client_state)
+    }
+
+    /// Fetch the proofs of the `LineaRollup` contract storage root in the L1 state root, and of
+    /// `currentL2BlockNumber`, `currentL2Timestamp` and `stateRootHashes[l2_block_number]` in the
+    /// `LineaRollup` contract storage.
+    #[instrument(
+        skip_all,
+        fields(
+            %l1_height,
+            %l2_block_number,
+            l1_rollup_contract_address = %self.l1_rollup_contract_address
+        )
+    )]
+    async fn fetch_rollup_proofs(
+        &self,
+        l1_height: u64,
+        l2_block_number: u64,
+    ) -> RpcResult<(AccountProof, [StorageProof; 3])> {
+        let proof = self
+            .l1_provider
+            .get_proof(
+                self.l1_rollup_contract_address.into(),
+                [
+                    self.l1_rollup_current_l2_block_number_slot,
+                    self.l1_rollup_current_l2_timestamp_slot,
+                    linea_verifier::state_root_hashes_mapping_key(
+                        &self.l1_rollup_l2_state_root_hashes_slot,
+                        &l2_block_number.into(),
+                    ),
+                ]
+                .into_iter()
+                .map(|slot| slot.to_be_bytes().into())
+                .collect(),
+            )
+            .block_id(l1_height.into())
+            .await
+            .map_err(|e| {
+                ErrorObject::owned(
+                    -1,
+                    ErrorReporter(e).with_message("error fetching rollup contract proof"),
+                    None::<()>,
+                )
+            })?;
+
+        debug!(storage_hash = %proof.storage_hash, "fetched rollup contract proof");
+
+        let storage_proofs = proof
+            .storage_proof
+            .into_iter()
+            .map(|proof| StorageProof {
+                key: U256::from_be_bytes(proof.key.as_b256().0),
+                value: U256::from_be_bytes(proof.value.to_be_bytes()),
+                proof: proof.proof.into_iter().map(|bytes| bytes.into()).collect(),
+            })
+            .collect::<Vec<_>>()
+            .try_into()
+            .map_err(|proofs: Vec<_>| {
+                ErrorObject::owned(
+                    -1,
+                    format!("expected 3 storage proofs, found {}", proofs.len()),
+                    None::<()>,
+                )
+            })?;
+
+        Ok((
+            AccountProof {
+                storage_root: proof.storage_hash.into(),
+                proof: proof.account_proof.into_iter().map(|x| x.into()).collect(),
+            },
+            storage_proofs,
+        ))
+    }
+
+    #[instrument(
+        skip_all,
+        fields(
+            chain_id = %self.chain_id,
+            %counterparty_chain_id,
+            %update_from,
+            %update_to,
+        )
+    )]
+    async fn fetch_update(
+        &self,
+        voy_client: &VoyagerClient,
+        update_from: Height,
+        update_to: Height,
+        counterparty_chain_id: ChainId,
+        client_id: ClientId,
+    ) -> Result<Op<VoyagerMessage>, BoxDynError> {
+        let ClientState::V1(linea_client_state) = self
+            .fetch_linea_client_state(voy_client, &counterparty_chain_id, client_id)
+            .await?;
+
+        if linea_client_state.latest_height >= update_to.height() {
+            info!("linea: irrelevant update");
+            Ok(data(OrderedHeaders { headers: vec![] }))
+        } else {
+            let l1_client_info = voy_client
+                .client_info::<IbcUnion>(
+                    counterparty_chain_id.clone(),
+                    linea_client_state.l1_client_id,
+                )
+                .await?;
+
+            let l1_client_meta = voy_client
+                .client_state_meta::<IbcUnion>(
+                    counterparty_chain_id.clone(),
+                    QueryHeight::Latest,
+                    linea_client_state.l1_client_id,
+                )
+                .await?;
+
+            // Latest L1 finalized height
+            let l1_latest_height = voy_client
+                .query_latest_height(l1_client_meta.counterparty_chain_id.clone(), true)
+                .await?;
+
+            Ok(conc([
+                promise(
+                    [call(FetchUpdateHeaders {
+                        client_type: l1_client_info.client_type,
+                        chain_id: l1_client_meta.counterparty_chain_id.clone(),
+                        counterparty_chain_id: counterparty_chain_id.clone(),
+                        client_id: RawClientId::new(linea_client_state.l1_client_id),
+                        update_from: l1_client_meta.counterparty_height,
+                        update_to: l1_latest_height,
+                    })],
+                    [],
+                    AggregateSubmitTxFromOrderedHeaders {
+                        ibc_spec_id: IbcUnion::ID,
+                        chain_id: counterparty_chain_id.clone(),
+                        client_id: RawClientId::new(linea_client_state.l1_client_id),
+                    },
+                ),
+                seq([
+                    call(WaitForTrustedHeight {
+                        chain_id: counterparty_chain_id.clone(),
+                        ibc_spec_id: IbcUnion::ID,
+                        client_id: RawClientId::new(linea_client_state.l1_client_id),
+                        height: l1_latest_height,
+                        finalized: false,
+                    }),
+                    // wait for 1 extra block to ensure that the L1 update is in state, and this update will not end up in the same block (and potentially get reordered)
+                    call(WaitForHeightRelative {
+                        chain_id: counterparty_chain_id.clone(),
+                        height_diff: 1,
+                        finalized: false,
+                    }),
+                    call(PluginMessage::new(
+                        self.plugin_name(),
+                        ModuleCall::from(FetchL2Update {
+                            update_from,
+                            counterparty_chain_id,
+                            client_id,
+                        }),
+                    )),
+                ]),
+            ]))
+        }
+    }
+
+    #[instrument(
+        skip_all,
+        fields(
+            chain_id = %self.chain_id,
+            %counterparty_chain_id,
+            %update_from,
+            %client_id,
+        )
+    )]
+    async fn fetch_l2_update(
+        &self,
+        voy_client: &VoyagerClient,
+        update_from: Height,
+        counterparty_chain_id: ChainId,
+        client_id: ClientId,
+    ) -> Result<Op<VoyagerMessage>, BoxDynError> {
+        let ClientState::V1(linea_client_state) = self
+            .fetch_linea_client_state(voy_client, &counterparty_chain_id, client_id)
+            .await?;
+
+        let l1_client_meta = voy_client
+            .client_state_meta::<IbcUnion>(
+                counterparty_chain_id.clone(),
+                QueryHeight::Latest,
+                linea_client_state.l1_client_id,
+            )
+            .await?;
+
+        let l1_height = l1_client_meta.counterparty_height.height();
+
+        let l2_block_number = linea_client::finalized_l2_block_number_of_l1_block_number(
+            &self.l1_provider,
+            self.l1_rollup_contract_address,
+            l1_height,
+        )
+        .await
+        .map_err(|err| ErrorObject::owned(-1, ErrorReporter(err).to_string(), None::<()>))?;
+
+        debug!(%l2_block_number);
+
+        let (
+            l1_rollup_contract_proof,
+            [l2_block_number_proof, l2_timestamp_proof, l2_state_root_proof],
+        ) = self.fetch_rollup_proofs(l1_height, l2_block_number).await?;
+
+        let l2_ibc_contract_proof = match linea_client::get_proof(
+            &self.l2_provider,
+            self.ibc_handler_address,
+            vec![],
+            l2_block_number,
+        )
+        .await
+        .map_err(|e| {
+            ErrorObject::owned(
+                -1,
+                ErrorReporter(e).with_message("error fetching ibc contract proof"),
+                None::<()>,
+            )
+        })?
+        .account_proof
+        {
+            MerkleProof::Inclusion(inclusion_proof) => inclusion_proof,
+            MerkleProof::NonInclusion(_) => {
+                return Err(ErrorObject::owned(
+                    FATAL_JSONRPC_ERROR_CODE,
+                    format!(
+                        "ibc handler {} does not exist at l2 height {l2_block_number}",
+                        self.ibc_handler_address
+                    ),
+                    None::<()>,
+                )
+                .into())
+            }
+        };
+
+        Ok(data(OrderedHeaders {
+            headers: vec![(
+                DecodedHeaderMeta {
+                    height: Height::new(l2_block_number),
+                },
+                into_value(Header {
+                    l1_height,
+                    l1_rollup_contract_proof,
+                    l2_block_number_proof,
+                    l2_state_root_proof,
+                    l2_timestamp_proof,
+                    l2_ibc_contract_proof,
+                }),
+            )],
+        }))
+    }
+}
@@ -0,0 +1,200 @@
+use std::collections::VecDeque;
+
+use jsonrpsee::{
+    core::{async_trait, RpcResult},
+    http_client::{HttpClient, HttpClientBuilder},
+    types::ErrorObject,
+    Extensions,
+};
+use mock_chain::rpc::MockChainRpcClient;
+use serde::{Deserialize, Serialize};
+use tracing::{info, instrument};
+use unionlabs::{ibc::core::client::height::Height, never::Never, ErrorReporter};
+use voyager_sdk::{
+    anyhow::{self, bail},
+    hook::UpdateHook,
+    into_value,
+    message::{
+        call::Call,
+        data::{Data, DecodedHeaderMeta, OrderedHeaders},
+        PluginMessage, VoyagerMessage,
+    },
+    plugin::Plugin,
+    primitives::{ChainId, ClientType},
+    rpc::{types::PluginInfo, PluginServer},
+    vm::{self, pass::PassResult, Op, Visit},
+    DefaultCmd,
+};
+
+use crate::call::{FetchUpdate, ModuleCall};
+
+pub mod call;
+
+#[derive(Debug, Clone)]
+pub struct Module {
+    pub chain_id: ChainId,
+
+    pub client: HttpClient,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    pub chain_id: ChainId,
+
+    /// The RPC endpoint of the mock chain.
+    pub rpc_url: String,
+}
+
+fn plugin_name(chain_id: &ChainId) -> String {
+    pub const PLUGIN_NAME: &str = env!("CARGO_PKG_NAME");
+
+    format!("{PLUGIN_NAME}/{}", chain_id)
+}
+
+impl Module {
+    fn plugin_name(&self) -> String {
+        plugin_name(&self.chain_id)
+    }
+}
+
+impl Plugin for Module {
+    type Call = ModuleCall;
+    type Callback = Never;
+
+    type Config = Config;
+    type Cmd = DefaultCmd;
+
+    async fn new(config: Self::Config) -> anyhow::Result<Self> {
+        let client = HttpClientBuilder::default().build(&config.rpc_url)?;
+
+        let chain_id = client.chain_id().await?;
+
+        if chain_id != config.chain_id {
+            bail!(
+                "incorrect chain id: expected `{}`, but found `{}`",
+                config.chain_id,
+                chain_id
+            );
+        }
+
+        Ok(Self { chain_id, client })
+    }
+
+    fn info(config: Self::Config) -> PluginInfo {
+        PluginInfo {
+            name: plugin_name(&config.chain_id),
+            interest_filter: UpdateHook::filter(
+                &config.chain_id,
+                &ClientType::new(ClientType::MOCK),
+            ),
+        }
+    }
+
+    async fn cmd(_config: Self::Config, cmd: Self::Cmd) {
+        match cmd {}
+    }
+}
+
+#[async_trait]
+impl PluginServer<ModuleCall, Never> for Module {
+    #[instrument(skip_all, fields(chain_id = %self.chain_id))]
+    async fn run_pass(
+        &self,
+        _: &Extensions,
+        msgs: Vec<Op<VoyagerMessage>>,
+    ) -> RpcResult<PassResult<VoyagerMessage>> {
+        Ok(PassResult {
+            optimize_further: vec![],
+            ready: msgs
+                .into_iter()
+                .map(|mut op| {
+                    UpdateHook::new(
+                        &self.chain_id,
+                        &ClientType::new(ClientType::MOCK),
+                        |fetch| {
+                            Call::Plugin(PluginMessage::new(
+                                self.plugin_name(),
+                                ModuleCall::from(FetchUpdate {
+                                    from_height: fetch.update_from,
+                                    to_height: fetch.update_to,
+                                    counterparty_chain_id: fetch.counterparty_chain_id.clone(),
+                                    client_id: fetch.client_id.clone(),
+                                }),
+                            ))
+                        },
+                    )
+                    .visit_op(&mut op);
+
+                    op
+                })
+                .enumerate()
+                .map(|(i, op)| (vec![i], op))
+                .collect(),
+        })
+    }
+
+    #[instrument(skip_all, fields(chain_id = %self.chain_id))]
+    async fn call(&self, _: &Extensions, msg: ModuleCall) -> RpcResult<Op<VoyagerMessage>> {
+        match msg {
+            ModuleCall::FetchUpdate(FetchUpdate {
+                from_height,
+                to_height,
+                ..
+            }) => self.fetch_update(from_height, to_height).await,
+        }
+    }
+
+    #[instrument(skip_all, fields(chain_id = %self.chain_id))]
+    async fn callback(
+        &self,
+        _: &Extensions,
+        cb: Never,
+        _data: VecDeque<Data>,
+    ) -> RpcResult<Op<VoyagerMessage>> {
+        match cb {}
+    }
+}
+
+impl Module {
+    /// Fetch a client update from the provided trusted height (`update_from`) to the desired new
+    /// height (`update_to`).
+    ///
+    /// Mock clients can jump directly to any finalized height, so the update is always a single
+    /// header at `update_to`.
+    #[instrument(skip_all, fields(chain_id = %self.chain_id, %update_from, %update_to))]
+    async fn fetch_update(
+        &self,
+        update_from: Height,
+        update_to: Height,
+    ) -> RpcResult<Op<VoyagerMessage>> {
+        if update_from == update_to {
+            info!("update is for the same height, noop");
+            return Ok(vm::data(OrderedHeaders { headers: vec![] }));
+        }
+
+        let header = self
+            .client
+            .block(update_to.height())
+            .await
+            .map_err(|e| {
+                ErrorObject::owned(
+                    -1,
+                    ErrorReporter(e).with_message("error fetching block"),
+                    None::<()>,
+                )
+            })?
+            .ok_or_else(|| {
+                ErrorObject::owned(
+                    -1,
+                    format!("block {update_to} does not exist yet"),
+                    None::<()>,
+                )
+            })?
+            .to_header();
+
+        Ok(vm::data(OrderedHeaders {
+            headers: vec![(DecodedHeaderMeta { height: update_to }, into_value(header))],
+        }))
+    }
+}
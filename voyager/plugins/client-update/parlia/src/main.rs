@@ -7,13 +7,13 @@ use alloy::{
     providers::{DynProvider, Provider, ProviderBuilder},
 };
 use ethereum_light_client_types::AccountProof;
-use ibc_union_spec::{ClientId, IbcUnion};
+use ibc_union_spec::{path::ClientStatePath, ClientId, IbcUnion};
 use jsonrpsee::{
     core::{async_trait, RpcResult},
     types::ErrorObject,
     Extensions,
 };
-use parlia_light_client_types::Header;
+use parlia_light_client_types::{ClientState, Header};
 use parlia_types::ParliaHeader;
 use parlia_verifier::EPOCH_LENGTH;
 use serde::{Deserialize, Serialize};
@@ -29,10 +29,10 @@ use voyager_sdk::{
         PluginMessage, VoyagerMessage,
     },
     plugin::Plugin,
-    primitives::{ChainId, ClientType},
-    rpc::{types::PluginInfo, PluginServer},
+    primitives::{ChainId, ClientType, QueryHeight},
+    rpc::{types::PluginInfo, PluginServer, FATAL_JSONRPC_ERROR_CODE},
     vm::{self, pass::PassResult, BoxDynError, Op, Visit},
-    DefaultCmd,
+    DefaultCmd, ExtensionsExt, VoyagerClient,
 };
 
 use crate::call::{FetchUpdate, ModuleCall};
@@ -186,7 +186,7 @@ impl PluginServer<ModuleCall, Never> for Module {
     }
 
     #[instrument(skip_all, fields(chain_id = %self.chain_id))]
-    async fn call(&self, _: &Extensions, msg: ModuleCall) -> RpcResult<Op<VoyagerMessage>> {
+    async fn call(&self, e: &Extensions, msg: ModuleCall) -> RpcResult<Op<VoyagerMessage>> {
         match msg {
             ModuleCall::FetchUpdate(FetchUpdate {
                 from_height,
@@ -194,8 +194,21 @@ impl PluginServer<ModuleCall, Never> for Module {
                 counterparty_chain_id,
                 client_id,
                 already_fetched_updates,
-            }) => self
-                .fetch_update(
+            }) => {
+                // the first update is the oldest, so if it is within the trusting window then all
+                // of the following updates are as well
+                if already_fetched_updates.is_empty() {
+                    self.ensure_within_trusting_window(
+                        e.voyager_client()?,
+                        from_height,
+                        to_height,
+                        &counterparty_chain_id,
+                        client_id,
+                    )
+                    .await?;
+                }
+
+                self.fetch_update(
                     already_fetched_updates,
                     from_height,
                     to_height,
@@ -209,7 +222,8 @@ impl PluginServer<ModuleCall, Never> for Module {
                         format!("error fetching update: {}", ErrorReporter(&*e)),
                         None::<()>,
                     )
-                }),
+                })
+            }
         }
     }
 
@@ -225,6 +239,95 @@ impl PluginServer<ModuleCall, Never> for Module {
 }
 
 impl Module {
+    /// Ensure that the first header that would be produced for an update from `update_from` to
+    /// `update_to` can still be verified by the client, i.e. that its attestation is not older
+    /// than the unbond period of the client.
+    ///
+    /// Headers outside of the trusting window will be rejected by the client, and will never
+    /// become valid again, so there is no point in retrying - the client must be recovered instead.
+    #[instrument(
+        skip_all,
+        fields(
+            chain_id = %self.chain_id,
+            %counterparty_chain_id,
+            %client_id,
+            %update_from,
+            %update_to,
+        )
+    )]
+    async fn ensure_within_trusting_window(
+        &self,
+        voyager_client: &VoyagerClient,
+        update_from: Height,
+        update_to: Height,
+        counterparty_chain_id: &ChainId,
+        client_id: ClientId,
+    ) -> RpcResult<()> {
+        let Some(block) = windows(update_from.height(), update_to.height()).next() else {
+            return Ok(());
+        };
+
+        let client_info = voyager_client
+            .client_info::<IbcUnion>(counterparty_chain_id.clone(), client_id)
+            .await?;
+
+        let client_state_raw = voyager_client
+            .query_ibc_state(
+                counterparty_chain_id.clone(),
+                QueryHeight::Latest,
+                ClientStatePath { client_id },
+            )
+            .await?;
+
+        let ClientState::V1(client_state) = voyager_client
+            .decode_client_state::<IbcUnion, ClientState>(
+                client_info.client_type,
+                client_info.ibc_interface,
+                client_state_raw,
+            )
+            .await?;
+
+        let counterparty_timestamp = voyager_client
+            .query_latest_timestamp(counterparty_chain_id.clone(), false)
+            .await?;
+
+        let attestation = self
+            .provider
+            .get_block((block + 2).into())
+            .await
+            .map_err(|e| {
+                ErrorObject::owned(
+                    -1,
+                    ErrorReporter(e).with_message(&format!("error fetching block {}", block + 2)),
+                    None::<()>,
+                )
+            })?
+            .ok_or_else(|| {
+                ErrorObject::owned(-1, format!("block {} not found", block + 2), None::<()>)
+            })?;
+
+        let attestation_timestamp = convert_header(attestation).full_timestamp();
+
+        if attestation_timestamp
+            .plus_duration(client_state.unbond_period)
+            .is_none_or(|expiry| expiry < counterparty_timestamp)
+        {
+            return Err(ErrorObject::owned(
+                FATAL_JSONRPC_ERROR_CODE,
+                format!(
+                    "the update to {block} is outside of the trusting window of client \
+                    {client_id} on {counterparty_chain_id} (attestation timestamp \
+                    {attestation_timestamp}, unbond period {unbond_period}, counterparty \
+                    timestamp {counterparty_timestamp}), the client must be recovered",
+                    unbond_period = client_state.unbond_period,
+                ),
+                None::<()>,
+            ));
+        }
+
+        Ok(())
+    }
+
     async fn fetch_ibc_contract_root_proof(&self, height: u64) -> RpcResult<AccountProof> {
         let proof = self
             .provider
@@ -7,6 +7,7 @@ use std::{
     sync::Arc,
 };
 
+use cometbft_rpc::PoolConfig;
 use cosmos_sdk_event::CosmosSdkEvent;
 use dashmap::DashMap;
 use ibc_classic_spec::IbcClassic;
@@ -84,7 +85,8 @@ pub struct Module {
 pub struct Config {
     pub chain_id: ChainId,
 
-    pub rpc_url: String,
+    /// The RPC endpoints of the chain.
+    pub rpc_url: PoolConfig,
 
     #[serde(default = "default_chunk_block_fetch_size")]
     pub chunk_block_fetch_size: u64,
@@ -122,7 +124,7 @@ impl Plugin for Module {
     type Cmd = Cmd;
 
     async fn new(config: Self::Config) -> anyhow::Result<Self> {
-        let tm_client = cometbft_rpc::Client::new(config.rpc_url).await?;
+        let tm_client = cometbft_rpc::Client::new_pool(config.rpc_url).await?;
 
         let chain_id = tm_client.status().await?.node_info.network;
 
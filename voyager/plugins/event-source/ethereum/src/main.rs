@@ -24,6 +24,7 @@ use jsonrpsee::{
     types::ErrorObject,
     Extensions,
 };
+use rpc_pool::PoolConfig;
 use serde::{Deserialize, Serialize};
 use tracing::{debug, info, info_span, instrument, trace, warn};
 use unionlabs::{
@@ -81,8 +82,8 @@ pub struct Config {
     #[serde(default = "default_chunk_block_fetch_size")]
     pub chunk_block_fetch_size: u64,
 
-    /// The RPC endpoint for the execution chain.
-    pub rpc_url: String,
+    /// The RPC endpoints for the execution chain.
+    pub rpc_url: PoolConfig,
 
     /// Whether or not to fully index events that do not produce a counterparty action (packet_recv, packet_acknowledgement, packet_timeout, update_client).
     #[serde(default)]
@@ -137,8 +138,7 @@ impl Module {
         let provider = DynProvider::new(
             ProviderBuilder::new()
                 .layer(CacheLayer::new(config.max_cache_size))
-                .connect(&config.rpc_url)
-                .await?,
+                .connect_client(rpc_pool::alloy::client(config.rpc_url).await?),
         );
 
         // TODO: Assert chain id is correct
@@ -0,0 +1,25 @@
+use enumorph::Enumorph;
+use macros::model;
+
+#[model]
+#[derive(Enumorph)]
+pub enum ModuleCall {
+    FetchBlock(FetchBlock),
+    FetchEvents(FetchEvents),
+}
+
+/// Fetch the events in the block at `height`, and then requeue a seq(wait(H+1), fetch(H+1)) if
+/// `height` is not the upper bound of the range being indexed.
+#[model]
+pub struct FetchBlock {
+    pub height: u64,
+    /// The (inclusive) upper bound of the range being indexed, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub until: Option<u64>,
+}
+
+/// Fetch all IBC events emitted in the block at `height`.
+#[model]
+pub struct FetchEvents {
+    pub height: u64,
+}
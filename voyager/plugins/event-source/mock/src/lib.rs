@@ -0,0 +1,246 @@
+use std::collections::VecDeque;
+
+use ibc_union_spec::IbcUnion;
+use jsonrpsee::{
+    core::{async_trait, RpcResult},
+    http_client::{HttpClient, HttpClientBuilder},
+    types::ErrorObject,
+    Extensions,
+};
+use mock_chain::{rpc::MockChainRpcClient, MockEvent};
+use serde::{Deserialize, Serialize};
+use tracing::{info, instrument};
+use unionlabs::{ibc::core::client::height::Height, never::Never, ErrorReporter};
+use voyager_sdk::{
+    anyhow::{self, bail},
+    hook::simple_take_filter,
+    message::{
+        call::{Call, WaitForHeight},
+        data::{ChainEvent, Data, EventProvableHeight},
+        PluginMessage, VoyagerMessage,
+    },
+    plugin::Plugin,
+    primitives::ChainId,
+    rpc::{types::PluginInfo, PluginServer},
+    vm::{call, conc, data, noop, pass::PassResult, seq, Op},
+    DefaultCmd, ExtensionsExt, VoyagerClient,
+};
+
+use crate::call::{FetchBlock, FetchEvents, ModuleCall};
+
+pub mod call;
+
+#[derive(Debug, Clone)]
+pub struct Module {
+    pub chain_id: ChainId,
+
+    pub client: HttpClient,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    pub chain_id: ChainId,
+
+    /// The RPC endpoint of the mock chain.
+    pub rpc_url: String,
+}
+
+impl Plugin for Module {
+    type Call = ModuleCall;
+    type Callback = Never;
+
+    type Config = Config;
+    type Cmd = DefaultCmd;
+
+    async fn new(config: Self::Config) -> anyhow::Result<Self> {
+        let client = HttpClientBuilder::default().build(&config.rpc_url)?;
+
+        let chain_id = client.chain_id().await?;
+
+        if chain_id != config.chain_id {
+            bail!(
+                "incorrect chain id: expected `{}`, but found `{}`",
+                config.chain_id,
+                chain_id
+            );
+        }
+
+        Ok(Self { chain_id, client })
+    }
+
+    fn info(config: Self::Config) -> PluginInfo {
+        PluginInfo {
+            name: plugin_name(&config.chain_id),
+            interest_filter: simple_take_filter(format!(
+                r#"[.. | (."@type"? == "index" or ."@type"? == "index_range") and ."@value".chain_id == "{}"] | any"#,
+                config.chain_id
+            )),
+        }
+    }
+
+    async fn cmd(_config: Self::Config, cmd: Self::Cmd) {
+        match cmd {}
+    }
+}
+
+fn plugin_name(chain_id: &ChainId) -> String {
+    pub const PLUGIN_NAME: &str = env!("CARGO_PKG_NAME");
+
+    format!("{PLUGIN_NAME}/{}", chain_id)
+}
+
+fn rpc_error(e: jsonrpsee::core::ClientError) -> ErrorObject<'static> {
+    ErrorObject::owned(
+        -1,
+        ErrorReporter(e).with_message("mock chain rpc error"),
+        None::<()>,
+    )
+}
+
+impl Module {
+    fn plugin_name(&self) -> String {
+        plugin_name(&self.chain_id)
+    }
+
+    #[instrument(skip_all, fields(%height))]
+    fn fetch_block(&self, height: u64, until: Option<u64>) -> Op<VoyagerMessage> {
+        let fetch_events = call(PluginMessage::new(
+            self.plugin_name(),
+            ModuleCall::from(FetchEvents { height }),
+        ));
+
+        if until.is_some_and(|until| height >= until) {
+            return fetch_events;
+        }
+
+        conc([
+            fetch_events,
+            seq([
+                call(WaitForHeight {
+                    chain_id: self.chain_id.clone(),
+                    height: Height::new(height + 1),
+                    finalized: true,
+                }),
+                call(PluginMessage::new(
+                    self.plugin_name(),
+                    ModuleCall::from(FetchBlock {
+                        height: height + 1,
+                        until,
+                    }),
+                )),
+            ]),
+        ])
+    }
+
+    #[instrument(skip_all, fields(%height))]
+    async fn fetch_events(
+        &self,
+        voyager_client: &VoyagerClient,
+        height: u64,
+    ) -> RpcResult<Op<VoyagerMessage>> {
+        let events = self.client.events(height).await.map_err(rpc_error)?;
+
+        info!("found {} events", events.len());
+
+        if events.is_empty() {
+            return Ok(noop());
+        }
+
+        let mut ops = vec![];
+
+        for MockEvent {
+            tx_hash,
+            client_id,
+            event,
+        } in events
+        {
+            let client_info = voyager_client
+                .client_info::<IbcUnion>(self.chain_id.clone(), client_id)
+                .await?;
+
+            let client_state_meta = voyager_client
+                .client_state_meta::<IbcUnion>(
+                    self.chain_id.clone(),
+                    Height::new(height).into(),
+                    client_id,
+                )
+                .await?;
+
+            ibc_union_spec::log_event(&event, &self.chain_id);
+
+            ops.push(data(ChainEvent::new::<IbcUnion>(
+                self.chain_id.clone(),
+                client_info,
+                client_state_meta.counterparty_chain_id,
+                tx_hash,
+                EventProvableHeight::Min(Height::new(height)),
+                event,
+            )));
+        }
+
+        Ok(conc(ops))
+    }
+}
+
+#[async_trait]
+impl PluginServer<ModuleCall, Never> for Module {
+    #[instrument(skip_all, fields(chain_id = %self.chain_id))]
+    async fn run_pass(
+        &self,
+        _: &Extensions,
+        msgs: Vec<Op<VoyagerMessage>>,
+    ) -> RpcResult<PassResult<VoyagerMessage>> {
+        Ok(PassResult {
+            optimize_further: vec![],
+            ready: msgs
+                .into_iter()
+                .map(|op| match op {
+                    Op::Call(Call::Index(fetch)) if fetch.chain_id == self.chain_id => {
+                        call(PluginMessage::new(
+                            self.plugin_name(),
+                            ModuleCall::from(FetchBlock {
+                                height: fetch.start_height.height(),
+                                until: None,
+                            }),
+                        ))
+                    }
+                    Op::Call(Call::IndexRange(fetch)) if fetch.chain_id == self.chain_id => {
+                        call(PluginMessage::new(
+                            self.plugin_name(),
+                            ModuleCall::from(FetchBlock {
+                                height: fetch.range.from_height().height(),
+                                until: Some(fetch.range.to_height().height()),
+                            }),
+                        ))
+                    }
+                    op => op,
+                })
+                .enumerate()
+                .map(|(i, op)| (vec![i], op))
+                .collect(),
+        })
+    }
+
+    #[instrument(skip_all, fields(chain_id = %self.chain_id))]
+    async fn callback(
+        &self,
+        _: &Extensions,
+        cb: Never,
+        _data: VecDeque<Data>,
+    ) -> RpcResult<Op<VoyagerMessage>> {
+        match cb {}
+    }
+
+    #[instrument(skip_all, fields(chain_id = %self.chain_id))]
+    async fn call(&self, e: &Extensions, msg: ModuleCall) -> RpcResult<Op<VoyagerMessage>> {
+        match msg {
+            ModuleCall::FetchBlock(FetchBlock { height, until }) => {
+                Ok(self.fetch_block(height, until))
+            }
+            ModuleCall::FetchEvents(FetchEvents { height }) => {
+                self.fetch_events(e.voyager_client()?, height).await
+            }
+        }
+    }
+}
@@ -0,0 +1,7 @@
+use voyager_event_source_plugin_mock::Module;
+use voyager_sdk::plugin::Plugin;
+
+#[tokio::main(flavor = "multi_thread")]
+async fn main() {
+    Module::run().await
+}
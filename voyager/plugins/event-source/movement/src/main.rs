@@ -14,7 +14,7 @@ use ibc_union_spec::{
         ChannelMetadata, ChannelOpenAck, ChannelOpenConfirm, ChannelOpenInit, ChannelOpenTry,
         ConnectionMetadata, ConnectionOpenAck, ConnectionOpenConfirm, ConnectionOpenInit,
         ConnectionOpenTry, CreateClient, FullEvent, PacketAck, PacketMetadata, PacketRecv,
-        PacketSend, UpdateClient, WriteAck,
+        PacketSend, PacketTimeout, UpdateClient, WriteAck,
     },
     path::{ChannelPath, ConnectionPath},
     ChannelId, ClientId, Connection, ConnectionState, IbcUnion, Timestamp,
@@ -763,7 +763,38 @@ impl PluginServer<ModuleCall, Never> for Module {
                             client_id,
                         )
                     }
-                    events::IbcEvent::TimeoutPacket(_) => todo!(),
+                    events::IbcEvent::TimeoutPacket(event) => {
+                        let (
+                            _counterparty_chain_id,
+                            _client_info,
+                            source_channel,
+                            destination_channel,
+                        ) = self
+                            .make_packet_metadata(
+                                self.make_height(height),
+                                event.packet.source_channel_id.try_into().unwrap(),
+                                e.voyager_client()?,
+                            )
+                            .await?;
+
+                        let client_id = source_channel.connection.client_id;
+
+                        (
+                            PacketTimeout {
+                                packet_data: event.packet.data.into(),
+                                packet: PacketMetadata {
+                                    source_channel,
+                                    destination_channel,
+                                    timeout_height: event.packet.timeout_height,
+                                    timeout_timestamp: Timestamp::from_nanos(
+                                        event.packet.timeout_timestamp,
+                                    ),
+                                },
+                            }
+                            .into(),
+                            client_id,
+                        )
+                    }
                 };
 
                 let voyager_client = e.voyager_client()?;
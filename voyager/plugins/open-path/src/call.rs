@@ -0,0 +1,167 @@
+use enumorph::Enumorph;
+use ibc_union_spec::{ChannelId, ClientId, ConnectionId};
+use jsonrpsee::{core::RpcResult, types::ErrorObject};
+use macros::model;
+use unionlabs::{ibc::core::client::height::Height, primitives::Bytes};
+use voyager_sdk::{
+    primitives::{ChainId, ClientType},
+    rpc::FATAL_JSONRPC_ERROR_CODE,
+};
+
+#[model]
+#[derive(Enumorph)]
+pub enum ModuleCall {
+    OpenPath(OpenPath),
+}
+
+/// Drive the handshakes for a new path between two chains, one [`Step`] at a time.
+///
+/// Steps that submit a datagram or check the state of a chain are handled as calls, and re-enqueue this call with the next step once they have made progress (or re-enqueue themselves after a short delay if the chain is not yet in the expected state). Steps that wait for the id of something created by a previous step are taken into this plugin's optimization queue, where they are matched against the IBC events emitted after they were queued. See [`Step`] for which steps are which.
+#[model]
+pub struct OpenPath {
+    pub path: PathConfig,
+    #[serde(default)]
+    pub ids: PathIds,
+    /// The height at which the connection or channel found by the last waiting step is provable on the chain it was created on, as reported by its event. The client tracking that chain is updated to at least this height before the next handshake datagram is submitted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provable_height: Option<Height>,
+    pub step: Step,
+}
+
+impl OpenPath {
+    /// Start opening a path. The clients on both chains must be created by ops that are enqueued at the same time as this call (see [`crate::open_path`]), since only clients created after this call is queued are considered.
+    pub fn new(path: PathConfig) -> Self {
+        Self {
+            path,
+            ids: PathIds::default(),
+            provable_height: None,
+            step: Step::WaitForClients,
+        }
+    }
+
+    pub(crate) fn next(self, step: Step) -> Self {
+        Self { step, ..self }
+    }
+}
+
+#[model]
+pub struct PathConfig {
+    pub chain_a: ChainId,
+    pub chain_b: ChainId,
+    /// The type of the client on `chain_a` tracking `chain_b`.
+    pub client_type_a: ClientType,
+    /// The type of the client on `chain_b` tracking `chain_a`.
+    pub client_type_b: ClientType,
+    /// The port on `chain_a` that the channel will be opened on.
+    pub port_a: Bytes,
+    /// The port on `chain_b` that the channel will be opened on.
+    pub port_b: Bytes,
+    pub version: String,
+}
+
+/// The ids discovered so far while opening a path. `_a` ids are on `chain_a`, and `_b` ids are on `chain_b`.
+#[model]
+#[derive(Default)]
+pub struct PathIds {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_a: Option<ClientId>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_b: Option<ClientId>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub connection_a: Option<ConnectionId>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub connection_b: Option<ConnectionId>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel_a: Option<ChannelId>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel_b: Option<ChannelId>,
+}
+
+impl PathIds {
+    pub(crate) fn client_a(&self) -> RpcResult<ClientId> {
+        require(self.client_a, "client_a")
+    }
+
+    pub(crate) fn client_b(&self) -> RpcResult<ClientId> {
+        require(self.client_b, "client_b")
+    }
+
+    pub(crate) fn connection_a(&self) -> RpcResult<ConnectionId> {
+        require(self.connection_a, "connection_a")
+    }
+
+    pub(crate) fn connection_b(&self) -> RpcResult<ConnectionId> {
+        require(self.connection_b, "connection_b")
+    }
+
+    pub(crate) fn channel_a(&self) -> RpcResult<ChannelId> {
+        require(self.channel_a, "channel_a")
+    }
+
+    pub(crate) fn channel_b(&self) -> RpcResult<ChannelId> {
+        require(self.channel_b, "channel_b")
+    }
+}
+
+fn require<T>(id: Option<T>, name: &str) -> RpcResult<T> {
+    id.ok_or_else(|| {
+        ErrorObject::owned(
+            FATAL_JSONRPC_ERROR_CODE,
+            format!("invalid open path state, `{name}` is required for this step"),
+            None::<()>,
+        )
+    })
+}
+
+/// The steps to open a path, in order.
+///
+/// All `ConnectionOpenInit` and `ChannelOpenInit` datagrams are submitted on `chain_a`. The `WaitForClients`, `WaitForConnectionInit`, `WaitForConnectionTry`, `WaitForChannelInit`, and `WaitForChannelTry` steps are driven by IBC events, and are never handled as calls.
+#[model]
+pub enum Step {
+    /// Wait for the `CreateClient` events of the clients on both chains.
+    WaitForClients,
+    /// Submit `ConnectionOpenInit` on `chain_a`.
+    ConnectionOpenInit,
+    /// Wait for the `ConnectionOpenInit` event of the connection on `chain_a`.
+    WaitForConnectionInit,
+    /// Submit `ConnectionOpenTry` on `chain_b`.
+    ConnectionOpenTry,
+    /// Wait for the `ConnectionOpenTry` event of the connection on `chain_b`.
+    WaitForConnectionTry,
+    /// Submit `ConnectionOpenAck` on `chain_a`.
+    ConnectionOpenAck,
+    /// Wait for the connection on `chain_a` to be open.
+    WaitForConnectionOpenA,
+    /// Submit `ConnectionOpenConfirm` on `chain_b`.
+    ConnectionOpenConfirm,
+    /// Wait for the connection on `chain_b` to be open, and submit `ChannelOpenInit` on `chain_a`.
+    WaitForConnectionOpenB,
+    /// Wait for the `ChannelOpenInit` event of the channel on `chain_a`.
+    WaitForChannelInit,
+    /// Submit `ChannelOpenTry` on `chain_b`.
+    ChannelOpenTry,
+    /// Wait for the `ChannelOpenTry` event of the channel on `chain_b`.
+    WaitForChannelTry,
+    /// Submit `ChannelOpenAck` on `chain_a`.
+    ChannelOpenAck,
+    /// Wait for the channel on `chain_a` to be open.
+    WaitForChannelOpenA,
+    /// Submit `ChannelOpenConfirm` on `chain_b`.
+    ChannelOpenConfirm,
+    /// Wait for the channel on `chain_b` to be open, completing the path.
+    WaitForChannelOpenB,
+}
+
+impl Step {
+    /// Whether this step is driven by IBC events in the optimization queue, rather than handled as a call.
+    pub fn is_waiting_for_event(&self) -> bool {
+        matches!(
+            self,
+            Self::WaitForClients
+                | Self::WaitForConnectionInit
+                | Self::WaitForConnectionTry
+                | Self::WaitForChannelInit
+                | Self::WaitForChannelTry
+        )
+    }
+}
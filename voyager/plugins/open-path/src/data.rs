@@ -0,0 +1,24 @@
+use enumorph::Enumorph;
+use ibc_union_spec::{ChannelId, ClientId, ConnectionId};
+use macros::model;
+use voyager_sdk::primitives::ChainId;
+
+#[model]
+#[derive(Enumorph)]
+pub enum ModuleData {
+    PathOpened(PathOpened),
+}
+
+/// A fully opened path, produced once both ends of the channel are open.
+#[model]
+pub struct PathOpened {
+    pub chain_a: ChainId,
+    pub client_a: ClientId,
+    pub connection_a: ConnectionId,
+    pub channel_a: ChannelId,
+
+    pub chain_b: ChainId,
+    pub client_b: ClientId,
+    pub connection_b: ConnectionId,
+    pub channel_b: ChannelId,
+}
@@ -0,0 +1,1043 @@
+use std::collections::VecDeque;
+
+use ibc_union_spec::{
+    datagram::{
+        Datagram, MsgChannelOpenAck, MsgChannelOpenConfirm, MsgChannelOpenInit, MsgChannelOpenTry,
+        MsgConnectionOpenAck, MsgConnectionOpenConfirm, MsgConnectionOpenInit,
+        MsgConnectionOpenTry,
+    },
+    event::FullEvent,
+    path::{ChannelPath, ConnectionPath},
+    Channel, ChannelState, ClientId, ConnectionState, IbcUnion,
+};
+use jsonrpsee::{
+    core::{async_trait, RpcResult},
+    types::ErrorObject,
+    Extensions,
+};
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info, instrument, warn};
+use unionlabs::{ibc::core::client::height::Height, never::Never, primitives::Bytes};
+use voyager_sdk::{
+    anyhow,
+    message::{
+        call::{Call, SubmitTx},
+        data::{ChainEvent, Data, IbcDatagram},
+        PluginMessage, VoyagerMessage,
+    },
+    plugin::Plugin,
+    primitives::{ChainId, IbcSpec, IbcStorePathKey, QueryHeight},
+    rpc::{types::PluginInfo, PluginServer, FATAL_JSONRPC_ERROR_CODE},
+    vm::{call, conc, data, defer, now, pass::PassResult, seq, Op},
+    DefaultCmd, ExtensionsExt, VoyagerClient,
+};
+
+use crate::{
+    call::{ModuleCall, OpenPath, PathConfig, Step},
+    data::{ModuleData, PathOpened},
+};
+
+pub mod call;
+pub mod data;
+
+/// How long to wait before checking again if a chain is not yet in the state expected by the current step.
+const RETRY_DELAY_SECONDS: u64 = 3;
+
+#[derive(Debug, Clone)]
+pub struct Module {}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {}
+
+impl Plugin for Module {
+    type Call = ModuleCall;
+    type Callback = Never;
+
+    type Config = Config;
+    type Cmd = DefaultCmd;
+
+    async fn new(config: Self::Config) -> anyhow::Result<Self> {
+        Ok(Module::new(config))
+    }
+
+    fn info(_config: Self::Config) -> PluginInfo {
+        PluginInfo {
+            name: plugin_name(),
+            // take the steps that wait for an event, and copy the events that they wait for. note that if another plugin that takes these events is checked before this one, this plugin will never see them.
+            interest_filter: format!(
+                r#"
+if ."@type" == "data"
+    and ."@value"."@type" == "ibc_event"
+    and ."@value"."@value".ibc_spec_id == "{ibc_union_id}"
+    and (."@value"."@value".event."@type" as $event_type | {event_types})
+then
+    false # interest, but only copy
+elif ."@type" == "call"
+    and ."@value"."@type" == "plugin"
+    and ."@value"."@value".plugin == "{plugin_name}"
+    and ."@value"."@value".message."@type" == "open_path"
+    and (."@value"."@value".message."@value".step."@type" as $step | {steps})
+then
+    true
+else
+    null
+end
+"#,
+                ibc_union_id = IbcUnion::ID,
+                plugin_name = plugin_name(),
+                event_types = [
+                    "create_client",
+                    "connection_open_init",
+                    "connection_open_try",
+                    "channel_open_init",
+                    "channel_open_try",
+                ]
+                .map(|event_type| format!(r#"$event_type == "{event_type}""#))
+                .join(" or "),
+                steps = [
+                    "wait_for_clients",
+                    "wait_for_connection_init",
+                    "wait_for_connection_try",
+                    "wait_for_channel_init",
+                    "wait_for_channel_try",
+                ]
+                .map(|step| format!(r#"$step == "{step}""#))
+                .join(" or "),
+            ),
+        }
+    }
+
+    async fn cmd(_config: Self::Config, cmd: Self::Cmd) {
+        match cmd {}
+    }
+}
+
+pub fn plugin_name() -> String {
+    pub const PLUGIN_NAME: &str = env!("CARGO_PKG_NAME");
+
+    PLUGIN_NAME.to_owned()
+}
+
+/// Open `path`, creating the client on `chain_a` with `create_client_a` and the client on `chain_b` with `create_client_b`.
+///
+/// The ops are queued concurrently so that the open path call is already waiting when the clients are created, and will see their `CreateClient` events.
+pub fn open_path(
+    path: PathConfig,
+    create_client_a: Op<VoyagerMessage>,
+    create_client_b: Op<VoyagerMessage>,
+) -> Op<VoyagerMessage> {
+    conc([
+        call(make_call(OpenPath::new(path))),
+        create_client_a,
+        create_client_b,
+    ])
+}
+
+impl Module {
+    pub fn new(_: Config) -> Self {
+        Self {}
+    }
+
+    #[instrument(
+        skip_all,
+        fields(
+            chain_a = %open_path.path.chain_a,
+            chain_b = %open_path.path.chain_b,
+            step = ?open_path.step,
+        )
+    )]
+    async fn open_path(
+        &self,
+        voyager_client: &VoyagerClient,
+        open_path: OpenPath,
+    ) -> RpcResult<Op<VoyagerMessage>> {
+        // the path and ids are cloned so that `open_path` can be moved into the next step
+        let OpenPath {
+            path,
+            ids,
+            provable_height,
+            step,
+        } = open_path.clone();
+
+        match step {
+            Step::WaitForClients
+            | Step::WaitForConnectionInit
+            | Step::WaitForConnectionTry
+            | Step::WaitForChannelInit
+            | Step::WaitForChannelTry => Err(ErrorObject::owned(
+                FATAL_JSONRPC_ERROR_CODE,
+                format!(
+                    "step {step:?} waits for an event and can only be handled in the \
+                    optimization queue, is another plugin taking calls for this plugin?"
+                ),
+                None::<()>,
+            )),
+            Step::ConnectionOpenInit => {
+                let (client_a, client_b) = (ids.client_a()?, ids.client_b()?);
+
+                Ok(submit_tx_then_wait(
+                    path.chain_a.clone(),
+                    MsgConnectionOpenInit {
+                        client_id: client_a,
+                        counterparty_client_id: client_b,
+                    },
+                    open_path.next(Step::WaitForConnectionInit),
+                ))
+            }
+            Step::ConnectionOpenTry => {
+                let (client_a, client_b, connection_a) =
+                    (ids.client_a()?, ids.client_b()?, ids.connection_a()?);
+
+                if let Some(update) =
+                    update_client(voyager_client, &path.chain_b, client_b, provable_height).await?
+                {
+                    return Ok(seq([update, call(make_call(open_path))]));
+                }
+
+                let (proof_height, proof_init) = prove(
+                    voyager_client,
+                    &path.chain_a,
+                    &path.chain_b,
+                    client_b,
+                    ConnectionPath {
+                        connection_id: connection_a,
+                    },
+                )
+                .await?;
+
+                Ok(submit_tx_then_wait(
+                    path.chain_b.clone(),
+                    MsgConnectionOpenTry {
+                        client_id: client_b,
+                        counterparty_client_id: client_a,
+                        counterparty_connection_id: connection_a,
+                        proof_height,
+                        proof_init,
+                    },
+                    open_path.next(Step::WaitForConnectionTry),
+                ))
+            }
+            Step::ConnectionOpenAck => {
+                let (client_a, connection_a, connection_b) =
+                    (ids.client_a()?, ids.connection_a()?, ids.connection_b()?);
+
+                if let Some(update) =
+                    update_client(voyager_client, &path.chain_a, client_a, provable_height).await?
+                {
+                    return Ok(seq([update, call(make_call(open_path))]));
+                }
+
+                let (proof_height, proof_try) = prove(
+                    voyager_client,
+                    &path.chain_b,
+                    &path.chain_a,
+                    client_a,
+                    ConnectionPath {
+                        connection_id: connection_b,
+                    },
+                )
+                .await?;
+
+                Ok(seq([
+                    submit_tx(
+                        path.chain_a.clone(),
+                        MsgConnectionOpenAck {
+                            connection_id: connection_a,
+                            counterparty_connection_id: connection_b,
+                            proof_height,
+                            proof_try,
+                        },
+                    ),
+                    call(make_call(open_path.next(Step::WaitForConnectionOpenA))),
+                ]))
+            }
+            Step::WaitForConnectionOpenA => {
+                let (client_b, connection_a) = (ids.client_b()?, ids.connection_a()?);
+
+                let connection = voyager_client
+                    .query_ibc_state(
+                        path.chain_a.clone(),
+                        QueryHeight::Finalized,
+                        ConnectionPath {
+                            connection_id: connection_a,
+                        },
+                    )
+                    .await?;
+
+                if connection.state != ConnectionState::Open {
+                    return Ok(retry(open_path));
+                }
+
+                update_client_then(
+                    voyager_client,
+                    &path.chain_b,
+                    client_b,
+                    open_path.next(Step::ConnectionOpenConfirm),
+                )
+                .await
+            }
+            Step::ConnectionOpenConfirm => {
+                let (client_b, connection_a, connection_b) =
+                    (ids.client_b()?, ids.connection_a()?, ids.connection_b()?);
+
+                let (proof_height, proof_ack) = prove(
+                    voyager_client,
+                    &path.chain_a,
+                    &path.chain_b,
+                    client_b,
+                    ConnectionPath {
+                        connection_id: connection_a,
+                    },
+                )
+                .await?;
+
+                Ok(seq([
+                    submit_tx(
+                        path.chain_b.clone(),
+                        MsgConnectionOpenConfirm {
+                            connection_id: connection_b,
+                            proof_height,
+                            proof_ack,
+                        },
+                    ),
+                    call(make_call(open_path.next(Step::WaitForConnectionOpenB))),
+                ]))
+            }
+            Step::WaitForConnectionOpenB => {
+                let (connection_a, connection_b) = (ids.connection_a()?, ids.connection_b()?);
+
+                let connection = voyager_client
+                    .query_ibc_state(
+                        path.chain_b.clone(),
+                        QueryHeight::Finalized,
+                        ConnectionPath {
+                            connection_id: connection_b,
+                        },
+                    )
+                    .await?;
+
+                if connection.state != ConnectionState::Open {
+                    return Ok(retry(open_path));
+                }
+
+                info!(%connection_a, %connection_b, "connection open");
+
+                Ok(submit_tx_then_wait(
+                    path.chain_a.clone(),
+                    MsgChannelOpenInit {
+                        port_id: path.port_a.clone(),
+                        counterparty_port_id: path.port_b.clone(),
+                        connection_id: connection_a,
+                        version: path.version.clone(),
+                    },
+                    open_path.next(Step::WaitForChannelInit),
+                ))
+            }
+            Step::ChannelOpenTry => {
+                let (client_b, connection_b, channel_a) =
+                    (ids.client_b()?, ids.connection_b()?, ids.channel_a()?);
+
+                if let Some(update) =
+                    update_client(voyager_client, &path.chain_b, client_b, provable_height).await?
+                {
+                    return Ok(seq([update, call(make_call(open_path))]));
+                }
+
+                let (proof_height, proof_init) = prove(
+                    voyager_client,
+                    &path.chain_a,
+                    &path.chain_b,
+                    client_b,
+                    ChannelPath {
+                        channel_id: channel_a,
+                    },
+                )
+                .await?;
+
+                Ok(submit_tx_then_wait(
+                    path.chain_b.clone(),
+                    MsgChannelOpenTry {
+                        port_id: path.port_b.clone(),
+                        channel: Channel {
+                            state: ChannelState::TryOpen,
+                            connection_id: connection_b,
+                            counterparty_channel_id: Some(channel_a),
+                            counterparty_port_id: path.port_a.clone(),
+                            version: path.version.clone(),
+                        },
+                        counterparty_version: path.version.clone(),
+                        proof_init,
+                        proof_height,
+                    },
+                    open_path.next(Step::WaitForChannelTry),
+                ))
+            }
+            Step::ChannelOpenAck => {
+                let (client_a, channel_a, channel_b) =
+                    (ids.client_a()?, ids.channel_a()?, ids.channel_b()?);
+
+                if let Some(update) =
+                    update_client(voyager_client, &path.chain_a, client_a, provable_height).await?
+                {
+                    return Ok(seq([update, call(make_call(open_path))]));
+                }
+
+                let (proof_height, proof_try) = prove(
+                    voyager_client,
+                    &path.chain_b,
+                    &path.chain_a,
+                    client_a,
+                    ChannelPath {
+                        channel_id: channel_b,
+                    },
+                )
+                .await?;
+
+                Ok(seq([
+                    submit_tx(
+                        path.chain_a.clone(),
+                        MsgChannelOpenAck {
+                            channel_id: channel_a,
+                            counterparty_channel_id: channel_b,
+                            counterparty_version: path.version.clone(),
+                            proof_try,
+                            proof_height,
+                        },
+                    ),
+                    call(make_call(open_path.next(Step::WaitForChannelOpenA))),
+                ]))
+            }
+            Step::WaitForChannelOpenA => {
+                let (client_b, channel_a) = (ids.client_b()?, ids.channel_a()?);
+
+                let channel = voyager_client
+                    .query_ibc_state(
+                        path.chain_a.clone(),
+                        QueryHeight::Finalized,
+                        ChannelPath {
+                            channel_id: channel_a,
+                        },
+                    )
+                    .await?;
+
+                if channel.state != ChannelState::Open {
+                    return Ok(retry(open_path));
+                }
+
+                update_client_then(
+                    voyager_client,
+                    &path.chain_b,
+                    client_b,
+                    open_path.next(Step::ChannelOpenConfirm),
+                )
+                .await
+            }
+            Step::ChannelOpenConfirm => {
+                let (client_b, channel_a, channel_b) =
+                    (ids.client_b()?, ids.channel_a()?, ids.channel_b()?);
+
+                let (proof_height, proof_ack) = prove(
+                    voyager_client,
+                    &path.chain_a,
+                    &path.chain_b,
+                    client_b,
+                    ChannelPath {
+                        channel_id: channel_a,
+                    },
+                )
+                .await?;
+
+                Ok(seq([
+                    submit_tx(
+                        path.chain_b.clone(),
+                        MsgChannelOpenConfirm {
+                            channel_id: channel_b,
+                            proof_ack,
+                            proof_height,
+                        },
+                    ),
+                    call(make_call(open_path.next(Step::WaitForChannelOpenB))),
+                ]))
+            }
+            Step::WaitForChannelOpenB => {
+                let channel_b = ids.channel_b()?;
+
+                let channel = voyager_client
+                    .query_ibc_state(
+                        path.chain_b.clone(),
+                        QueryHeight::Finalized,
+                        ChannelPath {
+                            channel_id: channel_b,
+                        },
+                    )
+                    .await?;
+
+                if channel.state != ChannelState::Open {
+                    return Ok(retry(open_path));
+                }
+
+                let path_opened = PathOpened {
+                    chain_a: path.chain_a.clone(),
+                    client_a: ids.client_a()?,
+                    connection_a: ids.connection_a()?,
+                    channel_a: ids.channel_a()?,
+                    chain_b: path.chain_b.clone(),
+                    client_b: ids.client_b()?,
+                    connection_b: ids.connection_b()?,
+                    channel_b,
+                };
+
+                info!(?path_opened, "path opened");
+
+                Ok(data(PluginMessage::new(
+                    plugin_name(),
+                    ModuleData::from(path_opened),
+                )))
+            }
+        }
+    }
+}
+
+/// Record the id created by `event` in `open_path`, if it is an event that the current step of `open_path` is waiting for. Returns whether the event was used.
+///
+/// Only events emitted after `open_path` was queued are ever seen, so the ids are never bound to anything that existed before the path started opening. The connection and channel events are additionally matched against the ids found by the previous steps.
+fn record_event(open_path: &mut OpenPath, chain_event: &ChainEvent, event: &FullEvent) -> bool {
+    let OpenPath {
+        path,
+        ids,
+        provable_height,
+        step,
+    } = open_path;
+
+    let on_a =
+        chain_event.chain_id == path.chain_a && chain_event.counterparty_chain_id == path.chain_b;
+    let on_b =
+        chain_event.chain_id == path.chain_b && chain_event.counterparty_chain_id == path.chain_a;
+
+    match (&*step, event) {
+        (Step::WaitForClients, FullEvent::CreateClient(event))
+            if on_a && ids.client_a.is_none() && event.client_type == path.client_type_a =>
+        {
+            ids.client_a = Some(event.client_id);
+        }
+        (Step::WaitForClients, FullEvent::CreateClient(event))
+            if on_b && ids.client_b.is_none() && event.client_type == path.client_type_b =>
+        {
+            ids.client_b = Some(event.client_id);
+        }
+        (Step::WaitForConnectionInit, FullEvent::ConnectionOpenInit(event))
+            if on_a
+                && ids.client_a == Some(event.client_id)
+                && ids.client_b == Some(event.counterparty_client_id) =>
+        {
+            ids.connection_a = Some(event.connection_id);
+            *provable_height = Some(*chain_event.provable_height.height());
+        }
+        (Step::WaitForConnectionTry, FullEvent::ConnectionOpenTry(event))
+            if on_b
+                && ids.client_b == Some(event.client_id)
+                && ids.connection_a == Some(event.counterparty_connection_id) =>
+        {
+            ids.connection_b = Some(event.connection_id);
+            *provable_height = Some(*chain_event.provable_height.height());
+        }
+        (Step::WaitForChannelInit, FullEvent::ChannelOpenInit(event))
+            if on_a
+                && ids.client_a == Some(event.connection.client_id)
+                && ids.connection_b == event.connection.counterparty_connection_id
+                && event.port_id == path.port_a
+                && event.counterparty_port_id == path.port_b
+                && event.version == path.version =>
+        {
+            ids.channel_a = Some(event.channel_id);
+            *provable_height = Some(*chain_event.provable_height.height());
+        }
+        (Step::WaitForChannelTry, FullEvent::ChannelOpenTry(event))
+            if on_b
+                && ids.client_b == Some(event.connection.client_id)
+                && ids.channel_a == Some(event.counterparty_channel_id)
+                && event.port_id == path.port_b
+                && event.counterparty_port_id == path.port_a =>
+        {
+            ids.channel_b = Some(event.channel_id);
+            *provable_height = Some(*chain_event.provable_height.height());
+        }
+        _ => return false,
+    }
+
+    true
+}
+
+/// The step following the current step of `open_path`, if it is waiting for an event and all of the ids it is waiting for have been found.
+fn next_step(open_path: &OpenPath) -> Option<Step> {
+    let ids = &open_path.ids;
+
+    match open_path.step {
+        Step::WaitForClients => {
+            (ids.client_a.is_some() && ids.client_b.is_some()).then_some(Step::ConnectionOpenInit)
+        }
+        Step::WaitForConnectionInit => ids
+            .connection_a
+            .is_some()
+            .then_some(Step::ConnectionOpenTry),
+        Step::WaitForConnectionTry => ids
+            .connection_b
+            .is_some()
+            .then_some(Step::ConnectionOpenAck),
+        Step::WaitForChannelInit => ids.channel_a.is_some().then_some(Step::ChannelOpenTry),
+        Step::WaitForChannelTry => ids.channel_b.is_some().then_some(Step::ChannelOpenAck),
+        _ => None,
+    }
+}
+
+fn make_call(open_path: OpenPath) -> PluginMessage {
+    PluginMessage::new(plugin_name(), ModuleCall::from(open_path))
+}
+
+/// Re-run the current step after [`RETRY_DELAY_SECONDS`].
+fn retry(open_path: OpenPath) -> Op<VoyagerMessage> {
+    seq([
+        defer(now() + RETRY_DELAY_SECONDS),
+        call(make_call(open_path)),
+    ])
+}
+
+fn submit_tx(chain_id: ChainId, datagram: impl Into<Datagram>) -> Op<VoyagerMessage> {
+    call(SubmitTx {
+        chain_id,
+        datagrams: vec![IbcDatagram::new::<IbcUnion>(datagram.into())],
+    })
+}
+
+/// Submit `datagram` on `chain_id`, and wait for its event with `next`.
+///
+/// `next` is queued concurrently with the submission (rather than after it) so that it is guaranteed to already be waiting when the event is emitted.
+fn submit_tx_then_wait(
+    chain_id: ChainId,
+    datagram: impl Into<Datagram>,
+    next: OpenPath,
+) -> Op<VoyagerMessage> {
+    debug_assert!(next.step.is_waiting_for_event());
+
+    conc([call(make_call(next)), submit_tx(chain_id, datagram)])
+}
+
+/// Ensure that `client_id` on `chain_id` has been updated to at least the latest finalized height of the chain it tracks, and then run `next`.
+///
+/// All state checked by the previous step was queried at a finalized height, so it is guaranteed to be provable once this update is complete.
+async fn update_client_then(
+    voyager_client: &VoyagerClient,
+    chain_id: &ChainId,
+    client_id: ClientId,
+    next: OpenPath,
+) -> RpcResult<Op<VoyagerMessage>> {
+    match update_client(voyager_client, chain_id, client_id, None).await? {
+        Some(update) => Ok(seq([update, call(make_call(next))])),
+        None => Ok(call(make_call(next))),
+    }
+}
+
+/// Build the ops to update `client_id` on `chain_id` to the latest finalized height of the chain it tracks, if it does not yet trust `min_height` (or the latest finalized height if `min_height` is `None`). Returns `None` if no update is required.
+///
+/// If the chain has not yet finalized `min_height`, this instead waits for [`RETRY_DELAY_SECONDS`], after which the caller is expected to check again.
+async fn update_client(
+    voyager_client: &VoyagerClient,
+    chain_id: &ChainId,
+    client_id: ClientId,
+    min_height: Option<Height>,
+) -> RpcResult<Option<Op<VoyagerMessage>>> {
+    let client_state_meta = voyager_client
+        .client_state_meta::<IbcUnion>(chain_id.clone(), QueryHeight::Latest, client_id)
+        .await?;
+
+    let latest_height = voyager_client
+        .query_latest_height(client_state_meta.counterparty_chain_id.clone(), true)
+        .await?;
+
+    let min_height = min_height.unwrap_or(latest_height);
+
+    if client_state_meta.counterparty_height >= min_height {
+        debug!(
+            %client_id,
+            "client is already updated to {} (>= {min_height})",
+            client_state_meta.counterparty_height,
+        );
+
+        return Ok(None);
+    }
+
+    if latest_height < min_height {
+        debug!(
+            %client_id,
+            "{min_height} is not yet finalized on {} (latest finalized height is {latest_height})",
+            client_state_meta.counterparty_chain_id,
+        );
+
+        return Ok(Some(defer(now() + RETRY_DELAY_SECONDS)));
+    }
+
+    voyager_sdk::update_client_to::<IbcUnion>(voyager_client, chain_id, client_id, latest_height)
+        .await
+}
+
+/// Prove `path` on `origin_chain_id` at the latest height trusted by `client_id` on `target_chain_id`, returning the proof height and the proof encoded for the client.
+async fn prove<P: IbcStorePathKey<Spec = IbcUnion>>(
+    voyager_client: &VoyagerClient,
+    origin_chain_id: &ChainId,
+    target_chain_id: &ChainId,
+    client_id: ClientId,
+    path: P,
+) -> RpcResult<(u64, Bytes)> {
+    let proof_height = voyager_client
+        .client_state_meta::<IbcUnion>(target_chain_id.clone(), QueryHeight::Latest, client_id)
+        .await?
+        .counterparty_height;
+
+    let client_info = voyager_client
+        .client_info::<IbcUnion>(target_chain_id.clone(), client_id)
+        .await?;
+
+    let proof = voyager_client
+        .query_ibc_proof(
+            origin_chain_id.clone(),
+            QueryHeight::Specific(proof_height),
+            path,
+        )
+        .await?
+        .into_result()?
+        .proof;
+
+    let encoded_proof = voyager_client
+        .encode_proof::<IbcUnion>(client_info.client_type, client_info.ibc_interface, proof)
+        .await?;
+
+    Ok((proof_height.height(), encoded_proof))
+}
+
+#[async_trait]
+impl PluginServer<ModuleCall, Never> for Module {
+    #[instrument(skip_all)]
+    async fn run_pass(
+        &self,
+        _: &Extensions,
+        msgs: Vec<Op<VoyagerMessage>>,
+    ) -> RpcResult<PassResult<VoyagerMessage>> {
+        let mut waiting = vec![];
+        let mut events = vec![];
+
+        for (idx, msg) in msgs.into_iter().enumerate() {
+            match msg {
+                Op::Call(Call::Plugin(msg)) => match msg.downcast::<ModuleCall>(plugin_name()) {
+                    Ok(ModuleCall::OpenPath(open_path)) => waiting.push((idx, open_path)),
+                    Err(msg) => warn!(?msg, "unexpected call in queue"),
+                },
+                Op::Data(Data::IbcEvent(chain_event)) => {
+                    match chain_event.decode_event::<IbcUnion>() {
+                        Some(Ok(event)) => events.push((idx, chain_event, event)),
+                        _ => warn!(?chain_event, "unable to decode ibc union event"),
+                    }
+                }
+                msg => warn!(?msg, "unexpected message in queue"),
+            }
+        }
+
+        let mut result = PassResult::default();
+
+        for (idx, mut open_path) in waiting {
+            let mut parents = vec![idx];
+
+            // an event is only ever used by one path, so that paths that are opened concurrently never bind to the same ids
+            events.retain(|(event_idx, chain_event, event)| {
+                let used = record_event(&mut open_path, chain_event, event);
+
+                if used {
+                    parents.push(*event_idx);
+                }
+
+                !used
+            });
+
+            if !open_path.step.is_waiting_for_event() {
+                warn!(step = ?open_path.step, "step does not wait for an event");
+                result.ready.push((parents, call(make_call(open_path))));
+            } else if let Some(step) = next_step(&open_path) {
+                info!(
+                    chain_a = %open_path.path.chain_a,
+                    chain_b = %open_path.path.chain_b,
+                    ids = ?open_path.ids,
+                    "found ids for step {:?}",
+                    open_path.step,
+                );
+
+                result
+                    .ready
+                    .push((parents, call(make_call(open_path.next(step)))));
+            } else {
+                result
+                    .optimize_further
+                    .push((parents, call(make_call(open_path)), plugin_name()));
+            }
+        }
+
+        Ok(result)
+    }
+
+    async fn call(&self, e: &Extensions, msg: ModuleCall) -> RpcResult<Op<VoyagerMessage>> {
+        match msg {
+            ModuleCall::OpenPath(open_path) => self.open_path(e.voyager_client()?, open_path).await,
+        }
+    }
+
+    async fn callback(
+        &self,
+        _: &Extensions,
+        cb: Never,
+        _data: VecDeque<Data>,
+    ) -> RpcResult<Op<VoyagerMessage>> {
+        match cb {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_union_spec::{
+        event::{ChannelOpenInit, ConnectionOpenInit, ConnectionOpenTry, CreateClient},
+        ChannelId, Connection, ConnectionId,
+    };
+    use voyager_sdk::{
+        message::data::EventProvableHeight,
+        primitives::{ClientInfo, ClientType, IbcInterface},
+    };
+
+    use super::*;
+    use crate::call::PathIds;
+
+    fn path() -> PathConfig {
+        PathConfig {
+            chain_a: ChainId::new("chain-a"),
+            chain_b: ChainId::new("chain-b"),
+            client_type_a: ClientType::new("client-a"),
+            client_type_b: ClientType::new("client-b"),
+            port_a: b"port-a".as_slice().into(),
+            port_b: b"port-b".as_slice().into(),
+            version: "version".to_owned(),
+        }
+    }
+
+    fn chain_event(chain_id: &str, counterparty_chain_id: &str, height: u64) -> ChainEvent {
+        ChainEvent {
+            chain_id: ChainId::new(chain_id.to_owned()),
+            client_info: ClientInfo {
+                client_type: ClientType::new("client"),
+                ibc_interface: IbcInterface::new("interface"),
+                metadata: Default::default(),
+            },
+            counterparty_chain_id: ChainId::new(counterparty_chain_id.to_owned()),
+            tx_hash: Default::default(),
+            provable_height: EventProvableHeight::Min(Height::new(height)),
+            ibc_spec_id: IbcUnion::ID,
+            event: Default::default(),
+        }
+    }
+
+    fn client_id(id: u32) -> ClientId {
+        ClientId::from_raw(id).unwrap()
+    }
+
+    fn connection_id(id: u32) -> ConnectionId {
+        ConnectionId::from_raw(id).unwrap()
+    }
+
+    #[test]
+    fn waits_for_both_clients() {
+        let mut open_path = OpenPath::new(path());
+
+        let create_client = |client_type: &str, client_id: u32| {
+            FullEvent::from(CreateClient {
+                client_type: ClientType::new(client_type.to_owned()),
+                client_id: self::client_id(client_id),
+            })
+        };
+
+        // wrong client type
+        assert!(!record_event(
+            &mut open_path,
+            &chain_event("chain-a", "chain-b", 1),
+            &create_client("client-b", 1),
+        ));
+        // wrong counterparty
+        assert!(!record_event(
+            &mut open_path,
+            &chain_event("chain-a", "chain-c", 1),
+            &create_client("client-a", 1),
+        ));
+
+        assert!(record_event(
+            &mut open_path,
+            &chain_event("chain-a", "chain-b", 1),
+            &create_client("client-a", 2),
+        ));
+        assert_eq!(next_step(&open_path), None);
+
+        // the first client created on each chain is used
+        assert!(!record_event(
+            &mut open_path,
+            &chain_event("chain-a", "chain-b", 1),
+            &create_client("client-a", 3),
+        ));
+
+        assert!(record_event(
+            &mut open_path,
+            &chain_event("chain-b", "chain-a", 1),
+            &create_client("client-b", 7),
+        ));
+
+        assert_eq!(
+            open_path.ids,
+            PathIds {
+                client_a: Some(client_id(2)),
+                client_b: Some(client_id(7)),
+                ..Default::default()
+            }
+        );
+        assert_eq!(next_step(&open_path), Some(Step::ConnectionOpenInit));
+    }
+
+    #[test]
+    fn connection_must_be_between_found_clients() {
+        let mut open_path = OpenPath::new(path()).next(Step::WaitForConnectionInit);
+        open_path.ids.client_a = Some(client_id(2));
+        open_path.ids.client_b = Some(client_id(7));
+
+        let connection_open_init = |client_id: u32, counterparty_client_id: u32| {
+            FullEvent::from(ConnectionOpenInit {
+                connection_id: connection_id(5),
+                client_id: self::client_id(client_id),
+                counterparty_client_id: self::client_id(counterparty_client_id),
+            })
+        };
+
+        assert!(!record_event(
+            &mut open_path,
+            &chain_event("chain-a", "chain-b", 10),
+            &connection_open_init(1, 7),
+        ));
+        assert!(!record_event(
+            &mut open_path,
+            &chain_event("chain-a", "chain-b", 10),
+            &connection_open_init(2, 1),
+        ));
+        // on the wrong chain
+        assert!(!record_event(
+            &mut open_path,
+            &chain_event("chain-b", "chain-a", 10),
+            &connection_open_init(2, 7),
+        ));
+        assert_eq!(next_step(&open_path), None);
+
+        assert!(record_event(
+            &mut open_path,
+            &chain_event("chain-a", "chain-b", 10),
+            &connection_open_init(2, 7),
+        ));
+
+        assert_eq!(open_path.ids.connection_a, Some(connection_id(5)));
+        assert_eq!(open_path.provable_height, Some(Height::new(10)));
+        assert_eq!(next_step(&open_path), Some(Step::ConnectionOpenTry));
+    }
+
+    #[test]
+    fn connection_try_must_reference_connection_init() {
+        let mut open_path = OpenPath::new(path()).next(Step::WaitForConnectionTry);
+        open_path.ids.client_a = Some(client_id(2));
+        open_path.ids.client_b = Some(client_id(7));
+        open_path.ids.connection_a = Some(connection_id(5));
+
+        let connection_open_try = |counterparty_connection_id: u32| {
+            FullEvent::from(ConnectionOpenTry {
+                connection_id: connection_id(3),
+                client_id: client_id(7),
+                counterparty_client_id: client_id(2),
+                counterparty_connection_id: connection_id(counterparty_connection_id),
+            })
+        };
+
+        assert!(!record_event(
+            &mut open_path,
+            &chain_event("chain-b", "chain-a", 20),
+            &connection_open_try(4),
+        ));
+        assert!(record_event(
+            &mut open_path,
+            &chain_event("chain-b", "chain-a", 20),
+            &connection_open_try(5),
+        ));
+
+        assert_eq!(open_path.ids.connection_b, Some(connection_id(3)));
+        assert_eq!(open_path.provable_height, Some(Height::new(20)));
+        assert_eq!(next_step(&open_path), Some(Step::ConnectionOpenAck));
+    }
+
+    #[test]
+    fn channel_must_match_path() {
+        let mut open_path = OpenPath::new(path()).next(Step::WaitForChannelInit);
+        open_path.ids.client_a = Some(client_id(2));
+        open_path.ids.client_b = Some(client_id(7));
+        open_path.ids.connection_a = Some(connection_id(5));
+        open_path.ids.connection_b = Some(connection_id(3));
+
+        let channel_open_init = |port_id: &[u8], version: &str| {
+            FullEvent::from(ChannelOpenInit {
+                port_id: port_id.into(),
+                channel_id: ChannelId::from_raw(9).unwrap(),
+                counterparty_port_id: b"port-b".as_slice().into(),
+                connection: Connection {
+                    state: ConnectionState::Open,
+                    client_id: client_id(2),
+                    counterparty_client_id: client_id(7),
+                    counterparty_connection_id: Some(connection_id(3)),
+                },
+                version: version.to_owned(),
+            })
+        };
+
+        assert!(!record_event(
+            &mut open_path,
+            &chain_event("chain-a", "chain-b", 30),
+            &channel_open_init(b"other-port", "version"),
+        ));
+        assert!(!record_event(
+            &mut open_path,
+            &chain_event("chain-a", "chain-b", 30),
+            &channel_open_init(b"port-a", "other-version"),
+        ));
+        assert!(record_event(
+            &mut open_path,
+            &chain_event("chain-a", "chain-b", 30),
+            &channel_open_init(b"port-a", "version"),
+        ));
+
+        assert_eq!(
+            open_path.ids.channel_a,
+            Some(ChannelId::from_raw(9).unwrap())
+        );
+        assert_eq!(next_step(&open_path), Some(Step::ChannelOpenTry));
+    }
+
+    #[test]
+    fn events_are_ignored_by_other_steps() {
+        let mut open_path = OpenPath::new(path()).next(Step::WaitForConnectionOpenA);
+
+        assert!(!record_event(
+            &mut open_path,
+            &chain_event("chain-a", "chain-b", 1),
+            &FullEvent::from(CreateClient {
+                client_type: ClientType::new("client-a"),
+                client_id: client_id(1),
+            }),
+        ));
+        assert_eq!(open_path.ids, PathIds::default());
+        assert_eq!(next_step(&open_path), None);
+    }
+}
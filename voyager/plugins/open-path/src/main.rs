@@ -0,0 +1,7 @@
+use voyager_plugin_open_path::Module;
+use voyager_sdk::plugin::Plugin;
+
+#[tokio::main]
+async fn main() {
+    Module::run().await
+}
@@ -1,22 +1,28 @@
 use std::cmp::Ordering;
 
+use alloy::sol_types::SolValue;
 use enumorph::Enumorph;
 use ibc_classic_spec::IbcClassic;
-use ibc_union_spec::{query::PacketsByBatchHash, IbcUnion};
+use ibc_union_spec::{path::COMMITMENT_MAGIC, query::PacketsByBatchHash, IbcUnion};
 use jsonrpsee::{core::RpcResult, types::ErrorObject};
 use macros::model;
 use serde_json::json;
 use tracing::{debug, info, instrument, warn};
-use unionlabs::{ibc::core::client::height::Height, primitives::Bytes};
+use unionlabs::{
+    encoding::{EncodeAs, Proto},
+    ethereum::keccak256,
+    ibc::core::client::height::Height,
+    primitives::Bytes,
+};
 use voyager_sdk::{
     message::{
         call::FetchUpdateHeaders,
         data::{EventProvableHeight, IbcDatagram},
         PluginMessage, VoyagerMessage,
     },
-    primitives::{ChainId, QueryHeight},
+    primitives::{ChainId, IbcSpec, IbcStorePathKey, QueryHeight},
     rpc::MISSING_STATE_ERROR_CODE,
-    types::RawClientId,
+    types::{IbcProof, RawClientId},
     vm::{data, now, promise, Op},
     VoyagerClient,
 };
@@ -263,7 +269,11 @@ impl MakeMsg<IbcUnion> {
             msg = IbcUnion::event_name(&self.event)
         )
     )]
-    pub async fn call(self, voyager_client: &VoyagerClient) -> RpcResult<Op<VoyagerMessage>> {
+    pub async fn call(
+        self,
+        module: &Module,
+        voyager_client: &VoyagerClient,
+    ) -> RpcResult<Op<VoyagerMessage>> {
         let MakeMsg {
             origin_chain_id,
             origin_chain_proof_height,
@@ -305,15 +315,18 @@ impl MakeMsg<IbcUnion> {
                 );
 
                 // proof of connection_state, encoded for the client on the target chain
-                let connection_proof = voyager_client
-                    .query_ibc_proof(
-                        origin_chain_id.clone(),
-                        QueryHeight::Specific(origin_chain_proof_height),
-                        ibc_union_spec::path::ConnectionPath { connection_id },
-                    )
-                    .await?
-                    .into_result()?
-                    .proof;
+                let connection_proof = query_proof(
+                    module,
+                    voyager_client,
+                    origin_chain_id.clone(),
+                    origin_chain_proof_height,
+                    ibc_union_spec::path::ConnectionPath { connection_id },
+                    Some(commit_connection(&connection_state)),
+                    target_chain_id.clone(),
+                    counterparty_client_id,
+                )
+                .await?
+                .proof;
                 debug!(%connection_proof, "connection proof");
 
                 let encoded_connection_state_proof = voyager_client
@@ -371,15 +384,18 @@ impl MakeMsg<IbcUnion> {
                 );
 
                 // proof of connection_state, encoded for the client on the target chain
-                let connection_proof = voyager_client
-                    .query_ibc_proof(
-                        origin_chain_id.clone(),
-                        QueryHeight::Specific(origin_chain_proof_height),
-                        ibc_union_spec::path::ConnectionPath { connection_id },
-                    )
-                    .await?
-                    .into_result()?
-                    .proof;
+                let connection_proof = query_proof(
+                    module,
+                    voyager_client,
+                    origin_chain_id.clone(),
+                    origin_chain_proof_height,
+                    ibc_union_spec::path::ConnectionPath { connection_id },
+                    Some(commit_connection(&connection_state)),
+                    target_chain_id.clone(),
+                    counterparty_client_id,
+                )
+                .await?
+                .proof;
                 debug!(%connection_proof, "connection proof");
 
                 let encoded_connection_state_proof = voyager_client
@@ -435,15 +451,18 @@ impl MakeMsg<IbcUnion> {
                 );
 
                 // proof of connection_state, encoded for the client on the target chain
-                let connection_proof = voyager_client
-                    .query_ibc_proof(
-                        origin_chain_id.clone(),
-                        QueryHeight::Specific(origin_chain_proof_height),
-                        ibc_union_spec::path::ConnectionPath { connection_id },
-                    )
-                    .await?
-                    .into_result()?
-                    .proof;
+                let connection_proof = query_proof(
+                    module,
+                    voyager_client,
+                    origin_chain_id.clone(),
+                    origin_chain_proof_height,
+                    ibc_union_spec::path::ConnectionPath { connection_id },
+                    Some(commit_connection(&connection_state)),
+                    target_chain_id.clone(),
+                    counterparty_client_id,
+                )
+                .await?
+                .proof;
                 debug!(%connection_proof, "connection proof");
 
                 let encoded_connection_state_proof = voyager_client
@@ -467,16 +486,29 @@ impl MakeMsg<IbcUnion> {
             }
 
             EventUnion::ChannelOpenInit(event) => {
-                let proof = voyager_client
-                    .query_ibc_proof(
-                        origin_chain_id,
+                let channel = voyager_client
+                    .query_ibc_state(
+                        origin_chain_id.clone(),
                         QueryHeight::Specific(origin_chain_proof_height),
                         ibc_union_spec::path::ChannelPath {
                             channel_id: event.channel_id,
                         },
                     )
-                    .await?
-                    .into_result()?;
+                    .await?;
+
+                let proof = query_proof(
+                    module,
+                    voyager_client,
+                    origin_chain_id,
+                    origin_chain_proof_height,
+                    ibc_union_spec::path::ChannelPath {
+                        channel_id: event.channel_id,
+                    },
+                    Some(commit_channel(&channel)),
+                    target_chain_id.clone(),
+                    event.connection.counterparty_client_id,
+                )
+                .await?;
 
                 let client_info = voyager_client
                     .client_info::<IbcUnion>(
@@ -513,16 +545,29 @@ impl MakeMsg<IbcUnion> {
             }
 
             EventUnion::ChannelOpenTry(event) => {
-                let proof = voyager_client
-                    .query_ibc_proof(
-                        origin_chain_id,
+                let channel = voyager_client
+                    .query_ibc_state(
+                        origin_chain_id.clone(),
                         QueryHeight::Specific(origin_chain_proof_height),
                         ibc_union_spec::path::ChannelPath {
                             channel_id: event.channel_id,
                         },
                     )
-                    .await?
-                    .into_result()?;
+                    .await?;
+
+                let proof = query_proof(
+                    module,
+                    voyager_client,
+                    origin_chain_id,
+                    origin_chain_proof_height,
+                    ibc_union_spec::path::ChannelPath {
+                        channel_id: event.channel_id,
+                    },
+                    Some(commit_channel(&channel)),
+                    target_chain_id.clone(),
+                    event.connection.counterparty_client_id,
+                )
+                .await?;
 
                 let client_info = voyager_client
                     .client_info::<IbcUnion>(
@@ -553,16 +598,29 @@ impl MakeMsg<IbcUnion> {
             }
 
             EventUnion::ChannelOpenAck(event) => {
-                let proof = voyager_client
-                    .query_ibc_proof(
-                        origin_chain_id,
+                let channel = voyager_client
+                    .query_ibc_state(
+                        origin_chain_id.clone(),
                         QueryHeight::Specific(origin_chain_proof_height),
                         ibc_union_spec::path::ChannelPath {
                             channel_id: event.channel_id,
                         },
                     )
-                    .await?
-                    .into_result()?;
+                    .await?;
+
+                let proof = query_proof(
+                    module,
+                    voyager_client,
+                    origin_chain_id,
+                    origin_chain_proof_height,
+                    ibc_union_spec::path::ChannelPath {
+                        channel_id: event.channel_id,
+                    },
+                    Some(commit_channel(&channel)),
+                    target_chain_id.clone(),
+                    event.connection.counterparty_client_id,
+                )
+                .await?;
 
                 let client_info = voyager_client
                     .client_info::<IbcUnion>(
@@ -593,14 +651,17 @@ impl MakeMsg<IbcUnion> {
             EventUnion::PacketSend(event) => {
                 let packet = event.packet();
 
-                let proof = voyager_client
-                    .query_ibc_proof(
-                        origin_chain_id,
-                        QueryHeight::Specific(origin_chain_proof_height),
-                        ibc_union_spec::path::BatchPacketsPath::from_packets(&[packet.clone()]),
-                    )
-                    .await?
-                    .into_result()?;
+                let proof = query_proof(
+                    module,
+                    voyager_client,
+                    origin_chain_id,
+                    origin_chain_proof_height,
+                    ibc_union_spec::path::BatchPacketsPath::from_packets(&[packet.clone()]),
+                    Some(COMMITMENT_MAGIC.into_bytes()),
+                    target_chain_id.clone(),
+                    event.packet.destination_channel.connection.client_id,
+                )
+                .await?;
 
                 let client_info = voyager_client
                     .client_info::<IbcUnion>(
@@ -642,16 +703,19 @@ impl MakeMsg<IbcUnion> {
 
                 packets.sort_by_cached_key(|packet| packet.hash());
 
-                let proof = voyager_client
-                    .query_ibc_proof(
-                        origin_chain_id,
-                        QueryHeight::Specific(origin_chain_proof_height),
-                        ibc_union_spec::path::BatchPacketsPath {
-                            batch_hash: event.batch_hash,
-                        },
-                    )
-                    .await?
-                    .into_result()?;
+                let proof = query_proof(
+                    module,
+                    voyager_client,
+                    origin_chain_id,
+                    origin_chain_proof_height,
+                    ibc_union_spec::path::BatchPacketsPath {
+                        batch_hash: event.batch_hash,
+                    },
+                    Some(COMMITMENT_MAGIC.into_bytes()),
+                    target_chain_id.clone(),
+                    event.destination_channel.connection.client_id,
+                )
+                .await?;
 
                 let client_info = voyager_client
                     .client_info::<IbcUnion>(
@@ -683,14 +747,17 @@ impl MakeMsg<IbcUnion> {
             EventUnion::WriteAck(event) => {
                 let packet = event.packet();
 
-                let proof = voyager_client
-                    .query_ibc_proof(
-                        origin_chain_id,
-                        QueryHeight::Specific(origin_chain_proof_height),
-                        ibc_union_spec::path::BatchReceiptsPath::from_packets(&[packet.clone()]),
-                    )
-                    .await?
-                    .into_result()?;
+                let proof = query_proof(
+                    module,
+                    voyager_client,
+                    origin_chain_id,
+                    origin_chain_proof_height,
+                    ibc_union_spec::path::BatchReceiptsPath::from_packets(&[packet.clone()]),
+                    Some(commit_acks(&[event.acknowledgement.clone()])),
+                    target_chain_id.clone(),
+                    event.packet.source_channel.connection.client_id,
+                )
+                .await?;
 
                 let client_info = voyager_client
                     .client_info::<IbcUnion>(
@@ -723,7 +790,11 @@ impl MakeMsg<IbcUnion> {
 }
 
 impl MakeMsg<IbcClassic> {
-    pub async fn call(self, voyager_client: &VoyagerClient) -> RpcResult<Op<VoyagerMessage>> {
+    pub async fn call(
+        self,
+        module: &Module,
+        voyager_client: &VoyagerClient,
+    ) -> RpcResult<Op<VoyagerMessage>> {
         let MakeMsg {
             origin_chain_id,
             origin_chain_proof_height,
@@ -737,6 +808,7 @@ impl MakeMsg<IbcClassic> {
                     connection_state,
                     encoded_connection_state_proof,
                 } = mk_connection_handshake_state_and_proofs(
+                    module,
                     voyager_client,
                     origin_chain_id,
                     target_chain_id,
@@ -1019,6 +1091,7 @@ impl MakeMsg<IbcClassic> {
 
 /// Used to fetch and construct the state and proofs for
 /// MsgConnectionOpenTry/Ack.
+#[allow(clippy::too_many_arguments)]
 #[instrument(
     skip_all,
     fields(
@@ -1031,6 +1104,7 @@ impl MakeMsg<IbcClassic> {
     )
 )]
 async fn mk_connection_handshake_state_and_proofs(
+    module: &Module,
     voyager_client: &VoyagerClient,
     origin_chain_id: ChainId,
     target_chain_id: ChainId,
@@ -1082,17 +1156,20 @@ async fn mk_connection_handshake_state_and_proofs(
     );
 
     // proof of connection_state, encoded for the client on the target chain
-    let connection_proof = voyager_client
-        .query_ibc_proof(
-            origin_chain_id.clone(),
-            QueryHeight::Specific(origin_chain_proof_height),
-            ibc_classic_spec::ConnectionPath {
-                connection_id: connection_id.clone(),
-            },
-        )
-        .await?
-        .into_result()?
-        .proof;
+    let connection_proof = query_proof(
+        module,
+        voyager_client,
+        origin_chain_id.clone(),
+        origin_chain_proof_height,
+        ibc_classic_spec::ConnectionPath {
+            connection_id: connection_id.clone(),
+        },
+        Some(connection_state.clone().encode_as::<Proto>().into()),
+        target_chain_id.clone(),
+        counterparty_client_id.clone(),
+    )
+    .await?
+    .proof;
     debug!(%connection_proof);
 
     let encoded_connection_state_proof = voyager_client
@@ -1110,6 +1187,61 @@ async fn mk_connection_handshake_state_and_proofs(
     })
 }
 
+/// Query a proof of `path` on the origin chain. If [`Module::verify_proofs`] is enabled, the proof
+/// is verified against the consensus state of `client_id` on the target chain before it is used,
+/// and must prove `value` (the raw value committed to at `path`, or its absence if `None`).
+#[allow(clippy::too_many_arguments)]
+async fn query_proof<P: IbcStorePathKey>(
+    module: &Module,
+    voyager_client: &VoyagerClient,
+    origin_chain_id: ChainId,
+    origin_chain_proof_height: Height,
+    path: P,
+    value: Option<Bytes>,
+    target_chain_id: ChainId,
+    client_id: <P::Spec as IbcSpec>::ClientId,
+) -> RpcResult<IbcProof> {
+    if module.verify_proofs {
+        voyager_client
+            .query_verified_ibc_proof(
+                origin_chain_id,
+                origin_chain_proof_height,
+                path,
+                value,
+                target_chain_id,
+                client_id,
+                module.proof_retries,
+            )
+            .await
+    } else {
+        voyager_client
+            .query_ibc_proof(
+                origin_chain_id,
+                QueryHeight::Specific(origin_chain_proof_height),
+                path,
+            )
+            .await?
+            .into_result()
+    }
+}
+
+/// The commitment of a connection in the ibc-union core contracts.
+fn commit_connection(connection: &ibc_union_spec::Connection) -> Bytes {
+    keccak256(connection.abi_encode_params()).into_bytes()
+}
+
+/// The commitment of a channel in the ibc-union core contracts.
+fn commit_channel(channel: &ibc_union_spec::Channel) -> Bytes {
+    keccak256(channel.abi_encode()).into_bytes()
+}
+
+/// The commitment of the acknowledgements of a batch of packets in the ibc-union core contracts.
+fn commit_acks(acks: &[Bytes]) -> Bytes {
+    let mut commitment = keccak256(acks.abi_encode());
+    commitment.get_mut()[0] = 0x01;
+    commitment.into_bytes()
+}
+
 struct ConnectionHandshakeStateAndProof {
     connection_state: unionlabs::ibc::core::connection::connection_end::ConnectionEnd,
     encoded_connection_state_proof: Bytes,
@@ -49,6 +49,8 @@ pub struct Module {
     // The destination chain (i.e. where the messages will be sent to)
     pub chain_id: ChainId,
     pub client_configs: ClientConfigs,
+    pub verify_proofs: bool,
+    pub proof_retries: u32,
 }
 
 #[derive(Debug, Clone)]
@@ -62,6 +64,18 @@ pub enum ClientConfigs {
 pub struct Config {
     pub chain_id: ChainId,
     pub client_configs: ClientConfigsSerde,
+    /// Verify proofs locally against the consensus state of the client on this chain before
+    /// building messages with them. Defaults to `false`.
+    #[serde(default)]
+    pub verify_proofs: bool,
+    /// How many times a proof that fails local verification is refetched before the message is
+    /// requeued. Only used if `verify_proofs` is enabled.
+    #[serde(default = "default_proof_retries")]
+    pub proof_retries: u32,
+}
+
+fn default_proof_retries() -> u32 {
+    3
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -318,6 +332,8 @@ impl Module {
         Self {
             chain_id: config.chain_id,
             client_configs: ClientConfigs::new(config.client_configs),
+            verify_proofs: config.verify_proofs,
+            proof_retries: config.proof_retries,
         }
     }
 }
@@ -344,8 +360,8 @@ impl PluginServer<ModuleCall, ModuleCallback> for Module {
             ModuleCall::MakeTransactionBatchesWithUpdateUnion(mk) => {
                 mk.call(self, e.voyager_client()?).await
             }
-            ModuleCall::MakeMsgClassic(mk) => mk.call(voyager_client).await,
-            ModuleCall::MakeMsgUnion(mk) => mk.call(voyager_client).await,
+            ModuleCall::MakeMsgClassic(mk) => mk.call(self, voyager_client).await,
+            ModuleCall::MakeMsgUnion(mk) => mk.call(self, voyager_client).await,
         }
     }
 
@@ -10,6 +10,7 @@ use concurrent_keyring::{ConcurrentKeyring, KeyringConfig, KeyringEntry};
 use ibc_union_spec::{datagram::Datagram, ChannelId, IbcUnion};
 use jsonrpsee::{
     core::{async_trait, RpcResult},
+    types::ErrorObject,
     Extensions,
 };
 use move_core_types::{
@@ -26,7 +27,7 @@ use voyager_sdk::{
     message::{data::Data, PluginMessage, VoyagerMessage},
     plugin::Plugin,
     primitives::ChainId,
-    rpc::{types::PluginInfo, PluginServer},
+    rpc::{types::PluginInfo, PluginServer, FATAL_JSONRPC_ERROR_CODE},
     vm::{self, call, noop, pass::PassResult, Op, Visit},
     DefaultCmd,
 };
@@ -218,7 +219,7 @@ impl PluginServer<ModuleCall, Never> for Module {
                             msgs.clone(),
                             sender,
                         )
-                        .await;
+                        .await?;
 
                         let mut txs = vec![];
 
@@ -288,13 +289,14 @@ async fn process_msgs<
     T: aptos_move_ibc::ibc::ClientExt
         + aptos_move_ibc::recv_packet::ClientExt
         + aptos_move_ibc::acknowledge_packet::ClientExt
+        + aptos_move_ibc::timeout_packet::ClientExt
         + aptos_move_ibc::channel_handshake::ClientExt,
 >(
     ibc_handler_address: AccountAddress,
     client: &T,
     msgs: Vec<Datagram>,
     relayer: AccountAddress,
-) -> Vec<(Datagram, EntryFunction)> {
+) -> RpcResult<Vec<(Datagram, EntryFunction)>> {
     let mut data = vec![];
     for msg in msgs {
         let item = match msg.clone() {
@@ -529,10 +531,53 @@ async fn process_msgs<
                 )
             }
 
-            _ => todo!(),
+            Datagram::PacketTimeout(data) => {
+                let port_id = client
+                    .get_module(
+                        ibc_handler_address,
+                        None,
+                        (data.packet.source_channel_id.raw(),),
+                    )
+                    .await
+                    .unwrap();
+
+                (
+                    msg,
+                    client.timeout_packet(
+                        ibc_handler_address,
+                        (
+                            port_id.into(),
+                            data.packet.source_channel_id.raw(),
+                            data.packet.destination_channel_id.raw(),
+                            data.packet.data.into_vec(),
+                            0,
+                            data.packet.timeout_timestamp.as_nanos(),
+                            data.proof.into_vec(),
+                            data.proof_height,
+                            // next_sequence_recv is unused by the ibc module
+                            0,
+                        ),
+                        (ibc_app_witness(port_id.into()),),
+                    ),
+                )
+            }
+
+            Datagram::RecoverClient(_)
+            | Datagram::UpgradeClient(_)
+            | Datagram::ChannelCloseInit(_)
+            | Datagram::ChannelCloseConfirm(_)
+            | Datagram::IntentPacketRecv(_)
+            | Datagram::BatchSend(_)
+            | Datagram::BatchAcks(_) => {
+                return Err(ErrorObject::owned(
+                    FATAL_JSONRPC_ERROR_CODE,
+                    format!("{} is not supported by the aptos ibc module", msg.name()),
+                    None::<()>,
+                ))
+            }
         };
         data.push(item);
     }
 
-    data
+    Ok(data)
 }
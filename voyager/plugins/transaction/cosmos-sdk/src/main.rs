@@ -14,7 +14,7 @@ use concurrent_keyring::{ConcurrentKeyring, KeyringConfig, KeyringEntry};
 use cosmos_client::{
     gas::{any, feemarket, fixed, osmosis_eip1559_feemarket, GasFillerT},
     rpc::{Rpc, RpcT},
-    wallet::{LocalSigner, WalletT},
+    wallet::{AnySigner, KeyType, WalletT},
     BroadcastTxCommitError, TxClient,
 };
 use ibc_union::ContractErrorKind;
@@ -65,13 +65,15 @@ pub struct Module(Arc<ModuleInner>);
 pub struct ModuleInner {
     pub chain_id: ChainId,
     pub ibc_host_contract_address: Bech32<H256>,
-    pub keyring: ConcurrentKeyring<Bech32<H160>, LocalSigner>,
+    pub keyring: ConcurrentKeyring<Bech32<H160>, AnySigner>,
     pub rpc: Rpc,
     pub gas_config: any::GasFiller,
     pub bech32_prefix: String,
     pub fatal_errors: HashMap<(String, NonZeroU32), Option<String>>,
     pub gas_station_config: Vec<Coin>,
     pub fee_recipient: Option<Bech32<Bytes>>,
+    pub fee_granter: Option<Bech32<Bytes>>,
+    pub authz_granter: Option<Bech32<Bytes>>,
     pub max_tx_size: u32,
 }
 
@@ -89,6 +91,9 @@ pub struct Config {
     pub chain_id: ChainId,
     pub ibc_host_contract_address: Bech32<H256>,
     pub keyring: KeyringConfig,
+    /// The type of the keys in the keyring. Set this to `eth_secp256k1` for ethermint-based chains.
+    #[serde(default)]
+    pub key_type: KeyType,
     pub rpc_url: String,
     pub gas_config: GasFillerConfig,
     /// A list of (codespace, code) tuples that are to be considered non-recoverable.
@@ -98,6 +103,14 @@ pub struct Config {
     pub gas_station_config: Vec<Coin>,
     #[serde(default)]
     pub fee_recipient: Option<Bech32<Bytes>>,
+    /// Pay the fees of all transactions with the fee grants given to the keys in the keyring by this
+    /// account.
+    #[serde(default)]
+    pub fee_granter: Option<Bech32<Bytes>>,
+    /// Execute all messages on behalf of this account through authz `MsgExec`, with the keys in the
+    /// keyring as the grantees.
+    #[serde(default)]
+    pub authz_granter: Option<Bech32<Bytes>>,
     pub max_tx_size: u32,
 }
 
@@ -208,20 +221,24 @@ impl Plugin for Module {
             .unwrap()
             .bech32_prefix;
 
+        let keys = config
+            .keyring
+            .keys
+            .into_iter()
+            .map(|entry| {
+                let signer =
+                    AnySigner::new(entry.key()?, config.key_type.clone(), bech32_prefix.clone())?;
+
+                anyhow::Ok(KeyringEntry {
+                    address: signer.address(),
+                    signer,
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
         Ok(Self(Arc::new(ModuleInner {
             ibc_host_contract_address: config.ibc_host_contract_address,
-            keyring: ConcurrentKeyring::new(
-                config.keyring.name,
-                config.keyring.keys.into_iter().map(|entry| {
-                    let signer =
-                        LocalSigner::new(entry.value().try_into().unwrap(), bech32_prefix.clone());
-
-                    KeyringEntry {
-                        address: signer.address(),
-                        signer,
-                    }
-                }),
-            ),
+            keyring: ConcurrentKeyring::new(config.keyring.name, keys.into_iter()),
             rpc,
             chain_id: ChainId::new(chain_id),
             gas_config: config
@@ -241,6 +258,8 @@ impl Plugin for Module {
                 .collect(),
             gas_station_config: config.gas_station_config,
             fee_recipient: config.fee_recipient,
+            fee_granter: config.fee_granter,
+            authz_granter: config.authz_granter,
             max_tx_size: config.max_tx_size,
         })))
     }
@@ -346,9 +365,15 @@ impl Module {
                 let memo = format!("Voyager {}", env!("CARGO_PKG_VERSION"));
 
                 let ibc_host_contract_address = self.ibc_host_contract_address.clone();
+                // messages executed through authz must be signed by the granter
+                let sender = self.authz_granter.as_ref().map_or_else(
+                    || signer.address().to_string(),
+                    |granter| granter.to_string(),
+                );
+
                 let msgs = process_msgs(
                     msgs,
-                    signer,
+                    &sender,
                     ibc_host_contract_address,
                     self.gas_station_config.clone(),
                     self.fee_recipient.as_ref(),
@@ -365,7 +390,9 @@ impl Module {
                     })
                     .collect::<Vec<_>>();
 
-                let tx_client = TxClient::new(signer, &self.rpc, &self.gas_config);
+                let tx_client = TxClient::new(signer, &self.rpc, &self.gas_config)
+                    .with_fee_granter(self.fee_granter.clone())
+                    .with_authz_granter(self.authz_granter.clone());
 
                 let batch_size = msgs.len();
                 let msg_names = msgs.iter().map(|x| x.0.name()).collect::<Vec<_>>();
@@ -729,14 +756,14 @@ impl PluginServer<ModuleCall, Never> for Module {
 
 fn process_msgs(
     msgs: Vec<IbcMessage>,
-    signer: &LocalSigner,
+    signer: &str,
     ibc_host_contract_address: Bech32<H256>,
     gas_station_config: Vec<Coin>,
     fee_recipient: Option<&Bech32<Bytes>>,
 ) -> Vec<RpcResult<(IbcMessage, protos::google::protobuf::Any)>> {
     msgs.into_iter()
         .map(|msg| {
-            let signer = signer.address().to_string();
+            let signer = signer.to_owned();
 
             let encoded = match msg.clone() {
                 IbcMessage::IbcV1(msg) => match msg {
@@ -918,6 +945,44 @@ fn process_msgs(
                             funds: vec![],
                         })
                     }
+                    ibc_union_spec::datagram::Datagram::RecoverClient(msg_recover_client) => {
+                        mk_any(&protos::cosmwasm::wasm::v1::MsgExecuteContract {
+                            sender: signer.to_string(),
+                            contract: ibc_host_contract_address.to_string(),
+                            msg: serde_json::to_vec(
+                                &ibc_union_msg::msg::ExecuteMsg::RecoverClient(
+                                    ibc_union_msg::msg::MsgRecoverClient {
+                                        subject_client_id: msg_recover_client.subject_client_id,
+                                        substitute_client_id: msg_recover_client
+                                            .substitute_client_id,
+                                    },
+                                ),
+                            )
+                            .unwrap(),
+                            funds: vec![],
+                        })
+                    }
+                    ibc_union_spec::datagram::Datagram::UpgradeClient(msg_upgrade_client) => {
+                        mk_any(&protos::cosmwasm::wasm::v1::MsgExecuteContract {
+                            sender: signer.to_string(),
+                            contract: ibc_host_contract_address.to_string(),
+                            msg: serde_json::to_vec(
+                                &ibc_union_msg::msg::ExecuteMsg::UpgradeClient(
+                                    ibc_union_msg::msg::MsgUpgradeClient {
+                                        client_id: msg_upgrade_client.client_id,
+                                        new_client_state: msg_upgrade_client.new_client_state,
+                                        new_consensus_state: msg_upgrade_client.new_consensus_state,
+                                        proof_upgrade_client: msg_upgrade_client
+                                            .proof_upgrade_client,
+                                        proof_upgrade_consensus_state: msg_upgrade_client
+                                            .proof_upgrade_consensus_state,
+                                    },
+                                ),
+                            )
+                            .unwrap(),
+                            funds: vec![],
+                        })
+                    }
                     ibc_union_spec::datagram::Datagram::ConnectionOpenInit(
                         msg_connection_open_init,
                     ) => mk_any(&protos::cosmwasm::wasm::v1::MsgExecuteContract {
@@ -1187,10 +1252,23 @@ fn parse_wasm_failure(log: &str) -> Option<ContractErrorKind> {
     log.split(' ').find_map(ContractErrorKind::parse)
 }
 
+// messages executed through authz are wrapped in a single `MsgExec`, which wraps the error of the
+// failed inner message the same way baseapp does:
+//
+// failed to execute message; message index: 0: failed to execute message; message index: 2: ...
+//
+// the innermost index is the index of the failed message in the batch.
 fn parse_msg_idx_from_log(log: &str) -> Option<(usize, &str)> {
-    let (_, log) = log.split_once("message index: ")?;
-    let (idx, log) = log.split_once(':')?;
-    Some((idx.parse().ok()?, log))
+    let (_, mut log) = log.split_once("message index: ")?;
+    loop {
+        let (idx, rest) = log.split_once(':')?;
+        let idx = idx.parse().ok()?;
+
+        match rest.split_once("message index: ") {
+            Some((_, inner)) => log = inner,
+            None => return Some((idx, rest)),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -1199,6 +1277,17 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_parse_msg_idx_from_authz_log() {
+        let (idx, log) = parse_msg_idx_from_log("failed to execute message; message index: 0: failed to execute message; message index: 2: IBC_UNION_ERR_PACKET_COMMITMENT_NOT_FOUND packet commitment not found: execute wasm contract failed [CosmWasm/wasmd@v0.53.2/x/wasm/keeper/keeper.go:436] with gas used: '287090'").unwrap();
+
+        assert_eq!(idx, 2);
+        assert_eq!(
+            parse_wasm_failure(log),
+            Some(ContractErrorKind::PacketCommitmentNotFound)
+        );
+    }
+
     #[test]
     fn test_parse_wasm_failure() {
         let (idx, log) = parse_msg_idx_from_log("rpc error: code = Unknown desc = failed to execute message; message index: 0: IBC_UNION_ERR_PACKET_COMMITMENT_NOT_FOUND packet commitment not found: execute wasm contract failed [CosmWasm/wasmd@v0.53.2/x/wasm/keeper/keeper.go:436] with gas used: '287090'").unwrap();
@@ -1249,6 +1338,7 @@ mod tests {
                         key: vec![0; 32],
                     }]
                 },
+                key_type: KeyType::Secp256k1,
                 rpc_url: "rpc_url".to_string(),
                 gas_config: GasFillerConfig::Feemarket(FeemarketConfig {
                     max_gas: 123456789,
@@ -1258,6 +1348,8 @@ mod tests {
                 fatal_errors: HashMap::default(),
                 gas_station_config: vec![],
                 fee_recipient: None,
+                fee_granter: None,
+                authz_granter: None,
                 max_tx_size: 1000000
             }
         );
@@ -13,11 +13,9 @@ use alloy::{
         fillers::RecommendedFillers, layers::CacheLayer, DynProvider, PendingTransactionError,
         Provider, ProviderBuilder,
     },
-    signers::local::LocalSigner,
     sol_types::{SolEvent, SolInterface},
     transports::TransportError,
 };
-use bip32::secp256k1::ecdsa::{self, SigningKey};
 use clap::Subcommand;
 use concurrent_keyring::{ConcurrentKeyring, KeyringConfig, KeyringEntry};
 use ibc_solidity::Ibc::{self, IbcErrors};
@@ -50,9 +48,11 @@ use voyager_sdk::{
 use crate::{
     call::ModuleCall,
     multicall::{Call3, Multicall, MulticallResult},
+    signer::EvmSigner,
 };
 
 pub mod call;
+pub mod signer;
 
 #[tokio::main]
 async fn main() {
@@ -81,7 +81,7 @@ pub struct ModuleInner {
 
     pub provider: DynProvider<AnyNetwork>,
 
-    pub keyring: ConcurrentKeyring<alloy::primitives::Address, LocalSigner<SigningKey>>,
+    pub keyring: ConcurrentKeyring<alloy::primitives::Address, EvmSigner>,
 
     pub max_gas_price: Option<u128>,
 
@@ -163,28 +163,27 @@ impl Plugin for Module {
             );
         }
 
+        let keys = config
+            .keyring
+            .keys
+            .into_iter()
+            .map(|entry| {
+                let signer = EvmSigner::new(entry.key()?)?;
+
+                anyhow::Ok(KeyringEntry {
+                    address: signer.address(),
+                    signer,
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
         Ok(Self(Arc::new(ModuleInner {
             chain_id,
             additional_chain_ids: config.additional_chain_ids,
             ibc_handler_address: config.ibc_handler_address,
             multicall_address: config.multicall_address,
             provider,
-            keyring: ConcurrentKeyring::new(
-                config.keyring.name,
-                config.keyring.keys.into_iter().map(|config| {
-                    let signing_key = <ecdsa::SigningKey as bip32::PrivateKey>::from_bytes(
-                        &config.value().as_slice().try_into().unwrap(),
-                    )
-                    .unwrap();
-
-                    let signer = LocalSigner::from_signing_key(signing_key);
-
-                    KeyringEntry {
-                        address: signer.address(),
-                        signer,
-                    }
-                }),
-            ),
+            keyring: ConcurrentKeyring::new(config.keyring.name, keys.into_iter()),
             max_gas_price: config.max_gas_price,
             fixed_gas_price: config.fixed_gas_price,
             legacy: config.legacy,
@@ -429,7 +428,7 @@ impl PluginServer<ModuleCall, Never> for Module {
 impl Module {
     async fn submit_transaction(
         &self,
-        wallet: &LocalSigner<SigningKey>,
+        wallet: &EvmSigner,
         ibc_messages: Vec<Datagram>,
     ) -> Result<(), TxSubmitError> {
         let signer = DynProvider::new(
@@ -801,7 +800,13 @@ fn process_msgs<'a>(
                         })
                         .clear_decoder(),
                 ),
-                _ => todo!(),
+                _ => {
+                    return Err(ErrorObject::owned(
+                        FATAL_JSONRPC_ERROR_CODE,
+                        format!("unsupported datagram: {}", msg.name()),
+                        None::<()>,
+                    ))
+                }
             })
         })
         .collect()
@@ -0,0 +1,260 @@
+use alloy::{
+    consensus::SignableTransaction,
+    network::TxSigner,
+    primitives::{Address, Signature},
+    signers::{local::LocalSigner, utils::public_key_to_address, Error},
+    transports::http::reqwest,
+};
+use bip32::secp256k1::ecdsa::{SigningKey, VerifyingKey};
+use concurrent_keyring::Key;
+use jsonrpsee::core::async_trait;
+use serde_json::json;
+use unionlabs::primitives::Bytes;
+
+/// Either a local private key or a key held by a remote [`Web3Signer`].
+#[derive(Debug, Clone)]
+pub enum EvmSigner {
+    Local(LocalSigner<SigningKey>),
+    Remote(Web3Signer),
+}
+
+impl EvmSigner {
+    pub fn new(key: Key) -> Result<Self, bip32::secp256k1::ecdsa::Error> {
+        Ok(match key {
+            Key::Local(private_key) => Self::Local(LocalSigner::from_signing_key(
+                SigningKey::from_slice(&private_key)?,
+            )),
+            Key::Remote { url, public_key } => Self::Remote(Web3Signer::new(
+                url,
+                VerifyingKey::from_sec1_bytes(&public_key)?,
+            )),
+        })
+    }
+
+    pub fn address(&self) -> Address {
+        match self {
+            Self::Local(signer) => signer.address(),
+            Self::Remote(signer) => signer.address,
+        }
+    }
+}
+
+#[async_trait]
+impl TxSigner<Signature> for EvmSigner {
+    fn address(&self) -> Address {
+        self.address()
+    }
+
+    async fn sign_transaction(
+        &self,
+        tx: &mut dyn SignableTransaction<Signature>,
+    ) -> alloy::signers::Result<Signature> {
+        match self {
+            Self::Local(signer) => TxSigner::sign_transaction(signer, tx).await,
+            Self::Remote(signer) => TxSigner::sign_transaction(signer, tx).await,
+        }
+    }
+}
+
+/// A signer backed by the [eth1 signing endpoint] of a [Web3Signer] instance.
+///
+/// Web3Signer hashes the signing payload of the transaction with keccak256 and signs the hash with
+/// the key identified by the public key. The returned signature is checked against the expected
+/// address before it is used.
+///
+/// [eth1 signing endpoint]: https://consensys.github.io/web3signer/web3signer-eth1.html#tag/Signing
+/// [Web3Signer]: https://docs.web3signer.consensys.io
+#[derive(Debug, Clone)]
+pub struct Web3Signer {
+    client: reqwest::Client,
+    url: String,
+    /// The uncompressed public key, without the leading `0x04` tag.
+    public_key: [u8; 64],
+    address: Address,
+}
+
+impl Web3Signer {
+    pub fn new(url: impl Into<String>, public_key: VerifyingKey) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            url: url.into().trim_end_matches('/').to_owned(),
+            public_key: public_key.to_encoded_point(false).as_bytes()[1..]
+                .try_into()
+                .expect("uncompressed public key is 65 bytes; qed;"),
+            address: public_key_to_address(&public_key),
+        }
+    }
+}
+
+#[async_trait]
+impl TxSigner<Signature> for Web3Signer {
+    fn address(&self) -> Address {
+        self.address
+    }
+
+    async fn sign_transaction(
+        &self,
+        tx: &mut dyn SignableTransaction<Signature>,
+    ) -> alloy::signers::Result<Signature> {
+        let signature = self
+            .client
+            .post(format!(
+                "{}/api/v1/eth1/sign/{}",
+                self.url,
+                <Bytes>::from(self.public_key)
+            ))
+            .json(&json!({
+                "data": <Bytes>::from(tx.encoded_for_signing()),
+            }))
+            .send()
+            .await
+            .and_then(|res| res.error_for_status())
+            .map_err(Error::other)?
+            .text()
+            .await
+            .map_err(Error::other)?
+            .trim()
+            .parse::<Signature>()?;
+
+        let recovered = signature.recover_address_from_prehash(&tx.signature_hash())?;
+
+        if recovered != self.address {
+            return Err(Error::message(format!(
+                "web3signer returned a signature for {recovered}, expected {}",
+                self.address
+            )));
+        }
+
+        Ok(signature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::{
+        consensus::TxLegacy,
+        primitives::{keccak256, TxKind, U256},
+        signers::SignerSync,
+    };
+    use serde_json::Value;
+    use tokio::{
+        io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader},
+        net::TcpListener,
+    };
+
+    use super::*;
+
+    /// Serve web3signer eth1 signing requests for `public_key`, signing with `signer`. Returns the
+    /// url of the server.
+    async fn serve(public_key: VerifyingKey, signer: LocalSigner<SigningKey>) -> String {
+        let path = format!(
+            "/api/v1/eth1/sign/{}",
+            <Bytes>::from(public_key.to_encoded_point(false).as_bytes()[1..].to_vec())
+        );
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            loop {
+                let (stream, _) = listener.accept().await.unwrap();
+                let mut stream = BufReader::new(stream);
+
+                loop {
+                    let mut request_line = String::new();
+                    if stream.read_line(&mut request_line).await.unwrap() == 0 {
+                        break;
+                    }
+                    assert!(
+                        request_line.starts_with(&format!("POST {path} ")),
+                        "{request_line}"
+                    );
+
+                    let mut content_length = 0;
+                    loop {
+                        let mut line = String::new();
+                        stream.read_line(&mut line).await.unwrap();
+                        match line.trim_end().split_once(':') {
+                            Some((k, v)) if k.eq_ignore_ascii_case("content-length") => {
+                                content_length = v.trim().parse().unwrap();
+                            }
+                            None if line.trim_end().is_empty() => break,
+                            _ => {}
+                        }
+                    }
+
+                    let mut body = vec![0; content_length];
+                    stream.read_exact(&mut body).await.unwrap();
+                    let request = serde_json::from_slice::<Value>(&body).unwrap();
+
+                    let data = serde_json::from_value::<Bytes>(request["data"].clone()).unwrap();
+
+                    let body = <Bytes>::from(
+                        signer
+                            .sign_hash_sync(&keccak256(&data))
+                            .unwrap()
+                            .as_bytes()
+                            .to_vec(),
+                    )
+                    .to_string();
+
+                    stream
+                        .get_mut()
+                        .write_all(
+                            format!(
+                                "HTTP/1.1 200 OK\r\ncontent-type: text/plain\r\ncontent-length: {}\r\n\r\n{body}",
+                                body.len()
+                            )
+                            .as_bytes(),
+                        )
+                        .await
+                        .unwrap();
+                }
+            }
+        });
+
+        format!("http://{addr}")
+    }
+
+    fn local_signer(byte: u8) -> LocalSigner<SigningKey> {
+        LocalSigner::from_signing_key(SigningKey::from_slice(&[byte; 32]).unwrap())
+    }
+
+    fn tx() -> TxLegacy {
+        TxLegacy {
+            chain_id: Some(1),
+            nonce: 1,
+            gas_price: 1_000_000_000,
+            gas_limit: 21_000,
+            to: TxKind::Call(Address::repeat_byte(0xAA)),
+            value: U256::from(1),
+            input: Default::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn web3signer() {
+        let local = local_signer(1);
+
+        let public_key = *local.credential().verifying_key();
+
+        let web3signer = Web3Signer::new(serve(public_key, local_signer(1)).await, public_key);
+
+        assert_eq!(TxSigner::address(&web3signer), TxSigner::address(&local));
+        assert_eq!(
+            web3signer.sign_transaction(&mut tx()).await.unwrap(),
+            local.sign_transaction(&mut tx()).await.unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn web3signer_wrong_key() {
+        let local = local_signer(1);
+
+        let public_key = *local.credential().verifying_key();
+
+        let web3signer = Web3Signer::new(serve(public_key, local_signer(2)).await, public_key);
+
+        assert!(web3signer.sign_transaction(&mut tx()).await.is_err());
+    }
+}
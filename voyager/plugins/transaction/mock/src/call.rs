@@ -0,0 +1,17 @@
+use enumorph::Enumorph;
+use macros::model;
+use unionlabs::primitives::H256;
+
+#[model]
+#[derive(Enumorph)]
+pub enum ModuleCall {
+    SubmitTransaction(Vec<ibc_union_spec::datagram::Datagram>),
+    WaitForTransaction(WaitForTransaction),
+}
+
+/// Wait for the transaction with the given hash to be included in a block, requeueing itself
+/// while the transaction is still pending.
+#[model]
+pub struct WaitForTransaction {
+    pub tx_hash: H256,
+}
@@ -0,0 +1,194 @@
+use std::collections::VecDeque;
+
+use ibc_union_spec::IbcUnion;
+use jsonrpsee::{
+    core::{async_trait, RpcResult},
+    http_client::{HttpClient, HttpClientBuilder},
+    types::ErrorObject,
+    Extensions,
+};
+use mock_chain::{rpc::MockChainRpcClient, TxResult};
+use serde::{Deserialize, Serialize};
+use tracing::{info, instrument, warn};
+use unionlabs::{never::Never, ErrorReporter};
+use voyager_sdk::{
+    anyhow::{self, bail},
+    hook::SubmitTxHook,
+    message::{data::Data, PluginMessage, VoyagerMessage},
+    plugin::Plugin,
+    primitives::ChainId,
+    rpc::{types::PluginInfo, PluginServer, FATAL_JSONRPC_ERROR_CODE},
+    vm::{call, defer, noop, now, pass::PassResult, seq, Op, Visit},
+    DefaultCmd,
+};
+
+use crate::call::{ModuleCall, WaitForTransaction};
+
+pub mod call;
+
+#[derive(Debug, Clone)]
+pub struct Module {
+    pub chain_id: ChainId,
+
+    pub client: HttpClient,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    pub chain_id: ChainId,
+
+    /// The RPC endpoint of the mock chain.
+    pub rpc_url: String,
+}
+
+fn plugin_name(chain_id: &ChainId) -> String {
+    pub const PLUGIN_NAME: &str = env!("CARGO_PKG_NAME");
+
+    format!("{PLUGIN_NAME}/{}", chain_id)
+}
+
+fn rpc_error(e: jsonrpsee::core::ClientError) -> ErrorObject<'static> {
+    ErrorObject::owned(
+        -1,
+        ErrorReporter(e).with_message("mock chain rpc error"),
+        None::<()>,
+    )
+}
+
+impl Module {
+    fn plugin_name(&self) -> String {
+        plugin_name(&self.chain_id)
+    }
+}
+
+impl Plugin for Module {
+    type Call = ModuleCall;
+    type Callback = Never;
+
+    type Config = Config;
+    type Cmd = DefaultCmd;
+
+    async fn new(config: Self::Config) -> anyhow::Result<Self> {
+        let client = HttpClientBuilder::default().build(&config.rpc_url)?;
+
+        let chain_id = client.chain_id().await?;
+
+        if chain_id != config.chain_id {
+            bail!(
+                "incorrect chain id: expected `{}`, but found `{}`",
+                config.chain_id,
+                chain_id
+            );
+        }
+
+        Ok(Self { chain_id, client })
+    }
+
+    fn info(config: Self::Config) -> PluginInfo {
+        PluginInfo {
+            name: plugin_name(&config.chain_id),
+            interest_filter: SubmitTxHook::filter(&config.chain_id),
+        }
+    }
+
+    async fn cmd(_config: Self::Config, cmd: Self::Cmd) {
+        match cmd {}
+    }
+}
+
+#[async_trait]
+impl PluginServer<ModuleCall, Never> for Module {
+    #[instrument(skip_all, fields(chain_id = %self.chain_id))]
+    async fn run_pass(
+        &self,
+        _: &Extensions,
+        msgs: Vec<Op<VoyagerMessage>>,
+    ) -> RpcResult<PassResult<VoyagerMessage>> {
+        Ok(PassResult {
+            optimize_further: vec![],
+            ready: msgs
+                .into_iter()
+                .enumerate()
+                .map(|(idx, mut op)| {
+                    SubmitTxHook::new(&self.chain_id, |submit_tx| {
+                        PluginMessage::new(
+                            self.plugin_name(),
+                            ModuleCall::SubmitTransaction(
+                                submit_tx
+                                    .datagrams
+                                    .iter()
+                                    .map(|message| {
+                                        message.decode_datagram::<IbcUnion>().unwrap().unwrap()
+                                    })
+                                    .collect(),
+                            ),
+                        )
+                        .into()
+                    })
+                    .visit_op(&mut op);
+
+                    (vec![idx], op)
+                })
+                .collect(),
+        })
+    }
+
+    #[instrument(skip_all, fields(chain_id = %self.chain_id))]
+    async fn call(&self, _: &Extensions, msg: ModuleCall) -> RpcResult<Op<VoyagerMessage>> {
+        match msg {
+            ModuleCall::SubmitTransaction(msgs) => {
+                let tx_hash = self.client.submit(msgs).await.map_err(rpc_error)?;
+
+                info!(%tx_hash, "submitted transaction");
+
+                Ok(call(PluginMessage::new(
+                    self.plugin_name(),
+                    ModuleCall::from(WaitForTransaction { tx_hash }),
+                )))
+            }
+            ModuleCall::WaitForTransaction(WaitForTransaction { tx_hash }) => {
+                match self.client.tx_result(tx_hash).await.map_err(rpc_error)? {
+                    Some(TxResult::Pending) => Ok(seq([
+                        defer(now() + 1),
+                        call(PluginMessage::new(
+                            self.plugin_name(),
+                            ModuleCall::from(WaitForTransaction { tx_hash }),
+                        )),
+                    ])),
+                    Some(TxResult::Success { height, .. }) => {
+                        info!(%tx_hash, %height, "transaction included");
+
+                        Ok(noop())
+                    }
+                    // the datagrams in a failed transaction will fail deterministically if
+                    // resubmitted, so there is no point in retrying
+                    Some(TxResult::Failure { height, error }) => {
+                        warn!(%tx_hash, %height, %error, "transaction failed");
+
+                        Err(ErrorObject::owned(
+                            FATAL_JSONRPC_ERROR_CODE,
+                            format!("transaction {tx_hash} failed at height {height}: {error}"),
+                            None::<()>,
+                        ))
+                    }
+                    None => Err(ErrorObject::owned(
+                        FATAL_JSONRPC_ERROR_CODE,
+                        format!("transaction {tx_hash} not found"),
+                        None::<()>,
+                    )),
+                }
+            }
+        }
+    }
+
+    #[instrument(skip_all, fields(chain_id = %self.chain_id))]
+    async fn callback(
+        &self,
+        _: &Extensions,
+        cb: Never,
+        _data: VecDeque<Data>,
+    ) -> RpcResult<Op<VoyagerMessage>> {
+        match cb {}
+    }
+}
@@ -0,0 +1,7 @@
+use voyager_sdk::plugin::Plugin;
+use voyager_transaction_plugin_mock::Module;
+
+#[tokio::main(flavor = "multi_thread")]
+async fn main() {
+    Module::run().await
+}
@@ -42,17 +42,14 @@ use sui_sdk::{
 };
 use tracing::{info, instrument};
 use ucs03_zkgm::com::{FungibleAssetOrder, ZkgmPacket};
-use unionlabs::{
-    primitives::{encoding::HexPrefixed, Bytes, U256},
-    ErrorReporter,
-};
+use unionlabs::{primitives::U256, ErrorReporter};
 use voyager_sdk::{
     anyhow,
     hook::SubmitTxHook,
     message::{data::Data, PluginMessage, VoyagerMessage},
     plugin::Plugin,
     primitives::ChainId,
-    rpc::{types::PluginInfo, PluginServer},
+    rpc::{types::PluginInfo, PluginServer, FATAL_JSONRPC_ERROR_CODE},
     vm::{call, noop, pass::PassResult, Op, Visit},
     DefaultCmd,
 };
@@ -123,7 +120,8 @@ impl Plugin for Module {
             keyring: ConcurrentKeyring::new(
                 config.keyring.name,
                 config.keyring.keys.into_iter().map(|config| {
-                    println!("{}", Bytes::<HexPrefixed>::new(config.value()));
+                    // sui keys are stored as encoded `suiprivkey` strings, remote signers are not
+                    // supported
                     let pk = SuiKeyPair::decode(
                         &String::from_utf8(config.value()).expect("priv keys are utf8 strings"),
                     )
@@ -221,7 +219,7 @@ impl PluginServer<ModuleCall, ModuleCallback> for Module {
                     let sender = SuiAddress::from(&pk.public());
                     let msgs = msgs.clone();
                     AssertUnwindSafe(async move {
-                        let msgs = process_msgs(self, pk, msgs, sender).await;
+                        let msgs = process_msgs(self, pk, msgs, sender).await?;
 
                         let mut ptb = ProgrammableTransactionBuilder::new();
 
@@ -277,14 +275,16 @@ async fn process_msgs(
     pk: &Arc<SuiKeyPair>,
     msgs: Vec<Datagram>,
     fee_recipient: SuiAddress,
-) -> Vec<(
-    SuiAddress,
-    Datagram,
-    Identifier,
-    Identifier,
-    Vec<CallArg>,
-    Vec<TypeTag>,
-)> {
+) -> RpcResult<
+    Vec<(
+        SuiAddress,
+        Datagram,
+        Identifier,
+        Identifier,
+        Vec<CallArg>,
+        Vec<TypeTag>,
+    )>,
+> {
     let mut data = vec![];
     for msg in msgs {
         let item = match msg.clone() {
@@ -631,12 +631,18 @@ async fn process_msgs(
                     }))],
                 )
             }
-            _ => todo!(),
+            _ => {
+                return Err(ErrorObject::owned(
+                    FATAL_JSONRPC_ERROR_CODE,
+                    format!("unsupported datagram: {}", msg.name()),
+                    None::<()>,
+                ))
+            }
         };
         data.push(item);
     }
 
-    data
+    Ok(data)
 }
 
 fn predict_wrapped_denom(path: U256, channel: ChannelId, base_token: Vec<u8>) -> Vec<u8> {
@@ -7,8 +7,10 @@ use std::{
 
 use anyhow::{anyhow, Context};
 use clap::{self, Parser, Subcommand};
-use ibc_union_spec::IbcUnion;
-use unionlabs::{self, bounded::BoundedI64, ibc::core::client::height::Height, result_unwrap};
+use ibc_union_spec::{ChannelId, ClientId, IbcUnion};
+use unionlabs::{
+    self, bounded::BoundedI64, ibc::core::client::height::Height, primitives::Bytes, result_unwrap,
+};
 use voyager_message::VoyagerMessage;
 use voyager_primitives::{ChainId, ClientType, IbcInterface, IbcSpec, IbcSpecId, QueryHeight};
 use voyager_types::RawClientId;
@@ -121,7 +123,16 @@ pub enum Command {
         rest_url: Option<String>,
     },
     /// Run Voyager.
-    Start,
+    Start {
+        /// Record all traffic between voyager and the plugins and modules to this file.
+        #[arg(long)]
+        record: Option<PathBuf>,
+        /// Replace all modules with a recording previously made with `--record`.
+        ///
+        /// Plugins are still run as normal, so a failing op can be re-run deterministically against the exact module responses that were observed when it failed.
+        #[arg(long, conflicts_with = "record")]
+        replay: Option<PathBuf>,
+    },
     /// Query and interact with the queue.
     #[command(subcommand, alias = "q")]
     Queue(QueueCmd),
@@ -503,6 +514,81 @@ pub enum MsgCmd {
         #[arg(long, global = true)]
         rpc_url: Option<String>,
     },
+    /// Open a new IBC union path between two chains.
+    ///
+    /// This creates a client on each chain tracking the other, and then runs the connection and channel handshakes between them, waiting for each step to complete before starting the next. The handshakes are driven by `voyager-plugin-open-path`, which must be enabled on the voyager instance that the op is enqueued to. Once the channel is open on both chains, the ids of the new clients, connections, and channels are produced as data. The ids are taken from the events emitted by the transactions of each step, so the event sources for both chains must be enabled, and the plugin must be listed before any plugin that takes the connection and channel handshake events (such as `voyager-plugin-transaction-batch`).
+    OpenPath {
+        #[arg(long, value_parser(|s: &str| ok(ChainId::new(s.to_owned()))))]
+        chain_a: ChainId,
+        #[arg(long, value_parser(|s: &str| ok(ChainId::new(s.to_owned()))))]
+        chain_b: ChainId,
+        /// The type of the client to create on `chain_a`, tracking `chain_b`.
+        #[arg(long, value_parser(|s: &str| ok(ClientType::new(s.to_owned()))))]
+        client_type_a: ClientType,
+        /// The type of the client to create on `chain_b`, tracking `chain_a`.
+        #[arg(long, value_parser(|s: &str| ok(ClientType::new(s.to_owned()))))]
+        client_type_b: ClientType,
+        #[arg(long, value_parser(|s: &str| ok(IbcInterface::new(s.to_owned()))))]
+        ibc_interface_a: IbcInterface,
+        #[arg(long, value_parser(|s: &str| ok(IbcInterface::new(s.to_owned()))))]
+        ibc_interface_b: IbcInterface,
+        /// The port on `chain_a` to open the channel on.
+        #[arg(long)]
+        port_a: Bytes,
+        /// The port on `chain_b` to open the channel on.
+        #[arg(long)]
+        port_b: Bytes,
+        /// The version of the channel.
+        #[arg(long)]
+        version: String,
+
+        /// Automatically enqueue the op.
+        #[arg(long, short = 'e', default_value_t = false)]
+        enqueue: bool,
+        #[arg(long, global = true)]
+        rest_url: Option<String>,
+        #[arg(long, global = true)]
+        rpc_url: Option<String>,
+    },
+    /// Relay all packets sent on a channel that are still pending.
+    ///
+    /// Packets sent on the channel within the height range whose commitments are still present on the source chain are relayed to the counterparty, and either their acknowledgements or timeouts are relayed back, depending on the state of the packet on the counterparty. This is useful for packets that were missed, for example if they were sent while the relayer was down. The packets are found and relayed by `voyager-plugin-clear-packets`, which must be enabled on the voyager instance that the op is enqueued to.
+    ClearPackets {
+        /// The chain the packets were sent from.
+        #[arg(value_parser(|s: &str| ok(ChainId::new(s.to_owned()))))]
+        on: ChainId,
+        /// The channel on `on` the packets were sent on.
+        channel_id: ChannelId,
+        /// The first height to search for packets in.
+        #[arg(long)]
+        from_height: u64,
+        /// The last height to search for packets in. Defaults to the latest finalized height of the chain.
+        #[arg(long)]
+        to_height: Option<u64>,
+
+        /// Automatically enqueue the op.
+        #[arg(long, short = 'e', default_value_t = false)]
+        enqueue: bool,
+        #[arg(long, global = true)]
+        rest_url: Option<String>,
+    },
+    /// Recover an expired or frozen IBC union client by substituting its state with that of an active client of the same type.
+    ///
+    /// The substitute client should be created beforehand (i.e. with `voyager msg create-client`) and must track the same chain as the subject client. The transaction is submitted by the transaction plugin of `on`, whose signer must be the relayer admin of the IBC union contract. Connections and channels built on the subject client can be used again once the recovery succeeds.
+    RecoverClient {
+        #[arg(value_parser(|s: &str| ok(ChainId::new(s.to_owned()))))]
+        on: ChainId,
+        /// The expired or frozen client to recover.
+        subject_client_id: ClientId,
+        /// The active client to copy the state from.
+        substitute_client_id: ClientId,
+
+        /// Automatically enqueue the op.
+        #[arg(long, short = 'e', default_value_t = false)]
+        enqueue: bool,
+        #[arg(long, global = true)]
+        rest_url: Option<String>,
+    },
 }
 
 #[allow(
@@ -16,7 +16,10 @@ use std::{collections::HashMap, fmt::Write, iter, process::ExitCode, time::Durat
 use anyhow::{anyhow, Context as _};
 use clap::Parser;
 use ibc_classic_spec::IbcClassic;
-use ibc_union_spec::IbcUnion;
+use ibc_union_spec::{
+    datagram::{Datagram, MsgRecoverClient},
+    IbcUnion,
+};
 use pg_queue::{
     default_max_connections, default_min_connections, default_retryable_error_expo_backoff_max,
     default_retryable_error_expo_backoff_multiplier, PgQueueConfig, Tables,
@@ -39,10 +42,14 @@ use voyager_core::{
     Engine,
 };
 use voyager_message::{
-    call::{FetchUpdateHeaders, Index, IndexRange, IndexRangeHeights},
+    call::{FetchUpdateHeaders, Index, IndexRange, IndexRangeHeights, SubmitTx},
     callback::AggregateSubmitTxFromOrderedHeaders,
-    VoyagerMessage,
+    data::IbcDatagram,
+    PluginMessage, VoyagerMessage,
 };
+use voyager_plugin_clear_packets::call::{ClearPackets, ModuleCall as ClearPacketsCall};
+use voyager_plugin_open_path::call::PathConfig;
+use voyager_plugin_protocol::record::{Recorder, Replay};
 use voyager_primitives::{IbcSpec, QueryHeight};
 use voyager_rpc::{types::IbcStateResponse, VoyagerRpcClient};
 use voyager_vm::{call, promise, Op, Queue};
@@ -184,12 +191,32 @@ async fn do_main(app: cli::App) -> anyhow::Result<()> {
                 .into_root_schema_for::<Config>(),
             ),
         },
-        Command::Start => {
+        Command::Start { record, replay } => {
             let config = get_voyager_config()?;
 
             metrics::init(&config.voyager.metrics_endpoint);
 
-            let voyager = Engine::builder()
+            let mut builder = Engine::builder();
+
+            if let Some(record) = record {
+                info!(path = %record.display(), "recording plugin and module traffic");
+
+                builder = builder.with_recorder(
+                    Recorder::create(&record)
+                        .with_context(|| format!("creating recording at {}", record.display()))?,
+                );
+            }
+
+            if let Some(replay) = replay {
+                info!(path = %replay.display(), "replaying module traffic");
+
+                builder = builder.with_replay(
+                    Replay::load(&replay)
+                        .with_context(|| format!("loading recording at {}", replay.display()))?,
+                );
+            }
+
+            let voyager = builder
                 .with_equivalent_chain_ids(config.equivalent_chain_ids)
                 .with_plugins(config.plugins)
                 .with_modules(config.modules)
@@ -789,6 +816,119 @@ async fn do_main(app: cli::App) -> anyhow::Result<()> {
                     },
                 );
 
+                if enqueue {
+                    send_enqueue(&get_rest_url(rest_url), op).await?;
+                } else {
+                    print_json(&op);
+                }
+            }
+            MsgCmd::OpenPath {
+                chain_a,
+                chain_b,
+                client_type_a,
+                client_type_b,
+                ibc_interface_a,
+                ibc_interface_b,
+                port_a,
+                port_b,
+                version,
+                enqueue,
+                rest_url,
+                rpc_url,
+            } => {
+                let voyager_client = VoyagerClient::new(
+                    jsonrpsee::http_client::HttpClient::builder().build(get_rpc_url(rpc_url))?,
+                );
+
+                let create_client_a = utils::make_msg_create_client(
+                    &voyager_client,
+                    chain_b.clone(),
+                    QueryHeight::Finalized,
+                    chain_a.clone(),
+                    client_type_a.clone(),
+                    ibc_interface_a,
+                    IbcUnion::ID,
+                    Value::Null,
+                    Value::Null,
+                    Value::Null,
+                )
+                .await?;
+
+                let create_client_b = utils::make_msg_create_client(
+                    &voyager_client,
+                    chain_a.clone(),
+                    QueryHeight::Finalized,
+                    chain_b.clone(),
+                    client_type_b.clone(),
+                    ibc_interface_b,
+                    IbcUnion::ID,
+                    Value::Null,
+                    Value::Null,
+                    Value::Null,
+                )
+                .await?;
+
+                let op = voyager_plugin_open_path::open_path(
+                    PathConfig {
+                        chain_a,
+                        chain_b,
+                        client_type_a,
+                        client_type_b,
+                        port_a,
+                        port_b,
+                        version,
+                    },
+                    create_client_a,
+                    create_client_b,
+                );
+
+                if enqueue {
+                    send_enqueue(&get_rest_url(rest_url), op).await?;
+                } else {
+                    print_json(&op);
+                }
+            }
+            MsgCmd::ClearPackets {
+                on,
+                channel_id,
+                from_height,
+                to_height,
+                enqueue,
+                rest_url,
+            } => {
+                let op = call::<VoyagerMessage>(PluginMessage::new(
+                    voyager_plugin_clear_packets::plugin_name(),
+                    ClearPacketsCall::from(ClearPackets {
+                        chain_id: on,
+                        channel_id,
+                        from_height,
+                        to_height,
+                    }),
+                ));
+
+                if enqueue {
+                    send_enqueue(&get_rest_url(rest_url), op).await?;
+                } else {
+                    print_json(&op);
+                }
+            }
+            MsgCmd::RecoverClient {
+                on,
+                subject_client_id,
+                substitute_client_id,
+                enqueue,
+                rest_url,
+            } => {
+                let op = call::<VoyagerMessage>(SubmitTx {
+                    chain_id: on,
+                    datagrams: vec![IbcDatagram::new::<IbcUnion>(Datagram::from(
+                        MsgRecoverClient {
+                            subject_client_id,
+                            substitute_client_id,
+                        },
+                    ))],
+                });
+
                 if enqueue {
                     send_enqueue(&get_rest_url(rest_url), op).await?;
                 } else {